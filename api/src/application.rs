@@ -1,3 +1,4 @@
+pub mod audit;
 pub mod auth;
 pub mod decoded_token;
 pub mod http;
@@ -0,0 +1,298 @@
+use axum::{
+    body::{Body, HttpBody, to_bytes},
+    extract::{MatchedPath, Request, State},
+    http::{
+        HeaderMap, Method,
+        header::{CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
+    },
+    middleware::Next,
+    response::Response,
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    seawatch::{AdminOperation, ports::SecurityEventService, value_objects::RecordAdminEventInput},
+};
+use serde_json::Value;
+
+use super::http::server::app_state::AppState;
+
+/// Responses larger than this are passed through without an `after` snapshot
+/// rather than buffered.
+const MAX_SNAPSHOT_BYTES: u64 = 1024 * 1024;
+
+/// Where in the realm a mutating call landed, derived from the matched route.
+#[derive(Debug, PartialEq, Eq)]
+struct AuditTarget {
+    realm_name: Option<String>,
+    resource_type: String,
+    resource_id: Option<String>,
+    resource_path: String,
+}
+
+/// Records every successful mutating call made with a bearer token as a
+/// SeaWatch admin event.
+///
+/// For updates and deletions the current state is captured first by replaying
+/// the request as a `GET` on the same path, and the response body is used as
+/// the state after the call. Routes without a `GET` simply have no `before`.
+pub async fn admin_audit(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    let operation = match *req.method() {
+        Method::POST => AdminOperation::Create,
+        Method::PUT | Method::PATCH => AdminOperation::Update,
+        Method::DELETE => AdminOperation::Delete,
+        _ => return next.run(req).await,
+    };
+
+    let root_path = state.args.server.root_path.clone();
+    let path = req.uri().path().to_string();
+    if !relative_to(&path, &root_path).starts_with("/realms") {
+        return next.run(req).await;
+    }
+
+    let before = match operation {
+        AdminOperation::Create => None,
+        _ => snapshot(next.clone(), replay_as_get(&req)).await,
+    };
+    let ip_address = client_ip(req.headers());
+    let user_agent = req
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let response = next.run(req).await;
+
+    // Only calls that went through `auth` carry these; anything else is not
+    // an admin call.
+    let (Some(identity), Some(matched_path)) = (
+        response.extensions().get::<Identity>().cloned(),
+        response.extensions().get::<MatchedPath>().cloned(),
+    ) else {
+        return response;
+    };
+    if !response.status().is_success() {
+        return response;
+    }
+
+    let (response, after) = match operation {
+        AdminOperation::Delete => (response, None),
+        _ => json_body(response).await,
+    };
+
+    let Some(target) = audit_target(
+        relative_to(matched_path.as_str(), &root_path),
+        relative_to(&path, &root_path),
+    ) else {
+        return response;
+    };
+
+    // Realm creation has no realm in its path: attribute it to the new realm.
+    let realm_name = target.realm_name.or_else(|| {
+        after
+            .as_ref()
+            .and_then(|after| after.get("name"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    });
+    let Some(realm_name) = realm_name else {
+        return response;
+    };
+
+    let result = state
+        .service
+        .record_admin_event(
+            identity,
+            RecordAdminEventInput {
+                realm_name,
+                operation,
+                resource_type: target.resource_type,
+                resource_id: target.resource_id,
+                resource_path: target.resource_path,
+                before,
+                after,
+                ip_address,
+                user_agent,
+            },
+        )
+        .await;
+
+    if let Err(e) = result {
+        tracing::error!("Failed to record admin event for {path}: {e}");
+    }
+
+    response
+}
+
+fn relative_to<'a>(path: &'a str, root_path: &str) -> &'a str {
+    path.strip_prefix(root_path).unwrap_or(path)
+}
+
+/// A bodiless `GET` on the same URI, carrying the caller's credentials.
+fn replay_as_get(req: &Request) -> Option<Request> {
+    let mut request = Request::builder()
+        .method(Method::GET)
+        .uri(req.uri().clone());
+    for (name, value) in req.headers() {
+        if name != CONTENT_TYPE && name != CONTENT_LENGTH {
+            request = request.header(name, value);
+        }
+    }
+
+    request.body(Body::empty()).ok()
+}
+
+async fn snapshot(next: Next, request: Option<Request>) -> Option<Value> {
+    let response = next.run(request?).await;
+    if !response.status().is_success() {
+        return None;
+    }
+
+    json_body(response).await.1
+}
+
+/// Buffers a JSON response body and hands back an equivalent response.
+async fn json_body(response: Response) -> (Response, Option<Value>) {
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    let too_large = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_none_or(|size| size > MAX_SNAPSHOT_BYTES);
+
+    if !is_json || too_large {
+        return (response, None);
+    }
+
+    let (parts, body) = response.into_parts();
+    match to_bytes(body, MAX_SNAPSHOT_BYTES as usize).await {
+        Ok(bytes) => {
+            let value = serde_json::from_slice(&bytes).ok();
+            (Response::from_parts(parts, Body::from(bytes)), value)
+        }
+        Err(e) => {
+            tracing::error!("Failed to buffer response body for admin audit: {e}");
+            (Response::from_parts(parts, Body::empty()), None)
+        }
+    }
+}
+
+pub(crate) fn client_ip(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(',').next())
+        .map(|ip| ip.trim().to_string())
+        .filter(|ip| !ip.is_empty())
+}
+
+/// Matches the route template against the concrete path. The resource type is
+/// made of the literal segments after the realm, the resource id is the path
+/// parameter following the last of them, if any.
+fn audit_target(template: &str, path: &str) -> Option<AuditTarget> {
+    let template: Vec<&str> = template.split('/').filter(|s| !s.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    if template.len() != path.len() || template.first() != Some(&"realms") {
+        return None;
+    }
+
+    let is_param = |segment: &str| segment.starts_with('{') && segment.ends_with('}');
+    let is_version = |segment: &str| {
+        segment
+            .strip_prefix('v')
+            .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+    };
+
+    let realm_name = match template.get(1) {
+        Some(segment) if is_param(segment) => Some(path[1].to_string()),
+        _ => None,
+    };
+    let skip = if realm_name.is_some() { 2 } else { 1 };
+
+    let mut resource_type = Vec::new();
+    let mut resource_id = None;
+    for (template_segment, segment) in template.iter().zip(&path).skip(skip) {
+        if is_param(template_segment) {
+            resource_id = Some(segment.to_string());
+        } else if !is_version(template_segment) {
+            resource_type.push(*template_segment);
+            resource_id = None;
+        }
+    }
+
+    let (resource_type, resource_id) = if resource_type.is_empty() {
+        ("realm".to_string(), realm_name.clone())
+    } else {
+        (resource_type.join("."), resource_id)
+    };
+
+    Some(AuditTarget {
+        resource_path: format!("/{}", path[skip..].join("/")),
+        realm_name,
+        resource_type,
+        resource_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_resource_uses_last_identifier() {
+        let target = audit_target(
+            "/realms/{realm_name}/clients/{client_id}/redirects/{uri_id}",
+            "/realms/acme/clients/c-1/redirects/r-9",
+        )
+        .unwrap();
+
+        assert_eq!(target.realm_name.as_deref(), Some("acme"));
+        assert_eq!(target.resource_type, "clients.redirects");
+        assert_eq!(target.resource_id.as_deref(), Some("r-9"));
+        assert_eq!(target.resource_path, "/clients/c-1/redirects/r-9");
+    }
+
+    #[test]
+    fn collection_and_versioned_routes_have_no_identifier() {
+        let target = audit_target(
+            "/realms/{realm_name}/seawatch/v1/retention",
+            "/realms/acme/seawatch/v1/retention",
+        )
+        .unwrap();
+
+        assert_eq!(target.resource_type, "seawatch.retention");
+        assert_eq!(target.resource_id, None);
+    }
+
+    #[test]
+    fn realm_itself_is_the_resource() {
+        let target = audit_target("/realms/{name}", "/realms/acme").unwrap();
+
+        assert_eq!(target.resource_type, "realm");
+        assert_eq!(target.resource_id.as_deref(), Some("acme"));
+    }
+
+    #[test]
+    fn realm_creation_has_no_realm_in_path() {
+        let target = audit_target("/realms", "/realms").unwrap();
+
+        assert_eq!(target.realm_name, None);
+    }
+
+    #[test]
+    fn routes_outside_realms_are_ignored() {
+        assert_eq!(audit_target("/config", "/config"), None);
+    }
+
+    #[test]
+    fn forwarded_for_takes_the_first_hop() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "203.0.113.7, 10.0.0.1".parse().unwrap());
+
+        assert_eq!(client_ip(&headers).as_deref(), Some("203.0.113.7"));
+    }
+}
@@ -1,6 +1,6 @@
 use axum::{
     RequestPartsExt,
-    extract::{FromRef, FromRequestParts, Request, State},
+    extract::{FromRef, FromRequestParts, MatchedPath, Request, State},
     http::{StatusCode, request::Parts},
     middleware::Next,
     response::{IntoResponse, Response},
@@ -152,9 +152,19 @@ pub async fn auth(
         .await
         .map_err(|_| StatusCode::UNAUTHORIZED)?;
 
-    req.extensions_mut().insert(output.identity);
+    // The identity and matched route are echoed on the response so outer
+    // layers, such as the admin audit trail, can attribute the call.
+    let identity = output.identity;
+    let matched_path = req.extensions().get::<MatchedPath>().cloned();
+    req.extensions_mut().insert(identity.clone());
 
-    Ok(next.run(req).await)
+    let mut response = next.run(req).await;
+    response.extensions_mut().insert(identity);
+    if let Some(matched_path) = matched_path {
+        response.extensions_mut().insert(matched_path);
+    }
+
+    Ok(response)
 }
 
 pub async fn auth_login_actions(
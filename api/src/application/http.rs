@@ -1,19 +1,28 @@
 pub mod abyss;
+pub mod account;
 pub mod aegis;
+pub mod auth_flow;
 pub mod authentication;
 pub mod broker;
 pub mod client;
 pub mod compass;
+pub mod email_outbox;
 pub mod email_template;
 pub mod error;
 pub mod health;
+pub mod housekeeping;
+pub mod localization;
 pub mod maintenance;
 pub mod organization;
+pub mod otp;
 pub mod portal_layouts;
 pub mod portal_theme;
 pub mod realm;
+pub mod risk;
 pub mod role;
+pub mod scim;
 pub mod seawatch;
+pub mod security_notification;
 pub mod server;
 pub mod test;
 pub mod trident;
@@ -0,0 +1,62 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    privacy::{ports::PrivacyService, value_objects::UserDataInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct CancelAccountDeletionResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/account/deletion",
+    tag = "account",
+    summary = "Cancel my account deletion",
+    description = "Cancels the pending deletion of the user owning the access token.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Deletion cancelled successfully", body = CancelAccountDeletionResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 404, description = "No deletion is pending", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn cancel_account_deletion(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<CancelAccountDeletionResponse>, ApiError> {
+    state
+        .service
+        .cancel_account_deletion(
+            identity,
+            UserDataInput {
+                realm_name,
+                user_id: None,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(CancelAccountDeletionResponse {
+        message: "Account deletion cancelled".to_string(),
+    }))
+}
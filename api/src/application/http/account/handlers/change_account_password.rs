@@ -0,0 +1,68 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::ChangeAccountPasswordInput, ports::AccountService},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::{
+    account::validators::ChangeAccountPasswordValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ChangeAccountPasswordResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/account/password",
+    tag = "account",
+    summary = "Change my password",
+    description = "Replaces the password of the user owning the access token. The current password is required when one is set, and the new one must satisfy the realm password policy.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    request_body = ChangeAccountPasswordValidator,
+    responses(
+        (status = 200, description = "Password changed successfully", body = ChangeAccountPasswordResponse),
+        (status = 400, description = "New password rejected by the realm policy", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Current password missing or wrong", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn change_account_password(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ChangeAccountPasswordValidator>,
+) -> Result<Response<ChangeAccountPasswordResponse>, ApiError> {
+    state
+        .service
+        .change_account_password(
+            identity,
+            ChangeAccountPasswordInput {
+                realm_name,
+                current_password: payload.current_password,
+                new_password: payload.new_password,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(ChangeAccountPasswordResponse {
+        message: "Password changed successfully".to_string(),
+    }))
+}
@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::DeleteAccountCredentialInput, ports::AccountService},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteAccountCredentialResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/account/credentials/{credential_id}",
+    tag = "account",
+    summary = "Delete one of my credentials",
+    description = "Removes an OTP, WebAuthn or recovery code credential of the user owning the access token. Deleting a recovery code removes the whole set. The password cannot be deleted.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("credential_id" = Uuid, Path, description = "Credential ID"),
+    ),
+    responses(
+        (status = 200, description = "Credential deleted successfully", body = DeleteAccountCredentialResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm, or the credential is the password", body = ApiErrorResponse),
+        (status = 404, description = "Credential not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_account_credential(
+    Path((realm_name, credential_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteAccountCredentialResponse>, ApiError> {
+    state
+        .service
+        .delete_account_credential(
+            identity,
+            DeleteAccountCredentialInput {
+                realm_name,
+                credential_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DeleteAccountCredentialResponse {
+        message: format!("Credential with ID {credential_id} deleted successfully"),
+    }))
+}
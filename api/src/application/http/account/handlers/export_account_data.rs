@@ -0,0 +1,59 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    privacy::{entities::UserDataExport, ports::PrivacyService, value_objects::UserDataInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountDataExportResponse {
+    pub data: UserDataExport,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/export",
+    tag = "account",
+    summary = "Export my data",
+    description = "Returns everything stored about the user owning the access token: profile, attributes, roles, organizations, credential metadata, linked identities, sessions, security events and authentication flows. Secrets are never included.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Data exported successfully", body = AccountDataExportResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn export_account_data(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AccountDataExportResponse>, ApiError> {
+    let export = state
+        .service
+        .export_user_data(
+            identity,
+            UserDataInput {
+                realm_name,
+                user_id: None,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountDataExportResponse { data: export }))
+}
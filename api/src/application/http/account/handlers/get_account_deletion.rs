@@ -0,0 +1,63 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    privacy::{
+        entities::AccountDeletionRequest, ports::PrivacyService, value_objects::UserDataInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountDeletionStatusResponse {
+    pub data: Option<AccountDeletionRequest>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/deletion",
+    tag = "account",
+    summary = "Get my pending account deletion",
+    description = "Returns the scheduled deletion of the user owning the access token, or null when none is pending.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Deletion status retrieved successfully", body = AccountDeletionStatusResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_account_deletion(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AccountDeletionStatusResponse>, ApiError> {
+    let request = state
+        .service
+        .get_account_deletion(
+            identity,
+            UserDataInput {
+                realm_name,
+                user_id: None,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountDeletionStatusResponse {
+        data: request,
+    }))
+}
@@ -0,0 +1,54 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::AccountInput, ports::AccountService},
+    authentication::value_objects::Identity,
+    user::entities::User,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountProfileResponse {
+    pub data: User,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account",
+    tag = "account",
+    summary = "Get my profile",
+    description = "Returns the profile of the user owning the access token.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Profile retrieved successfully", body = AccountProfileResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_account_profile(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AccountProfileResponse>, ApiError> {
+    let user = state
+        .service
+        .get_account_profile(identity, AccountInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountProfileResponse { data: user }))
+}
@@ -0,0 +1,56 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::AccountInput, ports::AccountService},
+    authentication::value_objects::Identity,
+    credential::entities::CredentialOverview,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountCredentialsResponse {
+    pub data: Vec<CredentialOverview>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/credentials",
+    tag = "account",
+    summary = "List my credentials",
+    description = "Lists the password, OTP, WebAuthn and recovery code credentials of the user owning the access token. Secrets are never returned.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Credentials retrieved successfully", body = AccountCredentialsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_account_credentials(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AccountCredentialsResponse>, ApiError> {
+    let credentials = state
+        .service
+        .list_account_credentials(identity, AccountInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountCredentialsResponse {
+        data: credentials,
+    }))
+}
@@ -0,0 +1,69 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::ListAccountEventsInput, ports::AccountService},
+    authentication::value_objects::Identity,
+    seawatch::entities::SecurityEvent,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountEventsResponse {
+    pub data: Vec<SecurityEvent>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListAccountEventsQuery {
+    /// Maximum number of events, capped at 100
+    pub limit: Option<u32>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/events",
+    tag = "account",
+    summary = "List my recent security events",
+    description = "Returns the latest security events where the user owning the access token is the actor or the target, newest first.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ListAccountEventsQuery,
+    ),
+    responses(
+        (status = 200, description = "Security events retrieved successfully", body = AccountEventsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_account_events(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<ListAccountEventsQuery>,
+) -> Result<Response<AccountEventsResponse>, ApiError> {
+    let events = state
+        .service
+        .list_account_events(
+            identity,
+            ListAccountEventsInput {
+                realm_name,
+                limit: query.limit,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountEventsResponse { data: events }))
+}
@@ -0,0 +1,56 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{
+        entities::{AccountInput, AccountSession},
+        ports::AccountService,
+    },
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountSessionsResponse {
+    pub data: Vec<AccountSession>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/sessions",
+    tag = "account",
+    summary = "List my sessions",
+    description = "Lists the active sessions of the user owning the access token, with the client each one was opened for.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Sessions retrieved successfully", body = AccountSessionsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_account_sessions(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AccountSessionsResponse>, ApiError> {
+    let sessions = state
+        .service
+        .list_account_sessions(identity, AccountInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountSessionsResponse { data: sessions }))
+}
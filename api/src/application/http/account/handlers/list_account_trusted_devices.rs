@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    trusted_device::{
+        entities::TrustedDevice, ports::TrustedDeviceService,
+        value_objects::ListTrustedDevicesInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountTrustedDevicesResponse {
+    pub data: Vec<TrustedDevice>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/trusted-devices",
+    tag = "account",
+    summary = "List my trusted devices",
+    description = "Lists the browsers the user owning the access token trusted during a sign-in, which skip the second factor until they expire.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Trusted devices retrieved successfully", body = AccountTrustedDevicesResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_account_trusted_devices(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AccountTrustedDevicesResponse>, ApiError> {
+    let devices = state
+        .service
+        .list_trusted_devices(
+            identity,
+            ListTrustedDevicesInput {
+                realm_name,
+                user_id: None,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountTrustedDevicesResponse {
+        data: devices,
+    }))
+}
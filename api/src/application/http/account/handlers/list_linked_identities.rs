@@ -0,0 +1,56 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{
+        entities::{AccountInput, LinkedIdentity},
+        ports::AccountService,
+    },
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct LinkedIdentitiesResponse {
+    pub data: Vec<LinkedIdentity>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/identities",
+    tag = "account",
+    summary = "List my linked identities",
+    description = "Lists the external identity provider accounts linked to the user owning the access token.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Linked identities retrieved successfully", body = LinkedIdentitiesResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_linked_identities(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<LinkedIdentitiesResponse>, ApiError> {
+    let identities = state
+        .service
+        .list_linked_identities(identity, AccountInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(LinkedIdentitiesResponse { data: identities }))
+}
@@ -0,0 +1,16 @@
+pub mod cancel_account_deletion;
+pub mod change_account_password;
+pub mod delete_account_credential;
+pub mod export_account_data;
+pub mod get_account_deletion;
+pub mod get_account_profile;
+pub mod list_account_credentials;
+pub mod list_account_events;
+pub mod list_account_sessions;
+pub mod list_account_trusted_devices;
+pub mod list_linked_identities;
+pub mod request_account_deletion;
+pub mod revoke_account_session;
+pub mod revoke_account_trusted_device;
+pub mod unlink_identity;
+pub mod update_account_profile;
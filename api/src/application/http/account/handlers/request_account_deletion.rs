@@ -0,0 +1,63 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    privacy::{
+        entities::AccountDeletionRequest, ports::PrivacyService,
+        value_objects::RequestAccountDeletionInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AccountDeletionResponse {
+    pub data: AccountDeletionRequest,
+}
+
+#[utoipa::path(
+    post,
+    path = "/account/deletion",
+    tag = "account",
+    summary = "Delete my account",
+    description = "Schedules the deletion of the user owning the access token after a 30 day cooling-off period, during which it can be cancelled. Requesting again returns the pending deletion.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Deletion scheduled successfully", body = AccountDeletionResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn request_account_deletion(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AccountDeletionResponse>, ApiError> {
+    let request = state
+        .service
+        .request_account_deletion(
+            identity,
+            RequestAccountDeletionInput {
+                realm_name,
+                user_id: None,
+                cooling_off_days: None,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AccountDeletionResponse { data: request }))
+}
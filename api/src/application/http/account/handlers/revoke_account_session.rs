@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::RevokeAccountSessionInput, ports::AccountService},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RevokeAccountSessionResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/account/sessions/{session_id}",
+    tag = "account",
+    summary = "Revoke one of my sessions",
+    description = "Signs the user owning the access token out of a session. The session can no longer be refreshed and its access tokens expire on their own.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("session_id" = Uuid, Path, description = "Session ID"),
+    ),
+    responses(
+        (status = 200, description = "Session revoked successfully", body = RevokeAccountSessionResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 404, description = "Session not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn revoke_account_session(
+    Path((realm_name, session_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RevokeAccountSessionResponse>, ApiError> {
+    state
+        .service
+        .revoke_account_session(
+            identity,
+            RevokeAccountSessionInput {
+                realm_name,
+                session_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(RevokeAccountSessionResponse {
+        message: format!("Session {session_id} revoked successfully"),
+    }))
+}
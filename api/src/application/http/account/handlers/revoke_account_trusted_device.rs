@@ -0,0 +1,65 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    trusted_device::{ports::TrustedDeviceService, value_objects::RevokeTrustedDeviceInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RevokeAccountTrustedDeviceResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/account/trusted-devices/{device_id}",
+    tag = "account",
+    summary = "Revoke one of my trusted devices",
+    description = "Stops a browser from skipping the second factor of the user owning the access token.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("device_id" = Uuid, Path, description = "Trusted device ID"),
+    ),
+    responses(
+        (status = 200, description = "Trusted device revoked successfully", body = RevokeAccountTrustedDeviceResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 404, description = "Trusted device not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn revoke_account_trusted_device(
+    Path((realm_name, device_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RevokeAccountTrustedDeviceResponse>, ApiError> {
+    state
+        .service
+        .revoke_trusted_device(
+            identity,
+            RevokeTrustedDeviceInput {
+                realm_name,
+                user_id: None,
+                device_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(RevokeAccountTrustedDeviceResponse {
+        message: format!("Trusted device {device_id} revoked successfully"),
+    }))
+}
@@ -0,0 +1,63 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::UnlinkIdentityInput, ports::AccountService},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct UnlinkIdentityResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/account/identities/{alias}",
+    tag = "account",
+    summary = "Unlink an identity provider",
+    description = "Removes the link between the user owning the access token and an external identity provider. The last link cannot be removed while the account has no password.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("alias" = String, Path, description = "Identity provider alias"),
+    ),
+    responses(
+        (status = 200, description = "Identity unlinked successfully", body = UnlinkIdentityResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Unlinking would lock the user out", body = ApiErrorResponse),
+        (status = 404, description = "Identity provider or link not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn unlink_identity(
+    Path((realm_name, alias)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<UnlinkIdentityResponse>, ApiError> {
+    state
+        .service
+        .unlink_identity(
+            identity,
+            UnlinkIdentityInput {
+                realm_name,
+                identity_provider_alias: alias.clone(),
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(UnlinkIdentityResponse {
+        message: format!("Identity provider {alias} unlinked successfully"),
+    }))
+}
@@ -0,0 +1,61 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    account::{entities::UpdateAccountProfileInput, ports::AccountService},
+    authentication::value_objects::Identity,
+};
+
+use crate::application::http::{
+    account::{
+        handlers::get_account_profile::AccountProfileResponse,
+        validators::UpdateAccountProfileValidator,
+    },
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/account",
+    tag = "account",
+    summary = "Update my profile",
+    description = "Updates the first and last name of the user owning the access token. The email address is changed through the email change flow instead.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    request_body = UpdateAccountProfileValidator,
+    responses(
+        (status = 200, description = "Profile updated successfully", body = AccountProfileResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Not a user of this realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_account_profile(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateAccountProfileValidator>,
+) -> Result<Response<AccountProfileResponse>, ApiError> {
+    let user = state
+        .service
+        .update_account_profile(
+            identity,
+            UpdateAccountProfileInput {
+                realm_name,
+                firstname: payload.firstname,
+                lastname: payload.lastname,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(AccountProfileResponse { data: user }))
+}
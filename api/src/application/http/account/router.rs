@@ -0,0 +1,103 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get, post},
+};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        account::handlers::{
+            cancel_account_deletion::{__path_cancel_account_deletion, cancel_account_deletion},
+            change_account_password::{__path_change_account_password, change_account_password},
+            delete_account_credential::{
+                __path_delete_account_credential, delete_account_credential,
+            },
+            export_account_data::{__path_export_account_data, export_account_data},
+            get_account_deletion::{__path_get_account_deletion, get_account_deletion},
+            get_account_profile::{__path_get_account_profile, get_account_profile},
+            list_account_credentials::{__path_list_account_credentials, list_account_credentials},
+            list_account_events::{__path_list_account_events, list_account_events},
+            list_account_sessions::{__path_list_account_sessions, list_account_sessions},
+            list_account_trusted_devices::{
+                __path_list_account_trusted_devices, list_account_trusted_devices,
+            },
+            list_linked_identities::{__path_list_linked_identities, list_linked_identities},
+            request_account_deletion::{__path_request_account_deletion, request_account_deletion},
+            revoke_account_session::{__path_revoke_account_session, revoke_account_session},
+            revoke_account_trusted_device::{
+                __path_revoke_account_trusted_device, revoke_account_trusted_device,
+            },
+            unlink_identity::{__path_unlink_identity, unlink_identity},
+            update_account_profile::{__path_update_account_profile, update_account_profile},
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_account_profile,
+    update_account_profile,
+    list_account_credentials,
+    delete_account_credential,
+    change_account_password,
+    list_linked_identities,
+    unlink_identity,
+    list_account_sessions,
+    revoke_account_session,
+    list_account_trusted_devices,
+    revoke_account_trusted_device,
+    list_account_events,
+    export_account_data,
+    get_account_deletion,
+    request_account_deletion,
+    cancel_account_deletion,
+))]
+pub struct AccountApiDoc;
+
+pub fn account_routes(state: AppState) -> Router<AppState> {
+    let base = format!(
+        "{}/realms/{{realm_name}}/account",
+        state.args.server.root_path
+    );
+
+    Router::new()
+        .route(&base, get(get_account_profile).put(update_account_profile))
+        .route(
+            &format!("{base}/credentials"),
+            get(list_account_credentials),
+        )
+        .route(
+            &format!("{base}/credentials/{{credential_id}}"),
+            delete(delete_account_credential),
+        )
+        .route(&format!("{base}/password"), post(change_account_password))
+        .route(&format!("{base}/identities"), get(list_linked_identities))
+        .route(
+            &format!("{base}/identities/{{alias}}"),
+            delete(unlink_identity),
+        )
+        .route(&format!("{base}/sessions"), get(list_account_sessions))
+        .route(
+            &format!("{base}/sessions/{{session_id}}"),
+            delete(revoke_account_session),
+        )
+        .route(
+            &format!("{base}/trusted-devices"),
+            get(list_account_trusted_devices),
+        )
+        .route(
+            &format!("{base}/trusted-devices/{{device_id}}"),
+            delete(revoke_account_trusted_device),
+        )
+        .route(&format!("{base}/events"), get(list_account_events))
+        .route(&format!("{base}/export"), get(export_account_data))
+        .route(
+            &format!("{base}/deletion"),
+            get(get_account_deletion)
+                .post(request_account_deletion)
+                .delete(cancel_account_deletion),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
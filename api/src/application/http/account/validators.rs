@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Fields left out keep their current value.
+#[derive(Debug, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateAccountProfileValidator {
+    #[serde(default)]
+    pub firstname: Option<String>,
+
+    #[serde(default)]
+    pub lastname: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ChangeAccountPasswordValidator {
+    /// Required when the account already has a password.
+    #[serde(default)]
+    pub current_password: Option<String>,
+
+    #[validate(length(min = 1, message = "new_password is required"))]
+    #[serde(default)]
+    pub new_password: String,
+}
@@ -0,0 +1,103 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    auth_flow::{ports::AuthFlowService, value_objects::DeleteAuthFlowInput},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteAuthFlowResponse {
+    message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/auth-flow",
+    tag = "auth-flow",
+    summary = "Reset the authentication flow of the realm",
+    description = "Deletes the flow configured for the realm, so its users sign in with the default flow again. Client overrides are kept.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Authentication flow deleted successfully", body = DeleteAuthFlowResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "No flow configured", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_auth_flow(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteAuthFlowResponse>, ApiError> {
+    state
+        .service
+        .delete_auth_flow(
+            identity,
+            DeleteAuthFlowInput {
+                realm_name,
+                client_id: None,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DeleteAuthFlowResponse {
+        message: "Authentication flow deleted successfully".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/clients/{client_id}/auth-flow",
+    tag = "auth-flow",
+    summary = "Remove the authentication flow override of a client",
+    description = "Deletes the flow configured for the client, so its users sign in with the realm's flow again.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("client_id" = Uuid, Path, description = "ID of the client"),
+    ),
+    responses(
+        (status = 200, description = "Authentication flow deleted successfully", body = DeleteAuthFlowResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client or override not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_client_auth_flow(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteAuthFlowResponse>, ApiError> {
+    state
+        .service
+        .delete_auth_flow(
+            identity,
+            DeleteAuthFlowInput {
+                realm_name,
+                client_id: Some(client_id),
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DeleteAuthFlowResponse {
+        message: "Authentication flow deleted successfully".to_string(),
+    }))
+}
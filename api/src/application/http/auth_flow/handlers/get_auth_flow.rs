@@ -0,0 +1,98 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    auth_flow::{entities::AuthFlow, ports::AuthFlowService, value_objects::GetAuthFlowInput},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AuthFlowResponse {
+    pub data: AuthFlow,
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth-flow",
+    tag = "auth-flow",
+    summary = "Get the authentication flow of the realm",
+    description = "Returns the steps users of the realm go through to sign in. Realms that never configured one use a password followed by any second factor the user has set up.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Authentication flow retrieved successfully", body = AuthFlowResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_auth_flow(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AuthFlowResponse>, ApiError> {
+    let flow = state
+        .service
+        .get_auth_flow(
+            identity,
+            GetAuthFlowInput {
+                realm_name,
+                client_id: None,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AuthFlowResponse { data: flow }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/clients/{client_id}/auth-flow",
+    tag = "auth-flow",
+    summary = "Get the authentication flow of a client",
+    description = "Returns the steps users signing in to the client go through: its own flow when it overrides the realm's, the realm's flow otherwise.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("client_id" = Uuid, Path, description = "ID of the client"),
+    ),
+    responses(
+        (status = 200, description = "Authentication flow retrieved successfully", body = AuthFlowResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_client_auth_flow(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AuthFlowResponse>, ApiError> {
+    let flow = state
+        .service
+        .get_auth_flow(
+            identity,
+            GetAuthFlowInput {
+                realm_name,
+                client_id: Some(client_id),
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AuthFlowResponse { data: flow }))
+}
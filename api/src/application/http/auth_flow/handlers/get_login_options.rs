@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+};
+use ferriskey_core::domain::auth_flow::{
+    entities::LoginOptions, ports::AuthFlowService, value_objects::GetLoginOptionsInput,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::application::{
+    audit::client_ip,
+    http::server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LoginOptionsQuery {
+    /// Client the user is signing in to
+    pub client_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct LoginOptionsResponse {
+    pub data: LoginOptions,
+}
+
+#[utoipa::path(
+    get,
+    path = "/login-actions/login-options",
+    tag = "auth",
+    summary = "List the ways to start signing in",
+    description = "Returns the authenticators the login page should offer first for the client, following its authentication flow.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        LoginOptionsQuery,
+    ),
+    responses(
+        (status = 200, description = "Login options retrieved successfully", body = LoginOptionsResponse),
+        (status = 401, description = "Unknown realm or client", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_login_options(
+    Path(realm_name): Path<String>,
+    Query(query): Query<LoginOptionsQuery>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response<LoginOptionsResponse>, ApiError> {
+    let options = state
+        .service
+        .get_login_options(GetLoginOptionsInput {
+            realm_name,
+            client_id: query.client_id,
+            ip_address: client_ip(&headers).and_then(|ip| ip.parse().ok()),
+        })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(LoginOptionsResponse { data: options }))
+}
@@ -0,0 +1,53 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    auth_flow::{entities::AuthFlow, ports::AuthFlowService, value_objects::ListAuthFlowsInput},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AuthFlowsResponse {
+    pub data: Vec<AuthFlow>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth-flows",
+    tag = "auth-flow",
+    summary = "List the authentication flows of the realm",
+    description = "Lists the flows configured in the realm: the realm's own and those of the clients overriding it. Flows left to their default are not listed.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Authentication flows retrieved successfully", body = AuthFlowsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_auth_flows(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AuthFlowsResponse>, ApiError> {
+    let flows = state
+        .service
+        .list_auth_flows(identity, ListAuthFlowsInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(AuthFlowsResponse { data: flows }))
+}
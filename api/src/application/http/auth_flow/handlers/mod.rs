@@ -0,0 +1,5 @@
+pub mod delete_auth_flow;
+pub mod get_auth_flow;
+pub mod get_login_options;
+pub mod list_auth_flows;
+pub mod update_auth_flow;
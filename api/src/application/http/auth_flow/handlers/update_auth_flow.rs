@@ -0,0 +1,102 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    auth_flow::{ports::AuthFlowService, value_objects::UpdateAuthFlowInput},
+    authentication::value_objects::Identity,
+};
+use uuid::Uuid;
+
+use crate::application::http::{
+    auth_flow::{handlers::get_auth_flow::AuthFlowResponse, validators::UpdateAuthFlowValidator},
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/auth-flow",
+    tag = "auth-flow",
+    summary = "Update the authentication flow of the realm",
+    description = "Replaces the steps users of the realm go through to sign in. The flow must start with a step identifying the user, such as a password, a passkey or an identity provider.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    request_body = UpdateAuthFlowValidator,
+    responses(
+        (status = 200, description = "Authentication flow updated successfully", body = AuthFlowResponse),
+        (status = 400, description = "Invalid authentication flow", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_auth_flow(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateAuthFlowValidator>,
+) -> Result<Response<AuthFlowResponse>, ApiError> {
+    let flow = state
+        .service
+        .update_auth_flow(
+            identity,
+            UpdateAuthFlowInput {
+                realm_name,
+                client_id: None,
+                steps: payload.steps,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(AuthFlowResponse { data: flow }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/clients/{client_id}/auth-flow",
+    tag = "auth-flow",
+    summary = "Override the authentication flow for a client",
+    description = "Sets the steps users signing in to the client go through, in place of the realm's flow.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("client_id" = Uuid, Path, description = "ID of the client"),
+    ),
+    request_body = UpdateAuthFlowValidator,
+    responses(
+        (status = 200, description = "Authentication flow updated successfully", body = AuthFlowResponse),
+        (status = 400, description = "Invalid authentication flow", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_client_auth_flow(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateAuthFlowValidator>,
+) -> Result<Response<AuthFlowResponse>, ApiError> {
+    let flow = state
+        .service
+        .update_auth_flow(
+            identity,
+            UpdateAuthFlowInput {
+                realm_name,
+                client_id: Some(client_id),
+                steps: payload.steps,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(AuthFlowResponse { data: flow }))
+}
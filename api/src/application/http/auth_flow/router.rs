@@ -0,0 +1,78 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        auth_flow::handlers::{
+            delete_auth_flow::{
+                __path_delete_auth_flow, __path_delete_client_auth_flow, delete_auth_flow,
+                delete_client_auth_flow,
+            },
+            get_auth_flow::{
+                __path_get_auth_flow, __path_get_client_auth_flow, get_auth_flow,
+                get_client_auth_flow,
+            },
+            get_login_options::{__path_get_login_options, get_login_options},
+            list_auth_flows::{__path_list_auth_flows, list_auth_flows},
+            update_auth_flow::{
+                __path_update_auth_flow, __path_update_client_auth_flow, update_auth_flow,
+                update_client_auth_flow,
+            },
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_auth_flow,
+    update_auth_flow,
+    delete_auth_flow,
+    get_client_auth_flow,
+    update_client_auth_flow,
+    delete_client_auth_flow,
+    list_auth_flows,
+    get_login_options,
+))]
+pub struct AuthFlowApiDoc;
+
+pub fn auth_flow_routes(state: AppState) -> Router<AppState> {
+    let admin_routes = Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/auth-flow",
+                state.args.server.root_path
+            ),
+            get(get_auth_flow)
+                .put(update_auth_flow)
+                .delete(delete_auth_flow),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/auth-flows",
+                state.args.server.root_path
+            ),
+            get(list_auth_flows),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/auth-flow",
+                state.args.server.root_path
+            ),
+            get(get_client_auth_flow)
+                .put(update_client_auth_flow)
+                .delete(delete_client_auth_flow),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth));
+
+    let public_routes = Router::new().route(
+        &format!(
+            "{}/realms/{{realm_name}}/login-actions/login-options",
+            state.args.server.root_path
+        ),
+        get(get_login_options),
+    );
+
+    admin_routes.merge(public_routes)
+}
@@ -0,0 +1,11 @@
+use ferriskey_core::domain::auth_flow::entities::AuthFlowStep;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateAuthFlowValidator {
+    /// Top-level steps, passed in order.
+    #[validate(length(min = 1, message = "steps must not be empty"))]
+    pub steps: Vec<AuthFlowStep>,
+}
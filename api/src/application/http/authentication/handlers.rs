@@ -1,5 +1,6 @@
 pub mod auth;
 pub mod authentificate;
+pub mod confirm_email_change;
 pub mod device_authorization;
 pub mod device_verify;
 pub mod get_certs;
@@ -7,7 +8,9 @@ pub mod introspect;
 pub mod logout;
 pub mod openid_configuration;
 pub mod registration;
+pub mod request_email_change;
 pub mod resend_verification_email;
+pub mod revert_email_change;
 pub mod revoke;
 pub mod token;
 pub mod userinfo;
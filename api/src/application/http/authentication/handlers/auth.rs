@@ -44,6 +44,16 @@ fn webapp_login_url(webapp_url: &str, realm_name: &str, login_url: &str) -> Stri
     )
 }
 
+fn with_ui_locales(url: String, ui_locales: Option<&str>) -> String {
+    match ui_locales
+        .map(str::trim)
+        .filter(|locales| !locales.is_empty())
+    {
+        Some(locales) => format!("{url}&ui_locales={}", urlencoding::encode(locales)),
+        None => url,
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema, IntoParams)]
 #[into_params(parameter_in = Query)]
 pub struct AuthRequest {
@@ -60,6 +70,9 @@ pub struct AuthRequest {
     pub scope: Option<String>,
     #[serde(default)]
     pub state: Option<String>,
+    /// Space-separated language tags, forwarded to the login portal.
+    #[serde(default)]
+    pub ui_locales: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema, PartialEq, Eq)]
@@ -172,7 +185,10 @@ pub async fn auth_handler(
         }
     }
 
-    let full_url = webapp_login_url(&state.args.webapp_url, &realm_name, &result.login_url);
+    let full_url = with_ui_locales(
+        webapp_login_url(&state.args.webapp_url, &realm_name, &result.login_url),
+        params.ui_locales.as_deref(),
+    );
 
     let mut session_cookie = Cookie::build((AUTH_SESSION_COOKIE, result.session.id.to_string()))
         .path("/")
@@ -224,7 +240,7 @@ pub async fn auth_handler(
 
 #[cfg(test)]
 mod tests {
-    use super::webapp_login_url;
+    use super::{webapp_login_url, with_ui_locales};
 
     #[test]
     fn joins_webapp_login_url_without_double_slashes() {
@@ -253,4 +269,25 @@ mod tests {
             "https://login.example.com/realms/demo/authentication/login?client_id=test-client"
         );
     }
+
+    #[test]
+    fn forwards_ui_locales_to_login_page() {
+        let full_url = with_ui_locales(
+            webapp_login_url(
+                "https://login.example.com",
+                "demo",
+                "?client_id=test-client",
+            ),
+            Some("fr-CA fr"),
+        );
+
+        assert_eq!(
+            full_url,
+            "https://login.example.com/realms/demo/authentication/login?client_id=test-client&ui_locales=fr-CA%20fr"
+        );
+        assert_eq!(
+            with_ui_locales("https://login.example.com".to_string(), Some("  ")),
+            "https://login.example.com"
+        );
+    }
 }
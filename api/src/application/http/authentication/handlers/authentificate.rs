@@ -1,18 +1,24 @@
 use super::auth::root_scoped_base_url;
+use crate::application::audit::client_ip;
 use crate::application::decoded_token::OptionalToken;
 use crate::application::http::server::api_entities::api_error::{ApiError, ValidateJson};
 use crate::application::http::server::app_state::AppState;
+use crate::application::http::trident::trusted_device::trusted_device_token;
 use crate::application::url::FullUrl;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, StatusCode, header::USER_AGENT};
 use axum::response::IntoResponse;
 use axum_cookie::CookieManager;
 
+use ferriskey_core::domain::auth_flow::entities::Authenticator;
 use ferriskey_core::domain::authentication::entities::{
     AuthenticateInput, AuthenticateOutput, AuthenticationStepStatus,
 };
 use ferriskey_core::domain::authentication::ports::AuthService;
 use ferriskey_core::domain::email_verification::ports::EmailVerificationService;
+use ferriskey_core::domain::security_notification::{
+    ports::SecurityNotificationService, value_objects::RecordSignInInput,
+};
 use ferriskey_core::domain::user::entities::RequiredAction;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
@@ -30,6 +36,7 @@ pub enum AuthenticationStatus {
     Success,
     RequiresActions,
     RequiresOtpChallenge,
+    RequiresChallenge,
     Failed,
 }
 
@@ -40,6 +47,9 @@ pub struct AuthenticateResponse {
     pub required_actions: Option<Vec<RequiredAction>>,
     pub token: Option<String>,
     pub message: Option<String>,
+    /// Authenticators the user can pass next, when a challenge is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub methods: Option<Vec<Authenticator>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -62,6 +72,7 @@ impl From<AuthenticateOutput> for AuthenticateResponse {
                 required_actions: None,
                 token: None,
                 message: Some("Authentication successful".to_string()),
+                methods: None,
             },
             AuthenticationStepStatus::RequiresActions => AuthenticateResponse {
                 status: AuthenticationStatus::RequiresActions,
@@ -73,6 +84,7 @@ impl From<AuthenticateOutput> for AuthenticateResponse {
                 },
                 token: result.temporary_token,
                 message: Some("Additional actions required before login".to_string()),
+                methods: None,
             },
             AuthenticationStepStatus::RequiresOtpChallenge => AuthenticateResponse {
                 status: AuthenticationStatus::RequiresOtpChallenge,
@@ -80,6 +92,15 @@ impl From<AuthenticateOutput> for AuthenticateResponse {
                 required_actions: None,
                 token: result.temporary_token,
                 message: Some("OTP verification required".to_string()),
+                methods: Some(result.methods),
+            },
+            AuthenticationStepStatus::RequiresChallenge => AuthenticateResponse {
+                status: AuthenticationStatus::RequiresChallenge,
+                url: None,
+                required_actions: None,
+                token: result.temporary_token,
+                message: Some("Additional authentication required".to_string()),
+                methods: Some(result.methods),
             },
             AuthenticationStepStatus::Failed => AuthenticateResponse {
                 status: AuthenticationStatus::Failed,
@@ -87,6 +108,7 @@ impl From<AuthenticateOutput> for AuthenticateResponse {
                 required_actions: None,
                 token: None,
                 message: Some("Authentication failed".to_string()),
+                methods: None,
             },
         }
     }
@@ -109,6 +131,7 @@ impl From<AuthenticateOutput> for AuthenticateResponse {
         (status = 500, description = "Internal server error", body = ApiError)
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn authenticate(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
@@ -116,6 +139,7 @@ pub async fn authenticate(
     OptionalToken(optional_token): OptionalToken,
     Query(query): Query<AuthenticateQueryParams>,
     cookie: CookieManager,
+    headers: HeaderMap,
     ValidateJson(payload): ValidateJson<AuthenticateRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let session_code = match cookie.get("FERRISKEY_SESSION") {
@@ -128,6 +152,10 @@ pub async fn authenticate(
         .map_err(|_| ApiError::BadRequest("Invalid session code in cookie".into()))?;
 
     let base_url = root_scoped_base_url(&base_url, &state.args.server.root_path);
+    let user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
 
     let authenticate_params = if let Some(token) = optional_token {
         AuthenticateInput::with_existing_token(
@@ -155,7 +183,10 @@ pub async fn authenticate(
             username,
             password,
         )
-    };
+    }
+    .with_ip_address(client_ip(&headers))
+    .with_user_agent(user_agent.clone())
+    .with_trusted_device_token(trusted_device_token(&cookie));
     let result = state.service.authenticate(authenticate_params).await?;
 
     // If user has VerifyEmail required action, automatically send verification email
@@ -182,6 +213,25 @@ pub async fn authenticate(
         }
     }
 
+    if result.status == AuthenticationStepStatus::Success
+        && let Err(e) = state
+            .service
+            .record_sign_in(RecordSignInInput {
+                realm_name: realm_name.clone(),
+                user_id: result.user_id,
+                ip_address: client_ip(&headers),
+                user_agent,
+            })
+            .await
+    {
+        warn!(
+            user_id = %result.user_id,
+            realm = %realm_name,
+            error = %e,
+            "Failed to record sign-in origin"
+        );
+    }
+
     let response: AuthenticateResponse = result.into();
     Ok((StatusCode::OK, axum::Json(response)).into_response())
 }
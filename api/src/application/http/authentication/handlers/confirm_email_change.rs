@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::email_verification::{
+    entities::EmailChangeResult, ports::EmailVerificationService,
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse, ValidateJson},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ConfirmEmailChangeRequest {
+    /// Token from the confirmation email
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+/// POST /realms/{realm_name}/login-actions/confirm-email-change
+#[utoipa::path(
+    post,
+    path = "/login-actions/confirm-email-change",
+    tag = "auth",
+    summary = "Confirm an email address change",
+    description = "Swap the user's email address for the one the token was sent to. The previous address receives a link to revert the change.",
+    request_body = ConfirmEmailChangeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Email address changed", body = EmailChangeResult),
+        (status = 400, description = "Invalid or expired token, or email already in use", body = ApiErrorResponse),
+        (status = 422, description = "Validation error"),
+    ),
+)]
+pub async fn confirm_email_change_handler(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    ValidateJson(payload): ValidateJson<ConfirmEmailChangeRequest>,
+) -> Result<Response<EmailChangeResult>, ApiError> {
+    // Use webapp_url (frontend URL) as the base for revert links
+    let base_url = state.args.webapp_url.trim_end_matches('/').to_string();
+
+    let result = state
+        .service
+        .email_verification_service
+        .confirm_email_change(realm_name, payload.token, base_url)
+        .await?;
+
+    Ok(Response::OK(result))
+}
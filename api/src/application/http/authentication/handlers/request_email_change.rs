@@ -0,0 +1,70 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::email_verification::ports::EmailVerificationService;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::{
+    decoded_token::OptionalToken,
+    http::server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RequestEmailChangeRequest {
+    /// The new email address
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RequestEmailChangeResponse {
+    pub message: String,
+}
+
+/// POST /realms/{realm_name}/login-actions/change-email
+#[utoipa::path(
+    post,
+    path = "/login-actions/change-email",
+    tag = "auth",
+    summary = "Request an email address change",
+    description = "Send a confirmation link to the new email address. The address of the account only changes once the link is followed. Requires a valid Bearer token in the Authorization header.",
+    request_body = RequestEmailChangeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Confirmation email sent", body = RequestEmailChangeResponse),
+        (status = 400, description = "Email address unchanged or already in use", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized - missing or invalid token", body = ApiErrorResponse),
+        (status = 422, description = "Validation error"),
+        (status = 503, description = "Email delivery unavailable", body = ApiErrorResponse),
+    ),
+)]
+pub async fn request_email_change_handler(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    OptionalToken(token): OptionalToken,
+    ValidateJson(payload): ValidateJson<RequestEmailChangeRequest>,
+) -> Result<Response<RequestEmailChangeResponse>, ApiError> {
+    let token = token.ok_or_else(|| ApiError::Unauthorized("Missing or invalid token".into()))?;
+    let user_id = token.claims.sub;
+
+    // Use webapp_url (frontend URL) as the base for confirmation links
+    let base_url = state.args.webapp_url.trim_end_matches('/').to_string();
+
+    state
+        .service
+        .email_verification_service
+        .request_email_change(user_id, realm_name, payload.email, base_url)
+        .await?;
+
+    Ok(Response::OK(RequestEmailChangeResponse {
+        message: "Confirmation email sent to the new address".to_string(),
+    }))
+}
@@ -0,0 +1,53 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::email_verification::{
+    entities::EmailChangeResult, ports::EmailVerificationService,
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse, ValidateJson},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RevertEmailChangeRequest {
+    /// Token from the email sent to the previous address
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+/// POST /realms/{realm_name}/login-actions/revert-email-change
+#[utoipa::path(
+    post,
+    path = "/login-actions/revert-email-change",
+    tag = "auth",
+    summary = "Revert an email address change",
+    description = "Restore the email address the user had before their last change, using the token sent to that address.",
+    request_body = RevertEmailChangeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Previous email address restored", body = EmailChangeResult),
+        (status = 400, description = "Invalid or expired token, or email already in use", body = ApiErrorResponse),
+        (status = 422, description = "Validation error"),
+    ),
+)]
+pub async fn revert_email_change_handler(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    ValidateJson(payload): ValidateJson<RevertEmailChangeRequest>,
+) -> Result<Response<EmailChangeResult>, ApiError> {
+    let result = state
+        .service
+        .email_verification_service
+        .revert_email_change(realm_name, payload.token)
+        .await?;
+
+    Ok(Response::OK(result))
+}
@@ -8,6 +8,7 @@ use utoipa::OpenApi;
 use super::handlers::{
     auth::{__path_auth_handler, auth_handler},
     authentificate::{__path_authenticate, authenticate},
+    confirm_email_change::{__path_confirm_email_change_handler, confirm_email_change_handler},
     device_authorization::{__path_device_authorization, device_authorization},
     device_verify::{
         __path_device_verification_page, __path_device_verify, device_verification_page,
@@ -18,9 +19,11 @@ use super::handlers::{
     logout::{__path_logout_get, __path_logout_post, logout_get, logout_post},
     openid_configuration::{__path_get_openid_configuration, get_openid_configuration},
     registration::{__path_registration_handler, registration_handler},
+    request_email_change::{__path_request_email_change_handler, request_email_change_handler},
     resend_verification_email::{
         __path_resend_verification_email_handler, resend_verification_email_handler,
     },
+    revert_email_change::{__path_revert_email_change_handler, revert_email_change_handler},
     revoke::{__path_revoke_token, revoke_token},
     token::{__path_exchange_token, exchange_token},
     userinfo::{__path_get_userinfo, get_userinfo},
@@ -46,6 +49,9 @@ use crate::application::{auth::auth, http::server::app_state::AppState};
     registration_handler,
     verify_email_handler,
     resend_verification_email_handler,
+    request_email_change_handler,
+    confirm_email_change_handler,
+    revert_email_change_handler,
     get_userinfo,
 ))]
 pub struct AuthenticationApiDoc;
@@ -113,6 +119,18 @@ pub fn authentication_routes(state: AppState, root_path: &str) -> Router<AppStat
             &format!("{root_path}/realms/{{realm_name}}/login-actions/resend-verification-email"),
             post(resend_verification_email_handler),
         )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/login-actions/change-email"),
+            post(request_email_change_handler),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/login-actions/confirm-email-change"),
+            post(confirm_email_change_handler),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/login-actions/revert-email-change"),
+            post(revert_email_change_handler),
+        )
         .route(
             &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/certs"),
             get(get_certs),
@@ -1,18 +1,24 @@
 use axum::{
     extract::{Path, Query, State},
-    http::{StatusCode, header::LOCATION},
+    http::{
+        HeaderMap, StatusCode,
+        header::{LOCATION, USER_AGENT},
+    },
     response::IntoResponse,
 };
+use axum_cookie::CookieManager;
 
 use ferriskey_core::domain::abyss::identity_provider::broker::{
     BrokerCallbackInput, BrokerService,
 };
 use ferriskey_core::domain::common::entities::app_errors::CoreError;
 
+use crate::application::audit::client_ip;
 use crate::application::http::server::{
     api_entities::api_error::{ApiError, ApiErrorResponse},
     app_state::AppState,
 };
+use crate::application::http::trident::trusted_device::trusted_device_token;
 use crate::application::url::FullUrl;
 
 use super::super::validators::BrokerCallbackQuery;
@@ -34,7 +40,7 @@ use super::super::validators::BrokerCallbackQuery;
         BrokerCallbackQuery
     ),
     responses(
-        (status = 302, description = "Redirect to client with authorization code"),
+        (status = 302, description = "Redirect to client with authorization code, or to the page of the next authenticator when the sign-in asks for one"),
         (status = 400, description = "Bad request - invalid state or expired session", body = ApiErrorResponse),
         (status = 401, description = "Authentication failed at identity provider", body = ApiErrorResponse),
         (status = 502, description = "Error communicating with identity provider", body = ApiErrorResponse),
@@ -45,8 +51,18 @@ pub async fn broker_callback(
     State(state): State<AppState>,
     FullUrl(_, base_url): FullUrl,
     Query(params): Query<BrokerCallbackQuery>,
+    cookie: CookieManager,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, ApiError> {
     let root_scoped_base_url = format!("{base_url}{}", state.args.server.root_path);
+    let frontend_origin = state
+        .args
+        .server
+        .allowed_origins
+        .first()
+        .map(|s| s.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| base_url.clone());
+
     let result = match state
         .service
         .handle_callback(BrokerCallbackInput {
@@ -57,24 +73,29 @@ pub async fn broker_callback(
             error: params.error,
             error_description: params.error_description,
             base_url: root_scoped_base_url.clone(),
+            frontend_url: frontend_origin.clone(),
+            ip_address: client_ip(&headers),
+            user_agent: headers
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            trusted_device_token: trusted_device_token(&cookie),
         })
         .await
     {
         Ok(result) => result,
         Err(CoreError::UserDisabled) => {
-            let frontend_origin = state
-                .args
-                .server
-                .allowed_origins
-                .first()
-                .map(|s| s.trim_end_matches('/').to_string())
-                .unwrap_or_else(|| base_url.clone());
-
             let login_url = format!(
                 "{frontend_origin}/realms/{realm_name}/authentication/login?login_error=User+account+is+disabled"
             );
             return Ok((StatusCode::FOUND, [(LOCATION, login_url)]).into_response());
         }
+        Err(CoreError::Forbidden(_)) => {
+            let login_url = format!(
+                "{frontend_origin}/realms/{realm_name}/authentication/login?login_error=Sign-in+was+blocked"
+            );
+            return Ok((StatusCode::FOUND, [(LOCATION, login_url)]).into_response());
+        }
         Err(e) => return Err(e.into()),
     };
 
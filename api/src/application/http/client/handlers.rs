@@ -1,3 +1,4 @@
+pub mod count_clients;
 pub mod create_client;
 pub mod create_post_logout_redirect_uri;
 pub mod create_redirect_uri;
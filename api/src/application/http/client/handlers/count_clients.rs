@@ -0,0 +1,50 @@
+use crate::application::http::{
+    client::handlers::get_clients::GetClientsQuery,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse},
+            pagination::CountResponse,
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::{entities::GetClientsInput, ports::ClientService};
+
+#[utoipa::path(
+    get,
+    path = "/count",
+    summary = "Count clients in a realm",
+    description = "Counts the clients of a realm matching the same search and filters as the client listing.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetClientsQuery,
+    ),
+    tag = "client",
+    responses(
+        (status = 200, description = "Clients counted successfully", body = CountResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn count_clients(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetClientsQuery>,
+) -> Result<Response<CountResponse>, ApiError> {
+    let filter = query.into_filter(Default::default())?;
+
+    let count = state
+        .service
+        .count_clients(identity, GetClientsInput { realm_name, filter })
+        .await?;
+
+    Ok(Response::OK(CountResponse { count }))
+}
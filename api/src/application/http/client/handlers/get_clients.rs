@@ -1,36 +1,65 @@
 use crate::application::http::server::{
     api_entities::{
         api_error::{ApiError, ApiErrorResponse},
+        pagination::PaginationQuery,
         response::Response,
     },
     app_state::AppState,
 };
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 use ferriskey_core::domain::authentication::value_objects::Identity;
 use ferriskey_core::domain::client::entities::Client;
+use ferriskey_core::domain::client::value_objects::{ClientFilter, ClientSortField};
 use ferriskey_core::domain::client::{entities::GetClientsInput, ports::ClientService};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct ClientsResponse {
     pub data: Vec<Client>,
+    /// Pass back as `cursor` to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetClientsQuery {
+    /// Matched against the client id and name
+    pub search: Option<String>,
+    pub enabled: Option<bool>,
+    pub public_client: Option<bool>,
+    pub sort: Option<ClientSortField>,
+}
+
+impl GetClientsQuery {
+    pub fn into_filter(self, pagination: PaginationQuery) -> Result<ClientFilter, ApiError> {
+        Ok(ClientFilter {
+            search: self.search,
+            enabled: self.enabled,
+            public_client: self.public_client,
+            sort: self.sort.unwrap_or_default(),
+            page: pagination.try_into()?,
+        })
+    }
 }
 
 #[utoipa::path(
     get,
     path = "",
     summary = "Get clients in a realm",
-    description = "Retrieves all clients associated with a specific realm.",
+    description = "Retrieves one page of the clients of a realm, optionally searched and filtered.",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
+        GetClientsQuery,
+        PaginationQuery,
     ),
     tag = "client",
     responses(
         (status = 200, description = "Clients retrieved successfully", body = ClientsResponse),
+        (status = 400, description = "Invalid cursor", body = ApiErrorResponse),
         (status = 401, description = "Realm not found", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 404, description = "Clients not found", body = ApiErrorResponse),
@@ -41,11 +70,18 @@ pub async fn get_clients(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    Query(query): Query<GetClientsQuery>,
+    Query(pagination): Query<PaginationQuery>,
 ) -> Result<Response<ClientsResponse>, ApiError> {
-    let clients = state
+    let filter = query.into_filter(pagination)?;
+
+    let page = state
         .service
-        .get_clients(identity, GetClientsInput { realm_name })
+        .get_clients(identity, GetClientsInput { realm_name, filter })
         .await?;
 
-    Ok(Response::OK(ClientsResponse { data: clients }))
+    Ok(Response::OK(ClientsResponse {
+        data: page.data,
+        next_cursor: page.next_cursor,
+    }))
 }
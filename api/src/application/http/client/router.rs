@@ -5,6 +5,7 @@ use axum::{
 use utoipa::OpenApi;
 
 use super::handlers::{
+    count_clients::{__path_count_clients, count_clients},
     create_client::{__path_create_client, create_client},
     create_post_logout_redirect_uri::{
         __path_create_post_logout_redirect_uri, create_post_logout_redirect_uri,
@@ -36,6 +37,7 @@ use crate::application::{auth::auth, http::server::app_state::AppState};
     paths(
         get_client,
         get_clients,
+        count_clients,
         create_client,
         delete_client,
         create_redirect_uri,
@@ -66,6 +68,13 @@ pub fn client_routes(state: AppState) -> Router<AppState> {
             ),
             get(get_clients),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/count",
+                state.args.server.root_path
+            ),
+            get(count_clients),
+        )
         .route(
             &format!(
                 "{}/realms/{{realm_name}}/clients/{{client_id}}",
@@ -0,0 +1,56 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_outbox::{
+        entities::OutboxEmail, ports::EmailOutboxService, value_objects::GetOutboxEmailInput,
+    },
+};
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    summary = "Get an outbox email",
+    description = "Returns the delivery status, attempt count and last error of a queued email.",
+    path = "/email-outbox/{email_id}",
+    tag = "email-outbox",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("email_id" = Uuid, Path, description = "Outbox email ID"),
+    ),
+    responses(
+        (status = 200, description = "Outbox email retrieved successfully", body = OutboxEmail),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Outbox email not found", body = ApiErrorResponse),
+    )
+)]
+pub async fn get_outbox_email(
+    Path((realm_name, email_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<OutboxEmail>, ApiError> {
+    let email = state
+        .service
+        .get_outbox_email(
+            identity,
+            GetOutboxEmailInput {
+                realm_name,
+                email_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(email))
+}
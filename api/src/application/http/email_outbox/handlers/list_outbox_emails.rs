@@ -0,0 +1,72 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_outbox::{
+        entities::{OutboxEmail, OutboxEmailStatus},
+        ports::EmailOutboxService,
+        value_objects::ListOutboxEmailsInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct OutboxEmailsResponse {
+    pub data: Vec<OutboxEmail>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListOutboxEmailsQuery {
+    /// Only emails with this delivery status
+    pub status: Option<OutboxEmailStatus>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "List outbox emails",
+    description = "Returns the latest emails queued by the realm with their delivery status. Bodies are never returned.",
+    path = "/email-outbox",
+    tag = "email-outbox",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ListOutboxEmailsQuery,
+    ),
+    responses(
+        (status = 200, description = "Outbox emails retrieved successfully", body = OutboxEmailsResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn list_outbox_emails(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<ListOutboxEmailsQuery>,
+) -> Result<Response<OutboxEmailsResponse>, ApiError> {
+    let emails = state
+        .service
+        .list_outbox_emails(
+            identity,
+            ListOutboxEmailsInput {
+                realm_name,
+                status: query.status,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(OutboxEmailsResponse { data: emails }))
+}
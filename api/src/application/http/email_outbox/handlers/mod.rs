@@ -0,0 +1,3 @@
+pub mod get_outbox_email;
+pub mod list_outbox_emails;
+pub mod resend_outbox_email;
@@ -0,0 +1,47 @@
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        email_outbox::handlers::{
+            get_outbox_email::{__path_get_outbox_email, get_outbox_email},
+            list_outbox_emails::{__path_list_outbox_emails, list_outbox_emails},
+            resend_outbox_email::{__path_resend_outbox_email, resend_outbox_email},
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(list_outbox_emails, get_outbox_email, resend_outbox_email))]
+pub struct EmailOutboxApiDoc;
+
+pub fn email_outbox_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-outbox",
+                state.args.server.root_path
+            ),
+            get(list_outbox_emails),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-outbox/{{email_id}}",
+                state.args.server.root_path
+            ),
+            get(get_outbox_email),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-outbox/{{email_id}}/resend",
+                state.args.server.root_path
+            ),
+            post(resend_outbox_email),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,66 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::ports::{DeleteEmailTemplateLocalizationInput, EmailTemplateService},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteEmailTemplateLocalizationResponse {
+    message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{template_id}/localizations/{locale}",
+    tag = "email-template",
+    summary = "Delete email template localization",
+    description = "Deletes the translation of an email template for one locale.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+        ("locale" = String, Path, description = "BCP 47 language tag"),
+    ),
+    responses(
+        (status = 200, description = "Localization deleted successfully", body = DeleteEmailTemplateLocalizationResponse),
+        (status = 404, description = "Email template or localization not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_localization(
+    Path((realm_name, template_id, locale)): Path<(String, Uuid, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteEmailTemplateLocalizationResponse>, ApiError> {
+    state
+        .service
+        .delete_template_localization(
+            identity,
+            DeleteEmailTemplateLocalizationInput {
+                realm_name,
+                template_id,
+                locale,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DeleteEmailTemplateLocalizationResponse {
+        message: "Email template localization deleted successfully".to_string(),
+    }))
+}
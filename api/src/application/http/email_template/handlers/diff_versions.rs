@@ -0,0 +1,80 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::{
+        entities::EmailTemplateVersionDiff,
+        ports::{DiffEmailTemplateVersionsInput, EmailTemplateService},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DiffVersionsQuery {
+    /// Version to compare from
+    pub from: i32,
+    /// Version to compare to
+    pub to: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DiffEmailTemplateVersionsResponse {
+    pub data: EmailTemplateVersionDiff,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{template_id}/diff",
+    tag = "email-template",
+    summary = "Diff email template versions",
+    description = "Lists the differences between the builder structures of two versions, each located by a JSON pointer.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+        DiffVersionsQuery,
+    ),
+    responses(
+        (status = 200, description = "Diff computed successfully", body = DiffEmailTemplateVersionsResponse),
+        (status = 404, description = "Email template or version not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn diff_versions(
+    Path((realm_name, template_id)): Path<(String, Uuid)>,
+    Query(query): Query<DiffVersionsQuery>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DiffEmailTemplateVersionsResponse>, ApiError> {
+    let diff = state
+        .service
+        .diff_template_versions(
+            identity,
+            DiffEmailTemplateVersionsInput {
+                realm_name,
+                template_id,
+                from_version: query.from,
+                to_version: query.to,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DiffEmailTemplateVersionsResponse {
+        data: diff,
+    }))
+}
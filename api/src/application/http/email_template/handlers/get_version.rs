@@ -0,0 +1,69 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::{
+        entities::EmailTemplateVersion,
+        ports::{EmailTemplateService, GetEmailTemplateVersionInput},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetEmailTemplateVersionResponse {
+    pub data: EmailTemplateVersion,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{template_id}/versions/{version}",
+    tag = "email-template",
+    summary = "Get email template version",
+    description = "Retrieves one saved version of an email template.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+        ("version" = i32, Path, description = "Version number"),
+    ),
+    responses(
+        (status = 200, description = "Version retrieved successfully", body = GetEmailTemplateVersionResponse),
+        (status = 404, description = "Email template or version not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_version(
+    Path((realm_name, template_id, version)): Path<(String, Uuid, i32)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetEmailTemplateVersionResponse>, ApiError> {
+    let version = state
+        .service
+        .get_template_version(
+            identity,
+            GetEmailTemplateVersionInput {
+                realm_name,
+                template_id,
+                version,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(GetEmailTemplateVersionResponse {
+        data: version,
+    }))
+}
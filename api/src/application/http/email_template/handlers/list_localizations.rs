@@ -0,0 +1,67 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::{
+        entities::EmailTemplateLocalization,
+        ports::{EmailTemplateService, GetEmailTemplateInput},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct EmailTemplateLocalizationsResponse {
+    pub data: Vec<EmailTemplateLocalization>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{template_id}/localizations",
+    tag = "email-template",
+    summary = "List email template localizations",
+    description = "Returns the translations of an email template, one per locale.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+    ),
+    responses(
+        (status = 200, description = "Localizations retrieved successfully", body = EmailTemplateLocalizationsResponse),
+        (status = 404, description = "Email template not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_localizations(
+    Path((realm_name, template_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<EmailTemplateLocalizationsResponse>, ApiError> {
+    let localizations = state
+        .service
+        .list_template_localizations(
+            identity,
+            GetEmailTemplateInput {
+                realm_name,
+                template_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(EmailTemplateLocalizationsResponse {
+        data: localizations,
+    }))
+}
@@ -0,0 +1,67 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::{
+        entities::EmailTemplateVersion,
+        ports::{EmailTemplateService, GetEmailTemplateInput},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct EmailTemplateVersionsResponse {
+    pub data: Vec<EmailTemplateVersion>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{template_id}/versions",
+    tag = "email-template",
+    summary = "List email template versions",
+    description = "Returns every saved version of an email template, newest first. A version is recorded each time the template is created, updated or rolled back.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+    ),
+    responses(
+        (status = 200, description = "Versions retrieved successfully", body = EmailTemplateVersionsResponse),
+        (status = 404, description = "Email template not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_versions(
+    Path((realm_name, template_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<EmailTemplateVersionsResponse>, ApiError> {
+    let versions = state
+        .service
+        .list_template_versions(
+            identity,
+            GetEmailTemplateInput {
+                realm_name,
+                template_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(EmailTemplateVersionsResponse {
+        data: versions,
+    }))
+}
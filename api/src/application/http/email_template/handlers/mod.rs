@@ -1,6 +1,15 @@
 pub mod create_template;
+pub mod delete_localization;
 pub mod delete_template;
+pub mod diff_versions;
 pub mod fetch_templates;
 pub mod get_template;
 pub mod get_variables;
+pub mod get_version;
+pub mod list_localizations;
+pub mod list_versions;
+pub mod preview_template;
+pub mod rollback_template;
+pub mod send_test_email;
 pub mod update_template;
+pub mod upsert_localization;
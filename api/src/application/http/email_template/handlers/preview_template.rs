@@ -0,0 +1,74 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::{
+        entities::EmailTemplatePreview,
+        ports::{EmailTemplateService, PreviewEmailTemplateInput},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::{
+    email_template::validators::PreviewEmailTemplateValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct PreviewEmailTemplateResponse {
+    pub data: EmailTemplatePreview,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{template_id}/preview",
+    tag = "email-template",
+    summary = "Preview email template",
+    description = "Renders an email template to HTML as it would be sent. Variables are filled with sample values, or with the data of `user_id` when given. Pass `structure` to preview unsaved changes.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+    ),
+    request_body = PreviewEmailTemplateValidator,
+    responses(
+        (status = 200, description = "Email template rendered successfully", body = PreviewEmailTemplateResponse),
+        (status = 400, description = "Invalid locale or structure", body = ApiErrorResponse),
+        (status = 404, description = "Email template or user not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn preview_template(
+    Path((realm_name, template_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<PreviewEmailTemplateValidator>,
+) -> Result<Response<PreviewEmailTemplateResponse>, ApiError> {
+    let preview = state
+        .service
+        .preview_template(
+            identity,
+            PreviewEmailTemplateInput {
+                realm_name,
+                template_id,
+                structure: payload.structure,
+                user_id: payload.user_id,
+                locale: payload.locale,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(PreviewEmailTemplateResponse { data: preview }))
+}
@@ -0,0 +1,69 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::{
+        entities::EmailTemplate,
+        ports::{EmailTemplateService, GetEmailTemplateVersionInput},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RollbackEmailTemplateResponse {
+    pub data: EmailTemplate,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{template_id}/versions/{version}/rollback",
+    tag = "email-template",
+    summary = "Roll back email template",
+    description = "Restores the name and structure of an earlier version. The restored content is saved as a new version, so the history is kept.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+        ("version" = i32, Path, description = "Version to restore"),
+    ),
+    responses(
+        (status = 200, description = "Email template rolled back successfully", body = RollbackEmailTemplateResponse),
+        (status = 404, description = "Email template or version not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn rollback_template(
+    Path((realm_name, template_id, version)): Path<(String, Uuid, i32)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RollbackEmailTemplateResponse>, ApiError> {
+    let template = state
+        .service
+        .rollback_template(
+            identity,
+            GetEmailTemplateVersionInput {
+                realm_name,
+                template_id,
+                version,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(RollbackEmailTemplateResponse {
+        data: template,
+    }))
+}
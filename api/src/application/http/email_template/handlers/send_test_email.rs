@@ -0,0 +1,73 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::ports::{EmailTemplateService, SendTestEmailInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::{
+    email_template::validators::SendTestEmailValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct SendTestEmailResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{template_id}/test",
+    tag = "email-template",
+    summary = "Send test email",
+    description = "Renders the saved email template like the preview endpoint and sends it to `recipient` through the realm's SMTP configuration. The subject is prefixed with `[Test]`.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+    ),
+    request_body = SendTestEmailValidator,
+    responses(
+        (status = 200, description = "Test email sent successfully", body = SendTestEmailResponse),
+        (status = 400, description = "Invalid recipient or locale", body = ApiErrorResponse),
+        (status = 404, description = "Email template or user not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "SMTP not configured or delivery failed", body = ApiErrorResponse),
+    ),
+)]
+pub async fn send_test_email(
+    Path((realm_name, template_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<SendTestEmailValidator>,
+) -> Result<Response<SendTestEmailResponse>, ApiError> {
+    state
+        .service
+        .send_test_email(
+            identity,
+            SendTestEmailInput {
+                realm_name,
+                template_id,
+                recipient: payload.recipient,
+                user_id: payload.user_id,
+                locale: payload.locale,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(SendTestEmailResponse {
+        message: "Test email sent successfully".to_string(),
+    }))
+}
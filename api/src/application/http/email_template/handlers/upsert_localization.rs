@@ -0,0 +1,76 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email_template::{
+        entities::EmailTemplateLocalization,
+        ports::{EmailTemplateService, UpsertEmailTemplateLocalizationInput},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::{
+    email_template::validators::UpsertEmailTemplateLocalizationValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct UpsertEmailTemplateLocalizationResponse {
+    pub data: EmailTemplateLocalization,
+}
+
+#[utoipa::path(
+    put,
+    path = "/{template_id}/localizations/{locale}",
+    tag = "email-template",
+    summary = "Upsert email template localization",
+    description = "Creates or replaces the translation of an email template for one locale. Emails follow the recipient's locale and fall back to the base template.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("template_id" = Uuid, Path, description = "Email template ID"),
+        ("locale" = String, Path, description = "BCP 47 language tag"),
+    ),
+    request_body = UpsertEmailTemplateLocalizationValidator,
+    responses(
+        (status = 200, description = "Localization saved successfully", body = UpsertEmailTemplateLocalizationResponse),
+        (status = 400, description = "Invalid locale or structure", body = ApiErrorResponse),
+        (status = 404, description = "Email template not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn upsert_localization(
+    Path((realm_name, template_id, locale)): Path<(String, Uuid, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpsertEmailTemplateLocalizationValidator>,
+) -> Result<Response<UpsertEmailTemplateLocalizationResponse>, ApiError> {
+    let localization = state
+        .service
+        .upsert_template_localization(
+            identity,
+            UpsertEmailTemplateLocalizationInput {
+                realm_name,
+                template_id,
+                locale,
+                structure: payload.structure,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(UpsertEmailTemplateLocalizationResponse {
+        data: localization,
+    }))
+}
@@ -1,11 +1,23 @@
 use super::handlers::create_template::{__path_create_template, create_template};
+use super::handlers::delete_localization::{__path_delete_localization, delete_localization};
 use super::handlers::delete_template::{__path_delete_template, delete_template};
+use super::handlers::diff_versions::{__path_diff_versions, diff_versions};
 use super::handlers::fetch_templates::{__path_fetch_templates, fetch_templates};
 use super::handlers::get_template::{__path_get_template, get_template};
 use super::handlers::get_variables::{__path_get_variables, get_variables};
+use super::handlers::get_version::{__path_get_version, get_version};
+use super::handlers::list_localizations::{__path_list_localizations, list_localizations};
+use super::handlers::list_versions::{__path_list_versions, list_versions};
+use super::handlers::preview_template::{__path_preview_template, preview_template};
+use super::handlers::rollback_template::{__path_rollback_template, rollback_template};
+use super::handlers::send_test_email::{__path_send_test_email, send_test_email};
 use super::handlers::update_template::{__path_update_template, update_template};
+use super::handlers::upsert_localization::{__path_upsert_localization, upsert_localization};
 use crate::application::{auth::auth, http::server::app_state::AppState};
-use axum::{Router, middleware, routing::get};
+use axum::{
+    Router, middleware,
+    routing::{get, post, put},
+};
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
@@ -15,6 +27,15 @@ use utoipa::OpenApi;
     create_template,
     update_template,
     delete_template,
+    list_localizations,
+    upsert_localization,
+    delete_localization,
+    list_versions,
+    get_version,
+    rollback_template,
+    diff_versions,
+    preview_template,
+    send_test_email,
 ))]
 pub struct EmailTemplateApiDoc;
 
@@ -40,6 +61,62 @@ pub fn email_template_routes(state: AppState) -> Router<AppState> {
                 .put(update_template)
                 .delete(delete_template),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/localizations",
+                state.args.server.root_path
+            ),
+            get(list_localizations),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/localizations/{{locale}}",
+                state.args.server.root_path
+            ),
+            put(upsert_localization).delete(delete_localization),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/versions",
+                state.args.server.root_path
+            ),
+            get(list_versions),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/versions/{{version}}",
+                state.args.server.root_path
+            ),
+            get(get_version),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/versions/{{version}}/rollback",
+                state.args.server.root_path
+            ),
+            post(rollback_template),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/diff",
+                state.args.server.root_path
+            ),
+            get(diff_versions),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/preview",
+                state.args.server.root_path
+            ),
+            post(preview_template),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/email-templates/{{template_id}}/test",
+                state.args.server.root_path
+            ),
+            post(send_test_email),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth));
 
     let variables_routes = Router::new().route(
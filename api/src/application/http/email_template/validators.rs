@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use uuid::Uuid;
 use validator::Validate;
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -20,3 +21,32 @@ pub struct UpdateEmailTemplateValidator {
 
     pub structure: serde_json::Value,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpsertEmailTemplateLocalizationValidator {
+    pub structure: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PreviewEmailTemplateValidator {
+    /// Unsaved structure to render instead of the stored template
+    pub structure: Option<serde_json::Value>,
+
+    /// User whose data fills the `user.*` variables
+    pub user_id: Option<Uuid>,
+
+    #[validate(length(min = 1, message = "locale must not be empty"))]
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SendTestEmailValidator {
+    #[validate(email(message = "recipient must be a valid email address"))]
+    pub recipient: String,
+
+    /// User whose data fills the `user.*` variables
+    pub user_id: Option<Uuid>,
+
+    #[validate(length(min = 1, message = "locale must not be empty"))]
+    pub locale: Option<String>,
+}
@@ -170,6 +170,7 @@ impl From<CoreError> for ApiError {
                 Self::BadRequest("Invalid provider URL".into())
             },
             CoreError::External(msg) => Self::ServiceUnavailable(format!("External service error: {}", msg).into()),
+            CoreError::EmailRejected(msg) => Self::ServiceUnavailable(format!("Email rejected: {}", msg).into()),
             CoreError::Database(msg) => Self::InternalServerError(format!("Database error: {}", msg).into()),
             CoreError::Configuration(msg) => Self::InternalServerError(format!("Configuration error: {}", msg).into()),
             CoreError::FederationAuthenticationFailed(msg) => Self::Unauthorized(format!("Federation authentication error: {}", msg).into()),
@@ -241,6 +242,9 @@ impl From<CoreError> for ApiError {
             CoreError::EmailVerificationTemplateNotConfigured => {
                 Self::BadRequest("Email verification template is not configured for this realm".into())
             }
+            CoreError::InvalidLocalization(msg) => {
+                Self::BadRequest(format!("Invalid localization: {msg}").into())
+            }
             CoreError::PortalThemePageInvalid(details) => {
                 Self::validation_error(details, "tree")
             }
@@ -272,6 +276,26 @@ impl From<CoreError> for ApiError {
             CoreError::PortalLayoutInUse => Self::BadRequest(
                 "Portal layout is referenced by one or more themes and cannot be deleted".into(),
             ),
+            CoreError::InvalidCursor => Self::BadRequest("Invalid pagination cursor".into()),
+            CoreError::PasswordPolicyViolation(msg) => {
+                Self::BadRequest(format!("Password does not satisfy the realm policy: {msg}").into())
+            }
+            CoreError::AccountDeletionNotFound => {
+                Self::NotFound("No account deletion is pending for this user".into())
+            }
+            CoreError::InvalidOneTimeCode => {
+                Self::Unauthorized("Invalid or expired one-time code".into())
+            }
+            CoreError::InvalidPhoneNumber => {
+                Self::BadRequest("Phone number must be in international format, e.g. +33612345678".into())
+            }
+            CoreError::TooManyRequests(msg) => Self::TooManyRequests(msg.into()),
+            CoreError::InvalidAuthFlow(msg) => {
+                Self::BadRequest(format!("Invalid authentication flow: {msg}").into())
+            }
+            CoreError::InvalidRiskPolicy(msg) => {
+                Self::BadRequest(format!("Invalid risk policy: {msg}").into())
+            }
         }
     }
 }
@@ -0,0 +1,49 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    housekeeping::{
+        entities::HousekeepingJobStatus, ports::HousekeepingService,
+        value_objects::GetHousekeepingStatusInput,
+    },
+};
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    summary = "Get housekeeping job status",
+    description = "Lists the background housekeeping jobs of this instance with their schedule, last run and counters. Only available on the master realm.",
+    path = "/housekeeping/jobs",
+    tag = "housekeeping",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Housekeeping status retrieved successfully", body = Vec<HousekeepingJobStatus>),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn get_housekeeping_status(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<HousekeepingJobStatus>>, ApiError> {
+    let status = state
+        .service
+        .get_housekeeping_status(identity, GetHousekeepingStatusInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(status))
+}
@@ -0,0 +1,48 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    housekeeping::{
+        entities::HousekeepingRetentionPolicy, ports::HousekeepingService,
+        value_objects::ListHousekeepingRetentionInput,
+    },
+};
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    summary = "List housekeeping retention overrides",
+    path = "/housekeeping/retention",
+    tag = "housekeeping",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Retention overrides retrieved successfully", body = Vec<HousekeepingRetentionPolicy>),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn list_housekeeping_retention(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<HousekeepingRetentionPolicy>>, ApiError> {
+    let policies = state
+        .service
+        .list_housekeeping_retention(identity, ListHousekeepingRetentionInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(policies))
+}
@@ -0,0 +1,3 @@
+pub mod get_housekeeping_status;
+pub mod list_housekeeping_retention;
+pub mod update_housekeeping_retention;
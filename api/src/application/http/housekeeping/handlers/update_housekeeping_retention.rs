@@ -0,0 +1,65 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    housekeeping::{
+        entities::HousekeepingRetentionPolicy, ports::HousekeepingService,
+        value_objects::UpdateHousekeepingRetentionInput,
+    },
+};
+
+use crate::application::http::{
+    housekeeping::validators::{UpdateHousekeepingRetentionValidator, parse_job},
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    summary = "Update housekeeping retention for a job",
+    description = "Overrides how long expired rows of a housekeeping job are kept for this realm before being purged. A null value keeps them forever.",
+    path = "/housekeeping/retention/{job}",
+    tag = "housekeeping",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("job" = String, Path, description = "Housekeeping job, e.g. refresh_tokens"),
+    ),
+    request_body = UpdateHousekeepingRetentionValidator,
+    responses(
+        (status = 200, description = "Retention override updated successfully", body = HousekeepingRetentionPolicy),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 422, description = "Unknown job or invalid retention window"),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn update_housekeeping_retention(
+    Path((realm_name, job)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateHousekeepingRetentionValidator>,
+) -> Result<Response<HousekeepingRetentionPolicy>, ApiError> {
+    let job = parse_job(&job)?;
+
+    let policy = state
+        .service
+        .update_housekeeping_retention(
+            identity,
+            UpdateHousekeepingRetentionInput {
+                realm_name,
+                job,
+                retention_hours: payload.retention_hours,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(policy))
+}
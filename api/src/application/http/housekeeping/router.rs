@@ -0,0 +1,55 @@
+use axum::{
+    Router, middleware,
+    routing::{get, put},
+};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        housekeeping::handlers::{
+            get_housekeeping_status::{__path_get_housekeeping_status, get_housekeeping_status},
+            list_housekeeping_retention::{
+                __path_list_housekeeping_retention, list_housekeeping_retention,
+            },
+            update_housekeeping_retention::{
+                __path_update_housekeeping_retention, update_housekeeping_retention,
+            },
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_housekeeping_status,
+    list_housekeeping_retention,
+    update_housekeeping_retention
+))]
+pub struct HousekeepingApiDoc;
+
+pub fn housekeeping_router(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/housekeeping/jobs",
+                state.args.server.root_path
+            ),
+            get(get_housekeeping_status),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/housekeeping/retention",
+                state.args.server.root_path
+            ),
+            get(list_housekeeping_retention),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/housekeeping/retention/{{job}}",
+                state.args.server.root_path
+            ),
+            put(update_housekeeping_retention),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,24 @@
+use ferriskey_core::domain::housekeeping::entities::HousekeepingJob;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::http::server::api_entities::api_error::ApiError;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateHousekeepingRetentionValidator {
+    #[validate(range(
+        min = 1,
+        max = 876000,
+        message = "retention_hours must be between 1 and 876000"
+    ))]
+    #[serde(default)]
+    pub retention_hours: Option<u32>,
+}
+
+/// Parses the `job` path segment.
+pub fn parse_job(value: &str) -> Result<HousekeepingJob, ApiError> {
+    value
+        .parse()
+        .map_err(|e: String| ApiError::validation_error(e, "job"))
+}
@@ -0,0 +1,57 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    localization::{ports::LocalizationService, value_objects::DeleteMessageBundleInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteMessageBundleResponse {
+    message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/localization/messages/{locale}",
+    tag = "localization",
+    summary = "Delete message bundle",
+    description = "Deletes the messages of one locale. Lookups fall back to the next locale of the chain.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("locale" = String, Path, description = "BCP 47 language tag"),
+    ),
+    responses(
+        (status = 200, description = "Message bundle deleted successfully", body = DeleteMessageBundleResponse),
+        (status = 404, description = "Message bundle not found", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn delete_message_bundle(
+    Path((realm_name, locale)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteMessageBundleResponse>, ApiError> {
+    state
+        .service
+        .delete_message_bundle(identity, DeleteMessageBundleInput { realm_name, locale })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DeleteMessageBundleResponse {
+        message: "Message bundle deleted successfully".to_string(),
+    }))
+}
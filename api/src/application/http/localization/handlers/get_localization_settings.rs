@@ -0,0 +1,58 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    localization::{
+        entities::RealmLocalization, ports::LocalizationService,
+        value_objects::GetLocalizationSettingsInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct LocalizationSettingsResponse {
+    pub data: RealmLocalization,
+}
+
+#[utoipa::path(
+    get,
+    path = "/localization",
+    tag = "localization",
+    summary = "Get localization settings",
+    description = "Returns the realm's default and supported locales. Realms that never configured localization report English only.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Localization settings retrieved successfully", body = LocalizationSettingsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_localization_settings(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<LocalizationSettingsResponse>, ApiError> {
+    let settings = state
+        .service
+        .get_localization_settings(identity, GetLocalizationSettingsInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(LocalizationSettingsResponse {
+        data: settings,
+    }))
+}
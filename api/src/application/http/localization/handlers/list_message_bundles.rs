@@ -0,0 +1,55 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    localization::{
+        entities::MessageBundle, ports::LocalizationService, value_objects::ListMessageBundlesInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct MessageBundlesResponse {
+    pub data: Vec<MessageBundle>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/localization/messages",
+    tag = "localization",
+    summary = "List message bundles",
+    description = "Returns the realm's message bundles, one per locale.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Message bundles retrieved successfully", body = MessageBundlesResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_message_bundles(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<MessageBundlesResponse>, ApiError> {
+    let bundles = state
+        .service
+        .list_message_bundles(identity, ListMessageBundlesInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(MessageBundlesResponse { data: bundles }))
+}
@@ -0,0 +1,6 @@
+pub mod delete_message_bundle;
+pub mod get_localization_settings;
+pub mod list_message_bundles;
+pub mod put_message_bundle;
+pub mod resolve_messages;
+pub mod update_localization_settings;
@@ -0,0 +1,69 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    localization::{
+        entities::MessageBundle, ports::LocalizationService, value_objects::PutMessageBundleInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::{
+    localization::validators::PutMessageBundleValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct MessageBundleResponse {
+    pub data: MessageBundle,
+}
+
+#[utoipa::path(
+    put,
+    path = "/localization/messages/{locale}",
+    tag = "localization",
+    summary = "Put message bundle",
+    description = "Replaces the messages of one locale. Templates and portal pages reference them as `{{msg.<key>}}`.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        ("locale" = String, Path, description = "BCP 47 language tag"),
+    ),
+    request_body = PutMessageBundleValidator,
+    responses(
+        (status = 200, description = "Message bundle saved successfully", body = MessageBundleResponse),
+        (status = 400, description = "Invalid locale or messages", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn put_message_bundle(
+    Path((realm_name, locale)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<PutMessageBundleValidator>,
+) -> Result<Response<MessageBundleResponse>, ApiError> {
+    let bundle = state
+        .service
+        .put_message_bundle(
+            identity,
+            PutMessageBundleInput {
+                realm_name,
+                locale,
+                messages: payload.messages,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(MessageBundleResponse { data: bundle }))
+}
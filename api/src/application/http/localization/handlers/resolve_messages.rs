@@ -0,0 +1,79 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, header::ACCEPT_LANGUAGE},
+};
+use ferriskey_core::domain::localization::{
+    entities::LocalizedMessages,
+    ports::LocalizationService,
+    value_objects::{LocaleHints, ResolveMessagesInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LocaleQuery {
+    /// Space-separated language tags, most preferred first (OIDC `ui_locales`)
+    pub ui_locales: Option<String>,
+}
+
+impl LocaleQuery {
+    /// Hints from the query, then the `Accept-Language` header.
+    pub fn hints(self, headers: &HeaderMap) -> LocaleHints {
+        LocaleHints {
+            ui_locales: self.ui_locales,
+            user_locale: None,
+            accept_language: headers
+                .get(ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ResolvedMessagesResponse {
+    pub data: LocalizedMessages,
+}
+
+#[utoipa::path(
+    get,
+    path = "/messages",
+    tag = "portal-theme-public",
+    summary = "Resolve portal messages",
+    description = "Public endpoint used by the portal renderer. Returns the realm's messages for the locale picked from `ui_locales`, then `Accept-Language`, then the realm default.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+        LocaleQuery,
+    ),
+    responses(
+        (status = 200, description = "Messages resolved successfully", body = ResolvedMessagesResponse),
+        (status = 401, description = "Unknown realm", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn resolve_messages(
+    Path(realm_name): Path<String>,
+    Query(query): Query<LocaleQuery>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response<ResolvedMessagesResponse>, ApiError> {
+    let messages = state
+        .service
+        .resolve_messages(ResolveMessagesInput {
+            realm_name,
+            hints: query.hints(&headers),
+        })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(ResolvedMessagesResponse { data: messages }))
+}
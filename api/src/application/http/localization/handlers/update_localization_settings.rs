@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    localization::{ports::LocalizationService, value_objects::UpdateLocalizationSettingsInput},
+};
+
+use crate::application::http::{
+    localization::{
+        handlers::get_localization_settings::LocalizationSettingsResponse,
+        validators::UpdateLocalizationSettingsValidator,
+    },
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/localization",
+    tag = "localization",
+    summary = "Update localization settings",
+    description = "Sets the realm's default and supported locales. The default locale is always supported.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    request_body = UpdateLocalizationSettingsValidator,
+    responses(
+        (status = 200, description = "Localization settings updated successfully", body = LocalizationSettingsResponse),
+        (status = 400, description = "Invalid locale", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_localization_settings(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateLocalizationSettingsValidator>,
+) -> Result<Response<LocalizationSettingsResponse>, ApiError> {
+    let settings = state
+        .service
+        .update_localization_settings(
+            identity,
+            UpdateLocalizationSettingsInput {
+                realm_name,
+                default_locale: payload.default_locale,
+                supported_locales: payload.supported_locales,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(LocalizationSettingsResponse {
+        data: settings,
+    }))
+}
@@ -0,0 +1,74 @@
+use axum::{
+    Router, middleware,
+    routing::{get, put},
+};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        localization::handlers::{
+            delete_message_bundle::{__path_delete_message_bundle, delete_message_bundle},
+            get_localization_settings::{
+                __path_get_localization_settings, get_localization_settings,
+            },
+            list_message_bundles::{__path_list_message_bundles, list_message_bundles},
+            put_message_bundle::{__path_put_message_bundle, put_message_bundle},
+            resolve_messages::{__path_resolve_messages, resolve_messages},
+            update_localization_settings::{
+                __path_update_localization_settings, update_localization_settings,
+            },
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_localization_settings,
+    update_localization_settings,
+    list_message_bundles,
+    put_message_bundle,
+    delete_message_bundle,
+))]
+pub struct LocalizationApiDoc;
+
+#[derive(OpenApi)]
+#[openapi(paths(resolve_messages))]
+pub struct LocalizationPublicApiDoc;
+
+pub fn localization_routes(state: AppState) -> Router<AppState> {
+    let admin_routes = Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/localization",
+                state.args.server.root_path
+            ),
+            get(get_localization_settings).put(update_localization_settings),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/localization/messages",
+                state.args.server.root_path
+            ),
+            get(list_message_bundles),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/localization/messages/{{locale}}",
+                state.args.server.root_path
+            ),
+            put(put_message_bundle).delete(delete_message_bundle),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth));
+
+    let public_routes = Router::new().route(
+        &format!(
+            "{}/realms/{{realm_name}}/portal/messages",
+            state.args.server.root_path
+        ),
+        get(resolve_messages),
+    );
+
+    admin_routes.merge(public_routes)
+}
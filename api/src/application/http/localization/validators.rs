@@ -0,0 +1,19 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateLocalizationSettingsValidator {
+    #[validate(length(min = 1, message = "default_locale is required"))]
+    pub default_locale: String,
+
+    #[serde(default)]
+    pub supported_locales: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct PutMessageBundleValidator {
+    pub messages: BTreeMap<String, String>,
+}
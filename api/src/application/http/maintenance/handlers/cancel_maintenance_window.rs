@@ -0,0 +1,42 @@
+use crate::application::http::server::api_entities::api_error::{ApiError, ApiErrorResponse};
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::maintenance::entities::MaintenanceWindow;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/settings/maintenance/windows/{window_id}",
+    tag = "maintenance",
+    summary = "Cancel a maintenance window",
+    description = "Cancels a scheduled or running maintenance window. Cancelling a running window ends its maintenance immediately.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("window_id" = Uuid, Path, description = "Maintenance window ID"),
+    ),
+    responses(
+        (status = 200, description = "Maintenance window cancelled", body = MaintenanceWindow),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Window not found or already over", body = ApiErrorResponse),
+    ),
+)]
+pub async fn cancel_maintenance_window(
+    Path((realm_name, window_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<MaintenanceWindow>, ApiError> {
+    let window = state
+        .service
+        .cancel_maintenance_window(identity, realm_name, window_id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(window))
+}
@@ -0,0 +1,48 @@
+use crate::application::http::server::api_entities::api_error::{ApiError, ApiErrorResponse};
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::maintenance::entities::MaintenanceWindow;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetMaintenanceWindowsResponse {
+    pub data: Vec<MaintenanceWindow>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/settings/maintenance/windows",
+    tag = "maintenance",
+    summary = "List maintenance windows",
+    description = "Returns the scheduled, running and past maintenance windows of the realm, latest first.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Maintenance windows retrieved", body = GetMaintenanceWindowsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_maintenance_windows(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetMaintenanceWindowsResponse>, ApiError> {
+    let windows = state
+        .service
+        .list_maintenance_windows(identity, realm_name)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(GetMaintenanceWindowsResponse {
+        data: windows,
+    }))
+}
@@ -0,0 +1,39 @@
+use crate::application::http::server::api_entities::api_error::{ApiError, ApiErrorResponse};
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::maintenance::entities::RealmMaintenance;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+
+#[utoipa::path(
+    get,
+    path = "/settings/maintenance",
+    tag = "maintenance",
+    summary = "Get realm maintenance mode",
+    description = "Returns whether the whole realm is under maintenance, with its reason and session strategy.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Realm maintenance retrieved", body = RealmMaintenance),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_realm_maintenance(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RealmMaintenance>, ApiError> {
+    let maintenance = state
+        .service
+        .get_realm_maintenance(identity, realm_name)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(maintenance))
+}
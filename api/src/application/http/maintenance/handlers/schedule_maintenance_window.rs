@@ -0,0 +1,57 @@
+use crate::application::http::maintenance::validators::ScheduleMaintenanceWindowValidator;
+use crate::application::http::server::api_entities::api_error::{
+    ApiError, ApiErrorResponse, ValidateJson,
+};
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::maintenance::entities::MaintenanceWindow;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+use ferriskey_core::domain::maintenance::value_objects::ScheduleMaintenanceWindowRequest;
+
+#[utoipa::path(
+    post,
+    path = "/settings/maintenance/windows",
+    tag = "maintenance",
+    summary = "Schedule a maintenance window",
+    description = "Plans maintenance for a client, or the whole realm when no client is given. Maintenance is enabled at starts_at and disabled at ends_at, firing the usual maintenance webhooks.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body = ScheduleMaintenanceWindowValidator,
+    responses(
+        (status = 201, description = "Maintenance window scheduled", body = MaintenanceWindow),
+        (status = 400, description = "The window ends before it starts or is already over", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "Client not found", body = ApiErrorResponse),
+    ),
+)]
+pub async fn schedule_maintenance_window(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ScheduleMaintenanceWindowValidator>,
+) -> Result<Response<MaintenanceWindow>, ApiError> {
+    let window = state
+        .service
+        .schedule_maintenance_window(
+            identity,
+            realm_name,
+            ScheduleMaintenanceWindowRequest {
+                client_id: payload.client_id,
+                reason: payload.reason,
+                session_strategy: payload.session_strategy,
+                starts_at: payload.starts_at,
+                ends_at: payload.ends_at,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Created(window))
+}
@@ -0,0 +1,53 @@
+use crate::application::http::maintenance::validators::ToggleMaintenanceValidator;
+use crate::application::http::server::api_entities::api_error::{
+    ApiError, ApiErrorResponse, ValidateJson,
+};
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::maintenance::entities::RealmMaintenance;
+use ferriskey_core::domain::maintenance::ports::MaintenanceService;
+use ferriskey_core::domain::maintenance::value_objects::ToggleMaintenanceRequest;
+
+#[utoipa::path(
+    put,
+    path = "/settings/maintenance",
+    tag = "maintenance",
+    summary = "Toggle realm maintenance mode",
+    description = "Enables or disables maintenance for every client of the realm. When enabled, only users and roles on the realm whitelist can obtain tokens. The terminate strategy revokes all outstanding tokens and sessions of the realm.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body = ToggleMaintenanceValidator,
+    responses(
+        (status = 200, description = "Realm maintenance toggled", body = RealmMaintenance),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+    ),
+)]
+pub async fn toggle_realm_maintenance(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ToggleMaintenanceValidator>,
+) -> Result<Response<RealmMaintenance>, ApiError> {
+    let maintenance = state
+        .service
+        .toggle_realm_maintenance(
+            identity,
+            realm_name,
+            ToggleMaintenanceRequest {
+                enabled: payload.enabled,
+                reason: payload.reason,
+                session_strategy: payload.session_strategy,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(maintenance))
+}
@@ -4,7 +4,14 @@ use super::handlers::add_client_whitelist_entry::{
 use super::handlers::add_realm_whitelist_entry::{
     __path_add_realm_whitelist_entry, add_realm_whitelist_entry,
 };
+use super::handlers::cancel_maintenance_window::{
+    __path_cancel_maintenance_window, cancel_maintenance_window,
+};
 use super::handlers::get_client_whitelist::{__path_get_client_whitelist, get_client_whitelist};
+use super::handlers::get_maintenance_windows::{
+    __path_get_maintenance_windows, get_maintenance_windows,
+};
+use super::handlers::get_realm_maintenance::{__path_get_realm_maintenance, get_realm_maintenance};
 use super::handlers::get_realm_whitelist::{__path_get_realm_whitelist, get_realm_whitelist};
 use super::handlers::remove_client_whitelist_entry::{
     __path_remove_client_whitelist_entry, remove_client_whitelist_entry,
@@ -12,7 +19,13 @@ use super::handlers::remove_client_whitelist_entry::{
 use super::handlers::remove_realm_whitelist_entry::{
     __path_remove_realm_whitelist_entry, remove_realm_whitelist_entry,
 };
+use super::handlers::schedule_maintenance_window::{
+    __path_schedule_maintenance_window, schedule_maintenance_window,
+};
 use super::handlers::toggle_maintenance::{__path_toggle_maintenance, toggle_maintenance};
+use super::handlers::toggle_realm_maintenance::{
+    __path_toggle_realm_maintenance, toggle_realm_maintenance,
+};
 use crate::application::{auth::auth, http::server::app_state::AppState};
 
 use axum::{
@@ -30,6 +43,11 @@ use utoipa::OpenApi;
     get_realm_whitelist,
     add_realm_whitelist_entry,
     remove_realm_whitelist_entry,
+    get_realm_maintenance,
+    toggle_realm_maintenance,
+    get_maintenance_windows,
+    schedule_maintenance_window,
+    cancel_maintenance_window,
 ))]
 pub struct MaintenanceApiDoc;
 
@@ -73,5 +91,28 @@ pub fn maintenance_routes(state: AppState) -> Router<AppState> {
             ),
             delete(remove_realm_whitelist_entry),
         )
+        // Realm maintenance toggle
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/settings/maintenance",
+                state.args.server.root_path
+            ),
+            get(get_realm_maintenance).put(toggle_realm_maintenance),
+        )
+        // Scheduled maintenance windows
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/settings/maintenance/windows",
+                state.args.server.root_path
+            ),
+            get(get_maintenance_windows).post(schedule_maintenance_window),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/settings/maintenance/windows/{{window_id}}",
+                state.args.server.root_path
+            ),
+            delete(cancel_maintenance_window),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
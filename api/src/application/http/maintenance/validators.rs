@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use ferriskey_core::domain::client::entities::MaintenanceSessionStrategy;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -23,3 +24,20 @@ pub struct AddWhitelistEntryValidator {
     #[serde(default)]
     pub role_id: Option<Uuid>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ScheduleMaintenanceWindowValidator {
+    /// Client put under maintenance; the whole realm when omitted
+    #[serde(default)]
+    pub client_id: Option<Uuid>,
+
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    #[serde(default)]
+    pub session_strategy: Option<MaintenanceSessionStrategy>,
+
+    pub starts_at: DateTime<Utc>,
+
+    pub ends_at: DateTime<Utc>,
+}
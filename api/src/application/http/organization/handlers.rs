@@ -1,4 +1,5 @@
 pub mod add_member;
+pub mod count_organizations;
 pub mod create_organization;
 pub mod delete_attribute;
 pub mod delete_organization;
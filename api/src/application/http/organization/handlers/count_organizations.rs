@@ -0,0 +1,48 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    organization::ports::{ListOrganizationsInput, OrganizationService},
+};
+
+use crate::application::http::organization::handlers::list_organizations::ListOrganizationsQuery;
+use crate::application::http::server::api_entities::{
+    api_error::{ApiError, ApiErrorResponse},
+    pagination::CountResponse,
+    response::Response,
+};
+use crate::application::http::server::app_state::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/count",
+    tag = "organization",
+    summary = "Count organizations in a realm",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ListOrganizationsQuery,
+    ),
+    responses(
+        (status = 200, description = "Organizations counted successfully", body = CountResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn count_organizations(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<ListOrganizationsQuery>,
+) -> Result<Response<CountResponse>, ApiError> {
+    let filter = query.into_filter(Default::default())?;
+
+    state
+        .service
+        .count_organizations(identity, ListOrganizationsInput { realm_name, filter })
+        .await
+        .map(|count| Response::OK(CountResponse { count }))
+        .map_err(ApiError::from)
+}
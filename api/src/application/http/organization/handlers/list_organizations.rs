@@ -1,16 +1,20 @@
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 use ferriskey_core::domain::{
     authentication::value_objects::Identity,
-    organization::ports::{ListOrganizationsInput, Organization, OrganizationService},
+    organization::ports::{
+        ListOrganizationsInput, Organization, OrganizationFilter, OrganizationService,
+        OrganizationSortField,
+    },
 };
-use serde::Serialize;
-use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::application::http::server::api_entities::{
     api_error::{ApiError, ApiErrorResponse},
+    pagination::PaginationQuery,
     response::Response,
 };
 use crate::application::http::server::app_state::AppState;
@@ -18,6 +22,28 @@ use crate::application::http::server::app_state::AppState;
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ListOrganizationsResponse {
     pub data: Vec<Organization>,
+    /// Pass back as `cursor` to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListOrganizationsQuery {
+    /// Matched against the name, alias and domain
+    pub search: Option<String>,
+    pub enabled: Option<bool>,
+    pub sort: Option<OrganizationSortField>,
+}
+
+impl ListOrganizationsQuery {
+    pub fn into_filter(self, pagination: PaginationQuery) -> Result<OrganizationFilter, ApiError> {
+        Ok(OrganizationFilter {
+            search: self.search,
+            enabled: self.enabled,
+            sort: self.sort.unwrap_or_default(),
+            page: pagination.try_into()?,
+        })
+    }
 }
 
 #[utoipa::path(
@@ -27,9 +53,12 @@ pub struct ListOrganizationsResponse {
     summary = "List organizations in a realm",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
+        ListOrganizationsQuery,
+        PaginationQuery,
     ),
     responses(
         (status = 200, description = "Organizations retrieved successfully", body = ListOrganizationsResponse),
+        (status = 400, description = "Invalid cursor", body = ApiErrorResponse),
         (status = 401, description = "Unauthorized", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
@@ -39,11 +68,20 @@ pub async fn list_organizations(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    Query(query): Query<ListOrganizationsQuery>,
+    Query(pagination): Query<PaginationQuery>,
 ) -> Result<Response<ListOrganizationsResponse>, ApiError> {
+    let filter = query.into_filter(pagination)?;
+
     state
         .service
-        .list_organizations(identity, ListOrganizationsInput { realm_name })
+        .list_organizations(identity, ListOrganizationsInput { realm_name, filter })
         .await
-        .map(|data| Response::OK(ListOrganizationsResponse { data }))
+        .map(|page| {
+            Response::OK(ListOrganizationsResponse {
+                data: page.data,
+                next_cursor: page.next_cursor,
+            })
+        })
         .map_err(ApiError::from)
 }
@@ -5,6 +5,7 @@ use crate::application::{auth::auth, http::server::app_state::AppState};
 
 use super::handlers::{
     add_member::{__path_add_member, add_member},
+    count_organizations::{__path_count_organizations, count_organizations},
     create_organization::{__path_create_organization, create_organization},
     delete_attribute::{__path_delete_attribute, delete_attribute},
     delete_organization::{__path_delete_organization, delete_organization},
@@ -20,6 +21,7 @@ use super::handlers::{
 #[derive(OpenApi)]
 #[openapi(paths(
     list_organizations,
+    count_organizations,
     create_organization,
     get_organization,
     update_organization,
@@ -42,6 +44,13 @@ pub fn organization_routes(state: AppState) -> Router<AppState> {
             ),
             get(list_organizations).post(create_organization),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/organizations/count",
+                state.args.server.root_path
+            ),
+            get(count_organizations),
+        )
         .route(
             &format!(
                 "{}/realms/{{realm_name}}/organizations/{{organization_id}}",
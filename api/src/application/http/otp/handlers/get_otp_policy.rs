@@ -0,0 +1,53 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    otp::{entities::OtpPolicy, ports::OtpPolicyService, value_objects::GetOtpPolicyInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct OtpPolicyResponse {
+    pub data: OtpPolicy,
+}
+
+#[utoipa::path(
+    get,
+    path = "/otp-policy",
+    tag = "otp-policy",
+    summary = "Get OTP policy",
+    description = "Returns the settings new OTP devices of the realm are enrolled with and how far off a code may be. Realms that never configured one use TOTP with SHA1, 6 digits and a 30 second period.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "OTP policy retrieved successfully", body = OtpPolicyResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_otp_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<OtpPolicyResponse>, ApiError> {
+    let policy = state
+        .service
+        .get_otp_policy(identity, GetOtpPolicyInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(OtpPolicyResponse { data: policy }))
+}
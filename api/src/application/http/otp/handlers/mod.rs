@@ -0,0 +1,2 @@
+pub mod get_otp_policy;
+pub mod update_otp_policy;
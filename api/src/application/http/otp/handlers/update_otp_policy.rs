@@ -0,0 +1,63 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    otp::{ports::OtpPolicyService, value_objects::UpdateOtpPolicyInput},
+};
+
+use crate::application::http::{
+    otp::{handlers::get_otp_policy::OtpPolicyResponse, validators::UpdateOtpPolicyValidator},
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/otp-policy",
+    tag = "otp-policy",
+    summary = "Update OTP policy",
+    description = "Changes the settings used to enroll new OTP devices and the look-around window used to check codes. Devices already enrolled keep their algorithm, digits and period. Omitted fields are left unchanged.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    request_body = UpdateOtpPolicyValidator,
+    responses(
+        (status = 200, description = "OTP policy updated successfully", body = OtpPolicyResponse),
+        (status = 400, description = "Invalid OTP policy", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_otp_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateOtpPolicyValidator>,
+) -> Result<Response<OtpPolicyResponse>, ApiError> {
+    let policy = state
+        .service
+        .update_otp_policy(
+            identity,
+            UpdateOtpPolicyInput {
+                realm_name,
+                otp_type: payload.otp_type,
+                algorithm: payload.algorithm,
+                digits: payload.digits,
+                period: payload.period,
+                look_around: payload.look_around,
+                initial_counter: payload.initial_counter,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(OtpPolicyResponse { data: policy }))
+}
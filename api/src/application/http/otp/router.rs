@@ -0,0 +1,29 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        otp::handlers::{
+            get_otp_policy::{__path_get_otp_policy, get_otp_policy},
+            update_otp_policy::{__path_update_otp_policy, update_otp_policy},
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_otp_policy, update_otp_policy))]
+pub struct OtpPolicyApiDoc;
+
+pub fn otp_policy_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/otp-policy",
+                state.args.server.root_path
+            ),
+            get(get_otp_policy).put(update_otp_policy),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
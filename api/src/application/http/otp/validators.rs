@@ -0,0 +1,18 @@
+use ferriskey_core::domain::otp::entities::{OtpAlgorithm, OtpType};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Fields left out keep their current value.
+#[derive(Debug, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateOtpPolicyValidator {
+    pub otp_type: Option<OtpType>,
+    pub algorithm: Option<OtpAlgorithm>,
+    #[validate(range(min = 6, max = 8, message = "digits must be between 6 and 8"))]
+    pub digits: Option<u32>,
+    #[validate(range(min = 15, max = 300, message = "period must be between 15 and 300"))]
+    pub period: Option<u64>,
+    #[validate(range(max = 10, message = "look_around must be at most 10"))]
+    pub look_around: Option<u32>,
+    pub initial_counter: Option<u64>,
+}
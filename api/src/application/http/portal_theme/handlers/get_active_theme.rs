@@ -1,23 +1,33 @@
-use axum::extract::{Path, Query, State};
-use ferriskey_core::domain::portal_theme::{
-    entities::{PortalPageType, PortalThemeConfig},
-    ports::{ListThemesInput, PortalThemeService},
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+};
+use ferriskey_core::domain::{
+    localization::{ports::LocalizationService, value_objects::ResolveMessagesInput},
+    portal_theme::{
+        entities::{PortalPageType, PortalThemeConfig},
+        ports::{ListThemesInput, PortalThemeService},
+    },
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::application::http::server::{
-    api_entities::{
-        api_error::{ApiError, ApiErrorResponse},
-        response::Response,
+use crate::application::http::{
+    localization::handlers::resolve_messages::LocaleQuery,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse},
+            response::Response,
+        },
+        app_state::AppState,
     },
-    app_state::AppState,
 };
 
 #[derive(Debug, Deserialize)]
 pub struct ActiveThemeQuery {
     pub page_type: PortalPageType,
+    pub ui_locales: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -26,6 +36,8 @@ pub struct ActiveThemeResponse {
     pub design_tokens: PortalThemeConfig,
     pub layout_id: Option<Uuid>,
     pub page_tree: serde_json::Value,
+    /// Locale the page tree's `{{msg.<key>}}` references were resolved for.
+    pub locale: String,
 }
 
 #[utoipa::path(
@@ -33,10 +45,11 @@ pub struct ActiveThemeResponse {
     path = "/active",
     tag = "portal-theme-public",
     summary = "Get the active portal theme bundle for a page",
-    description = "Public endpoint used by the portal renderer. Returns the realm's active theme design tokens, the referenced layout ID (if any), and the JSONB component tree for the requested page type. Callers fetch the layout itself via the public portal-layouts endpoint. Falls back to defaults / empty trees when nothing is configured.",
+    description = "Public endpoint used by the portal renderer. Returns the realm's active theme design tokens, the referenced layout ID (if any), and the JSONB component tree for the requested page type. Callers fetch the layout itself via the public portal-layouts endpoint. Message references in the tree are resolved for the locale picked from `ui_locales`, then `Accept-Language`, then the realm default. Falls back to defaults / empty trees when nothing is configured.",
     params(
         ("realm_name" = String, Path, description = "Name of the realm"),
         ("page_type" = PortalPageType, Query, description = "Portal page type"),
+        ("ui_locales" = Option<String>, Query, description = "Space-separated language tags, most preferred first"),
     ),
     responses(
         (status = 200, description = "Active theme bundle retrieved successfully", body = ActiveThemeResponse),
@@ -48,14 +61,27 @@ pub async fn get_active_theme(
     Path(realm_name): Path<String>,
     Query(query): Query<ActiveThemeQuery>,
     State(state): State<AppState>,
+    headers: HeaderMap,
 ) -> Result<Response<ActiveThemeResponse>, ApiError> {
+    let messages = state
+        .service
+        .resolve_messages(ResolveMessagesInput {
+            realm_name: realm_name.clone(),
+            hints: LocaleQuery {
+                ui_locales: query.ui_locales,
+            }
+            .hints(&headers),
+        })
+        .await
+        .map_err(ApiError::from)?;
+
     let active = state
         .service
         .get_active_theme(ListThemesInput { realm_name })
         .await
         .map_err(ApiError::from)?;
 
-    let (theme_id, design_tokens, layout_id, page_tree) = match active {
+    let (theme_id, design_tokens, layout_id, mut page_tree) = match active {
         Some(theme) => (
             Some(theme.id),
             theme.config,
@@ -70,10 +96,13 @@ pub async fn get_active_theme(
         ),
     };
 
+    messages.localize_tree(&mut page_tree);
+
     Ok(Response::OK(ActiveThemeResponse {
         theme_id,
         design_tokens,
         layout_id,
         page_tree,
+        locale: messages.locale,
     }))
 }
@@ -67,6 +67,7 @@ pub async fn update_realm_setting(
                 email_verification_template_id: payload.email_verification_template_id,
                 email_verification_enabled: payload.email_verification_enabled,
                 email_verification_ttl_hours: payload.email_verification_ttl_hours,
+                trusted_device_days: payload.trusted_device_days,
             },
         )
         .await
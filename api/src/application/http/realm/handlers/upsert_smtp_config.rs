@@ -25,8 +25,8 @@ use crate::application::http::{
     put,
     path = "/{realm_name}/smtp-config",
     tag = "realm",
-    summary = "Create or update SMTP configuration for a realm",
-    description = "Creates or updates the SMTP configuration for the specified realm.",
+    summary = "Create or update email configuration for a realm",
+    description = "Creates or updates the email configuration for the specified realm. `provider` selects SMTP (the default), a provider HTTP API, or the server's maildir directory; SMTP credentials are only required for SMTP.",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
     ),
@@ -57,6 +57,7 @@ pub async fn upsert_smtp_config(
                 from_email: payload.from_email,
                 from_name: payload.from_name,
                 encryption: payload.encryption,
+                provider: payload.provider,
             },
         )
         .await
@@ -1,3 +1,4 @@
+use ferriskey_core::domain::realm::entities::EmailProvider;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -50,24 +51,83 @@ pub struct UpdateRealmSettingValidator {
         message = "email_verification_ttl_hours must be between 1 and 720"
     ))]
     pub email_verification_ttl_hours: Option<i64>,
+    /// Days a device the user trusted skips the second factor; `0` turns
+    /// trusted devices off.
+    #[validate(range(max = 365, message = "trusted_device_days must be at most 365"))]
+    pub trusted_device_days: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_email_provider"))]
 pub struct UpsertSmtpConfigValidator {
-    #[validate(length(min = 1, message = "host is required"))]
+    /// Required for the `smtp` provider.
+    #[serde(default)]
     pub host: String,
+    #[serde(default = "default_smtp_port")]
     #[validate(range(min = 1, max = 65535, message = "port must be between 1 and 65535"))]
     pub port: u16,
-    #[validate(length(min = 1, message = "username is required"))]
+    /// Required for the `smtp` provider.
+    #[serde(default)]
     pub username: String,
-    #[validate(length(min = 1, message = "password is required"))]
+    /// Required for the `smtp` provider.
+    #[serde(default)]
     pub password: String,
     #[validate(email(message = "from_email must be a valid email"))]
     pub from_email: String,
     #[validate(length(min = 1, message = "from_name is required"))]
     pub from_name: String,
+    #[serde(default = "default_smtp_encryption")]
     #[validate(custom(function = "validate_encryption"))]
     pub encryption: String,
+    /// Defaults to `smtp`.
+    #[serde(default)]
+    pub provider: EmailProvider,
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+fn default_smtp_encryption() -> String {
+    "starttls".to_string()
+}
+
+fn validate_email_provider(
+    value: &UpsertSmtpConfigValidator,
+) -> Result<(), validator::ValidationError> {
+    match &value.provider {
+        EmailProvider::Smtp => {
+            if value.host.is_empty() {
+                return Err(validator::ValidationError::new("host is required"));
+            }
+            if value.username.is_empty() {
+                return Err(validator::ValidationError::new("username is required"));
+            }
+            if value.password.is_empty() {
+                return Err(validator::ValidationError::new("password is required"));
+            }
+        }
+        EmailProvider::HttpApi(settings) => {
+            if !(settings.url.starts_with("https://") || settings.url.starts_with("http://")) {
+                return Err(validator::ValidationError::new(
+                    "provider url must be an http(s) URL",
+                ));
+            }
+            if settings.body_template.trim().is_empty() {
+                return Err(validator::ValidationError::new(
+                    "provider body_template is required",
+                ));
+            }
+            if settings.headers.keys().any(|name| name.trim().is_empty()) {
+                return Err(validator::ValidationError::new(
+                    "provider header names must not be empty",
+                ));
+            }
+        }
+        EmailProvider::Maildir => {}
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
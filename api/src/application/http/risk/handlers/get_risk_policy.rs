@@ -0,0 +1,53 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    risk::{entities::RiskPolicy, ports::RiskService, value_objects::GetRiskPolicyInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RiskPolicyResponse {
+    pub data: RiskPolicy,
+}
+
+#[utoipa::path(
+    get,
+    path = "/risk-policy",
+    tag = "risk-policy",
+    summary = "Get the risk policy of the realm",
+    description = "Returns how password sign-ins of the realm are scored and the thresholds at which users are notified, asked for a second factor or blocked. Realms that never configured one get a disabled policy.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Risk policy retrieved successfully", body = RiskPolicyResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_risk_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RiskPolicyResponse>, ApiError> {
+    let policy = state
+        .service
+        .get_risk_policy(identity, GetRiskPolicyInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(RiskPolicyResponse { data: policy }))
+}
@@ -0,0 +1,2 @@
+pub mod get_risk_policy;
+pub mod update_risk_policy;
@@ -0,0 +1,63 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    risk::{ports::RiskService, value_objects::UpdateRiskPolicyInput},
+};
+
+use crate::application::http::{
+    risk::{handlers::get_risk_policy::RiskPolicyResponse, validators::UpdateRiskPolicyValidator},
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/risk-policy",
+    tag = "risk-policy",
+    summary = "Update the risk policy of the realm",
+    description = "Updates how password sign-ins of the realm are scored. Thresholds must not decrease from notifying to stepping up to blocking.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    request_body = UpdateRiskPolicyValidator,
+    responses(
+        (status = 200, description = "Risk policy updated successfully", body = RiskPolicyResponse),
+        (status = 400, description = "Invalid risk policy", body = ApiErrorResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_risk_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateRiskPolicyValidator>,
+) -> Result<Response<RiskPolicyResponse>, ApiError> {
+    let policy = state
+        .service
+        .update_risk_policy(
+            identity,
+            UpdateRiskPolicyInput {
+                realm_name,
+                enabled: payload.enabled,
+                notify_threshold: payload.notify_threshold,
+                step_up_threshold: payload.step_up_threshold,
+                block_threshold: payload.block_threshold,
+                max_failures: payload.max_failures,
+                failure_window_minutes: payload.failure_window_minutes,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(RiskPolicyResponse { data: policy }))
+}
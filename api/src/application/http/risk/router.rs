@@ -0,0 +1,29 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        risk::handlers::{
+            get_risk_policy::{__path_get_risk_policy, get_risk_policy},
+            update_risk_policy::{__path_update_risk_policy, update_risk_policy},
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(get_risk_policy, update_risk_policy))]
+pub struct RiskPolicyApiDoc;
+
+pub fn risk_policy_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/risk-policy",
+                state.args.server.root_path
+            ),
+            get(get_risk_policy).put(update_risk_policy),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Fields left out keep their current value.
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateRiskPolicyValidator {
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Score from 0 to 100 at which the user is warned of the sign-in.
+    #[serde(default)]
+    #[validate(range(max = 101, message = "notify_threshold must be at most 101"))]
+    pub notify_threshold: Option<u8>,
+    /// Score at which a second factor is required.
+    #[serde(default)]
+    #[validate(range(max = 101, message = "step_up_threshold must be at most 101"))]
+    pub step_up_threshold: Option<u8>,
+    /// Score at which the sign-in is refused. 101 never blocks.
+    #[serde(default)]
+    #[validate(range(max = 101, message = "block_threshold must be at most 101"))]
+    pub block_threshold: Option<u8>,
+    /// Failed sign-ins within the window that count as a signal.
+    #[serde(default)]
+    #[validate(range(min = 1, message = "max_failures must be at least 1"))]
+    pub max_failures: Option<u32>,
+    #[serde(default)]
+    #[validate(range(
+        min = 1,
+        max = 1440,
+        message = "failure_window_minutes must be between 1 and 1440"
+    ))]
+    pub failure_window_minutes: Option<u32>,
+}
@@ -1,3 +1,4 @@
+pub mod count_roles;
 pub mod create_role;
 pub mod delete_role;
 pub mod get_role;
@@ -0,0 +1,51 @@
+use crate::application::http::{
+    role::handlers::get_roles::GetRolesQuery,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse},
+            pagination::CountResponse,
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::role::entities::GetRolesInput;
+use ferriskey_core::domain::role::ports::RoleService;
+
+#[utoipa::path(
+    get,
+    summary = "Count roles for a realm",
+    description = "Counts the roles of a realm matching the same search and filters as the role listing.",
+    path = "/count",
+    tag = "role",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetRolesQuery,
+    ),
+    responses(
+        (status = 200, description = "Roles counted successfully", body = CountResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn count_roles(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetRolesQuery>,
+) -> Result<Response<CountResponse>, ApiError> {
+    let filter = query.into_filter(Default::default())?;
+
+    let count = state
+        .service
+        .count_roles(identity, GetRolesInput { realm_name, filter })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(CountResponse { count }))
+}
@@ -1,35 +1,65 @@
 use crate::application::http::server::{
     api_entities::{
         api_error::{ApiError, ApiErrorResponse},
+        pagination::PaginationQuery,
         response::Response,
     },
     app_state::AppState,
 };
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 use ferriskey_core::domain::authentication::value_objects::Identity;
-use ferriskey_core::domain::role::entities::Role;
+use ferriskey_core::domain::role::entities::{GetRolesInput, Role};
 use ferriskey_core::domain::role::ports::RoleService;
+use ferriskey_core::domain::role::value_objects::{RoleFilter, RoleSortField};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct GetRolesResponse {
     pub data: Vec<Role>,
+    /// Pass back as `cursor` to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetRolesQuery {
+    /// Matched against the role name and description
+    pub search: Option<String>,
+    /// Only the roles of this client
+    pub client_id: Option<Uuid>,
+    pub sort: Option<RoleSortField>,
+}
+
+impl GetRolesQuery {
+    pub fn into_filter(self, pagination: PaginationQuery) -> Result<RoleFilter, ApiError> {
+        Ok(RoleFilter {
+            search: self.search,
+            client_id: self.client_id,
+            sort: self.sort.unwrap_or_default(),
+            page: pagination.try_into()?,
+        })
+    }
 }
 
 #[utoipa::path(
     get,
-    summary = "Get all roles for a realm",
+    summary = "Get roles for a realm",
+    description = "Retrieves one page of the roles of a realm, optionally searched and filtered.",
     path = "",
     tag = "role",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
+        GetRolesQuery,
+        PaginationQuery,
     ),
     responses(
         (status = 200, description = "Roles retrieved successfully", body = GetRolesResponse),
+        (status = 400, description = "Invalid cursor", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
     )
@@ -38,12 +68,19 @@ pub async fn get_roles(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    Query(query): Query<GetRolesQuery>,
+    Query(pagination): Query<PaginationQuery>,
 ) -> Result<Response<GetRolesResponse>, ApiError> {
-    let roles = state
+    let filter = query.into_filter(pagination)?;
+
+    let page = state
         .service
-        .get_roles(identity, realm_name)
+        .get_roles(identity, GetRolesInput { realm_name, filter })
         .await
         .map_err(ApiError::from)?;
 
-    Ok(Response::OK(GetRolesResponse { data: roles }))
+    Ok(Response::OK(GetRolesResponse {
+        data: page.data,
+        next_cursor: page.next_cursor,
+    }))
 }
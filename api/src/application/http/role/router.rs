@@ -7,6 +7,7 @@ use utoipa::OpenApi;
 use crate::application::{auth::auth, http::server::app_state::AppState};
 
 use super::handlers::{
+    count_roles::{__path_count_roles, count_roles},
     create_role::{__path_create_role, create_role},
     delete_role::{__path_delete_role, delete_role},
     get_role::{__path_get_role, get_role},
@@ -19,6 +20,7 @@ use super::handlers::{
 #[openapi(paths(
     create_role,
     get_roles,
+    count_roles,
     get_role,
     update_role,
     update_role_permissions,
@@ -35,6 +37,13 @@ pub fn role_routes(state: AppState) -> Router<AppState> {
             ),
             get(get_roles).post(create_role),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/roles/count",
+                state.args.server.root_path
+            ),
+            get(count_roles),
+        )
         .route(
             &format!(
                 "{}/realms/{{realm_name}}/roles/{{role_id}}",
@@ -0,0 +1,3 @@
+pub mod handlers;
+mod protocol;
+pub mod router;
@@ -0,0 +1,15 @@
+pub mod create_scim_group;
+pub mod create_scim_user;
+pub mod delete_scim_group;
+pub mod delete_scim_user;
+pub mod get_scim_group;
+pub mod get_scim_schemas;
+pub mod get_scim_user;
+pub mod get_service_provider_config;
+pub mod list_scim_groups;
+pub mod list_scim_users;
+pub mod patch_scim_group;
+pub mod patch_scim_user;
+pub mod replace_scim_group;
+pub mod replace_scim_user;
+pub mod scim_bulk;
@@ -0,0 +1,55 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimGroup},
+        ports::ScimService,
+        value_objects::CreateScimGroupInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimJson, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    post,
+    path = "/Groups",
+    tag = "scim",
+    summary = "Provision a SCIM group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body(content = ScimGroup, content_type = "application/scim+json"),
+    responses(
+        (status = 201, description = "Group created successfully", body = ScimGroup),
+        (status = 400, description = "Invalid Group resource", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 409, description = "Group already exists", body = ScimErrorMessage),
+    ),
+)]
+pub async fn create_scim_group(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    ScimJson(group): ScimJson<ScimGroup>,
+) -> Result<ScimResponse<ScimGroup>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let group = state
+        .service
+        .create_group(identity, CreateScimGroupInput { endpoint, group })
+        .await?;
+    let location = group.meta.as_ref().map(|meta| meta.location.clone());
+
+    Ok(ScimResponse::created(location, group))
+}
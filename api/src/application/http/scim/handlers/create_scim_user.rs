@@ -0,0 +1,55 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimUser},
+        ports::ScimService,
+        value_objects::CreateScimUserInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimJson, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    post,
+    path = "/Users",
+    tag = "scim",
+    summary = "Provision a SCIM user",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body(content = ScimUser, content_type = "application/scim+json"),
+    responses(
+        (status = 201, description = "User created successfully", body = ScimUser),
+        (status = 400, description = "Invalid User resource", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 409, description = "User already exists", body = ScimErrorMessage),
+    ),
+)]
+pub async fn create_scim_user(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    ScimJson(user): ScimJson<ScimUser>,
+) -> Result<ScimResponse<ScimUser>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let user = state
+        .service
+        .create_user(identity, CreateScimUserInput { endpoint, user })
+        .await?;
+    let location = user.meta.as_ref().map(|meta| meta.location.clone());
+
+    Ok(ScimResponse::created(location, user))
+}
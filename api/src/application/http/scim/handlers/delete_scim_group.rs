@@ -0,0 +1,50 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::ScimErrorMessage, ports::ScimService, value_objects::DeleteScimResourceInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/Groups/{id}",
+    tag = "scim",
+    summary = "Deprovision a SCIM group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("id" = String, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 204, description = "Group deleted successfully"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 404, description = "Group not found", body = ScimErrorMessage),
+    ),
+)]
+pub async fn delete_scim_group(
+    Path((realm_name, id)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+) -> Result<ScimResponse<()>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    state
+        .service
+        .delete_group(identity, DeleteScimResourceInput { endpoint, id })
+        .await?;
+
+    Ok(ScimResponse::no_content())
+}
@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimGroup},
+        ports::ScimService,
+        value_objects::GetScimResourceInput,
+    },
+};
+use serde_json::Value;
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimAttributeParams, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    get,
+    path = "/Groups/{id}",
+    tag = "scim",
+    summary = "Get a SCIM group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("id" = String, Path, description = "Group ID"),
+        ScimAttributeParams,
+    ),
+    responses(
+        (status = 200, description = "Group retrieved successfully", body = ScimGroup),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 404, description = "Group not found", body = ScimErrorMessage),
+    ),
+)]
+pub async fn get_scim_group(
+    Path((realm_name, id)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    Query(params): Query<ScimAttributeParams>,
+) -> Result<ScimResponse<Value>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+    let (attributes, excluded_attributes) = params.into_parts();
+
+    let group = state
+        .service
+        .get_group(
+            identity,
+            GetScimResourceInput {
+                endpoint,
+                id,
+                attributes,
+                excluded_attributes,
+            },
+        )
+        .await?;
+
+    Ok(ScimResponse::ok(group))
+}
@@ -0,0 +1,33 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::scim::{discovery::schemas, entities::ScimListResponse};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    get,
+    path = "/Schemas",
+    tag = "scim",
+    summary = "List the SCIM schemas",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Schemas of the User and Group resources", body = ScimListResponse),
+    ),
+)]
+pub async fn get_scim_schemas(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+) -> ScimResponse<ScimListResponse> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+    let schemas = schemas(&endpoint);
+
+    ScimResponse::ok(ScimListResponse::new(schemas.len(), 1, schemas))
+}
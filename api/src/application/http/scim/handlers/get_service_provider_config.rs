@@ -0,0 +1,33 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::scim::discovery::service_provider_config;
+use serde_json::Value;
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    get,
+    path = "/ServiceProviderConfig",
+    tag = "scim",
+    summary = "Get the SCIM service provider configuration",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Supported SCIM features", body = Object),
+    ),
+)]
+pub async fn get_service_provider_config(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+) -> ScimResponse<Value> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    ScimResponse::ok(service_provider_config(&endpoint))
+}
@@ -0,0 +1,60 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimListResponse},
+        ports::ScimService,
+        value_objects::ListScimResourcesInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimListParams, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    get,
+    path = "/Users",
+    tag = "scim",
+    summary = "List SCIM users",
+    description = "Lists the users of the realm as SCIM User resources, with SCIM filtering, attribute projection and index-based pagination.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ScimListParams,
+    ),
+    responses(
+        (status = 200, description = "Users retrieved successfully", body = ScimListResponse),
+        (status = 400, description = "Invalid filter", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+    ),
+)]
+pub async fn list_scim_users(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    Query(params): Query<ScimListParams>,
+) -> Result<ScimResponse<ScimListResponse>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let response = state
+        .service
+        .list_users(
+            identity,
+            ListScimResourcesInput {
+                endpoint,
+                query: params.into_query(),
+            },
+        )
+        .await?;
+
+    Ok(ScimResponse::ok(response))
+}
@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimGroup, ScimPatchRequest},
+        ports::ScimService,
+        value_objects::PatchScimResourceInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimJson, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/Groups/{id}",
+    tag = "scim",
+    summary = "Patch a SCIM group",
+    description = "Applies RFC 7644 PATCH operations (`add`, `replace`, `remove`) to the group.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("id" = String, Path, description = "Group ID"),
+    ),
+    request_body(content = ScimPatchRequest, content_type = "application/scim+json"),
+    responses(
+        (status = 200, description = "Group patched successfully", body = ScimGroup),
+        (status = 400, description = "Invalid PATCH operation", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 404, description = "Group not found", body = ScimErrorMessage),
+    ),
+)]
+pub async fn patch_scim_group(
+    Path((realm_name, id)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    ScimJson(patch): ScimJson<ScimPatchRequest>,
+) -> Result<ScimResponse<ScimGroup>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let group = state
+        .service
+        .patch_group(
+            identity,
+            PatchScimResourceInput {
+                endpoint,
+                id,
+                patch,
+            },
+        )
+        .await?;
+    let location = group.meta.as_ref().map(|meta| meta.location.clone());
+
+    Ok(ScimResponse::ok(group).with_location(location))
+}
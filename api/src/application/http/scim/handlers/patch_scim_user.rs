@@ -0,0 +1,64 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimPatchRequest, ScimUser},
+        ports::ScimService,
+        value_objects::PatchScimResourceInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimJson, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/Users/{id}",
+    tag = "scim",
+    summary = "Patch a SCIM user",
+    description = "Applies RFC 7644 PATCH operations (`add`, `replace`, `remove`) to the user.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("id" = String, Path, description = "User ID"),
+    ),
+    request_body(content = ScimPatchRequest, content_type = "application/scim+json"),
+    responses(
+        (status = 200, description = "User patched successfully", body = ScimUser),
+        (status = 400, description = "Invalid PATCH operation", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 404, description = "User not found", body = ScimErrorMessage),
+    ),
+)]
+pub async fn patch_scim_user(
+    Path((realm_name, id)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    ScimJson(patch): ScimJson<ScimPatchRequest>,
+) -> Result<ScimResponse<ScimUser>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let user = state
+        .service
+        .patch_user(
+            identity,
+            PatchScimResourceInput {
+                endpoint,
+                id,
+                patch,
+            },
+        )
+        .await?;
+    let location = user.meta.as_ref().map(|meta| meta.location.clone());
+
+    Ok(ScimResponse::ok(user).with_location(location))
+}
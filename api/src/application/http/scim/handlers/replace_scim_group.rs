@@ -0,0 +1,63 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimGroup},
+        ports::ScimService,
+        value_objects::ReplaceScimGroupInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimJson, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    put,
+    path = "/Groups/{id}",
+    tag = "scim",
+    summary = "Replace a SCIM group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("id" = String, Path, description = "Group ID"),
+    ),
+    request_body(content = ScimGroup, content_type = "application/scim+json"),
+    responses(
+        (status = 200, description = "Group replaced successfully", body = ScimGroup),
+        (status = 400, description = "Invalid Group resource", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 404, description = "Group not found", body = ScimErrorMessage),
+    ),
+)]
+pub async fn replace_scim_group(
+    Path((realm_name, id)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    ScimJson(group): ScimJson<ScimGroup>,
+) -> Result<ScimResponse<ScimGroup>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let group = state
+        .service
+        .replace_group(
+            identity,
+            ReplaceScimGroupInput {
+                endpoint,
+                id,
+                group,
+            },
+        )
+        .await?;
+    let location = group.meta.as_ref().map(|meta| meta.location.clone());
+
+    Ok(ScimResponse::ok(group).with_location(location))
+}
@@ -0,0 +1,56 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{ScimErrorMessage, ScimUser},
+        ports::ScimService,
+        value_objects::ReplaceScimUserInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimJson, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    put,
+    path = "/Users/{id}",
+    tag = "scim",
+    summary = "Replace a SCIM user",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("id" = String, Path, description = "User ID"),
+    ),
+    request_body(content = ScimUser, content_type = "application/scim+json"),
+    responses(
+        (status = 200, description = "User replaced successfully", body = ScimUser),
+        (status = 400, description = "Invalid User resource", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 404, description = "User not found", body = ScimErrorMessage),
+    ),
+)]
+pub async fn replace_scim_user(
+    Path((realm_name, id)): Path<(String, String)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    ScimJson(user): ScimJson<ScimUser>,
+) -> Result<ScimResponse<ScimUser>, ScimApiError> {
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let user = state
+        .service
+        .replace_user(identity, ReplaceScimUserInput { endpoint, id, user })
+        .await?;
+    let location = user.meta.as_ref().map(|meta| meta.location.clone());
+
+    Ok(ScimResponse::ok(user).with_location(location))
+}
@@ -0,0 +1,62 @@
+use axum::{
+    Extension,
+    body::Bytes,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    scim::{
+        entities::{MAX_BULK_PAYLOAD_SIZE, ScimBulkRequest, ScimBulkResponse, ScimErrorMessage},
+        error::ScimError,
+        ports::ScimService,
+        value_objects::ScimBulkInput,
+    },
+};
+
+use crate::application::{
+    http::{
+        scim::protocol::{ScimApiError, ScimResponse, endpoint},
+        server::app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    post,
+    path = "/Bulk",
+    tag = "scim",
+    summary = "Run SCIM bulk operations",
+    description = "Runs up to 1000 operations in order. Later operations may reference resources created earlier in the request with `bulkId:<id>`; processing stops once `failOnErrors` errors occurred.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body(content = ScimBulkRequest, content_type = "application/scim+json"),
+    responses(
+        (status = 200, description = "Bulk request processed", body = ScimBulkResponse),
+        (status = 400, description = "Invalid bulk request", body = ScimErrorMessage),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient permissions", body = ScimErrorMessage),
+        (status = 413, description = "Bulk request too large", body = ScimErrorMessage),
+    ),
+)]
+pub async fn scim_bulk(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    body: Bytes,
+) -> Result<ScimResponse<ScimBulkResponse>, ScimApiError> {
+    if body.len() > MAX_BULK_PAYLOAD_SIZE {
+        return Err(ScimError::PayloadTooLarge(MAX_BULK_PAYLOAD_SIZE).into());
+    }
+    let request: ScimBulkRequest =
+        serde_json::from_slice(&body).map_err(|e| ScimError::InvalidSyntax(e.to_string()))?;
+    let endpoint = endpoint(realm_name, &base_url, &state.args.server.root_path);
+
+    let response = state
+        .service
+        .bulk(identity, ScimBulkInput { endpoint, request })
+        .await?;
+
+    Ok(ScimResponse::ok(response))
+}
@@ -0,0 +1,198 @@
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Request},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use ferriskey_core::domain::scim::{
+    entities::ScimErrorMessage,
+    error::ScimError,
+    value_objects::{ScimEndpoint, ScimQuery},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use utoipa::IntoParams;
+
+use crate::application::http::authentication::handlers::auth::root_scoped_base_url;
+
+pub const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+
+/// Builds the SCIM endpoint of a request from the public base URL returned by
+/// `FullUrl`.
+pub fn endpoint(realm_name: String, base_url: &str, root_path: &str) -> ScimEndpoint {
+    ScimEndpoint {
+        realm_name,
+        base_url: root_scoped_base_url(base_url, root_path),
+    }
+}
+
+/// JSON request body. SCIM clients send `application/scim+json`, which
+/// axum's `Json` extractor rejects, so the content type is not enforced.
+pub struct ScimJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ScimJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ScimApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req, state)
+            .await
+            .map_err(|e| ScimError::InvalidSyntax(e.body_text()))?;
+
+        serde_json::from_slice(&bytes)
+            .map(ScimJson)
+            .map_err(|e| ScimError::InvalidSyntax(e.to_string()).into())
+    }
+}
+
+/// A SCIM response: the status, the resource location when one was created
+/// or returned, and the body.
+pub struct ScimResponse<T> {
+    pub status: StatusCode,
+    pub location: Option<String>,
+    pub body: Option<T>,
+}
+
+impl<T> ScimResponse<T> {
+    pub fn ok(body: T) -> Self {
+        Self {
+            status: StatusCode::OK,
+            location: None,
+            body: Some(body),
+        }
+    }
+
+    pub fn created(location: Option<String>, body: T) -> Self {
+        Self {
+            status: StatusCode::CREATED,
+            location,
+            body: Some(body),
+        }
+    }
+
+    pub fn no_content() -> Self {
+        Self {
+            status: StatusCode::NO_CONTENT,
+            location: None,
+            body: None,
+        }
+    }
+
+    pub fn with_location(mut self, location: Option<String>) -> Self {
+        self.location = location;
+        self
+    }
+}
+
+impl<T: Serialize> IntoResponse for ScimResponse<T> {
+    fn into_response(self) -> Response {
+        let mut response = match self.body {
+            Some(body) => match serde_json::to_vec(&body) {
+                Ok(body) => (self.status, body).into_response(),
+                Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+            },
+            None => self.status.into_response(),
+        };
+
+        let headers = response.headers_mut();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(SCIM_CONTENT_TYPE),
+        );
+        if let Some(location) = self
+            .location
+            .and_then(|location| HeaderValue::from_str(&location).ok())
+        {
+            headers.insert(header::LOCATION, location);
+        }
+
+        response
+    }
+}
+
+/// Renders a [`ScimError`] as a SCIM error message (RFC 7644 §3.12).
+#[derive(Debug)]
+pub struct ScimApiError(pub ScimError);
+
+impl From<ScimError> for ScimApiError {
+    fn from(error: ScimError) -> Self {
+        Self(error)
+    }
+}
+
+impl IntoResponse for ScimApiError {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.0.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        if status.is_server_error() {
+            tracing::error!(error = ?self.0, "SCIM request failed");
+        }
+
+        ScimResponse {
+            status,
+            location: None,
+            body: Some(ScimErrorMessage::from(&self.0)),
+        }
+        .into_response()
+    }
+}
+
+/// Query parameters of the list endpoints (RFC 7644 §3.4.2).
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListParams {
+    /// SCIM filter expression, e.g. `userName eq "jane"`
+    pub filter: Option<String>,
+    /// Comma-separated attributes to return
+    pub attributes: Option<String>,
+    /// Comma-separated attributes to leave out
+    pub excluded_attributes: Option<String>,
+    /// 1-based index of the first result
+    pub start_index: Option<usize>,
+    /// Maximum number of results per page
+    pub count: Option<usize>,
+}
+
+impl ScimListParams {
+    pub fn into_query(self) -> ScimQuery {
+        ScimQuery {
+            filter: self.filter.filter(|filter| !filter.trim().is_empty()),
+            attributes: split_attributes(self.attributes),
+            excluded_attributes: split_attributes(self.excluded_attributes),
+            start_index: self.start_index,
+            count: self.count,
+        }
+    }
+}
+
+/// `attributes` / `excludedAttributes` of the single-resource endpoints.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimAttributeParams {
+    /// Comma-separated attributes to return
+    pub attributes: Option<String>,
+    /// Comma-separated attributes to leave out
+    pub excluded_attributes: Option<String>,
+}
+
+impl ScimAttributeParams {
+    pub fn into_parts(self) -> (Vec<String>, Vec<String>) {
+        (
+            split_attributes(self.attributes),
+            split_attributes(self.excluded_attributes),
+        )
+    }
+}
+
+fn split_attributes(raw: Option<String>) -> Vec<String> {
+    raw.map(|raw| {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|attribute| !attribute.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+    .unwrap_or_default()
+}
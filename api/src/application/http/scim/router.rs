@@ -0,0 +1,89 @@
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+use utoipa::OpenApi;
+
+use crate::application::{auth::auth, http::server::app_state::AppState};
+
+use super::handlers::{
+    create_scim_group::{__path_create_scim_group, create_scim_group},
+    create_scim_user::{__path_create_scim_user, create_scim_user},
+    delete_scim_group::{__path_delete_scim_group, delete_scim_group},
+    delete_scim_user::{__path_delete_scim_user, delete_scim_user},
+    get_scim_group::{__path_get_scim_group, get_scim_group},
+    get_scim_schemas::{__path_get_scim_schemas, get_scim_schemas},
+    get_scim_user::{__path_get_scim_user, get_scim_user},
+    get_service_provider_config::{
+        __path_get_service_provider_config, get_service_provider_config,
+    },
+    list_scim_groups::{__path_list_scim_groups, list_scim_groups},
+    list_scim_users::{__path_list_scim_users, list_scim_users},
+    patch_scim_group::{__path_patch_scim_group, patch_scim_group},
+    patch_scim_user::{__path_patch_scim_user, patch_scim_user},
+    replace_scim_group::{__path_replace_scim_group, replace_scim_group},
+    replace_scim_user::{__path_replace_scim_user, replace_scim_user},
+    scim_bulk::{__path_scim_bulk, scim_bulk},
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    list_scim_users,
+    get_scim_user,
+    create_scim_user,
+    replace_scim_user,
+    patch_scim_user,
+    delete_scim_user,
+    list_scim_groups,
+    get_scim_group,
+    create_scim_group,
+    replace_scim_group,
+    patch_scim_group,
+    delete_scim_group,
+    scim_bulk,
+    get_service_provider_config,
+    get_scim_schemas,
+))]
+pub struct ScimApiDoc;
+
+pub fn scim_routes(state: AppState) -> Router<AppState> {
+    let base = format!(
+        "{}/realms/{{realm_name}}/scim/v2",
+        state.args.server.root_path
+    );
+
+    // Discovery endpoints are public (RFC 7644 §4).
+    let discovery = Router::new()
+        .route(
+            &format!("{base}/ServiceProviderConfig"),
+            get(get_service_provider_config),
+        )
+        .route(&format!("{base}/Schemas"), get(get_scim_schemas));
+
+    Router::new()
+        .route(
+            &format!("{base}/Users"),
+            get(list_scim_users).post(create_scim_user),
+        )
+        .route(
+            &format!("{base}/Users/{{id}}"),
+            get(get_scim_user)
+                .put(replace_scim_user)
+                .patch(patch_scim_user)
+                .delete(delete_scim_user),
+        )
+        .route(
+            &format!("{base}/Groups"),
+            get(list_scim_groups).post(create_scim_group),
+        )
+        .route(
+            &format!("{base}/Groups/{{id}}"),
+            get(get_scim_group)
+                .put(replace_scim_group)
+                .patch(patch_scim_group)
+                .delete(delete_scim_group),
+        )
+        .route(&format!("{base}/Bulk"), post(scim_bulk))
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+        .merge(discovery)
+}
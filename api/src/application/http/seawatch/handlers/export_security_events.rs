@@ -0,0 +1,105 @@
+use axum::{
+    Extension,
+    body::Body,
+    extract::{Path, Query, State},
+    http::{
+        HeaderValue,
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    },
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    seawatch::{ExportFormat, ports::SecurityEventService, value_objects::ExportEventsInput},
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::application::http::{
+    seawatch::validators::parse_event_types,
+    server::{
+        api_entities::api_error::{ApiError, ApiErrorResponse},
+        app_state::AppState,
+    },
+};
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportSecurityEventsQuery {
+    /// `ndjson` (default) or `csv`
+    pub format: Option<String>,
+    /// Comma-separated list of event types, e.g. `login_failure,user_deleted`
+    pub event_types: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Export Security Events",
+    description = "Streams every security event in the time range as NDJSON or CSV. The response is sent in chunks, so large ranges do not need to fit in memory.",
+    path = "/seawatch/v1/security-events/export",
+    tag = "seawatch",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ExportSecurityEventsQuery,
+    ),
+    responses(
+        (status = 200, description = "Security events export", content_type = "application/x-ndjson", body = String),
+        (status = 400, description = "Invalid format or event type", body = ApiErrorResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn export_security_events(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<ExportSecurityEventsQuery>,
+) -> Result<Response, ApiError> {
+    let format = match query.format {
+        Some(format) => {
+            ExportFormat::try_from(format).map_err(|e| ApiError::BadRequest(e.into()))?
+        }
+        None => ExportFormat::Ndjson,
+    };
+
+    let stream = state
+        .service
+        .export_events(
+            identity,
+            ExportEventsInput {
+                realm_name: realm_name.clone(),
+                format,
+                event_types: parse_event_types(query.event_types)?,
+                from_timestamp: query.from,
+                to_timestamp: query.to,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    let disposition = format!(
+        "attachment; filename=\"{}-security-events.{}\"",
+        realm_name,
+        format.file_extension()
+    );
+
+    Ok((
+        [
+            (
+                CONTENT_TYPE,
+                HeaderValue::from_static(format.content_type()),
+            ),
+            (
+                CONTENT_DISPOSITION,
+                HeaderValue::from_str(&disposition)
+                    .unwrap_or_else(|_| HeaderValue::from_static("attachment")),
+            ),
+        ],
+        Body::from_stream(stream),
+    )
+        .into_response())
+}
@@ -0,0 +1,88 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use chrono::{DateTime, Utc};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    seawatch::{
+        AdminEventFilter, AdminEventPage, AdminOperation, SecurityEventCursor,
+        ports::SecurityEventService, value_objects::FetchAdminEventsInput,
+    },
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetAdminEventsQuery {
+    pub actor_id: Option<Uuid>,
+    /// Dotted resource type, e.g. `clients.redirects`
+    pub resource_type: Option<String>,
+    pub resource_id: Option<String>,
+    pub operation: Option<AdminOperation>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub limit: Option<u32>,
+    /// Opaque cursor returned as `next_cursor` by the previous page
+    pub cursor: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get Admin Events",
+    path = "/seawatch/v1/admin-events",
+    tag = "seawatch",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetAdminEventsQuery,
+    ),
+    responses(
+        (status = 200, description = "Admin events retrieved successfully", body = AdminEventPage),
+        (status = 400, description = "Invalid cursor", body = ApiErrorResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn get_admin_events(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetAdminEventsQuery>,
+) -> Result<Response<AdminEventPage>, ApiError> {
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(SecurityEventCursor::decode)
+        .transpose()
+        .map_err(ApiError::from)?;
+
+    let filter = AdminEventFilter {
+        actor_id: query.actor_id,
+        resource_type: query.resource_type,
+        resource_id: query.resource_id,
+        operation: query.operation,
+        from_timestamp: query.from,
+        to_timestamp: query.to,
+        limit: query.limit.or(Some(100)),
+        cursor,
+    };
+
+    let page = state
+        .service
+        .fetch_admin_events(identity, FetchAdminEventsInput { realm_name, filter })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(page))
+}
@@ -0,0 +1,48 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    seawatch::{
+        SecurityEventRetentionPolicy, ports::SecurityEventService,
+        value_objects::GetRetentionPolicyInput,
+    },
+};
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    summary = "Get security event retention policy",
+    path = "/seawatch/v1/retention",
+    tag = "seawatch",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Retention policy retrieved successfully", body = SecurityEventRetentionPolicy),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn get_retention_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<SecurityEventRetentionPolicy>, ApiError> {
+    let policy = state
+        .service
+        .get_retention_policy(identity, GetRetentionPolicyInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(policy))
+}
@@ -1,25 +1,49 @@
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
+use chrono::{DateTime, Utc};
 use ferriskey_core::domain::{
     authentication::value_objects::Identity,
-    seawatch::{SecurityEvent, ports::SecurityEventService, value_objects::FetchEventsInput},
+    seawatch::{
+        SecurityEvent, SecurityEventCursor, SecurityEventFilter, ports::SecurityEventService,
+        value_objects::FetchEventsInput,
+    },
 };
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
-use crate::application::http::server::{
-    api_entities::{
-        api_error::{ApiError, ApiErrorResponse},
-        response::Response,
+use crate::application::http::{
+    seawatch::validators::parse_event_types,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse},
+            response::Response,
+        },
+        app_state::AppState,
     },
-    app_state::AppState,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub struct GetSecurityEventsResponse {
     data: Vec<SecurityEvent>,
+    /// Pass back as `cursor` to fetch the next page; absent on the last page.
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetSecurityEventsQuery {
+    /// Comma-separated list of event types, e.g. `login_failure,user_deleted`
+    pub event_types: Option<String>,
+    pub actor_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub limit: Option<u32>,
+    /// Opaque cursor returned as `next_cursor` by the previous page
+    pub cursor: Option<String>,
 }
 
 #[utoipa::path(
@@ -29,9 +53,11 @@ pub struct GetSecurityEventsResponse {
     tag = "seawatch",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
+        GetSecurityEventsQuery,
     ),
     responses(
         (status = 200, description = "Security events retrieved successfully", body = GetSecurityEventsResponse),
+        (status = 400, description = "Invalid cursor or event type", body = ApiErrorResponse),
         (status = 401, description = "Realm not found", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
@@ -41,14 +67,34 @@ pub async fn get_security_events(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    Query(query): Query<GetSecurityEventsQuery>,
 ) -> Result<Response<GetSecurityEventsResponse>, ApiError> {
-    let security_events = state
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(SecurityEventCursor::decode)
+        .transpose()
+        .map_err(ApiError::from)?;
+
+    let filter = SecurityEventFilter {
+        actor_id: query.actor_id,
+        event_types: parse_event_types(query.event_types)?,
+        from_timestamp: query.from,
+        to_timestamp: query.to,
+        ip_address: query.ip_address,
+        limit: query.limit.or(Some(100)),
+        cursor,
+        ..Default::default()
+    };
+
+    let page = state
         .service
-        .fetch_events(identity, FetchEventsInput { realm_name })
+        .fetch_events(identity, FetchEventsInput { realm_name, filter })
         .await
         .map_err(ApiError::from)?;
 
     Ok(Response::OK(GetSecurityEventsResponse {
-        data: security_events,
+        data: page.data,
+        next_cursor: page.next_cursor,
     }))
 }
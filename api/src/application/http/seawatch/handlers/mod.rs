@@ -1 +1,6 @@
+pub mod export_security_events;
+pub mod get_admin_events;
+pub mod get_retention_policy;
 pub mod get_security_events;
+pub mod update_retention_policy;
+pub mod verify_security_event_chain;
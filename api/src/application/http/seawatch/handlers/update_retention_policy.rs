@@ -0,0 +1,61 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    seawatch::{
+        SecurityEventRetentionPolicy, ports::SecurityEventService,
+        value_objects::UpdateRetentionPolicyInput,
+    },
+};
+
+use crate::application::http::{
+    seawatch::validators::UpdateRetentionPolicyValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    summary = "Update security event retention policy",
+    description = "Sets how many days security events are kept for this realm. Older events are deleted by the background purge job; a null value keeps events forever.",
+    path = "/seawatch/v1/retention",
+    tag = "seawatch",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body = UpdateRetentionPolicyValidator,
+    responses(
+        (status = 200, description = "Retention policy updated successfully", body = SecurityEventRetentionPolicy),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 422, description = "Invalid retention window"),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn update_retention_policy(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateRetentionPolicyValidator>,
+) -> Result<Response<SecurityEventRetentionPolicy>, ApiError> {
+    let policy = state
+        .service
+        .update_retention_policy(
+            identity,
+            UpdateRetentionPolicyInput {
+                realm_name,
+                retention_days: payload.retention_days,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(policy))
+}
@@ -0,0 +1,48 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    seawatch::{
+        ChainVerificationReport, ports::SecurityEventService, value_objects::VerifyChainInput,
+    },
+};
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    summary = "Verify the security event hash chain",
+    description = "Walks the tamper-evident hash chain of the realm's security events, checks it against the signed checkpoints and reports the first broken link, if any.",
+    path = "/seawatch/v1/security-events/verify",
+    tag = "seawatch",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Chain verification completed", body = ChainVerificationReport),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn verify_security_event_chain(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ChainVerificationReport>, ApiError> {
+    let report = state
+        .service
+        .verify_chain(identity, VerifyChainInput { realm_name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(report))
+}
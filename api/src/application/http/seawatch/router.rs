@@ -4,15 +4,29 @@ use utoipa::OpenApi;
 use crate::application::{
     auth::auth,
     http::{
-        seawatch::handlers::get_security_events::{
-            __path_get_security_events, get_security_events,
+        seawatch::handlers::{
+            export_security_events::{__path_export_security_events, export_security_events},
+            get_admin_events::{__path_get_admin_events, get_admin_events},
+            get_retention_policy::{__path_get_retention_policy, get_retention_policy},
+            get_security_events::{__path_get_security_events, get_security_events},
+            update_retention_policy::{__path_update_retention_policy, update_retention_policy},
+            verify_security_event_chain::{
+                __path_verify_security_event_chain, verify_security_event_chain,
+            },
         },
         server::app_state::AppState,
     },
 };
 
 #[derive(OpenApi)]
-#[openapi(paths(get_security_events))]
+#[openapi(paths(
+    get_security_events,
+    export_security_events,
+    get_retention_policy,
+    update_retention_policy,
+    verify_security_event_chain,
+    get_admin_events
+))]
 pub struct SeawatchApiDoc;
 
 pub fn seawatch_router(state: AppState) -> Router<AppState> {
@@ -24,5 +38,33 @@ pub fn seawatch_router(state: AppState) -> Router<AppState> {
             ),
             get(get_security_events),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/seawatch/v1/security-events/export",
+                state.args.server.root_path
+            ),
+            get(export_security_events),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/seawatch/v1/security-events/verify",
+                state.args.server.root_path
+            ),
+            get(verify_security_event_chain),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/seawatch/v1/retention",
+                state.args.server.root_path
+            ),
+            get(get_retention_policy).put(update_retention_policy),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/seawatch/v1/admin-events",
+                state.args.server.root_path
+            ),
+            get(get_admin_events),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
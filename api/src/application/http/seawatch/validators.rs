@@ -0,0 +1,37 @@
+use ferriskey_core::domain::seawatch::SecurityEventType;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::application::http::server::api_entities::api_error::ApiError;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateRetentionPolicyValidator {
+    #[validate(range(
+        min = 1,
+        max = 36500,
+        message = "retention_days must be between 1 and 36500"
+    ))]
+    #[serde(default)]
+    pub retention_days: Option<u32>,
+}
+
+/// Parses the comma-separated `event_types` query parameter.
+pub fn parse_event_types(
+    value: Option<String>,
+) -> Result<Option<Vec<SecurityEventType>>, ApiError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            SecurityEventType::try_from(s.to_string())
+                .map_err(|e| ApiError::validation_error(e, "event_types"))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
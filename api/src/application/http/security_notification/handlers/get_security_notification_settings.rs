@@ -0,0 +1,61 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    security_notification::{
+        entities::SecurityNotificationSettings, ports::SecurityNotificationService,
+        value_objects::GetSecurityNotificationSettingsInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct SecurityNotificationSettingsResponse {
+    pub data: SecurityNotificationSettings,
+}
+
+#[utoipa::path(
+    get,
+    path = "/security-notifications",
+    tag = "security-notification",
+    summary = "Get security notification settings",
+    description = "Returns which account changes send a notification email to the user. Every notification is enabled until the realm configures them.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    responses(
+        (status = 200, description = "Security notification settings retrieved successfully", body = SecurityNotificationSettingsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn get_security_notification_settings(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<SecurityNotificationSettingsResponse>, ApiError> {
+    let settings = state
+        .service
+        .get_security_notification_settings(
+            identity,
+            GetSecurityNotificationSettingsInput { realm_name },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(SecurityNotificationSettingsResponse {
+        data: settings,
+    }))
+}
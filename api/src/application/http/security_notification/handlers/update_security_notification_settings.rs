@@ -0,0 +1,71 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    security_notification::{
+        ports::SecurityNotificationService, value_objects::UpdateSecurityNotificationSettingsInput,
+    },
+};
+
+use crate::application::http::{
+    security_notification::{
+        handlers::get_security_notification_settings::SecurityNotificationSettingsResponse,
+        validators::UpdateSecurityNotificationSettingsValidator,
+    },
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/security-notifications",
+    tag = "security-notification",
+    summary = "Update security notification settings",
+    description = "Enables or disables the notification email sent for each kind of account change. Omitted toggles are left unchanged.",
+    params(
+        ("realm_name" = String, Path, description = "Name of the realm"),
+    ),
+    request_body = UpdateSecurityNotificationSettingsValidator,
+    responses(
+        (status = 200, description = "Security notification settings updated successfully", body = SecurityNotificationSettingsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn update_security_notification_settings(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateSecurityNotificationSettingsValidator>,
+) -> Result<Response<SecurityNotificationSettingsResponse>, ApiError> {
+    let settings = state
+        .service
+        .update_security_notification_settings(
+            identity,
+            UpdateSecurityNotificationSettingsInput {
+                realm_name,
+                password_changed: payload.password_changed,
+                new_sign_in: payload.new_sign_in,
+                mfa_added: payload.mfa_added,
+                mfa_removed: payload.mfa_removed,
+                recovery_codes_regenerated: payload.recovery_codes_regenerated,
+                account_locked: payload.account_locked,
+                email_changed: payload.email_changed,
+                suspicious_sign_in: payload.suspicious_sign_in,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Updated(SecurityNotificationSettingsResponse {
+        data: settings,
+    }))
+}
@@ -0,0 +1,36 @@
+use axum::{Router, middleware, routing::get};
+use utoipa::OpenApi;
+
+use crate::application::{
+    auth::auth,
+    http::{
+        security_notification::handlers::{
+            get_security_notification_settings::{
+                __path_get_security_notification_settings, get_security_notification_settings,
+            },
+            update_security_notification_settings::{
+                __path_update_security_notification_settings, update_security_notification_settings,
+            },
+        },
+        server::app_state::AppState,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_security_notification_settings,
+    update_security_notification_settings,
+))]
+pub struct SecurityNotificationApiDoc;
+
+pub fn security_notification_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/security-notifications",
+                state.args.server.root_path
+            ),
+            get(get_security_notification_settings).put(update_security_notification_settings),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Toggles left out keep their current value.
+#[derive(Debug, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateSecurityNotificationSettingsValidator {
+    pub password_changed: Option<bool>,
+    pub new_sign_in: Option<bool>,
+    pub mfa_added: Option<bool>,
+    pub mfa_removed: Option<bool>,
+    pub recovery_codes_regenerated: Option<bool>,
+    pub account_locked: Option<bool>,
+    pub email_changed: Option<bool>,
+    pub suspicious_sign_in: Option<bool>,
+}
@@ -1,4 +1,5 @@
 pub mod api_error;
 pub mod api_response_body;
 pub mod api_success;
+pub mod pagination;
 pub mod response;
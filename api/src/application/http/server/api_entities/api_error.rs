@@ -34,6 +34,7 @@ pub enum ApiError {
     Forbidden(Cow<'static, str>),
     BadRequest(Cow<'static, str>),
     ServiceUnavailable(Cow<'static, str>),
+    TooManyRequests(Cow<'static, str>),
     /// RFC 6749 §5.2 OAuth2 error response
     OAuthError {
         error: Cow<'static, str>,
@@ -156,6 +157,8 @@ impl From<JwtError> for ApiError {
             JwtError::ExpiredToken => Self::InternalServerError("Token expired".into()),
             JwtError::InvalidKey(e) => Self::InternalServerError(e.into()),
             JwtError::ParsingError(e) => Self::InternalServerError(e.into()),
+            JwtError::EncryptionError(e) => Self::InternalServerError(e.into()),
+            JwtError::DecryptionError(e) => Self::InternalServerError(e.into()),
             JwtError::RealmKeyNotFound => Self::InternalServerError("Realm key not found".into()),
         }
     }
@@ -255,6 +258,15 @@ impl IntoResponse for ApiError {
                 }),
             )
                 .into_response(),
+            ApiError::TooManyRequests(message) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(ApiErrorResponse {
+                    code: "E_TOO_MANY_REQUESTS".to_string(),
+                    status: 429,
+                    message: message.into(),
+                }),
+            )
+                .into_response(),
             ApiError::OAuthError {
                 error,
                 error_description,
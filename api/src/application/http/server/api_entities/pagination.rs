@@ -0,0 +1,42 @@
+use ferriskey_core::domain::common::entities::listing::{ListCursor, PageRequest, SortDirection};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::api_error::ApiError;
+
+/// Query parameters shared by the paginated listings.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PaginationQuery {
+    /// Page size, 100 by default and at most 1000
+    pub limit: Option<u32>,
+    /// Number of items to skip; ignored when `cursor` is set
+    pub offset: Option<u32>,
+    /// Opaque cursor returned as `next_cursor` by the previous page
+    pub cursor: Option<String>,
+    pub direction: Option<SortDirection>,
+}
+
+impl TryFrom<PaginationQuery> for PageRequest {
+    type Error = ApiError;
+
+    fn try_from(query: PaginationQuery) -> Result<Self, Self::Error> {
+        let cursor = query
+            .cursor
+            .as_deref()
+            .map(ListCursor::decode)
+            .transpose()?;
+
+        Ok(PageRequest {
+            limit: query.limit,
+            offset: query.offset,
+            cursor,
+            direction: query.direction.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct CountResponse {
+    pub count: u64,
+}
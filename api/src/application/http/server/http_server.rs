@@ -1,19 +1,29 @@
 use std::sync::Arc;
 
+use crate::application::audit::admin_audit;
 use crate::application::http::abyss::routes::abyss_routes;
+use crate::application::http::account::router::account_routes;
 use crate::application::http::aegis::router::aegis_routes;
+use crate::application::http::auth_flow::router::auth_flow_routes;
 use crate::application::http::authentication::router::authentication_routes;
 use crate::application::http::broker::router::broker_routes;
 use crate::application::http::client::router::client_routes;
 use crate::application::http::compass::router::compass_routes;
+use crate::application::http::email_outbox::router::email_outbox_router;
 use crate::application::http::email_template::router::email_template_routes;
+use crate::application::http::housekeeping::router::housekeeping_router;
+use crate::application::http::localization::router::localization_routes;
 use crate::application::http::maintenance::router::maintenance_routes;
 use crate::application::http::organization::router::organization_routes;
+use crate::application::http::otp::router::otp_policy_routes;
 use crate::application::http::portal_layouts::router::portal_layouts_routes;
 use crate::application::http::portal_theme::router::portal_theme_routes;
 use crate::application::http::realm::router::realm_routes;
+use crate::application::http::risk::router::risk_policy_routes;
 use crate::application::http::role::router::role_routes;
+use crate::application::http::scim::router::scim_routes;
 use crate::application::http::seawatch::router::seawatch_router;
+use crate::application::http::security_notification::router::security_notification_routes;
 use crate::application::http::server::app_state::AppState;
 use crate::application::http::server::openapi::ApiDoc;
 use crate::application::http::trident::router::trident_routes;
@@ -24,10 +34,10 @@ use crate::args::Args;
 use super::config::get_config;
 use crate::application::http::health::health_routes;
 use anyhow::Context;
-use axum::Router;
 use axum::http::header::{ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, LOCATION};
 use axum::http::{HeaderValue, Method};
 use axum::routing::get;
+use axum::{Router, middleware};
 use axum_cookie::prelude::*;
 use axum_prometheus::PrometheusMetricLayer;
 use ferriskey_core::application::create_service;
@@ -131,19 +141,29 @@ pub fn router(state: AppState) -> Result<Router, anyhow::Error> {
         .merge(maintenance_routes(state.clone()))
         .merge(email_template_routes(state.clone()))
         .merge(portal_theme_routes(state.clone()))
+        .merge(localization_routes(state.clone()))
+        .merge(security_notification_routes(state.clone()))
+        .merge(otp_policy_routes(state.clone()))
+        .merge(auth_flow_routes(state.clone()))
+        .merge(risk_policy_routes(state.clone()))
+        .merge(account_routes(state.clone()))
         .merge(portal_layouts_routes(state.clone()))
         .merge(trident_routes(state.clone()))
         .merge(seawatch_router(state.clone()))
+        .merge(housekeeping_router(state.clone()))
+        .merge(email_outbox_router(state.clone()))
         .merge(compass_routes(state.clone()))
         .merge(abyss_routes(state.clone()))
         .merge(aegis_routes(state.clone()))
         .merge(broker_routes(state.clone(), &root_path))
         .merge(organization_routes(state.clone()))
+        .merge(scim_routes(state.clone()))
         .merge(health_routes(&root_path))
         .route(
             &format!("{}/metrics", root_path),
             get(|| async move { metric_handle.render() }),
         )
+        .layer(middleware::from_fn_with_state(state.clone(), admin_audit))
         .layer(trace_layer)
         .layer(cors)
         .layer(CookieLayer::default())
@@ -1,18 +1,27 @@
 use crate::application::http::{
     abyss::AbyssApiDoc,
+    account::router::AccountApiDoc,
     aegis::router::AegisApiDoc,
+    auth_flow::router::AuthFlowApiDoc,
     authentication::router::AuthenticationApiDoc,
     broker::BrokerApiDoc,
     client::router::ClientApiDoc,
     compass::router::CompassApiDoc,
+    email_outbox::router::EmailOutboxApiDoc,
     email_template::router::{EmailTemplateApiDoc, EmailTemplateVariablesApiDoc},
+    housekeeping::router::HousekeepingApiDoc,
+    localization::router::{LocalizationApiDoc, LocalizationPublicApiDoc},
     maintenance::router::MaintenanceApiDoc,
     organization::router::OrganizationApiDoc,
+    otp::router::OtpPolicyApiDoc,
     portal_layouts::router::{PortalLayoutsApiDoc, PortalLayoutsPublicApiDoc},
     portal_theme::router::{PortalThemeApiDoc, PortalThemePublicApiDoc},
     realm::router::RealmApiDoc,
+    risk::router::RiskPolicyApiDoc,
     role::router::RoleApiDoc,
+    scim::router::ScimApiDoc,
     seawatch::router::SeawatchApiDoc,
+    security_notification::router::SecurityNotificationApiDoc,
     trident::router::TridentApiDoc,
     user::router::UserApiDoc,
     webhook::router::WebhookApiDoc,
@@ -34,6 +43,8 @@ use utoipa::OpenApi;
         (path = "/realms/{realm_name}/webhooks", api = WebhookApiDoc),
         (path = "/realms/{realm_name}", api = TridentApiDoc),
         (path = "/realms/{realm_name}", api = SeawatchApiDoc),
+        (path = "/realms/{realm_name}", api = HousekeepingApiDoc),
+        (path = "/realms/{realm_name}", api = EmailOutboxApiDoc),
         (path = "/realms/{realm_name}", api = AbyssApiDoc),
         (path = "/realms/{realm_name}", api = BrokerApiDoc),
         (path = "/realms/{realm_name}", api = AegisApiDoc),
@@ -41,11 +52,19 @@ use utoipa::OpenApi;
         (path = "/realms/{realm_name}/email-templates", api = EmailTemplateApiDoc),
         (path = "/realms/{realm_name}", api = PortalThemeApiDoc),
         (path = "/realms/{realm_name}/portal", api = PortalThemePublicApiDoc),
+        (path = "/realms/{realm_name}", api = LocalizationApiDoc),
+        (path = "/realms/{realm_name}/portal", api = LocalizationPublicApiDoc),
+        (path = "/realms/{realm_name}", api = SecurityNotificationApiDoc),
+        (path = "/realms/{realm_name}", api = OtpPolicyApiDoc),
+        (path = "/realms/{realm_name}", api = AuthFlowApiDoc),
+        (path = "/realms/{realm_name}", api = RiskPolicyApiDoc),
+        (path = "/realms/{realm_name}", api = AccountApiDoc),
         (path = "/realms/{realm_name}/portal-layouts", api = PortalLayoutsApiDoc),
         (path = "/realms/{realm_name}/portal-layouts/public", api = PortalLayoutsPublicApiDoc),
         (path = "/email-templates/variables", api = EmailTemplateVariablesApiDoc),
         (path = "/realms/{realm_name}/organizations", api = OrganizationApiDoc),
-        (path = "/realms/{realm_name}/clients", api = MaintenanceApiDoc)
+        (path = "/realms/{realm_name}/clients", api = MaintenanceApiDoc),
+        (path = "/realms/{realm_name}/scim/v2", api = ScimApiDoc)
     )
 )]
 pub struct ApiDoc;
@@ -1,3 +1,4 @@
 pub mod handlers;
 pub mod router;
+pub mod trusted_device;
 pub mod validators;
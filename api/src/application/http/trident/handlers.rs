@@ -3,6 +3,8 @@ pub mod challenge_otp;
 pub mod forgot_password;
 pub mod generate_recovery_codes;
 pub mod magic_link;
+pub mod one_time_code;
+pub mod one_time_code_enrollment;
 pub mod passkey_authenticate;
 pub mod passkey_request_options;
 pub mod reset_password;
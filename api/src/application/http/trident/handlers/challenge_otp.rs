@@ -1,3 +1,4 @@
+use crate::application::audit::client_ip;
 use crate::application::http::server::{
     api_entities::{
         api_error::{ApiError, ApiErrorResponse, ValidateJson},
@@ -5,18 +6,35 @@ use crate::application::http::server::{
     },
     app_state::AppState,
 };
-use axum::{Extension, extract::State};
+use crate::application::http::trident::{
+    trusted_device::trust_device_cookie, validators::TrustDeviceQuery,
+};
+use crate::application::url::FullUrl;
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    http::{
+        HeaderMap,
+        header::{SET_COOKIE, USER_AGENT},
+    },
+    response::{AppendHeaders, IntoResponse},
+};
 use axum_cookie::CookieManager;
+use ferriskey_core::domain::auth_flow::entities::Authenticator;
 use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::security_notification::{
+    ports::SecurityNotificationService, value_objects::RecordSignInInput,
+};
 use ferriskey_core::domain::trident::ports::{ChallengeOtpInput, TridentService};
 use ferriskey_core::domain::user::entities::RequiredAction;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 use utoipa::ToSchema;
 use validator::Validate;
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct ChallengeOtpRequest {
-    #[validate(length(min = 6, max = 6, message = "OTP code must be exactly 6 digits"))]
+    #[validate(length(min = 6, max = 8, message = "OTP code must be 6 to 8 digits"))]
     #[serde(default)]
     pub code: String,
 }
@@ -27,6 +45,9 @@ pub struct ChallengeOtpResponse {
     pub url: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub required_actions: Vec<RequiredAction>,
+    /// Authenticators the user can pass next when the flow has more steps.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub methods: Vec<Authenticator>,
 }
 
 #[utoipa::path(
@@ -38,26 +59,36 @@ pub struct ChallengeOtpResponse {
     request_body = ChallengeOtpRequest,
     params(
         ("realm_name" = String, Path, description = "Name of the realm"),
+        TrustDeviceQuery,
     ),
     responses(
-        (status = 200, description = "Successfully challenged OTP", body = ChallengeOtpResponse),
+        (status = 200, description = "Successfully challenged OTP. Sets the trusted device cookie when asked to and the realm allows it", body = ChallengeOtpResponse),
         (status = 400, description = "Invalid request payload", body = ApiErrorResponse),
         (status = 401, description = "Missing or invalid session cookie", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
     )
 )]
 pub async fn challenge_otp(
+    Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    Query(query): Query<TrustDeviceQuery>,
     cookie: CookieManager,
+    headers: HeaderMap,
     ValidateJson(payload): ValidateJson<ChallengeOtpRequest>,
-) -> Result<Response<ChallengeOtpResponse>, ApiError> {
+) -> Result<impl IntoResponse, ApiError> {
     let session_code = cookie
         .get("FERRISKEY_SESSION")
         .ok_or_else(|| ApiError::Unauthorized("Missing session cookie".into()))? // Ou un type d'erreur 401/403
         .value()
         .to_string();
 
+    let user_id = match &identity {
+        Identity::User(user) => Some(user.id),
+        _ => None,
+    };
+
     let result = state
         .service
         .challenge_otp(
@@ -70,10 +101,51 @@ pub async fn challenge_otp(
         .await
         .map_err(|e| ApiError::InternalServerError(e.to_string().into()))?;
 
+    let mut trusted_device_cookie = None;
+    if let (Some(user_id), Some(_)) = (user_id, &result.login_url) {
+        if query.trust_device {
+            trusted_device_cookie = trust_device_cookie(
+                &state,
+                &realm_name,
+                user_id,
+                &headers,
+                base_url.starts_with("https://"),
+            )
+            .await;
+        }
+
+        let user_agent = headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        if let Err(e) = state
+            .service
+            .record_sign_in(RecordSignInInput {
+                realm_name: realm_name.clone(),
+                user_id,
+                ip_address: client_ip(&headers),
+                user_agent,
+            })
+            .await
+        {
+            warn!(
+                user_id = %user_id,
+                realm = %realm_name,
+                error = %e,
+                "Failed to record sign-in origin"
+            );
+        }
+    }
+
     let response = ChallengeOtpResponse {
         url: result.login_url,
         required_actions: result.required_actions,
+        methods: result.methods,
     };
 
-    Ok(Response::OK(response))
+    Ok((
+        AppendHeaders(trusted_device_cookie.map(|value| (SET_COOKIE, value))),
+        Response::OK(response),
+    ))
 }
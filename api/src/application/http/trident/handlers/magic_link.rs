@@ -132,6 +132,7 @@ pub async fn verify_magic_link(
         required_actions: None,
         token: None,
         message: Some("Magic link authentication successful".to_string()),
+        methods: None,
     };
 
     Ok((
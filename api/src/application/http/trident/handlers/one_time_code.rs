@@ -0,0 +1,209 @@
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    http::{
+        HeaderMap,
+        header::{SET_COOKIE, USER_AGENT},
+    },
+    response::{AppendHeaders, IntoResponse},
+};
+use axum_cookie::CookieManager;
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    one_time_code::{
+        entities::OneTimeCodeMethod,
+        ports::OneTimeCodeService,
+        value_objects::{ChallengeOneTimeCodeInput, SendOneTimeCodeInput},
+    },
+    security_notification::{ports::SecurityNotificationService, value_objects::RecordSignInInput},
+};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use utoipa::ToSchema;
+
+use crate::application::{
+    audit::client_ip,
+    http::{
+        server::{
+            api_entities::{
+                api_error::{ApiError, ApiErrorResponse, ValidateJson},
+                response::Response,
+            },
+            app_state::AppState,
+        },
+        trident::{
+            handlers::{
+                challenge_otp::ChallengeOtpResponse,
+                one_time_code_enrollment::OneTimeCodeDeliveryResponse,
+            },
+            trusted_device::trust_device_cookie,
+            validators::{ChallengeOneTimeCodeRequest, SendOneTimeCodeRequest, TrustDeviceQuery},
+        },
+    },
+    url::FullUrl,
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct OneTimeCodeMethodsResponse {
+    pub data: Vec<OneTimeCodeMethod>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/login-actions/one-time-code-methods",
+    tag = "auth",
+    summary = "List the email and SMS second factors of the user",
+    description = "Lists the channels the signing-in user can receive a code on, with masked destinations, so the login page can offer them next to the authenticator app.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Enrolled channels", body = OneTimeCodeMethodsResponse),
+        (status = 401, description = "Unauthorized", body = ApiErrorResponse),
+    )
+)]
+pub async fn list_one_time_code_methods(
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<OneTimeCodeMethodsResponse>, ApiError> {
+    let methods = state
+        .service
+        .list_one_time_code_methods(identity)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(OneTimeCodeMethodsResponse { data: methods }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/send-one-time-code",
+    tag = "auth",
+    summary = "Send a sign-in code by email or SMS",
+    description = "Sends a sign-in code on one of the user's enrolled channels. Codes expire after a few minutes and can only be requested at a limited rate.",
+    request_body = SendOneTimeCodeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Code sent", body = OneTimeCodeDeliveryResponse),
+        (status = 403, description = "Channel not enrolled", body = ApiErrorResponse),
+        (status = 429, description = "Too many codes requested", body = ApiErrorResponse),
+        (status = 503, description = "Email or SMS delivery failed", body = ApiErrorResponse),
+    )
+)]
+pub async fn send_one_time_code(
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<SendOneTimeCodeRequest>,
+) -> Result<Response<OneTimeCodeDeliveryResponse>, ApiError> {
+    let delivery = state
+        .service
+        .send_one_time_code(
+            identity,
+            SendOneTimeCodeInput {
+                channel: payload.channel,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(OneTimeCodeDeliveryResponse { data: delivery }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/challenge-one-time-code",
+    tag = "auth",
+    summary = "Complete the sign-in with an email or SMS code",
+    description = "Checks the code sent by send-one-time-code and completes the sign-in of the current session, like challenge-otp does for authenticator apps.",
+    request_body = ChallengeOneTimeCodeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        TrustDeviceQuery,
+    ),
+    responses(
+        (status = 200, description = "Code accepted. Sets the trusted device cookie when asked to and the realm allows it", body = ChallengeOtpResponse),
+        (status = 401, description = "Invalid or expired code, or missing session cookie", body = ApiErrorResponse),
+    )
+)]
+pub async fn challenge_one_time_code(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    Query(query): Query<TrustDeviceQuery>,
+    cookie: CookieManager,
+    headers: HeaderMap,
+    ValidateJson(payload): ValidateJson<ChallengeOneTimeCodeRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let session_code = cookie
+        .get("FERRISKEY_SESSION")
+        .ok_or_else(|| ApiError::Unauthorized("Missing session cookie".into()))?
+        .value()
+        .to_string();
+
+    let user_id = match &identity {
+        Identity::User(user) => Some(user.id),
+        _ => None,
+    };
+
+    let result = state
+        .service
+        .challenge_one_time_code(
+            identity,
+            ChallengeOneTimeCodeInput {
+                session_code,
+                channel: payload.channel,
+                code: payload.code,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    let mut trusted_device_cookie = None;
+    if let (Some(user_id), Some(_)) = (user_id, &result.login_url) {
+        if query.trust_device {
+            trusted_device_cookie = trust_device_cookie(
+                &state,
+                &realm_name,
+                user_id,
+                &headers,
+                base_url.starts_with("https://"),
+            )
+            .await;
+        }
+
+        let user_agent = headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        if let Err(e) = state
+            .service
+            .record_sign_in(RecordSignInInput {
+                realm_name: realm_name.clone(),
+                user_id,
+                ip_address: client_ip(&headers),
+                user_agent,
+            })
+            .await
+        {
+            warn!(
+                user_id = %user_id,
+                realm = %realm_name,
+                error = %e,
+                "Failed to record sign-in origin"
+            );
+        }
+    }
+
+    Ok((
+        AppendHeaders(trusted_device_cookie.map(|value| (SET_COOKIE, value))),
+        Response::OK(ChallengeOtpResponse {
+            url: result.login_url,
+            required_actions: result.required_actions,
+            methods: result.methods,
+        }),
+    ))
+}
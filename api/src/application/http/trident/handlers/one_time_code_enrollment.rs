@@ -0,0 +1,105 @@
+use axum::{Extension, extract::State};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    one_time_code::{
+        entities::{OneTimeCodeDelivery, OneTimeCodeMethod},
+        ports::OneTimeCodeService,
+        value_objects::{ConfirmOneTimeCodeEnrollmentInput, SendOneTimeCodeEnrollmentInput},
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::http::{
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+    trident::validators::{ConfirmEnrollmentCodeRequest, SendEnrollmentCodeRequest},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct OneTimeCodeDeliveryResponse {
+    pub data: OneTimeCodeDelivery,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct OneTimeCodeMethodResponse {
+    pub data: OneTimeCodeMethod,
+}
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/send-enrollment-code",
+    tag = "auth",
+    summary = "Send an email or SMS enrollment code",
+    description = "Sends a code to the user's email address, or to the given phone number, to prove they receive it before using the channel as a second factor.",
+    request_body = SendEnrollmentCodeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Code sent", body = OneTimeCodeDeliveryResponse),
+        (status = 400, description = "Invalid phone number", body = ApiErrorResponse),
+        (status = 429, description = "Too many codes requested", body = ApiErrorResponse),
+        (status = 503, description = "Email or SMS delivery failed", body = ApiErrorResponse),
+    )
+)]
+pub async fn send_enrollment_code(
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<SendEnrollmentCodeRequest>,
+) -> Result<Response<OneTimeCodeDeliveryResponse>, ApiError> {
+    let delivery = state
+        .service
+        .send_one_time_code_enrollment(
+            identity,
+            SendOneTimeCodeEnrollmentInput {
+                channel: payload.channel,
+                phone_number: payload.phone_number,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(OneTimeCodeDeliveryResponse { data: delivery }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/confirm-enrollment-code",
+    tag = "auth",
+    summary = "Confirm an email or SMS enrollment code",
+    description = "Checks the code sent by send-enrollment-code and enrolls its channel as a second factor, replacing a previously verified phone number.",
+    request_body = ConfirmEnrollmentCodeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Channel enrolled", body = OneTimeCodeMethodResponse),
+        (status = 401, description = "Invalid or expired code", body = ApiErrorResponse),
+    )
+)]
+pub async fn confirm_enrollment_code(
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ConfirmEnrollmentCodeRequest>,
+) -> Result<Response<OneTimeCodeMethodResponse>, ApiError> {
+    let method = state
+        .service
+        .confirm_one_time_code_enrollment(
+            identity,
+            ConfirmOneTimeCodeEnrollmentInput {
+                channel: payload.channel,
+                code: payload.code,
+                label: payload.label,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(OneTimeCodeMethodResponse { data: method }))
+}
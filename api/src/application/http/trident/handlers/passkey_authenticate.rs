@@ -1,7 +1,9 @@
 use axum::extract::{Path, State};
+use axum::http::{HeaderMap, header::USER_AGENT};
 use axum_cookie::CookieManager;
-use ferriskey_core::domain::trident::ports::{
-    PasskeyAuthenticateInput, PublicKeyCredential, TridentService,
+use ferriskey_core::domain::{
+    auth_flow::entities::Authenticator,
+    trident::ports::{PasskeyAuthenticateInput, PublicKeyCredential, TridentService},
 };
 use serde::{Deserialize, Serialize};
 use utoipa::{
@@ -10,7 +12,9 @@ use utoipa::{
 };
 use validator::Validate;
 
+use crate::application::audit::client_ip;
 use crate::application::http::{
+    authentication::handlers::auth::root_scoped_base_url,
     server::{
         api_entities::{
             api_error::{ApiError, ApiErrorResponse, ValidateJson},
@@ -18,8 +22,9 @@ use crate::application::http::{
         },
         app_state::AppState,
     },
-    trident::validators::webauthn_rp_info_from_webapp_url,
+    trident::{trusted_device::trusted_device_token, validators::webauthn_rp_info_from_webapp_url},
 };
+use crate::application::url::FullUrl;
 
 #[derive(Debug, Deserialize)]
 #[serde(transparent)]
@@ -49,8 +54,15 @@ impl PartialSchema for PasskeyAuthenticateRequest {
 
 #[derive(Debug, Serialize, ToSchema, PartialEq, Eq)]
 pub struct PasskeyAuthenticateResponse {
-    login_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    login_url: Option<String>,
     status: String,
+    /// Authenticators the user can pass next when the flow has more steps.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    methods: Vec<Authenticator>,
+    /// Token to pass the next authenticators with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temporary_token: Option<String>,
 }
 
 #[utoipa::path(
@@ -58,7 +70,7 @@ pub struct PasskeyAuthenticateResponse {
     path = "/login-actions/passkey-authenticate",
     tag = "auth",
     summary = "Authenticate using a passkey",
-    description = "Complete passkey authentication by submitting the browser's assertion response. On success, returns a login URL with an authorization code.",
+    description = "Complete passkey authentication by submitting the browser's assertion response. On success, returns a login URL with an authorization code, or the authenticators to pass next when the authentication flow has more steps.",
     request_body = PasskeyAuthenticateRequest,
     params(
         ("realm_name" = String, Path, description = "Name of the realm"),
@@ -67,13 +79,16 @@ pub struct PasskeyAuthenticateResponse {
         (status = 200, description = "Passkey authentication successful", body = PasskeyAuthenticateResponse),
         (status = 400, description = "Invalid request payload", body = ApiErrorResponse),
         (status = 401, description = "Missing or invalid session cookie", body = ApiErrorResponse),
+        (status = 403, description = "Sign-in blocked by the realm's risk policy", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
     )
 )]
 pub async fn passkey_authenticate(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
     cookie: CookieManager,
+    headers: HeaderMap,
     ValidateJson(payload): ValidateJson<PasskeyAuthenticateRequest>,
 ) -> Result<Response<PasskeyAuthenticateResponse>, ApiError> {
     let session_code = cookie
@@ -91,12 +106,27 @@ pub async fn passkey_authenticate(
             session_code,
             rp_info,
             credential: payload.0,
+            base_url: root_scoped_base_url(&base_url, &state.args.server.root_path),
+            ip_address: client_ip(&headers),
+            user_agent: headers
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            trusted_device_token: trusted_device_token(&cookie),
         })
         .await
         .map_err(ApiError::from)?;
 
+    let status = if output.login_url.is_some() {
+        "Success"
+    } else {
+        "RequiresChallenge"
+    };
+
     Ok(Response::OK(PasskeyAuthenticateResponse {
         login_url: output.login_url,
-        status: "Success".to_string(),
+        status: status.to_string(),
+        methods: output.methods,
+        temporary_token: output.temporary_token,
     }))
 }
@@ -1,4 +1,9 @@
-use axum::{Extension, extract::State};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+    http::{HeaderMap, header::SET_COOKIE},
+    response::{AppendHeaders, IntoResponse},
+};
 use axum_cookie::CookieManager;
 use ferriskey_core::domain::{
     authentication::value_objects::Identity,
@@ -10,15 +15,21 @@ use utoipa::{
     openapi::{ObjectBuilder, RefOr, Schema},
 };
 
-use crate::application::http::{
-    server::{
-        api_entities::{
-            api_error::{ApiError, ApiErrorResponse, ValidateJson},
-            response::Response,
+use crate::application::{
+    http::{
+        server::{
+            api_entities::{
+                api_error::{ApiError, ApiErrorResponse, ValidateJson},
+                response::Response,
+            },
+            app_state::AppState,
+        },
+        trident::{
+            trusted_device::trust_device_cookie,
+            validators::{TrustDeviceQuery, webauthn_rp_info_from_webapp_url},
         },
-        app_state::AppState,
     },
-    trident::validators::webauthn_rp_info_from_webapp_url,
+    url::FullUrl,
 };
 use validator::Validate;
 
@@ -61,9 +72,10 @@ pub struct AuthenticationAttemptResponse {
     request_body = AuthenticationAttemptRequest,
     params(
         ("realm_name" = String, Path, description = "Name of the realm"),
+        TrustDeviceQuery,
     ),
     responses(
-        (status = 200, description = "Authentication attempt successful", body = AuthenticationAttemptResponse),
+        (status = 200, description = "Authentication attempt successful. Sets the trusted device cookie when asked to and the realm allows it", body = AuthenticationAttemptResponse),
         (status = 400, description = "Invalid request payload", body = ApiErrorResponse),
         (status = 401, description = "Missing or invalid session cookie", body = ApiErrorResponse),
         (status = 403, description = "Identity not authorized", body = ApiErrorResponse),
@@ -71,11 +83,15 @@ pub struct AuthenticationAttemptResponse {
     )
 )]
 pub async fn webauthn_public_key_authenticate(
+    Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    Query(query): Query<TrustDeviceQuery>,
     cookie: CookieManager,
+    headers: HeaderMap,
     ValidateJson(payload): ValidateJson<AuthenticationAttemptRequest>,
-) -> Result<Response<AuthenticationAttemptResponse>, ApiError> {
+) -> Result<impl IntoResponse, ApiError> {
     let session_code = cookie
         .get("FERRISKEY_SESSION")
         .ok_or_else(|| ApiError::Unauthorized("Missing session cookie".into()))? // Ou un type d'erreur 401/403
@@ -83,6 +99,10 @@ pub async fn webauthn_public_key_authenticate(
         .to_string();
 
     let rp_info = webauthn_rp_info_from_webapp_url(&state.args.webapp_url);
+    let user_id = match &identity {
+        Identity::User(user) => Some(user.id),
+        _ => None,
+    };
 
     let output = state
         .service
@@ -97,7 +117,24 @@ pub async fn webauthn_public_key_authenticate(
         .await
         .map_err(ApiError::from)?;
 
-    Ok(Response::OK(AuthenticationAttemptResponse {
-        login_url: output.login_url,
-    }))
+    let trusted_device_cookie = match user_id {
+        Some(user_id) if query.trust_device => {
+            trust_device_cookie(
+                &state,
+                &realm_name,
+                user_id,
+                &headers,
+                base_url.starts_with("https://"),
+            )
+            .await
+        }
+        _ => None,
+    };
+
+    Ok((
+        AppendHeaders(trusted_device_cookie.map(|value| (SET_COOKIE, value))),
+        Response::OK(AuthenticationAttemptResponse {
+            login_url: output.login_url,
+        }),
+    ))
 }
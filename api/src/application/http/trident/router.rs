@@ -17,6 +17,15 @@ use crate::application::{
                 __path_send_magic_link, __path_verify_magic_link, send_magic_link,
                 verify_magic_link,
             },
+            one_time_code::{
+                __path_challenge_one_time_code, __path_list_one_time_code_methods,
+                __path_send_one_time_code, challenge_one_time_code, list_one_time_code_methods,
+                send_one_time_code,
+            },
+            one_time_code_enrollment::{
+                __path_confirm_enrollment_code, __path_send_enrollment_code,
+                confirm_enrollment_code, send_enrollment_code,
+            },
             passkey_authenticate::{__path_passkey_authenticate, passkey_authenticate},
             passkey_request_options::{__path_passkey_request_options, passkey_request_options},
             reset_password::{
@@ -47,6 +56,11 @@ use crate::application::{
     setup_otp,
     verify_otp,
     challenge_otp,
+    send_enrollment_code,
+    confirm_enrollment_code,
+    list_one_time_code_methods,
+    send_one_time_code,
+    challenge_one_time_code,
     update_password,
     burn_recovery_code,
     generate_recovery_codes,
@@ -140,6 +154,41 @@ pub fn trident_routes(state: AppState) -> Router<AppState> {
             ),
             post(challenge_otp),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/login-actions/send-enrollment-code",
+                state.args.server.root_path
+            ),
+            post(send_enrollment_code),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/login-actions/confirm-enrollment-code",
+                state.args.server.root_path
+            ),
+            post(confirm_enrollment_code),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/login-actions/one-time-code-methods",
+                state.args.server.root_path
+            ),
+            get(list_one_time_code_methods),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/login-actions/send-one-time-code",
+                state.args.server.root_path
+            ),
+            post(send_one_time_code),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/login-actions/challenge-one-time-code",
+                state.args.server.root_path
+            ),
+            post(challenge_one_time_code),
+        )
         .route(
             &format!(
                 "{}/realms/{{realm_name}}/login-actions/update-password",
@@ -0,0 +1,75 @@
+use axum::http::{HeaderMap, HeaderValue, header::USER_AGENT};
+use axum_cookie::CookieManager;
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use chrono::Utc;
+use ferriskey_core::domain::trusted_device::{
+    ports::TrustedDeviceService, value_objects::TrustDeviceInput,
+};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::application::{audit::client_ip, http::server::app_state::AppState};
+
+/// Cookie remembering a browser the user trusted, which skips their second
+/// factor until the realm's window runs out.
+pub const TRUSTED_DEVICE_COOKIE: &str = "FERRISKEY_TRUSTED_DEVICE";
+
+/// The token of the browser's trusted device cookie, if it has one.
+pub fn trusted_device_token(cookie: &CookieManager) -> Option<String> {
+    cookie
+        .get(TRUSTED_DEVICE_COOKIE)
+        .map(|value| value.value().to_string())
+}
+
+/// Trusts the browser the user just passed a second factor from, and
+/// returns the cookie remembering it. Like recording the sign-in origin,
+/// this is best-effort: the sign-in goes on without it.
+pub async fn trust_device_cookie(
+    state: &AppState,
+    realm_name: &str,
+    user_id: Uuid,
+    headers: &HeaderMap,
+    secure: bool,
+) -> Option<HeaderValue> {
+    let user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let issued = match state
+        .service
+        .trust_device(TrustDeviceInput {
+            realm_name: realm_name.to_string(),
+            user_id,
+            user_agent,
+            ip_address: client_ip(headers),
+        })
+        .await
+    {
+        Ok(issued) => issued?,
+        Err(e) => {
+            warn!(
+                user_id = %user_id,
+                realm = %realm_name,
+                error = %e,
+                "Failed to trust device"
+            );
+            return None;
+        }
+    };
+
+    let max_age = (issued.device.expires_at - Utc::now())
+        .to_std()
+        .unwrap_or_default();
+    let mut cookie = Cookie::build((TRUSTED_DEVICE_COOKIE, issued.token.to_string()))
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .max_age(max_age.try_into().unwrap_or_default());
+
+    if secure {
+        cookie = cookie.secure(true);
+    }
+
+    HeaderValue::from_str(&cookie.to_string()).ok()
+}
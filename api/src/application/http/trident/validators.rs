@@ -1,7 +1,9 @@
-use ferriskey_core::domain::trident::ports::WebAuthnRpInfo;
+use ferriskey_core::domain::{
+    one_time_code::entities::OneTimeCodeChannel, trident::ports::WebAuthnRpInfo,
+};
 use serde::{Deserialize, Serialize};
 use url::Url;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -11,6 +13,45 @@ pub struct OtpVerifyRequest {
     pub secret: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SendEnrollmentCodeRequest {
+    pub channel: OneTimeCodeChannel,
+    /// International number, required for the `sms` channel.
+    #[validate(length(max = 32, message = "phone number is too long"))]
+    pub phone_number: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ConfirmEnrollmentCodeRequest {
+    pub channel: OneTimeCodeChannel,
+    #[validate(length(min = 6, max = 6, message = "code must be exactly 6 digits"))]
+    pub code: String,
+    #[validate(length(max = 255, message = "label is too long"))]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SendOneTimeCodeRequest {
+    pub channel: OneTimeCodeChannel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ChallengeOneTimeCodeRequest {
+    pub channel: OneTimeCodeChannel,
+    #[validate(length(min = 6, max = 6, message = "code must be exactly 6 digits"))]
+    pub code: String,
+}
+
+/// Lets the user trust the browser when the second factor they pass
+/// completes the sign-in.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TrustDeviceQuery {
+    /// Skip the second factor from this browser for the realm's window.
+    #[serde(default)]
+    pub trust_device: bool,
+}
+
 /// Derives the WebAuthn Relying Party info from the webapp URL.
 ///
 /// The `rp_id` must be a valid domain that matches the origin,
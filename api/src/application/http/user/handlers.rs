@@ -1,17 +1,24 @@
 pub mod assign_role;
 pub mod bulk_delete_user;
+pub mod cancel_user_deletion;
+pub mod count_users;
 pub mod create_user;
 pub mod delete_credential;
 pub mod delete_user;
 pub mod delete_user_attribute;
+pub mod export_user_data;
 pub mod get_credentials;
 pub mod get_user;
 pub mod get_user_attributes;
+pub mod get_user_deletion;
 pub mod get_user_permissions;
 pub mod get_user_roles;
 pub mod get_users;
 pub mod list_user_organizations;
+pub mod list_user_trusted_devices;
 pub mod reset_password;
+pub mod revoke_user_trusted_device;
+pub mod schedule_user_deletion;
 pub mod set_user_attributes;
 pub mod unassign_role;
 pub mod update_user;
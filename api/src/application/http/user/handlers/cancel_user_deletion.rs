@@ -0,0 +1,63 @@
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    privacy::{ports::PrivacyService, value_objects::UserDataInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct CancelUserDeletionResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{user_id}/deletion",
+    tag = "user",
+    summary = "Cancel a user's deletion",
+    description = "Cancels the pending deletion of a user.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, description = "Deletion cancelled successfully", body = CancelUserDeletionResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "User not found or no deletion is pending", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn cancel_user_deletion(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<CancelUserDeletionResponse>, ApiError> {
+    state
+        .service
+        .cancel_account_deletion(
+            identity,
+            UserDataInput {
+                realm_name,
+                user_id: Some(user_id),
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(CancelUserDeletionResponse {
+        message: format!("Deletion of user {user_id} cancelled"),
+    }))
+}
@@ -0,0 +1,52 @@
+use crate::application::http::{
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse},
+            pagination::CountResponse,
+            response::Response,
+        },
+        app_state::AppState,
+    },
+    user::handlers::get_users::GetUsersQuery,
+};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::user::entities::GetUsersInput;
+use ferriskey_core::domain::user::ports::UserService;
+
+#[utoipa::path(
+    get,
+    path = "/count",
+    tag = "user",
+    summary = "Count users in a realm",
+    description = "Counts the users of a realm matching the same search and filters as the user listing.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetUsersQuery,
+    ),
+    responses(
+        (status = 200, description = "Users counted successfully", body = CountResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    )
+)]
+pub async fn count_users(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(query): Query<GetUsersQuery>,
+) -> Result<Response<CountResponse>, ApiError> {
+    let filter = query.into_filter(Default::default())?;
+
+    let count = state
+        .service
+        .count_users(identity, GetUsersInput { realm_name, filter })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(CountResponse { count }))
+}
@@ -52,6 +52,7 @@ pub async fn delete_user_credential(
         .delete_credential(
             identity,
             DeleteCredentialInput {
+                user_id,
                 credential_id,
                 realm_name: realm_name.clone(),
             },
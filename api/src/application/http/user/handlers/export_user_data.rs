@@ -0,0 +1,61 @@
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    privacy::{entities::UserDataExport, ports::PrivacyService, value_objects::UserDataInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct UserDataExportResponse {
+    pub data: UserDataExport,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{user_id}/export",
+    tag = "user",
+    summary = "Export a user's data",
+    description = "Returns everything stored about a user, to answer a data access request on their behalf. Secrets are never included.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, description = "Data exported successfully", body = UserDataExportResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "User not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn export_user_data(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<UserDataExportResponse>, ApiError> {
+    let export = state
+        .service
+        .export_user_data(
+            identity,
+            UserDataInput {
+                realm_name,
+                user_id: Some(user_id),
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(UserDataExportResponse { data: export }))
+}
@@ -1,36 +1,79 @@
 use crate::application::http::server::{
     api_entities::{
         api_error::{ApiError, ApiErrorResponse},
+        pagination::PaginationQuery,
         response::Response,
     },
     app_state::AppState,
 };
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 use ferriskey_core::domain::authentication::value_objects::Identity;
-use ferriskey_core::domain::user::entities::User;
+use ferriskey_core::domain::user::entities::{GetUsersInput, User};
 use ferriskey_core::domain::user::ports::UserService;
+use ferriskey_core::domain::user::value_objects::{UserFilter, UserSortField};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct UsersResponse {
     pub data: Vec<User>,
+    /// Pass back as `cursor` to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetUsersQuery {
+    /// Matched against username, email, first and last name
+    pub search: Option<String>,
+    pub enabled: Option<bool>,
+    pub email_verified: Option<bool>,
+    pub role_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    /// Only users having this attribute
+    pub attribute_key: Option<String>,
+    /// Value of `attribute_key` to match
+    pub attribute_value: Option<String>,
+    /// Only service account users when `true`, only regular users when `false`
+    pub service_account: Option<bool>,
+    pub sort: Option<UserSortField>,
+}
+
+impl GetUsersQuery {
+    pub fn into_filter(self, pagination: PaginationQuery) -> Result<UserFilter, ApiError> {
+        Ok(UserFilter {
+            search: self.search,
+            enabled: self.enabled,
+            email_verified: self.email_verified,
+            role_id: self.role_id,
+            organization_id: self.organization_id,
+            attribute_key: self.attribute_key,
+            attribute_value: self.attribute_value,
+            service_account: self.service_account,
+            sort: self.sort.unwrap_or_default(),
+            page: pagination.try_into()?,
+        })
+    }
 }
 
 #[utoipa::path(
     get,
     path = "",
     tag = "user",
-    summary = "Get all users in a realm",
-    description = "Retrieves all users associated with a specific realm.",
+    summary = "Get users in a realm",
+    description = "Retrieves one page of the users of a realm, optionally searched and filtered.",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
+        GetUsersQuery,
+        PaginationQuery,
     ),
     responses(
         (status = 200, description = "Users retrieved successfully", body = UsersResponse),
+        (status = 400, description = "Invalid cursor", body = ApiErrorResponse),
         (status = 401, description = "Realm not found", body = ApiErrorResponse),
         (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
         (status = 500, description = "Internal server error", body = ApiErrorResponse),
@@ -40,12 +83,19 @@ pub async fn get_users(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    Query(query): Query<GetUsersQuery>,
+    Query(pagination): Query<PaginationQuery>,
 ) -> Result<Response<UsersResponse>, ApiError> {
-    let users = state
+    let filter = query.into_filter(pagination)?;
+
+    let page = state
         .service
-        .get_users(identity, realm_name)
+        .get_users(identity, GetUsersInput { realm_name, filter })
         .await
         .map_err(ApiError::from)?;
 
-    Ok(Response::OK(UsersResponse { data: users }))
+    Ok(Response::OK(UsersResponse {
+        data: page.data,
+        next_cursor: page.next_cursor,
+    }))
 }
@@ -0,0 +1,65 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    trusted_device::{
+        entities::TrustedDevice, ports::TrustedDeviceService,
+        value_objects::ListTrustedDevicesInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct TrustedDevicesResponse {
+    pub data: Vec<TrustedDevice>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{user_id}/trusted-devices",
+    tag = "user",
+    summary = "List a user's trusted devices",
+    description = "Lists the browsers the user trusted during a sign-in, which skip the second factor until they expire.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, description = "Trusted devices retrieved successfully", body = TrustedDevicesResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "User not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn list_user_trusted_devices(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<TrustedDevicesResponse>, ApiError> {
+    let devices = state
+        .service
+        .list_trusted_devices(
+            identity,
+            ListTrustedDevicesInput {
+                realm_name,
+                user_id: Some(user_id),
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(TrustedDevicesResponse { data: devices }))
+}
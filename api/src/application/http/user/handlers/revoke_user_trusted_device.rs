@@ -0,0 +1,66 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    trusted_device::{ports::TrustedDeviceService, value_objects::RevokeTrustedDeviceInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::http::server::{
+    api_entities::{
+        api_error::{ApiError, ApiErrorResponse},
+        response::Response,
+    },
+    app_state::AppState,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RevokeTrustedDeviceResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{user_id}/trusted-devices/{device_id}",
+    tag = "user",
+    summary = "Revoke a user's trusted device",
+    description = "Stops a browser from skipping the second factor of the user. Their next sign-in from it asks for one again.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+        ("device_id" = Uuid, Path, description = "Trusted device ID"),
+    ),
+    responses(
+        (status = 200, description = "Trusted device revoked successfully", body = RevokeTrustedDeviceResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "User or trusted device not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn revoke_user_trusted_device(
+    Path((realm_name, user_id, device_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RevokeTrustedDeviceResponse>, ApiError> {
+    state
+        .service
+        .revoke_trusted_device(
+            identity,
+            RevokeTrustedDeviceInput {
+                realm_name,
+                user_id: Some(user_id),
+                device_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(RevokeTrustedDeviceResponse {
+        message: format!("Trusted device {device_id} revoked successfully"),
+    }))
+}
@@ -0,0 +1,75 @@
+use crate::application::http::{
+    server::{
+        api_entities::{
+            api_error::{ApiError, ApiErrorResponse, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+    user::validators::ScheduleUserDeletionValidator,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    privacy::{
+        entities::AccountDeletionRequest, ports::PrivacyService,
+        value_objects::RequestAccountDeletionInput,
+    },
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct UserDeletionResponse {
+    pub data: AccountDeletionRequest,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{user_id}/deletion",
+    tag = "user",
+    summary = "Schedule a user's deletion",
+    description = "Schedules the deletion of a user after a cooling-off period, on their behalf. When it runs, audit records about the user are pseudonymized rather than removed. Scheduling again returns the pending deletion.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    request_body(
+        content = ScheduleUserDeletionValidator,
+        description = "Cooling-off period before the deletion",
+        content_type = "application/json",
+    ),
+    responses(
+        (status = 200, description = "Deletion scheduled successfully", body = UserDeletionResponse),
+        (status = 400, description = "Invalid request body", body = ApiErrorResponse),
+        (status = 401, description = "Realm not found", body = ApiErrorResponse),
+        (status = 403, description = "Insufficient permissions", body = ApiErrorResponse),
+        (status = 404, description = "User not found", body = ApiErrorResponse),
+        (status = 500, description = "Internal server error", body = ApiErrorResponse),
+    ),
+)]
+pub async fn schedule_user_deletion(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<ScheduleUserDeletionValidator>,
+) -> Result<Response<UserDeletionResponse>, ApiError> {
+    let request = state
+        .service
+        .request_account_deletion(
+            identity,
+            RequestAccountDeletionInput {
+                realm_name,
+                user_id: Some(user_id),
+                cooling_off_days: payload.cooling_off_days,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(UserDeletionResponse { data: request }))
+}
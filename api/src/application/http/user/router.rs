@@ -10,18 +10,25 @@ use crate::application::http::server::app_state::AppState;
 use super::handlers::{
     assign_role::{__path_assign_role, assign_role},
     bulk_delete_user::{__path_bulk_delete_user, bulk_delete_user},
+    cancel_user_deletion::{__path_cancel_user_deletion, cancel_user_deletion},
+    count_users::{__path_count_users, count_users},
     create_user::{__path_create_user, create_user},
     delete_credential::{__path_delete_user_credential, delete_user_credential},
     delete_user::{__path_delete_user, delete_user},
     delete_user_attribute::{__path_delete_user_attribute, delete_user_attribute},
+    export_user_data::{__path_export_user_data, export_user_data},
     get_credentials::{__path_get_user_credentials, get_user_credentials},
     get_user::{__path_get_user, get_user},
     get_user_attributes::{__path_get_user_attributes, get_user_attributes},
+    get_user_deletion::{__path_get_user_deletion, get_user_deletion},
     get_user_permissions::{__path_get_user_permissions, get_user_permissions},
     get_user_roles::{__path_get_user_roles, get_user_roles},
     get_users::{__path_get_users, get_users},
     list_user_organizations::{__path_list_user_organizations, list_user_organizations},
+    list_user_trusted_devices::{__path_list_user_trusted_devices, list_user_trusted_devices},
     reset_password::{__path_reset_password, reset_password},
+    revoke_user_trusted_device::{__path_revoke_user_trusted_device, revoke_user_trusted_device},
+    schedule_user_deletion::{__path_schedule_user_deletion, schedule_user_deletion},
     set_user_attributes::{__path_set_user_attributes, set_user_attributes},
     unassign_role::{__path_unassign_role, unassign_role},
     update_user::{__path_update_user, update_user},
@@ -30,6 +37,7 @@ use super::handlers::{
 #[derive(OpenApi)]
 #[openapi(paths(
     get_users,
+    count_users,
     get_user,
     get_user_roles,
     assign_role,
@@ -46,6 +54,12 @@ use super::handlers::{
     get_user_attributes,
     set_user_attributes,
     delete_user_attribute,
+    export_user_data,
+    get_user_deletion,
+    schedule_user_deletion,
+    cancel_user_deletion,
+    list_user_trusted_devices,
+    revoke_user_trusted_device,
 ))]
 pub struct UserApiDoc;
 
@@ -58,6 +72,13 @@ pub fn user_routes(state: AppState) -> Router<AppState> {
             ),
             get(get_users),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/count",
+                state.args.server.root_path
+            ),
+            get(count_users),
+        )
         .route(
             &format!(
                 "{}/realms/{{realm_name}}/users/{{user_id}}",
@@ -163,5 +184,35 @@ pub fn user_routes(state: AppState) -> Router<AppState> {
             ),
             delete(delete_user_attribute),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/export",
+                state.args.server.root_path
+            ),
+            get(export_user_data),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/deletion",
+                state.args.server.root_path
+            ),
+            get(get_user_deletion)
+                .post(schedule_user_deletion)
+                .delete(cancel_user_deletion),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/trusted-devices",
+                state.args.server.root_path
+            ),
+            get(list_user_trusted_devices),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/trusted-devices/{{device_id}}",
+                state.args.server.root_path
+            ),
+            delete(revoke_user_trusted_device),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
@@ -61,3 +61,11 @@ pub struct UpdateUserValidator {
     #[serde(default)]
     pub required_actions: Option<Vec<String>>,
 }
+
+#[derive(Debug, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ScheduleUserDeletionValidator {
+    /// Days before the account is deleted; defaults to 30.
+    #[validate(range(max = 365, message = "cooling_off_days must be at most 365"))]
+    #[serde(default)]
+    pub cooling_off_days: Option<u32>,
+}
@@ -3,7 +3,17 @@
 use std::{fmt::Display, path::PathBuf};
 
 use clap::{Parser, Subcommand, ValueEnum};
-use ferriskey_core::domain::common::{DatabaseConfig, FerriskeyConfig};
+use ferriskey_core::domain::{
+    common::{
+        DEFAULT_SMS_BODY_TEMPLATE, DatabaseConfig, FerriskeyConfig, MailConfig, RiskConfig,
+        SecretsConfig, SmsConfig,
+    },
+    housekeeping::{
+        entities::HousekeepingJob,
+        value_objects::{HousekeepingConfig, HousekeepingJobConfig},
+    },
+    seawatch::value_objects::{SeawatchConfig, SyslogConfig, SyslogFormat, SyslogTransport},
+};
 use url::Url;
 
 #[derive(Debug, Clone, ValueEnum, Default)]
@@ -42,6 +52,16 @@ pub enum Command {
         #[arg(short, long, value_name = "FILE")]
         output: Option<PathBuf>,
     },
+    /// Walk the SeaWatch audit hash chain of a realm and report the first
+    /// broken link. Exits with an error if the chain does not verify.
+    VerifyAuditChain {
+        /// Name of the realm whose chain is verified
+        #[arg(short, long)]
+        realm: String,
+    },
+    /// Re-encrypt every secret stored in clear or under a previous master key
+    /// with the current one, then print how many were rewritten.
+    RotateSecrets,
 }
 
 #[derive(Debug, Clone, Parser)]
@@ -72,6 +92,18 @@ pub struct Args {
     pub webapp_url: String,
     #[command(flatten)]
     pub observability: ObservabilityArgs,
+    #[command(flatten)]
+    pub seawatch: SeawatchArgs,
+    #[command(flatten)]
+    pub housekeeping: HousekeepingArgs,
+    #[command(flatten)]
+    pub mail: MailArgs,
+    #[command(flatten)]
+    pub secrets: SecretsArgs,
+    #[command(flatten)]
+    pub sms: SmsArgs,
+    #[command(flatten)]
+    pub risk: RiskArgs,
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -86,6 +118,12 @@ impl Default for Args {
             server: ServerArgs::default(),
             webapp_url: "http://localhost:5555".to_string(),
             observability: ObservabilityArgs::default(),
+            seawatch: SeawatchArgs::default(),
+            housekeeping: HousekeepingArgs::default(),
+            mail: MailArgs::default(),
+            secrets: SecretsArgs::default(),
+            sms: SmsArgs::default(),
+            risk: RiskArgs::default(),
             command: None,
         }
     }
@@ -361,6 +399,407 @@ impl Default for ObservabilityArgs {
     }
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum SyslogTransportArg {
+    #[default]
+    Udp,
+    Tcp,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum SyslogFormatArg {
+    #[default]
+    Rfc5424,
+    Cef,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct SeawatchArgs {
+    #[arg(
+        long = "seawatch-retention-interval-secs",
+        env = "SEAWATCH_RETENTION_INTERVAL_SECS",
+        name = "SEAWATCH_RETENTION_INTERVAL_SECS",
+        default_value_t = 3600,
+        long_help = "How often, in seconds, expired security events are purged"
+    )]
+    pub retention_interval_secs: u64,
+    #[arg(
+        long = "seawatch-checkpoint-interval-secs",
+        env = "SEAWATCH_CHECKPOINT_INTERVAL_SECS",
+        name = "SEAWATCH_CHECKPOINT_INTERVAL_SECS",
+        default_value_t = 900,
+        long_help = "How often, in seconds, each realm audit chain head is signed into a checkpoint"
+    )]
+    pub checkpoint_interval_secs: u64,
+    #[arg(
+        long = "seawatch-syslog-address",
+        env = "SEAWATCH_SYSLOG_ADDRESS",
+        name = "SEAWATCH_SYSLOG_ADDRESS",
+        long_help = "host:port of a syslog collector to forward every security event to",
+        required = false
+    )]
+    pub syslog_address: Option<String>,
+    #[arg(
+        long = "seawatch-syslog-transport",
+        env = "SEAWATCH_SYSLOG_TRANSPORT",
+        name = "SEAWATCH_SYSLOG_TRANSPORT",
+        value_enum,
+        default_value_t = SyslogTransportArg::Udp,
+        long_help = "Transport used to reach the syslog collector"
+    )]
+    pub syslog_transport: SyslogTransportArg,
+    #[arg(
+        long = "seawatch-syslog-format",
+        env = "SEAWATCH_SYSLOG_FORMAT",
+        name = "SEAWATCH_SYSLOG_FORMAT",
+        value_enum,
+        default_value_t = SyslogFormatArg::Rfc5424,
+        long_help = "Payload format: plain RFC 5424 with a JSON message, or CEF"
+    )]
+    pub syslog_format: SyslogFormatArg,
+    #[arg(
+        long = "seawatch-syslog-hostname",
+        env = "SEAWATCH_SYSLOG_HOSTNAME",
+        name = "SEAWATCH_SYSLOG_HOSTNAME",
+        default_value = "",
+        long_help = "HOSTNAME reported in forwarded syslog messages"
+    )]
+    pub syslog_hostname: String,
+}
+
+impl Default for SeawatchArgs {
+    fn default() -> Self {
+        Self {
+            retention_interval_secs: 3600,
+            checkpoint_interval_secs: 900,
+            syslog_address: None,
+            syslog_transport: SyslogTransportArg::Udp,
+            syslog_format: SyslogFormatArg::Rfc5424,
+            syslog_hostname: String::new(),
+        }
+    }
+}
+
+impl From<SeawatchArgs> for SeawatchConfig {
+    fn from(value: SeawatchArgs) -> Self {
+        SeawatchConfig {
+            retention_interval: std::time::Duration::from_secs(value.retention_interval_secs),
+            checkpoint_interval: std::time::Duration::from_secs(value.checkpoint_interval_secs),
+            syslog: value.syslog_address.map(|address| SyslogConfig {
+                address,
+                transport: match value.syslog_transport {
+                    SyslogTransportArg::Udp => SyslogTransport::Udp,
+                    SyslogTransportArg::Tcp => SyslogTransport::Tcp,
+                },
+                format: match value.syslog_format {
+                    SyslogFormatArg::Rfc5424 => SyslogFormat::Rfc5424,
+                    SyslogFormatArg::Cef => SyslogFormat::Cef,
+                },
+                hostname: value.syslog_hostname,
+            }),
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct HousekeepingArgs {
+    #[arg(
+        long = "housekeeping-disabled",
+        env = "HOUSEKEEPING_DISABLED",
+        name = "HOUSEKEEPING_DISABLED",
+        long_help = "Do not purge expired tokens, sessions and old flows from this instance"
+    )]
+    pub disabled: bool,
+    #[arg(
+        long = "housekeeping-disabled-jobs",
+        env = "HOUSEKEEPING_DISABLED_JOBS",
+        name = "HOUSEKEEPING_DISABLED_JOBS",
+        num_args = 0..,
+        value_delimiter = ',',
+        value_parser = parse_housekeeping_job,
+        long_help = "Comma-separated housekeeping jobs not to run, e.g. compass_flows,refresh_tokens"
+    )]
+    pub disabled_jobs: Vec<HousekeepingJob>,
+    #[arg(
+        long = "housekeeping-batch-size",
+        env = "HOUSEKEEPING_BATCH_SIZE",
+        name = "HOUSEKEEPING_BATCH_SIZE",
+        default_value_t = 5000,
+        long_help = "Rows deleted per statement by the housekeeping jobs"
+    )]
+    pub batch_size: u64,
+    #[arg(
+        long = "housekeeping-interval-secs",
+        env = "HOUSEKEEPING_INTERVAL_SECS",
+        name = "HOUSEKEEPING_INTERVAL_SECS",
+        default_value_t = 300,
+        long_help = "How often, in seconds, expired tokens and sessions are purged"
+    )]
+    pub interval_secs: u64,
+    #[arg(
+        long = "housekeeping-expired-retention-hours",
+        env = "HOUSEKEEPING_EXPIRED_RETENTION_HOURS",
+        name = "HOUSEKEEPING_EXPIRED_RETENTION_HOURS",
+        default_value_t = 24,
+        long_help = "How long, in hours, expired tokens and sessions are kept before being purged"
+    )]
+    pub expired_retention_hours: u64,
+    #[arg(
+        long = "housekeeping-compass-interval-secs",
+        env = "HOUSEKEEPING_COMPASS_INTERVAL_SECS",
+        name = "HOUSEKEEPING_COMPASS_INTERVAL_SECS",
+        default_value_t = 3600,
+        long_help = "How often, in seconds, old compass flows are purged"
+    )]
+    pub compass_interval_secs: u64,
+    #[arg(
+        long = "housekeeping-compass-retention-days",
+        env = "HOUSEKEEPING_COMPASS_RETENTION_DAYS",
+        name = "HOUSEKEEPING_COMPASS_RETENTION_DAYS",
+        default_value_t = 30,
+        long_help = "How long, in days, compass flows are kept"
+    )]
+    pub compass_retention_days: u64,
+}
+
+impl Default for HousekeepingArgs {
+    fn default() -> Self {
+        Self {
+            disabled: false,
+            disabled_jobs: Vec::new(),
+            batch_size: 5000,
+            interval_secs: 300,
+            expired_retention_hours: 24,
+            compass_interval_secs: 3600,
+            compass_retention_days: 30,
+        }
+    }
+}
+
+impl From<HousekeepingArgs> for HousekeepingConfig {
+    fn from(value: HousekeepingArgs) -> Self {
+        let jobs = HousekeepingJob::ALL
+            .into_iter()
+            .map(|job| {
+                let (interval_secs, retention_secs) = match job {
+                    HousekeepingJob::CompassFlows => (
+                        value.compass_interval_secs,
+                        value.compass_retention_days * 86400,
+                    ),
+                    _ => (value.interval_secs, value.expired_retention_hours * 3600),
+                };
+                let config = HousekeepingJobConfig {
+                    enabled: !value.disabled_jobs.contains(&job),
+                    interval: std::time::Duration::from_secs(interval_secs),
+                    retention: std::time::Duration::from_secs(retention_secs),
+                };
+                (job, config)
+            })
+            .collect();
+
+        HousekeepingConfig {
+            enabled: !value.disabled,
+            batch_size: value.batch_size,
+            jobs,
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct MailArgs {
+    #[arg(
+        long = "mail-maildir-path",
+        env = "MAIL_MAILDIR_PATH",
+        name = "MAIL_MAILDIR_PATH",
+        long_help = "Directory realms using the maildir email provider write their emails to, one subdirectory per realm"
+    )]
+    pub maildir_path: Option<PathBuf>,
+}
+
+impl From<MailArgs> for MailConfig {
+    fn from(value: MailArgs) -> Self {
+        MailConfig {
+            maildir_path: value.maildir_path,
+        }
+    }
+}
+
+#[derive(clap::Args, Clone)]
+pub struct SecretsArgs {
+    #[arg(
+        long = "secrets-master-key",
+        env = "SECRETS_MASTER_KEY",
+        name = "SECRETS_MASTER_KEY",
+        long_help = "Base64 encoded 32 byte key sealing OTP seeds, signing keys and provider secrets at rest"
+    )]
+    pub master_key: Option<String>,
+    #[arg(
+        long = "secrets-master-key-file",
+        env = "SECRETS_MASTER_KEY_FILE",
+        name = "SECRETS_MASTER_KEY_FILE",
+        long_help = "File holding the base64 encoded master key, used when no key is given inline"
+    )]
+    pub master_key_file: Option<PathBuf>,
+    #[arg(
+        long = "secrets-master-key-id",
+        env = "SECRETS_MASTER_KEY_ID",
+        name = "SECRETS_MASTER_KEY_ID",
+        default_value = "primary",
+        long_help = "Identifier stored alongside every secret sealed with the master key"
+    )]
+    pub master_key_id: String,
+    #[arg(
+        long = "secrets-previous-master-keys",
+        env = "SECRETS_PREVIOUS_MASTER_KEYS",
+        name = "SECRETS_PREVIOUS_MASTER_KEYS",
+        num_args = 0..,
+        value_delimiter = ',',
+        long_help = "Comma-separated id:base64 master keys still accepted for decryption during a rotation"
+    )]
+    pub previous_master_keys: Vec<String>,
+}
+
+impl Default for SecretsArgs {
+    fn default() -> Self {
+        Self {
+            master_key: None,
+            master_key_file: None,
+            master_key_id: "primary".to_string(),
+            previous_master_keys: Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Debug for SecretsArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretsArgs")
+            .field(
+                "master_key",
+                &self.master_key.as_ref().map(|_| "<redacted>"),
+            )
+            .field("master_key_file", &self.master_key_file)
+            .field("master_key_id", &self.master_key_id)
+            .field(
+                "previous_master_keys",
+                &format_args!("[{} redacted]", self.previous_master_keys.len()),
+            )
+            .finish()
+    }
+}
+
+impl From<SecretsArgs> for SecretsConfig {
+    fn from(value: SecretsArgs) -> Self {
+        SecretsConfig {
+            master_key: value.master_key,
+            master_key_file: value.master_key_file,
+            master_key_id: value.master_key_id,
+            previous_master_keys: value.previous_master_keys,
+        }
+    }
+}
+
+#[derive(clap::Args, Clone)]
+pub struct SmsArgs {
+    #[arg(
+        long = "sms-gateway-url",
+        env = "SMS_GATEWAY_URL",
+        name = "SMS_GATEWAY_URL",
+        long_help = "HTTP endpoint SMS one-time codes are posted to. Without it, messages are only logged"
+    )]
+    pub gateway_url: Option<String>,
+    #[arg(
+        long = "sms-gateway-authorization",
+        env = "SMS_GATEWAY_AUTHORIZATION",
+        name = "SMS_GATEWAY_AUTHORIZATION",
+        long_help = "Authorization header sent to the SMS gateway, e.g. \"Bearer <token>\""
+    )]
+    pub gateway_authorization: Option<String>,
+    #[arg(
+        long = "sms-gateway-body-template",
+        env = "SMS_GATEWAY_BODY_TEMPLATE",
+        name = "SMS_GATEWAY_BODY_TEMPLATE",
+        default_value = DEFAULT_SMS_BODY_TEMPLATE,
+        long_help = "JSON body posted to the SMS gateway. {{to}}, {{from}} and {{body}} are replaced with JSON strings and must not be quoted"
+    )]
+    pub body_template: String,
+    #[arg(
+        long = "sms-sender",
+        env = "SMS_SENDER",
+        name = "SMS_SENDER",
+        long_help = "Sender name or number passed to the SMS gateway as {{from}}"
+    )]
+    pub sender: Option<String>,
+}
+
+impl Default for SmsArgs {
+    fn default() -> Self {
+        Self {
+            gateway_url: None,
+            gateway_authorization: None,
+            body_template: DEFAULT_SMS_BODY_TEMPLATE.to_string(),
+            sender: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for SmsArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmsArgs")
+            .field("gateway_url", &self.gateway_url)
+            .field(
+                "gateway_authorization",
+                &self.gateway_authorization.as_ref().map(|_| "<redacted>"),
+            )
+            .field("body_template", &self.body_template)
+            .field("sender", &self.sender)
+            .finish()
+    }
+}
+
+impl From<SmsArgs> for SmsConfig {
+    fn from(value: SmsArgs) -> Self {
+        SmsConfig {
+            gateway_url: value.gateway_url,
+            gateway_authorization: value.gateway_authorization,
+            body_template: value.body_template,
+            sender: value.sender,
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct RiskArgs {
+    #[arg(
+        long = "risk-geoip-database",
+        env = "RISK_GEOIP_DATABASE",
+        name = "RISK_GEOIP_DATABASE",
+        long_help = "DB-IP lite CSV (country or city) used to locate sign-in addresses for the risk engine"
+    )]
+    pub geoip_database: Option<PathBuf>,
+    #[arg(
+        long = "risk-ip-blocklists",
+        env = "RISK_IP_BLOCKLISTS",
+        name = "RISK_IP_BLOCKLISTS",
+        num_args = 0..,
+        value_delimiter = ',',
+        long_help = "Comma-separated files of addresses or CIDR ranges, one per line, scored as known-bad by the risk engine"
+    )]
+    pub ip_blocklists: Vec<PathBuf>,
+}
+
+impl From<RiskArgs> for RiskConfig {
+    fn from(value: RiskArgs) -> Self {
+        RiskConfig {
+            geoip_database: value.geoip_database,
+            ip_blocklists: value.ip_blocklists,
+        }
+    }
+}
+
+fn parse_housekeeping_job(value: &str) -> Result<HousekeepingJob, String> {
+    value.trim().parse()
+}
+
 fn parse_root_path(value: &str) -> Result<String, String> {
     let value = value.trim_end_matches('/');
     if value.is_empty() || value.starts_with('/') {
@@ -381,6 +820,12 @@ impl From<Args> for FerriskeyConfig {
                 username: value.db.user,
                 schema: value.db.schema,
             },
+            seawatch: value.seawatch.into(),
+            housekeeping: value.housekeeping.into(),
+            mail: value.mail.into(),
+            secrets: value.secrets.into(),
+            sms: value.sms.into(),
+            risk: value.risk.into(),
         }
     }
 }
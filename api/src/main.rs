@@ -168,6 +168,31 @@ async fn main() -> Result<(), anyhow::Error> {
 
     let app_state = state(args.clone()).await?;
 
+    if let Some(Command::VerifyAuditChain { realm }) = &args.command {
+        let report = app_state.service.verify_audit_chain(realm).await?;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+
+        if let Some(broken) = &report.broken_link {
+            anyhow::bail!(
+                "audit chain of realm {realm} is broken at sequence {} ({:?})",
+                broken.sequence,
+                broken.reason
+            );
+        }
+        info!(
+            "audit chain of realm {realm} verified: {} events",
+            report.verified_events
+        );
+        return Ok(());
+    }
+
+    if let Some(Command::RotateSecrets) = &args.command {
+        let report = app_state.service.rotate_secrets().await?;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        info!("rotated {} secrets", report.total());
+        return Ok(());
+    }
+
     app_state
         .service
         .initialize_application(StartupConfig {
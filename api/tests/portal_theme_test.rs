@@ -106,6 +106,12 @@ mod tests {
                 name: db_name,
                 schema: schema.clone(),
             },
+            seawatch: Default::default(),
+            housekeeping: Default::default(),
+            mail: Default::default(),
+            secrets: Default::default(),
+            sms: Default::default(),
+            risk: Default::default(),
         })
         .await
         .expect("create service");
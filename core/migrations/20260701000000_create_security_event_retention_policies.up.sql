@@ -0,0 +1,12 @@
+-- Per-realm retention window for SeaWatch security events. A NULL
+-- `retention_days` keeps events forever; the background purge job only
+-- considers realms with a value set.
+CREATE TABLE security_event_retention_policies (
+    realm_id UUID PRIMARY KEY,
+    retention_days INTEGER CHECK (retention_days IS NULL OR retention_days > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_security_event_retention_policies_realm
+        FOREIGN KEY (realm_id)
+        REFERENCES realms(id)
+        ON DELETE CASCADE
+);
@@ -0,0 +1,9 @@
+DROP TABLE IF EXISTS security_event_checkpoints;
+DROP TABLE IF EXISTS security_event_chain_heads;
+
+DROP INDEX IF EXISTS idx_security_events_realm_sequence;
+
+ALTER TABLE security_events
+    DROP COLUMN IF EXISTS hash,
+    DROP COLUMN IF EXISTS prev_hash,
+    DROP COLUMN IF EXISTS sequence;
@@ -0,0 +1,43 @@
+-- Tamper-evident hash chain for SeaWatch security events. Every new event is
+-- linked to its realm predecessor: `hash = sha256(prev_hash || event)`.
+-- Events stored before this migration keep NULL chain columns and are not
+-- covered by verification.
+ALTER TABLE security_events
+    ADD COLUMN sequence BIGINT,
+    ADD COLUMN prev_hash VARCHAR(64),
+    ADD COLUMN hash VARCHAR(64);
+
+CREATE UNIQUE INDEX idx_security_events_realm_sequence
+    ON security_events(realm_id, sequence)
+    WHERE sequence IS NOT NULL;
+
+-- Tip of each realm chain. The row is locked while appending so concurrent
+-- writers are serialized per realm.
+CREATE TABLE security_event_chain_heads (
+    realm_id UUID PRIMARY KEY,
+    sequence BIGINT NOT NULL,
+    hash VARCHAR(64) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_security_event_chain_heads_realm
+        FOREIGN KEY (realm_id)
+        REFERENCES realms(id)
+        ON DELETE CASCADE
+);
+
+-- Periodic checkpoints of a realm chain, signed with the realm key. They are
+-- never purged by the retention job.
+CREATE TABLE security_event_checkpoints (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL,
+    sequence BIGINT NOT NULL,
+    hash VARCHAR(64) NOT NULL,
+    signature TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_security_event_checkpoints_realm
+        FOREIGN KEY (realm_id)
+        REFERENCES realms(id)
+        ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX idx_security_event_checkpoints_realm_sequence
+    ON security_event_checkpoints(realm_id, sequence);
@@ -0,0 +1,27 @@
+-- Generic audit trail of successful mutating admin API calls, queryable next
+-- to SeaWatch security events. Snapshots are stored with secrets masked.
+CREATE TABLE admin_events (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL,
+    actor_id UUID NOT NULL,
+    actor_type VARCHAR(50) NOT NULL,
+    actor_name VARCHAR(255) NOT NULL,
+    operation VARCHAR(20) NOT NULL,
+    resource_type VARCHAR(255) NOT NULL,
+    resource_id VARCHAR(255),
+    resource_path TEXT NOT NULL,
+    before JSONB,
+    after JSONB,
+    changes JSONB NOT NULL DEFAULT '[]'::jsonb,
+    ip_address VARCHAR(45),
+    user_agent TEXT,
+    timestamp TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_admin_events_realm
+        FOREIGN KEY (realm_id)
+        REFERENCES realms(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX idx_admin_events_realm_timestamp ON admin_events(realm_id, timestamp DESC, id DESC);
+CREATE INDEX idx_admin_events_actor_id ON admin_events(actor_id);
+CREATE INDEX idx_admin_events_resource ON admin_events(realm_id, resource_type, resource_id);
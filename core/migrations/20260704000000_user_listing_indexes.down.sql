@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS idx_user_role_role_id;
+DROP INDEX IF EXISTS idx_users_realm_created_at;
+DROP INDEX IF EXISTS idx_users_realm_username;
+
+DROP INDEX IF EXISTS idx_users_lastname_trgm;
+DROP INDEX IF EXISTS idx_users_firstname_trgm;
+DROP INDEX IF EXISTS idx_users_email_trgm;
+DROP INDEX IF EXISTS idx_users_username_trgm;
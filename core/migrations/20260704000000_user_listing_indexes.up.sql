@@ -0,0 +1,12 @@
+-- Search and keyset pagination over large user listings. Trigram indexes let
+-- the substring (ILIKE '%term%') search use an index.
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX idx_users_username_trgm ON users USING gin (username gin_trgm_ops);
+CREATE INDEX idx_users_email_trgm ON users USING gin (email gin_trgm_ops);
+CREATE INDEX idx_users_firstname_trgm ON users USING gin (firstname gin_trgm_ops);
+CREATE INDEX idx_users_lastname_trgm ON users USING gin (lastname gin_trgm_ops);
+
+CREATE INDEX idx_users_realm_username ON users (realm_id, username, id);
+CREATE INDEX idx_users_realm_created_at ON users (realm_id, created_at, id);
+CREATE INDEX idx_user_role_role_id ON user_role (role_id);
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_compass_flows_started_at;
+DROP INDEX IF EXISTS idx_email_verification_tokens_expires_at;
+DROP INDEX IF EXISTS idx_password_reset_tokens_expires_at;
+DROP INDEX IF EXISTS idx_magic_links_expires_at;
+DROP INDEX IF EXISTS idx_access_tokens_expires_at;
+DROP INDEX IF EXISTS idx_refresh_tokens_expires_at;
+DROP TABLE IF EXISTS housekeeping_retention_policies;
@@ -0,0 +1,22 @@
+-- Per-realm override of a housekeeping job retention window. A NULL
+-- `retention_hours` keeps the realm's rows forever; realms without a row use
+-- the job's configured default.
+CREATE TABLE housekeeping_retention_policies (
+    realm_id UUID NOT NULL,
+    job VARCHAR(64) NOT NULL,
+    retention_hours INTEGER CHECK (retention_hours IS NULL OR retention_hours > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (realm_id, job),
+    CONSTRAINT fk_housekeeping_retention_policies_realm
+        FOREIGN KEY (realm_id)
+        REFERENCES realms(id)
+        ON DELETE CASCADE
+);
+
+-- The purge jobs delete by expiry; these tables had no index to find them.
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_expires_at ON refresh_tokens(expires_at);
+CREATE INDEX IF NOT EXISTS idx_access_tokens_expires_at ON access_tokens(expires_at);
+CREATE INDEX IF NOT EXISTS idx_magic_links_expires_at ON magic_links(expires_at);
+CREATE INDEX IF NOT EXISTS idx_password_reset_tokens_expires_at ON password_reset_tokens(expires_at);
+CREATE INDEX IF NOT EXISTS idx_email_verification_tokens_expires_at ON email_verification_tokens(expires_at);
+CREATE INDEX IF NOT EXISTS idx_compass_flows_started_at ON compass_flows(started_at);
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS maintenance_windows;
+DROP TABLE IF EXISTS realm_maintenance;
+DROP INDEX IF EXISTS idx_refresh_tokens_client_id;
+ALTER TABLE refresh_tokens DROP COLUMN IF EXISTS client_id;
@@ -0,0 +1,30 @@
+-- Record the client a refresh token was issued to, so maintenance can revoke them per client
+ALTER TABLE refresh_tokens ADD COLUMN client_id UUID REFERENCES clients(id) ON DELETE SET NULL;
+CREATE INDEX idx_refresh_tokens_client_id ON refresh_tokens(client_id);
+
+-- Realm-wide maintenance state
+CREATE TABLE realm_maintenance (
+    realm_id UUID PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    reason TEXT,
+    session_strategy VARCHAR(50) NOT NULL DEFAULT 'expire',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Scheduled maintenance windows, for a client or the whole realm
+CREATE TABLE maintenance_windows (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    client_id UUID REFERENCES clients(id) ON DELETE CASCADE,
+    reason TEXT,
+    session_strategy VARCHAR(50) NOT NULL DEFAULT 'expire',
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'scheduled',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT chk_maintenance_window_range CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX idx_maintenance_windows_realm_id ON maintenance_windows(realm_id);
+CREATE INDEX idx_maintenance_windows_due ON maintenance_windows(status, starts_at, ends_at);
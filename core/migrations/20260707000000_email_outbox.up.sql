@@ -0,0 +1,19 @@
+-- Emails written by the application and delivered by the outbox worker
+CREATE TABLE email_outbox (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    recipient VARCHAR(320) NOT NULL,
+    subject TEXT NOT NULL,
+    body TEXT NOT NULL,
+    html_body TEXT,
+    status VARCHAR(20) NOT NULL DEFAULT 'queued',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_email_outbox_realm_id_created_at ON email_outbox(realm_id, created_at DESC);
+CREATE INDEX idx_email_outbox_due ON email_outbox(next_attempt_at) WHERE status = 'queued';
@@ -0,0 +1,5 @@
+ALTER TABLE smtp_configs
+    DROP COLUMN IF EXISTS http_body_template,
+    DROP COLUMN IF EXISTS http_headers,
+    DROP COLUMN IF EXISTS http_url,
+    DROP COLUMN IF EXISTS provider;
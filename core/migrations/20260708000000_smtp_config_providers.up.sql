@@ -0,0 +1,5 @@
+ALTER TABLE smtp_configs
+    ADD COLUMN provider VARCHAR(20) NOT NULL DEFAULT 'smtp',
+    ADD COLUMN http_url TEXT,
+    ADD COLUMN http_headers JSONB,
+    ADD COLUMN http_body_template TEXT;
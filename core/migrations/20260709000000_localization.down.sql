@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS email_template_localizations;
+DROP TABLE IF EXISTS message_bundles;
+DROP TABLE IF EXISTS realm_localizations;
@@ -0,0 +1,26 @@
+CREATE TABLE realm_localizations (
+    realm_id UUID PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    default_locale VARCHAR(35) NOT NULL,
+    supported_locales JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE message_bundles (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    locale VARCHAR(35) NOT NULL,
+    messages JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_message_bundles_realm_locale UNIQUE (realm_id, locale)
+);
+
+CREATE TABLE email_template_localizations (
+    id UUID PRIMARY KEY,
+    template_id UUID NOT NULL REFERENCES email_templates(id) ON DELETE CASCADE,
+    locale VARCHAR(35) NOT NULL,
+    structure JSONB NOT NULL,
+    mjml TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT uq_email_template_localizations_template_locale UNIQUE (template_id, locale)
+);
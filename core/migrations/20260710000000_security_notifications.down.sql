@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS user_sign_in_origins;
+DROP TABLE IF EXISTS security_notification_settings;
@@ -0,0 +1,22 @@
+CREATE TABLE security_notification_settings (
+    realm_id UUID PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    password_changed BOOLEAN NOT NULL DEFAULT TRUE,
+    new_sign_in BOOLEAN NOT NULL DEFAULT TRUE,
+    mfa_added BOOLEAN NOT NULL DEFAULT TRUE,
+    mfa_removed BOOLEAN NOT NULL DEFAULT TRUE,
+    recovery_codes_regenerated BOOLEAN NOT NULL DEFAULT TRUE,
+    account_locked BOOLEAN NOT NULL DEFAULT TRUE,
+    email_changed BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE user_sign_in_origins (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    fingerprint VARCHAR(64) NOT NULL,
+    ip_address VARCHAR(64),
+    user_agent TEXT,
+    first_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_user_sign_in_origins_user_fingerprint UNIQUE (user_id, fingerprint)
+);
@@ -0,0 +1,16 @@
+CREATE TABLE email_template_versions (
+    id UUID PRIMARY KEY,
+    template_id UUID NOT NULL REFERENCES email_templates(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    structure JSONB NOT NULL,
+    mjml TEXT NOT NULL,
+    created_by UUID,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT uq_email_template_versions_template_version UNIQUE (template_id, version)
+);
+
+-- Existing templates start their history at version 1.
+INSERT INTO email_template_versions (id, template_id, version, name, structure, mjml, created_at)
+SELECT gen_random_uuid(), id, 1, name, structure, mjml, updated_at
+FROM email_templates;
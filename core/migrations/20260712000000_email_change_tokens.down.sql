@@ -0,0 +1,7 @@
+DELETE FROM email_verification_tokens WHERE purpose <> 'verify_email';
+
+DROP INDEX IF EXISTS idx_email_verification_tokens_user_purpose;
+
+ALTER TABLE email_verification_tokens
+    DROP COLUMN email,
+    DROP COLUMN purpose;
@@ -0,0 +1,7 @@
+-- Email verification tokens also confirm and revert email address changes.
+ALTER TABLE email_verification_tokens
+    ADD COLUMN purpose VARCHAR(32) NOT NULL DEFAULT 'verify_email',
+    ADD COLUMN email VARCHAR(255);
+
+CREATE INDEX idx_email_verification_tokens_user_purpose
+    ON email_verification_tokens(user_id, purpose);
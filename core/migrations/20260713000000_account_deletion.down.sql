@@ -0,0 +1,4 @@
+ALTER TABLE security_events
+    DROP COLUMN pseudonymized_at;
+
+DROP TABLE IF EXISTS account_deletion_requests;
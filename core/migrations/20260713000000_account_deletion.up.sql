@@ -0,0 +1,19 @@
+-- Account deletions requested by users or by admins on their behalf. A
+-- background job deletes the account once `scheduled_for` has passed.
+CREATE TABLE account_deletion_requests (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    requested_by UUID NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    scheduled_for TIMESTAMPTZ NOT NULL,
+    CONSTRAINT uq_account_deletion_requests_user UNIQUE (user_id)
+);
+
+CREATE INDEX idx_account_deletion_requests_scheduled_for
+    ON account_deletion_requests(scheduled_for);
+
+-- Events about deleted users are pseudonymized in place. Their chain hash is
+-- kept, so verification still checks the links but skips their content.
+ALTER TABLE security_events
+    ADD COLUMN pseudonymized_at TIMESTAMP;
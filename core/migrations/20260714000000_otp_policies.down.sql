@@ -0,0 +1,17 @@
+DELETE FROM credentials c
+USING credentials newer
+WHERE c.credential_type = 'otp'
+  AND newer.credential_type = 'otp'
+  AND newer.user_id = c.user_id
+  AND newer.created_at > c.created_at;
+
+DROP INDEX unique_credential_type_per_user_id_idx;
+
+CREATE UNIQUE INDEX unique_credential_type_per_user_id_idx
+ON credentials (user_id, credential_type)
+
+WHERE
+credential_type <> 'recovery-code' AND
+credential_type <> 'webauthn-public-key-credential';
+
+DROP TABLE IF EXISTS otp_policies;
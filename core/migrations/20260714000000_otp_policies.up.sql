@@ -0,0 +1,21 @@
+CREATE TABLE otp_policies (
+    realm_id UUID PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    otp_type VARCHAR(16) NOT NULL DEFAULT 'totp',
+    algorithm VARCHAR(16) NOT NULL DEFAULT 'SHA1',
+    digits INTEGER NOT NULL DEFAULT 6,
+    period INTEGER NOT NULL DEFAULT 30,
+    look_around INTEGER NOT NULL DEFAULT 1,
+    initial_counter BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Users may enroll several OTP devices
+DROP INDEX unique_credential_type_per_user_id_idx;
+
+CREATE UNIQUE INDEX unique_credential_type_per_user_id_idx
+ON credentials (user_id, credential_type)
+
+WHERE
+credential_type <> 'recovery-code' AND
+credential_type <> 'webauthn-public-key-credential' AND
+credential_type <> 'otp';
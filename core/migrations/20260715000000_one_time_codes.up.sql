@@ -0,0 +1,18 @@
+-- Email and SMS second factor codes, stored hashed.
+CREATE TABLE one_time_codes (
+    id           UUID PRIMARY KEY,
+    realm_id     UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    user_id      UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    channel      VARCHAR(16) NOT NULL,
+    purpose      VARCHAR(32) NOT NULL,
+    destination  VARCHAR(320) NOT NULL,
+    code_hash    VARCHAR(64) NOT NULL,
+    attempts     INTEGER NOT NULL DEFAULT 0,
+    expires_at   TIMESTAMPTZ NOT NULL,
+    consumed_at  TIMESTAMPTZ NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_one_time_codes_user_channel_created_at
+    ON one_time_codes(user_id, channel, created_at DESC);
+CREATE INDEX idx_one_time_codes_expires_at ON one_time_codes(expires_at);
@@ -0,0 +1,3 @@
+ALTER TABLE auth_sessions DROP COLUMN pending_auth_stages;
+
+DROP TABLE auth_flows;
@@ -0,0 +1,15 @@
+CREATE TABLE auth_flows (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    -- NULL for the realm's own flow, set for a client override
+    client_id UUID REFERENCES clients(id) ON DELETE CASCADE,
+    steps JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX auth_flows_realm_idx ON auth_flows (realm_id) WHERE client_id IS NULL;
+CREATE UNIQUE INDEX auth_flows_client_idx ON auth_flows (client_id) WHERE client_id IS NOT NULL;
+
+-- Stages of the flow the session still has to pass before it completes
+ALTER TABLE auth_sessions ADD COLUMN pending_auth_stages JSONB NOT NULL DEFAULT '[]'::jsonb;
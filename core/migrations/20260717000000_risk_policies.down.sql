@@ -0,0 +1,5 @@
+ALTER TABLE security_notification_settings DROP COLUMN suspicious_sign_in;
+
+ALTER TABLE auth_sessions DROP COLUMN risk_assessment;
+
+DROP TABLE risk_policies;
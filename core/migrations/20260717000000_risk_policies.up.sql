@@ -0,0 +1,16 @@
+CREATE TABLE risk_policies (
+    realm_id UUID PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    notify_threshold SMALLINT NOT NULL DEFAULT 20,
+    step_up_threshold SMALLINT NOT NULL DEFAULT 50,
+    block_threshold SMALLINT NOT NULL DEFAULT 90,
+    max_failures INTEGER NOT NULL DEFAULT 5,
+    failure_window_minutes INTEGER NOT NULL DEFAULT 15,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Score of the sign-in, carried into the tokens of the session
+ALTER TABLE auth_sessions ADD COLUMN risk_assessment JSONB;
+
+ALTER TABLE security_notification_settings
+    ADD COLUMN suspicious_sign_in BOOLEAN NOT NULL DEFAULT TRUE;
@@ -0,0 +1,3 @@
+ALTER TABLE realm_settings DROP COLUMN trusted_device_days;
+
+DROP TABLE trusted_devices;
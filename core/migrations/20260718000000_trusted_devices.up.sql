@@ -0,0 +1,19 @@
+-- Browsers users trusted to skip the second factor, keyed by a hashed secret
+-- kept in a cookie.
+CREATE TABLE trusted_devices (
+    id            UUID PRIMARY KEY,
+    realm_id      UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    user_id       UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    user_agent    TEXT NULL,
+    ip_address    VARCHAR(45) NULL,
+    token_hash    VARCHAR(64) NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_used_at  TIMESTAMPTZ NULL,
+    expires_at    TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_trusted_devices_user_id ON trusted_devices(user_id);
+CREATE INDEX idx_trusted_devices_expires_at ON trusted_devices(expires_at);
+
+-- Days a trusted device skips the second factor; 0 disables trusting devices.
+ALTER TABLE realm_settings ADD COLUMN trusted_device_days INTEGER NOT NULL DEFAULT 0;
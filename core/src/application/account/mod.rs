@@ -0,0 +1,118 @@
+use crate::{
+    application::services::ApplicationService,
+    domain::{
+        account::{
+            entities::{
+                AccountInput, AccountSession, ChangeAccountPasswordInput,
+                DeleteAccountCredentialInput, LinkedIdentity, ListAccountEventsInput,
+                RevokeAccountSessionInput, UnlinkIdentityInput, UpdateAccountProfileInput,
+            },
+            ports::AccountService,
+        },
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        credential::entities::CredentialOverview,
+        seawatch::entities::SecurityEvent,
+        user::entities::User,
+    },
+};
+
+impl AccountService for ApplicationService {
+    async fn get_account_profile(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<User, CoreError> {
+        self.account_service
+            .get_account_profile(identity, input)
+            .await
+    }
+
+    async fn update_account_profile(
+        &self,
+        identity: Identity,
+        input: UpdateAccountProfileInput,
+    ) -> Result<User, CoreError> {
+        self.account_service
+            .update_account_profile(identity, input)
+            .await
+    }
+
+    async fn list_account_credentials(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<Vec<CredentialOverview>, CoreError> {
+        self.account_service
+            .list_account_credentials(identity, input)
+            .await
+    }
+
+    async fn delete_account_credential(
+        &self,
+        identity: Identity,
+        input: DeleteAccountCredentialInput,
+    ) -> Result<(), CoreError> {
+        self.account_service
+            .delete_account_credential(identity, input)
+            .await
+    }
+
+    async fn change_account_password(
+        &self,
+        identity: Identity,
+        input: ChangeAccountPasswordInput,
+    ) -> Result<(), CoreError> {
+        self.account_service
+            .change_account_password(identity, input)
+            .await
+    }
+
+    async fn list_linked_identities(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<Vec<LinkedIdentity>, CoreError> {
+        self.account_service
+            .list_linked_identities(identity, input)
+            .await
+    }
+
+    async fn unlink_identity(
+        &self,
+        identity: Identity,
+        input: UnlinkIdentityInput,
+    ) -> Result<(), CoreError> {
+        self.account_service.unlink_identity(identity, input).await
+    }
+
+    async fn list_account_sessions(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<Vec<AccountSession>, CoreError> {
+        self.account_service
+            .list_account_sessions(identity, input)
+            .await
+    }
+
+    async fn revoke_account_session(
+        &self,
+        identity: Identity,
+        input: RevokeAccountSessionInput,
+    ) -> Result<(), CoreError> {
+        self.account_service
+            .revoke_account_session(identity, input)
+            .await
+    }
+
+    async fn list_account_events(
+        &self,
+        identity: Identity,
+        input: ListAccountEventsInput,
+    ) -> Result<Vec<SecurityEvent>, CoreError> {
+        self.account_service
+            .list_account_events(identity, input)
+            .await
+    }
+}
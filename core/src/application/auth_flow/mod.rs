@@ -0,0 +1,62 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        auth_flow::{
+            entities::{AuthFlow, LoginOptions},
+            ports::AuthFlowService,
+            value_objects::{
+                DeleteAuthFlowInput, GetAuthFlowInput, GetLoginOptionsInput, ListAuthFlowsInput,
+                UpdateAuthFlowInput,
+            },
+        },
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+    },
+};
+
+impl AuthFlowService for ApplicationService {
+    async fn get_auth_flow(
+        &self,
+        identity: Identity,
+        input: GetAuthFlowInput,
+    ) -> Result<AuthFlow, CoreError> {
+        self.auth_flow_service.get_auth_flow(identity, input).await
+    }
+
+    async fn list_auth_flows(
+        &self,
+        identity: Identity,
+        input: ListAuthFlowsInput,
+    ) -> Result<Vec<AuthFlow>, CoreError> {
+        self.auth_flow_service
+            .list_auth_flows(identity, input)
+            .await
+    }
+
+    async fn update_auth_flow(
+        &self,
+        identity: Identity,
+        input: UpdateAuthFlowInput,
+    ) -> Result<AuthFlow, CoreError> {
+        self.auth_flow_service
+            .update_auth_flow(identity, input)
+            .await
+    }
+
+    async fn delete_auth_flow(
+        &self,
+        identity: Identity,
+        input: DeleteAuthFlowInput,
+    ) -> Result<(), CoreError> {
+        self.auth_flow_service
+            .delete_auth_flow(identity, input)
+            .await
+    }
+
+    async fn get_login_options(
+        &self,
+        input: GetLoginOptionsInput,
+    ) -> Result<LoginOptions, CoreError> {
+        self.auth_flow_service.get_login_options(input).await
+    }
+}
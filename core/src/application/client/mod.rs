@@ -13,7 +13,7 @@ use crate::{
             },
             ports::ClientService,
         },
-        common::entities::app_errors::CoreError,
+        common::entities::{app_errors::CoreError, listing::Page},
         role::entities::Role,
     },
 };
@@ -103,10 +103,18 @@ impl ClientService for ApplicationService {
         &self,
         identity: Identity,
         input: GetClientsInput,
-    ) -> Result<Vec<Client>, CoreError> {
+    ) -> Result<Page<Client>, CoreError> {
         self.client_service.get_clients(identity, input).await
     }
 
+    async fn count_clients(
+        &self,
+        identity: Identity,
+        input: GetClientsInput,
+    ) -> Result<u64, CoreError> {
+        self.client_service.count_clients(identity, input).await
+    }
+
     async fn get_redirect_uris(
         &self,
         identity: Identity,
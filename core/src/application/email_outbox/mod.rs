@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        email_outbox::{
+            entities::OutboxEmail,
+            ports::EmailOutboxService,
+            value_objects::{GetOutboxEmailInput, ListOutboxEmailsInput, ResendOutboxEmailInput},
+        },
+    },
+};
+
+impl EmailOutboxService for ApplicationService {
+    async fn list_outbox_emails(
+        &self,
+        identity: Identity,
+        input: ListOutboxEmailsInput,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        self.email_outbox_service
+            .list_outbox_emails(identity, input)
+            .await
+    }
+
+    async fn get_outbox_email(
+        &self,
+        identity: Identity,
+        input: GetOutboxEmailInput,
+    ) -> Result<OutboxEmail, CoreError> {
+        self.email_outbox_service
+            .get_outbox_email(identity, input)
+            .await
+    }
+
+    async fn resend_outbox_email(
+        &self,
+        identity: Identity,
+        input: ResendOutboxEmailInput,
+    ) -> Result<OutboxEmail, CoreError> {
+        self.email_outbox_service
+            .resend_outbox_email(identity, input)
+            .await
+    }
+
+    async fn deliver_due_emails(&self, now: DateTime<Utc>) -> Result<usize, CoreError> {
+        self.email_outbox_service.deliver_due_emails(now).await
+    }
+}
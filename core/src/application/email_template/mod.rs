@@ -6,10 +6,16 @@ use crate::{
         authentication::value_objects::Identity,
         common::entities::app_errors::CoreError,
         email_template::{
-            entities::EmailTemplate,
+            entities::{
+                EmailTemplate, EmailTemplateLocalization, EmailTemplatePreview,
+                EmailTemplateVersion, EmailTemplateVersionDiff,
+            },
             ports::{
-                CreateEmailTemplateInput, DeleteEmailTemplateInput, EmailTemplateService,
-                GetEmailTemplateInput, GetEmailTemplatesInput, UpdateEmailTemplateInput,
+                CreateEmailTemplateInput, DeleteEmailTemplateInput,
+                DeleteEmailTemplateLocalizationInput, DiffEmailTemplateVersionsInput,
+                EmailTemplateService, GetEmailTemplateInput, GetEmailTemplateVersionInput,
+                GetEmailTemplatesInput, PreviewEmailTemplateInput, SendTestEmailInput,
+                UpdateEmailTemplateInput, UpsertEmailTemplateLocalizationInput,
             },
         },
     },
@@ -71,4 +77,94 @@ impl EmailTemplateService for ApplicationService {
             .render_template_html(template_id)
             .await
     }
+
+    async fn list_template_localizations(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateInput,
+    ) -> Result<Vec<EmailTemplateLocalization>, CoreError> {
+        self.email_template_service
+            .list_template_localizations(identity, input)
+            .await
+    }
+
+    async fn upsert_template_localization(
+        &self,
+        identity: Identity,
+        input: UpsertEmailTemplateLocalizationInput,
+    ) -> Result<EmailTemplateLocalization, CoreError> {
+        self.email_template_service
+            .upsert_template_localization(identity, input)
+            .await
+    }
+
+    async fn delete_template_localization(
+        &self,
+        identity: Identity,
+        input: DeleteEmailTemplateLocalizationInput,
+    ) -> Result<(), CoreError> {
+        self.email_template_service
+            .delete_template_localization(identity, input)
+            .await
+    }
+
+    async fn list_template_versions(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateInput,
+    ) -> Result<Vec<EmailTemplateVersion>, CoreError> {
+        self.email_template_service
+            .list_template_versions(identity, input)
+            .await
+    }
+
+    async fn get_template_version(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateVersionInput,
+    ) -> Result<EmailTemplateVersion, CoreError> {
+        self.email_template_service
+            .get_template_version(identity, input)
+            .await
+    }
+
+    async fn rollback_template(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateVersionInput,
+    ) -> Result<EmailTemplate, CoreError> {
+        self.email_template_service
+            .rollback_template(identity, input)
+            .await
+    }
+
+    async fn diff_template_versions(
+        &self,
+        identity: Identity,
+        input: DiffEmailTemplateVersionsInput,
+    ) -> Result<EmailTemplateVersionDiff, CoreError> {
+        self.email_template_service
+            .diff_template_versions(identity, input)
+            .await
+    }
+
+    async fn preview_template(
+        &self,
+        identity: Identity,
+        input: PreviewEmailTemplateInput,
+    ) -> Result<EmailTemplatePreview, CoreError> {
+        self.email_template_service
+            .preview_template(identity, input)
+            .await
+    }
+
+    async fn send_test_email(
+        &self,
+        identity: Identity,
+        input: SendTestEmailInput,
+    ) -> Result<(), CoreError> {
+        self.email_template_service
+            .send_test_email(identity, input)
+            .await
+    }
 }
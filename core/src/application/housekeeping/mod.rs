@@ -0,0 +1,47 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        housekeeping::{
+            entities::{HousekeepingJobStatus, HousekeepingRetentionPolicy},
+            ports::HousekeepingService,
+            value_objects::{
+                GetHousekeepingStatusInput, ListHousekeepingRetentionInput,
+                UpdateHousekeepingRetentionInput,
+            },
+        },
+    },
+};
+
+impl HousekeepingService for ApplicationService {
+    async fn get_housekeeping_status(
+        &self,
+        identity: Identity,
+        input: GetHousekeepingStatusInput,
+    ) -> Result<Vec<HousekeepingJobStatus>, CoreError> {
+        self.housekeeping_service
+            .get_housekeeping_status(identity, input)
+            .await
+    }
+
+    async fn list_housekeeping_retention(
+        &self,
+        identity: Identity,
+        input: ListHousekeepingRetentionInput,
+    ) -> Result<Vec<HousekeepingRetentionPolicy>, CoreError> {
+        self.housekeeping_service
+            .list_housekeeping_retention(identity, input)
+            .await
+    }
+
+    async fn update_housekeeping_retention(
+        &self,
+        identity: Identity,
+        input: UpdateHousekeepingRetentionInput,
+    ) -> Result<HousekeepingRetentionPolicy, CoreError> {
+        self.housekeeping_service
+            .update_housekeeping_retention(identity, input)
+            .await
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        localization::{
+            entities::{LocalizedMessages, MessageBundle, RealmLocalization},
+            ports::LocalizationService,
+            value_objects::{
+                DeleteMessageBundleInput, GetLocalizationSettingsInput, ListMessageBundlesInput,
+                PutMessageBundleInput, ResolveMessagesInput, UpdateLocalizationSettingsInput,
+            },
+        },
+    },
+};
+
+impl LocalizationService for ApplicationService {
+    async fn get_localization_settings(
+        &self,
+        identity: Identity,
+        input: GetLocalizationSettingsInput,
+    ) -> Result<RealmLocalization, CoreError> {
+        self.localization_service
+            .get_localization_settings(identity, input)
+            .await
+    }
+
+    async fn update_localization_settings(
+        &self,
+        identity: Identity,
+        input: UpdateLocalizationSettingsInput,
+    ) -> Result<RealmLocalization, CoreError> {
+        self.localization_service
+            .update_localization_settings(identity, input)
+            .await
+    }
+
+    async fn list_message_bundles(
+        &self,
+        identity: Identity,
+        input: ListMessageBundlesInput,
+    ) -> Result<Vec<MessageBundle>, CoreError> {
+        self.localization_service
+            .list_message_bundles(identity, input)
+            .await
+    }
+
+    async fn put_message_bundle(
+        &self,
+        identity: Identity,
+        input: PutMessageBundleInput,
+    ) -> Result<MessageBundle, CoreError> {
+        self.localization_service
+            .put_message_bundle(identity, input)
+            .await
+    }
+
+    async fn delete_message_bundle(
+        &self,
+        identity: Identity,
+        input: DeleteMessageBundleInput,
+    ) -> Result<(), CoreError> {
+        self.localization_service
+            .delete_message_bundle(identity, input)
+            .await
+    }
+
+    async fn resolve_messages(
+        &self,
+        input: ResolveMessagesInput,
+    ) -> Result<LocalizedMessages, CoreError> {
+        self.localization_service.resolve_messages(input).await
+    }
+}
@@ -1,14 +1,19 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::{
     ApplicationService,
     domain::{
         authentication::value_objects::Identity,
+        client::entities::Client,
         common::entities::app_errors::CoreError,
         maintenance::{
-            entities::{MaintenanceWhitelistEntry, RealmMaintenanceWhitelistEntry},
+            entities::{
+                MaintenanceWhitelistEntry, MaintenanceWindow, RealmMaintenance,
+                RealmMaintenanceWhitelistEntry,
+            },
             ports::MaintenanceService,
-            value_objects::ToggleMaintenanceRequest,
+            value_objects::{ScheduleMaintenanceWindowRequest, ToggleMaintenanceRequest},
         },
     },
 };
@@ -39,6 +44,76 @@ impl MaintenanceService for ApplicationService {
             .await
     }
 
+    async fn ensure_access_allowed(
+        &self,
+        realm_id: RealmId,
+        client: Option<&Client>,
+        user_id: Uuid,
+    ) -> Result<(), CoreError> {
+        self.maintenance_service
+            .ensure_access_allowed(realm_id, client, user_id)
+            .await
+    }
+
+    async fn get_realm_maintenance(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<RealmMaintenance, CoreError> {
+        self.maintenance_service
+            .get_realm_maintenance(identity, realm_name)
+            .await
+    }
+
+    async fn toggle_realm_maintenance(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ToggleMaintenanceRequest,
+    ) -> Result<RealmMaintenance, CoreError> {
+        self.maintenance_service
+            .toggle_realm_maintenance(identity, realm_name, request)
+            .await
+    }
+
+    async fn list_maintenance_windows(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        self.maintenance_service
+            .list_maintenance_windows(identity, realm_name)
+            .await
+    }
+
+    async fn schedule_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ScheduleMaintenanceWindowRequest,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        self.maintenance_service
+            .schedule_maintenance_window(identity, realm_name, request)
+            .await
+    }
+
+    async fn cancel_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        window_id: Uuid,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        self.maintenance_service
+            .cancel_maintenance_window(identity, realm_name, window_id)
+            .await
+    }
+
+    async fn apply_due_maintenance_windows(&self, now: DateTime<Utc>) -> Result<usize, CoreError> {
+        self.maintenance_service
+            .apply_due_maintenance_windows(now)
+            .await
+    }
+
     async fn add_client_whitelist_user(
         &self,
         identity: Identity,
@@ -4,35 +4,41 @@ use ferriskey_aegis::ports::{
     ClientScopeMappingRepository, ClientScopeRepository, ProtocolMapperRepository,
 };
 
-use crate::domain::{client::ports::ClientRepository, realm::ports::RealmRepository};
+use crate::domain::{
+    client::ports::ClientRepository, realm::ports::RealmRepository,
+    secrets::ports::SecretRotationRepository,
+};
 
 /// Shared execution context passed to every data migration.
 ///
 /// All fields are trait objects so migrations depend on ports, not infrastructure.
 /// Add fields as new migrations require access to additional repositories.
 #[derive(Clone)]
-pub struct MigrationContext<R, C, CS, PM, CSM>
+pub struct MigrationContext<R, C, CS, PM, CSM, SR>
 where
     R: RealmRepository,
     C: ClientRepository,
     CS: ClientScopeRepository,
     PM: ProtocolMapperRepository,
     CSM: ClientScopeMappingRepository,
+    SR: SecretRotationRepository,
 {
     pub realm_repository: Arc<R>,
     pub client_repository: Arc<C>,
     pub client_scope_repository: Arc<CS>,
     pub protocol_mapper_repository: Arc<PM>,
     pub scope_mapping_repository: Arc<CSM>,
+    pub secret_rotation_repository: Arc<SR>,
 }
 
-impl<R, C, CS, PM, CSM> MigrationContext<R, C, CS, PM, CSM>
+impl<R, C, CS, PM, CSM, SR> MigrationContext<R, C, CS, PM, CSM, SR>
 where
     R: RealmRepository,
     C: ClientRepository,
     CS: ClientScopeRepository,
     PM: ProtocolMapperRepository,
     CSM: ClientScopeMappingRepository,
+    SR: SecretRotationRepository,
 {
     pub fn new(
         realm_repository: Arc<R>,
@@ -40,6 +46,7 @@ where
         client_scope_repository: Arc<CS>,
         protocol_mapper_repository: Arc<PM>,
         scope_mapping_repository: Arc<CSM>,
+        secret_rotation_repository: Arc<SR>,
     ) -> Self {
         Self {
             realm_repository,
@@ -47,6 +54,7 @@ where
             client_scope_repository,
             protocol_mapper_repository,
             scope_mapping_repository,
+            secret_rotation_repository,
         }
     }
 }
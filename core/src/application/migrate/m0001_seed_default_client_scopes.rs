@@ -8,7 +8,10 @@ use ferriskey_migrate::ports::{Migration, MigrationFuture};
 use serde_json::json;
 use uuid::Uuid;
 
-use crate::domain::{client::ports::ClientRepository, realm::ports::RealmRepository};
+use crate::domain::{
+    client::ports::ClientRepository, realm::ports::RealmRepository,
+    secrets::ports::SecretRotationRepository,
+};
 
 use super::context::MigrationContext;
 
@@ -140,13 +143,15 @@ fn default_scopes() -> Vec<ScopeTuple> {
     ]
 }
 
-impl<R, C, CS, PM, CSM> Migration<MigrationContext<R, C, CS, PM, CSM>> for SeedDefaultClientScopes
+impl<R, C, CS, PM, CSM, SR> Migration<MigrationContext<R, C, CS, PM, CSM, SR>>
+    for SeedDefaultClientScopes
 where
     R: RealmRepository + 'static,
     C: ClientRepository + 'static,
     CS: ClientScopeRepository + 'static,
     PM: ProtocolMapperRepository + 'static,
     CSM: ClientScopeMappingRepository + 'static,
+    SR: SecretRotationRepository + 'static,
 {
     fn version(&self) -> u64 {
         1
@@ -156,7 +161,7 @@ where
         "v0_4_0_seed_default_client_scopes"
     }
 
-    fn up<'a>(&'a self, ctx: &'a MigrationContext<R, C, CS, PM, CSM>) -> MigrationFuture<'a> {
+    fn up<'a>(&'a self, ctx: &'a MigrationContext<R, C, CS, PM, CSM, SR>) -> MigrationFuture<'a> {
         Box::pin(async move {
             let realms = ctx.realm_repository.fetch_realm().await?;
 
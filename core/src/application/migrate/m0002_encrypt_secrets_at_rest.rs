@@ -0,0 +1,55 @@
+use ferriskey_aegis::ports::{
+    ClientScopeMappingRepository, ClientScopeRepository, ProtocolMapperRepository,
+};
+use ferriskey_migrate::ports::{Migration, MigrationFuture};
+
+use crate::domain::{
+    client::ports::ClientRepository, realm::ports::RealmRepository,
+    secrets::ports::SecretRotationRepository,
+};
+
+use super::context::MigrationContext;
+
+/// V0_5_0 — Seal secrets stored in clear with the configured master key.
+///
+/// Covers OTP seeds, JWT signing keys, SMTP passwords, identity provider
+/// client secrets and LDAP bind passwords. Legacy base64 bind passwords are
+/// decoded first. Does nothing when no master key is configured; run
+/// `ferriskey rotate-secrets` once one is.
+pub struct EncryptSecretsAtRest;
+
+impl<R, C, CS, PM, CSM, SR> Migration<MigrationContext<R, C, CS, PM, CSM, SR>>
+    for EncryptSecretsAtRest
+where
+    R: RealmRepository + 'static,
+    C: ClientRepository + 'static,
+    CS: ClientScopeRepository + 'static,
+    PM: ProtocolMapperRepository + 'static,
+    CSM: ClientScopeMappingRepository + 'static,
+    SR: SecretRotationRepository + 'static,
+{
+    fn version(&self) -> u64 {
+        2
+    }
+
+    fn name(&self) -> &str {
+        "v0_5_0_encrypt_secrets_at_rest"
+    }
+
+    fn up<'a>(&'a self, ctx: &'a MigrationContext<R, C, CS, PM, CSM, SR>) -> MigrationFuture<'a> {
+        Box::pin(async move {
+            let report = ctx.secret_rotation_repository.rotate_secrets().await?;
+
+            tracing::info!(
+                credentials = report.credentials,
+                jwt_keys = report.jwt_keys,
+                smtp_configs = report.smtp_configs,
+                identity_providers = report.identity_providers,
+                federation_providers = report.federation_providers,
+                "sealed secrets at rest"
+            );
+
+            Ok(())
+        })
+    }
+}
@@ -1,5 +1,6 @@
 pub mod context;
 pub mod m0001_seed_default_client_scopes;
+pub mod m0002_encrypt_secrets_at_rest;
 
 use ferriskey_aegis::ports::{
     ClientScopeMappingRepository, ClientScopeRepository, ProtocolMapperRepository,
@@ -7,11 +8,17 @@ use ferriskey_aegis::ports::{
 use ferriskey_migrate::runner::MigrationRunner;
 
 use crate::{
-    domain::{client::ports::ClientRepository, realm::ports::RealmRepository},
+    domain::{
+        client::ports::ClientRepository, realm::ports::RealmRepository,
+        secrets::ports::SecretRotationRepository,
+    },
     infrastructure::migrate::repository::PostgresMigrationRepository,
 };
 
-use self::{context::MigrationContext, m0001_seed_default_client_scopes::SeedDefaultClientScopes};
+use self::{
+    context::MigrationContext, m0001_seed_default_client_scopes::SeedDefaultClientScopes,
+    m0002_encrypt_secrets_at_rest::EncryptSecretsAtRest,
+};
 
 /// Builds the migration runner with every registered data migration.
 ///
@@ -21,15 +28,18 @@ use self::{context::MigrationContext, m0001_seed_default_client_scopes::SeedDefa
 /// let ctx = MigrationContext::new(realm.clone(), client.clone(), ...);
 /// build_runner(db.clone()).run(&ctx).await?;
 /// ```
-pub fn build_runner<R, C, CS, PM, CSM>(
+pub fn build_runner<R, C, CS, PM, CSM, SR>(
     repository: PostgresMigrationRepository,
-) -> MigrationRunner<MigrationContext<R, C, CS, PM, CSM>, PostgresMigrationRepository>
+) -> MigrationRunner<MigrationContext<R, C, CS, PM, CSM, SR>, PostgresMigrationRepository>
 where
     R: RealmRepository + 'static,
     C: ClientRepository + 'static,
     CS: ClientScopeRepository + 'static,
     PM: ProtocolMapperRepository + 'static,
     CSM: ClientScopeMappingRepository + 'static,
+    SR: SecretRotationRepository + 'static,
 {
-    MigrationRunner::new(repository).register(SeedDefaultClientScopes)
+    MigrationRunner::new(repository)
+        .register(SeedDefaultClientScopes)
+        .register(EncryptSecretsAtRest)
 }
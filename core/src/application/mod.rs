@@ -8,9 +8,11 @@ use crate::{
             BrokerServiceImpl, IdentityProviderServiceImpl,
             federation::services::FederationServiceImpl,
         },
+        account::services::AccountServiceImpl,
         aegis::services::{
             ClientScopeServiceImpl, ProtocolMapperServiceImpl, ScopeMappingServiceImpl,
         },
+        auth_flow::services::AuthFlowServiceImpl,
         authentication::{
             device_flow::services::{DeviceFlowConfig, DeviceFlowServiceImpl},
             mapper_engine::MapperEngine,
@@ -23,28 +25,40 @@ use crate::{
         },
         compass::services::CompassServiceImpl,
         credential::services::CredentialServiceImpl,
+        email_outbox::services::{EmailOutboxServiceImpl, OutboxEmailPort},
         email_template::services::EmailTemplateServiceImpl,
         email_verification::services::EmailVerificationServiceImpl,
         health::services::HealthServiceImpl,
+        housekeeping::{registry::HousekeepingRegistry, services::HousekeepingServiceImpl},
+        localization::services::LocalizationServiceImpl,
         maintenance::services::MaintenanceServiceImpl,
+        one_time_code::services::OneTimeCodeServiceImpl,
         organization::services::OrganizationServiceImpl,
+        otp::services::OtpPolicyServiceImpl,
         password_policy::service::PasswordPolicyService,
         portal_layouts::services::PortalLayoutsServiceImpl,
         portal_theme::services::PortalThemeServiceImpl,
+        privacy::services::PrivacyServiceImpl,
         realm::services::{MailServiceImpl, RealmServiceImpl},
+        risk::services::{RiskEngine, RiskServiceImpl},
         role::services::RoleServiceImpl,
+        scim::services::ScimServiceImpl,
         seawatch::services::SecurityEventServiceImpl,
+        security_notification::services::{SecurityNotificationServiceImpl, SecurityNotifierImpl},
         trident::services::TridentServiceImpl,
+        trusted_device::services::TrustedDeviceServiceImpl,
         user::services::UserServiceImpl,
         webhook::services::WebhookServiceImpl,
     },
     infrastructure::{
         abyss::federation::repository::FederationRepositoryImpl,
+        account::repositories::PostgresAccountSessionRepository,
         aegis::repositories::{
             client_scope_postgres_repository::PostgresClientScopeRepository,
             protocol_mapper_postgres_repository::PostgresProtocolMapperRepository,
             scope_mapping_postgres_repository::PostgresScopeMappingRepository,
         },
+        auth_flow::repositories::PostgresAuthFlowRepository,
         client::repositories::{
             client_postgres_repository::PostgresClientRepository,
             post_logout_redirect_uri_postgres_repository::PostgresPostLogoutRedirectUriRepository,
@@ -55,25 +69,47 @@ use crate::{
             writer::compass_writer_task,
         },
         db::postgres::{Postgres, PostgresConfig},
-        email::SmtpEmailPort,
+        email::ProviderEmailPort,
+        email_outbox::{
+            repositories::PostgresOutboxEmailRepository,
+            scheduler::{EMAIL_OUTBOX_INTERVAL, email_outbox_task},
+        },
         email_template::{
             renderer::mjml_renderer::MjmlTemplateRenderer,
             repositories::email_template_repository::PostgresEmailTemplateRepository,
         },
         health::repositories::PostgresHealthCheckRepository,
+        housekeeping::{
+            lock::PostgresHousekeepingLock,
+            repositories::{
+                PostgresHousekeepingRepository, PostgresHousekeepingRetentionRepository,
+            },
+            scheduler::spawn_housekeeping,
+        },
         identity_provider::{
             PostgresBrokerAuthSessionRepository, PostgresIdentityProviderLinkRepository,
             PostgresIdentityProviderRepository, ReqwestOAuthClient,
         },
+        localization::repositories::PostgresLocalizationRepository,
         maintenance::repositories::{
+            maintenance_session_repository::PostgresMaintenanceSessionRepository,
             maintenance_whitelist_repository::PostgresMaintenanceWhitelistRepository,
+            maintenance_window_repository::PostgresMaintenanceWindowRepository,
+            realm_maintenance_repository::PostgresRealmMaintenanceRepository,
             realm_maintenance_whitelist_repository::PostgresRealmMaintenanceWhitelistRepository,
         },
+        maintenance::scheduler::{MAINTENANCE_WINDOW_INTERVAL, maintenance_window_task},
+        one_time_code::repositories::PostgresOneTimeCodeRepository,
         organization::{
             organization_attribute_repository::PostgresOrganizationAttributeRepository,
             organization_member_repository::PostgresOrganizationMemberRepository,
             organization_repository::PostgresOrganizationRepository,
         },
+        otp::repositories::PostgresOtpPolicyRepository,
+        privacy::{
+            repositories::PostgresAccountDeletionRepository,
+            scheduler::{ACCOUNT_DELETION_INTERVAL, account_deletion_task},
+        },
         realm::repositories::{
             realm_postgres_repository::PostgresRealmRepository,
             smtp_config_postgres_repository::PostgresSmtpConfigRepository,
@@ -94,8 +130,23 @@ use crate::{
             random_bytes_recovery_code::RandBytesRecoveryCodeRepository,
             refresh_token_repository::PostgresRefreshTokenRepository,
         },
+        risk::{ip_intelligence::FileIpIntelligence, repositories::PostgresRiskPolicyRepository},
         role::repositories::role_postgres_repository::PostgresRoleRepository,
-        seawatch::repositories::security_event_postgres_repository::PostgresSecurityEventRepository,
+        seawatch::{
+            checkpoint::security_event_checkpoint_task,
+            repositories::{
+                admin_event_postgres_repository::PostgresAdminEventRepository,
+                security_event_chain_postgres_repository::PostgresSecurityEventChainRepository,
+                security_event_postgres_repository::PostgresSecurityEventRepository,
+                security_event_retention_postgres_repository::PostgresSecurityEventRetentionRepository,
+            },
+            retention::security_event_retention_task,
+            syslog::syslog_forwarder_task,
+        },
+        secrets::{build_secret_cipher, rotation::PostgresSecretRotationRepository},
+        security_notification::repositories::PostgresSecurityNotificationRepository,
+        sms::HttpSmsSender,
+        trusted_device::repositories::PostgresTrustedDeviceRepository,
         user::{
             repositories::{
                 user_attribute_repository::PostgresUserAttributeRepository,
@@ -111,25 +162,37 @@ use crate::{
 pub mod services;
 
 pub mod abyss;
+pub mod account;
 pub mod aegis;
 pub mod auth;
+pub mod auth_flow;
 pub mod broker;
 pub mod client;
 pub mod compass;
 pub mod credential;
+pub mod email_outbox;
 pub mod email_template;
 pub mod health;
+pub mod housekeeping;
 pub mod identity_provider;
+pub mod localization;
 pub mod mail;
 pub mod maintenance;
 pub mod migrate;
+pub mod one_time_code;
 pub mod organization;
+pub mod otp;
 pub mod portal_layouts;
 pub mod portal_theme;
+pub mod privacy;
 pub mod realm;
+pub mod risk;
 pub mod role;
+pub mod scim;
 pub mod seawatch;
+pub mod security_notification;
 pub mod trident;
+pub mod trusted_device;
 pub mod user;
 pub mod webhook;
 
@@ -150,10 +213,15 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         .await
         .map_err(|e| CoreError::ServiceUnavailable(e.to_string()))?;
 
+    let cipher = build_secret_cipher(&config.secrets)?;
+
     let realm = Arc::new(PostgresRealmRepository::new(postgres.get_db()));
     let client = Arc::new(PostgresClientRepository::new(postgres.get_db()));
     let user = Arc::new(PostgresUserRepository::new(postgres.get_db()));
-    let credential = Arc::new(PostgresCredentialRepository::new(postgres.get_db()));
+    let credential = Arc::new(PostgresCredentialRepository::new(
+        postgres.get_db(),
+        cipher.clone(),
+    ));
     let hasher = Arc::new(Argon2HasherRepository::new());
     let auth_session = Arc::new(PostgresAuthSessionRepository::new(postgres.get_db()));
     let device_auth = Arc::new(PostgresDeviceAuthRepository::new(postgres.get_db()));
@@ -162,7 +230,10 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         postgres.get_db(),
     ));
     let role = Arc::new(PostgresRoleRepository::new(postgres.get_db()));
-    let keystore = Arc::new(PostgresKeyStoreRepository::new(postgres.get_db()));
+    let keystore = Arc::new(PostgresKeyStoreRepository::new(
+        postgres.get_db(),
+        cipher.clone(),
+    ));
     let user_role = Arc::new(PostgresUserRoleRepository::new(postgres.get_db()));
     let user_required_action =
         Arc::new(PostgresUserRequiredActionRepository::new(postgres.get_db()));
@@ -172,9 +243,32 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
     let refresh_token = Arc::new(PostgresRefreshTokenRepository::new(postgres.get_db()));
     let access_token = Arc::new(PostgresAccessTokenRepository::new(postgres.get_db()));
     let recovery_code = Arc::new(RandBytesRecoveryCodeRepository::new(hasher.clone()));
-    let security_event = Arc::new(PostgresSecurityEventRepository::new(postgres.get_db()));
-    let identity_provider = Arc::new(PostgresIdentityProviderRepository::new(postgres.get_db()));
-    let federation = Arc::new(FederationRepositoryImpl::new(postgres.get_db()));
+    let security_event = {
+        let repository = PostgresSecurityEventRepository::new(postgres.get_db());
+        match config.seawatch.syslog.clone() {
+            Some(syslog) => {
+                let (syslog_tx, syslog_rx) = tokio::sync::mpsc::channel(1024);
+                tokio::spawn(syslog_forwarder_task(syslog_rx, syslog));
+                Arc::new(repository.with_forwarder(syslog_tx))
+            }
+            None => Arc::new(repository),
+        }
+    };
+    let security_event_retention = Arc::new(PostgresSecurityEventRetentionRepository::new(
+        postgres.get_db(),
+    ));
+    let security_event_chain =
+        Arc::new(PostgresSecurityEventChainRepository::new(postgres.get_db()));
+    let admin_event = Arc::new(PostgresAdminEventRepository::new(postgres.get_db()));
+    let identity_provider = Arc::new(PostgresIdentityProviderRepository::new(
+        postgres.get_db(),
+        cipher.clone(),
+    ));
+    let password_policy = Arc::new(PostgresPasswordPolicyRepository::new(postgres.get_db()));
+    let federation = Arc::new(FederationRepositoryImpl::new(
+        postgres.get_db(),
+        cipher.clone(),
+    ));
     let broker_auth_session = Arc::new(PostgresBrokerAuthSessionRepository::new(postgres.get_db()));
     let identity_provider_link = Arc::new(PostgresIdentityProviderLinkRepository::new(
         postgres.get_db(),
@@ -186,8 +280,13 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
     let scope_mapping = Arc::new(PostgresScopeMappingRepository::new(postgres.get_db()));
     let compass_flow = Arc::new(PostgresCompassFlowRepository::new(postgres.get_db()));
     let compass_flow_step = Arc::new(PostgresCompassFlowStepRepository::new(postgres.get_db()));
-    let smtp_config = Arc::new(PostgresSmtpConfigRepository::new(postgres.get_db()));
-    let email_port = Arc::new(SmtpEmailPort::new());
+    let smtp_config = Arc::new(PostgresSmtpConfigRepository::new(
+        postgres.get_db(),
+        cipher.clone(),
+    ));
+    let email_outbox = Arc::new(PostgresOutboxEmailRepository::new(postgres.get_db()));
+    let email_transport = Arc::new(ProviderEmailPort::new(config.mail.maildir_path.clone()));
+    let email_port = Arc::new(OutboxEmailPort::new(email_outbox.clone()));
     let password_reset_token =
         Arc::new(PostgresPasswordResetTokenRepository::new(postgres.get_db()));
 
@@ -201,6 +300,32 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
     ));
     let organization_member =
         Arc::new(PostgresOrganizationMemberRepository::new(postgres.get_db()));
+    let localization = Arc::new(PostgresLocalizationRepository::new(postgres.get_db()));
+    let security_notification = Arc::new(PostgresSecurityNotificationRepository::new(
+        postgres.get_db(),
+    ));
+    let otp_policy = Arc::new(PostgresOtpPolicyRepository::new(postgres.get_db()));
+    let one_time_code = Arc::new(PostgresOneTimeCodeRepository::new(postgres.get_db()));
+    let auth_flow = Arc::new(PostgresAuthFlowRepository::new(postgres.get_db()));
+    let sms_sender = Arc::new(HttpSmsSender::new(config.sms.clone()));
+    let security_notifier = Arc::new(SecurityNotifierImpl::new(
+        user.clone(),
+        smtp_config.clone(),
+        email_port.clone(),
+        email_template.clone(),
+        mjml_renderer.clone(),
+        localization.clone(),
+        security_notification.clone(),
+    ));
+    let risk_policy = Arc::new(PostgresRiskPolicyRepository::new(postgres.get_db()));
+    let risk_engine = Arc::new(RiskEngine::new(
+        risk_policy.clone(),
+        security_notification.clone(),
+        Arc::new(FileIpIntelligence::load(&config.risk)?),
+        security_event.clone(),
+        security_notifier.clone(),
+    ));
+    let trusted_device = Arc::new(PostgresTrustedDeviceRepository::new(postgres.get_db()));
     let email_verification_token_repo = Arc::new(PostgresEmailVerificationTokenRepository::new(
         postgres.get_db(),
     ));
@@ -220,12 +345,49 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
     ));
     let flow_recorder = FlowRecorder::new(compass_tx);
 
+    tokio::spawn(security_event_retention_task(
+        PostgresSecurityEventRetentionRepository::new(postgres.get_db()),
+        PostgresSecurityEventRepository::new(postgres.get_db()),
+        config.seawatch.retention_interval,
+    ));
+
+    let housekeeping_retention = Arc::new(PostgresHousekeepingRetentionRepository::new(
+        postgres.get_db(),
+    ));
+    let housekeeping_registry = HousekeepingRegistry::new(&config.housekeeping);
+    spawn_housekeeping(
+        &config.housekeeping,
+        Arc::new(PostgresHousekeepingLock::new(postgres.get_db())),
+        Arc::new(PostgresHousekeepingRepository::new(postgres.get_db())),
+        housekeeping_retention.clone(),
+        housekeeping_registry.clone(),
+    );
+
+    tokio::spawn(security_event_checkpoint_task(
+        PostgresSecurityEventChainRepository::new(postgres.get_db()),
+        PostgresKeyStoreRepository::new(postgres.get_db(), cipher.clone()),
+        config.seawatch.checkpoint_interval,
+    ));
+
     let policy = Arc::new(FerriskeyPolicy::new(
         user.clone(),
         client.clone(),
         user_role.clone(),
     ));
 
+    let email_outbox_service = EmailOutboxServiceImpl::new(
+        realm.clone(),
+        email_outbox,
+        smtp_config.clone(),
+        email_transport.clone(),
+        security_event.clone(),
+        policy.clone(),
+    );
+    tokio::spawn(email_outbox_task(
+        email_outbox_service.clone(),
+        EMAIL_OUTBOX_INTERVAL,
+    ));
+
     let email_verification_service = EmailVerificationServiceImpl::new(
         email_verification_token_repo,
         user.clone(),
@@ -237,7 +399,47 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         mjml_renderer.clone(),
         webhook.clone(),
         security_event.clone(),
+        localization.clone(),
+    );
+
+    let maintenance_service = MaintenanceServiceImpl::new(
+        realm.clone(),
+        client.clone(),
+        user_role.clone(),
+        webhook.clone(),
+        security_event.clone(),
+        maintenance_whitelist.clone(),
+        realm_maintenance_whitelist.clone(),
+        Arc::new(PostgresRealmMaintenanceRepository::new(postgres.get_db())),
+        Arc::new(PostgresMaintenanceWindowRepository::new(postgres.get_db())),
+        Arc::new(PostgresMaintenanceSessionRepository::new(postgres.get_db())),
+        policy.clone(),
+    );
+    tokio::spawn(maintenance_window_task(
+        maintenance_service.clone(),
+        MAINTENANCE_WINDOW_INTERVAL,
+    ));
+
+    let privacy_service = PrivacyServiceImpl::new(
+        realm.clone(),
+        user.clone(),
+        user_role.clone(),
+        user_attribute.clone(),
+        organization_member.clone(),
+        credential.clone(),
+        identity_provider.clone(),
+        identity_provider_link.clone(),
+        Arc::new(PostgresAccountSessionRepository::new(postgres.get_db())),
+        security_event.clone(),
+        compass_flow.clone(),
+        Arc::new(PostgresAccountDeletionRepository::new(postgres.get_db())),
+        webhook.clone(),
+        policy.clone(),
     );
+    tokio::spawn(account_deletion_task(
+        privacy_service.clone(),
+        ACCOUNT_DELETION_INTERVAL,
+    ));
 
     let auth_service = AuthServiceImpl::new(
         realm.clone(),
@@ -259,12 +461,14 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         organization.clone(),
         organization_attribute.clone(),
         user_required_action.clone(),
-        maintenance_whitelist.clone(),
-        realm_maintenance_whitelist.clone(),
+        maintenance_service.clone(),
         user_attribute.clone(),
         email_verification_service.clone(),
         webhook.clone(),
         security_event.clone(),
+        auth_flow.clone(),
+        risk_engine,
+        trusted_device.clone(),
         Arc::new(MapperEngine::new()),
         flow_recorder.clone(),
     );
@@ -277,16 +481,11 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         DeviceFlowConfig::default(),
     );
 
+    // Sign-ins that don't start with a password go through its checks.
+    let sign_in_gate = Arc::new(auth_service.clone());
+
     let app = ApplicationService {
-        maintenance_service: MaintenanceServiceImpl::new(
-            realm.clone(),
-            client.clone(),
-            webhook.clone(),
-            security_event.clone(),
-            maintenance_whitelist.clone(),
-            realm_maintenance_whitelist.clone(),
-            policy.clone(),
-        ),
+        maintenance_service,
         auth_service,
         device_flow_service,
         client_service: ClientServiceImpl::new(
@@ -305,9 +504,16 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         credential_service: CredentialServiceImpl::new(
             realm.clone(),
             credential.clone(),
+            security_notifier.clone(),
             policy.clone(),
         ),
         health_service: HealthServiceImpl::new(health_check.clone()),
+        housekeeping_service: HousekeepingServiceImpl::new(
+            realm.clone(),
+            housekeeping_retention,
+            housekeeping_registry,
+            policy.clone(),
+        ),
         realm_service: RealmServiceImpl::new(
             realm.clone(),
             user.clone(),
@@ -334,6 +540,10 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
         security_event_service: SecurityEventServiceImpl::new(
             realm.clone(),
             security_event.clone(),
+            security_event_retention.clone(),
+            security_event_chain.clone(),
+            keystore.clone(),
+            admin_event.clone(),
             policy.clone(),
         ),
         trident_service: TridentServiceImpl::new(
@@ -352,6 +562,10 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             webhook.clone(),
             email_template.clone(),
             mjml_renderer.clone(),
+            localization.clone(),
+            security_notifier.clone(),
+            otp_policy.clone(),
+            sign_in_gate.clone(),
         ),
         user_service: UserServiceImpl::new(
             realm.clone(),
@@ -364,13 +578,74 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             user_attribute.clone(),
             webhook.clone(),
             security_event.clone(),
+            security_notifier.clone(),
             policy.clone(),
         ),
+        account_service: AccountServiceImpl::new(
+            realm.clone(),
+            user.clone(),
+            credential.clone(),
+            hasher.clone(),
+            password_policy.clone(),
+            identity_provider.clone(),
+            identity_provider_link.clone(),
+            Arc::new(PostgresAccountSessionRepository::new(postgres.get_db())),
+            security_event.clone(),
+            webhook.clone(),
+            security_notifier.clone(),
+        ),
+        privacy_service,
         webhook_service: WebhookServiceImpl::new(realm.clone(), webhook.clone(), policy.clone()),
+        email_outbox_service,
+        localization_service: LocalizationServiceImpl::new(
+            realm.clone(),
+            localization.clone(),
+            policy.clone(),
+        ),
+        security_notification_service: SecurityNotificationServiceImpl::new(
+            realm.clone(),
+            security_notification.clone(),
+            security_notifier.clone(),
+            policy.clone(),
+        ),
+        otp_policy_service: OtpPolicyServiceImpl::new(
+            realm.clone(),
+            otp_policy.clone(),
+            policy.clone(),
+        ),
+        auth_flow_service: AuthFlowServiceImpl::new(
+            realm.clone(),
+            client.clone(),
+            auth_flow.clone(),
+            policy.clone(),
+        ),
+        risk_service: RiskServiceImpl::new(realm.clone(), risk_policy, policy.clone()),
+        trusted_device_service: TrustedDeviceServiceImpl::new(
+            realm.clone(),
+            user.clone(),
+            trusted_device,
+            security_event.clone(),
+            policy.clone(),
+        ),
+        one_time_code_service: OneTimeCodeServiceImpl::new(
+            credential.clone(),
+            one_time_code,
+            user_required_action.clone(),
+            auth_session.clone(),
+            email_port.clone(),
+            smtp_config.clone(),
+            sms_sender,
+            localization.clone(),
+            security_notifier.clone(),
+        ),
         email_template_service: EmailTemplateServiceImpl::new(
             realm.clone(),
+            user.clone(),
             email_template.clone(),
             mjml_renderer.clone(),
+            smtp_config.clone(),
+            email_transport.clone(),
+            localization.clone(),
             policy.clone(),
         ),
         portal_theme_service: PortalThemeServiceImpl::new(
@@ -416,6 +691,7 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             user.clone(),
             auth_session.clone(),
             oauth_client.clone(),
+            sign_in_gate.clone(),
             flow_recorder.clone(),
         ),
         client_scope_service: ClientScopeServiceImpl::new(
@@ -442,19 +718,33 @@ pub async fn create_service(config: FerriskeyConfig) -> Result<ApplicationServic
             compass_flow_step.clone(),
             policy.clone(),
         ),
-        password_policy_service: PasswordPolicyService::new(
-            Arc::new(PostgresPasswordPolicyRepository::new(postgres.get_db())),
+        password_policy_service: PasswordPolicyService::new(password_policy, policy.clone()),
+        organization_service: OrganizationServiceImpl::new(
+            realm.clone(),
+            user.clone(),
+            organization.clone(),
+            organization_attribute.clone(),
+            organization_member.clone(),
             policy.clone(),
         ),
-        organization_service: OrganizationServiceImpl::new(
+        scim_service: ScimServiceImpl::new(
             realm.clone(),
             user.clone(),
+            user_role.clone(),
+            role.clone(),
+            user_attribute.clone(),
             organization.clone(),
             organization_attribute.clone(),
             organization_member.clone(),
+            security_event.clone(),
+            webhook.clone(),
             policy.clone(),
         ),
         flow_recorder,
+        secret_rotation: Arc::new(PostgresSecretRotationRepository::new(
+            postgres.get_db(),
+            cipher,
+        )),
         db: postgres.get_db(),
         email_verification_service,
     };
@@ -556,6 +846,12 @@ mod tests {
                 name: db_name,
                 schema: schema.clone(),
             },
+            seawatch: Default::default(),
+            housekeeping: Default::default(),
+            mail: Default::default(),
+            secrets: Default::default(),
+            sms: Default::default(),
+            risk: Default::default(),
         })
         .await
         .expect("create service");
@@ -0,0 +1,67 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        one_time_code::{
+            entities::{OneTimeCodeDelivery, OneTimeCodeMethod},
+            ports::OneTimeCodeService,
+            value_objects::{
+                ChallengeOneTimeCodeInput, ConfirmOneTimeCodeEnrollmentInput,
+                SendOneTimeCodeEnrollmentInput, SendOneTimeCodeInput,
+            },
+        },
+        trident::ports::ChallengeOtpOutput,
+    },
+};
+
+impl OneTimeCodeService for ApplicationService {
+    async fn send_one_time_code_enrollment(
+        &self,
+        identity: Identity,
+        input: SendOneTimeCodeEnrollmentInput,
+    ) -> Result<OneTimeCodeDelivery, CoreError> {
+        self.one_time_code_service
+            .send_one_time_code_enrollment(identity, input)
+            .await
+    }
+
+    async fn confirm_one_time_code_enrollment(
+        &self,
+        identity: Identity,
+        input: ConfirmOneTimeCodeEnrollmentInput,
+    ) -> Result<OneTimeCodeMethod, CoreError> {
+        self.one_time_code_service
+            .confirm_one_time_code_enrollment(identity, input)
+            .await
+    }
+
+    async fn list_one_time_code_methods(
+        &self,
+        identity: Identity,
+    ) -> Result<Vec<OneTimeCodeMethod>, CoreError> {
+        self.one_time_code_service
+            .list_one_time_code_methods(identity)
+            .await
+    }
+
+    async fn send_one_time_code(
+        &self,
+        identity: Identity,
+        input: SendOneTimeCodeInput,
+    ) -> Result<OneTimeCodeDelivery, CoreError> {
+        self.one_time_code_service
+            .send_one_time_code(identity, input)
+            .await
+    }
+
+    async fn challenge_one_time_code(
+        &self,
+        identity: Identity,
+        input: ChallengeOneTimeCodeInput,
+    ) -> Result<ChallengeOtpOutput, CoreError> {
+        self.one_time_code_service
+            .challenge_one_time_code(identity, input)
+            .await
+    }
+}
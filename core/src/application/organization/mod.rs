@@ -2,7 +2,7 @@ use crate::{
     ApplicationService,
     domain::{
         authentication::value_objects::Identity,
-        common::entities::app_errors::CoreError,
+        common::entities::{app_errors::CoreError, listing::Page},
         organization::ports::{
             AddOrganizationMemberInput, CreateOrganizationInput, DeleteOrganizationAttributeInput,
             DeleteOrganizationInput, GetOrganizationInput, ListOrganizationAttributesInput,
@@ -39,12 +39,22 @@ impl OrganizationService for ApplicationService {
         &self,
         identity: Identity,
         input: ListOrganizationsInput,
-    ) -> Result<Vec<Organization>, CoreError> {
+    ) -> Result<Page<Organization>, CoreError> {
         self.organization_service
             .list_organizations(identity, input)
             .await
     }
 
+    async fn count_organizations(
+        &self,
+        identity: Identity,
+        input: ListOrganizationsInput,
+    ) -> Result<u64, CoreError> {
+        self.organization_service
+            .count_organizations(identity, input)
+            .await
+    }
+
     async fn update_organization(
         &self,
         identity: Identity,
@@ -0,0 +1,34 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        otp::{
+            entities::OtpPolicy,
+            ports::OtpPolicyService,
+            value_objects::{GetOtpPolicyInput, UpdateOtpPolicyInput},
+        },
+    },
+};
+
+impl OtpPolicyService for ApplicationService {
+    async fn get_otp_policy(
+        &self,
+        identity: Identity,
+        input: GetOtpPolicyInput,
+    ) -> Result<OtpPolicy, CoreError> {
+        self.otp_policy_service
+            .get_otp_policy(identity, input)
+            .await
+    }
+
+    async fn update_otp_policy(
+        &self,
+        identity: Identity,
+        input: UpdateOtpPolicyInput,
+    ) -> Result<OtpPolicy, CoreError> {
+        self.otp_policy_service
+            .update_otp_policy(identity, input)
+            .await
+    }
+}
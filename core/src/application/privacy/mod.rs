@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    application::services::ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        privacy::{
+            entities::{AccountDeletionRequest, UserDataExport},
+            ports::PrivacyService,
+            value_objects::{RequestAccountDeletionInput, UserDataInput},
+        },
+    },
+};
+
+impl PrivacyService for ApplicationService {
+    async fn export_user_data(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> Result<UserDataExport, CoreError> {
+        self.privacy_service.export_user_data(identity, input).await
+    }
+
+    async fn request_account_deletion(
+        &self,
+        identity: Identity,
+        input: RequestAccountDeletionInput,
+    ) -> Result<AccountDeletionRequest, CoreError> {
+        self.privacy_service
+            .request_account_deletion(identity, input)
+            .await
+    }
+
+    async fn get_account_deletion(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> Result<Option<AccountDeletionRequest>, CoreError> {
+        self.privacy_service
+            .get_account_deletion(identity, input)
+            .await
+    }
+
+    async fn cancel_account_deletion(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> Result<(), CoreError> {
+        self.privacy_service
+            .cancel_account_deletion(identity, input)
+            .await
+    }
+
+    async fn process_due_account_deletions(&self, now: DateTime<Utc>) -> Result<u64, CoreError> {
+        self.privacy_service
+            .process_due_account_deletions(now)
+            .await
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        risk::{
+            entities::RiskPolicy,
+            ports::RiskService,
+            value_objects::{GetRiskPolicyInput, UpdateRiskPolicyInput},
+        },
+    },
+};
+
+impl RiskService for ApplicationService {
+    async fn get_risk_policy(
+        &self,
+        identity: Identity,
+        input: GetRiskPolicyInput,
+    ) -> Result<RiskPolicy, CoreError> {
+        self.risk_service.get_risk_policy(identity, input).await
+    }
+
+    async fn update_risk_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRiskPolicyInput,
+    ) -> Result<RiskPolicy, CoreError> {
+        self.risk_service.update_risk_policy(identity, input).await
+    }
+}
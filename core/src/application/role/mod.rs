@@ -4,9 +4,9 @@ use crate::{
     ApplicationService,
     domain::{
         authentication::value_objects::Identity,
-        common::entities::app_errors::CoreError,
+        common::entities::{app_errors::CoreError, listing::Page},
         role::{
-            entities::{CreateRoleInput, GetUserRolesInput, Role, UpdateRoleInput},
+            entities::{CreateRoleInput, GetRolesInput, GetUserRolesInput, Role, UpdateRoleInput},
             ports::RoleService,
         },
     },
@@ -46,9 +46,17 @@ impl RoleService for ApplicationService {
     async fn get_roles(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> Result<Vec<Role>, CoreError> {
-        self.role_service.get_roles(identity, realm_name).await
+        input: GetRolesInput,
+    ) -> Result<Page<Role>, CoreError> {
+        self.role_service.get_roles(identity, input).await
+    }
+
+    async fn count_roles(
+        &self,
+        identity: Identity,
+        input: GetRolesInput,
+    ) -> Result<u64, CoreError> {
+        self.role_service.count_roles(identity, input).await
     }
 
     async fn get_user_roles(
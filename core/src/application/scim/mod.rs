@@ -0,0 +1,124 @@
+use serde_json::Value;
+
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        scim::{
+            entities::{ScimBulkResponse, ScimGroup, ScimListResponse, ScimUser},
+            error::ScimError,
+            ports::ScimService,
+            value_objects::{
+                CreateScimGroupInput, CreateScimUserInput, DeleteScimResourceInput,
+                GetScimResourceInput, ListScimResourcesInput, PatchScimResourceInput,
+                ReplaceScimGroupInput, ReplaceScimUserInput, ScimBulkInput,
+            },
+        },
+    },
+};
+
+impl ScimService for ApplicationService {
+    async fn list_users(
+        &self,
+        identity: Identity,
+        input: ListScimResourcesInput,
+    ) -> Result<ScimListResponse, ScimError> {
+        self.scim_service.list_users(identity, input).await
+    }
+
+    async fn get_user(
+        &self,
+        identity: Identity,
+        input: GetScimResourceInput,
+    ) -> Result<Value, ScimError> {
+        self.scim_service.get_user(identity, input).await
+    }
+
+    async fn create_user(
+        &self,
+        identity: Identity,
+        input: CreateScimUserInput,
+    ) -> Result<ScimUser, ScimError> {
+        self.scim_service.create_user(identity, input).await
+    }
+
+    async fn replace_user(
+        &self,
+        identity: Identity,
+        input: ReplaceScimUserInput,
+    ) -> Result<ScimUser, ScimError> {
+        self.scim_service.replace_user(identity, input).await
+    }
+
+    async fn patch_user(
+        &self,
+        identity: Identity,
+        input: PatchScimResourceInput,
+    ) -> Result<ScimUser, ScimError> {
+        self.scim_service.patch_user(identity, input).await
+    }
+
+    async fn delete_user(
+        &self,
+        identity: Identity,
+        input: DeleteScimResourceInput,
+    ) -> Result<(), ScimError> {
+        self.scim_service.delete_user(identity, input).await
+    }
+
+    async fn list_groups(
+        &self,
+        identity: Identity,
+        input: ListScimResourcesInput,
+    ) -> Result<ScimListResponse, ScimError> {
+        self.scim_service.list_groups(identity, input).await
+    }
+
+    async fn get_group(
+        &self,
+        identity: Identity,
+        input: GetScimResourceInput,
+    ) -> Result<Value, ScimError> {
+        self.scim_service.get_group(identity, input).await
+    }
+
+    async fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateScimGroupInput,
+    ) -> Result<ScimGroup, ScimError> {
+        self.scim_service.create_group(identity, input).await
+    }
+
+    async fn replace_group(
+        &self,
+        identity: Identity,
+        input: ReplaceScimGroupInput,
+    ) -> Result<ScimGroup, ScimError> {
+        self.scim_service.replace_group(identity, input).await
+    }
+
+    async fn patch_group(
+        &self,
+        identity: Identity,
+        input: PatchScimResourceInput,
+    ) -> Result<ScimGroup, ScimError> {
+        self.scim_service.patch_group(identity, input).await
+    }
+
+    async fn delete_group(
+        &self,
+        identity: Identity,
+        input: DeleteScimResourceInput,
+    ) -> Result<(), ScimError> {
+        self.scim_service.delete_group(identity, input).await
+    }
+
+    async fn bulk(
+        &self,
+        identity: Identity,
+        input: ScimBulkInput,
+    ) -> Result<ScimBulkResponse, ScimError> {
+        self.scim_service.bulk(identity, input).await
+    }
+}
@@ -1,9 +1,20 @@
+use futures::stream::BoxStream;
+
 use crate::{
     application::services::ApplicationService,
     domain::{
         authentication::value_objects::Identity,
         common::entities::app_errors::CoreError,
-        seawatch::{SecurityEvent, ports::SecurityEventService, value_objects::FetchEventsInput},
+        seawatch::{
+            AdminEventPage, ChainVerificationReport, SecurityEventPage,
+            SecurityEventRetentionPolicy,
+            ports::SecurityEventService,
+            value_objects::{
+                ExportEventsInput, FetchAdminEventsInput, FetchEventsInput,
+                GetRetentionPolicyInput, RecordAdminEventInput, UpdateRetentionPolicyInput,
+                VerifyChainInput,
+            },
+        },
     },
 };
 
@@ -12,9 +23,69 @@ impl SecurityEventService for ApplicationService {
         &self,
         identity: Identity,
         input: FetchEventsInput,
-    ) -> Result<Vec<SecurityEvent>, CoreError> {
+    ) -> Result<SecurityEventPage, CoreError> {
         self.security_event_service
             .fetch_events(identity, input)
             .await
     }
+
+    async fn export_events(
+        &self,
+        identity: Identity,
+        input: ExportEventsInput,
+    ) -> Result<BoxStream<'static, Result<String, CoreError>>, CoreError> {
+        self.security_event_service
+            .export_events(identity, input)
+            .await
+    }
+
+    async fn get_retention_policy(
+        &self,
+        identity: Identity,
+        input: GetRetentionPolicyInput,
+    ) -> Result<SecurityEventRetentionPolicy, CoreError> {
+        self.security_event_service
+            .get_retention_policy(identity, input)
+            .await
+    }
+
+    async fn update_retention_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRetentionPolicyInput,
+    ) -> Result<SecurityEventRetentionPolicy, CoreError> {
+        self.security_event_service
+            .update_retention_policy(identity, input)
+            .await
+    }
+
+    async fn verify_chain(
+        &self,
+        identity: Identity,
+        input: VerifyChainInput,
+    ) -> Result<ChainVerificationReport, CoreError> {
+        self.security_event_service
+            .verify_chain(identity, input)
+            .await
+    }
+
+    async fn record_admin_event(
+        &self,
+        identity: Identity,
+        input: RecordAdminEventInput,
+    ) -> Result<(), CoreError> {
+        self.security_event_service
+            .record_admin_event(identity, input)
+            .await
+    }
+
+    async fn fetch_admin_events(
+        &self,
+        identity: Identity,
+        input: FetchAdminEventsInput,
+    ) -> Result<AdminEventPage, CoreError> {
+        self.security_event_service
+            .fetch_admin_events(identity, input)
+            .await
+    }
 }
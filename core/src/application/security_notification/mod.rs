@@ -0,0 +1,43 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        security_notification::{
+            entities::SecurityNotificationSettings,
+            ports::SecurityNotificationService,
+            value_objects::{
+                GetSecurityNotificationSettingsInput, RecordSignInInput,
+                UpdateSecurityNotificationSettingsInput,
+            },
+        },
+    },
+};
+
+impl SecurityNotificationService for ApplicationService {
+    async fn get_security_notification_settings(
+        &self,
+        identity: Identity,
+        input: GetSecurityNotificationSettingsInput,
+    ) -> Result<SecurityNotificationSettings, CoreError> {
+        self.security_notification_service
+            .get_security_notification_settings(identity, input)
+            .await
+    }
+
+    async fn update_security_notification_settings(
+        &self,
+        identity: Identity,
+        input: UpdateSecurityNotificationSettingsInput,
+    ) -> Result<SecurityNotificationSettings, CoreError> {
+        self.security_notification_service
+            .update_security_notification_settings(identity, input)
+            .await
+    }
+
+    async fn record_sign_in(&self, input: RecordSignInInput) -> Result<(), CoreError> {
+        self.security_notification_service
+            .record_sign_in(input)
+            .await
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use ferriskey_compass::recorder::FlowRecorder;
 use ferriskey_migrate::{entities::MigrationReport, error::MigrationError};
 use sea_orm::DatabaseConnection;
@@ -6,9 +8,11 @@ use crate::{
     application::migrate::{build_runner, context::MigrationContext},
     domain::{
         abyss::{BrokerServiceImpl, IdentityProviderServiceImpl},
+        account::services::AccountServiceImpl,
         aegis::services::{
             ClientScopeServiceImpl, ProtocolMapperServiceImpl, ScopeMappingServiceImpl,
         },
+        auth_flow::services::AuthFlowServiceImpl,
         authentication::{
             device_flow::{
                 error::DeviceFlowError,
@@ -32,56 +36,76 @@ use crate::{
         },
         compass::services::CompassServiceImpl,
         credential::services::CredentialServiceImpl,
+        email_outbox::services::{EmailOutboxServiceImpl, OutboxEmailPort},
         email_template::services::EmailTemplateServiceImpl,
         email_verification::services::EmailVerificationServiceImpl,
         health::services::HealthServiceImpl,
+        housekeeping::services::HousekeepingServiceImpl,
+        localization::services::LocalizationServiceImpl,
         maintenance::services::MaintenanceServiceImpl,
+        one_time_code::services::OneTimeCodeServiceImpl,
         organization::services::OrganizationServiceImpl,
+        otp::services::OtpPolicyServiceImpl,
         password_policy::{
             entity::{PasswordPolicy, UpdatePasswordPolicy},
             service::PasswordPolicyService,
         },
         portal_layouts::services::PortalLayoutsServiceImpl,
         portal_theme::services::PortalThemeServiceImpl,
+        privacy::services::PrivacyServiceImpl,
         realm::{
             ports::RealmRepository,
             services::{MailServiceImpl, RealmServiceImpl},
         },
+        risk::services::{RiskEngine, RiskServiceImpl},
         role::services::RoleServiceImpl,
-        seawatch::services::SecurityEventServiceImpl,
+        scim::services::ScimServiceImpl,
+        seawatch::{ChainVerificationReport, services::SecurityEventServiceImpl},
+        secrets::{entities::SecretRotationReport, ports::SecretRotationRepository},
+        security_notification::services::{SecurityNotificationServiceImpl, SecurityNotifierImpl},
         trident::services::TridentServiceImpl,
+        trusted_device::services::TrustedDeviceServiceImpl,
         user::services::UserServiceImpl,
         webhook::services::WebhookServiceImpl,
     },
     infrastructure::migrate::repository::PostgresMigrationRepository,
+    infrastructure::secrets::rotation::PostgresSecretRotationRepository,
     infrastructure::{
         abyss::federation::repository::FederationRepositoryImpl,
+        account::repositories::PostgresAccountSessionRepository,
         aegis::repositories::{
             client_scope_postgres_repository::PostgresClientScopeRepository,
             protocol_mapper_postgres_repository::PostgresProtocolMapperRepository,
             scope_mapping_postgres_repository::PostgresScopeMappingRepository,
         },
+        auth_flow::repositories::PostgresAuthFlowRepository,
         client::repositories::{
             client_postgres_repository::PostgresClientRepository,
             post_logout_redirect_uri_postgres_repository::PostgresPostLogoutRedirectUriRepository,
             redirect_uri_postgres_repository::PostgresRedirectUriRepository,
         },
         compass::repositories::{PostgresCompassFlowRepository, PostgresCompassFlowStepRepository},
-        email::SmtpEmailPort,
+        email::ProviderEmailPort,
+        email_outbox::repositories::PostgresOutboxEmailRepository,
         email_template::{
             renderer::mjml_renderer::MjmlTemplateRenderer,
             repositories::email_template_repository::PostgresEmailTemplateRepository,
         },
         health::repositories::PostgresHealthCheckRepository,
+        housekeeping::repositories::PostgresHousekeepingRetentionRepository,
         identity_provider::{
             PostgresBrokerAuthSessionRepository, PostgresIdentityProviderLinkRepository,
             PostgresIdentityProviderRepository, ReqwestOAuthClient,
         },
+        localization::repositories::PostgresLocalizationRepository,
+        one_time_code::repositories::PostgresOneTimeCodeRepository,
         organization::{
             organization_attribute_repository::PostgresOrganizationAttributeRepository,
             organization_member_repository::PostgresOrganizationMemberRepository,
             organization_repository::PostgresOrganizationRepository,
         },
+        otp::repositories::PostgresOtpPolicyRepository,
+        privacy::repositories::PostgresAccountDeletionRepository,
         realm::repositories::{
             realm_postgres_repository::PostgresRealmRepository,
             smtp_config_postgres_repository::PostgresSmtpConfigRepository,
@@ -101,8 +125,17 @@ use crate::{
             random_bytes_recovery_code::RandBytesRecoveryCodeRepository,
             refresh_token_repository::PostgresRefreshTokenRepository,
         },
+        risk::{ip_intelligence::FileIpIntelligence, repositories::PostgresRiskPolicyRepository},
         role::repositories::role_postgres_repository::PostgresRoleRepository,
-        seawatch::repositories::security_event_postgres_repository::PostgresSecurityEventRepository,
+        seawatch::repositories::{
+            admin_event_postgres_repository::PostgresAdminEventRepository,
+            security_event_chain_postgres_repository::PostgresSecurityEventChainRepository,
+            security_event_postgres_repository::PostgresSecurityEventRepository,
+            security_event_retention_postgres_repository::PostgresSecurityEventRetentionRepository,
+        },
+        security_notification::repositories::PostgresSecurityNotificationRepository,
+        sms::HttpSmsSender,
+        trusted_device::repositories::PostgresTrustedDeviceRepository,
         user::{
             repositories::{
                 user_attribute_repository::PostgresUserAttributeRepository,
@@ -120,6 +153,9 @@ type ClientRepo = PostgresClientRepository;
 type UserRepo = PostgresUserRepository;
 type UserRoleRepo = PostgresUserRoleRepository;
 type SecurityEventRepo = PostgresSecurityEventRepository;
+type SecurityEventRetentionRepo = PostgresSecurityEventRetentionRepository;
+type SecurityEventChainRepo = PostgresSecurityEventChainRepository;
+type AdminEventRepo = PostgresAdminEventRepository;
 type CredentialRepo = PostgresCredentialRepository;
 type WebhookRepo = PostgresWebhookRepository;
 type RedirectUriRepo = PostgresRedirectUriRepository;
@@ -146,7 +182,9 @@ type MagicLinkRepo = PostgresMagicLinkRepository;
 type CompassFlowRepo = PostgresCompassFlowRepository;
 type CompassFlowStepRepo = PostgresCompassFlowStepRepository;
 type SmtpConfigRepo = PostgresSmtpConfigRepository;
-type EmailPortImpl = SmtpEmailPort;
+type EmailOutboxRepo = PostgresOutboxEmailRepository;
+/// Services queue their emails; only the outbox worker talks SMTP.
+type EmailPortImpl = OutboxEmailPort<EmailOutboxRepo>;
 type PasswordResetTokenRepo = PostgresPasswordResetTokenRepository;
 type PasswordPolicyRepo = crate::infrastructure::repositories::password_policy_repository::PostgresPasswordPolicyRepository;
 type EmailTemplateRepo = PostgresEmailTemplateRepository;
@@ -157,6 +195,32 @@ type OrganizationRepo = PostgresOrganizationRepository;
 type OrganizationAttributeRepo = PostgresOrganizationAttributeRepository;
 type OrganizationMemberRepo = PostgresOrganizationMemberRepository;
 type EmailVerificationTokenRepo = PostgresEmailVerificationTokenRepository;
+type HousekeepingRetentionRepo = PostgresHousekeepingRetentionRepository;
+type LocalizationRepo = PostgresLocalizationRepository;
+type SecurityNotificationRepo = PostgresSecurityNotificationRepository;
+type OtpPolicyRepo = PostgresOtpPolicyRepository;
+type OneTimeCodeRepo = PostgresOneTimeCodeRepository;
+type AuthFlowRepo = PostgresAuthFlowRepository;
+type SmsSenderImpl = HttpSmsSender;
+type RiskPolicyRepo = PostgresRiskPolicyRepository;
+type TrustedDeviceRepo = PostgresTrustedDeviceRepository;
+type IpIntelligenceImpl = FileIpIntelligence;
+type SecurityNotifierType = SecurityNotifierImpl<
+    UserRepo,
+    SmtpConfigRepo,
+    EmailPortImpl,
+    EmailTemplateRepo,
+    MjmlRenderer,
+    LocalizationRepo,
+    SecurityNotificationRepo,
+>;
+type RiskEngineType = RiskEngine<
+    RiskPolicyRepo,
+    SecurityNotificationRepo,
+    IpIntelligenceImpl,
+    SecurityEventRepo,
+    SecurityNotifierType,
+>;
 
 type ApplicationTridentService = TridentServiceImpl<
     CredentialRepo,
@@ -174,10 +238,17 @@ type ApplicationTridentService = TridentServiceImpl<
     WebhookRepo,
     EmailTemplateRepo,
     MjmlRenderer,
+    LocalizationRepo,
+    SecurityNotifierType,
+    OtpPolicyRepo,
+    ApplicationAuthService,
 >;
 
 type MaintenanceWhitelistRepo = crate::infrastructure::maintenance::repositories::maintenance_whitelist_repository::PostgresMaintenanceWhitelistRepository;
 type RealmMaintenanceWhitelistRepo = crate::infrastructure::maintenance::repositories::realm_maintenance_whitelist_repository::PostgresRealmMaintenanceWhitelistRepository;
+type RealmMaintenanceRepo = crate::infrastructure::maintenance::repositories::realm_maintenance_repository::PostgresRealmMaintenanceRepository;
+type MaintenanceWindowRepo = crate::infrastructure::maintenance::repositories::maintenance_window_repository::PostgresMaintenanceWindowRepository;
+type MaintenanceSessionRepo = crate::infrastructure::maintenance::repositories::maintenance_session_repository::PostgresMaintenanceSessionRepository;
 type ApplicationEmailVerificationService = EmailVerificationServiceImpl<
     EmailVerificationTokenRepo,
     UserRepo,
@@ -189,6 +260,91 @@ type ApplicationEmailVerificationService = EmailVerificationServiceImpl<
     MjmlRenderer,
     WebhookRepo,
     SecurityEventRepo,
+    LocalizationRepo,
+>;
+
+type ApplicationAccountService = AccountServiceImpl<
+    RealmRepo,
+    UserRepo,
+    CredentialRepo,
+    HasherRepo,
+    PasswordPolicyRepo,
+    IdentityProviderRepo,
+    IdentityProviderLinkRepo,
+    PostgresAccountSessionRepository,
+    SecurityEventRepo,
+    WebhookRepo,
+    SecurityNotifierType,
+>;
+
+type ApplicationPrivacyService = PrivacyServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    UserAttributeRepo,
+    OrganizationMemberRepo,
+    CredentialRepo,
+    IdentityProviderRepo,
+    IdentityProviderLinkRepo,
+    PostgresAccountSessionRepository,
+    SecurityEventRepo,
+    CompassFlowRepo,
+    PostgresAccountDeletionRepository,
+    WebhookRepo,
+>;
+
+type ApplicationLocalizationService =
+    LocalizationServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, LocalizationRepo>;
+
+type ApplicationSecurityNotificationService = SecurityNotificationServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    SecurityNotificationRepo,
+    SecurityNotifierType,
+>;
+
+type ApplicationOtpPolicyService =
+    OtpPolicyServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, OtpPolicyRepo>;
+
+type ApplicationAuthFlowService =
+    AuthFlowServiceImpl<RealmRepo, ClientRepo, UserRepo, UserRoleRepo, AuthFlowRepo>;
+
+type ApplicationRiskService =
+    RiskServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, RiskPolicyRepo>;
+
+type ApplicationTrustedDeviceService = TrustedDeviceServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    TrustedDeviceRepo,
+    SecurityEventRepo,
+>;
+
+type ApplicationOneTimeCodeService = OneTimeCodeServiceImpl<
+    CredentialRepo,
+    OneTimeCodeRepo,
+    UserRequiredActionRepo,
+    AuthSessionRepo,
+    EmailPortImpl,
+    SmtpConfigRepo,
+    SmsSenderImpl,
+    LocalizationRepo,
+    SecurityNotifierType,
+>;
+
+pub(crate) type ApplicationEmailOutboxService = EmailOutboxServiceImpl<
+    RealmRepo,
+    UserRepo,
+    ClientRepo,
+    UserRoleRepo,
+    EmailOutboxRepo,
+    SmtpConfigRepo,
+    ProviderEmailPort,
+    SecurityEventRepo,
 >;
 
 type ApplicationMaintenanceService = MaintenanceServiceImpl<
@@ -200,6 +356,9 @@ type ApplicationMaintenanceService = MaintenanceServiceImpl<
     SecurityEventRepo,
     MaintenanceWhitelistRepo,
     RealmMaintenanceWhitelistRepo,
+    RealmMaintenanceRepo,
+    MaintenanceWindowRepo,
+    MaintenanceSessionRepo,
 >;
 
 type ApplicationAuthService = AuthServiceImpl<
@@ -222,12 +381,14 @@ type ApplicationAuthService = AuthServiceImpl<
     OrganizationRepo,
     OrganizationAttributeRepo,
     UserRequiredActionRepo,
-    MaintenanceWhitelistRepo,
-    RealmMaintenanceWhitelistRepo,
+    ApplicationMaintenanceService,
     UserAttributeRepo,
     ApplicationEmailVerificationService,
     WebhookRepo,
     SecurityEventRepo,
+    AuthFlowRepo,
+    RiskEngineType,
+    TrustedDeviceRepo,
 >;
 
 type DeviceAuthRepo = PostgresDeviceAuthRepository;
@@ -248,10 +409,25 @@ type ApplicationDeviceFlowService =
 
 #[derive(Clone, Debug)]
 pub struct ApplicationService {
-    pub(crate) security_event_service:
-        SecurityEventServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, SecurityEventRepo>,
-    pub(crate) credential_service:
-        CredentialServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, CredentialRepo>,
+    pub(crate) security_event_service: SecurityEventServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        SecurityEventRepo,
+        SecurityEventRetentionRepo,
+        SecurityEventChainRepo,
+        KeystoreRepo,
+        AdminEventRepo,
+    >,
+    pub(crate) credential_service: CredentialServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        CredentialRepo,
+        SecurityNotifierType,
+    >,
     pub(crate) client_service: ClientServiceImpl<
         RealmRepo,
         UserRepo,
@@ -302,10 +478,28 @@ pub struct ApplicationService {
         WebhookRepo,
         SecurityEventRepo,
         UserAttributeRepo,
+        SecurityNotifierType,
     >,
+    pub(crate) account_service: ApplicationAccountService,
+    pub(crate) privacy_service: ApplicationPrivacyService,
     pub(crate) health_service: HealthServiceImpl<HealthCheckRepo>,
+    pub(crate) housekeeping_service: HousekeepingServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        HousekeepingRetentionRepo,
+    >,
     pub(crate) webhook_service:
         WebhookServiceImpl<RealmRepo, UserRepo, ClientRepo, UserRoleRepo, WebhookRepo>,
+    pub(crate) email_outbox_service: ApplicationEmailOutboxService,
+    pub(crate) localization_service: ApplicationLocalizationService,
+    pub(crate) security_notification_service: ApplicationSecurityNotificationService,
+    pub(crate) otp_policy_service: ApplicationOtpPolicyService,
+    pub(crate) one_time_code_service: ApplicationOneTimeCodeService,
+    pub(crate) auth_flow_service: ApplicationAuthFlowService,
+    pub(crate) risk_service: ApplicationRiskService,
+    pub(crate) trusted_device_service: ApplicationTrustedDeviceService,
 
     pub(crate) maintenance_service: ApplicationMaintenanceService,
     pub(crate) auth_service: ApplicationAuthService,
@@ -344,6 +538,7 @@ pub struct ApplicationService {
         UserRepo,
         AuthSessionRepo,
         OAuthClientImpl,
+        ApplicationAuthService,
     >,
     pub(crate) client_scope_service: ClientScopeServiceImpl<
         RealmRepo,
@@ -384,6 +579,9 @@ pub struct ApplicationService {
         UserRoleRepo,
         EmailTemplateRepo,
         MjmlRenderer,
+        SmtpConfigRepo,
+        ProviderEmailPort,
+        LocalizationRepo,
     >,
     #[allow(dead_code)]
     pub(crate) portal_theme_service:
@@ -402,8 +600,22 @@ pub struct ApplicationService {
         OrganizationAttributeRepo,
         OrganizationMemberRepo,
     >,
+    pub(crate) scim_service: ScimServiceImpl<
+        RealmRepo,
+        UserRepo,
+        ClientRepo,
+        UserRoleRepo,
+        RoleRepo,
+        UserAttributeRepo,
+        OrganizationRepo,
+        OrganizationAttributeRepo,
+        OrganizationMemberRepo,
+        SecurityEventRepo,
+        WebhookRepo,
+    >,
     #[allow(dead_code)]
     pub(crate) flow_recorder: FlowRecorder,
+    pub(crate) secret_rotation: Arc<PostgresSecretRotationRepository>,
     pub(crate) db: DatabaseConnection,
     pub email_verification_service: ApplicationEmailVerificationService,
 }
@@ -560,6 +772,19 @@ impl ApplicationService {
         self.device_flow_service.deny(user_code, user_id).await
     }
 
+    pub async fn verify_audit_chain(
+        &self,
+        realm_name: &str,
+    ) -> Result<ChainVerificationReport, CoreError> {
+        self.security_event_service
+            .verify_realm_chain(realm_name)
+            .await
+    }
+
+    pub async fn rotate_secrets(&self) -> Result<SecretRotationReport, CoreError> {
+        self.secret_rotation.rotate_secrets().await
+    }
+
     pub async fn run_data_migrations(&self) -> Result<MigrationReport, MigrationError> {
         let ctx = MigrationContext::new(
             self.realm_service.realm_repository.clone(),
@@ -567,6 +792,7 @@ impl ApplicationService {
             self.realm_service.client_scope_repository.clone(),
             self.realm_service.protocol_mapper_repository.clone(),
             self.realm_service.client_scope_mapping_repository.clone(),
+            self.secret_rotation.clone(),
         );
 
         build_runner(PostgresMigrationRepository::new(self.db.clone()))
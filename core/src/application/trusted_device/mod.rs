@@ -0,0 +1,41 @@
+use crate::{
+    ApplicationService,
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        trusted_device::{
+            entities::{IssuedTrustedDevice, TrustedDevice},
+            ports::TrustedDeviceService,
+            value_objects::{ListTrustedDevicesInput, RevokeTrustedDeviceInput, TrustDeviceInput},
+        },
+    },
+};
+
+impl TrustedDeviceService for ApplicationService {
+    async fn trust_device(
+        &self,
+        input: TrustDeviceInput,
+    ) -> Result<Option<IssuedTrustedDevice>, CoreError> {
+        self.trusted_device_service.trust_device(input).await
+    }
+
+    async fn list_trusted_devices(
+        &self,
+        identity: Identity,
+        input: ListTrustedDevicesInput,
+    ) -> Result<Vec<TrustedDevice>, CoreError> {
+        self.trusted_device_service
+            .list_trusted_devices(identity, input)
+            .await
+    }
+
+    async fn revoke_trusted_device(
+        &self,
+        identity: Identity,
+        input: RevokeTrustedDeviceInput,
+    ) -> Result<(), CoreError> {
+        self.trusted_device_service
+            .revoke_trusted_device(identity, input)
+            .await
+    }
+}
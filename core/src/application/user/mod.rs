@@ -2,13 +2,13 @@ use crate::{
     ApplicationService,
     domain::{
         authentication::value_objects::Identity,
-        common::entities::app_errors::CoreError,
+        common::entities::{app_errors::CoreError, listing::Page},
         role::entities::permission::Permissions,
         user::{
             entities::{
                 AssignRoleInput, CreateUserInput, DeleteUserAttributeInput, GetUserAttributesInput,
-                GetUserInput, GetUserPermissionsInput, ResetPasswordInput, SetUserAttributesInput,
-                UnassignRoleInput, UpdateUserInput, User, UserAttribute,
+                GetUserInput, GetUserPermissionsInput, GetUsersInput, ResetPasswordInput,
+                SetUserAttributesInput, UnassignRoleInput, UpdateUserInput, User, UserAttribute,
             },
             ports::UserService,
         },
@@ -58,9 +58,17 @@ impl UserService for ApplicationService {
     async fn get_users(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> Result<Vec<User>, CoreError> {
-        self.user_service.get_users(identity, realm_name).await
+        input: GetUsersInput,
+    ) -> Result<Page<User>, CoreError> {
+        self.user_service.get_users(identity, input).await
+    }
+
+    async fn count_users(
+        &self,
+        identity: Identity,
+        input: GetUsersInput,
+    ) -> Result<u64, CoreError> {
+        self.user_service.count_users(identity, input).await
     }
 
     async fn reset_password(
@@ -18,8 +18,10 @@ use crate::domain::abyss::identity_provider::broker::{
     OAuthClient, OAuthProviderConfig, OAuthTokenResponse,
 };
 use crate::domain::abyss::identity_provider::{IdentityProvider, IdentityProviderRepository};
+use crate::domain::auth_flow::entities::Authenticator;
 use crate::domain::authentication::entities::{AuthSession, AuthSessionParams};
-use crate::domain::authentication::ports::AuthSessionRepository;
+use crate::domain::authentication::ports::{AuthSessionRepository, SignInGate};
+use crate::domain::authentication::value_objects::CheckSignInInput;
 use crate::domain::client::ports::{ClientRepository, RedirectUriRepository};
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::domain::realm::entities::RealmId;
@@ -30,7 +32,7 @@ use crate::domain::user::value_objects::CreateUserRequest;
 
 /// Implementation of the BrokerService trait
 #[derive(Clone, Debug)]
-pub struct BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC>
+pub struct BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC, SG>
 where
     RR: RealmRepository,
     IR: IdentityProviderRepository,
@@ -41,6 +43,7 @@ where
     UR: UserRepository,
     ASR: AuthSessionRepository,
     OC: OAuthClient,
+    SG: SignInGate,
 {
     realm_repository: Arc<RR>,
     identity_provider_repository: Arc<IR>,
@@ -51,6 +54,7 @@ where
     user_repository: Arc<UR>,
     auth_session_repository: Arc<ASR>,
     oauth_client: Arc<OC>,
+    sign_in_gate: Arc<SG>,
     flow_recorder: FlowRecorder,
 }
 
@@ -78,7 +82,8 @@ fn evaluate_redirect_uri(allowed: &[String], redirect_uri: &str) -> Result<(), C
     }
 }
 
-impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC> BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC>
+impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC, SG>
+    BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC, SG>
 where
     RR: RealmRepository,
     IR: IdentityProviderRepository,
@@ -89,6 +94,7 @@ where
     UR: UserRepository,
     ASR: AuthSessionRepository,
     OC: OAuthClient,
+    SG: SignInGate,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -101,6 +107,7 @@ where
         user_repository: Arc<UR>,
         auth_session_repository: Arc<ASR>,
         oauth_client: Arc<OC>,
+        sign_in_gate: Arc<SG>,
         flow_recorder: FlowRecorder,
     ) -> Self {
         Self {
@@ -113,6 +120,7 @@ where
             user_repository,
             auth_session_repository,
             oauth_client,
+            sign_in_gate,
             flow_recorder,
         }
     }
@@ -351,8 +359,8 @@ where
     }
 }
 
-impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC> BrokerService
-    for BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC>
+impl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC, SG> BrokerService
+    for BrokerServiceImpl<RR, IR, BR, LR, CR, RUR, UR, ASR, OC, SG>
 where
     RR: RealmRepository,
     IR: IdentityProviderRepository,
@@ -363,6 +371,7 @@ where
     UR: UserRepository,
     ASR: AuthSessionRepository,
     OC: OAuthClient,
+    SG: SignInGate,
 {
     #[instrument(
         skip(self, input),
@@ -643,20 +652,15 @@ where
             return Err(CoreError::UserDisabled);
         }
 
-        // 9. Create or update auth session with authorization code
+        // 9. Attach the user to the auth session
         // Set compass_flow_id so authorization_code() records TokenExchange + complete_flow
-        let authorization_code = Self::generate_random_string(32);
-
-        if let Some(auth_session_id) = broker_session.auth_session_id {
-            self.auth_session_repository
-                .update_user_id(auth_session_id, user.id)
-                .await?;
-            self.auth_session_repository
-                .update_code(auth_session_id, authorization_code.clone())
-                .await?;
+        let auth_session = if let Some(auth_session_id) = broker_session.auth_session_id {
             self.auth_session_repository
                 .update_compass_flow_id(auth_session_id, flow_id.0)
                 .await?;
+            self.auth_session_repository
+                .update_user_id(auth_session_id, user.id)
+                .await?
         } else {
             let auth_session = AuthSession::new(AuthSessionParams {
                 realm_id: realm.id,
@@ -667,21 +671,66 @@ where
                 state: broker_session.state.clone(),
                 nonce: broker_session.nonce.clone(),
                 user_id: Some(user.id),
-                code: Some(authorization_code.clone()),
+                code: None,
                 authenticated: false,
                 webauthn_challenge: None,
                 webauthn_challenge_issued_at: None,
                 compass_flow_id: Some(flow_id.0),
             });
-            self.auth_session_repository.create(&auth_session).await?;
-        }
+            self.auth_session_repository.create(&auth_session).await?
+        };
 
         // 10. Clean up broker session
         self.broker_session_repository
             .delete(broker_session.id)
             .await?;
 
-        // 11. Build redirect URL back to client
+        // 11. Run the checks of a password sign-in, which may ask for a
+        // second factor before any code is issued
+        let check = self
+            .sign_in_gate
+            .check_sign_in(CheckSignInInput {
+                auth_session: auth_session.clone(),
+                user_id: user.id,
+                authenticator: Authenticator::IdentityProvider {
+                    alias: idp.alias.clone(),
+                },
+                base_url: input.base_url.clone(),
+                ip_address: input.ip_address.clone(),
+                user_agent: input.user_agent.clone(),
+                trusted_device_token: input.trusted_device_token.clone(),
+            })
+            .await?;
+
+        if let (Some(next), Some(token)) = (check.next, check.temporary_token) {
+            let methods = next
+                .methods
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+
+            return Ok(BrokerCallbackOutput {
+                redirect_url: format!(
+                    "{}/realms/{}/authentication/otp?token={}&methods={}",
+                    input.frontend_url.trim_end_matches('/'),
+                    realm.name,
+                    urlencoding::encode(&token),
+                    urlencoding::encode(&methods)
+                ),
+                authorization_code: None,
+                user_id: user.id,
+                is_new_user,
+                client_id: client.client_id,
+            });
+        }
+
+        let authorization_code = Self::generate_random_string(32);
+        self.auth_session_repository
+            .update_code(auth_session.id, authorization_code.clone())
+            .await?;
+
+        // 12. Build redirect URL back to client
         let mut redirect_url = broker_session.redirect_uri.clone();
         redirect_url.push_str(&format!(
             "?code={}",
@@ -693,7 +742,7 @@ where
 
         Ok(BrokerCallbackOutput {
             redirect_url,
-            authorization_code,
+            authorization_code: Some(authorization_code),
             user_id: user.id,
             is_new_user,
             client_id: client.client_id,
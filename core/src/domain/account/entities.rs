@@ -1,4 +1,6 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::domain::realm::entities::RealmId;
@@ -28,3 +30,69 @@ impl AccountHint {
         }
     }
 }
+
+/// A signed-in application of the user, backed by a refresh token that is
+/// neither revoked nor expired.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AccountSession {
+    pub id: Uuid,
+    /// OAuth `client_id` of the application, when the client still exists.
+    pub client_id: Option<String>,
+    pub client_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// An external identity provider account the user signs in with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LinkedIdentity {
+    pub identity_provider_alias: String,
+    pub identity_provider_display_name: Option<String>,
+    pub external_user_id: String,
+    pub external_username: String,
+    pub linked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountInput {
+    pub realm_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateAccountProfileInput {
+    pub realm_name: String,
+    pub firstname: Option<String>,
+    pub lastname: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteAccountCredentialInput {
+    pub realm_name: String,
+    pub credential_id: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChangeAccountPasswordInput {
+    pub realm_name: String,
+    /// Required when the user already has a password.
+    pub current_password: Option<String>,
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnlinkIdentityInput {
+    pub realm_name: String,
+    pub identity_provider_alias: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RevokeAccountSessionInput {
+    pub realm_name: String,
+    pub session_id: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListAccountEventsInput {
+    pub realm_name: String,
+    pub limit: Option<u32>,
+}
@@ -1,9 +1,17 @@
 use std::future::Future;
 use uuid::Uuid;
 
-use crate::domain::account::entities::AccountHint;
+use crate::domain::account::entities::{
+    AccountHint, AccountInput, AccountSession, ChangeAccountPasswordInput,
+    DeleteAccountCredentialInput, LinkedIdentity, ListAccountEventsInput,
+    RevokeAccountSessionInput, UnlinkIdentityInput, UpdateAccountProfileInput,
+};
+use crate::domain::authentication::value_objects::Identity;
 use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::credential::entities::CredentialOverview;
 use crate::domain::realm::entities::RealmId;
+use crate::domain::seawatch::entities::SecurityEvent;
+use crate::domain::user::entities::User;
 
 pub trait AccountHintService: Send + Sync {
     fn create_account_hint(
@@ -51,3 +59,79 @@ pub trait AccountHintRepository: Send + Sync {
         realm_id: &RealmId,
     ) -> impl Future<Output = Result<Vec<AccountHint>, CoreError>> + Send;
 }
+
+/// Self-service operations on the account of the identity making the call.
+/// Only regular users of the realm can use them.
+pub trait AccountService: Send + Sync {
+    fn get_account_profile(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> impl Future<Output = Result<User, CoreError>> + Send;
+    fn update_account_profile(
+        &self,
+        identity: Identity,
+        input: UpdateAccountProfileInput,
+    ) -> impl Future<Output = Result<User, CoreError>> + Send;
+    fn list_account_credentials(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> impl Future<Output = Result<Vec<CredentialOverview>, CoreError>> + Send;
+    /// Deletes an OTP, WebAuthn or recovery code credential. Recovery codes
+    /// only work as a set, so deleting one deletes them all.
+    fn delete_account_credential(
+        &self,
+        identity: Identity,
+        input: DeleteAccountCredentialInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn change_account_password(
+        &self,
+        identity: Identity,
+        input: ChangeAccountPasswordInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn list_linked_identities(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> impl Future<Output = Result<Vec<LinkedIdentity>, CoreError>> + Send;
+    /// Refuses to remove the last way the user can sign in.
+    fn unlink_identity(
+        &self,
+        identity: Identity,
+        input: UnlinkIdentityInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn list_account_sessions(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> impl Future<Output = Result<Vec<AccountSession>, CoreError>> + Send;
+    fn revoke_account_session(
+        &self,
+        identity: Identity,
+        input: RevokeAccountSessionInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Security events the user caused or that target them, newest first.
+    fn list_account_events(
+        &self,
+        identity: Identity,
+        input: ListAccountEventsInput,
+    ) -> impl Future<Output = Result<Vec<SecurityEvent>, CoreError>> + Send;
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait AccountSessionRepository: Send + Sync {
+    /// Sessions whose refresh token is neither revoked nor expired, newest
+    /// first.
+    fn list_active(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<AccountSession>, CoreError>> + Send;
+    /// Revokes the session if it belongs to the user and is still active.
+    /// Returns whether a session was revoked.
+    fn revoke(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
@@ -1,11 +1,51 @@
+use std::sync::Arc;
+
+use tracing::warn;
 use uuid::Uuid;
 
+use crate::domain::abyss::identity_provider::{
+    IdentityProviderRepository, broker::IdentityProviderLinkRepository,
+};
 use crate::domain::account::{
-    entities::AccountHint,
-    ports::{AccountHintRepository, AccountHintService},
+    entities::{
+        AccountHint, AccountInput, AccountSession, ChangeAccountPasswordInput,
+        DeleteAccountCredentialInput, LinkedIdentity, ListAccountEventsInput,
+        RevokeAccountSessionInput, UnlinkIdentityInput, UpdateAccountProfileInput,
+    },
+    ports::{AccountHintRepository, AccountHintService, AccountService, AccountSessionRepository},
 };
+use crate::domain::authentication::value_objects::Identity;
 use crate::domain::common::entities::app_errors::CoreError;
-use crate::domain::realm::entities::RealmId;
+use crate::domain::credential::{
+    entities::{CredentialData, CredentialOverview, CredentialType},
+    ports::CredentialRepository,
+};
+use crate::domain::crypto::HasherRepository;
+use crate::domain::password_policy::{
+    entity::PasswordPolicy, repository::PasswordPolicyRepository,
+};
+use crate::domain::realm::{
+    entities::{Realm, RealmId},
+    ports::RealmRepository,
+};
+use crate::domain::seawatch::{
+    entities::{EventStatus, SecurityEvent, SecurityEventType},
+    ports::SecurityEventRepository,
+    value_objects::SecurityEventFilter,
+};
+use crate::domain::security_notification::{
+    entities::SecurityNotification, ports::SecurityNotifier,
+};
+use crate::domain::user::{
+    entities::User, ports::UserRepository, value_objects::UpdateUserRequest,
+};
+use crate::domain::webhook::{
+    entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
+    ports::WebhookRepository,
+};
+
+/// Upper bound on the security events returned by one call.
+const MAX_ACCOUNT_EVENTS: u32 = 100;
 
 #[derive(Clone)]
 pub struct AccountHintServiceImpl<A>
@@ -71,3 +111,721 @@ where
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct AccountServiceImpl<R, U, CR, H, PP, IP, IL, AS, SE, W, N>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    CR: CredentialRepository,
+    H: HasherRepository,
+    PP: PasswordPolicyRepository,
+    IP: IdentityProviderRepository,
+    IL: IdentityProviderLinkRepository,
+    AS: AccountSessionRepository,
+    SE: SecurityEventRepository,
+    W: WebhookRepository,
+    N: SecurityNotifier,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) credential_repository: Arc<CR>,
+    pub(crate) hasher_repository: Arc<H>,
+    pub(crate) password_policy_repository: Arc<PP>,
+    pub(crate) identity_provider_repository: Arc<IP>,
+    pub(crate) identity_provider_link_repository: Arc<IL>,
+    pub(crate) account_session_repository: Arc<AS>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) webhook_repository: Arc<W>,
+    pub(crate) security_notifier: Arc<N>,
+}
+
+impl<R, U, CR, H, PP, IP, IL, AS, SE, W, N>
+    AccountServiceImpl<R, U, CR, H, PP, IP, IL, AS, SE, W, N>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    CR: CredentialRepository,
+    H: HasherRepository,
+    PP: PasswordPolicyRepository,
+    IP: IdentityProviderRepository,
+    IL: IdentityProviderLinkRepository,
+    AS: AccountSessionRepository,
+    SE: SecurityEventRepository,
+    W: WebhookRepository,
+    N: SecurityNotifier,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        user_repository: Arc<U>,
+        credential_repository: Arc<CR>,
+        hasher_repository: Arc<H>,
+        password_policy_repository: Arc<PP>,
+        identity_provider_repository: Arc<IP>,
+        identity_provider_link_repository: Arc<IL>,
+        account_session_repository: Arc<AS>,
+        security_event_repository: Arc<SE>,
+        webhook_repository: Arc<W>,
+        security_notifier: Arc<N>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            user_repository,
+            credential_repository,
+            hasher_repository,
+            password_policy_repository,
+            identity_provider_repository,
+            identity_provider_link_repository,
+            account_session_repository,
+            security_event_repository,
+            webhook_repository,
+            security_notifier,
+        }
+    }
+
+    /// The realm and the fresh state of the calling user. Service accounts
+    /// and users of other realms have no account here.
+    async fn resolve_account(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+    ) -> Result<(Realm, User), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let user_id = identity
+            .as_user()
+            .filter(|user| identity.is_regular_user() && user.realm_id == realm.id)
+            .map(|user| user.id)
+            .ok_or_else(|| {
+                CoreError::Forbidden(
+                    "the account API is only available to users of this realm".into(),
+                )
+            })?;
+
+        let user = self.user_repository.get_by_id(user_id).await?;
+        if !user.enabled {
+            return Err(CoreError::UserDisabled);
+        }
+
+        Ok((realm, user))
+    }
+
+    async fn verify_current_password(
+        &self,
+        user: &User,
+        password: Option<&str>,
+    ) -> Result<bool, CoreError> {
+        let Ok(credential) = self
+            .credential_repository
+            .get_password_credential(user.id)
+            .await
+        else {
+            // Users who only sign in through an identity provider may set a
+            // first password.
+            return Ok(false);
+        };
+
+        let CredentialData::Hash {
+            hash_iterations,
+            algorithm,
+        } = &credential.credential_data
+        else {
+            return Err(CoreError::InternalServerError);
+        };
+        let salt = credential
+            .salt
+            .as_deref()
+            .ok_or(CoreError::InternalServerError)?;
+        let password =
+            password.ok_or_else(|| CoreError::Forbidden("current password is required".into()))?;
+
+        let valid = self
+            .hasher_repository
+            .verify_password(
+                password,
+                &credential.secret_data,
+                *hash_iterations,
+                algorithm,
+                salt,
+            )
+            .await
+            .map_err(|e| CoreError::VerifyPasswordError(e.to_string()))?;
+        if !valid {
+            return Err(CoreError::Forbidden("current password is incorrect".into()));
+        }
+
+        Ok(true)
+    }
+}
+
+impl<R, U, CR, H, PP, IP, IL, AS, SE, W, N> AccountService
+    for AccountServiceImpl<R, U, CR, H, PP, IP, IL, AS, SE, W, N>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    CR: CredentialRepository,
+    H: HasherRepository,
+    PP: PasswordPolicyRepository,
+    IP: IdentityProviderRepository,
+    IL: IdentityProviderLinkRepository,
+    AS: AccountSessionRepository,
+    SE: SecurityEventRepository,
+    W: WebhookRepository,
+    N: SecurityNotifier,
+{
+    async fn get_account_profile(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<User, CoreError> {
+        let (_, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        Ok(user)
+    }
+
+    async fn update_account_profile(
+        &self,
+        identity: Identity,
+        input: UpdateAccountProfileInput,
+    ) -> Result<User, CoreError> {
+        let (realm, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        // The email address changes through the confirmation flow only.
+        let updated_user = self
+            .user_repository
+            .update_user(
+                user.id,
+                UpdateUserRequest {
+                    firstname: input.firstname.or(user.firstname),
+                    lastname: input.lastname.or(user.lastname),
+                    email: user.email,
+                    email_verified: user.email_verified,
+                    enabled: user.enabled,
+                    required_actions: None,
+                },
+            )
+            .await?;
+
+        self.webhook_repository
+            .notify(
+                realm.id,
+                WebhookPayload::new(
+                    WebhookTrigger::UserUpdated,
+                    updated_user.id,
+                    Some(updated_user.clone()),
+                ),
+            )
+            .await?;
+
+        Ok(updated_user)
+    }
+
+    async fn list_account_credentials(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<Vec<CredentialOverview>, CoreError> {
+        let (_, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        let credentials = self
+            .credential_repository
+            .get_credentials_by_user_id(user.id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?;
+
+        Ok(credentials
+            .into_iter()
+            .map(CredentialOverview::from)
+            .collect())
+    }
+
+    async fn delete_account_credential(
+        &self,
+        identity: Identity,
+        input: DeleteAccountCredentialInput,
+    ) -> Result<(), CoreError> {
+        let (realm, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        let credentials = self
+            .credential_repository
+            .get_credentials_by_user_id(user.id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?;
+        let credential = credentials
+            .iter()
+            .find(|credential| credential.id == input.credential_id)
+            .ok_or(CoreError::NotFound)?;
+
+        let (doomed, method): (Vec<Uuid>, &str) = match credential.credential_type {
+            CredentialType::Otp => (vec![credential.id], "otp"),
+            CredentialType::WebAuthnPublicKeyCredential => (vec![credential.id], "webauthn"),
+            CredentialType::EmailOtp => (vec![credential.id], "email-otp"),
+            CredentialType::SmsOtp => (vec![credential.id], "sms-otp"),
+            CredentialType::RecoveryCode => (
+                credentials
+                    .iter()
+                    .filter(|c| c.credential_type == CredentialType::RecoveryCode)
+                    .map(|c| c.id)
+                    .collect(),
+                "recovery_codes",
+            ),
+            CredentialType::Password => {
+                return Err(CoreError::Forbidden(
+                    "the password can only be changed, not deleted".into(),
+                ));
+            }
+        };
+
+        for credential_id in doomed {
+            self.credential_repository
+                .delete_by_id(credential_id)
+                .await
+                .map_err(|_| CoreError::DeleteCredentialError)?;
+        }
+
+        self.security_notifier
+            .notify(
+                realm.id,
+                user.id,
+                SecurityNotification::MfaRemoved {
+                    method: method.to_string(),
+                },
+            )
+            .await;
+
+        Ok(())
+    }
+
+    async fn change_account_password(
+        &self,
+        identity: Identity,
+        input: ChangeAccountPasswordInput,
+    ) -> Result<(), CoreError> {
+        let (realm, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        let had_password = self
+            .verify_current_password(&user, input.current_password.as_deref())
+            .await?;
+
+        let policy = self
+            .password_policy_repository
+            .find_by_realm_id(realm.id.into())
+            .await?
+            .unwrap_or_else(|| PasswordPolicy::default(realm.id.into()));
+        policy.validate(&input.new_password).map_err(|errors| {
+            CoreError::PasswordPolicyViolation(
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            )
+        })?;
+
+        let hash_result = self
+            .hasher_repository
+            .hash_password(&input.new_password)
+            .await
+            .map_err(|e| CoreError::HashPasswordError(e.to_string()))?;
+
+        if had_password {
+            self.credential_repository
+                .delete_password_credential(user.id)
+                .await
+                .map_err(|_| CoreError::DeletePasswordCredentialError)?;
+        }
+        self.credential_repository
+            .create_credential(user.id, "password".into(), hash_result, "".into(), false)
+            .await
+            .map_err(|_| CoreError::CreateCredentialError)?;
+
+        if let Err(e) = self
+            .security_event_repository
+            .store_event(
+                SecurityEvent::new(
+                    realm.id,
+                    SecurityEventType::PasswordReset,
+                    EventStatus::Success,
+                    user.id,
+                )
+                .with_target("user".to_string(), user.id, None),
+            )
+            .await
+        {
+            warn!("Failed to store PasswordReset security event: {}", e);
+        }
+
+        self.security_notifier
+            .notify(realm.id, user.id, SecurityNotification::PasswordChanged)
+            .await;
+
+        Ok(())
+    }
+
+    async fn list_linked_identities(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<Vec<LinkedIdentity>, CoreError> {
+        let (_, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        let links = self
+            .identity_provider_link_repository
+            .get_by_user_id(user.id)
+            .await?;
+
+        let mut identities = Vec::with_capacity(links.len());
+        for link in links {
+            let Some(provider) = self
+                .identity_provider_repository
+                .get_identity_provider_by_id(link.identity_provider_id.as_uuid())
+                .await?
+            else {
+                continue;
+            };
+
+            identities.push(LinkedIdentity {
+                identity_provider_alias: provider.alias,
+                identity_provider_display_name: provider.display_name,
+                external_user_id: link.identity_provider_user_id,
+                external_username: link.identity_provider_username,
+                linked_at: link.created_at,
+            });
+        }
+
+        Ok(identities)
+    }
+
+    async fn unlink_identity(
+        &self,
+        identity: Identity,
+        input: UnlinkIdentityInput,
+    ) -> Result<(), CoreError> {
+        let (realm, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        let provider = self
+            .identity_provider_repository
+            .get_identity_provider_by_realm_and_alias(realm.id, &input.identity_provider_alias)
+            .await?
+            .ok_or(CoreError::ProviderNotFound)?;
+
+        let links = self
+            .identity_provider_link_repository
+            .get_by_user_id(user.id)
+            .await?;
+        let link = links
+            .iter()
+            .find(|link| link.identity_provider_id == provider.id)
+            .ok_or(CoreError::LinkNotFound)?;
+
+        let has_password = self
+            .credential_repository
+            .get_password_credential(user.id)
+            .await
+            .is_ok();
+        if !has_password && links.len() == 1 {
+            return Err(CoreError::Forbidden(
+                "set a password before unlinking your last identity provider".into(),
+            ));
+        }
+
+        self.identity_provider_link_repository.delete(link.id).await
+    }
+
+    async fn list_account_sessions(
+        &self,
+        identity: Identity,
+        input: AccountInput,
+    ) -> Result<Vec<AccountSession>, CoreError> {
+        let (_, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        self.account_session_repository.list_active(user.id).await
+    }
+
+    async fn revoke_account_session(
+        &self,
+        identity: Identity,
+        input: RevokeAccountSessionInput,
+    ) -> Result<(), CoreError> {
+        let (_, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        if !self
+            .account_session_repository
+            .revoke(user.id, input.session_id)
+            .await?
+        {
+            return Err(CoreError::SessionNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn list_account_events(
+        &self,
+        identity: Identity,
+        input: ListAccountEventsInput,
+    ) -> Result<Vec<SecurityEvent>, CoreError> {
+        let (realm, user) = self.resolve_account(&identity, &input.realm_name).await?;
+
+        self.security_event_repository
+            .get_events(
+                realm.id,
+                SecurityEventFilter {
+                    user_id: Some(user.id),
+                    limit: Some(input.limit.unwrap_or(50).min(MAX_ACCOUNT_EVENTS)),
+                    ..Default::default()
+                },
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::{
+        abyss::identity_provider::{
+            broker::ports::MockIdentityProviderLinkRepository,
+            ports::MockIdentityProviderRepository,
+        },
+        account::ports::MockAccountSessionRepository,
+        common::services::tests::{
+            create_test_realm_with_name, create_test_user_with_params_and_realm,
+        },
+        credential::{
+            entities::{Credential, CredentialError},
+            ports::MockCredentialRepository,
+        },
+        crypto::MockHasherRepository,
+        password_policy::repository::MockPasswordPolicyRepository,
+        realm::ports::MockRealmRepository,
+        seawatch::ports::MockSecurityEventRepository,
+        security_notification::{
+            ports::MockSecurityNotifier, services::tests::permissive_notifier,
+        },
+        user::ports::MockUserRepository,
+        webhook::ports::MockWebhookRepository,
+    };
+
+    type TestAccountService = AccountServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockCredentialRepository,
+        MockHasherRepository,
+        MockPasswordPolicyRepository,
+        MockIdentityProviderRepository,
+        MockIdentityProviderLinkRepository,
+        MockAccountSessionRepository,
+        MockSecurityEventRepository,
+        MockWebhookRepository,
+        MockSecurityNotifier,
+    >;
+
+    struct Mocks {
+        realm: MockRealmRepository,
+        user: MockUserRepository,
+        credential: MockCredentialRepository,
+        hasher: MockHasherRepository,
+        password_policy: MockPasswordPolicyRepository,
+        identity_provider: MockIdentityProviderRepository,
+        identity_provider_link: MockIdentityProviderLinkRepository,
+    }
+
+    /// Mocks resolving `user` as the caller in `realm`.
+    fn mocks_for(realm: &Realm, user: &User) -> Mocks {
+        let mut realm_repo = MockRealmRepository::new();
+        let r = realm.clone();
+        realm_repo
+            .expect_get_by_name()
+            .returning(move |_| Box::pin(std::future::ready(Ok(Some(r.clone())))));
+        let mut user_repo = MockUserRepository::new();
+        let u = user.clone();
+        user_repo
+            .expect_get_by_id()
+            .returning(move |_| Box::pin(std::future::ready(Ok(u.clone()))));
+
+        Mocks {
+            realm: realm_repo,
+            user: user_repo,
+            credential: MockCredentialRepository::new(),
+            hasher: MockHasherRepository::new(),
+            password_policy: MockPasswordPolicyRepository::new(),
+            identity_provider: MockIdentityProviderRepository::new(),
+            identity_provider_link: MockIdentityProviderLinkRepository::new(),
+        }
+    }
+
+    fn build(mocks: Mocks) -> TestAccountService {
+        let mut security_event = MockSecurityEventRepository::new();
+        security_event
+            .expect_store_event()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        AccountServiceImpl::new(
+            Arc::new(mocks.realm),
+            Arc::new(mocks.user),
+            Arc::new(mocks.credential),
+            Arc::new(mocks.hasher),
+            Arc::new(mocks.password_policy),
+            Arc::new(mocks.identity_provider),
+            Arc::new(mocks.identity_provider_link),
+            Arc::new(MockAccountSessionRepository::new()),
+            Arc::new(security_event),
+            Arc::new(MockWebhookRepository::new()),
+            Arc::new(permissive_notifier()),
+        )
+    }
+
+    fn credential(user_id: Uuid, credential_type: CredentialType) -> Credential {
+        Credential {
+            id: Uuid::new_v4(),
+            salt: Some("salt".to_string()),
+            credential_type,
+            user_id,
+            user_label: None,
+            secret_data: "hash".to_string(),
+            credential_data: CredentialData::new_hash(1, "argon2".to_string()),
+            temporary: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            webauthn_credential_id: None,
+        }
+    }
+
+    fn setup() -> (Realm, User) {
+        let realm = create_test_realm_with_name("account");
+        let user = create_test_user_with_params_and_realm(
+            &realm,
+            "jane",
+            "jane@example.com".to_string(),
+            true,
+        );
+        (realm, user)
+    }
+
+    #[tokio::test]
+    async fn rejects_users_of_another_realm() {
+        let (realm, user) = setup();
+        let other_realm = create_test_realm_with_name("other");
+        let stranger = create_test_user_with_params_and_realm(
+            &other_realm,
+            "john",
+            "john@example.com".to_string(),
+            true,
+        );
+        let service = build(mocks_for(&realm, &user));
+
+        let result = service
+            .get_account_profile(
+                Identity::User(stranger),
+                AccountInput {
+                    realm_name: realm.name.clone(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn change_password_requires_the_current_one() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user);
+        let password = credential(user.id, CredentialType::Password);
+        mocks
+            .credential
+            .expect_get_password_credential()
+            .return_once(move |_| Box::pin(async move { Ok(password) }));
+        mocks
+            .hasher
+            .expect_verify_password()
+            .return_once(|_, _, _, _, _| Box::pin(async { Ok(false) }));
+        mocks.credential.expect_create_credential().never();
+        let service = build(mocks);
+
+        let result = service
+            .change_account_password(
+                Identity::User(user),
+                ChangeAccountPasswordInput {
+                    realm_name: realm.name.clone(),
+                    current_password: Some("wrong".to_string()),
+                    new_password: "N3w-password!".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn change_password_enforces_the_realm_policy() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user);
+        mocks
+            .credential
+            .expect_get_password_credential()
+            .return_once(|_| Box::pin(async { Err(CredentialError::GetPasswordCredentialError) }));
+        mocks
+            .password_policy
+            .expect_find_by_realm_id()
+            .return_once(|_| Box::pin(async { Ok(None) }));
+        mocks.credential.expect_create_credential().never();
+        let service = build(mocks);
+
+        let result = service
+            .change_account_password(
+                Identity::User(user),
+                ChangeAccountPasswordInput {
+                    realm_name: realm.name.clone(),
+                    current_password: None,
+                    new_password: "short".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::PasswordPolicyViolation(_))));
+    }
+
+    #[tokio::test]
+    async fn deleting_a_recovery_code_deletes_the_whole_set() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user);
+        let codes: Vec<Credential> = (0..3)
+            .map(|_| credential(user.id, CredentialType::RecoveryCode))
+            .collect();
+        let target = codes[1].id;
+        let mut credentials = codes.clone();
+        credentials.push(credential(user.id, CredentialType::Otp));
+        mocks
+            .credential
+            .expect_get_credentials_by_user_id()
+            .return_once(move |_| Box::pin(async move { Ok(credentials) }));
+        for code in codes {
+            mocks
+                .credential
+                .expect_delete_by_id()
+                .with(mockall::predicate::eq(code.id))
+                .times(1)
+                .returning(|_| Box::pin(async { Ok(()) }));
+        }
+        let service = build(mocks);
+
+        service
+            .delete_account_credential(
+                Identity::User(user),
+                DeleteAccountCredentialInput {
+                    realm_name: realm.name.clone(),
+                    credential_id: target,
+                },
+            )
+            .await
+            .unwrap();
+    }
+}
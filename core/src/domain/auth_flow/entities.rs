@@ -0,0 +1,853 @@
+use std::{fmt::Display, net::IpAddr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    credential::entities::CredentialType, realm::entities::RealmId, user::entities::RequiredAction,
+};
+
+/// Sub-flows can't be nested deeper than this, which is plenty for real
+/// flows and keeps evaluation cheap.
+pub const MAX_AUTH_FLOW_DEPTH: usize = 3;
+
+/// A way for the user to prove who they are during sign-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Authenticator {
+    UsernamePassword,
+    /// Asks for the username alone first, so the login page can pick the
+    /// next step for that user.
+    IdentityFirst,
+    Passkey,
+    /// Authenticator app code.
+    Otp,
+    EmailOtp,
+    SmsOtp,
+    /// Redirect to the identity provider with this alias.
+    IdentityProvider {
+        alias: String,
+    },
+}
+
+impl Authenticator {
+    /// Authenticators that only identify the user and can't be asked for
+    /// once they are signed in, unlike second factors.
+    pub fn is_identification(&self) -> bool {
+        matches!(
+            self,
+            Authenticator::UsernamePassword
+                | Authenticator::IdentityFirst
+                | Authenticator::IdentityProvider { .. }
+        )
+    }
+
+    /// The authenticator a credential of this type lets the user pass.
+    pub fn from_credential_type(credential_type: &CredentialType) -> Option<Self> {
+        match credential_type {
+            CredentialType::Password => Some(Authenticator::UsernamePassword),
+            CredentialType::Otp => Some(Authenticator::Otp),
+            CredentialType::EmailOtp => Some(Authenticator::EmailOtp),
+            CredentialType::SmsOtp => Some(Authenticator::SmsOtp),
+            CredentialType::WebAuthnPublicKeyCredential => Some(Authenticator::Passkey),
+            _ => None,
+        }
+    }
+
+    /// What the user has to do to set this authenticator up.
+    fn setup_action(&self) -> Option<RequiredAction> {
+        match self {
+            Authenticator::Otp => Some(RequiredAction::ConfigureOtp),
+            Authenticator::EmailOtp => Some(RequiredAction::ConfigureEmailOtp),
+            Authenticator::SmsOtp => Some(RequiredAction::ConfigureSmsOtp),
+            Authenticator::Passkey => Some(RequiredAction::ConfigurePasskey),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Authenticator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Authenticator::UsernamePassword => write!(f, "username_password"),
+            Authenticator::IdentityFirst => write!(f, "identity_first"),
+            Authenticator::Passkey => write!(f, "passkey"),
+            Authenticator::Otp => write!(f, "otp"),
+            Authenticator::EmailOtp => write!(f, "email_otp"),
+            Authenticator::SmsOtp => write!(f, "sms_otp"),
+            Authenticator::IdentityProvider { alias } => write!(f, "identity_provider:{alias}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthFlowRequirement {
+    /// Must be passed.
+    Required,
+    /// One of the alternatives of its level must be passed. Alternatives are
+    /// ignored when the level also has required steps.
+    Alternative,
+    /// Required when its condition holds, skipped otherwise.
+    Conditional,
+    Disabled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthFlowCondition {
+    /// The user has set up one of the authenticators of the step.
+    UserConfigured,
+    /// The user has the realm role with this name.
+    UserRole { role: String },
+    /// The user is a member of the organization with this alias.
+    OrganizationMember { organization: String },
+    /// The sign-in comes from one of these addresses or CIDR ranges.
+    IpRange { ranges: Vec<String> },
+    /// The sign-in is for one of these clients, by `client_id`.
+    Client { client_ids: Vec<String> },
+}
+
+impl AuthFlowCondition {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            AuthFlowCondition::UserConfigured => Ok(()),
+            AuthFlowCondition::UserRole { role } if role.trim().is_empty() => {
+                Err("role conditions need a role name".to_string())
+            }
+            AuthFlowCondition::OrganizationMember { organization }
+                if organization.trim().is_empty() =>
+            {
+                Err("organization conditions need an organization alias".to_string())
+            }
+            AuthFlowCondition::IpRange { ranges } => {
+                if ranges.is_empty() {
+                    return Err("IP range conditions need at least one range".to_string());
+                }
+
+                match ranges.iter().find(|range| IpRange::parse(range).is_none()) {
+                    Some(range) => Err(format!("invalid IP range: {range}")),
+                    None => Ok(()),
+                }
+            }
+            AuthFlowCondition::Client { client_ids } if client_ids.is_empty() => {
+                Err("client conditions need at least one client".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A step of a flow: either an authenticator, or a sub-flow of steps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AuthFlowStep {
+    pub requirement: AuthFlowRequirement,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authenticator: Option<Authenticator>,
+    /// Only for conditional steps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<AuthFlowCondition>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schema(no_recursion)]
+    pub steps: Vec<AuthFlowStep>,
+}
+
+impl AuthFlowStep {
+    pub fn authenticator(requirement: AuthFlowRequirement, authenticator: Authenticator) -> Self {
+        Self {
+            requirement,
+            authenticator: Some(authenticator),
+            condition: None,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn sub_flow(
+        requirement: AuthFlowRequirement,
+        condition: Option<AuthFlowCondition>,
+        steps: Vec<AuthFlowStep>,
+    ) -> Self {
+        Self {
+            requirement,
+            authenticator: None,
+            condition,
+            steps,
+        }
+    }
+
+    fn authenticators(&self) -> Vec<&Authenticator> {
+        match &self.authenticator {
+            Some(authenticator) => vec![authenticator],
+            None => self
+                .steps
+                .iter()
+                .flat_map(AuthFlowStep::authenticators)
+                .collect(),
+        }
+    }
+
+    fn conditions(&self) -> Vec<&AuthFlowCondition> {
+        self.condition
+            .iter()
+            .chain(self.steps.iter().flat_map(AuthFlowStep::conditions))
+            .collect()
+    }
+
+    fn validate(&self, depth: usize) -> Result<(), String> {
+        if depth > MAX_AUTH_FLOW_DEPTH {
+            return Err(format!(
+                "sub-flows can't be nested more than {MAX_AUTH_FLOW_DEPTH} levels deep"
+            ));
+        }
+
+        match (&self.authenticator, self.steps.is_empty()) {
+            (Some(_), false) => {
+                return Err("a step has either an authenticator or sub-steps, not both".to_string());
+            }
+            (None, true) => {
+                return Err("a step needs an authenticator or sub-steps".to_string());
+            }
+            _ => {}
+        }
+
+        match (self.requirement, &self.condition) {
+            (AuthFlowRequirement::Conditional, None) => {
+                return Err("conditional steps need a condition".to_string());
+            }
+            (AuthFlowRequirement::Conditional, Some(condition)) => condition.validate()?,
+            (_, Some(_)) => return Err("only conditional steps can have a condition".to_string()),
+            (_, None) => {}
+        }
+
+        if self.requirement == AuthFlowRequirement::Alternative && self.authenticator.is_none() {
+            return Err("alternatives must be authenticators, not sub-flows".to_string());
+        }
+
+        if depth > 0
+            && self
+                .authenticator
+                .as_ref()
+                .is_some_and(Authenticator::is_identification)
+        {
+            return Err(
+                "username/password, identity-first and identity provider steps must be top-level"
+                    .to_string(),
+            );
+        }
+
+        self.steps
+            .iter()
+            .try_for_each(|step| step.validate(depth + 1))
+    }
+}
+
+/// The sign-in sequence of a realm, or of one of its clients when
+/// `client_id` is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AuthFlow {
+    pub realm_id: RealmId,
+    pub client_id: Option<Uuid>,
+    pub steps: Vec<AuthFlowStep>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl AuthFlow {
+    /// The flow realms use until they configure one: a password, then any
+    /// second factor the user has set up.
+    pub fn new(realm_id: RealmId) -> Self {
+        Self {
+            realm_id,
+            client_id: None,
+            steps: Self::default_steps(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn default_steps() -> Vec<AuthFlowStep> {
+        use AuthFlowRequirement::{Alternative, Conditional, Required};
+
+        vec![
+            AuthFlowStep::authenticator(Required, Authenticator::UsernamePassword),
+            AuthFlowStep::sub_flow(
+                Conditional,
+                Some(AuthFlowCondition::UserConfigured),
+                vec![
+                    AuthFlowStep::authenticator(Alternative, Authenticator::Otp),
+                    AuthFlowStep::authenticator(Alternative, Authenticator::EmailOtp),
+                    AuthFlowStep::authenticator(Alternative, Authenticator::SmsOtp),
+                ],
+            ),
+        ]
+    }
+
+    pub fn validate_steps(steps: &[AuthFlowStep]) -> Result<(), String> {
+        steps.iter().try_for_each(|step| step.validate(0))?;
+
+        let can_sign_in = steps.iter().any(|step| {
+            matches!(
+                step.requirement,
+                AuthFlowRequirement::Required | AuthFlowRequirement::Alternative
+            ) && step.authenticator.as_ref().is_some_and(|authenticator| {
+                *authenticator == Authenticator::Passkey
+                    || (authenticator.is_identification()
+                        && *authenticator != Authenticator::IdentityFirst)
+            })
+        });
+
+        if !can_sign_in {
+            return Err(
+                "the flow needs a top-level required or alternative step that identifies the user"
+                    .to_string(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Whether evaluating the flow needs something the caller may not have
+    /// loaded yet, like the user's roles.
+    pub fn uses_condition(&self, matches: impl Fn(&AuthFlowCondition) -> bool) -> bool {
+        self.steps
+            .iter()
+            .flat_map(AuthFlowStep::conditions)
+            .any(matches)
+    }
+
+    /// The authenticators the login page can offer first.
+    pub fn first_stage(&self, context: &AuthFlowContext) -> Vec<Authenticator> {
+        let mut stages = Vec::new();
+        collect_stages(&self.steps, context, &mut stages, &mut Vec::new());
+
+        stages
+            .into_iter()
+            .next()
+            .map(|stage| stage.methods)
+            .unwrap_or_default()
+    }
+
+    /// Works out what is left of the flow once the user signed in with the
+    /// `completed` authenticators.
+    pub fn plan(
+        &self,
+        context: &AuthFlowContext,
+        completed: &[Authenticator],
+    ) -> Result<AuthFlowPlan, AuthFlowDenied> {
+        let mut stages = Vec::new();
+        let mut conditions = Vec::new();
+        collect_stages(&self.steps, context, &mut stages, &mut conditions);
+
+        let used_allowed = completed
+            .iter()
+            .filter(|authenticator| **authenticator != Authenticator::IdentityFirst)
+            .all(|authenticator| {
+                stages
+                    .iter()
+                    .any(|stage| stage.methods.contains(authenticator))
+            });
+
+        if stages.is_empty() || !used_allowed {
+            return Err(AuthFlowDenied { conditions });
+        }
+
+        let mut pending = Vec::new();
+        let mut required_actions = Vec::new();
+
+        for stage in stages {
+            if stage
+                .methods
+                .iter()
+                .any(|method| completed.contains(method))
+            {
+                continue;
+            }
+
+            let usable: Vec<Authenticator> = stage
+                .methods
+                .iter()
+                .filter(|method| !method.is_identification() && context.configured.contains(method))
+                .cloned()
+                .collect();
+
+            if !usable.is_empty() {
+                pending.push(AuthFlowStage { methods: usable });
+                continue;
+            }
+
+            match stage.methods.iter().find_map(Authenticator::setup_action) {
+                Some(action) if !required_actions.contains(&action) => {
+                    required_actions.push(action)
+                }
+                Some(_) => {}
+                None => return Err(AuthFlowDenied { conditions }),
+            }
+        }
+
+        Ok(AuthFlowPlan {
+            pending,
+            required_actions,
+            conditions,
+        })
+    }
+}
+
+/// How the login page should start the sign-in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LoginOptions {
+    /// Any one of these starts the sign-in.
+    pub methods: Vec<Authenticator>,
+}
+
+/// What is known about the sign-in when evaluating a flow.
+#[derive(Debug, Clone, Default)]
+pub struct AuthFlowContext {
+    pub client_id: String,
+    pub ip_address: Option<IpAddr>,
+    /// Realm role names of the user.
+    pub roles: Vec<String>,
+    /// Aliases of the organizations the user is a member of.
+    pub organizations: Vec<String>,
+    /// Authenticators the user has set up.
+    pub configured: Vec<Authenticator>,
+}
+
+impl AuthFlowContext {
+    fn matches(&self, condition: &AuthFlowCondition, step: &AuthFlowStep) -> bool {
+        match condition {
+            AuthFlowCondition::UserConfigured => step
+                .authenticators()
+                .into_iter()
+                .any(|authenticator| self.configured.contains(authenticator)),
+            AuthFlowCondition::UserRole { role } => self.roles.contains(role),
+            AuthFlowCondition::OrganizationMember { organization } => {
+                self.organizations.contains(organization)
+            }
+            AuthFlowCondition::IpRange { ranges } => self.ip_address.is_some_and(|ip| {
+                ranges
+                    .iter()
+                    .filter_map(|range| IpRange::parse(range))
+                    .any(|range| range.contains(ip))
+            }),
+            AuthFlowCondition::Client { client_ids } => client_ids.contains(&self.client_id),
+        }
+    }
+}
+
+/// Authenticators of which the user has to pass any one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthFlowStage {
+    pub methods: Vec<Authenticator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthFlowPlan {
+    /// Stages still to pass before the sign-in completes, in order.
+    pub pending: Vec<AuthFlowStage>,
+    /// Setup the user has to go through because a step asks for an
+    /// authenticator they don't have.
+    pub required_actions: Vec<RequiredAction>,
+    /// The conditions evaluated, and whether they held.
+    pub conditions: Vec<(AuthFlowCondition, bool)>,
+}
+
+impl AuthFlowPlan {
+    /// Makes sure the sign-in passes a second factor, adding a stage of the
+    /// ones the user has when the flow doesn't ask for any. Returns whether
+    /// a second factor is pending.
+    pub fn require_second_factor(&mut self, configured: &[Authenticator]) -> bool {
+        if !self.pending.is_empty() {
+            return true;
+        }
+
+        let mut methods: Vec<Authenticator> = configured
+            .iter()
+            .filter(|authenticator| !authenticator.is_identification())
+            .cloned()
+            .collect();
+        methods.sort();
+        methods.dedup();
+
+        if methods.is_empty() {
+            return false;
+        }
+
+        self.pending.push(AuthFlowStage { methods });
+        true
+    }
+
+    /// Drops the stages that only ask for a second factor, for a sign-in
+    /// from a device the user trusted. Stages that can still identify the
+    /// user are kept.
+    pub fn skip_second_factors(&mut self) {
+        self.pending.retain(|stage| {
+            stage
+                .methods
+                .iter()
+                .any(|authenticator| authenticator.is_identification())
+        });
+    }
+}
+
+/// The flow doesn't let the user sign in the way they did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthFlowDenied {
+    pub conditions: Vec<(AuthFlowCondition, bool)>,
+}
+
+/// Flattens the active steps of a level into the stages the user goes
+/// through, recording the conditions evaluated on the way.
+fn collect_stages(
+    steps: &[AuthFlowStep],
+    context: &AuthFlowContext,
+    stages: &mut Vec<AuthFlowStage>,
+    conditions: &mut Vec<(AuthFlowCondition, bool)>,
+) {
+    let mut required = Vec::new();
+    let mut alternatives = Vec::new();
+
+    for step in steps {
+        match step.requirement {
+            AuthFlowRequirement::Required => required.push(step),
+            AuthFlowRequirement::Alternative => alternatives.extend(step.authenticator.clone()),
+            AuthFlowRequirement::Conditional => {
+                let Some(condition) = &step.condition else {
+                    continue;
+                };
+
+                let holds = context.matches(condition, step);
+                conditions.push((condition.clone(), holds));
+
+                if holds {
+                    required.push(step);
+                }
+            }
+            AuthFlowRequirement::Disabled => {}
+        }
+    }
+
+    if required.is_empty() {
+        if !alternatives.is_empty() {
+            stages.push(AuthFlowStage {
+                methods: alternatives,
+            });
+        }
+        return;
+    }
+
+    for step in required {
+        match &step.authenticator {
+            Some(authenticator) => stages.push(AuthFlowStage {
+                methods: vec![authenticator.clone()],
+            }),
+            None => collect_stages(&step.steps, context, stages, conditions),
+        }
+    }
+}
+
+/// An address, or a CIDR range of addresses.
+#[derive(Clone)]
+pub(crate) struct IpRange {
+    network: IpAddr,
+    prefix: u32,
+}
+
+impl IpRange {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let (address, prefix) = match value.trim().split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix.parse::<u32>().ok()?)),
+            None => (value.trim(), None),
+        };
+
+        let network: IpAddr = address.parse().ok()?;
+        let bits = if network.is_ipv4() { 32 } else { 128 };
+        let prefix = prefix.unwrap_or(bits);
+
+        (prefix <= bits).then_some(Self { network, prefix })
+    }
+
+    pub(crate) fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use AuthFlowRequirement::{Alternative, Conditional, Disabled, Required};
+
+    fn context(configured: Vec<Authenticator>) -> AuthFlowContext {
+        AuthFlowContext {
+            client_id: "web".to_string(),
+            ip_address: Some("10.1.2.3".parse().unwrap()),
+            roles: vec!["staff".to_string()],
+            organizations: Vec::new(),
+            configured,
+        }
+    }
+
+    fn flow(steps: Vec<AuthFlowStep>) -> AuthFlow {
+        AuthFlow {
+            steps,
+            ..AuthFlow::new(RealmId::default())
+        }
+    }
+
+    #[test]
+    fn default_flow_signs_in_with_password_alone() {
+        let plan = AuthFlow::new(RealmId::default())
+            .plan(
+                &context(vec![Authenticator::UsernamePassword]),
+                &[Authenticator::UsernamePassword],
+            )
+            .unwrap();
+
+        assert!(plan.pending.is_empty());
+        assert!(plan.required_actions.is_empty());
+        assert_eq!(
+            plan.conditions,
+            vec![(AuthFlowCondition::UserConfigured, false)]
+        );
+    }
+
+    #[test]
+    fn default_flow_asks_for_the_second_factors_the_user_has() {
+        let plan = AuthFlow::new(RealmId::default())
+            .plan(
+                &context(vec![
+                    Authenticator::UsernamePassword,
+                    Authenticator::Otp,
+                    Authenticator::SmsOtp,
+                ]),
+                &[Authenticator::UsernamePassword],
+            )
+            .unwrap();
+
+        assert_eq!(
+            plan.pending,
+            vec![AuthFlowStage {
+                methods: vec![Authenticator::Otp, Authenticator::SmsOtp],
+            }]
+        );
+    }
+
+    #[test]
+    fn required_factor_the_user_lacks_becomes_a_setup_action() {
+        let plan = flow(vec![
+            AuthFlowStep::authenticator(Required, Authenticator::UsernamePassword),
+            AuthFlowStep::authenticator(Required, Authenticator::Otp),
+        ])
+        .plan(&context(Vec::new()), &[Authenticator::UsernamePassword])
+        .unwrap();
+
+        assert!(plan.pending.is_empty());
+        assert_eq!(plan.required_actions, vec![RequiredAction::ConfigureOtp]);
+    }
+
+    #[test]
+    fn conditions_pick_the_steps_that_apply() {
+        let steps = vec![
+            AuthFlowStep::authenticator(Required, Authenticator::UsernamePassword),
+            AuthFlowStep::sub_flow(
+                Conditional,
+                Some(AuthFlowCondition::IpRange {
+                    ranges: vec!["192.168.0.0/16".to_string()],
+                }),
+                vec![AuthFlowStep::authenticator(
+                    Required,
+                    Authenticator::EmailOtp,
+                )],
+            ),
+            AuthFlowStep::sub_flow(
+                Conditional,
+                Some(AuthFlowCondition::UserRole {
+                    role: "staff".to_string(),
+                }),
+                vec![AuthFlowStep::authenticator(
+                    Required,
+                    Authenticator::Passkey,
+                )],
+            ),
+        ];
+
+        let plan = flow(steps)
+            .plan(
+                &context(vec![Authenticator::Passkey, Authenticator::EmailOtp]),
+                &[Authenticator::UsernamePassword],
+            )
+            .unwrap();
+
+        assert_eq!(
+            plan.pending,
+            vec![AuthFlowStage {
+                methods: vec![Authenticator::Passkey],
+            }]
+        );
+        assert_eq!(plan.conditions.len(), 2);
+        assert!(!plan.conditions[0].1);
+        assert!(plan.conditions[1].1);
+    }
+
+    #[test]
+    fn signing_in_a_way_the_flow_does_not_offer_is_denied() {
+        let only_idp = flow(vec![AuthFlowStep::authenticator(
+            Required,
+            Authenticator::IdentityProvider {
+                alias: "corp".to_string(),
+            },
+        )]);
+
+        assert!(
+            only_idp
+                .plan(&context(Vec::new()), &[Authenticator::UsernamePassword])
+                .is_err()
+        );
+
+        let disabled_password = flow(vec![
+            AuthFlowStep::authenticator(Disabled, Authenticator::UsernamePassword),
+            AuthFlowStep::authenticator(Alternative, Authenticator::Passkey),
+        ]);
+
+        assert!(
+            disabled_password
+                .plan(&context(Vec::new()), &[Authenticator::UsernamePassword])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn alternatives_are_ignored_next_to_required_steps() {
+        let plan = flow(vec![
+            AuthFlowStep::authenticator(Required, Authenticator::UsernamePassword),
+            AuthFlowStep::authenticator(Alternative, Authenticator::Passkey),
+        ])
+        .plan(
+            &context(vec![Authenticator::Passkey]),
+            &[Authenticator::UsernamePassword],
+        )
+        .unwrap();
+
+        assert!(plan.pending.is_empty());
+    }
+
+    #[test]
+    fn invalid_steps_are_rejected() {
+        let conditional_without_condition = vec![
+            AuthFlowStep::authenticator(Required, Authenticator::UsernamePassword),
+            AuthFlowStep::authenticator(Conditional, Authenticator::Otp),
+        ];
+        let nested_identity_provider = vec![
+            AuthFlowStep::authenticator(Required, Authenticator::UsernamePassword),
+            AuthFlowStep::sub_flow(
+                Required,
+                None,
+                vec![AuthFlowStep::authenticator(
+                    Required,
+                    Authenticator::IdentityProvider {
+                        alias: "corp".to_string(),
+                    },
+                )],
+            ),
+        ];
+        let nobody_can_sign_in = vec![AuthFlowStep::authenticator(Required, Authenticator::Otp)];
+        let bad_range = vec![
+            AuthFlowStep::authenticator(Required, Authenticator::UsernamePassword),
+            AuthFlowStep::sub_flow(
+                Conditional,
+                Some(AuthFlowCondition::IpRange {
+                    ranges: vec!["10.0.0.0/33".to_string()],
+                }),
+                vec![AuthFlowStep::authenticator(Required, Authenticator::Otp)],
+            ),
+        ];
+
+        assert!(AuthFlow::validate_steps(&AuthFlow::default_steps()).is_ok());
+        assert!(AuthFlow::validate_steps(&conditional_without_condition).is_err());
+        assert!(AuthFlow::validate_steps(&nested_identity_provider).is_err());
+        assert!(AuthFlow::validate_steps(&nobody_can_sign_in).is_err());
+        assert!(AuthFlow::validate_steps(&bad_range).is_err());
+    }
+
+    #[test]
+    fn ip_ranges_match_addresses_of_their_network() {
+        let range = IpRange::parse("10.0.0.0/8").unwrap();
+        assert!(range.contains("10.200.0.1".parse().unwrap()));
+        assert!(!range.contains("11.0.0.1".parse().unwrap()));
+
+        let v6 = IpRange::parse("2001:db8::/32").unwrap();
+        assert!(v6.contains("2001:db8::1".parse().unwrap()));
+        assert!(!v6.contains("10.0.0.1".parse().unwrap()));
+
+        assert!(
+            IpRange::parse("0.0.0.0/0")
+                .unwrap()
+                .contains("8.8.8.8".parse().unwrap())
+        );
+        assert!(
+            IpRange::parse("10.0.0.1")
+                .unwrap()
+                .contains("10.0.0.1".parse().unwrap())
+        );
+        assert!(IpRange::parse("not-an-ip").is_none());
+    }
+
+    #[test]
+    fn second_factor_stage_uses_configured_authenticators() {
+        let mut plan = AuthFlowPlan {
+            pending: Vec::new(),
+            required_actions: Vec::new(),
+            conditions: Vec::new(),
+        };
+
+        assert!(!plan.require_second_factor(&[Authenticator::UsernamePassword]));
+        assert!(plan.pending.is_empty());
+
+        assert!(plan.require_second_factor(&[
+            Authenticator::UsernamePassword,
+            Authenticator::Otp,
+            Authenticator::Passkey,
+        ]));
+        assert_eq!(
+            plan.pending,
+            vec![AuthFlowStage {
+                methods: vec![Authenticator::Passkey, Authenticator::Otp],
+            }]
+        );
+
+        assert!(plan.require_second_factor(&[Authenticator::SmsOtp]));
+        assert_eq!(plan.pending.len(), 1);
+    }
+
+    #[test]
+    fn trusted_device_skips_second_factor_stages() {
+        let mut plan = AuthFlowPlan {
+            pending: vec![
+                AuthFlowStage {
+                    methods: vec![Authenticator::Otp, Authenticator::Passkey],
+                },
+                AuthFlowStage {
+                    methods: vec![Authenticator::IdentityProvider {
+                        alias: "corp".to_string(),
+                    }],
+                },
+            ],
+            required_actions: Vec::new(),
+            conditions: Vec::new(),
+        };
+
+        plan.skip_second_factors();
+
+        assert_eq!(plan.pending.len(), 1);
+        assert!(plan.pending[0].methods[0].is_identification());
+    }
+}
@@ -0,0 +1,85 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    realm::entities::{Realm, RealmId},
+};
+
+use super::{
+    entities::{AuthFlow, LoginOptions},
+    value_objects::{
+        DeleteAuthFlowInput, GetAuthFlowInput, GetLoginOptionsInput, ListAuthFlowsInput,
+        UpdateAuthFlowInput,
+    },
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait AuthFlowRepository: Send + Sync {
+    /// The realm's own flow when `client_id` is `None`, the client's
+    /// override otherwise.
+    fn get_flow(
+        &self,
+        realm_id: RealmId,
+        client_id: Option<Uuid>,
+    ) -> impl Future<Output = Result<Option<AuthFlow>, CoreError>> + Send;
+    fn list_flows(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<AuthFlow>, CoreError>> + Send;
+    fn upsert_flow(
+        &self,
+        flow: AuthFlow,
+    ) -> impl Future<Output = Result<AuthFlow, CoreError>> + Send;
+    fn delete_flow(
+        &self,
+        realm_id: RealmId,
+        client_id: Option<Uuid>,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait AuthFlowService: Send + Sync {
+    /// The flow in effect: the client's override, else the realm's flow,
+    /// else the default one.
+    fn get_auth_flow(
+        &self,
+        identity: Identity,
+        input: GetAuthFlowInput,
+    ) -> impl Future<Output = Result<AuthFlow, CoreError>> + Send;
+    /// The flows the realm and its clients configured.
+    fn list_auth_flows(
+        &self,
+        identity: Identity,
+        input: ListAuthFlowsInput,
+    ) -> impl Future<Output = Result<Vec<AuthFlow>, CoreError>> + Send;
+    fn update_auth_flow(
+        &self,
+        identity: Identity,
+        input: UpdateAuthFlowInput,
+    ) -> impl Future<Output = Result<AuthFlow, CoreError>> + Send;
+    /// Goes back to the realm's flow for a client, or to the default flow
+    /// for the realm.
+    fn delete_auth_flow(
+        &self,
+        identity: Identity,
+        input: DeleteAuthFlowInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// The sign-in methods the login page should offer first.
+    fn get_login_options(
+        &self,
+        input: GetLoginOptionsInput,
+    ) -> impl Future<Output = Result<LoginOptions, CoreError>> + Send;
+}
+
+pub trait AuthFlowPolicy: Send + Sync {
+    fn can_view_auth_flow(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_auth_flow(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
@@ -0,0 +1,411 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::{
+    auth_flow::{
+        entities::{AuthFlow, AuthFlowContext, AuthFlowStage, Authenticator, LoginOptions},
+        ports::{AuthFlowPolicy, AuthFlowRepository, AuthFlowService},
+        value_objects::{
+            DeleteAuthFlowInput, GetAuthFlowInput, GetLoginOptionsInput, ListAuthFlowsInput,
+            UpdateAuthFlowInput,
+        },
+    },
+    authentication::{
+        entities::AuthSession, ports::AuthSessionRepository, value_objects::Identity,
+    },
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    realm::{
+        entities::{Realm, RealmId},
+        ports::RealmRepository,
+    },
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// The flow a sign-in for the client goes through: its own override, else
+/// the realm's flow, else the default one.
+pub(crate) async fn effective_auth_flow<AF: AuthFlowRepository>(
+    repository: &AF,
+    realm_id: RealmId,
+    client_id: Option<Uuid>,
+) -> Result<AuthFlow, CoreError> {
+    if client_id.is_some()
+        && let Some(flow) = repository.get_flow(realm_id, client_id).await?
+    {
+        return Ok(flow);
+    }
+
+    Ok(repository
+        .get_flow(realm_id, None)
+        .await?
+        .unwrap_or_else(|| AuthFlow::new(realm_id)))
+}
+
+/// Records that the user passed `authenticator` on the session, and returns
+/// the stage they still have to pass, if any.
+///
+/// Sessions without pending stages have nothing left of their flow, as do
+/// sessions started before flows were tracked.
+pub(crate) async fn complete_auth_flow_stage<AS: AuthSessionRepository>(
+    auth_session_repository: &AS,
+    auth_session: &AuthSession,
+    user_id: Uuid,
+    authenticator: &Authenticator,
+) -> Result<Option<AuthFlowStage>, CoreError> {
+    let Some((current, remaining)) = auth_session.pending_auth_stages.split_first() else {
+        return Ok(None);
+    };
+
+    if auth_session
+        .user_id
+        .is_some_and(|session_user| session_user != user_id)
+    {
+        return Err(CoreError::Forbidden(
+            "the session belongs to another user".to_string(),
+        ));
+    }
+
+    if !current.methods.contains(authenticator) {
+        warn!(
+            session_id = %auth_session.id,
+            authenticator = %authenticator,
+            "authenticator passed out of the order of the authentication flow"
+        );
+        return Err(CoreError::Forbidden(
+            "this step is not expected by the authentication flow".to_string(),
+        ));
+    }
+
+    auth_session_repository
+        .update_pending_auth_stages(auth_session.id, remaining.to_vec())
+        .await
+        .map_err(|_| CoreError::InternalServerError)?;
+
+    Ok(remaining.first().cloned())
+}
+
+#[derive(Clone, Debug)]
+pub struct AuthFlowServiceImpl<R, C, U, UR, AF>
+where
+    R: RealmRepository,
+    C: ClientRepository,
+    U: UserRepository,
+    UR: UserRoleRepository,
+    AF: AuthFlowRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) client_repository: Arc<C>,
+    pub(crate) auth_flow_repository: Arc<AF>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, C, U, UR, AF> AuthFlowServiceImpl<R, C, U, UR, AF>
+where
+    R: RealmRepository,
+    C: ClientRepository,
+    U: UserRepository,
+    UR: UserRoleRepository,
+    AF: AuthFlowRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        client_repository: Arc<C>,
+        auth_flow_repository: Arc<AF>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            client_repository,
+            auth_flow_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    /// Makes sure the targeted client belongs to the realm.
+    async fn ensure_client(&self, realm: &Realm, client_id: Option<Uuid>) -> Result<(), CoreError> {
+        let Some(client_id) = client_id else {
+            return Ok(());
+        };
+
+        let client = self.client_repository.get_by_id(client_id).await?;
+
+        if client.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+impl<R, C, U, UR, AF> AuthFlowService for AuthFlowServiceImpl<R, C, U, UR, AF>
+where
+    R: RealmRepository,
+    C: ClientRepository,
+    U: UserRepository,
+    UR: UserRoleRepository,
+    AF: AuthFlowRepository,
+{
+    async fn get_auth_flow(
+        &self,
+        identity: Identity,
+        input: GetAuthFlowInput,
+    ) -> Result<AuthFlow, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_auth_flow(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.ensure_client(&realm, input.client_id).await?;
+
+        effective_auth_flow(
+            self.auth_flow_repository.as_ref(),
+            realm.id,
+            input.client_id,
+        )
+        .await
+    }
+
+    async fn list_auth_flows(
+        &self,
+        identity: Identity,
+        input: ListAuthFlowsInput,
+    ) -> Result<Vec<AuthFlow>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_auth_flow(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.auth_flow_repository.list_flows(realm.id).await
+    }
+
+    async fn update_auth_flow(
+        &self,
+        identity: Identity,
+        input: UpdateAuthFlowInput,
+    ) -> Result<AuthFlow, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_auth_flow(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.ensure_client(&realm, input.client_id).await?;
+
+        AuthFlow::validate_steps(&input.steps).map_err(CoreError::InvalidAuthFlow)?;
+
+        self.auth_flow_repository
+            .upsert_flow(AuthFlow {
+                realm_id: realm.id,
+                client_id: input.client_id,
+                steps: input.steps,
+                updated_at: Utc::now(),
+            })
+            .await
+    }
+
+    async fn delete_auth_flow(
+        &self,
+        identity: Identity,
+        input: DeleteAuthFlowInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_auth_flow(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.ensure_client(&realm, input.client_id).await?;
+
+        if !self
+            .auth_flow_repository
+            .delete_flow(realm.id, input.client_id)
+            .await?
+        {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_login_options(
+        &self,
+        input: GetLoginOptionsInput,
+    ) -> Result<LoginOptions, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        let client = self
+            .client_repository
+            .get_by_client_id(input.client_id.clone(), realm.id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+
+        let flow = effective_auth_flow(
+            self.auth_flow_repository.as_ref(),
+            realm.id,
+            Some(client.id),
+        )
+        .await?;
+
+        // The user isn't known yet, so conditions on them don't hold.
+        let context = AuthFlowContext {
+            client_id: input.client_id,
+            ip_address: input.ip_address,
+            ..Default::default()
+        };
+
+        Ok(LoginOptions {
+            methods: flow.first_stage(&context),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        auth_flow::ports::MockAuthFlowRepository,
+        authentication::{entities::AuthSessionParams, ports::MockAuthSessionRepository},
+    };
+
+    fn session(pending_auth_stages: Vec<AuthFlowStage>) -> AuthSession {
+        let mut session = AuthSession::new(AuthSessionParams {
+            realm_id: RealmId::default(),
+            client_id: Uuid::new_v4(),
+            redirect_uri: "https://app.example/callback".to_string(),
+            response_type: "code".to_string(),
+            scope: "openid".to_string(),
+            state: Some("state".to_string()),
+            nonce: None,
+            user_id: Some(Uuid::new_v4()),
+            code: None,
+            authenticated: false,
+            webauthn_challenge: None,
+            webauthn_challenge_issued_at: None,
+            compass_flow_id: None,
+        });
+        session.pending_auth_stages = pending_auth_stages;
+        session
+    }
+
+    fn stage(methods: Vec<Authenticator>) -> AuthFlowStage {
+        AuthFlowStage { methods }
+    }
+
+    #[tokio::test]
+    async fn client_override_wins_over_the_realm_flow() {
+        let realm_id = RealmId::default();
+        let client_id = Uuid::new_v4();
+
+        let mut repository = MockAuthFlowRepository::new();
+        repository
+            .expect_get_flow()
+            .withf(move |_, client| *client == Some(client_id))
+            .returning(move |realm_id, client_id| {
+                Box::pin(async move {
+                    Ok(Some(AuthFlow {
+                        client_id,
+                        ..AuthFlow::new(realm_id)
+                    }))
+                })
+            });
+
+        let flow = effective_auth_flow(&repository, realm_id, Some(client_id))
+            .await
+            .unwrap();
+
+        assert_eq!(flow.client_id, Some(client_id));
+    }
+
+    #[tokio::test]
+    async fn realms_without_flow_use_the_default_one() {
+        let mut repository = MockAuthFlowRepository::new();
+        repository
+            .expect_get_flow()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        let flow = effective_auth_flow(&repository, RealmId::default(), Some(Uuid::new_v4()))
+            .await
+            .unwrap();
+
+        assert_eq!(flow.client_id, None);
+        assert_eq!(flow.steps, AuthFlow::default_steps());
+    }
+
+    #[tokio::test]
+    async fn completing_a_stage_returns_the_next_one() {
+        let auth_session = session(vec![
+            stage(vec![Authenticator::Otp, Authenticator::SmsOtp]),
+            stage(vec![Authenticator::Passkey]),
+        ]);
+
+        let mut repository = MockAuthSessionRepository::new();
+        repository
+            .expect_update_pending_auth_stages()
+            .withf(|_, remaining| *remaining == vec![stage(vec![Authenticator::Passkey])])
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let user_id = auth_session.user_id.unwrap();
+        let next =
+            complete_auth_flow_stage(&repository, &auth_session, user_id, &Authenticator::SmsOtp)
+                .await
+                .unwrap();
+
+        assert_eq!(next, Some(stage(vec![Authenticator::Passkey])));
+    }
+
+    #[tokio::test]
+    async fn stages_cannot_be_passed_out_of_order() {
+        let auth_session = session(vec![
+            stage(vec![Authenticator::Otp]),
+            stage(vec![Authenticator::Passkey]),
+        ]);
+
+        let mut repository = MockAuthSessionRepository::new();
+        repository.expect_update_pending_auth_stages().never();
+
+        let user_id = auth_session.user_id.unwrap();
+        let result =
+            complete_auth_flow_stage(&repository, &auth_session, user_id, &Authenticator::Passkey)
+                .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn stages_cannot_be_passed_by_another_user() {
+        let auth_session = session(vec![stage(vec![Authenticator::Otp])]);
+
+        let mut repository = MockAuthSessionRepository::new();
+        repository.expect_update_pending_auth_stages().never();
+
+        let result = complete_auth_flow_stage(
+            &repository,
+            &auth_session,
+            Uuid::new_v4(),
+            &Authenticator::Otp,
+        )
+        .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+}
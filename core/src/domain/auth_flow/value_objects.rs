@@ -0,0 +1,39 @@
+use std::net::IpAddr;
+
+use uuid::Uuid;
+
+use super::entities::AuthFlowStep;
+
+/// `client_id` is the client's id, not its `client_id`; `None` targets the
+/// realm's flow.
+#[derive(Debug, Clone)]
+pub struct GetAuthFlowInput {
+    pub realm_name: String,
+    pub client_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListAuthFlowsInput {
+    pub realm_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateAuthFlowInput {
+    pub realm_name: String,
+    pub client_id: Option<Uuid>,
+    pub steps: Vec<AuthFlowStep>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteAuthFlowInput {
+    pub realm_name: String,
+    pub client_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetLoginOptionsInput {
+    pub realm_name: String,
+    /// The client's `client_id`, as on the authorization request.
+    pub client_id: String,
+    pub ip_address: Option<IpAddr>,
+}
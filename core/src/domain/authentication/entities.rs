@@ -10,7 +10,11 @@ use webauthn_rs::prelude::{
 
 use crate::domain::realm::entities::RealmId;
 use crate::domain::{
-    authentication::value_objects::Identity, common::generate_timestamp, jwt::entities::JwtClaim,
+    auth_flow::entities::{AuthFlowStage, Authenticator},
+    authentication::value_objects::Identity,
+    common::generate_timestamp,
+    jwt::entities::JwtClaim,
+    risk::entities::RiskAssessment,
     user::entities::RequiredAction,
 };
 
@@ -44,6 +48,11 @@ pub struct AuthSession {
     pub webauthn_challenge: Option<WebAuthnChallenge>,
     pub webauthn_challenge_issued_at: Option<DateTime<Utc>>,
     pub compass_flow_id: Option<Uuid>,
+    /// Stages of the authentication flow left to pass before the sign-in
+    /// completes.
+    pub pending_auth_stages: Vec<AuthFlowStage>,
+    /// Score the realm's risk policy gave the sign-in, if it scores them.
+    pub risk_assessment: Option<RiskAssessment>,
 }
 
 #[derive(Debug, Clone)]
@@ -85,6 +94,8 @@ impl AuthSession {
             webauthn_challenge: params.webauthn_challenge,
             webauthn_challenge_issued_at: params.webauthn_challenge_issued_at,
             compass_flow_id: params.compass_flow_id,
+            pending_auth_stages: Vec::new(),
+            risk_assessment: None,
         }
     }
 }
@@ -164,6 +175,12 @@ pub struct AuthenticateInput {
     pub session_code: Uuid,
     pub base_url: String,
     pub auth_method: AuthenticationMethod,
+    /// Address the sign-in comes from, for flow conditions on IP ranges.
+    pub ip_address: Option<String>,
+    /// Browser or device the sign-in comes from, for risk scoring.
+    pub user_agent: Option<String>,
+    /// Token of the browser's trusted device cookie, if it has one.
+    pub trusted_device_token: Option<String>,
 }
 
 impl AuthenticateInput {
@@ -181,6 +198,9 @@ impl AuthenticateInput {
             session_code,
             base_url,
             auth_method: AuthenticationMethod::UserCredentials { username, password },
+            ip_address: None,
+            user_agent: None,
+            trusted_device_token: None,
         }
     }
 
@@ -197,9 +217,27 @@ impl AuthenticateInput {
             session_code,
             base_url,
             auth_method: AuthenticationMethod::ExistingToken { token },
+            ip_address: None,
+            user_agent: None,
+            trusted_device_token: None,
         }
     }
 
+    pub fn with_ip_address(mut self, ip_address: Option<String>) -> Self {
+        self.ip_address = ip_address;
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.user_agent = user_agent;
+        self
+    }
+
+    pub fn with_trusted_device_token(mut self, trusted_device_token: Option<String>) -> Self {
+        self.trusted_device_token = trusted_device_token;
+        self
+    }
+
     pub fn is_token_refresh(&self) -> bool {
         matches!(self.auth_method, AuthenticationMethod::ExistingToken { .. })
     }
@@ -220,6 +258,8 @@ pub struct AuthenticateOutput {
     pub required_actions: Vec<RequiredAction>,
     pub redirect_url: Option<String>,
     pub session_state: Option<String>,
+    /// Authenticators of which the user has to pass one next.
+    pub methods: Vec<Authenticator>,
 }
 
 impl AuthenticateOutput {
@@ -236,6 +276,7 @@ impl AuthenticateOutput {
             required_actions: Vec::new(),
             redirect_url: Some(redirect_url),
             session_state: None,
+            methods: Vec::new(),
         }
     }
 
@@ -252,18 +293,37 @@ impl AuthenticateOutput {
             required_actions,
             redirect_url: None,
             session_state: None,
+            methods: Vec::new(),
         }
     }
 
-    pub fn requires_otp_challenge(user_id: Uuid, temporary_token: String) -> Self {
+    /// Authenticator app, email and SMS codes keep the OTP challenge status
+    /// login pages already handle.
+    pub fn requires_challenge(
+        user_id: Uuid,
+        temporary_token: String,
+        methods: Vec<Authenticator>,
+    ) -> Self {
+        let only_codes = methods.iter().all(|method| {
+            matches!(
+                method,
+                Authenticator::Otp | Authenticator::EmailOtp | Authenticator::SmsOtp
+            )
+        });
+
         Self {
             user_id,
-            status: AuthenticationStepStatus::RequiresOtpChallenge,
+            status: if only_codes {
+                AuthenticationStepStatus::RequiresOtpChallenge
+            } else {
+                AuthenticationStepStatus::RequiresChallenge
+            },
             authorization_code: None,
             temporary_token: Some(temporary_token),
             required_actions: Vec::new(),
             redirect_url: None,
             session_state: None,
+            methods,
         }
     }
 }
@@ -276,6 +336,9 @@ pub struct CredentialsAuthParams {
     pub base_url: String,
     pub username: String,
     pub password: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub trusted_device_token: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -283,6 +346,8 @@ pub enum AuthenticationStepStatus {
     Success,
     RequiresActions,
     RequiresOtpChallenge,
+    /// The flow asks for another authenticator, like a passkey.
+    RequiresChallenge,
     Failed,
 }
 
@@ -5,13 +5,17 @@ use ferriskey_organization::OrganizationId;
 use serde_json::Value;
 use uuid::Uuid;
 
-use crate::domain::{common::entities::app_errors::CoreError, realm::entities::RealmId};
+use crate::domain::{
+    common::entities::app_errors::CoreError, realm::entities::RealmId,
+    risk::entities::RiskAssessment,
+};
 
 use super::mappers::{
     audience_mapper::AudienceMapper, hardcoded_claim_mapper::HardcodedClaimMapper,
     org_detail_mapper::OrgDetailMapper, org_membership_mapper::OrgMembershipMapper,
-    user_attribute_mapper::UserAttributeMapper, user_client_role_mapper::UserClientRoleMapper,
-    user_property_mapper::UserPropertyMapper, user_realm_role_mapper::UserRealmRoleMapper,
+    risk_score_mapper::RiskScoreMapper, user_attribute_mapper::UserAttributeMapper,
+    user_client_role_mapper::UserClientRoleMapper, user_property_mapper::UserPropertyMapper,
+    user_realm_role_mapper::UserRealmRoleMapper,
 };
 
 /// Organization membership data available to protocol mappers.
@@ -47,6 +51,8 @@ pub struct MapperContext {
     pub user_attributes: HashMap<String, Value>,
     /// Organizations the user belongs to, with their attributes pre-loaded.
     pub organizations: Vec<ContextOrganization>,
+    /// Score the realm's risk policy gave the sign-in, if any.
+    pub risk: Option<RiskAssessment>,
 }
 
 /// Which token the mapper should apply to.
@@ -76,6 +82,7 @@ enum MapperExecutor {
     UserRealmRole(UserRealmRoleMapper),
     OrgMembership(OrgMembershipMapper),
     OrgDetail(OrgDetailMapper),
+    RiskScore(RiskScoreMapper),
 }
 
 impl MapperExecutor {
@@ -94,6 +101,7 @@ impl MapperExecutor {
             Self::UserRealmRole(m) => m.execute(config, context, token_type),
             Self::OrgMembership(m) => m.execute(config, context, token_type),
             Self::OrgDetail(m) => m.execute(config, context, token_type),
+            Self::RiskScore(m) => m.execute(config, context, token_type),
         }
     }
 }
@@ -139,6 +147,10 @@ impl MapperEngine {
             "oidc-organization-detail-mapper".to_string(),
             MapperExecutor::OrgDetail(OrgDetailMapper),
         );
+        executors.insert(
+            "oidc-risk-score-mapper".to_string(),
+            MapperExecutor::RiskScore(RiskScoreMapper),
+        );
         Self { executors }
     }
 
@@ -402,6 +414,7 @@ mod tests {
             realm_id: RealmId::new(Uuid::new_v4()),
             user_attributes: HashMap::new(),
             organizations: vec![],
+            risk: None,
         };
 
         // Empty mappers should produce empty output
@@ -432,6 +445,7 @@ mod tests {
             realm_id: RealmId::new(Uuid::new_v4()),
             user_attributes: HashMap::new(),
             organizations: vec![],
+            risk: None,
         };
 
         let mapper = ProtocolMapper {
@@ -474,6 +488,7 @@ mod tests {
             realm_id: RealmId::new(Uuid::new_v4()),
             user_attributes: HashMap::new(),
             organizations: vec![],
+            risk: None,
         };
 
         assert!(context.organizations.is_empty());
@@ -511,6 +526,7 @@ mod tests {
             realm_id: RealmId::new(Uuid::new_v4()),
             user_attributes: HashMap::new(),
             organizations: vec![org],
+            risk: None,
         };
 
         assert_eq!(context.organizations.len(), 1);
@@ -565,6 +581,7 @@ mod tests {
             realm_id: RealmId::new(Uuid::new_v4()),
             user_attributes: HashMap::new(),
             organizations: orgs,
+            risk: None,
         };
 
         assert_eq!(context.organizations.len(), 2);
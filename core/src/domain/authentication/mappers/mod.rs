@@ -2,6 +2,7 @@ pub mod audience_mapper;
 pub mod hardcoded_claim_mapper;
 pub mod org_detail_mapper;
 pub mod org_membership_mapper;
+pub mod risk_score_mapper;
 pub mod user_attribute_mapper;
 pub mod user_client_role_mapper;
 pub mod user_property_mapper;
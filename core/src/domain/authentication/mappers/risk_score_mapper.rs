@@ -0,0 +1,132 @@
+use serde_json::{Value, json};
+
+use crate::domain::common::entities::app_errors::CoreError;
+
+use super::super::mapper_engine::{
+    MapperContext, MapperOutput, TokenType, resolve_claim_name, set_claim_at_path,
+    should_apply_to_token,
+};
+
+/// Maps the risk score of the sign-in to a claim, so that clients can
+/// apply their own rules on top of the realm's.
+/// Tokens of sign-ins that were not scored don't get the claim.
+///
+/// Expected config:
+/// ```json
+/// {
+///   "claim.name": "risk",
+///   "access.token.claim": "true",
+///   "id.token.claim": "true"
+/// }
+/// ```
+///
+/// Produces `{"score": 50, "signals": ["new_device"], "action": "step_up"}`.
+#[derive(Debug)]
+pub struct RiskScoreMapper;
+
+impl RiskScoreMapper {
+    pub fn execute(
+        &self,
+        config: &Value,
+        context: &MapperContext,
+        token_type: TokenType,
+    ) -> Result<MapperOutput, CoreError> {
+        if !should_apply_to_token(config, token_type) {
+            return Ok(MapperOutput::default());
+        }
+
+        let Some(risk) = &context.risk else {
+            return Ok(MapperOutput::default());
+        };
+
+        let claim_name = resolve_claim_name(config, "risk");
+
+        if claim_name.is_empty() {
+            return Ok(MapperOutput::default());
+        }
+
+        let mut output = MapperOutput::default();
+        set_claim_at_path(
+            &mut output.claims,
+            claim_name,
+            json!({
+                "score": risk.score,
+                "signals": risk.signals,
+                "action": risk.action,
+            }),
+        );
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::domain::{
+        realm::entities::RealmId,
+        risk::entities::{RiskAction, RiskAssessment, RiskSignal},
+    };
+
+    fn test_context(risk: Option<RiskAssessment>) -> MapperContext {
+        MapperContext {
+            user_id: Uuid::new_v4(),
+            username: "test".to_string(),
+            email: "test@test.com".to_string(),
+            email_verified: true,
+            firstname: "Test".to_string(),
+            lastname: "User".to_string(),
+            realm_roles: vec![],
+            client_roles: HashMap::new(),
+            client_id: "my-client".to_string(),
+            client_uuid: Uuid::new_v4(),
+            realm_name: "test-realm".to_string(),
+            realm_id: RealmId::new(Uuid::new_v4()),
+            user_attributes: HashMap::new(),
+            organizations: vec![],
+            risk,
+        }
+    }
+
+    #[test]
+    fn test_risk_claim() {
+        let context = test_context(Some(RiskAssessment {
+            score: 50,
+            signals: vec![RiskSignal::NewDevice, RiskSignal::NewCountry],
+            action: RiskAction::StepUp,
+            country: Some("FR".to_string()),
+        }));
+
+        let result = RiskScoreMapper
+            .execute(
+                &json!({"claim.name": "auth.risk"}),
+                &context,
+                TokenType::AccessToken,
+            )
+            .unwrap();
+
+        assert_eq!(
+            result.claims.get("auth"),
+            Some(&json!({
+                "risk": {
+                    "score": 50,
+                    "signals": ["new_device", "new_country"],
+                    "action": "step_up",
+                }
+            }))
+        );
+    }
+
+    #[test]
+    fn test_unscored_sign_in_has_no_claim() {
+        let result = RiskScoreMapper
+            .execute(&json!({}), &test_context(None), TokenType::IdToken)
+            .unwrap();
+
+        assert!(result.claims.is_empty());
+    }
+}
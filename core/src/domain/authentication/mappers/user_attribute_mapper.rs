@@ -85,6 +85,7 @@ mod tests {
             realm_id: RealmId::new(Uuid::new_v4()),
             user_attributes,
             organizations: vec![],
+            risk: None,
         }
     }
 
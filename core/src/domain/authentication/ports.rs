@@ -1,11 +1,13 @@
 use uuid::Uuid;
 
 use crate::domain::authentication::value_objects::{
-    EndSessionInput, EndSessionOutput, GenerateTokensForUserInput, GetUserInfoInput, Identity,
-    IntrospectTokenInput, RevokeTokenInput, UserInfoResponse,
+    CheckSignInInput, EndSessionInput, EndSessionOutput, GenerateTokensForUserInput,
+    GetUserInfoInput, Identity, IntrospectTokenInput, RevokeTokenInput, SignInCheck,
+    UserInfoResponse,
 };
 use crate::domain::realm::entities::RealmId;
 use crate::domain::{
+    auth_flow::entities::AuthFlowStage,
     authentication::{
         entities::{
             AuthInput, AuthOutput, AuthSession, AuthenticateInput, AuthenticateOutput,
@@ -20,6 +22,7 @@ use crate::domain::{
     },
     common::entities::app_errors::CoreError,
     jwt::entities::JwkKey,
+    risk::entities::RiskAssessment,
 };
 
 /// A strategy for handling different OAuth2 grant types during authentication.
@@ -112,6 +115,18 @@ pub trait AuthSessionRepository: Send + Sync {
         session_code: Uuid,
         authenticated: bool,
     ) -> impl Future<Output = Result<(), AuthenticationError>> + Send;
+
+    fn update_pending_auth_stages(
+        &self,
+        session_code: Uuid,
+        pending_auth_stages: Vec<AuthFlowStage>,
+    ) -> impl Future<Output = Result<(), AuthenticationError>> + Send;
+
+    fn update_risk_assessment(
+        &self,
+        session_code: Uuid,
+        risk_assessment: RiskAssessment,
+    ) -> impl Future<Output = Result<(), AuthenticationError>> + Send;
 }
 
 pub trait AuthService: Send + Sync {
@@ -166,6 +181,20 @@ pub trait AuthService: Send + Sync {
     ) -> impl Future<Output = Result<JwtToken, CoreError>> + Send;
 }
 
+/// Runs the checks of a password sign-in on the sign-ins that don't go
+/// through the credentials endpoint, without depending on the full
+/// `AuthService` surface. Implemented by the authentication service.
+#[cfg_attr(test, mockall::automock)]
+pub trait SignInGate: Send + Sync {
+    /// Scores the sign-in against the realm's risk policy and stores on the
+    /// session what the user still has to pass. Blocked sign-ins fail with
+    /// `CoreError::Forbidden`.
+    fn check_sign_in(
+        &self,
+        input: CheckSignInInput,
+    ) -> impl Future<Output = Result<SignInCheck, CoreError>> + Send;
+}
+
 /// A strategy for handling different OAuth2 grant types during authentication.
 ///
 /// This trait defines the contract for implementing specific grant type strategies,
@@ -206,7 +235,7 @@ pub trait AuthenticatePort: Send + Sync {
     fn determine_next_step(
         &self,
         auth_result: AuthenticationResult,
-        session_code: Uuid,
+        params: &CredentialsAuthParams,
         auth_session: AuthSession,
     ) -> impl Future<Output = Result<AuthenticateOutput, CoreError>> + Send;
     fn finalize_authentication(
@@ -19,11 +19,16 @@ use ferriskey_organization::{
 };
 
 use crate::domain::authentication::mapper_engine::ContextOrganization;
-use crate::domain::maintenance::ports::{
-    MaintenanceWhitelistRepository, RealmMaintenanceWhitelistRepository,
-};
+use crate::domain::maintenance::ports::MaintenanceService;
 use crate::domain::{
     abyss::federation::ports::FederationRepository,
+    auth_flow::{
+        entities::{
+            AuthFlowCondition, AuthFlowContext, AuthFlowDenied, AuthFlowPlan, Authenticator,
+        },
+        ports::AuthFlowRepository,
+        services::effective_auth_flow,
+    },
     authentication::{
         OidcScope,
         entities::{
@@ -33,17 +38,20 @@ use crate::domain::{
             TokenIntrospectionResponse,
         },
         mapper_engine::{MapperContext, MapperEngine, TokenType},
-        ports::{AuthService, AuthSessionRepository},
+        ports::{AuthService, AuthSessionRepository, SignInGate},
         value_objects::{
-            AuthenticationResult, EndSessionInput, EndSessionOutput, GenerateTokenInput,
-            GenerateTokensForUserInput, GetUserInfoInput, GrantTypeParams, Identity,
-            IntrospectTokenInput, RegisterUserInput, RegisterUserOutput, RegisterUserUrlContext,
-            RevokeTokenInput, UserInfoResponse,
+            AuthenticationResult, CheckSignInInput, EndSessionInput, EndSessionOutput,
+            GenerateTokenInput, GenerateTokensForUserInput, GetUserInfoInput, GrantTypeParams,
+            Identity, IntrospectTokenInput, RegisterUserInput, RegisterUserOutput,
+            RegisterUserUrlContext, RevokeTokenInput, SignInCheck, UserInfoResponse,
         },
     },
     client::ports::{ClientRepository, PostLogoutRedirectUriRepository, RedirectUriRepository},
     common::{entities::app_errors::CoreError, generate_random_string},
-    credential::{entities::CredentialData, ports::CredentialRepository},
+    credential::{
+        entities::{CredentialData, CredentialType},
+        ports::CredentialRepository,
+    },
     crypto::HasherRepository,
     email_verification::ports::EmailVerificationService,
     jwt::{
@@ -52,7 +60,9 @@ use crate::domain::{
         ports::{AccessTokenRepository, RefreshTokenRepository},
     },
     realm::{entities::RealmId, ports::RealmRepository},
+    risk::{entities::RiskAction, ports::RiskAssessor, value_objects::AssessSignInInput},
     seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
+    trusted_device::{ports::TrustedDeviceRepository, services::trusted_device_accepted},
     user::{
         entities::{RequiredAction, UserAttribute},
         ports::{
@@ -126,12 +136,14 @@ pub struct AuthServiceImpl<
     OR,
     OAR,
     URA,
-    MW,
-    RMW,
+    MS,
     UAR,
     EV,
     WR,
     SER,
+    AF,
+    RA,
+    TD,
 > where
     R: RealmRepository,
     C: ClientRepository,
@@ -152,12 +164,14 @@ pub struct AuthServiceImpl<
     OR: OrganizationRepository,
     OAR: OrganizationAttributeRepository,
     URA: UserRequiredActionRepository,
-    MW: MaintenanceWhitelistRepository,
-    RMW: RealmMaintenanceWhitelistRepository,
+    MS: MaintenanceService,
     UAR: UserAttributeRepository,
     EV: EmailVerificationService,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    AF: AuthFlowRepository,
+    RA: RiskAssessor,
+    TD: TrustedDeviceRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) client_repository: Arc<C>,
@@ -178,12 +192,14 @@ pub struct AuthServiceImpl<
     pub(crate) organization_repository: Arc<OR>,
     pub(crate) organization_attribute_repository: Arc<OAR>,
     pub(crate) user_required_action_repository: Arc<URA>,
-    pub(crate) maintenance_whitelist_repository: Arc<MW>,
-    pub(crate) realm_maintenance_whitelist_repository: Arc<RMW>,
+    pub(crate) maintenance_service: MS,
     pub(crate) user_attribute_repository: Arc<UAR>,
     pub(crate) email_verification_service: EV,
     pub(crate) webhook_repository: Arc<WR>,
     pub(crate) security_event_repository: Arc<SER>,
+    pub(crate) auth_flow_repository: Arc<AF>,
+    pub(crate) risk_assessor: Arc<RA>,
+    pub(crate) trusted_device_repository: Arc<TD>,
     pub(crate) mapper_engine: Arc<MapperEngine>,
     pub(crate) ldap_client: LdapClientImpl,
     pub(crate) flow_recorder: FlowRecorder,
@@ -209,12 +225,14 @@ impl<
     OR,
     OAR,
     URA,
-    MW,
-    RMW,
+    MS,
     UAR,
     EV,
     WR,
     SER,
+    AF,
+    RA,
+    TD,
 >
     AuthServiceImpl<
         R,
@@ -236,12 +254,14 @@ impl<
         OR,
         OAR,
         URA,
-        MW,
-        RMW,
+        MS,
         UAR,
         EV,
         WR,
         SER,
+        AF,
+        RA,
+        TD,
     >
 where
     R: RealmRepository,
@@ -263,12 +283,14 @@ where
     OR: OrganizationRepository,
     OAR: OrganizationAttributeRepository,
     URA: UserRequiredActionRepository,
-    MW: MaintenanceWhitelistRepository,
-    RMW: RealmMaintenanceWhitelistRepository,
+    MS: MaintenanceService,
     UAR: UserAttributeRepository,
     EV: EmailVerificationService,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    AF: AuthFlowRepository,
+    RA: RiskAssessor,
+    TD: TrustedDeviceRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -291,12 +313,14 @@ where
         organization_repository: Arc<OR>,
         organization_attribute_repository: Arc<OAR>,
         user_required_action_repository: Arc<URA>,
-        maintenance_whitelist_repository: Arc<MW>,
-        realm_maintenance_whitelist_repository: Arc<RMW>,
+        maintenance_service: MS,
         user_attribute_repository: Arc<UAR>,
         email_verification_service: EV,
         webhook_repository: Arc<WR>,
         security_event_repository: Arc<SER>,
+        auth_flow_repository: Arc<AF>,
+        risk_assessor: Arc<RA>,
+        trusted_device_repository: Arc<TD>,
         mapper_engine: Arc<MapperEngine>,
         flow_recorder: FlowRecorder,
     ) -> Self {
@@ -320,12 +344,14 @@ where
             organization_repository,
             organization_attribute_repository,
             user_required_action_repository,
-            maintenance_whitelist_repository,
-            realm_maintenance_whitelist_repository,
+            maintenance_service,
             user_attribute_repository,
             email_verification_service,
             webhook_repository,
             security_event_repository,
+            auth_flow_repository,
+            risk_assessor,
+            trusted_device_repository,
             mapper_engine,
             ldap_client: LdapClientImpl,
             flow_recorder,
@@ -353,12 +379,14 @@ impl<
     OR,
     OAR,
     URA,
-    MW,
-    RMW,
+    MS,
     UAR,
     EV,
     WR,
     SER,
+    AF,
+    RA,
+    TD,
 >
     AuthServiceImpl<
         R,
@@ -380,12 +408,14 @@ impl<
         OR,
         OAR,
         URA,
-        MW,
-        RMW,
+        MS,
         UAR,
         EV,
         WR,
         SER,
+        AF,
+        RA,
+        TD,
     >
 where
     R: RealmRepository,
@@ -407,13 +437,108 @@ where
     OR: OrganizationRepository,
     OAR: OrganizationAttributeRepository,
     URA: UserRequiredActionRepository,
-    MW: MaintenanceWhitelistRepository,
-    RMW: RealmMaintenanceWhitelistRepository,
+    MS: MaintenanceService,
     UAR: UserAttributeRepository,
     EV: EmailVerificationService,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    AF: AuthFlowRepository,
+    RA: RiskAssessor,
+    TD: TrustedDeviceRepository,
 {
+    /// Evaluates the flow of the session's client for a user who signed in
+    /// with their password, recording the conditions checked to Compass.
+    async fn plan_auth_flow(
+        &self,
+        auth_result: &AuthenticationResult,
+        params: &CredentialsAuthParams,
+        auth_session: &AuthSession,
+        flow_id: Option<&FlowId>,
+    ) -> Result<Result<AuthFlowPlan, AuthFlowDenied>, CoreError> {
+        let flow = effective_auth_flow(
+            self.auth_flow_repository.as_ref(),
+            auth_session.realm_id,
+            Some(auth_session.client_id),
+        )
+        .await?;
+
+        let roles = if flow.uses_condition(|c| matches!(c, AuthFlowCondition::UserRole { .. })) {
+            self.user_role_repository
+                .get_user_roles(auth_result.user_id)
+                .await?
+                .into_iter()
+                .filter(|role| role.client_id.is_none())
+                .map(|role| role.name)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut organizations = Vec::new();
+        if flow.uses_condition(|c| matches!(c, AuthFlowCondition::OrganizationMember { .. })) {
+            let memberships = self
+                .organization_member_repository
+                .list_organizations_for_user(auth_result.user_id)
+                .await?;
+
+            for membership in memberships {
+                if let Some(organization) = self
+                    .organization_repository
+                    .get_organization_by_id(membership.organization_id)
+                    .await?
+                {
+                    organizations.push(organization.alias);
+                }
+            }
+        }
+
+        let context = AuthFlowContext {
+            client_id: params.client_id.clone(),
+            ip_address: params.ip_address.as_deref().and_then(|ip| ip.parse().ok()),
+            roles,
+            organizations,
+            configured: auth_result
+                .credentials
+                .iter()
+                .filter_map(|cred| {
+                    Authenticator::from_credential_type(&CredentialType::from(cred.clone()))
+                })
+                .collect(),
+        };
+
+        let plan = flow.plan(
+            &context,
+            &[
+                Authenticator::IdentityFirst,
+                Authenticator::UsernamePassword,
+            ],
+        );
+
+        if let Some(fid) = flow_id {
+            let conditions = match &plan {
+                Ok(plan) => &plan.conditions,
+                Err(denied) => &denied.conditions,
+            };
+
+            for (_, holds) in conditions {
+                self.flow_recorder.record_step(
+                    fid.clone(),
+                    FlowStepName::ConditionCheck,
+                    if *holds {
+                        StepStatus::Success
+                    } else {
+                        StepStatus::Skipped
+                    },
+                    None,
+                    None,
+                    None,
+                );
+            }
+        }
+
+        Ok(plan)
+    }
+
     fn expires_in_from(exp: i64) -> u32 {
         let now = Utc::now().timestamp();
         if exp <= now { 0 } else { (exp - now) as u32 }
@@ -604,6 +729,7 @@ where
             realm_id: input.realm_id,
             user_attributes,
             organizations,
+            risk: input.risk.clone(),
         };
 
         // Apply mappers for access token
@@ -730,6 +856,7 @@ where
             self.refresh_token_repository.create(
                 refresh_claims.jti,
                 input.user_id,
+                Some(input.client_uuid),
                 Some(refresh_token_expires_at),
             )
         )
@@ -942,6 +1069,15 @@ where
         let user_id = auth_session.user_id.ok_or(CoreError::NotFound)?;
         let user = self.user_repository.get_by_id(user_id).await?;
 
+        let client = self
+            .client_repository
+            .get_by_id(auth_session.client_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+        self.maintenance_service
+            .ensure_access_allowed(params.realm_id, Some(&client), user.id)
+            .await?;
+
         let final_scope = self
             .resolve_scopes_for_client(auth_session.client_id, Some(auth_session.scope.clone()))
             .await?;
@@ -969,6 +1105,7 @@ where
                 access_token_lifetime: lifetimes.access_token,
                 refresh_token_lifetime: lifetimes.refresh_token,
                 id_token_lifetime: lifetimes.id_token,
+                risk: auth_session.risk_assessment.clone(),
             })
             .await
             .map_err(|e| {
@@ -1070,6 +1207,10 @@ where
                 _ => CoreError::InternalServerError,
             })?;
 
+        self.maintenance_service
+            .ensure_access_allowed(params.realm_id, Some(&client), user.id)
+            .await?;
+
         let final_scope = self
             .resolve_scopes_for_client(client.id, params.scope)
             .await?;
@@ -1095,6 +1236,7 @@ where
                 access_token_lifetime: lifetimes.access_token,
                 refresh_token_lifetime: lifetimes.refresh_token,
                 id_token_lifetime: lifetimes.id_token,
+                risk: None,
             })
             .await?;
 
@@ -1170,6 +1312,10 @@ where
             return Err(CoreError::Invalid);
         }
 
+        self.maintenance_service
+            .ensure_access_allowed(params.realm_id, Some(&client), user.id)
+            .await?;
+
         let final_scope = self
             .resolve_scopes_for_client(client.id, params.scope)
             .await?;
@@ -1195,6 +1341,7 @@ where
                 access_token_lifetime: lifetimes.access_token,
                 refresh_token_lifetime: lifetimes.refresh_token,
                 id_token_lifetime: lifetimes.id_token,
+                risk: None,
             })
             .instrument(info_span!("auth.password.create_jwt"))
             .await?;
@@ -1244,6 +1391,12 @@ where
             .await
             .map_err(|_| CoreError::InvalidClient)?;
 
+        // Whatever the session strategy, maintenance stops refreshes for
+        // users who are not whitelisted.
+        self.maintenance_service
+            .ensure_access_allowed(params.realm_id, Some(&client), user.id)
+            .await?;
+
         let lifetimes = self
             .resolve_token_lifetimes(params.realm_id, client.id)
             .await?;
@@ -1265,6 +1418,7 @@ where
                 access_token_lifetime: lifetimes.access_token,
                 refresh_token_lifetime: lifetimes.refresh_token,
                 id_token_lifetime: lifetimes.id_token,
+                risk: None,
             })
             .await?;
 
@@ -1311,12 +1465,12 @@ where
 
         let auth_result = self
             .using_session_code(
-                params.realm_name,
-                params.client_id,
+                params.realm_name.clone(),
+                params.client_id.clone(),
                 params.session_code,
-                params.username,
-                params.password,
-                params.base_url,
+                params.username.clone(),
+                params.password.clone(),
+                params.base_url.clone(),
             )
             .await
             .map_err(|e| {
@@ -1347,32 +1501,101 @@ where
             );
         }
 
-        self.determine_next_step(auth_result, params.session_code, auth_session)
+        self.determine_next_step(auth_result, &params, auth_session)
             .await
     }
 
     async fn determine_next_step(
         &self,
         auth_result: AuthenticationResult,
-        session_code: Uuid,
+        params: &CredentialsAuthParams,
         auth_session: AuthSession,
     ) -> Result<AuthenticateOutput, CoreError> {
         let flow_id = auth_session.compass_flow_id.map(FlowId);
+        let session_code = params.session_code;
+        let token = auth_result
+            .token
+            .clone()
+            .ok_or(CoreError::InternalServerError)?;
+
+        let plan = match self
+            .plan_auth_flow(&auth_result, params, &auth_session, flow_id.as_ref())
+            .await?
+        {
+            Ok(plan) => plan,
+            Err(_) => {
+                if let Some(ref fid) = flow_id {
+                    self.flow_recorder.record_step(
+                        fid.clone(),
+                        FlowStepName::CredentialValidation,
+                        StepStatus::Failure,
+                        None,
+                        Some("auth_flow_denied".to_string()),
+                        Some("sign-in method not allowed by the authentication flow".to_string()),
+                    );
+                }
+                return Err(CoreError::Forbidden(
+                    "sign-in method not allowed by the authentication flow".to_string(),
+                ));
+            }
+        };
 
-        if !auth_result.required_actions.is_empty() {
+        let sign_in = CheckSignInInput {
+            auth_session: auth_session.clone(),
+            user_id: auth_result.user_id,
+            authenticator: Authenticator::UsernamePassword,
+            base_url: params.base_url.clone(),
+            ip_address: params.ip_address.clone(),
+            user_agent: params.user_agent.clone(),
+            trusted_device_token: params.trusted_device_token.clone(),
+        };
+        let configured: Vec<Authenticator> = auth_result
+            .credentials
+            .iter()
+            .filter_map(|cred| {
+                Authenticator::from_credential_type(&CredentialType::from(cred.clone()))
+            })
+            .collect();
+        let plan = self.skip_trusted_second_factors(&sign_in, plan).await?;
+        let plan = self
+            .apply_risk_policy(&sign_in, &configured, plan, flow_id.as_ref())
+            .await?;
+
+        // Setup the flow asks for is kept on the user, so it can't be
+        // skipped by coming back with the temporary token.
+        let mut required_actions = auth_result.required_actions;
+        for action in plan.required_actions {
+            if required_actions.contains(&action) {
+                continue;
+            }
+
+            self.user_required_action_repository
+                .add_required_action(auth_result.user_id, action.clone())
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+            required_actions.push(action);
+        }
+
+        if !plan.pending.is_empty() {
+            self.auth_session_repository
+                .update_pending_auth_stages(session_code, plan.pending.clone())
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+            self.auth_session_repository
+                .update_user_id(session_code, auth_result.user_id)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+        }
+
+        if !required_actions.is_empty() {
             return Ok(AuthenticateOutput::requires_actions(
                 auth_result.user_id,
-                auth_result.required_actions,
-                auth_result.token.ok_or(CoreError::InternalServerError)?,
+                required_actions,
+                token,
             ));
         }
 
-        let has_otp_credentials = auth_result.credentials.iter().any(|cred| cred == "otp");
-        let needs_configure_otp = auth_result
-            .required_actions
-            .contains(&RequiredAction::ConfigureOtp);
-
-        if has_otp_credentials && !needs_configure_otp {
+        if let Some(stage) = plan.pending.into_iter().next() {
             if let Some(ref fid) = flow_id {
                 self.flow_recorder.record_step(
                     fid.clone(),
@@ -1383,10 +1606,10 @@ where
                     None,
                 );
             }
-            let token = auth_result.token.ok_or(CoreError::InternalServerError)?;
-            return Ok(AuthenticateOutput::requires_otp_challenge(
+            return Ok(AuthenticateOutput::requires_challenge(
                 auth_result.user_id,
                 token,
+                stage.methods,
             ));
         }
 
@@ -1394,6 +1617,160 @@ where
             .await
     }
 
+    /// Drops the second factors the flow asks for when the sign-in comes
+    /// from a device the user trusted. The risk policy still applies after.
+    async fn skip_trusted_second_factors(
+        &self,
+        sign_in: &CheckSignInInput,
+        mut plan: AuthFlowPlan,
+    ) -> Result<AuthFlowPlan, CoreError> {
+        if plan.pending.is_empty() {
+            return Ok(plan);
+        }
+
+        let trusted = trusted_device_accepted(
+            self.realm_repository.as_ref(),
+            self.trusted_device_repository.as_ref(),
+            sign_in.auth_session.realm_id,
+            sign_in.user_id,
+            sign_in.trusted_device_token.as_deref(),
+            sign_in.user_agent.as_deref(),
+        )
+        .await?;
+
+        if trusted {
+            plan.skip_second_factors();
+        }
+
+        Ok(plan)
+    }
+
+    /// Scores the sign-in against the realm's risk policy, blocking it or
+    /// asking for a second factor when the score calls for it. A sign-in
+    /// that already passed one, like a passkey, isn't asked for another.
+    async fn apply_risk_policy(
+        &self,
+        sign_in: &CheckSignInInput,
+        configured: &[Authenticator],
+        mut plan: AuthFlowPlan,
+        flow_id: Option<&FlowId>,
+    ) -> Result<AuthFlowPlan, CoreError> {
+        let Some(assessment) = self
+            .risk_assessor
+            .assess_sign_in(AssessSignInInput {
+                realm_id: sign_in.auth_session.realm_id,
+                user_id: sign_in.user_id,
+                ip_address: sign_in.ip_address.clone(),
+                user_agent: sign_in.user_agent.clone(),
+            })
+            .await?
+        else {
+            return Ok(plan);
+        };
+
+        self.auth_session_repository
+            .update_risk_assessment(sign_in.auth_session.id, assessment.clone())
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if let Some(fid) = flow_id {
+            let blocked = assessment.action == RiskAction::Block;
+            self.flow_recorder.record_step(
+                fid.clone(),
+                FlowStepName::RiskAssessment,
+                if blocked {
+                    StepStatus::Failure
+                } else {
+                    StepStatus::Success
+                },
+                None,
+                blocked.then(|| "risk_blocked".to_string()),
+                Some(format!(
+                    "score {} ({})",
+                    assessment.score,
+                    assessment
+                        .signals
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            );
+        }
+
+        match assessment.action {
+            RiskAction::Block => Err(CoreError::Forbidden(
+                "sign-in blocked by risk policy".to_string(),
+            )),
+            RiskAction::StepUp if sign_in.authenticator.is_identification() => {
+                if !plan.require_second_factor(configured) {
+                    warn!(
+                        user_id = %sign_in.user_id,
+                        score = assessment.score,
+                        "Risk policy asks for a second factor but the user has none"
+                    );
+                }
+
+                Ok(plan)
+            }
+            RiskAction::StepUp | RiskAction::Allow | RiskAction::Notify => Ok(plan),
+        }
+    }
+
+    /// Authenticators the user has set up.
+    async fn configured_authenticators(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<Authenticator>, CoreError> {
+        let credentials = self
+            .credential_repository
+            .get_credentials_by_user_id(user_id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?;
+
+        Ok(credentials
+            .iter()
+            .filter_map(|cred| Authenticator::from_credential_type(&cred.credential_type))
+            .collect())
+    }
+
+    /// A token the user comes back with to pass the next stage of their
+    /// sign-in, like the one a password sign-in gets.
+    async fn temporary_token(&self, sign_in: &CheckSignInInput) -> Result<String, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_id(sign_in.auth_session.realm_id)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        let user = self.user_repository.get_by_id(sign_in.user_id).await?;
+        let client = self
+            .client_repository
+            .get_by_id(sign_in.auth_session.client_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+
+        let access_lifetime = self
+            .realm_repository
+            .get_realm_settings(realm.id)
+            .await?
+            .map(|s| s.access_token_lifetime)
+            .unwrap_or(DEFAULT_ACCESS_TOKEN_LIFETIME);
+
+        let claim = JwtClaim::new(
+            user.id,
+            user.username.clone(),
+            format!("{}/realms/{}", sign_in.base_url, realm.name),
+            vec![format!("{}-realm", realm.name), "account".to_string()],
+            ClaimsTyp::Temporary,
+            client.client_id,
+            user.email.clone(),
+            Some(sign_in.auth_session.scope.clone()),
+            access_lifetime,
+        );
+
+        Ok(self.generate_token(claim, realm.id).await?.token)
+    }
+
     async fn finalize_authentication(
         &self,
         user_id: Uuid,
@@ -1461,48 +1838,9 @@ where
             return Err(CoreError::UserDisabled);
         }
 
-        // Check maintenance mode
-        if client.maintenance_enabled {
-            let user_roles = self
-                .user_role_repository
-                .get_user_roles(user.id)
-                .await
-                .map_err(|_| CoreError::InternalServerError)?;
-            let role_ids: Vec<Uuid> = user_roles.iter().map(|r| r.id).collect();
-
-            let allowed_user_ids = self
-                .maintenance_whitelist_repository
-                .get_whitelisted_user_ids(client.id)
-                .await?;
-            let allowed_role_ids = self
-                .maintenance_whitelist_repository
-                .get_whitelisted_role_ids(client.id)
-                .await?;
-            let realm_allowed_user_ids = self
-                .realm_maintenance_whitelist_repository
-                .get_whitelisted_user_ids(realm.id)
-                .await?;
-            let realm_allowed_role_ids = self
-                .realm_maintenance_whitelist_repository
-                .get_whitelisted_role_ids(realm.id)
-                .await?;
-
-            let is_allowed = allowed_user_ids.contains(&user.id)
-                || role_ids.iter().any(|r| allowed_role_ids.contains(r))
-                || realm_allowed_user_ids.contains(&user.id)
-                || role_ids.iter().any(|r| realm_allowed_role_ids.contains(r));
-
-            if !is_allowed {
-                let reason = client
-                    .maintenance_reason
-                    .unwrap_or_else(|| "This service is currently under maintenance".to_string());
-                warn!(
-                    "User {} denied access to client {} (maintenance mode)",
-                    user.username, client.name
-                );
-                return Err(CoreError::ClientUnderMaintenance(reason));
-            }
-        }
+        self.maintenance_service
+            .ensure_access_allowed(realm.id, Some(&client), user.id)
+            .await?;
 
         // Check if user has federation mapping (LDAP authentication) FIRST
         let federation_mapping = self
@@ -1619,6 +1957,20 @@ This is a server error that should be investigated. Do not forward back this mes
             };
 
         if !has_valid_password {
+            self.security_event_repository
+                .store_event(
+                    SecurityEvent::new(
+                        realm.id,
+                        SecurityEventType::LoginFailure,
+                        EventStatus::Failure,
+                        user.id,
+                    )
+                    .with_target("user".to_string(), user.id, None),
+                )
+                .await
+                .map_err(|err| warn!("Failed to store LoginFailure security event: {}", err))
+                .ok();
+
             return Err(CoreError::InvalidPassword);
         }
 
@@ -1665,24 +2017,16 @@ This is a server error that should be investigated. Do not forward back this mes
                 credentials,
             });
         }
-        let has_otp_credentials = credentials.iter().any(|cred| cred == "otp");
-        if has_otp_credentials {
-            let jwt_token = self.generate_token(jwt_claim, realm.id).await?;
 
-            return Ok(AuthenticationResult {
-                code: None,
-                required_actions: user.required_actions.clone(),
-                user_id: user.id,
-                token: Some(jwt_token.token),
-                credentials,
-            });
-        }
+        // The authentication flow decides what comes next, and may need the
+        // user to come back with this token.
+        let jwt_token = self.generate_token(jwt_claim, realm.id).await?;
 
         Ok(AuthenticationResult {
-            code: Some(generate_random_string()),
+            code: None,
             required_actions: Vec::new(),
             user_id: user.id,
-            token: None,
+            token: Some(jwt_token.token),
             credentials,
         })
     }
@@ -1739,9 +2083,30 @@ This is a server error that should be investigated. Do not forward back this mes
                 required_actions: user.required_actions,
                 session_state: None,
                 temporary_token: Some(jwt_token.token),
+                methods: Vec::new(),
             });
         }
 
+        // A token doesn't stand in for the stages of the flow the session
+        // still has to pass.
+        if let Some(stage) = auth_session.pending_auth_stages.first() {
+            if auth_session
+                .user_id
+                .is_some_and(|user_id| user_id != user.id)
+            {
+                return Err(CoreError::InvalidSession);
+            }
+
+            let methods = stage.methods.clone();
+            let jwt_token = self.generate_token(claims, realm_id).await?;
+
+            return Ok(AuthenticateOutput::requires_challenge(
+                user.id,
+                jwt_token.token,
+                methods,
+            ));
+        }
+
         self.finalize_authentication(claims.sub, session_code, auth_session)
             .await
     }
@@ -1862,12 +2227,146 @@ impl<
     OR,
     OAR,
     URA,
-    MW,
-    RMW,
+    MS,
+    UAR,
+    EV,
+    WR,
+    SER,
+    AF,
+    RA,
+    TD,
+> SignInGate
+    for AuthServiceImpl<
+        R,
+        C,
+        RU,
+        PLRU,
+        U,
+        UR,
+        CR,
+        H,
+        AS,
+        KS,
+        RT,
+        AT,
+        F,
+        CSM,
+        PM,
+        OM,
+        OR,
+        OAR,
+        URA,
+        MS,
+        UAR,
+        EV,
+        WR,
+        SER,
+        AF,
+        RA,
+        TD,
+    >
+where
+    R: RealmRepository,
+    C: ClientRepository,
+    RU: RedirectUriRepository,
+    PLRU: PostLogoutRedirectUriRepository,
+    U: UserRepository,
+    UR: UserRoleRepository,
+    CR: CredentialRepository,
+    H: HasherRepository,
+    AS: AuthSessionRepository,
+    KS: KeyStoreRepository,
+    RT: RefreshTokenRepository,
+    AT: AccessTokenRepository,
+    F: FederationRepository,
+    CSM: ClientScopeMappingRepository,
+    PM: ProtocolMapperRepository,
+    OM: OrganizationMemberRepository,
+    OR: OrganizationRepository,
+    OAR: OrganizationAttributeRepository,
+    URA: UserRequiredActionRepository,
+    MS: MaintenanceService,
+    UAR: UserAttributeRepository,
+    EV: EmailVerificationService,
+    WR: WebhookRepository,
+    SER: SecurityEventRepository,
+    AF: AuthFlowRepository,
+    RA: RiskAssessor,
+    TD: TrustedDeviceRepository,
+{
+    async fn check_sign_in(&self, input: CheckSignInInput) -> Result<SignInCheck, CoreError> {
+        let flow_id = input.auth_session.compass_flow_id.map(FlowId);
+        let configured = self.configured_authenticators(input.user_id).await?;
+
+        let plan = AuthFlowPlan {
+            pending: Vec::new(),
+            required_actions: Vec::new(),
+            conditions: Vec::new(),
+        };
+        let plan = self.skip_trusted_second_factors(&input, plan).await?;
+        let plan = self
+            .apply_risk_policy(&input, &configured, plan, flow_id.as_ref())
+            .await?;
+
+        let Some(next) = plan.pending.first().cloned() else {
+            return Ok(SignInCheck::default());
+        };
+
+        self.auth_session_repository
+            .update_pending_auth_stages(input.auth_session.id, plan.pending)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+        self.auth_session_repository
+            .update_user_id(input.auth_session.id, input.user_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if let Some(ref fid) = flow_id {
+            self.flow_recorder.record_step(
+                fid.clone(),
+                FlowStepName::MfaChallenge,
+                StepStatus::Success,
+                None,
+                None,
+                None,
+            );
+        }
+
+        Ok(SignInCheck {
+            next: Some(next),
+            temporary_token: Some(self.temporary_token(&input).await?),
+        })
+    }
+}
+
+impl<
+    R,
+    C,
+    RU,
+    PLRU,
+    U,
+    UR,
+    CR,
+    H,
+    AS,
+    KS,
+    RT,
+    AT,
+    F,
+    CSM,
+    PM,
+    OM,
+    OR,
+    OAR,
+    URA,
+    MS,
     UAR,
     EV,
     WR,
     SER,
+    AF,
+    RA,
+    TD,
 > AuthService
     for AuthServiceImpl<
         R,
@@ -1889,12 +2388,14 @@ impl<
         OR,
         OAR,
         URA,
-        MW,
-        RMW,
+        MS,
         UAR,
         EV,
         WR,
         SER,
+        AF,
+        RA,
+        TD,
     >
 where
     R: RealmRepository,
@@ -1916,12 +2417,14 @@ where
     OR: OrganizationRepository,
     OAR: OrganizationAttributeRepository,
     URA: UserRequiredActionRepository,
-    MW: MaintenanceWhitelistRepository,
-    RMW: RealmMaintenanceWhitelistRepository,
+    MS: MaintenanceService,
     UAR: UserAttributeRepository,
     EV: EmailVerificationService,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    AF: AuthFlowRepository,
+    RA: RiskAssessor,
+    TD: TrustedDeviceRepository,
 {
     async fn auth(&self, input: AuthInput) -> Result<AuthOutput, CoreError> {
         let realm = self
@@ -2243,6 +2746,9 @@ where
                     base_url: input.base_url,
                     username,
                     password,
+                    ip_address: input.ip_address,
+                    user_agent: input.user_agent,
+                    trusted_device_token: input.trusted_device_token,
                 };
 
                 self.handle_user_credentials_authentication(params, auth_session)
@@ -2710,6 +3216,19 @@ where
 
         let user = self.user_repository.get_by_id(input.user_id).await?;
 
+        let client = match input.client_id {
+            Some(client_uuid) => Some(
+                self.client_repository
+                    .get_by_id(client_uuid)
+                    .await
+                    .map_err(|_| CoreError::InvalidClient)?,
+            ),
+            None => None,
+        };
+        self.maintenance_service
+            .ensure_access_allowed(realm.id, client.as_ref(), user.id)
+            .await?;
+
         let iss = format!("{}/realms/{}", input.base_url, realm.name);
         let claims = JwtClaim::new(
             user.id,
@@ -2785,6 +3304,8 @@ mod tests {
             webauthn_challenge: None,
             webauthn_challenge_issued_at: None,
             compass_flow_id: None,
+            pending_auth_stages: Vec::new(),
+            risk_assessment: None,
         }
     }
 
@@ -4,9 +4,11 @@ use uuid::Uuid;
 
 use crate::domain::jwt::entities::JwtClaim;
 use crate::domain::realm::entities::RealmId;
+use crate::domain::risk::entities::RiskAssessment;
 use crate::domain::user::entities::User;
 use crate::domain::{
-    authentication::entities::{GrantType, JwtToken},
+    auth_flow::entities::{AuthFlowStage, Authenticator},
+    authentication::entities::{AuthSession, GrantType, JwtToken},
     user::entities::RequiredAction,
 };
 
@@ -93,6 +95,38 @@ pub struct GenerateTokensForUserInput {
     pub client_id: Option<Uuid>,
 }
 
+/// A sign-in that passed its first authenticator outside of the credentials
+/// endpoint, like a passkey or an identity provider.
+#[derive(Debug, Clone)]
+pub struct CheckSignInInput {
+    pub auth_session: AuthSession,
+    pub user_id: Uuid,
+    /// The authenticator the user signed in with.
+    pub authenticator: Authenticator,
+    pub base_url: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    /// Token of the browser's trusted device cookie, which skips the second
+    /// factor.
+    pub trusted_device_token: Option<String>,
+}
+
+/// What is left of a sign-in that passed the checks.
+#[derive(Debug, Clone, Default)]
+pub struct SignInCheck {
+    /// The stage to pass next. Any later one is stored on the session.
+    pub next: Option<AuthFlowStage>,
+    /// Token to come back with for the next stage.
+    pub temporary_token: Option<String>,
+}
+
+impl SignInCheck {
+    /// Whether the sign-in can complete right away.
+    pub fn is_complete(&self) -> bool {
+        self.next.is_none()
+    }
+}
+
 impl CreateAuthSessionRequest {
     pub fn new(realm_id: Uuid, client_id: Uuid, redirect_uri: String) -> Self {
         Self {
@@ -143,6 +177,8 @@ pub struct GenerateTokenInput {
     pub access_token_lifetime: i64,
     pub refresh_token_lifetime: i64,
     pub id_token_lifetime: i64,
+    /// Score of the sign-in the tokens are issued for, if it was scored.
+    pub risk: Option<RiskAssessment>,
 }
 
 pub struct GetUserInfoInput {
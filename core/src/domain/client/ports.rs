@@ -19,7 +19,7 @@ mod mocks {
     use crate::domain::common::entities::app_errors::CoreError;
     use ferriskey_domain::client::{
         entities::{Client, redirect_uri::RedirectUri},
-        value_objects::{CreateClientRequest, UpdateClientRequest},
+        value_objects::{ClientFilter, CreateClientRequest, UpdateClientRequest},
     };
     use ferriskey_domain::realm::RealmId;
 
@@ -42,6 +42,16 @@ mod mocks {
                 &self,
                 realm_id: RealmId,
             ) -> impl Future<Output = Result<Vec<Client>, CoreError>> + Send;
+            fn list_clients(
+                &self,
+                realm_id: RealmId,
+                filter: ClientFilter,
+            ) -> impl Future<Output = Result<Vec<Client>, CoreError>> + Send;
+            fn count_clients(
+                &self,
+                realm_id: RealmId,
+                filter: ClientFilter,
+            ) -> impl Future<Output = Result<u64, CoreError>> + Send;
 
             fn update_client(
                 &self,
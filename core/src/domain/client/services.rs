@@ -17,7 +17,7 @@ use crate::domain::{
         value_objects::CreateClientRequest,
     },
     common::{
-        entities::app_errors::CoreError,
+        entities::{app_errors::CoreError, listing::Page},
         generate_random_string,
         policies::{FerriskeyPolicy, ensure_policy},
     },
@@ -526,7 +526,36 @@ where
         &self,
         identity: Identity,
         input: GetClientsInput,
-    ) -> Result<Vec<Client>, CoreError> {
+    ) -> Result<Page<Client>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy.can_view_client(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let limit = input.filter.page.limit();
+        let sort = input.filter.sort;
+        let clients = self
+            .client_repository
+            .list_clients(realm.id, input.filter)
+            .await?;
+
+        Ok(Page::from_overfetch(clients, limit, |client| {
+            sort.cursor(client)
+        }))
+    }
+
+    async fn count_clients(
+        &self,
+        identity: Identity,
+        input: GetClientsInput,
+    ) -> Result<u64, CoreError> {
         let realm = self
             .realm_repository
             .get_by_name(&input.realm_name)
@@ -534,16 +563,14 @@ where
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
-        let realm_id = realm.id;
         ensure_policy(
             self.policy.can_view_client(&identity, &realm).await,
             "insufficient permissions",
         )?;
 
         self.client_repository
-            .get_by_realm_id(realm_id)
+            .count_clients(realm.id, input.filter)
             .await
-            .map_err(|_| CoreError::NotFound)
     }
 
     async fn get_redirect_uris(
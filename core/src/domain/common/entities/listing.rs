@@ -0,0 +1 @@
+pub use ferriskey_domain::common::listing::*;
@@ -2,6 +2,7 @@ use crate::domain::realm::entities::RealmId;
 use uuid::Uuid;
 
 pub mod app_errors;
+pub mod listing;
 
 #[derive(Debug, Clone)]
 pub struct StartupConfig {
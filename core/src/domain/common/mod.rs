@@ -1,12 +1,19 @@
+use std::path::PathBuf;
+
 use chrono::{DateTime, Utc};
 use rand::{Rng, distributions::Alphanumeric};
 use uuid::{NoContext, Timestamp, Uuid};
 
+use crate::domain::{
+    housekeeping::value_objects::HousekeepingConfig, seawatch::value_objects::SeawatchConfig,
+};
+
 pub mod email;
 pub mod entities;
 pub mod policies;
 pub mod ports;
 pub mod services;
+pub mod sms;
 
 pub struct AppConfig {
     pub database_url: String,
@@ -15,6 +22,104 @@ pub struct AppConfig {
 #[derive(Clone, Debug)]
 pub struct FerriskeyConfig {
     pub database: DatabaseConfig,
+    pub seawatch: SeawatchConfig,
+    pub housekeeping: HousekeepingConfig,
+    pub mail: MailConfig,
+    pub secrets: SecretsConfig,
+    pub sms: SmsConfig,
+    pub risk: RiskConfig,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MailConfig {
+    /// Directory realms using the maildir provider write their emails
+    /// under, one maildir per realm id. Unset disables that provider.
+    pub maildir_path: Option<PathBuf>,
+}
+
+#[derive(Clone)]
+pub struct SecretsConfig {
+    /// Base64 master key sealing secrets at rest. Without it, nor a
+    /// `master_key_file`, secrets are stored in clear.
+    pub master_key: Option<String>,
+    pub master_key_file: Option<PathBuf>,
+    /// Recorded next to every secret sealed with the master key.
+    pub master_key_id: String,
+    /// `id:base64` keys only used to decrypt, while secrets sealed with them
+    /// are rotated to the current key.
+    pub previous_master_keys: Vec<String>,
+}
+
+impl Default for SecretsConfig {
+    fn default() -> Self {
+        Self {
+            master_key: None,
+            master_key_file: None,
+            master_key_id: "primary".to_string(),
+            previous_master_keys: Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Debug for SecretsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretsConfig")
+            .field("master_key", &self.master_key.as_ref().map(|_| "********"))
+            .field("master_key_file", &self.master_key_file)
+            .field("master_key_id", &self.master_key_id)
+            .field("previous_master_keys", &self.previous_master_keys.len())
+            .finish()
+    }
+}
+
+/// Generic HTTP gateway SMS codes are posted to.
+#[derive(Clone)]
+pub struct SmsConfig {
+    /// Without it, messages are logged instead of sent.
+    pub gateway_url: Option<String>,
+    /// Sent as is in the `Authorization` header.
+    pub gateway_authorization: Option<String>,
+    /// JSON body posted to the gateway, see `render_sms_body`.
+    pub body_template: String,
+    pub sender: Option<String>,
+}
+
+pub const DEFAULT_SMS_BODY_TEMPLATE: &str = r#"{"from": {{from}}, "to": {{to}}, "body": {{body}}}"#;
+
+impl Default for SmsConfig {
+    fn default() -> Self {
+        Self {
+            gateway_url: None,
+            gateway_authorization: None,
+            body_template: DEFAULT_SMS_BODY_TEMPLATE.to_string(),
+            sender: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for SmsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmsConfig")
+            .field("gateway_url", &self.gateway_url)
+            .field(
+                "gateway_authorization",
+                &self.gateway_authorization.as_ref().map(|_| "********"),
+            )
+            .field("body_template", &self.body_template)
+            .field("sender", &self.sender)
+            .finish()
+    }
+}
+
+/// Local IP databases the risk engine scores sign-ins with. Without them,
+/// sign-ins are only scored on devices and failures.
+#[derive(Clone, Debug, Default)]
+pub struct RiskConfig {
+    /// DB-IP lite country or city CSV.
+    pub geoip_database: Option<PathBuf>,
+    /// Files of addresses or CIDR ranges, one per line, such as TOR exit
+    /// node lists.
+    pub ip_blocklists: Vec<PathBuf>,
 }
 
 #[derive(Clone, Debug)]
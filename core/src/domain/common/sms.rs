@@ -0,0 +1,7 @@
+use crate::domain::common::entities::app_errors::CoreError;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait SmsSender: Send + Sync {
+    /// Sends `body` to `to`, an E.164 phone number.
+    fn send_sms(&self, to: &str, body: &str) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
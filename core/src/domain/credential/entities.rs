@@ -6,6 +6,11 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 use webauthn_rs::prelude::{Credential as WebAuthnCredential, CredentialID, Passkey};
 
+use crate::domain::{
+    one_time_code::entities::{OneTimeCodeChannel, mask_destination},
+    otp::entities::{OtpAlgorithm, OtpCredentialData, OtpType},
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Credential {
     pub id: Uuid,
@@ -27,6 +32,8 @@ pub enum CredentialType {
     Otp,
     RecoveryCode,
     WebAuthnPublicKeyCredential,
+    EmailOtp,
+    SmsOtp,
 }
 
 impl Display for CredentialType {
@@ -36,6 +43,8 @@ impl Display for CredentialType {
             CredentialType::Otp => "otp",
             CredentialType::RecoveryCode => "recovery-code",
             CredentialType::WebAuthnPublicKeyCredential => "webauthn-public-key-credential",
+            CredentialType::EmailOtp => "email-otp",
+            CredentialType::SmsOtp => "sms-otp",
         };
         write!(f, "{}", s)
     }
@@ -48,6 +57,8 @@ impl From<String> for CredentialType {
             "otp" => CredentialType::Otp,
             "recovery-code" => CredentialType::RecoveryCode,
             "webauthn-public-key-credential" => CredentialType::WebAuthnPublicKeyCredential,
+            "email-otp" => CredentialType::EmailOtp,
+            "sms-otp" => CredentialType::SmsOtp,
             _ => CredentialType::Password, // default to Password if unknown
         }
     }
@@ -60,8 +71,19 @@ impl CredentialType {
             CredentialType::Otp => "otp",
             CredentialType::RecoveryCode => "recovery-code",
             CredentialType::WebAuthnPublicKeyCredential => "webauthn-public-key-credential",
+            CredentialType::EmailOtp => "email-otp",
+            CredentialType::SmsOtp => "sms-otp",
         }
     }
+
+    /// Whether signing in with this credential goes through a code
+    /// challenge after the password.
+    pub fn is_one_time_code(&self) -> bool {
+        matches!(
+            self,
+            CredentialType::Otp | CredentialType::EmailOtp | CredentialType::SmsOtp
+        )
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd, ToSchema)]
@@ -122,6 +144,13 @@ pub enum CredentialData {
         provider_id: String,
         provider_type: String,
     },
+    /// Email or SMS second factor. The address of email codes is the
+    /// user's, only SMS credentials keep their verified number.
+    OneTimeCode {
+        channel: OneTimeCodeChannel,
+        destination: Option<String>,
+    },
+    Otp(OtpCredentialData),
 }
 
 impl CredentialData {
@@ -159,6 +188,17 @@ pub enum CredentialDataOverview {
         provider_id: String,
         provider_type: String,
     },
+    Otp {
+        otp_type: OtpType,
+        algorithm: OtpAlgorithm,
+        digits: u32,
+        period: u64,
+    },
+    OneTimeCode {
+        channel: OneTimeCodeChannel,
+        /// Masked phone number of SMS credentials.
+        destination: Option<String>,
+    },
 }
 
 impl From<CredentialData> for CredentialDataOverview {
@@ -179,6 +219,19 @@ impl From<CredentialData> for CredentialDataOverview {
                 provider_id,
                 provider_type,
             },
+            CredentialData::Otp(data) => CredentialDataOverview::Otp {
+                otp_type: data.sub_type,
+                algorithm: data.algorithm,
+                digits: data.digits,
+                period: data.period,
+            },
+            CredentialData::OneTimeCode {
+                channel,
+                destination,
+            } => CredentialDataOverview::OneTimeCode {
+                channel,
+                destination: destination.map(|destination| mask_destination(channel, &destination)),
+            },
         }
     }
 }
@@ -234,5 +287,6 @@ pub struct GetCredentialsInput {
 
 pub struct DeleteCredentialInput {
     pub realm_name: String,
+    pub user_id: Uuid,
     pub credential_id: Uuid,
 }
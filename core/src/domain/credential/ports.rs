@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use webauthn_rs::prelude::{AuthenticationResult, Passkey};
 
@@ -8,6 +9,7 @@ use crate::domain::{
         Credential, CredentialError, CredentialOverview, DeleteCredentialInput, GetCredentialsInput,
     },
     crypto::HashResult,
+    otp::entities::OtpCredentialData,
 };
 
 pub trait CredentialService: Send + Sync {
@@ -84,6 +86,16 @@ pub trait CredentialRepository: Send + Sync {
         user_id: Uuid,
     ) -> impl Future<Output = Result<Option<Credential>, CredentialError>> + Send;
 
+    /// Stores the moving factor of an OTP device after one of its codes was
+    /// accepted. Returns `false`, leaving the credential untouched, when it
+    /// changed since it was read at `read_at`.
+    fn update_otp_credential_data(
+        &self,
+        credential_id: Uuid,
+        read_at: DateTime<Utc>,
+        data: OtpCredentialData,
+    ) -> impl Future<Output = Result<bool, CredentialError>> + Send;
+
     /// Sometimes webauthn credential needs an internal counter updated after auth attempt
     fn update_webauthn_credential(
         &self,
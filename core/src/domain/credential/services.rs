@@ -8,56 +8,65 @@ use crate::domain::{
         policies::{FerriskeyPolicy, ensure_policy},
     },
     credential::{
-        entities::{CredentialOverview, DeleteCredentialInput, GetCredentialsInput},
+        entities::{
+            CredentialOverview, CredentialType, DeleteCredentialInput, GetCredentialsInput,
+        },
         ports::{CredentialRepository, CredentialService},
     },
     realm::ports::RealmRepository,
+    security_notification::{entities::SecurityNotification, ports::SecurityNotifier},
     user::ports::{UserPolicy, UserRepository, UserRoleRepository},
 };
 
 #[derive(Clone, Debug)]
-pub struct CredentialServiceImpl<R, U, C, UR, CR>
+pub struct CredentialServiceImpl<R, U, C, UR, CR, N>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     CR: CredentialRepository,
+    N: SecurityNotifier,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) credential_repository: Arc<CR>,
+    pub(crate) security_notifier: Arc<N>,
 
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, CR> CredentialServiceImpl<R, U, C, UR, CR>
+impl<R, U, C, UR, CR, N> CredentialServiceImpl<R, U, C, UR, CR, N>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     CR: CredentialRepository,
+    N: SecurityNotifier,
 {
     pub fn new(
         realm_repository: Arc<R>,
         credential_repository: Arc<CR>,
+        security_notifier: Arc<N>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
             realm_repository,
             credential_repository,
+            security_notifier,
             policy,
         }
     }
 }
 
-impl<R, U, C, UR, CR> CredentialService for CredentialServiceImpl<R, U, C, UR, CR>
+impl<R, U, C, UR, CR, N> CredentialService for CredentialServiceImpl<R, U, C, UR, CR, N>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     CR: CredentialRepository,
+    N: SecurityNotifier,
 {
     async fn get_credentials(
         &self,
@@ -105,6 +114,14 @@ where
             "insufficient permissions",
         )?;
 
+        let credential = self
+            .credential_repository
+            .get_credentials_by_user_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?
+            .into_iter()
+            .find(|credential| credential.id == input.credential_id);
+
         self.credential_repository
             .delete_by_id(input.credential_id)
             .await
@@ -112,6 +129,23 @@ where
 
         // @TODO: implement webhook notifier
 
+        let method = match credential.map(|credential| credential.credential_type) {
+            Some(CredentialType::Otp) => Some("otp"),
+            Some(CredentialType::WebAuthnPublicKeyCredential) => Some("webauthn"),
+            _ => None,
+        };
+        if let Some(method) = method {
+            self.security_notifier
+                .notify(
+                    realm.id,
+                    input.user_id,
+                    SecurityNotification::MfaRemoved {
+                        method: method.to_string(),
+                    },
+                )
+                .await;
+        }
+
         Ok(())
     }
 }
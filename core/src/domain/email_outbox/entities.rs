@@ -0,0 +1,145 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{common::generate_uuid_v7, realm::entities::RealmId};
+
+/// Deliveries attempted before an email is given up on as failed.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 8;
+
+const FIRST_RETRY_DELAY: Duration = Duration::from_secs(30);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(3600);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutboxEmailStatus {
+    /// Waiting for its first delivery or for a retry.
+    Queued,
+    Sent,
+    /// Every delivery attempt failed.
+    Failed,
+    /// The relay permanently rejected the message.
+    Bounced,
+}
+
+impl fmt::Display for OutboxEmailStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutboxEmailStatus::Queued => write!(f, "queued"),
+            OutboxEmailStatus::Sent => write!(f, "sent"),
+            OutboxEmailStatus::Failed => write!(f, "failed"),
+            OutboxEmailStatus::Bounced => write!(f, "bounced"),
+        }
+    }
+}
+
+impl FromStr for OutboxEmailStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "queued" => Ok(OutboxEmailStatus::Queued),
+            "sent" => Ok(OutboxEmailStatus::Sent),
+            "failed" => Ok(OutboxEmailStatus::Failed),
+            "bounced" => Ok(OutboxEmailStatus::Bounced),
+            _ => Err(format!("Unknown outbox email status: {s}")),
+        }
+    }
+}
+
+/// An email written by a service and delivered later by the outbox worker.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OutboxEmail {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub recipient: String,
+    pub subject: String,
+    /// Bodies carry sign-in and reset links, so they are never exposed.
+    #[serde(skip_serializing)]
+    pub body: String,
+    #[serde(skip_serializing)]
+    pub html_body: Option<String>,
+    pub status: OutboxEmailStatus,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OutboxEmail {
+    pub fn new(
+        realm_id: RealmId,
+        recipient: String,
+        subject: String,
+        body: String,
+        html_body: Option<String>,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: generate_uuid_v7(),
+            realm_id,
+            recipient,
+            subject,
+            body,
+            html_body,
+            status: OutboxEmailStatus::Queued,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            sent_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Whether the worker may try again after the current attempt failed.
+    pub fn can_retry(&self) -> bool {
+        self.attempts < MAX_DELIVERY_ATTEMPTS
+    }
+}
+
+/// Delay before retrying an email that failed `attempts` times: doubling
+/// from 30 seconds, capped at one hour.
+pub fn retry_delay(attempts: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+
+    FIRST_RETRY_DELAY
+        .saturating_mul(factor)
+        .min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_from_thirty_seconds() {
+        assert_eq!(retry_delay(1), Duration::from_secs(30));
+        assert_eq!(retry_delay(2), Duration::from_secs(60));
+        assert_eq!(retry_delay(4), Duration::from_secs(240));
+    }
+
+    #[test]
+    fn retry_delay_is_capped_at_one_hour() {
+        assert_eq!(retry_delay(8), Duration::from_secs(3600));
+        assert_eq!(retry_delay(u32::MAX), Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn status_round_trips_through_strings() {
+        for status in [
+            OutboxEmailStatus::Queued,
+            OutboxEmailStatus::Sent,
+            OutboxEmailStatus::Failed,
+            OutboxEmailStatus::Bounced,
+        ] {
+            assert_eq!(status.to_string().parse::<OutboxEmailStatus>(), Ok(status));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    realm::entities::{Realm, RealmId},
+};
+
+use super::{
+    entities::{OutboxEmail, OutboxEmailStatus},
+    value_objects::{GetOutboxEmailInput, ListOutboxEmailsInput, ResendOutboxEmailInput},
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait OutboxEmailRepository: Send + Sync {
+    fn enqueue(
+        &self,
+        email: OutboxEmail,
+    ) -> impl Future<Output = Result<OutboxEmail, CoreError>> + Send;
+    fn get_by_id(
+        &self,
+        realm_id: RealmId,
+        email_id: Uuid,
+    ) -> impl Future<Output = Result<Option<OutboxEmail>, CoreError>> + Send;
+    /// Latest emails of the realm first, at most `limit`.
+    fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+        status: Option<OutboxEmailStatus>,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<OutboxEmail>, CoreError>> + Send;
+    /// Takes up to `limit` queued emails due at `now`, counting the attempt
+    /// and pushing their next attempt to `lease_until` so that other
+    /// replicas skip them while they are being delivered.
+    fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<OutboxEmail>, CoreError>> + Send;
+    fn mark_sent(
+        &self,
+        email_id: Uuid,
+        sent_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn schedule_retry(
+        &self,
+        email_id: Uuid,
+        error: String,
+        next_attempt_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Moves the email to a final `Failed` or `Bounced` status.
+    fn mark_undeliverable(
+        &self,
+        email_id: Uuid,
+        status: OutboxEmailStatus,
+        error: String,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Queues the email again with a fresh attempt budget.
+    fn requeue(
+        &self,
+        realm_id: RealmId,
+        email_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Option<OutboxEmail>, CoreError>> + Send;
+}
+
+pub trait EmailOutboxService: Send + Sync {
+    fn list_outbox_emails(
+        &self,
+        identity: Identity,
+        input: ListOutboxEmailsInput,
+    ) -> impl Future<Output = Result<Vec<OutboxEmail>, CoreError>> + Send;
+    fn get_outbox_email(
+        &self,
+        identity: Identity,
+        input: GetOutboxEmailInput,
+    ) -> impl Future<Output = Result<OutboxEmail, CoreError>> + Send;
+    fn resend_outbox_email(
+        &self,
+        identity: Identity,
+        input: ResendOutboxEmailInput,
+    ) -> impl Future<Output = Result<OutboxEmail, CoreError>> + Send;
+    /// Delivers the emails due at `now` and returns how many were attempted.
+    fn deliver_due_emails(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<usize, CoreError>> + Send;
+}
+
+pub trait EmailOutboxPolicy: Send + Sync {
+    fn can_view_email_outbox(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_email_outbox(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
@@ -0,0 +1,514 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use tokio::time::timeout;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        email::EmailPort,
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    email_outbox::{
+        entities::{OutboxEmail, OutboxEmailStatus, retry_delay},
+        ports::{EmailOutboxPolicy, EmailOutboxService, OutboxEmailRepository},
+        value_objects::{GetOutboxEmailInput, ListOutboxEmailsInput, ResendOutboxEmailInput},
+    },
+    realm::{
+        entities::{Realm, SmtpConfig},
+        ports::{RealmRepository, SmtpConfigRepository},
+    },
+    seawatch::{ActorType, EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// Emails a single worker run delivers.
+const DELIVERY_BATCH_SIZE: u64 = 20;
+
+/// Longest a single delivery may take before it counts as failed.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// How long claimed emails are hidden from other replicas; longer than a
+/// whole batch of timed out deliveries.
+const DELIVERY_LEASE: TimeDelta = TimeDelta::minutes(10);
+
+/// Emails returned by the admin listing.
+const LIST_LIMIT: u64 = 200;
+
+/// Queues emails in the outbox instead of sending them, so that a request
+/// writing an email does not depend on the SMTP relay being reachable.
+#[derive(Clone, Debug)]
+pub struct OutboxEmailPort<OR>
+where
+    OR: OutboxEmailRepository,
+{
+    pub(crate) outbox_repository: Arc<OR>,
+}
+
+impl<OR> OutboxEmailPort<OR>
+where
+    OR: OutboxEmailRepository,
+{
+    pub fn new(outbox_repository: Arc<OR>) -> Self {
+        Self { outbox_repository }
+    }
+}
+
+impl<OR> EmailPort for OutboxEmailPort<OR>
+where
+    OR: OutboxEmailRepository,
+{
+    async fn send_email(
+        &self,
+        config: &SmtpConfig,
+        to_email: &str,
+        subject: &str,
+        body: &str,
+        html_body: Option<String>,
+    ) -> Result<(), CoreError> {
+        self.outbox_repository
+            .enqueue(OutboxEmail::new(
+                config.realm_id.into(),
+                to_email.to_string(),
+                subject.to_string(),
+                body.to_string(),
+                html_body,
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EmailOutboxServiceImpl<R, U, C, UR, OR, SC, ES, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OR: OutboxEmailRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    SE: SecurityEventRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) outbox_repository: Arc<OR>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    /// Transport the worker delivers through; never the outbox port itself.
+    pub(crate) email_port: Arc<ES>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, OR, SC, ES, SE> EmailOutboxServiceImpl<R, U, C, UR, OR, SC, ES, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OR: OutboxEmailRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    SE: SecurityEventRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        outbox_repository: Arc<OR>,
+        smtp_config_repository: Arc<SC>,
+        email_port: Arc<ES>,
+        security_event_repository: Arc<SE>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            outbox_repository,
+            smtp_config_repository,
+            email_port,
+            security_event_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn send(&self, email: &OutboxEmail) -> Result<(), CoreError> {
+        let config = self
+            .smtp_config_repository
+            .get_by_realm_id(email.realm_id)
+            .await?
+            .ok_or_else(|| {
+                CoreError::Configuration("SMTP is not configured for the realm".to_string())
+            })?;
+
+        timeout(
+            DELIVERY_TIMEOUT,
+            self.email_port.send_email(
+                &config,
+                &email.recipient,
+                &email.subject,
+                &email.body,
+                email.html_body.clone(),
+            ),
+        )
+        .await
+        .unwrap_or_else(|_| {
+            Err(CoreError::ServiceUnavailable(
+                "Email delivery timed out".to_string(),
+            ))
+        })
+    }
+
+    /// Attempts one delivery and records its outcome on the email.
+    async fn deliver(&self, email: OutboxEmail, now: DateTime<Utc>) -> Result<(), CoreError> {
+        let (status, error) = match self.send(&email).await {
+            Ok(()) => return self.outbox_repository.mark_sent(email.id, Utc::now()).await,
+            Err(CoreError::EmailRejected(reason)) => (OutboxEmailStatus::Bounced, reason),
+            Err(e) if email.can_retry() => {
+                let delay =
+                    TimeDelta::from_std(retry_delay(email.attempts)).unwrap_or(TimeDelta::hours(1));
+
+                return self
+                    .outbox_repository
+                    .schedule_retry(email.id, e.to_string(), now + delay)
+                    .await;
+            }
+            Err(e) => (OutboxEmailStatus::Failed, e.to_string()),
+        };
+
+        warn!(email_id = %email.id, "Giving up on email delivery: {}", error);
+        self.outbox_repository
+            .mark_undeliverable(email.id, status, error.clone())
+            .await?;
+
+        let _ = self
+            .security_event_repository
+            .store_event(undelivered_event(&email, status, error))
+            .await
+            .inspect_err(|e| warn!("Failed to log email not sent event: {}", e));
+
+        Ok(())
+    }
+}
+
+fn undelivered_event(
+    email: &OutboxEmail,
+    status: OutboxEmailStatus,
+    error: String,
+) -> SecurityEvent {
+    let error_code = match status {
+        OutboxEmailStatus::Bounced => "SMTP_REJECTED",
+        _ => "SMTP_RETRIES_EXHAUSTED",
+    };
+
+    SecurityEvent {
+        actor_id: None,
+        actor_type: Some(ActorType::System),
+        ..SecurityEvent::new(
+            email.realm_id,
+            SecurityEventType::EmailNotSent,
+            EventStatus::Failure,
+            Uuid::nil(),
+        )
+    }
+    .with_details(serde_json::json!({
+        "reason": error,
+        "error_code": error_code,
+        "email_id": email.id.to_string(),
+        "attempts": email.attempts,
+    }))
+}
+
+impl<R, U, C, UR, OR, SC, ES, SE> EmailOutboxService
+    for EmailOutboxServiceImpl<R, U, C, UR, OR, SC, ES, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OR: OutboxEmailRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    SE: SecurityEventRepository,
+{
+    async fn list_outbox_emails(
+        &self,
+        identity: Identity,
+        input: ListOutboxEmailsInput,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_email_outbox(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.outbox_repository
+            .list_by_realm(realm.id, input.status, LIST_LIMIT)
+            .await
+    }
+
+    async fn get_outbox_email(
+        &self,
+        identity: Identity,
+        input: GetOutboxEmailInput,
+    ) -> Result<OutboxEmail, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_email_outbox(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.outbox_repository
+            .get_by_id(realm.id, input.email_id)
+            .await?
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn resend_outbox_email(
+        &self,
+        identity: Identity,
+        input: ResendOutboxEmailInput,
+    ) -> Result<OutboxEmail, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_email_outbox(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.outbox_repository
+            .requeue(realm.id, input.email_id, Utc::now())
+            .await?
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn deliver_due_emails(&self, now: DateTime<Utc>) -> Result<usize, CoreError> {
+        let emails = self
+            .outbox_repository
+            .claim_due(now, now + DELIVERY_LEASE, DELIVERY_BATCH_SIZE)
+            .await?;
+        let claimed = emails.len();
+
+        // A failing email must not hold back the rest of the batch.
+        for email in emails {
+            let email_id = email.id;
+            if let Err(e) = self.deliver(email, now).await {
+                warn!(%email_id, "Failed to record email delivery: {}", e);
+            }
+        }
+
+        Ok(claimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        client::ports::MockClientRepository,
+        common::email::MockEmailPort,
+        email_outbox::{entities::MAX_DELIVERY_ATTEMPTS, ports::MockOutboxEmailRepository},
+        realm::{
+            entities::{EmailProvider, SmtpEncryption},
+            ports::{MockRealmRepository, MockSmtpConfigRepository},
+        },
+        seawatch::ports::MockSecurityEventRepository,
+        user::ports::{MockUserRepository, MockUserRoleRepository},
+    };
+    use mockall::predicate::*;
+
+    type TestService = EmailOutboxServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockOutboxEmailRepository,
+        MockSmtpConfigRepository,
+        MockEmailPort,
+        MockSecurityEventRepository,
+    >;
+
+    fn smtp_config(realm_id: Uuid) -> SmtpConfig {
+        SmtpConfig {
+            id: Uuid::new_v4(),
+            realm_id,
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            username: "user".to_string(),
+            password: "password".to_string(),
+            from_email: "noreply@example.com".to_string(),
+            from_name: "Example".to_string(),
+            encryption: SmtpEncryption::StartTls,
+            provider: EmailProvider::Smtp,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn claimed_email(attempts: u32) -> OutboxEmail {
+        OutboxEmail {
+            attempts,
+            ..OutboxEmail::new(
+                Uuid::new_v4().into(),
+                "alice@example.com".to_string(),
+                "Your magic link".to_string(),
+                "body".to_string(),
+                None,
+            )
+        }
+    }
+
+    fn service(
+        outbox: MockOutboxEmailRepository,
+        email_port: MockEmailPort,
+        security_events: MockSecurityEventRepository,
+    ) -> TestService {
+        let mut smtp_configs = MockSmtpConfigRepository::new();
+        smtp_configs.expect_get_by_realm_id().returning(|realm_id| {
+            let config = smtp_config(realm_id.into());
+            Box::pin(async move { Ok(Some(config)) })
+        });
+
+        EmailOutboxServiceImpl::new(
+            Arc::new(MockRealmRepository::new()),
+            Arc::new(outbox),
+            Arc::new(smtp_configs),
+            Arc::new(email_port),
+            Arc::new(security_events),
+            Arc::new(FerriskeyPolicy::new(
+                Arc::new(MockUserRepository::new()),
+                Arc::new(MockClientRepository::new()),
+                Arc::new(MockUserRoleRepository::new()),
+            )),
+        )
+    }
+
+    fn outbox_claiming(email: OutboxEmail) -> MockOutboxEmailRepository {
+        let mut outbox = MockOutboxEmailRepository::new();
+        outbox
+            .expect_claim_due()
+            .times(1)
+            .returning(move |_, _, _| {
+                let email = email.clone();
+                Box::pin(async move { Ok(vec![email]) })
+            });
+        outbox
+    }
+
+    #[tokio::test]
+    async fn deliver_due_emails_marks_delivered_emails_sent() {
+        let email = claimed_email(1);
+        let mut outbox = outbox_claiming(email.clone());
+        outbox
+            .expect_mark_sent()
+            .with(eq(email.id), always())
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let mut email_port = MockEmailPort::new();
+        email_port
+            .expect_send_email()
+            .times(1)
+            .returning(|_, _, _, _, _| Box::pin(async { Ok(()) }));
+
+        let delivered = service(outbox, email_port, MockSecurityEventRepository::new())
+            .deliver_due_emails(Utc::now())
+            .await
+            .expect("delivery should succeed");
+
+        assert_eq!(delivered, 1);
+    }
+
+    #[tokio::test]
+    async fn deliver_due_emails_retries_transient_failures_with_backoff() {
+        let now = Utc::now();
+        let email = claimed_email(2);
+        let mut outbox = outbox_claiming(email.clone());
+        outbox
+            .expect_schedule_retry()
+            .with(eq(email.id), always(), eq(now + TimeDelta::seconds(60)))
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let mut email_port = MockEmailPort::new();
+        email_port.expect_send_email().returning(|_, _, _, _, _| {
+            Box::pin(async { Err(CoreError::External("connection reset".to_string())) })
+        });
+
+        service(outbox, email_port, MockSecurityEventRepository::new())
+            .deliver_due_emails(now)
+            .await
+            .expect("delivery should succeed");
+    }
+
+    #[tokio::test]
+    async fn deliver_due_emails_fails_emails_out_of_attempts() {
+        let email = claimed_email(MAX_DELIVERY_ATTEMPTS);
+        let mut outbox = outbox_claiming(email.clone());
+        outbox
+            .expect_mark_undeliverable()
+            .with(eq(email.id), eq(OutboxEmailStatus::Failed), always())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let mut email_port = MockEmailPort::new();
+        email_port.expect_send_email().returning(|_, _, _, _, _| {
+            Box::pin(async { Err(CoreError::External("connection reset".to_string())) })
+        });
+
+        let mut security_events = MockSecurityEventRepository::new();
+        security_events
+            .expect_store_event()
+            .withf(|event| event.event_type == SecurityEventType::EmailNotSent)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        service(outbox, email_port, security_events)
+            .deliver_due_emails(Utc::now())
+            .await
+            .expect("delivery should succeed");
+    }
+
+    #[tokio::test]
+    async fn deliver_due_emails_bounces_rejected_emails_without_retrying() {
+        let email = claimed_email(1);
+        let mut outbox = outbox_claiming(email.clone());
+        outbox
+            .expect_mark_undeliverable()
+            .with(eq(email.id), eq(OutboxEmailStatus::Bounced), always())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        outbox.expect_schedule_retry().never();
+
+        let mut email_port = MockEmailPort::new();
+        email_port.expect_send_email().returning(|_, _, _, _, _| {
+            Box::pin(async {
+                Err(CoreError::EmailRejected(
+                    "550 mailbox unavailable".to_string(),
+                ))
+            })
+        });
+
+        let mut security_events = MockSecurityEventRepository::new();
+        security_events
+            .expect_store_event()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        service(outbox, email_port, security_events)
+            .deliver_due_emails(Utc::now())
+            .await
+            .expect("delivery should succeed");
+    }
+}
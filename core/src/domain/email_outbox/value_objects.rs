@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use super::entities::OutboxEmailStatus;
+
+#[derive(Debug, Clone)]
+pub struct ListOutboxEmailsInput {
+    pub realm_name: String,
+    pub status: Option<OutboxEmailStatus>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GetOutboxEmailInput {
+    pub realm_name: String,
+    pub email_id: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResendOutboxEmailInput {
+    pub realm_name: String,
+    pub email_id: Uuid,
+}
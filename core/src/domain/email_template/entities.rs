@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::{
+    common::entities::app_errors::CoreError, localization::entities::LocalizedMessages,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -14,6 +16,14 @@ pub enum EmailType {
     ResetPassword,
     MagicLink,
     EmailVerification,
+    PasswordChanged,
+    NewSignIn,
+    MfaAdded,
+    MfaRemoved,
+    RecoveryCodesRegenerated,
+    AccountLocked,
+    EmailChanged,
+    SuspiciousSignIn,
 }
 
 impl Display for EmailType {
@@ -22,6 +32,14 @@ impl Display for EmailType {
             EmailType::ResetPassword => write!(f, "reset_password"),
             EmailType::MagicLink => write!(f, "magic_link"),
             EmailType::EmailVerification => write!(f, "email_verification"),
+            EmailType::PasswordChanged => write!(f, "password_changed"),
+            EmailType::NewSignIn => write!(f, "new_sign_in"),
+            EmailType::MfaAdded => write!(f, "mfa_added"),
+            EmailType::MfaRemoved => write!(f, "mfa_removed"),
+            EmailType::RecoveryCodesRegenerated => write!(f, "recovery_codes_regenerated"),
+            EmailType::AccountLocked => write!(f, "account_locked"),
+            EmailType::EmailChanged => write!(f, "email_changed"),
+            EmailType::SuspiciousSignIn => write!(f, "suspicious_sign_in"),
         }
     }
 }
@@ -34,6 +52,14 @@ impl TryFrom<String> for EmailType {
             "reset_password" => Ok(EmailType::ResetPassword),
             "magic_link" => Ok(EmailType::MagicLink),
             "email_verification" => Ok(EmailType::EmailVerification),
+            "password_changed" => Ok(EmailType::PasswordChanged),
+            "new_sign_in" => Ok(EmailType::NewSignIn),
+            "mfa_added" => Ok(EmailType::MfaAdded),
+            "mfa_removed" => Ok(EmailType::MfaRemoved),
+            "recovery_codes_regenerated" => Ok(EmailType::RecoveryCodesRegenerated),
+            "account_locked" => Ok(EmailType::AccountLocked),
+            "email_changed" => Ok(EmailType::EmailChanged),
+            "suspicious_sign_in" => Ok(EmailType::SuspiciousSignIn),
             _ => Err(CoreError::InvalidEmailTemplateStructure(format!(
                 "unknown email type: {value}"
             ))),
@@ -42,55 +68,234 @@ impl TryFrom<String> for EmailType {
 }
 
 impl EmailType {
+    pub const ALL: [EmailType; 11] = [
+        EmailType::ResetPassword,
+        EmailType::MagicLink,
+        EmailType::EmailVerification,
+        EmailType::PasswordChanged,
+        EmailType::NewSignIn,
+        EmailType::MfaAdded,
+        EmailType::MfaRemoved,
+        EmailType::RecoveryCodesRegenerated,
+        EmailType::AccountLocked,
+        EmailType::EmailChanged,
+        EmailType::SuspiciousSignIn,
+    ];
+
+    /// Security notifications tell the user about a change on their
+    /// account; the other types carry a link the user asked for.
+    pub fn is_security_notification(&self) -> bool {
+        !matches!(
+            self,
+            EmailType::ResetPassword | EmailType::MagicLink | EmailType::EmailVerification
+        )
+    }
+
     pub fn available_variables(&self) -> Vec<TemplateVariable> {
         let mut vars = vec![
-            TemplateVariable {
-                name: "user.first_name".to_string(),
-                description: "User's first name".to_string(),
-            },
-            TemplateVariable {
-                name: "user.last_name".to_string(),
-                description: "User's last name".to_string(),
-            },
-            TemplateVariable {
-                name: "user.email".to_string(),
-                description: "User's email address".to_string(),
-            },
-            TemplateVariable {
-                name: "expiration".to_string(),
-                description: "Expiration time".to_string(),
-            },
+            TemplateVariable::new("user.first_name", "User's first name"),
+            TemplateVariable::new("user.last_name", "User's last name"),
+            TemplateVariable::new("user.email", "User's email address"),
         ];
 
+        if !self.is_security_notification() {
+            vars.push(TemplateVariable::new("expiration", "Expiration time"));
+        }
+
         match self {
             EmailType::ResetPassword => {
-                vars.push(TemplateVariable {
-                    name: "reset_link".to_string(),
-                    description: "Password reset link".to_string(),
-                });
+                vars.push(TemplateVariable::new("reset_link", "Password reset link"));
             }
             EmailType::MagicLink => {
-                vars.push(TemplateVariable {
-                    name: "magic_link".to_string(),
-                    description: "Magic link URL".to_string(),
-                });
+                vars.push(TemplateVariable::new("magic_link", "Magic link URL"));
             }
             EmailType::EmailVerification => {
-                vars.push(TemplateVariable {
-                    name: "verification_link".to_string(),
-                    description: "Email verification link".to_string(),
-                });
+                vars.push(TemplateVariable::new(
+                    "verification_link",
+                    "Email verification link",
+                ));
+            }
+            EmailType::PasswordChanged => {
+                vars.push(TemplateVariable::new(
+                    "changed_at",
+                    "When the password was changed",
+                ));
+            }
+            EmailType::NewSignIn | EmailType::SuspiciousSignIn => {
+                vars.push(TemplateVariable::new(
+                    "signed_in_at",
+                    "When the sign-in happened",
+                ));
+                vars.push(TemplateVariable::new(
+                    "ip_address",
+                    "IP address the sign-in came from",
+                ));
+                vars.push(TemplateVariable::new(
+                    "user_agent",
+                    "Browser or device used to sign in",
+                ));
+
+                if *self == EmailType::SuspiciousSignIn {
+                    vars.push(TemplateVariable::new(
+                        "risk_signals",
+                        "What made the sign-in look unusual",
+                    ));
+                }
+            }
+            EmailType::MfaAdded | EmailType::MfaRemoved => {
+                vars.push(TemplateVariable::new(
+                    "mfa_method",
+                    "Second factor that changed (otp, webauthn)",
+                ));
+                vars.push(TemplateVariable::new(
+                    "changed_at",
+                    "When the second factor changed",
+                ));
+            }
+            EmailType::RecoveryCodesRegenerated => {
+                vars.push(TemplateVariable::new(
+                    "changed_at",
+                    "When the recovery codes were regenerated",
+                ));
+            }
+            EmailType::AccountLocked => {
+                vars.push(TemplateVariable::new(
+                    "changed_at",
+                    "When the account was locked",
+                ));
+            }
+            EmailType::EmailChanged => {
+                vars.push(TemplateVariable::new("old_email", "Previous email address"));
+                vars.push(TemplateVariable::new("new_email", "New email address"));
+                vars.push(TemplateVariable::new(
+                    "changed_at",
+                    "When the email address was changed",
+                ));
+                vars.push(TemplateVariable::new(
+                    "revert_link",
+                    "Link restoring the previous address, set for self-service changes",
+                ));
             }
         }
 
         vars
     }
+
+    /// Built-in subject and text of security notifications, used when the
+    /// realm has no template of this type. Link emails have none: they stay
+    /// opt-in.
+    pub fn default_content(&self) -> Option<(&'static str, &'static str)> {
+        let content = match self {
+            EmailType::PasswordChanged => (
+                "Your password was changed",
+                "The password of your account was changed on {{changed_at}}. If you did not change it, reset your password and contact your administrator.",
+            ),
+            EmailType::NewSignIn => (
+                "New sign-in to your account",
+                "Your account was signed in to on {{signed_in_at}} from {{ip_address}} ({{user_agent}}). If this was not you, change your password right away.",
+            ),
+            EmailType::MfaAdded => (
+                "A second factor was added",
+                "A {{mfa_method}} second factor was added to your account on {{changed_at}}. If you did not add it, contact your administrator.",
+            ),
+            EmailType::MfaRemoved => (
+                "A second factor was removed",
+                "A {{mfa_method}} second factor was removed from your account on {{changed_at}}. If you did not remove it, contact your administrator.",
+            ),
+            EmailType::RecoveryCodesRegenerated => (
+                "Your recovery codes were regenerated",
+                "New recovery codes were generated for your account on {{changed_at}}. Your previous codes no longer work.",
+            ),
+            EmailType::AccountLocked => (
+                "Your account was locked",
+                "Your account was locked on {{changed_at}}. Contact your administrator to unlock it.",
+            ),
+            EmailType::EmailChanged => (
+                "Your email address was changed",
+                "The email address of your account was changed from {{old_email}} to {{new_email}} on {{changed_at}}. If you did not change it, contact your administrator.",
+            ),
+            EmailType::SuspiciousSignIn => (
+                "Unusual sign-in to your account",
+                "Your account was signed in to on {{signed_in_at}} from {{ip_address}} ({{user_agent}}), which looked unusual: {{risk_signals}}. If this was not you, change your password right away.",
+            ),
+            EmailType::ResetPassword | EmailType::MagicLink | EmailType::EmailVerification => {
+                return None;
+            }
+        };
+
+        Some(content)
+    }
+
+    /// Subject used when the realm's message bundles do not override
+    /// `email.<type>.subject`.
+    pub fn default_subject(&self) -> &'static str {
+        match self {
+            EmailType::ResetPassword => "Reset your password",
+            EmailType::MagicLink => "Your magic link",
+            EmailType::EmailVerification => "Verify your email address",
+            _ => self
+                .default_content()
+                .map_or("Notification", |(subject, _)| subject),
+        }
+    }
+
+    /// [`Self::default_content`] laid out as MJML.
+    pub fn default_mjml(&self) -> Option<String> {
+        let (title, text) = self.default_content()?;
+
+        Some(format!(
+            r##"<mjml>
+  <mj-body background-color="#f4f4f5">
+    <mj-section background-color="#ffffff" padding="24px">
+      <mj-column>
+        <mj-text font-size="20px" font-weight="600">{title}</mj-text>
+        <mj-text font-size="14px" line-height="22px">Hello {{{{user.first_name}}}},</mj-text>
+        <mj-text font-size="14px" line-height="22px">{text}</mj-text>
+      </mj-column>
+    </mj-section>
+  </mj-body>
+</mjml>"##
+        ))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct TemplateVariable {
     pub name: String,
     pub description: String,
+    /// Sample value used when previewing a template.
+    pub example: String,
+}
+
+impl TemplateVariable {
+    fn new(name: &str, description: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            example: Self::example_for(name),
+        }
+    }
+
+    fn example_for(name: &str) -> String {
+        let example = match name {
+            "user.first_name" => "Jane",
+            "user.last_name" => "Doe",
+            "user.email" | "new_email" => "jane.doe@example.com",
+            "old_email" => "jane@example.org",
+            "expiration" => "15 minutes",
+            "reset_link" | "magic_link" | "verification_link" | "revert_link" => {
+                "https://auth.example.com/realms/example/link?token=sample"
+            }
+            "changed_at" | "signed_in_at" => "2026-01-15 09:30 UTC",
+            "ip_address" => "203.0.113.42",
+            "user_agent" => "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_0)",
+            "mfa_method" => "otp",
+            "risk_signals" => "new_device, new_country",
+            _ => return format!("sample {name}"),
+        };
+
+        example.to_string()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
@@ -105,6 +310,164 @@ pub struct EmailTemplate {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Immutable snapshot of a template, written on every save. Version numbers
+/// start at 1 and increase by one per template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EmailTemplateVersion {
+    pub id: Uuid,
+    pub template_id: Uuid,
+    pub version: i32,
+    pub name: String,
+    pub structure: serde_json::Value,
+    pub mjml: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StructureChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// One difference between two builder structures.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct StructureChange {
+    /// JSON pointer (RFC 6901) to the value that differs.
+    pub path: String,
+    pub kind: StructureChangeKind,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EmailTemplateVersionDiff {
+    pub template_id: Uuid,
+    pub from_version: i32,
+    pub to_version: i32,
+    pub changes: Vec<StructureChange>,
+}
+
+/// A template rendered with sample or real data, as it would be sent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EmailTemplatePreview {
+    /// Locale the messages were resolved for.
+    pub locale: String,
+    pub subject: String,
+    pub html: String,
+    pub variables: HashMap<String, String>,
+}
+
+/// Differences turning `old` into `new`. Objects are compared key by key
+/// and arrays index by index; anything else that differs is reported as
+/// changed at its own path.
+pub fn diff_structure(old: &serde_json::Value, new: &serde_json::Value) -> Vec<StructureChange> {
+    let mut changes = Vec::new();
+    diff_value(String::new(), old, new, &mut changes);
+    changes
+}
+
+fn diff_value(
+    path: String,
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    changes: &mut Vec<StructureChange>,
+) {
+    use serde_json::Value;
+
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let child = format!("{path}/{}", escape_pointer(key));
+                match new.get(key) {
+                    Some(new_value) => diff_value(child, old_value, new_value, changes),
+                    None => changes.push(StructureChange {
+                        path: child,
+                        kind: StructureChangeKind::Removed,
+                        old_value: Some(old_value.clone()),
+                        new_value: None,
+                    }),
+                }
+            }
+            for (key, new_value) in new.iter().filter(|(key, _)| !old.contains_key(*key)) {
+                changes.push(StructureChange {
+                    path: format!("{path}/{}", escape_pointer(key)),
+                    kind: StructureChangeKind::Added,
+                    old_value: None,
+                    new_value: Some(new_value.clone()),
+                });
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for index in 0..old.len().max(new.len()) {
+                let child = format!("{path}/{index}");
+                match (old.get(index), new.get(index)) {
+                    (Some(old_value), Some(new_value)) => {
+                        diff_value(child, old_value, new_value, changes)
+                    }
+                    (Some(old_value), None) => changes.push(StructureChange {
+                        path: child,
+                        kind: StructureChangeKind::Removed,
+                        old_value: Some(old_value.clone()),
+                        new_value: None,
+                    }),
+                    (None, Some(new_value)) => changes.push(StructureChange {
+                        path: child,
+                        kind: StructureChangeKind::Added,
+                        old_value: None,
+                        new_value: Some(new_value.clone()),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        (old, new) if old != new => changes.push(StructureChange {
+            path,
+            kind: StructureChangeKind::Changed,
+            old_value: Some(old.clone()),
+            new_value: Some(new.clone()),
+        }),
+        _ => {}
+    }
+}
+
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Translation of a template for one locale.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EmailTemplateLocalization {
+    pub id: Uuid,
+    pub template_id: Uuid,
+    pub locale: String,
+    pub structure: serde_json::Value,
+    pub mjml: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// MJML of the first localization along `fallback_chain`, or of the
+/// template itself when none matches.
+pub fn localized_mjml<'a>(
+    template: &'a EmailTemplate,
+    localizations: &'a [EmailTemplateLocalization],
+    fallback_chain: &[String],
+) -> &'a str {
+    fallback_chain
+        .iter()
+        .find_map(|locale| localizations.iter().find(|l| &l.locale == locale))
+        .map_or(template.mjml.as_str(), |l| l.mjml.as_str())
+}
+
+/// Replaces `{{msg.<key>}}` references with HTML-escaped messages. Runs
+/// before [`interpolate_variables`] so that messages may use variables.
+pub fn interpolate_messages(html: &str, messages: &LocalizedMessages) -> String {
+    messages.interpolate(html, html_escape)
+}
+
 /// Interpolates template variables into HTML content.
 /// All variable values are HTML-escaped before substitution.
 pub fn interpolate_variables(html: &str, variables: &HashMap<String, String>) -> String {
@@ -179,6 +542,50 @@ mod tests {
         assert!(!result.contains("<script>"));
     }
 
+    fn test_localization(template_id: Uuid, locale: &str) -> EmailTemplateLocalization {
+        EmailTemplateLocalization {
+            id: Uuid::new_v4(),
+            template_id,
+            locale: locale.to_string(),
+            structure: serde_json::json!({}),
+            mjml: format!("<mjml>{locale}</mjml>"),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_localized_mjml_follows_fallback_chain() {
+        let template = EmailTemplate {
+            id: Uuid::new_v4(),
+            realm_id: Uuid::new_v4(),
+            name: "Reset".to_string(),
+            email_type: EmailType::ResetPassword,
+            structure: serde_json::json!({}),
+            mjml: "<mjml>base</mjml>".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let localizations = vec![
+            test_localization(template.id, "de"),
+            test_localization(template.id, "fr"),
+        ];
+        let chain = |locales: &[&str]| locales.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            localized_mjml(&template, &localizations, &chain(&["fr", "de", "en"])),
+            "<mjml>fr</mjml>"
+        );
+        assert_eq!(
+            localized_mjml(&template, &localizations, &chain(&["ja", "de", "en"])),
+            "<mjml>de</mjml>"
+        );
+        assert_eq!(
+            localized_mjml(&template, &localizations, &chain(&["ja", "en"])),
+            "<mjml>base</mjml>"
+        );
+    }
+
     #[test]
     fn test_available_variables_per_type() {
         let vars = EmailType::ResetPassword.available_variables();
@@ -190,5 +597,105 @@ mod tests {
 
         let vars = EmailType::EmailVerification.available_variables();
         assert!(vars.iter().any(|v| v.name == "verification_link"));
+
+        let vars = EmailType::EmailChanged.available_variables();
+        assert!(vars.iter().any(|v| v.name == "old_email"));
+        assert!(vars.iter().any(|v| v.name == "new_email"));
+        assert!(!vars.iter().any(|v| v.name == "expiration"));
+    }
+
+    #[test]
+    fn test_email_type_round_trips_through_string() {
+        for email_type in EmailType::ALL {
+            assert_eq!(
+                EmailType::try_from(email_type.to_string()).unwrap(),
+                email_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_mjml_only_for_security_notifications() {
+        for email_type in EmailType::ALL {
+            assert_eq!(
+                email_type.default_mjml().is_some(),
+                email_type.is_security_notification()
+            );
+        }
+
+        let mjml = EmailType::NewSignIn.default_mjml().unwrap();
+        assert!(mjml.contains("{{user.first_name}}"));
+        assert!(mjml.contains("{{ip_address}}"));
+    }
+
+    #[test]
+    fn test_every_variable_has_an_example() {
+        for email_type in EmailType::ALL {
+            for variable in email_type.available_variables() {
+                assert!(
+                    !variable.example.starts_with("sample "),
+                    "{} has no example",
+                    variable.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_diff_structure_reports_added_removed_and_changed() {
+        let old = serde_json::json!({
+            "type": "root",
+            "children": [
+                {"type": "text", "content": "Hello"},
+                {"type": "button", "href": "{{reset_link}}"}
+            ],
+            "attrs/legacy": true
+        });
+        let new = serde_json::json!({
+            "type": "root",
+            "children": [
+                {"type": "text", "content": "Hi", "align": "center"}
+            ]
+        });
+
+        let changes = diff_structure(&old, &new);
+
+        assert_eq!(
+            changes,
+            vec![
+                StructureChange {
+                    path: "/attrs~1legacy".to_string(),
+                    kind: StructureChangeKind::Removed,
+                    old_value: Some(serde_json::json!(true)),
+                    new_value: None,
+                },
+                StructureChange {
+                    path: "/children/0/content".to_string(),
+                    kind: StructureChangeKind::Changed,
+                    old_value: Some(serde_json::json!("Hello")),
+                    new_value: Some(serde_json::json!("Hi")),
+                },
+                StructureChange {
+                    path: "/children/0/align".to_string(),
+                    kind: StructureChangeKind::Added,
+                    old_value: None,
+                    new_value: Some(serde_json::json!("center")),
+                },
+                StructureChange {
+                    path: "/children/1".to_string(),
+                    kind: StructureChangeKind::Removed,
+                    old_value: Some(
+                        serde_json::json!({"type": "button", "href": "{{reset_link}}"})
+                    ),
+                    new_value: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_structure_of_identical_values_is_empty() {
+        let structure = serde_json::json!({"type": "root", "children": [1, 2]});
+        assert!(diff_structure(&structure, &structure).is_empty());
     }
 }
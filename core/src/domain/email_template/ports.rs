@@ -5,7 +5,10 @@ use uuid::Uuid;
 use crate::domain::{
     authentication::value_objects::Identity,
     common::entities::app_errors::CoreError,
-    email_template::entities::{EmailTemplate, EmailType},
+    email_template::entities::{
+        EmailTemplate, EmailTemplateLocalization, EmailTemplatePreview, EmailTemplateVersion,
+        EmailTemplateVersionDiff, EmailType,
+    },
     realm::entities::Realm,
 };
 
@@ -44,6 +47,64 @@ pub trait EmailTemplateService: Send + Sync {
         &self,
         template_id: Uuid,
     ) -> impl Future<Output = Result<String, CoreError>> + Send;
+
+    fn list_template_localizations(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateInput,
+    ) -> impl Future<Output = Result<Vec<EmailTemplateLocalization>, CoreError>> + Send;
+
+    fn upsert_template_localization(
+        &self,
+        identity: Identity,
+        input: UpsertEmailTemplateLocalizationInput,
+    ) -> impl Future<Output = Result<EmailTemplateLocalization, CoreError>> + Send;
+
+    fn delete_template_localization(
+        &self,
+        identity: Identity,
+        input: DeleteEmailTemplateLocalizationInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Versions of the template, newest first.
+    fn list_template_versions(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateInput,
+    ) -> impl Future<Output = Result<Vec<EmailTemplateVersion>, CoreError>> + Send;
+
+    fn get_template_version(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateVersionInput,
+    ) -> impl Future<Output = Result<EmailTemplateVersion, CoreError>> + Send;
+
+    /// Restores an earlier version by saving it again as the newest one.
+    fn rollback_template(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateVersionInput,
+    ) -> impl Future<Output = Result<EmailTemplate, CoreError>> + Send;
+
+    fn diff_template_versions(
+        &self,
+        identity: Identity,
+        input: DiffEmailTemplateVersionsInput,
+    ) -> impl Future<Output = Result<EmailTemplateVersionDiff, CoreError>> + Send;
+
+    fn preview_template(
+        &self,
+        identity: Identity,
+        input: PreviewEmailTemplateInput,
+    ) -> impl Future<Output = Result<EmailTemplatePreview, CoreError>> + Send;
+
+    /// Renders the template like [`Self::preview_template`] and sends it
+    /// through the realm's SMTP configuration right away.
+    fn send_test_email(
+        &self,
+        identity: Identity,
+        input: SendTestEmailInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -58,6 +119,7 @@ pub trait EmailTemplateRepository: Send + Sync {
         template_id: Uuid,
     ) -> impl Future<Output = Result<Option<EmailTemplate>, CoreError>> + Send;
 
+    /// Creates the template along with its first version.
     fn create(
         &self,
         realm_id: Uuid,
@@ -65,17 +127,52 @@ pub trait EmailTemplateRepository: Send + Sync {
         email_type: String,
         structure: serde_json::Value,
         mjml: String,
+        created_by: Option<Uuid>,
     ) -> impl Future<Output = Result<EmailTemplate, CoreError>> + Send;
 
+    /// Updates the template and records the result as its next version.
     fn update(
         &self,
         template_id: Uuid,
         name: String,
         structure: serde_json::Value,
         mjml: String,
+        created_by: Option<Uuid>,
     ) -> impl Future<Output = Result<EmailTemplate, CoreError>> + Send;
 
     fn delete(&self, template_id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn list_localizations(
+        &self,
+        template_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<EmailTemplateLocalization>, CoreError>> + Send;
+
+    fn upsert_localization(
+        &self,
+        template_id: Uuid,
+        locale: String,
+        structure: serde_json::Value,
+        mjml: String,
+    ) -> impl Future<Output = Result<EmailTemplateLocalization, CoreError>> + Send;
+
+    /// Returns whether a localization was deleted.
+    fn delete_localization(
+        &self,
+        template_id: Uuid,
+        locale: String,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    /// Versions of the template, newest first.
+    fn list_versions(
+        &self,
+        template_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<EmailTemplateVersion>, CoreError>> + Send;
+
+    fn get_version(
+        &self,
+        template_id: Uuid,
+        version: i32,
+    ) -> impl Future<Output = Result<Option<EmailTemplateVersion>, CoreError>> + Send;
 }
 
 /// Trait for rendering a builder structure JSON into an intermediate format (e.g. MJML)
@@ -130,3 +227,49 @@ pub struct DeleteEmailTemplateInput {
     pub realm_name: String,
     pub template_id: Uuid,
 }
+
+pub struct UpsertEmailTemplateLocalizationInput {
+    pub realm_name: String,
+    pub template_id: Uuid,
+    pub locale: String,
+    pub structure: serde_json::Value,
+}
+
+pub struct DeleteEmailTemplateLocalizationInput {
+    pub realm_name: String,
+    pub template_id: Uuid,
+    pub locale: String,
+}
+
+pub struct GetEmailTemplateVersionInput {
+    pub realm_name: String,
+    pub template_id: Uuid,
+    pub version: i32,
+}
+
+pub struct DiffEmailTemplateVersionsInput {
+    pub realm_name: String,
+    pub template_id: Uuid,
+    pub from_version: i32,
+    pub to_version: i32,
+}
+
+pub struct PreviewEmailTemplateInput {
+    pub realm_name: String,
+    pub template_id: Uuid,
+    /// Unsaved structure to render instead of the stored template.
+    pub structure: Option<serde_json::Value>,
+    /// User of the realm whose data fills the `user.*` variables; sample
+    /// values are used otherwise.
+    pub user_id: Option<Uuid>,
+    /// Locale to render; defaults to the user's locale, then the realm's.
+    pub locale: Option<String>,
+}
+
+pub struct SendTestEmailInput {
+    pub realm_name: String,
+    pub template_id: Uuid,
+    pub recipient: String,
+    pub user_id: Option<Uuid>,
+    pub locale: Option<String>,
+}
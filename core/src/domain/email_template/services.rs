@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use uuid::Uuid;
 
@@ -6,23 +6,38 @@ use crate::domain::{
     authentication::value_objects::Identity,
     client::ports::ClientRepository,
     common::{
+        email::EmailPort,
         entities::app_errors::CoreError,
         policies::{FerriskeyPolicy, ensure_policy},
     },
     email_template::{
-        entities::EmailTemplate,
+        entities::{
+            EmailTemplate, EmailTemplateLocalization, EmailTemplatePreview, EmailTemplateVersion,
+            EmailTemplateVersionDiff, diff_structure, interpolate_messages, interpolate_variables,
+            localized_mjml,
+        },
         ports::{
-            CreateEmailTemplateInput, DeleteEmailTemplateInput, EmailTemplatePolicy,
-            EmailTemplateRepository, EmailTemplateService, GetEmailTemplateInput,
-            GetEmailTemplatesInput, TemplateRenderer, UpdateEmailTemplateInput,
+            CreateEmailTemplateInput, DeleteEmailTemplateInput,
+            DeleteEmailTemplateLocalizationInput, DiffEmailTemplateVersionsInput,
+            EmailTemplatePolicy, EmailTemplateRepository, EmailTemplateService,
+            GetEmailTemplateInput, GetEmailTemplateVersionInput, GetEmailTemplatesInput,
+            PreviewEmailTemplateInput, SendTestEmailInput, TemplateRenderer,
+            UpdateEmailTemplateInput, UpsertEmailTemplateLocalizationInput,
         },
     },
-    realm::ports::RealmRepository,
+    localization::{
+        entities::normalize_locale, ports::LocalizationRepository,
+        services::resolve_localized_messages, value_objects::LocaleHints,
+    },
+    realm::{
+        entities::Realm,
+        ports::{RealmRepository, SmtpConfigRepository},
+    },
     user::ports::{UserRepository, UserRoleRepository},
 };
 
 #[derive(Clone, Debug)]
-pub struct EmailTemplateServiceImpl<R, U, C, UR, ET, TR>
+pub struct EmailTemplateServiceImpl<R, U, C, UR, ET, TR, SC, ES, LO>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -30,14 +45,22 @@ where
     UR: UserRoleRepository,
     ET: EmailTemplateRepository,
     TR: TemplateRenderer,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    LO: LocalizationRepository,
 {
     pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
     pub(crate) email_template_repository: Arc<ET>,
     pub(crate) template_renderer: Arc<TR>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    /// Test emails skip the outbox so that delivery errors reach the caller.
+    pub(crate) email_port: Arc<ES>,
+    pub(crate) localization_repository: Arc<LO>,
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, ET, TR> EmailTemplateServiceImpl<R, U, C, UR, ET, TR>
+impl<R, U, C, UR, ET, TR, SC, ES, LO> EmailTemplateServiceImpl<R, U, C, UR, ET, TR, SC, ES, LO>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -45,23 +68,35 @@ where
     UR: UserRoleRepository,
     ET: EmailTemplateRepository,
     TR: TemplateRenderer,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    LO: LocalizationRepository,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         realm_repository: Arc<R>,
+        user_repository: Arc<U>,
         email_template_repository: Arc<ET>,
         template_renderer: Arc<TR>,
+        smtp_config_repository: Arc<SC>,
+        email_port: Arc<ES>,
+        localization_repository: Arc<LO>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
             realm_repository,
+            user_repository,
             email_template_repository,
             template_renderer,
+            smtp_config_repository,
+            email_port,
+            localization_repository,
             policy,
         }
     }
 }
 
-impl<R, U, C, UR, ET, TR> EmailTemplateService for EmailTemplateServiceImpl<R, U, C, UR, ET, TR>
+impl<R, U, C, UR, ET, TR, SC, ES, LO> EmailTemplateServiceImpl<R, U, C, UR, ET, TR, SC, ES, LO>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -69,6 +104,137 @@ where
     UR: UserRoleRepository,
     ET: EmailTemplateRepository,
     TR: TemplateRenderer,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    LO: LocalizationRepository,
+{
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    /// The template, provided it belongs to `realm`.
+    async fn get_realm_template(
+        &self,
+        realm: &Realm,
+        template_id: Uuid,
+    ) -> Result<EmailTemplate, CoreError> {
+        self.email_template_repository
+            .get_by_id(template_id)
+            .await?
+            .filter(|template| realm.id == template.realm_id)
+            .ok_or(CoreError::EmailTemplateNotFound)
+    }
+
+    async fn get_version(
+        &self,
+        template: &EmailTemplate,
+        version: i32,
+    ) -> Result<EmailTemplateVersion, CoreError> {
+        self.email_template_repository
+            .get_version(template.id, version)
+            .await?
+            .ok_or(CoreError::NotFound)
+    }
+
+    /// Renders `template` (or the unsaved `structure`) the way it would be
+    /// sent, filling variables with samples or with the data of `user_id`.
+    async fn render_preview(
+        &self,
+        realm: &Realm,
+        template: &EmailTemplate,
+        structure: Option<&serde_json::Value>,
+        user_id: Option<Uuid>,
+        locale: Option<&str>,
+    ) -> Result<EmailTemplatePreview, CoreError> {
+        let locale = locale
+            .map(|locale| {
+                normalize_locale(locale).ok_or_else(|| {
+                    CoreError::InvalidLocalization(format!("invalid locale `{locale}`"))
+                })
+            })
+            .transpose()?;
+
+        let mut variables: HashMap<String, String> = template
+            .email_type
+            .available_variables()
+            .into_iter()
+            .map(|variable| (variable.name, variable.example))
+            .collect();
+        let mut user_locale = None;
+
+        if let Some(user_id) = user_id {
+            let user = self
+                .user_repository
+                .get_by_id(user_id)
+                .await
+                .ok()
+                .filter(|user| user.realm_id == realm.id)
+                .ok_or(CoreError::UserNotFound)?;
+
+            variables.insert(
+                "user.first_name".to_string(),
+                user.firstname.unwrap_or_default(),
+            );
+            variables.insert(
+                "user.last_name".to_string(),
+                user.lastname.unwrap_or_default(),
+            );
+            variables.insert("user.email".to_string(), user.email.unwrap_or_default());
+            user_locale = self
+                .localization_repository
+                .get_user_locale(user.id)
+                .await?;
+        }
+
+        let hints = LocaleHints {
+            ui_locales: locale,
+            user_locale,
+            ..Default::default()
+        };
+        let messages =
+            resolve_localized_messages(&*self.localization_repository, realm.id, &hints).await?;
+
+        let mjml = match structure {
+            Some(structure) => self.template_renderer.render_to_intermediate(structure)?,
+            None => {
+                let localizations = self
+                    .email_template_repository
+                    .list_localizations(template.id)
+                    .await?;
+                localized_mjml(template, &localizations, &messages.fallback_chain).to_string()
+            }
+        };
+
+        let html = interpolate_messages(&self.template_renderer.render_to_html(&mjml)?, &messages);
+        let subject = messages.message_or(
+            &format!("email.{}.subject", template.email_type),
+            template.email_type.default_subject(),
+        );
+
+        Ok(EmailTemplatePreview {
+            locale: messages.locale,
+            subject,
+            html: interpolate_variables(&html, &variables),
+            variables,
+        })
+    }
+}
+
+impl<R, U, C, UR, ET, TR, SC, ES, LO> EmailTemplateService
+    for EmailTemplateServiceImpl<R, U, C, UR, ET, TR, SC, ES, LO>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    ET: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    LO: LocalizationRepository,
 {
     async fn get_templates_by_realm(
         &self,
@@ -145,6 +311,7 @@ where
                 input.email_type.to_string(),
                 input.structure,
                 mjml,
+                Some(identity.id()),
             )
             .await
     }
@@ -181,7 +348,13 @@ where
         self.template_renderer.render_to_html(&mjml)?;
 
         self.email_template_repository
-            .update(input.template_id, input.name, input.structure, mjml)
+            .update(
+                input.template_id,
+                input.name,
+                input.structure,
+                mjml,
+                Some(identity.id()),
+            )
             .await
     }
 
@@ -223,6 +396,246 @@ where
 
         self.template_renderer.render_to_html(&template.mjml)
     }
+
+    async fn list_template_localizations(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateInput,
+    ) -> Result<Vec<EmailTemplateLocalization>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_email_template(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+
+        self.email_template_repository
+            .list_localizations(template.id)
+            .await
+    }
+
+    async fn upsert_template_localization(
+        &self,
+        identity: Identity,
+        input: UpsertEmailTemplateLocalizationInput,
+    ) -> Result<EmailTemplateLocalization, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_email_template(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+        let locale = normalize_locale(&input.locale).ok_or_else(|| {
+            CoreError::InvalidLocalization(format!("invalid locale `{}`", input.locale))
+        })?;
+
+        let mjml = self
+            .template_renderer
+            .render_to_intermediate(&input.structure)?;
+
+        // Validate that the MJML can be converted to HTML
+        self.template_renderer.render_to_html(&mjml)?;
+
+        self.email_template_repository
+            .upsert_localization(template.id, locale, input.structure, mjml)
+            .await
+    }
+
+    async fn delete_template_localization(
+        &self,
+        identity: Identity,
+        input: DeleteEmailTemplateLocalizationInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_email_template(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+        let locale = normalize_locale(&input.locale).ok_or_else(|| {
+            CoreError::InvalidLocalization(format!("invalid locale `{}`", input.locale))
+        })?;
+
+        if !self
+            .email_template_repository
+            .delete_localization(template.id, locale)
+            .await?
+        {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn list_template_versions(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateInput,
+    ) -> Result<Vec<EmailTemplateVersion>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_email_template(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+
+        self.email_template_repository
+            .list_versions(template.id)
+            .await
+    }
+
+    async fn get_template_version(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateVersionInput,
+    ) -> Result<EmailTemplateVersion, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_email_template(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+
+        self.get_version(&template, input.version).await
+    }
+
+    async fn rollback_template(
+        &self,
+        identity: Identity,
+        input: GetEmailTemplateVersionInput,
+    ) -> Result<EmailTemplate, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_email_template(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+        let version = self.get_version(&template, input.version).await?;
+
+        self.email_template_repository
+            .update(
+                template.id,
+                version.name,
+                version.structure,
+                version.mjml,
+                Some(identity.id()),
+            )
+            .await
+    }
+
+    async fn diff_template_versions(
+        &self,
+        identity: Identity,
+        input: DiffEmailTemplateVersionsInput,
+    ) -> Result<EmailTemplateVersionDiff, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_email_template(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+        let from = self.get_version(&template, input.from_version).await?;
+        let to = self.get_version(&template, input.to_version).await?;
+
+        Ok(EmailTemplateVersionDiff {
+            template_id: template.id,
+            from_version: from.version,
+            to_version: to.version,
+            changes: diff_structure(&from.structure, &to.structure),
+        })
+    }
+
+    async fn preview_template(
+        &self,
+        identity: Identity,
+        input: PreviewEmailTemplateInput,
+    ) -> Result<EmailTemplatePreview, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_email_template(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+
+        self.render_preview(
+            &realm,
+            &template,
+            input.structure.as_ref(),
+            input.user_id,
+            input.locale.as_deref(),
+        )
+        .await
+    }
+
+    async fn send_test_email(
+        &self,
+        identity: Identity,
+        input: SendTestEmailInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_email_template(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let template = self.get_realm_template(&realm, input.template_id).await?;
+        let smtp_config = self
+            .smtp_config_repository
+            .get_by_realm_id(realm.id)
+            .await?
+            .ok_or_else(|| {
+                CoreError::Configuration("SMTP is not configured for the realm".to_string())
+            })?;
+
+        let preview = self
+            .render_preview(
+                &realm,
+                &template,
+                None,
+                input.user_id,
+                input.locale.as_deref(),
+            )
+            .await?;
+        let body = format!(
+            "This is a test of the \"{}\" email template. Open it in an email client that displays HTML to see the rendered version.",
+            template.name
+        );
+
+        self.email_port
+            .send_email(
+                &smtp_config,
+                &input.recipient,
+                &format!("[Test] {}", preview.subject),
+                &body,
+                Some(preview.html),
+            )
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -230,8 +643,13 @@ mod tests {
     use super::*;
     use crate::domain::{
         client::ports::MockClientRepository,
+        common::email::MockEmailPort,
         email_template::{entities::EmailType, ports::MockEmailTemplateRepository},
-        realm::{entities::Realm, ports::MockRealmRepository},
+        localization::ports::MockLocalizationRepository,
+        realm::{
+            entities::Realm,
+            ports::{MockRealmRepository, MockSmtpConfigRepository},
+        },
         role::entities::Role,
         user::{
             entities::User,
@@ -257,6 +675,147 @@ mod tests {
         }
     }
 
+    /// Renders MJML as-is, so that tests can inspect interpolation.
+    struct EchoRenderer;
+
+    impl TemplateRenderer for EchoRenderer {
+        fn render_to_intermediate(
+            &self,
+            structure: &serde_json::Value,
+        ) -> Result<String, CoreError> {
+            Ok(structure["mjml"].as_str().unwrap_or_default().to_string())
+        }
+
+        fn render_to_html(&self, intermediate: &str) -> Result<String, CoreError> {
+            Ok(intermediate.to_string())
+        }
+    }
+
+    type TestService<TR> = EmailTemplateServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockEmailTemplateRepository,
+        TR,
+        MockSmtpConfigRepository,
+        MockEmailPort,
+        MockLocalizationRepository,
+    >;
+
+    fn build_service<TR: TemplateRenderer>(
+        realm_repo: MockRealmRepository,
+        user_repo: MockUserRepository,
+        user_role_repo: MockUserRoleRepository,
+        et_repo: MockEmailTemplateRepository,
+        renderer: TR,
+    ) -> TestService<TR> {
+        build_service_with_email(
+            realm_repo,
+            user_repo,
+            user_role_repo,
+            et_repo,
+            renderer,
+            MockSmtpConfigRepository::new(),
+            MockEmailPort::new(),
+        )
+    }
+
+    fn build_service_with_email<TR: TemplateRenderer>(
+        realm_repo: MockRealmRepository,
+        user_repo: MockUserRepository,
+        user_role_repo: MockUserRoleRepository,
+        et_repo: MockEmailTemplateRepository,
+        renderer: TR,
+        smtp_repo: MockSmtpConfigRepository,
+        email_port: MockEmailPort,
+    ) -> TestService<TR> {
+        let mut localization_repo = MockLocalizationRepository::new();
+        localization_repo
+            .expect_get_user_locale()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        localization_repo
+            .expect_get_settings()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        localization_repo
+            .expect_get_bundles()
+            .returning(|_, _| Box::pin(async { Ok(vec![]) }));
+
+        let user_repo = Arc::new(user_repo);
+        let policy = Arc::new(FerriskeyPolicy::new(
+            user_repo.clone(),
+            Arc::new(MockClientRepository::new()),
+            Arc::new(user_role_repo),
+        ));
+
+        EmailTemplateServiceImpl::new(
+            Arc::new(realm_repo),
+            user_repo,
+            Arc::new(et_repo),
+            Arc::new(renderer),
+            Arc::new(smtp_repo),
+            Arc::new(email_port),
+            Arc::new(localization_repo),
+            policy,
+        )
+    }
+
+    /// Realm, user and role repositories for an admin of `realm`.
+    fn admin_repositories(
+        realm: &Realm,
+    ) -> (
+        MockRealmRepository,
+        MockUserRepository,
+        MockUserRoleRepository,
+    ) {
+        let realm_clone = realm.clone();
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let realm = realm_clone.clone();
+            Box::pin(async move { Ok(Some(realm)) })
+        });
+
+        let user = test_user(realm);
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_get_by_id().returning(move |_| {
+            let u = user.clone();
+            Box::pin(async move { Ok(u) })
+        });
+
+        let role_realm_id = realm.id;
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo.expect_get_user_roles().returning(move |_| {
+            Box::pin(async move {
+                Ok(vec![Role {
+                    id: uuid::Uuid::new_v4(),
+                    name: "admin".to_string(),
+                    description: None,
+                    permissions: vec!["manage_realm".to_string()],
+                    realm_id: role_realm_id,
+                    client_id: None,
+                    client: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                }])
+            })
+        });
+
+        (realm_repo, user_repo, user_role_repo)
+    }
+
+    fn test_version(template: &EmailTemplate, version: i32) -> EmailTemplateVersion {
+        EmailTemplateVersion {
+            id: uuid::Uuid::new_v4(),
+            template_id: template.id,
+            version,
+            name: format!("Test Template v{version}"),
+            structure: json!({"type": "root", "children": [version]}),
+            mjml: format!("<mjml>v{version}</mjml>"),
+            created_by: None,
+            created_at: Utc::now(),
+        }
+    }
+
     fn test_realm() -> Realm {
         Realm {
             id: uuid::Uuid::new_v4().into(),
@@ -338,26 +897,13 @@ mod tests {
             })
         });
 
-        let client_repo = MockClientRepository::new();
-
         let mut et_repo = MockEmailTemplateRepository::new();
-        et_repo.expect_create().returning(move |_, _, _, _, _| {
+        et_repo.expect_create().returning(move |_, _, _, _, _, _| {
             let t = template_clone.clone();
             Box::pin(async move { Ok(t) })
         });
 
-        let policy = Arc::new(FerriskeyPolicy::new(
-            Arc::new(user_repo),
-            Arc::new(client_repo),
-            Arc::new(user_role_repo),
-        ));
-
-        let service = EmailTemplateServiceImpl::new(
-            Arc::new(realm_repo),
-            Arc::new(et_repo),
-            Arc::new(TestRenderer),
-            policy,
-        );
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, TestRenderer);
 
         let identity = Identity::User(test_user(&realm));
 
@@ -376,6 +922,84 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_upsert_template_localization_normalizes_locale() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let template = test_template(&realm);
+        let template_id = template.id;
+        let realm_clone = realm.clone();
+
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let realm = realm_clone.clone();
+            Box::pin(async move { Ok(Some(realm)) })
+        });
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_get_by_id().returning(move |_| {
+            let u = user.clone();
+            Box::pin(async move { Ok(u) })
+        });
+
+        let mut user_role_repo = MockUserRoleRepository::new();
+        let role_realm_id = realm.id;
+        user_role_repo.expect_get_user_roles().returning(move |_| {
+            Box::pin(async move {
+                Ok(vec![Role {
+                    id: uuid::Uuid::new_v4(),
+                    name: "admin".to_string(),
+                    description: None,
+                    permissions: vec!["manage_realm".to_string()],
+                    realm_id: role_realm_id,
+                    client_id: None,
+                    client: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                }])
+            })
+        });
+
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo.expect_get_by_id().returning(move |_| {
+            let t = template.clone();
+            Box::pin(async move { Ok(Some(t)) })
+        });
+        et_repo
+            .expect_upsert_localization()
+            .with(eq(template_id), eq("fr-CA".to_string()), always(), always())
+            .returning(|template_id, locale, structure, mjml| {
+                Box::pin(async move {
+                    Ok(EmailTemplateLocalization {
+                        id: uuid::Uuid::new_v4(),
+                        template_id,
+                        locale,
+                        structure,
+                        mjml,
+                        created_at: Utc::now(),
+                        updated_at: Utc::now(),
+                    })
+                })
+            });
+
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, TestRenderer);
+
+        let localization = service
+            .upsert_template_localization(
+                Identity::User(test_user(&realm)),
+                UpsertEmailTemplateLocalizationInput {
+                    realm_name: "test-realm".to_string(),
+                    template_id,
+                    locale: "fr_ca".to_string(),
+                    structure: json!({"type": "root", "children": []}),
+                },
+            )
+            .await
+            .expect("localization should be saved");
+
+        assert_eq!(localization.locale, "fr-CA");
+    }
+
     #[tokio::test]
     async fn test_render_template_html() {
         let realm = test_realm();
@@ -386,7 +1010,6 @@ mod tests {
         let realm_repo = MockRealmRepository::new();
         let user_repo = MockUserRepository::new();
         let user_role_repo = MockUserRoleRepository::new();
-        let client_repo = MockClientRepository::new();
 
         let mut et_repo = MockEmailTemplateRepository::new();
         et_repo.expect_get_by_id().returning(move |_| {
@@ -394,18 +1017,7 @@ mod tests {
             Box::pin(async move { Ok(Some(t)) })
         });
 
-        let policy = Arc::new(FerriskeyPolicy::new(
-            Arc::new(user_repo),
-            Arc::new(client_repo),
-            Arc::new(user_role_repo),
-        ));
-
-        let service = EmailTemplateServiceImpl::new(
-            Arc::new(realm_repo),
-            Arc::new(et_repo),
-            Arc::new(TestRenderer),
-            policy,
-        );
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, TestRenderer);
 
         let result = service.render_template_html(template_id).await;
 
@@ -418,29 +1030,276 @@ mod tests {
         let realm_repo = MockRealmRepository::new();
         let user_repo = MockUserRepository::new();
         let user_role_repo = MockUserRoleRepository::new();
-        let client_repo = MockClientRepository::new();
 
         let mut et_repo = MockEmailTemplateRepository::new();
         et_repo
             .expect_get_by_id()
             .returning(|_| Box::pin(async { Ok(None) }));
 
-        let policy = Arc::new(FerriskeyPolicy::new(
-            Arc::new(user_repo),
-            Arc::new(client_repo),
-            Arc::new(user_role_repo),
-        ));
-
-        let service = EmailTemplateServiceImpl::new(
-            Arc::new(realm_repo),
-            Arc::new(et_repo),
-            Arc::new(TestRenderer),
-            policy,
-        );
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, TestRenderer);
 
         let result = service.render_template_html(uuid::Uuid::new_v4()).await;
 
         assert!(result.is_err());
         matches!(result.unwrap_err(), CoreError::EmailTemplateNotFound);
     }
+
+    #[tokio::test]
+    async fn test_rollback_template_saves_old_version_as_newest() {
+        let realm = test_realm();
+        let admin = test_user(&realm);
+        let admin_id = admin.id;
+        let template = test_template(&realm);
+        let template_id = template.id;
+        let version = test_version(&template, 2);
+        let (realm_repo, user_repo, user_role_repo) = admin_repositories(&realm);
+
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo.expect_get_by_id().returning(move |_| {
+            let t = template.clone();
+            Box::pin(async move { Ok(Some(t)) })
+        });
+        let version_clone = version.clone();
+        et_repo
+            .expect_get_version()
+            .with(eq(template_id), eq(2))
+            .returning(move |_, _| {
+                let v = version_clone.clone();
+                Box::pin(async move { Ok(Some(v)) })
+            });
+        et_repo
+            .expect_update()
+            .with(
+                eq(template_id),
+                eq(version.name.clone()),
+                eq(version.structure.clone()),
+                eq(version.mjml.clone()),
+                eq(Some(admin_id)),
+            )
+            .times(1)
+            .returning(move |id, name, structure, mjml, _| {
+                let realm_id = realm.id.into();
+                Box::pin(async move {
+                    Ok(EmailTemplate {
+                        id,
+                        realm_id,
+                        name,
+                        email_type: EmailType::ResetPassword,
+                        structure,
+                        mjml,
+                        created_at: Utc::now(),
+                        updated_at: Utc::now(),
+                    })
+                })
+            });
+
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, TestRenderer);
+
+        let restored = service
+            .rollback_template(
+                Identity::User(admin),
+                GetEmailTemplateVersionInput {
+                    realm_name: "test-realm".to_string(),
+                    template_id,
+                    version: 2,
+                },
+            )
+            .await
+            .expect("rollback should succeed");
+
+        assert_eq!(restored.mjml, "<mjml>v2</mjml>");
+    }
+
+    #[tokio::test]
+    async fn test_rollback_to_missing_version_is_not_found() {
+        let realm = test_realm();
+        let template = test_template(&realm);
+        let template_id = template.id;
+        let (realm_repo, user_repo, user_role_repo) = admin_repositories(&realm);
+
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo.expect_get_by_id().returning(move |_| {
+            let t = template.clone();
+            Box::pin(async move { Ok(Some(t)) })
+        });
+        et_repo
+            .expect_get_version()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        et_repo.expect_update().never();
+
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, TestRenderer);
+
+        let result = service
+            .rollback_template(
+                Identity::User(test_user(&realm)),
+                GetEmailTemplateVersionInput {
+                    realm_name: "test-realm".to_string(),
+                    template_id,
+                    version: 7,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_diff_template_versions() {
+        let realm = test_realm();
+        let template = test_template(&realm);
+        let template_id = template.id;
+        let (realm_repo, user_repo, user_role_repo) = admin_repositories(&realm);
+
+        let versions = [test_version(&template, 1), test_version(&template, 3)];
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo.expect_get_by_id().returning(move |_| {
+            let t = template.clone();
+            Box::pin(async move { Ok(Some(t)) })
+        });
+        et_repo.expect_get_version().returning(move |_, version| {
+            let v = versions.iter().find(|v| v.version == version).cloned();
+            Box::pin(async move { Ok(v) })
+        });
+
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, TestRenderer);
+
+        let diff = service
+            .diff_template_versions(
+                Identity::User(test_user(&realm)),
+                DiffEmailTemplateVersionsInput {
+                    realm_name: "test-realm".to_string(),
+                    template_id,
+                    from_version: 1,
+                    to_version: 3,
+                },
+            )
+            .await
+            .expect("diff should succeed");
+
+        assert_eq!((diff.from_version, diff.to_version), (1, 3));
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].path, "/children/0");
+    }
+
+    #[tokio::test]
+    async fn test_preview_template_fills_user_data_and_samples() {
+        let realm = test_realm();
+        let template = EmailTemplate {
+            mjml: "<p>Hi {{user.first_name}}, go to {{reset_link}} ({{expiration}})</p>"
+                .to_string(),
+            ..test_template(&realm)
+        };
+        let template_id = template.id;
+        let (realm_repo, user_repo, user_role_repo) = admin_repositories(&realm);
+
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo.expect_get_by_id().returning(move |_| {
+            let t = template.clone();
+            Box::pin(async move { Ok(Some(t)) })
+        });
+        et_repo
+            .expect_list_localizations()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, EchoRenderer);
+
+        let preview = service
+            .preview_template(
+                Identity::User(test_user(&realm)),
+                PreviewEmailTemplateInput {
+                    realm_name: "test-realm".to_string(),
+                    template_id,
+                    structure: None,
+                    user_id: Some(uuid::Uuid::new_v4()),
+                    locale: None,
+                },
+            )
+            .await
+            .expect("preview should render");
+
+        assert_eq!(preview.subject, "Reset your password");
+        assert_eq!(
+            preview.html,
+            "<p>Hi Admin, go to https://auth.example.com/realms/example/link?token=sample (15 minutes)</p>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preview_template_renders_unsaved_structure() {
+        let realm = test_realm();
+        let template = test_template(&realm);
+        let template_id = template.id;
+        let (realm_repo, user_repo, user_role_repo) = admin_repositories(&realm);
+
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo.expect_get_by_id().returning(move |_| {
+            let t = template.clone();
+            Box::pin(async move { Ok(Some(t)) })
+        });
+        et_repo.expect_list_localizations().never();
+
+        let service = build_service(realm_repo, user_repo, user_role_repo, et_repo, EchoRenderer);
+
+        let preview = service
+            .preview_template(
+                Identity::User(test_user(&realm)),
+                PreviewEmailTemplateInput {
+                    realm_name: "test-realm".to_string(),
+                    template_id,
+                    structure: Some(json!({"mjml": "<p>Draft for {{user.first_name}}</p>"})),
+                    user_id: None,
+                    locale: Some("fr_ca".to_string()),
+                },
+            )
+            .await
+            .expect("preview should render");
+
+        assert_eq!(preview.html, "<p>Draft for Jane</p>");
+    }
+
+    #[tokio::test]
+    async fn test_send_test_email_requires_smtp_config() {
+        let realm = test_realm();
+        let template = test_template(&realm);
+        let template_id = template.id;
+        let (realm_repo, user_repo, user_role_repo) = admin_repositories(&realm);
+
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo.expect_get_by_id().returning(move |_| {
+            let t = template.clone();
+            Box::pin(async move { Ok(Some(t)) })
+        });
+
+        let mut smtp_repo = MockSmtpConfigRepository::new();
+        smtp_repo
+            .expect_get_by_realm_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        let mut email_port = MockEmailPort::new();
+        email_port.expect_send_email().never();
+
+        let service = build_service_with_email(
+            realm_repo,
+            user_repo,
+            user_role_repo,
+            et_repo,
+            TestRenderer,
+            smtp_repo,
+            email_port,
+        );
+
+        let result = service
+            .send_test_email(
+                Identity::User(test_user(&realm)),
+                SendTestEmailInput {
+                    realm_name: "test-realm".to_string(),
+                    template_id,
+                    recipient: "qa@example.com".to_string(),
+                    user_id: None,
+                    locale: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Configuration(_))));
+    }
 }
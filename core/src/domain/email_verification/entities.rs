@@ -1,7 +1,47 @@
+use std::fmt::Display;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// What clicking the link of a token does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTokenPurpose {
+    /// Marks the current address as verified.
+    VerifyEmail,
+    /// Swaps the user's address for the token's `email`.
+    ChangeEmail,
+    /// Restores the previous address, the token's `email`, after a change.
+    RevertEmailChange,
+}
+
+impl Display for EmailTokenPurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmailTokenPurpose::VerifyEmail => write!(f, "verify_email"),
+            EmailTokenPurpose::ChangeEmail => write!(f, "change_email"),
+            EmailTokenPurpose::RevertEmailChange => write!(f, "revert_email_change"),
+        }
+    }
+}
+
+impl TryFrom<&str> for EmailTokenPurpose {
+    type Error = CoreError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "verify_email" => Ok(EmailTokenPurpose::VerifyEmail),
+            "change_email" => Ok(EmailTokenPurpose::ChangeEmail),
+            "revert_email_change" => Ok(EmailTokenPurpose::RevertEmailChange),
+            _ => Err(CoreError::InternalServerError),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmailVerificationToken {
     pub id: Uuid,
@@ -11,6 +51,17 @@ pub struct EmailVerificationToken {
     pub expires_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub used_at: Option<DateTime<Utc>>,
+    pub purpose: EmailTokenPurpose,
+    /// Address the token applies, for email changes and their reverts.
+    pub email: Option<String>,
+}
+
+/// Outcome of confirming or reverting an email change.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EmailChangeResult {
+    pub user_id: Uuid,
+    /// The user's address after the operation.
+    pub email: String,
 }
 
 impl EmailVerificationToken {
@@ -42,6 +93,8 @@ mod tests {
             expires_at: Utc::now() + Duration::hours(1),
             created_at: Utc::now(),
             used_at: None,
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(!token.is_expired());
@@ -57,6 +110,8 @@ mod tests {
             expires_at: Utc::now() - Duration::hours(1),
             created_at: Utc::now() - Duration::hours(2),
             used_at: None,
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(token.is_expired());
@@ -72,6 +127,8 @@ mod tests {
             expires_at: Utc::now() + Duration::hours(1),
             created_at: Utc::now(),
             used_at: Some(Utc::now()),
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(token.is_used());
@@ -87,6 +144,8 @@ mod tests {
             expires_at: Utc::now() + Duration::hours(1),
             created_at: Utc::now(),
             used_at: None,
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(!token.is_used());
@@ -102,6 +161,8 @@ mod tests {
             expires_at: Utc::now() + Duration::hours(1),
             created_at: Utc::now(),
             used_at: None,
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(token.is_valid());
@@ -117,6 +178,8 @@ mod tests {
             expires_at: Utc::now() - Duration::hours(1),
             created_at: Utc::now() - Duration::hours(2),
             used_at: None,
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(!token.is_valid());
@@ -132,6 +195,8 @@ mod tests {
             expires_at: Utc::now() + Duration::hours(1),
             created_at: Utc::now(),
             used_at: Some(Utc::now()),
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(!token.is_valid());
@@ -147,8 +212,24 @@ mod tests {
             expires_at: Utc::now() - Duration::hours(1),
             created_at: Utc::now() - Duration::hours(2),
             used_at: Some(Utc::now() - Duration::minutes(30)),
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         assert!(!token.is_valid());
     }
+
+    #[test]
+    fn test_purpose_round_trips_through_string() {
+        for purpose in [
+            EmailTokenPurpose::VerifyEmail,
+            EmailTokenPurpose::ChangeEmail,
+            EmailTokenPurpose::RevertEmailChange,
+        ] {
+            assert_eq!(
+                EmailTokenPurpose::try_from(purpose.to_string().as_str()).unwrap(),
+                purpose
+            );
+        }
+    }
 }
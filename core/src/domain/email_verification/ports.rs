@@ -5,7 +5,7 @@ use uuid::Uuid;
 
 use crate::domain::common::entities::app_errors::CoreError;
 
-use super::entities::EmailVerificationToken;
+use super::entities::{EmailChangeResult, EmailTokenPurpose, EmailVerificationToken};
 
 #[derive(Debug, Clone)]
 pub struct CreateEmailVerificationTokenInput {
@@ -13,6 +13,8 @@ pub struct CreateEmailVerificationTokenInput {
     pub realm_id: Uuid,
     pub token_hash: String,
     pub expires_at: DateTime<Utc>,
+    pub purpose: EmailTokenPurpose,
+    pub email: Option<String>,
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -45,6 +47,14 @@ pub trait EmailVerificationTokenRepository: Send + Sync {
         &self,
         user_id: Uuid,
     ) -> impl std::future::Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Marks the user's unused tokens of `purpose` as used, so that only
+    /// the latest one works. Returns how many were revoked.
+    fn revoke_pending(
+        &self,
+        user_id: Uuid,
+        purpose: EmailTokenPurpose,
+    ) -> impl std::future::Future<Output = Result<u64, CoreError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -61,6 +71,31 @@ pub trait EmailVerificationService: Send + Sync {
         realm_name: String,
         token: String,
     ) -> impl std::future::Future<Output = Result<VerifyEmailResult, CoreError>> + Send;
+
+    /// Sends a confirmation link to `new_email`. The user's address stays
+    /// unchanged until the link is used.
+    fn request_email_change(
+        &self,
+        user_id: Uuid,
+        realm_name: String,
+        new_email: String,
+        base_url: String,
+    ) -> impl std::future::Future<Output = Result<(), CoreError>> + Send;
+
+    /// Swaps in the confirmed address and sends the previous one a link
+    /// to revert the change.
+    fn confirm_email_change(
+        &self,
+        realm_name: String,
+        token: String,
+        base_url: String,
+    ) -> impl std::future::Future<Output = Result<EmailChangeResult, CoreError>> + Send;
+
+    fn revert_email_change(
+        &self,
+        realm_name: String,
+        token: String,
+    ) -> impl std::future::Future<Output = Result<EmailChangeResult, CoreError>> + Send;
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
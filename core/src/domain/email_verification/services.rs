@@ -10,18 +10,26 @@ use uuid::Uuid;
 
 use crate::domain::common::email::EmailPort;
 use crate::domain::common::entities::app_errors::CoreError;
-use crate::domain::email_template::entities::interpolate_variables;
+use crate::domain::email_template::entities::{
+    EmailType, interpolate_messages, interpolate_variables, localized_mjml,
+};
 use crate::domain::email_template::ports::{EmailTemplateRepository, TemplateRenderer};
+use crate::domain::localization::{
+    entities::LocalizedMessages, ports::LocalizationRepository, services::localize_for_user,
+};
+use crate::domain::realm::entities::{Realm, SmtpConfig};
 use crate::domain::realm::ports::{RealmRepository, SmtpConfigRepository};
 use crate::domain::seawatch::{
     EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType,
 };
-use crate::domain::user::entities::{RequiredAction, RequiredActionError};
+use crate::domain::user::entities::{RequiredAction, RequiredActionError, User};
 use crate::domain::user::ports::{UserRepository, UserRequiredActionRepository};
+use crate::domain::user::value_objects::UpdateUserRequest;
 use crate::domain::webhook::entities::webhook_payload::WebhookPayload;
 use crate::domain::webhook::entities::webhook_trigger::WebhookTrigger;
 use crate::domain::webhook::ports::WebhookRepository;
 
+use super::entities::{EmailChangeResult, EmailTokenPurpose, EmailVerificationToken};
 use super::ports::*;
 
 fn generate_token_hash(token: &str) -> String {
@@ -35,8 +43,11 @@ const EMAIL_DELIVERY_TIMEOUT: StdDuration = StdDuration::from_secs(10);
 #[cfg(test)]
 const EMAIL_DELIVERY_TIMEOUT: StdDuration = StdDuration::from_millis(10);
 
+/// How long the previous address can undo an email change.
+const EMAIL_CHANGE_REVERT_TTL_DAYS: i64 = 7;
+
 #[derive(Debug)]
-pub struct EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+pub struct EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, LO>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -48,6 +59,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    LO: LocalizationRepository,
 {
     pub(crate) email_verification_token_repository: Arc<EVRT>,
     pub(crate) user_repository: Arc<UR>,
@@ -59,10 +71,11 @@ where
     pub(crate) template_renderer: Arc<TR>,
     pub(crate) webhook_repository: Arc<WR>,
     pub(crate) security_event_repository: Arc<SER>,
+    pub(crate) localization_repository: Arc<LO>,
 }
 
-impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER> Clone
-    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, LO> Clone
+    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, LO>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -74,6 +87,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    LO: LocalizationRepository,
 {
     fn clone(&self) -> Self {
         Self {
@@ -87,12 +101,13 @@ where
             template_renderer: self.template_renderer.clone(),
             webhook_repository: self.webhook_repository.clone(),
             security_event_repository: self.security_event_repository.clone(),
+            localization_repository: self.localization_repository.clone(),
         }
     }
 }
 
-impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
-    EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, LO>
+    EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, LO>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -104,6 +119,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    LO: LocalizationRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -117,6 +133,7 @@ where
         template_renderer: Arc<TR>,
         webhook_repository: Arc<WR>,
         security_event_repository: Arc<SER>,
+        localization_repository: Arc<LO>,
     ) -> Self {
         Self {
             email_verification_token_repository,
@@ -129,6 +146,7 @@ where
             template_renderer,
             webhook_repository,
             security_event_repository,
+            localization_repository,
         }
     }
 
@@ -136,6 +154,7 @@ where
         &self,
         template_id: Uuid,
         user: &crate::domain::user::entities::User,
+        messages: &LocalizedMessages,
         extra_vars: &[(&str, &str)],
     ) -> Result<String, CoreError> {
         let template = self
@@ -143,8 +162,13 @@ where
             .get_by_id(template_id)
             .await?
             .ok_or(CoreError::EmailTemplateNotFound)?;
+        let localizations = self
+            .email_template_repository
+            .list_localizations(template_id)
+            .await?;
 
-        let html = self.template_renderer.render_to_html(&template.mjml)?;
+        let mjml = localized_mjml(&template, &localizations, &messages.fallback_chain);
+        let html = interpolate_messages(&self.template_renderer.render_to_html(mjml)?, messages);
 
         let mut variables = HashMap::new();
         variables.insert(
@@ -165,10 +189,244 @@ where
 
         Ok(interpolate_variables(&html, &variables))
     }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn get_smtp_config(&self, realm: &Realm) -> Result<SmtpConfig, CoreError> {
+        self.smtp_config_repository
+            .get_by_realm_id(realm.id)
+            .await?
+            .ok_or_else(|| {
+                warn!("SMTP not configured for realm {}", realm.name);
+                CoreError::ServiceUnavailable(
+                    "Email delivery is not configured for this realm".to_string(),
+                )
+            })
+    }
+
+    /// Identity providers in `link_only` or `trust_email` mode attach logins
+    /// to the user owning the address, so an address may only belong to one
+    /// user of the realm.
+    async fn ensure_email_available(
+        &self,
+        realm: &Realm,
+        user_id: Uuid,
+        email: &str,
+    ) -> Result<(), CoreError> {
+        match self.user_repository.get_by_email(email, realm.id).await? {
+            Some(owner) if owner.id != user_id => Err(CoreError::EmailAlreadyExists),
+            _ => Ok(()),
+        }
+    }
+
+    /// Stores a token and returns its raw value, the one sent by email.
+    async fn issue_token(
+        &self,
+        user_id: Uuid,
+        realm: &Realm,
+        purpose: EmailTokenPurpose,
+        email: Option<String>,
+        ttl: Duration,
+    ) -> Result<String, CoreError> {
+        let raw_token = Uuid::new_v4().to_string();
+
+        self.email_verification_token_repository
+            .create(CreateEmailVerificationTokenInput {
+                user_id,
+                realm_id: realm.id.into(),
+                token_hash: generate_token_hash(&raw_token),
+                expires_at: Utc::now() + ttl,
+                purpose,
+                email,
+            })
+            .await?;
+
+        Ok(raw_token)
+    }
+
+    /// The unused, unexpired token of `purpose` matching `token`.
+    async fn find_token(
+        &self,
+        realm: &Realm,
+        token: &str,
+        purpose: EmailTokenPurpose,
+    ) -> Result<(EmailVerificationToken, String), CoreError> {
+        self.email_verification_token_repository
+            .find_valid_by_hash(&generate_token_hash(token), realm.id.into())
+            .await?
+            .filter(|t| t.is_valid() && t.purpose == purpose)
+            .and_then(|t| t.email.clone().map(|email| (t, email)))
+            .ok_or(CoreError::InvalidOrExpiredToken)
+    }
+
+    async fn deliver(
+        &self,
+        smtp_config: &SmtpConfig,
+        to: &str,
+        subject: &str,
+        body: &str,
+        html_body: Option<String>,
+    ) -> Result<(), CoreError> {
+        match timeout(
+            EMAIL_DELIVERY_TIMEOUT,
+            self.email_port
+                .send_email(smtp_config, to, subject, body, html_body),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(CoreError::ServiceUnavailable(
+                "Email delivery timed out".to_string(),
+            )),
+        }
+    }
+
+    /// Sets the user's address, marking it verified: the user proved they
+    /// own it by following the link sent there.
+    async fn set_verified_email(&self, user: User, email: String) -> Result<User, CoreError> {
+        let user_id = user.id;
+        let updated_user = self
+            .user_repository
+            .update_user(
+                user_id,
+                UpdateUserRequest {
+                    firstname: user.firstname,
+                    lastname: user.lastname,
+                    email: Some(email),
+                    email_verified: true,
+                    enabled: user.enabled,
+                    required_actions: None,
+                },
+            )
+            .await?;
+
+        if let Err(e) = self
+            .user_required_action_repository
+            .remove_required_action(user_id, RequiredAction::VerifyEmail)
+            .await
+            && !matches!(e, RequiredActionError::NotFound)
+        {
+            warn!("Failed to remove VerifyEmail required action: {}", e);
+        }
+
+        Ok(updated_user)
+    }
+
+    /// Audit + webhook emission is best-effort: the address has already
+    /// changed.
+    async fn emit_email_changed(&self, realm: &Realm, user: User, old_email: Option<String>) {
+        if let Err(e) = self
+            .security_event_repository
+            .store_event(
+                SecurityEvent::new(
+                    realm.id,
+                    SecurityEventType::UserEmailChanged,
+                    EventStatus::Success,
+                    user.id,
+                )
+                .with_target("user".to_string(), user.id, None)
+                .with_details(serde_json::json!({
+                    "old_email": old_email,
+                    "new_email": user.email,
+                })),
+            )
+            .await
+        {
+            warn!("Failed to store UserEmailChanged security event: {}", e);
+        }
+
+        if let Err(e) = self
+            .webhook_repository
+            .notify(
+                realm.id,
+                WebhookPayload::new(
+                    WebhookTrigger::UserEmailChanged,
+                    realm.id.into(),
+                    Some(user),
+                ),
+            )
+            .await
+        {
+            warn!("Failed to notify UserEmailChanged webhook: {}", e);
+        }
+    }
+
+    /// Tells the previous address about the change, with a link to undo it.
+    async fn send_revert_email(
+        &self,
+        realm: &Realm,
+        user: &User,
+        old_email: &str,
+        base_url: &str,
+    ) -> Result<(), CoreError> {
+        let smtp_config = self.get_smtp_config(realm).await?;
+        let raw_token = self
+            .issue_token(
+                user.id,
+                realm,
+                EmailTokenPurpose::RevertEmailChange,
+                Some(old_email.to_string()),
+                Duration::days(EMAIL_CHANGE_REVERT_TTL_DAYS),
+            )
+            .await?;
+
+        let revert_link = format!(
+            "{}/realms/{}/authentication/revert-email-change?token={}",
+            base_url, realm.name, raw_token
+        );
+        let new_email = user.email.clone().unwrap_or_default();
+        let changed_at = Utc::now().format("%Y-%m-%d %H:%M UTC").to_string();
+        let body = format!(
+            "The email address of your account was changed from {old_email} to {new_email} on {changed_at}.\n\nIf you did not make this change, restore your previous address with the link below:\n{revert_link}\n\nThis link expires in {EMAIL_CHANGE_REVERT_TTL_DAYS} days."
+        );
+
+        let messages = localize_for_user(&*self.localization_repository, realm.id, user.id).await;
+        let template = self
+            .email_template_repository
+            .fetch_by_realm(realm.id.into())
+            .await?
+            .into_iter()
+            .filter(|t| t.email_type == EmailType::EmailChanged)
+            .max_by_key(|t| t.updated_at);
+        let html_body = match template {
+            Some(template) => self
+                .render_email_template(
+                    template.id,
+                    user,
+                    &messages,
+                    &[
+                        ("old_email", old_email),
+                        ("new_email", new_email.as_str()),
+                        ("changed_at", changed_at.as_str()),
+                        ("revert_link", revert_link.as_str()),
+                    ],
+                )
+                .await
+                .ok(),
+            None => None,
+        };
+
+        let (subject, _) = EmailType::EmailChanged
+            .default_content()
+            .ok_or(CoreError::EmailTemplateNotFound)?;
+        self.deliver(
+            &smtp_config,
+            old_email,
+            &messages.message_or("email.email_changed.subject", subject),
+            &body,
+            html_body,
+        )
+        .await
+    }
 }
 
-impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER> EmailVerificationService
-    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER>
+impl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, LO> EmailVerificationService
+    for EmailVerificationServiceImpl<EVRT, UR, RR, URA, ES, SC, ETR, TR, WR, SER, LO>
 where
     EVRT: EmailVerificationTokenRepository,
     UR: UserRepository,
@@ -180,6 +438,7 @@ where
     TR: TemplateRenderer,
     WR: WebhookRepository,
     SER: SecurityEventRepository,
+    LO: LocalizationRepository,
 {
     async fn send_verification_email(
         &self,
@@ -229,6 +488,8 @@ where
                 realm_id: realm.id.into(),
                 token_hash,
                 expires_at,
+                purpose: EmailTokenPurpose::VerifyEmail,
+                email: None,
             })
             .await?;
 
@@ -249,10 +510,12 @@ where
             verification_link, expiration_label
         );
 
+        let messages = localize_for_user(&*self.localization_repository, realm.id, user.id).await;
         let html_body = self
             .render_email_template(
                 template_id,
                 &user,
+                &messages,
                 &[
                     ("verification_link", verification_link.as_str()),
                     ("expiration", expiration_label.as_str()),
@@ -266,7 +529,10 @@ where
             self.email_port.send_email(
                 &smtp_config,
                 user_email,
-                "Verify your email address",
+                &messages.message_or(
+                    "email.email_verification.subject",
+                    "Verify your email address",
+                ),
                 &body,
                 html_body,
             ),
@@ -310,7 +576,8 @@ where
         let token_record = self
             .email_verification_token_repository
             .find_valid_by_hash(&token_hash, realm.id.into())
-            .await?;
+            .await?
+            .filter(|t| t.purpose == EmailTokenPurpose::VerifyEmail);
 
         // If no valid token found, check if it was already used (idempotency)
         let token_record = match token_record {
@@ -321,6 +588,7 @@ where
                     .email_verification_token_repository
                     .find_by_hash(&token_hash, realm.id.into())
                     .await?
+                    .filter(|t| t.purpose == EmailTokenPurpose::VerifyEmail)
                 {
                     // Token was found but already used - check if user's email is verified
                     let user = self.user_repository.get_by_id(used_token.user_id).await?;
@@ -423,6 +691,193 @@ where
             verified: true,
         })
     }
+
+    async fn request_email_change(
+        &self,
+        user_id: Uuid,
+        realm_name: String,
+        new_email: String,
+        base_url: String,
+    ) -> Result<(), CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        let user = self.user_repository.get_by_id(user_id).await?;
+        if user.realm_id != realm.id {
+            return Err(CoreError::InvalidUser);
+        }
+
+        let new_email = new_email.trim().to_string();
+        if user
+            .email
+            .as_deref()
+            .is_some_and(|current| current.eq_ignore_ascii_case(&new_email))
+        {
+            return Err(CoreError::Invalid);
+        }
+        self.ensure_email_available(&realm, user.id, &new_email)
+            .await?;
+
+        let smtp_config = self.get_smtp_config(&realm).await?;
+
+        // Only the latest request can be confirmed.
+        self.email_verification_token_repository
+            .revoke_pending(user.id, EmailTokenPurpose::ChangeEmail)
+            .await?;
+
+        let ttl_hours = realm
+            .settings
+            .as_ref()
+            .map(|s| s.email_verification_ttl_hours)
+            .unwrap_or(24);
+        let raw_token = self
+            .issue_token(
+                user.id,
+                &realm,
+                EmailTokenPurpose::ChangeEmail,
+                Some(new_email.clone()),
+                Duration::hours(ttl_hours),
+            )
+            .await?;
+
+        let confirmation_link = format!(
+            "{}/realms/{}/authentication/confirm-email-change?token={}",
+            base_url, realm_name, raw_token
+        );
+        let expiration_label = if ttl_hours == 1 {
+            "1 hour".to_string()
+        } else {
+            format!("{} hours", ttl_hours)
+        };
+        let body = format!(
+            "Click the link below to confirm {new_email} as the new email address of your account:\n{confirmation_link}\n\nThis link expires in {expiration_label}.\n\nIf you did not ask for this change, please ignore this email."
+        );
+
+        // The verification template greets the recipient, who owns the new
+        // address.
+        let recipient = User {
+            email: Some(new_email.clone()),
+            ..user
+        };
+        let messages = localize_for_user(&*self.localization_repository, realm.id, user_id).await;
+        let html_body = match realm
+            .settings
+            .as_ref()
+            .and_then(|s| s.email_verification_template_id)
+        {
+            Some(template_id) => self
+                .render_email_template(
+                    template_id,
+                    &recipient,
+                    &messages,
+                    &[
+                        ("verification_link", confirmation_link.as_str()),
+                        ("expiration", expiration_label.as_str()),
+                    ],
+                )
+                .await
+                .ok(),
+            None => None,
+        };
+
+        self.deliver(
+            &smtp_config,
+            &new_email,
+            &messages.message_or(
+                "email.email_change.subject",
+                "Confirm your new email address",
+            ),
+            &body,
+            html_body,
+        )
+        .await
+        .inspect_err(|e| warn!("Failed to send email change confirmation: {}", e))
+    }
+
+    async fn confirm_email_change(
+        &self,
+        realm_name: String,
+        token: String,
+        base_url: String,
+    ) -> Result<EmailChangeResult, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        let (token_record, new_email) = self
+            .find_token(&realm, &token, EmailTokenPurpose::ChangeEmail)
+            .await?;
+
+        let user = self.user_repository.get_by_id(token_record.user_id).await?;
+        // The address may have been taken since the request.
+        self.ensure_email_available(&realm, user.id, &new_email)
+            .await?;
+
+        let old_email = user.email.clone();
+        let updated_user = self.set_verified_email(user, new_email.clone()).await?;
+
+        // Consumes this token along with any older request.
+        if let Err(e) = self
+            .email_verification_token_repository
+            .revoke_pending(updated_user.id, EmailTokenPurpose::ChangeEmail)
+            .await
+        {
+            warn!("Failed to revoke email change tokens: {}", e);
+        }
+
+        if let Some(old_email) = old_email.as_deref()
+            && let Err(e) = self
+                .send_revert_email(&realm, &updated_user, old_email, &base_url)
+                .await
+        {
+            warn!(user_id = %updated_user.id, "Failed to send email change revert link: {}", e);
+        }
+
+        self.emit_email_changed(&realm, updated_user, old_email)
+            .await;
+
+        Ok(EmailChangeResult {
+            user_id: token_record.user_id,
+            email: new_email,
+        })
+    }
+
+    async fn revert_email_change(
+        &self,
+        realm_name: String,
+        token: String,
+    ) -> Result<EmailChangeResult, CoreError> {
+        let realm = self.get_realm(&realm_name).await?;
+        let (token_record, previous_email) = self
+            .find_token(&realm, &token, EmailTokenPurpose::RevertEmailChange)
+            .await?;
+
+        let user = self.user_repository.get_by_id(token_record.user_id).await?;
+        self.ensure_email_available(&realm, user.id, &previous_email)
+            .await?;
+
+        let changed_email = user.email.clone();
+        let updated_user = self
+            .set_verified_email(user, previous_email.clone())
+            .await?;
+
+        // Neither a pending change nor another revert may undo this one.
+        for purpose in [
+            EmailTokenPurpose::ChangeEmail,
+            EmailTokenPurpose::RevertEmailChange,
+        ] {
+            if let Err(e) = self
+                .email_verification_token_repository
+                .revoke_pending(updated_user.id, purpose)
+                .await
+            {
+                warn!("Failed to revoke {} tokens: {}", purpose, e);
+            }
+        }
+
+        self.emit_email_changed(&realm, updated_user, changed_email)
+            .await;
+
+        Ok(EmailChangeResult {
+            user_id: token_record.user_id,
+            email: previous_email,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -437,8 +892,9 @@ mod tests {
             ports::{MockEmailTemplateRepository, TemplateRenderer},
         },
         email_verification::entities::EmailVerificationToken,
+        localization::ports::MockLocalizationRepository,
         realm::{
-            entities::{Realm, RealmSetting, SmtpConfig, SmtpEncryption},
+            entities::{EmailProvider, Realm, RealmSetting, SmtpConfig, SmtpEncryption},
             ports::{MockRealmRepository, MockSmtpConfigRepository},
         },
         seawatch::ports::MockSecurityEventRepository,
@@ -503,6 +959,7 @@ mod tests {
             from_email: "noreply@example.com".to_string(),
             from_name: "Test".to_string(),
             encryption: SmtpEncryption::Tls,
+            provider: EmailProvider::Smtp,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -530,9 +987,47 @@ mod tests {
             expires_at: Utc::now() + Duration::hours(24),
             created_at: Utc::now(),
             used_at: None,
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         }
     }
 
+    fn email_change_token(
+        user_id: Uuid,
+        realm_id: RealmId,
+        token_hash: String,
+        purpose: EmailTokenPurpose,
+        email: &str,
+    ) -> EmailVerificationToken {
+        EmailVerificationToken {
+            purpose,
+            email: Some(email.to_string()),
+            ..test_token(user_id, realm_id, token_hash)
+        }
+    }
+
+    fn realm_repo_returning(realm: &Realm) -> MockRealmRepository {
+        let realm = realm.clone();
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let r = realm.clone();
+            Box::pin(async move { Ok(Some(r)) })
+        });
+        realm_repo
+    }
+
+    /// A realm without localization settings or bundles.
+    fn unlocalized_repo() -> MockLocalizationRepository {
+        let mut repo = MockLocalizationRepository::new();
+        repo.expect_get_user_locale()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        repo.expect_get_settings()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        repo.expect_get_bundles()
+            .returning(|_, _| Box::pin(async { Ok(vec![]) }));
+        repo
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn build_service(
         evrt: MockEmailVerificationTokenRepository,
@@ -555,6 +1050,7 @@ mod tests {
         TestRenderer,
         MockWebhookRepository,
         MockSecurityEventRepository,
+        MockLocalizationRepository,
     > {
         EmailVerificationServiceImpl::new(
             Arc::new(evrt),
@@ -567,6 +1063,7 @@ mod tests {
             Arc::new(TestRenderer),
             Arc::new(webhook_repo),
             Arc::new(security_event_repo),
+            Arc::new(unlocalized_repo()),
         )
     }
 
@@ -696,6 +1193,8 @@ mod tests {
             expires_at: Utc::now() - Duration::hours(1),
             created_at: Utc::now() - Duration::hours(25),
             used_at: None,
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         let mut evrt = MockEmailVerificationTokenRepository::new();
@@ -746,6 +1245,8 @@ mod tests {
             expires_at: Utc::now() + Duration::hours(1),
             created_at: Utc::now(),
             used_at: Some(Utc::now() - Duration::minutes(5)),
+            purpose: EmailTokenPurpose::VerifyEmail,
+            email: None,
         };
 
         let mut evrt = MockEmailVerificationTokenRepository::new();
@@ -852,6 +1353,8 @@ mod tests {
                 expires_at: Utc::now() + Duration::hours(24),
                 created_at: Utc::now(),
                 used_at: None,
+                purpose: EmailTokenPurpose::VerifyEmail,
+                email: None,
             };
             Box::pin(async move { Ok(token) })
         });
@@ -861,6 +1364,9 @@ mod tests {
             let t = template_clone.clone();
             Box::pin(async move { Ok(Some(t)) })
         });
+        et_repo
+            .expect_list_localizations()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
 
         let mut smtp_repo = MockSmtpConfigRepository::new();
         smtp_repo.expect_get_by_realm_id().returning(move |_| {
@@ -1040,6 +1546,8 @@ mod tests {
                         expires_at: input.expires_at,
                         created_at: Utc::now(),
                         used_at: None,
+                        purpose: EmailTokenPurpose::VerifyEmail,
+                        email: None,
                     })
                 })
             });
@@ -1049,6 +1557,9 @@ mod tests {
             let t = template_clone.clone();
             Box::pin(async move { Ok(Some(t)) })
         });
+        et_repo
+            .expect_list_localizations()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
 
         let mut smtp_repo = MockSmtpConfigRepository::new();
         smtp_repo.expect_get_by_realm_id().returning(move |_| {
@@ -1134,6 +1645,8 @@ mod tests {
                     expires_at: Utc::now() + Duration::hours(24),
                     created_at: Utc::now(),
                     used_at: None,
+                    purpose: EmailTokenPurpose::VerifyEmail,
+                    email: None,
                 })
             })
         });
@@ -1143,6 +1656,9 @@ mod tests {
             let t = template_clone.clone();
             Box::pin(async move { Ok(Some(t)) })
         });
+        et_repo
+            .expect_list_localizations()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
 
         let mut smtp_repo = MockSmtpConfigRepository::new();
         smtp_repo.expect_get_by_realm_id().returning(move |_| {
@@ -1216,6 +1732,8 @@ mod tests {
                     expires_at: Utc::now() + Duration::hours(24),
                     created_at: Utc::now(),
                     used_at: None,
+                    purpose: EmailTokenPurpose::VerifyEmail,
+                    email: None,
                 })
             })
         });
@@ -1225,6 +1743,9 @@ mod tests {
             let t = template_clone.clone();
             Box::pin(async move { Ok(Some(t)) })
         });
+        et_repo
+            .expect_list_localizations()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
 
         let mut smtp_repo = MockSmtpConfigRepository::new();
         smtp_repo.expect_get_by_realm_id().returning(move |_| {
@@ -1265,4 +1786,235 @@ mod tests {
                 if message.contains("Verification email delivery timed out")
         ));
     }
+
+    #[tokio::test]
+    async fn test_verify_email_rejects_email_change_token() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let raw_token = "change-token";
+        let token = email_change_token(
+            user.id,
+            realm.id,
+            generate_token_hash(raw_token),
+            EmailTokenPurpose::ChangeEmail,
+            "new@example.com",
+        );
+
+        let mut evrt = MockEmailVerificationTokenRepository::new();
+        let tc = token.clone();
+        evrt.expect_find_valid_by_hash()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(tc)) }));
+        evrt.expect_find_by_hash()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(token)) }));
+
+        let service = build_service(
+            evrt,
+            MockUserRepository::new(),
+            realm_repo_returning(&realm),
+            MockUserRequiredActionRepository::new(),
+            MockEmailPort::new(),
+            MockSmtpConfigRepository::new(),
+            MockEmailTemplateRepository::new(),
+            MockWebhookRepository::new(),
+            MockSecurityEventRepository::new(),
+        );
+
+        let result = service
+            .verify_email("test-realm".to_string(), raw_token.to_string())
+            .await;
+        assert!(matches!(result, Err(CoreError::InvalidOrExpiredToken)));
+    }
+
+    #[tokio::test]
+    async fn test_request_email_change_rejects_address_of_another_user() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let mut other = test_user(&realm);
+        other.id = Uuid::new_v4();
+        other.email = Some("taken@example.com".to_string());
+
+        let mut user_repo = MockUserRepository::new();
+        let uc = user.clone();
+        user_repo
+            .expect_get_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(uc) }));
+        user_repo
+            .expect_get_by_email()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(other)) }));
+
+        let service = build_service(
+            MockEmailVerificationTokenRepository::new(),
+            user_repo,
+            realm_repo_returning(&realm),
+            MockUserRequiredActionRepository::new(),
+            MockEmailPort::new(),
+            MockSmtpConfigRepository::new(),
+            MockEmailTemplateRepository::new(),
+            MockWebhookRepository::new(),
+            MockSecurityEventRepository::new(),
+        );
+
+        let result = service
+            .request_email_change(
+                user.id,
+                "test-realm".to_string(),
+                "taken@example.com".to_string(),
+                "http://localhost".to_string(),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::EmailAlreadyExists)));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_email_change_swaps_address_and_notifies_old_one() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let user_id = user.id;
+        let raw_token = "change-token";
+        let token = email_change_token(
+            user_id,
+            realm.id,
+            generate_token_hash(raw_token),
+            EmailTokenPurpose::ChangeEmail,
+            "new@example.com",
+        );
+
+        let mut evrt = MockEmailVerificationTokenRepository::new();
+        evrt.expect_find_valid_by_hash()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(token)) }));
+        evrt.expect_revoke_pending()
+            .with(eq(user_id), eq(EmailTokenPurpose::ChangeEmail))
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(1) }));
+        evrt.expect_create()
+            .withf(|input| {
+                input.purpose == EmailTokenPurpose::RevertEmailChange
+                    && input.email.as_deref() == Some("test@example.com")
+            })
+            .times(1)
+            .returning(move |input| {
+                let token = email_change_token(
+                    input.user_id,
+                    RealmId::new(input.realm_id),
+                    input.token_hash,
+                    input.purpose,
+                    "test@example.com",
+                );
+                Box::pin(async move { Ok(token) })
+            });
+
+        let mut user_repo = MockUserRepository::new();
+        let uc = user.clone();
+        user_repo
+            .expect_get_by_id()
+            .return_once(move |_| Box::pin(async move { Ok(uc) }));
+        user_repo
+            .expect_get_by_email()
+            .return_once(|_, _| Box::pin(async { Ok(None) }));
+        let mut updated = user.clone();
+        updated.email = Some("new@example.com".to_string());
+        updated.email_verified = true;
+        user_repo
+            .expect_update_user()
+            .withf(|_, request| {
+                request.email.as_deref() == Some("new@example.com") && request.email_verified
+            })
+            .times(1)
+            .return_once(move |_, _| Box::pin(async move { Ok(updated) }));
+
+        let mut ura_repo = MockUserRequiredActionRepository::new();
+        ura_repo
+            .expect_remove_required_action()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let smtp_config = test_smtp_config(&realm);
+        let mut smtp_repo = MockSmtpConfigRepository::new();
+        smtp_repo.expect_get_by_realm_id().returning(move |_| {
+            let s = smtp_config.clone();
+            Box::pin(async move { Ok(Some(s)) })
+        });
+
+        let mut et_repo = MockEmailTemplateRepository::new();
+        et_repo
+            .expect_fetch_by_realm()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let mut email_port = MockEmailPort::new();
+        email_port
+            .expect_send_email()
+            .withf(|_, to, _, body, html| {
+                to == "test@example.com" && body.contains("revert-email-change") && html.is_none()
+            })
+            .times(1)
+            .returning(|_, _, _, _, _| Box::pin(async { Ok(()) }));
+
+        let mut security_event_repo = MockSecurityEventRepository::new();
+        security_event_repo
+            .expect_store_event()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let mut webhook_repo = MockWebhookRepository::new();
+        webhook_repo
+            .expect_notify::<User>()
+            .withf(|_, payload| payload.event == WebhookTrigger::UserEmailChanged)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let service = build_service(
+            evrt,
+            user_repo,
+            realm_repo_returning(&realm),
+            ura_repo,
+            email_port,
+            smtp_repo,
+            et_repo,
+            webhook_repo,
+            security_event_repo,
+        );
+
+        let result = service
+            .confirm_email_change(
+                "test-realm".to_string(),
+                raw_token.to_string(),
+                "http://localhost".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.user_id, user_id);
+        assert_eq!(result.email, "new@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_confirm_email_change_rejects_verification_token() {
+        let realm = test_realm();
+        let user = test_user(&realm);
+        let raw_token = "verify-token";
+        let token = test_token(user.id, realm.id, generate_token_hash(raw_token));
+
+        let mut evrt = MockEmailVerificationTokenRepository::new();
+        evrt.expect_find_valid_by_hash()
+            .return_once(move |_, _| Box::pin(async move { Ok(Some(token)) }));
+
+        let service = build_service(
+            evrt,
+            MockUserRepository::new(),
+            realm_repo_returning(&realm),
+            MockUserRequiredActionRepository::new(),
+            MockEmailPort::new(),
+            MockSmtpConfigRepository::new(),
+            MockEmailTemplateRepository::new(),
+            MockWebhookRepository::new(),
+            MockSecurityEventRepository::new(),
+        );
+
+        let result = service
+            .confirm_email_change(
+                "test-realm".to_string(),
+                raw_token.to_string(),
+                "http://localhost".to_string(),
+            )
+            .await;
+        assert!(matches!(result, Err(CoreError::InvalidOrExpiredToken)));
+    }
 }
@@ -0,0 +1,175 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::realm::entities::RealmId;
+
+/// A table the housekeeping scheduler keeps from growing forever.
+///
+/// Token and session jobs delete rows that expired more than the retention
+/// window ago; the compass job deletes flows older than the window.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum HousekeepingJob {
+    AuthSessions,
+    UserSessions,
+    BrokerAuthSessions,
+    DeviceAuthSessions,
+    RefreshTokens,
+    AccessTokens,
+    MagicLinks,
+    PasswordResetTokens,
+    EmailVerificationTokens,
+    CompassFlows,
+    OneTimeCodes,
+    TrustedDevices,
+}
+
+impl HousekeepingJob {
+    pub const ALL: [HousekeepingJob; 12] = [
+        HousekeepingJob::AuthSessions,
+        HousekeepingJob::UserSessions,
+        HousekeepingJob::BrokerAuthSessions,
+        HousekeepingJob::DeviceAuthSessions,
+        HousekeepingJob::RefreshTokens,
+        HousekeepingJob::AccessTokens,
+        HousekeepingJob::MagicLinks,
+        HousekeepingJob::PasswordResetTokens,
+        HousekeepingJob::EmailVerificationTokens,
+        HousekeepingJob::CompassFlows,
+        HousekeepingJob::OneTimeCodes,
+        HousekeepingJob::TrustedDevices,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HousekeepingJob::AuthSessions => "auth_sessions",
+            HousekeepingJob::UserSessions => "user_sessions",
+            HousekeepingJob::BrokerAuthSessions => "broker_auth_sessions",
+            HousekeepingJob::DeviceAuthSessions => "device_auth_sessions",
+            HousekeepingJob::RefreshTokens => "refresh_tokens",
+            HousekeepingJob::AccessTokens => "access_tokens",
+            HousekeepingJob::MagicLinks => "magic_links",
+            HousekeepingJob::PasswordResetTokens => "password_reset_tokens",
+            HousekeepingJob::EmailVerificationTokens => "email_verification_tokens",
+            HousekeepingJob::CompassFlows => "compass_flows",
+            HousekeepingJob::OneTimeCodes => "one_time_codes",
+            HousekeepingJob::TrustedDevices => "trusted_devices",
+        }
+    }
+
+    /// Key of the Postgres advisory lock held while the job runs, so that a
+    /// single replica purges a table at a time.
+    pub fn lock_key(&self) -> i64 {
+        // "FKHK" followed by the job position keeps clear of keys other
+        // applications sharing the database might use.
+        const NAMESPACE: i64 = 0x464B_484B << 16;
+        let position = Self::ALL
+            .iter()
+            .position(|job| job == self)
+            .unwrap_or_default();
+
+        NAMESPACE + position as i64
+    }
+}
+
+impl fmt::Display for HousekeepingJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for HousekeepingJob {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|job| job.name() == s)
+            .ok_or_else(|| format!("unknown housekeeping job `{s}`"))
+    }
+}
+
+/// Per-realm override of a job's retention window.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct HousekeepingRetentionPolicy {
+    pub realm_id: RealmId,
+    pub job: HousekeepingJob,
+    /// Rows are kept this many hours past their expiry (or creation, for
+    /// compass flows). `None` keeps the realm's rows forever.
+    pub retention_hours: Option<u32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HousekeepingRetentionPolicy {
+    pub fn new(realm_id: RealmId, job: HousekeepingJob, retention_hours: Option<u32>) -> Self {
+        Self {
+            realm_id,
+            job,
+            retention_hours,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn retention(&self) -> Option<Duration> {
+        self.retention_hours
+            .map(|hours| Duration::from_secs(u64::from(hours) * 3600))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HousekeepingRunOutcome {
+    Succeeded,
+    Failed,
+    /// Another replica held the job lock.
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct HousekeepingRun {
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub outcome: HousekeepingRunOutcome,
+    /// Rows deleted by the run.
+    pub purged: u64,
+    pub error: Option<String>,
+}
+
+/// What this replica knows about a job: its schedule, last run and totals
+/// since the process started.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct HousekeepingJobStatus {
+    pub job: HousekeepingJob,
+    pub enabled: bool,
+    pub interval_secs: u64,
+    /// Default retention window, in hours, for realms without an override.
+    pub retention_hours: u32,
+    pub running: bool,
+    pub last_run: Option<HousekeepingRun>,
+    pub runs_total: u64,
+    pub failures_total: u64,
+    pub purged_total: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn job_names_round_trip_and_lock_keys_are_distinct() {
+        let mut keys = Vec::new();
+        for job in HousekeepingJob::ALL {
+            assert_eq!(job.name().parse::<HousekeepingJob>(), Ok(job));
+            keys.push(job.lock_key());
+        }
+        keys.dedup();
+
+        assert_eq!(keys.len(), HousekeepingJob::ALL.len());
+        assert!("sessions".parse::<HousekeepingJob>().is_err());
+    }
+}
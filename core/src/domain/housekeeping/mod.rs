@@ -0,0 +1,6 @@
+pub mod entities;
+pub mod policies;
+pub mod ports;
+pub mod registry;
+pub mod services;
+pub mod value_objects;
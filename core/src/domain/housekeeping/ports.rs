@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    realm::entities::{Realm, RealmId},
+};
+
+use super::{
+    entities::{HousekeepingJob, HousekeepingJobStatus, HousekeepingRetentionPolicy},
+    value_objects::{
+        GetHousekeepingStatusInput, ListHousekeepingRetentionInput, PurgeScope,
+        UpdateHousekeepingRetentionInput,
+    },
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait HousekeepingRepository: Send + Sync {
+    /// Deletes the job's rows older than `cutoff` within `scope`, at most
+    /// `batch_size` per statement, and returns how many were deleted.
+    fn purge(
+        &self,
+        job: HousekeepingJob,
+        scope: PurgeScope,
+        cutoff: DateTime<Utc>,
+        batch_size: u64,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait HousekeepingRetentionRepository: Send + Sync {
+    fn list_by_job(
+        &self,
+        job: HousekeepingJob,
+    ) -> impl Future<Output = Result<Vec<HousekeepingRetentionPolicy>, CoreError>> + Send;
+    fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<HousekeepingRetentionPolicy>, CoreError>> + Send;
+    fn upsert(
+        &self,
+        policy: HousekeepingRetentionPolicy,
+    ) -> impl Future<Output = Result<HousekeepingRetentionPolicy, CoreError>> + Send;
+}
+
+/// Cluster-wide mutual exclusion of housekeeping jobs.
+pub trait HousekeepingLock: Send + Sync {
+    type Guard: HousekeepingLockGuard;
+
+    /// Takes the job's lock, or returns `None` when another replica holds it.
+    fn try_acquire(
+        &self,
+        job: HousekeepingJob,
+    ) -> impl Future<Output = Result<Option<Self::Guard>, CoreError>> + Send;
+}
+
+pub trait HousekeepingLockGuard: Send {
+    fn release(self) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait HousekeepingService: Send + Sync {
+    fn get_housekeeping_status(
+        &self,
+        identity: Identity,
+        input: GetHousekeepingStatusInput,
+    ) -> impl Future<Output = Result<Vec<HousekeepingJobStatus>, CoreError>> + Send;
+    fn list_housekeeping_retention(
+        &self,
+        identity: Identity,
+        input: ListHousekeepingRetentionInput,
+    ) -> impl Future<Output = Result<Vec<HousekeepingRetentionPolicy>, CoreError>> + Send;
+    fn update_housekeeping_retention(
+        &self,
+        identity: Identity,
+        input: UpdateHousekeepingRetentionInput,
+    ) -> impl Future<Output = Result<HousekeepingRetentionPolicy, CoreError>> + Send;
+}
+
+pub trait HousekeepingPolicy: Send + Sync {
+    fn can_view_housekeeping(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_housekeeping(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
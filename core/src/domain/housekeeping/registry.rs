@@ -0,0 +1,78 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+};
+
+use super::{
+    entities::{HousekeepingJob, HousekeepingJobStatus, HousekeepingRun, HousekeepingRunOutcome},
+    value_objects::HousekeepingConfig,
+};
+
+/// Last-run status of every housekeeping job on this replica, written by the
+/// scheduler and read by the status endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct HousekeepingRegistry {
+    statuses: Arc<RwLock<BTreeMap<HousekeepingJob, HousekeepingJobStatus>>>,
+}
+
+impl HousekeepingRegistry {
+    pub fn new(config: &HousekeepingConfig) -> Self {
+        let statuses = HousekeepingJob::ALL
+            .into_iter()
+            .map(|job| {
+                let job_config = config.job(job);
+                let status = HousekeepingJobStatus {
+                    job,
+                    enabled: config.enabled && job_config.enabled,
+                    interval_secs: job_config.interval.as_secs(),
+                    retention_hours: (job_config.retention.as_secs() / 3600) as u32,
+                    running: false,
+                    last_run: None,
+                    runs_total: 0,
+                    failures_total: 0,
+                    purged_total: 0,
+                };
+                (job, status)
+            })
+            .collect();
+
+        Self {
+            statuses: Arc::new(RwLock::new(statuses)),
+        }
+    }
+
+    pub fn mark_running(&self, job: HousekeepingJob) {
+        if let Some(status) = self.write().get_mut(&job) {
+            status.running = true;
+        }
+    }
+
+    pub fn record(&self, job: HousekeepingJob, run: HousekeepingRun) {
+        if let Some(status) = self.write().get_mut(&job) {
+            status.running = false;
+            status.runs_total += 1;
+            status.purged_total += run.purged;
+            if run.outcome == HousekeepingRunOutcome::Failed {
+                status.failures_total += 1;
+            }
+            status.last_run = Some(run);
+        }
+    }
+
+    pub fn snapshot(&self) -> Vec<HousekeepingJobStatus> {
+        self.statuses
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn write(
+        &self,
+    ) -> std::sync::RwLockWriteGuard<'_, BTreeMap<HousekeepingJob, HousekeepingJobStatus>> {
+        self.statuses
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    housekeeping::{
+        entities::{HousekeepingJobStatus, HousekeepingRetentionPolicy},
+        ports::{HousekeepingPolicy, HousekeepingRetentionRepository, HousekeepingService},
+        registry::HousekeepingRegistry,
+        value_objects::{
+            GetHousekeepingStatusInput, ListHousekeepingRetentionInput,
+            UpdateHousekeepingRetentionInput,
+        },
+    },
+    realm::{entities::Realm, ports::RealmRepository},
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// Job status describes the whole deployment, so it is only exposed through
+/// the master realm.
+const MASTER_REALM: &str = "master";
+
+#[derive(Clone, Debug)]
+pub struct HousekeepingServiceImpl<R, U, C, UR, HR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    HR: HousekeepingRetentionRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) retention_repository: Arc<HR>,
+    pub(crate) registry: HousekeepingRegistry,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, HR> HousekeepingServiceImpl<R, U, C, UR, HR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    HR: HousekeepingRetentionRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        retention_repository: Arc<HR>,
+        registry: HousekeepingRegistry,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            retention_repository,
+            registry,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+}
+
+impl<R, U, C, UR, HR> HousekeepingService for HousekeepingServiceImpl<R, U, C, UR, HR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    HR: HousekeepingRetentionRepository,
+{
+    async fn get_housekeeping_status(
+        &self,
+        identity: Identity,
+        input: GetHousekeepingStatusInput,
+    ) -> Result<Vec<HousekeepingJobStatus>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_housekeeping(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+        if realm.name != MASTER_REALM {
+            return Err(CoreError::Forbidden(
+                "housekeeping status is only available in the master realm".to_string(),
+            ));
+        }
+
+        Ok(self.registry.snapshot())
+    }
+
+    async fn list_housekeeping_retention(
+        &self,
+        identity: Identity,
+        input: ListHousekeepingRetentionInput,
+    ) -> Result<Vec<HousekeepingRetentionPolicy>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_housekeeping(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.retention_repository.list_by_realm(realm.id).await
+    }
+
+    async fn update_housekeeping_retention(
+        &self,
+        identity: Identity,
+        input: UpdateHousekeepingRetentionInput,
+    ) -> Result<HousekeepingRetentionPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_housekeeping(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        if input.retention_hours == Some(0) {
+            return Err(CoreError::Invalid);
+        }
+
+        self.retention_repository
+            .upsert(HousekeepingRetentionPolicy::new(
+                realm.id,
+                input.job,
+                input.retention_hours,
+            ))
+            .await
+    }
+}
@@ -0,0 +1,88 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use crate::domain::realm::entities::RealmId;
+
+use super::entities::HousekeepingJob;
+
+const HOUR: Duration = Duration::from_secs(3600);
+
+/// Schedule and default retention of one housekeeping job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HousekeepingJobConfig {
+    pub enabled: bool,
+    pub interval: Duration,
+    /// How long rows are kept past their expiry (creation for compass
+    /// flows) in realms without a retention override.
+    pub retention: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HousekeepingConfig {
+    /// Turns the whole scheduler off, e.g. when an external job purges the
+    /// tables instead.
+    pub enabled: bool,
+    /// Rows deleted per statement, so that a large backlog does not hold
+    /// long locks on hot tables.
+    pub batch_size: u64,
+    pub jobs: BTreeMap<HousekeepingJob, HousekeepingJobConfig>,
+}
+
+impl HousekeepingConfig {
+    pub fn job(&self, job: HousekeepingJob) -> HousekeepingJobConfig {
+        self.jobs
+            .get(&job)
+            .copied()
+            .unwrap_or_else(|| default_job_config(job))
+    }
+}
+
+impl Default for HousekeepingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            batch_size: 5000,
+            jobs: HousekeepingJob::ALL
+                .into_iter()
+                .map(|job| (job, default_job_config(job)))
+                .collect(),
+        }
+    }
+}
+
+fn default_job_config(job: HousekeepingJob) -> HousekeepingJobConfig {
+    match job {
+        HousekeepingJob::CompassFlows => HousekeepingJobConfig {
+            enabled: true,
+            interval: HOUR,
+            retention: 30 * 24 * HOUR,
+        },
+        _ => HousekeepingJobConfig {
+            enabled: true,
+            interval: Duration::from_secs(300),
+            retention: 24 * HOUR,
+        },
+    }
+}
+
+/// Which realms a purge statement covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PurgeScope {
+    Realm(RealmId),
+    /// Every realm except those with a retention override.
+    AllExcept(Vec<RealmId>),
+}
+
+pub struct GetHousekeepingStatusInput {
+    pub realm_name: String,
+}
+
+pub struct ListHousekeepingRetentionInput {
+    pub realm_name: String,
+}
+
+pub struct UpdateHousekeepingRetentionInput {
+    pub realm_name: String,
+    pub job: HousekeepingJob,
+    /// `None` keeps the realm's rows forever.
+    pub retention_hours: Option<u32>,
+}
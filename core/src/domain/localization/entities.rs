@@ -0,0 +1,365 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::realm::entities::RealmId;
+
+/// Locale used by realms that never configured localization.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// User attribute holding the user's preferred locale.
+pub const USER_LOCALE_ATTRIBUTE: &str = "locale";
+
+/// Prefix of message references in email templates and portal pages, as in
+/// `{{msg.login.title}}`.
+const MESSAGE_REFERENCE_PREFIX: &str = "msg.";
+
+/// Longest accepted language tag (RFC 5646 recommends supporting 35).
+const MAX_LOCALE_LENGTH: usize = 35;
+
+/// Locales a realm serves and the one it falls back to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmLocalization {
+    pub realm_id: RealmId,
+    pub default_locale: String,
+    /// Always contains `default_locale`.
+    pub supported_locales: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RealmLocalization {
+    /// Settings of a realm that never configured localization.
+    pub fn new(realm_id: RealmId) -> Self {
+        Self {
+            realm_id,
+            default_locale: DEFAULT_LOCALE.to_string(),
+            supported_locales: vec![DEFAULT_LOCALE.to_string()],
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn supports(&self, locale: &str) -> bool {
+        self.supported_locales.iter().any(|l| l == locale)
+    }
+
+    /// Supported locales to try, most preferred first, for the requested
+    /// language tags: each tag is followed by its less specific forms
+    /// (`fr-CA` then `fr`) and the chain always ends with the default.
+    pub fn fallback_chain(&self, requested: &[String]) -> Vec<String> {
+        let mut chain: Vec<String> = Vec::new();
+
+        for tag in requested.iter().filter_map(|tag| normalize_locale(tag)) {
+            let mut candidate = tag.as_str();
+            loop {
+                if self.supports(candidate) && !chain.iter().any(|l| l == candidate) {
+                    chain.push(candidate.to_string());
+                }
+                match candidate.rfind('-') {
+                    Some(index) => candidate = &candidate[..index],
+                    None => break,
+                }
+            }
+        }
+
+        if !chain.contains(&self.default_locale) {
+            chain.push(self.default_locale.clone());
+        }
+
+        chain
+    }
+}
+
+/// Translated messages of a realm for one locale, referenced by key from
+/// email templates and portal pages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MessageBundle {
+    pub realm_id: RealmId,
+    pub locale: String,
+    pub messages: BTreeMap<String, String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Messages resolved for a request, merged along its fallback chain.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LocalizedMessages {
+    /// Locale the messages were resolved for, the head of `fallback_chain`.
+    pub locale: String,
+    pub fallback_chain: Vec<String>,
+    pub messages: BTreeMap<String, String>,
+}
+
+impl LocalizedMessages {
+    /// Merges `bundles` so that a message comes from the first locale of
+    /// `fallback_chain` defining it.
+    pub fn from_bundles(fallback_chain: Vec<String>, bundles: Vec<MessageBundle>) -> Self {
+        let mut messages = BTreeMap::new();
+
+        for locale in fallback_chain.iter().rev() {
+            if let Some(bundle) = bundles.iter().find(|b| &b.locale == locale) {
+                messages.extend(bundle.messages.clone());
+            }
+        }
+
+        Self {
+            locale: fallback_chain
+                .first()
+                .cloned()
+                .unwrap_or_else(|| DEFAULT_LOCALE.to_string()),
+            fallback_chain,
+            messages,
+        }
+    }
+
+    /// Messages of a realm with nothing configured.
+    pub fn fallback() -> Self {
+        Self::from_bundles(vec![DEFAULT_LOCALE.to_string()], Vec::new())
+    }
+
+    pub fn message_or(&self, key: &str, default: &str) -> String {
+        self.messages
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| default.to_string())
+    }
+
+    /// Replaces every `{{msg.<key>}}` in `text` with its message, passed
+    /// through `escape`. References to unknown keys are left untouched so
+    /// they stay visible while a translation is missing.
+    pub fn interpolate(&self, text: &str, escape: impl Fn(&str) -> String) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start..].find("}}").map(|end| start + end) else {
+                break;
+            };
+
+            let message = rest[start + 2..end]
+                .trim()
+                .strip_prefix(MESSAGE_REFERENCE_PREFIX)
+                .and_then(|key| self.messages.get(key));
+
+            result.push_str(&rest[..start]);
+            match message {
+                Some(message) => result.push_str(&escape(message)),
+                None => result.push_str(&rest[start..end + 2]),
+            }
+            rest = &rest[end + 2..];
+        }
+        result.push_str(rest);
+
+        result
+    }
+
+    /// Interpolates messages into every string of a portal page tree.
+    pub fn localize_tree(&self, tree: &mut serde_json::Value) {
+        match tree {
+            serde_json::Value::String(text) if text.contains("{{") => {
+                *text = self.interpolate(text, str::to_string);
+            }
+            serde_json::Value::Array(items) => {
+                items.iter_mut().for_each(|item| self.localize_tree(item));
+            }
+            serde_json::Value::Object(fields) => {
+                fields
+                    .values_mut()
+                    .for_each(|value| self.localize_tree(value));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Normalizes a BCP 47 language tag to its canonical case (`fr-ca` becomes
+/// `fr-CA`, `zh-hant` becomes `zh-Hant`), or `None` when it is not one.
+pub fn normalize_locale(tag: &str) -> Option<String> {
+    let tag = tag.trim().replace('_', "-");
+    if tag.is_empty() || tag.len() > MAX_LOCALE_LENGTH {
+        return None;
+    }
+
+    let mut subtags = tag.split('-');
+    let language = subtags.next()?;
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut normalized = language.to_ascii_lowercase();
+    for subtag in subtags {
+        if subtag.is_empty()
+            || subtag.len() > 8
+            || !subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+
+        normalized.push('-');
+        match subtag.len() {
+            2 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                normalized.push_str(&subtag.to_ascii_uppercase())
+            }
+            4 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                normalized.push_str(&subtag[..1].to_ascii_uppercase());
+                normalized.push_str(&subtag[1..].to_ascii_lowercase());
+            }
+            _ => normalized.push_str(&subtag.to_ascii_lowercase()),
+        }
+    }
+
+    Some(normalized)
+}
+
+/// Language tags of an `Accept-Language` header, highest quality first.
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut weighted: Vec<(f32, usize, String)> = header
+        .split(',')
+        .enumerate()
+        .filter_map(|(position, entry)| {
+            let mut parts = entry.split(';');
+            let tag = parts.next()?.trim();
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+
+            let quality = parts
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (quality > 0.0).then(|| (quality, position, tag.to_string()))
+        })
+        .collect();
+
+    weighted.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+    weighted.into_iter().map(|(_, _, tag)| tag).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn localization(default_locale: &str, supported: &[&str]) -> RealmLocalization {
+        RealmLocalization {
+            realm_id: RealmId::new(Uuid::new_v4()),
+            default_locale: default_locale.to_string(),
+            supported_locales: supported.iter().map(|l| l.to_string()).collect(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn bundle(locale: &str, messages: &[(&str, &str)]) -> MessageBundle {
+        MessageBundle {
+            realm_id: RealmId::new(Uuid::new_v4()),
+            locale: locale.to_string(),
+            messages: messages
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn normalize_locale_canonicalizes_case() {
+        assert_eq!(normalize_locale("FR"), Some("fr".to_string()));
+        assert_eq!(normalize_locale("fr_ca"), Some("fr-CA".to_string()));
+        assert_eq!(
+            normalize_locale("zh-hant-tw"),
+            Some("zh-Hant-TW".to_string())
+        );
+        assert_eq!(normalize_locale("es-419"), Some("es-419".to_string()));
+    }
+
+    #[test]
+    fn normalize_locale_rejects_invalid_tags() {
+        assert_eq!(normalize_locale(""), None);
+        assert_eq!(normalize_locale("f"), None);
+        assert_eq!(normalize_locale("fr-"), None);
+        assert_eq!(normalize_locale("fr-C@"), None);
+        assert_eq!(normalize_locale("../etc"), None);
+    }
+
+    #[test]
+    fn parse_accept_language_orders_by_quality() {
+        assert_eq!(
+            parse_accept_language("de;q=0.7, ja, fr-CH;q=0.9, *;q=0.1, en;q=0"),
+            vec!["ja", "fr-CH", "de"]
+        );
+    }
+
+    #[test]
+    fn fallback_chain_truncates_tags_and_ends_with_default() {
+        let settings = localization("en", &["en", "fr", "de", "ja"]);
+
+        assert_eq!(
+            settings.fallback_chain(&["fr-CA".to_string(), "ja".to_string()]),
+            vec!["fr", "ja", "en"]
+        );
+        assert_eq!(settings.fallback_chain(&["pt-BR".to_string()]), vec!["en"]);
+        assert_eq!(settings.fallback_chain(&[]), vec!["en"]);
+    }
+
+    #[test]
+    fn fallback_chain_prefers_specific_supported_locale() {
+        let settings = localization("fr", &["fr", "fr-CA"]);
+
+        assert_eq!(
+            settings.fallback_chain(&["fr-ca".to_string()]),
+            vec!["fr-CA", "fr"]
+        );
+    }
+
+    #[test]
+    fn from_bundles_prefers_first_locale_of_chain() {
+        let messages = LocalizedMessages::from_bundles(
+            vec!["fr".to_string(), "en".to_string()],
+            vec![
+                bundle("en", &[("login.title", "Sign in"), ("login.help", "Help")]),
+                bundle("fr", &[("login.title", "Connexion")]),
+            ],
+        );
+
+        assert_eq!(messages.locale, "fr");
+        assert_eq!(messages.message_or("login.title", ""), "Connexion");
+        assert_eq!(messages.message_or("login.help", ""), "Help");
+        assert_eq!(messages.message_or("login.missing", "Default"), "Default");
+    }
+
+    #[test]
+    fn interpolate_escapes_messages_and_keeps_unknown_references() {
+        let messages = LocalizedMessages::from_bundles(
+            vec!["en".to_string()],
+            vec![bundle("en", &[("greeting", "Hi <b>there</b>")])],
+        );
+
+        let result = messages.interpolate(
+            "{{ msg.greeting }}, {{user.first_name}} {{msg.unknown}}",
+            |m| m.replace('<', "&lt;").replace('>', "&gt;"),
+        );
+
+        assert_eq!(
+            result,
+            "Hi &lt;b&gt;there&lt;/b&gt;, {{user.first_name}} {{msg.unknown}}"
+        );
+    }
+
+    #[test]
+    fn localize_tree_replaces_nested_strings() {
+        let messages = LocalizedMessages::from_bundles(
+            vec!["ja".to_string()],
+            vec![bundle("ja", &[("login.title", "ログイン")])],
+        );
+        let mut tree = json!([
+            { "type": "heading", "props": { "text": "{{msg.login.title}}", "level": 1 } }
+        ]);
+
+        messages.localize_tree(&mut tree);
+
+        assert_eq!(tree[0]["props"]["text"], "ログイン");
+        assert_eq!(tree[0]["props"]["level"], 1);
+    }
+}
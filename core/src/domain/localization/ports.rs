@@ -0,0 +1,98 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    realm::entities::{Realm, RealmId},
+};
+
+use super::{
+    entities::{LocalizedMessages, MessageBundle, RealmLocalization},
+    value_objects::{
+        DeleteMessageBundleInput, GetLocalizationSettingsInput, ListMessageBundlesInput,
+        PutMessageBundleInput, ResolveMessagesInput, UpdateLocalizationSettingsInput,
+    },
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait LocalizationRepository: Send + Sync {
+    fn get_settings(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<RealmLocalization>, CoreError>> + Send;
+    fn upsert_settings(
+        &self,
+        settings: RealmLocalization,
+    ) -> impl Future<Output = Result<RealmLocalization, CoreError>> + Send;
+    fn list_bundles(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<MessageBundle>, CoreError>> + Send;
+    /// Bundles of the given locales that exist, in no particular order.
+    fn get_bundles(
+        &self,
+        realm_id: RealmId,
+        locales: Vec<String>,
+    ) -> impl Future<Output = Result<Vec<MessageBundle>, CoreError>> + Send;
+    fn upsert_bundle(
+        &self,
+        bundle: MessageBundle,
+    ) -> impl Future<Output = Result<MessageBundle, CoreError>> + Send;
+    /// Returns whether a bundle was deleted.
+    fn delete_bundle(
+        &self,
+        realm_id: RealmId,
+        locale: String,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    /// The user's `locale` attribute, if set.
+    fn get_user_locale(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Option<String>, CoreError>> + Send;
+}
+
+pub trait LocalizationService: Send + Sync {
+    fn get_localization_settings(
+        &self,
+        identity: Identity,
+        input: GetLocalizationSettingsInput,
+    ) -> impl Future<Output = Result<RealmLocalization, CoreError>> + Send;
+    fn update_localization_settings(
+        &self,
+        identity: Identity,
+        input: UpdateLocalizationSettingsInput,
+    ) -> impl Future<Output = Result<RealmLocalization, CoreError>> + Send;
+    fn list_message_bundles(
+        &self,
+        identity: Identity,
+        input: ListMessageBundlesInput,
+    ) -> impl Future<Output = Result<Vec<MessageBundle>, CoreError>> + Send;
+    fn put_message_bundle(
+        &self,
+        identity: Identity,
+        input: PutMessageBundleInput,
+    ) -> impl Future<Output = Result<MessageBundle, CoreError>> + Send;
+    fn delete_message_bundle(
+        &self,
+        identity: Identity,
+        input: DeleteMessageBundleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Public: messages for the login portal, no identity required.
+    fn resolve_messages(
+        &self,
+        input: ResolveMessagesInput,
+    ) -> impl Future<Output = Result<LocalizedMessages, CoreError>> + Send;
+}
+
+pub trait LocalizationPolicy: Send + Sync {
+    fn can_view_localization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_localization(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
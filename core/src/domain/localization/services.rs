@@ -0,0 +1,586 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    localization::{
+        entities::{LocalizedMessages, MessageBundle, RealmLocalization, normalize_locale},
+        ports::{LocalizationPolicy, LocalizationRepository, LocalizationService},
+        value_objects::{
+            DeleteMessageBundleInput, GetLocalizationSettingsInput, ListMessageBundlesInput,
+            LocaleHints, PutMessageBundleInput, ResolveMessagesInput,
+            UpdateLocalizationSettingsInput,
+        },
+    },
+    realm::{
+        entities::{Realm, RealmId},
+        ports::RealmRepository,
+    },
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// Locales a realm may declare.
+const MAX_SUPPORTED_LOCALES: usize = 50;
+
+/// Messages a single bundle may hold.
+const MAX_BUNDLE_MESSAGES: usize = 2000;
+
+const MAX_MESSAGE_KEY_LENGTH: usize = 128;
+
+const MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Resolves the messages for `hints` against the realm's settings and
+/// bundles.
+pub async fn resolve_localized_messages<L>(
+    localization_repository: &L,
+    realm_id: RealmId,
+    hints: &LocaleHints,
+) -> Result<LocalizedMessages, CoreError>
+where
+    L: LocalizationRepository,
+{
+    let settings = localization_repository
+        .get_settings(realm_id)
+        .await?
+        .unwrap_or_else(|| RealmLocalization::new(realm_id));
+
+    let chain = settings.fallback_chain(&hints.requested());
+    let bundles = localization_repository
+        .get_bundles(realm_id, chain.clone())
+        .await?;
+
+    Ok(LocalizedMessages::from_bundles(chain, bundles))
+}
+
+/// Messages for an email to `user_id`, following the user's `locale`
+/// attribute. Falls back to the unlocalized defaults rather than failing
+/// the email.
+pub async fn localize_for_user<L>(
+    localization_repository: &L,
+    realm_id: RealmId,
+    user_id: Uuid,
+) -> LocalizedMessages
+where
+    L: LocalizationRepository,
+{
+    let resolved = async {
+        let hints = LocaleHints {
+            user_locale: localization_repository.get_user_locale(user_id).await?,
+            ..Default::default()
+        };
+
+        resolve_localized_messages(localization_repository, realm_id, &hints).await
+    }
+    .await;
+
+    resolved.unwrap_or_else(|e| {
+        warn!(user_id = %user_id, "Failed to resolve email locale: {}", e);
+        LocalizedMessages::fallback()
+    })
+}
+
+fn parse_locale(tag: &str) -> Result<String, CoreError> {
+    normalize_locale(tag)
+        .ok_or_else(|| CoreError::InvalidLocalization(format!("invalid locale `{tag}`")))
+}
+
+fn validate_messages(messages: &BTreeMap<String, String>) -> Result<(), CoreError> {
+    if messages.len() > MAX_BUNDLE_MESSAGES {
+        return Err(CoreError::InvalidLocalization(format!(
+            "a bundle holds at most {MAX_BUNDLE_MESSAGES} messages"
+        )));
+    }
+
+    for (key, message) in messages {
+        let valid_key = !key.is_empty()
+            && key.len() <= MAX_MESSAGE_KEY_LENGTH
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+        if !valid_key {
+            return Err(CoreError::InvalidLocalization(format!(
+                "invalid message key `{key}`"
+            )));
+        }
+
+        if message.len() > MAX_MESSAGE_LENGTH {
+            return Err(CoreError::InvalidLocalization(format!(
+                "message `{key}` is longer than {MAX_MESSAGE_LENGTH} bytes"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+pub struct LocalizationServiceImpl<R, U, C, UR, L>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    L: LocalizationRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) localization_repository: Arc<L>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, L> LocalizationServiceImpl<R, U, C, UR, L>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    L: LocalizationRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        localization_repository: Arc<L>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            localization_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn get_settings(&self, realm_id: RealmId) -> Result<RealmLocalization, CoreError> {
+        Ok(self
+            .localization_repository
+            .get_settings(realm_id)
+            .await?
+            .unwrap_or_else(|| RealmLocalization::new(realm_id)))
+    }
+}
+
+impl<R, U, C, UR, L> LocalizationService for LocalizationServiceImpl<R, U, C, UR, L>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    L: LocalizationRepository,
+{
+    async fn get_localization_settings(
+        &self,
+        identity: Identity,
+        input: GetLocalizationSettingsInput,
+    ) -> Result<RealmLocalization, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_localization(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.get_settings(realm.id).await
+    }
+
+    async fn update_localization_settings(
+        &self,
+        identity: Identity,
+        input: UpdateLocalizationSettingsInput,
+    ) -> Result<RealmLocalization, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_localization(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let default_locale = parse_locale(&input.default_locale)?;
+        let mut supported_locales = vec![default_locale.clone()];
+        for tag in &input.supported_locales {
+            let locale = parse_locale(tag)?;
+            if !supported_locales.contains(&locale) {
+                supported_locales.push(locale);
+            }
+        }
+
+        if supported_locales.len() > MAX_SUPPORTED_LOCALES {
+            return Err(CoreError::InvalidLocalization(format!(
+                "a realm supports at most {MAX_SUPPORTED_LOCALES} locales"
+            )));
+        }
+
+        self.localization_repository
+            .upsert_settings(RealmLocalization {
+                realm_id: realm.id,
+                default_locale,
+                supported_locales,
+                updated_at: Utc::now(),
+            })
+            .await
+    }
+
+    async fn list_message_bundles(
+        &self,
+        identity: Identity,
+        input: ListMessageBundlesInput,
+    ) -> Result<Vec<MessageBundle>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_localization(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.localization_repository.list_bundles(realm.id).await
+    }
+
+    async fn put_message_bundle(
+        &self,
+        identity: Identity,
+        input: PutMessageBundleInput,
+    ) -> Result<MessageBundle, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_localization(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let locale = parse_locale(&input.locale)?;
+        if !self.get_settings(realm.id).await?.supports(&locale) {
+            return Err(CoreError::InvalidLocalization(format!(
+                "locale `{locale}` is not supported by the realm"
+            )));
+        }
+        validate_messages(&input.messages)?;
+
+        self.localization_repository
+            .upsert_bundle(MessageBundle {
+                realm_id: realm.id,
+                locale,
+                messages: input.messages,
+                updated_at: Utc::now(),
+            })
+            .await
+    }
+
+    async fn delete_message_bundle(
+        &self,
+        identity: Identity,
+        input: DeleteMessageBundleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_localization(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let locale = parse_locale(&input.locale)?;
+        if !self
+            .localization_repository
+            .delete_bundle(realm.id, locale)
+            .await?
+        {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn resolve_messages(
+        &self,
+        input: ResolveMessagesInput,
+    ) -> Result<LocalizedMessages, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        resolve_localized_messages(
+            self.localization_repository.as_ref(),
+            realm.id,
+            &input.hints,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        client::ports::MockClientRepository,
+        localization::ports::MockLocalizationRepository,
+        realm::ports::MockRealmRepository,
+        role::entities::Role,
+        user::{
+            entities::User,
+            ports::{MockUserRepository, MockUserRoleRepository},
+        },
+    };
+
+    type TestService = LocalizationServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockLocalizationRepository,
+    >;
+
+    fn test_realm() -> Realm {
+        Realm {
+            id: RealmId::new(Uuid::new_v4()),
+            name: "test-realm".to_string(),
+            settings: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn test_user(realm: &Realm) -> User {
+        User {
+            id: Uuid::new_v4(),
+            realm_id: realm.id,
+            username: "admin".to_string(),
+            firstname: None,
+            lastname: None,
+            email: Some("admin@test.com".to_string()),
+            email_verified: true,
+            enabled: true,
+            roles: None,
+            realm: Some(realm.clone()),
+            client_id: None,
+            required_actions: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn settings(realm: &Realm, default_locale: &str, supported: &[&str]) -> RealmLocalization {
+        RealmLocalization {
+            realm_id: realm.id,
+            default_locale: default_locale.to_string(),
+            supported_locales: supported.iter().map(|l| l.to_string()).collect(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn build_service(realm: &Realm, localization: MockLocalizationRepository) -> TestService {
+        let mut realm_repo = MockRealmRepository::new();
+        let realm_clone = realm.clone();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let realm = realm_clone.clone();
+            Box::pin(async move { Ok(Some(realm)) })
+        });
+
+        let user = test_user(realm);
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_get_by_id().returning(move |_| {
+            let user = user.clone();
+            Box::pin(async move { Ok(user) })
+        });
+
+        let role_realm_id = realm.id;
+        let mut user_role_repo = MockUserRoleRepository::new();
+        user_role_repo.expect_get_user_roles().returning(move |_| {
+            Box::pin(async move {
+                Ok(vec![Role {
+                    id: Uuid::new_v4(),
+                    name: "admin".to_string(),
+                    description: None,
+                    permissions: vec!["manage_realm".to_string()],
+                    realm_id: role_realm_id,
+                    client_id: None,
+                    client: None,
+                    created_at: Utc::now(),
+                    updated_at: Utc::now(),
+                }])
+            })
+        });
+
+        let policy = Arc::new(FerriskeyPolicy::new(
+            Arc::new(user_repo),
+            Arc::new(MockClientRepository::new()),
+            Arc::new(user_role_repo),
+        ));
+
+        LocalizationServiceImpl::new(Arc::new(realm_repo), Arc::new(localization), policy)
+    }
+
+    #[tokio::test]
+    async fn update_settings_normalizes_and_includes_default() {
+        let realm = test_realm();
+        let mut localization = MockLocalizationRepository::new();
+        localization
+            .expect_upsert_settings()
+            .returning(|settings| Box::pin(async move { Ok(settings) }));
+
+        let service = build_service(&realm, localization);
+        let settings = service
+            .update_localization_settings(
+                Identity::User(test_user(&realm)),
+                UpdateLocalizationSettingsInput {
+                    realm_name: realm.name.clone(),
+                    default_locale: "EN".to_string(),
+                    supported_locales: vec![
+                        "fr".to_string(),
+                        "de".to_string(),
+                        "ja".to_string(),
+                        "fr".to_string(),
+                    ],
+                },
+            )
+            .await
+            .expect("settings should be saved");
+
+        assert_eq!(settings.default_locale, "en");
+        assert_eq!(settings.supported_locales, vec!["en", "fr", "de", "ja"]);
+    }
+
+    #[tokio::test]
+    async fn update_settings_rejects_invalid_locale() {
+        let realm = test_realm();
+        let service = build_service(&realm, MockLocalizationRepository::new());
+
+        let result = service
+            .update_localization_settings(
+                Identity::User(test_user(&realm)),
+                UpdateLocalizationSettingsInput {
+                    realm_name: realm.name.clone(),
+                    default_locale: "en".to_string(),
+                    supported_locales: vec!["not a locale".to_string()],
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidLocalization(_))));
+    }
+
+    #[tokio::test]
+    async fn put_bundle_rejects_unsupported_locale() {
+        let realm = test_realm();
+        let realm_settings = settings(&realm, "en", &["en", "fr"]);
+        let mut localization = MockLocalizationRepository::new();
+        localization.expect_get_settings().returning(move |_| {
+            let settings = realm_settings.clone();
+            Box::pin(async move { Ok(Some(settings)) })
+        });
+
+        let service = build_service(&realm, localization);
+        let result = service
+            .put_message_bundle(
+                Identity::User(test_user(&realm)),
+                PutMessageBundleInput {
+                    realm_name: realm.name.clone(),
+                    locale: "ja".to_string(),
+                    messages: BTreeMap::new(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidLocalization(_))));
+    }
+
+    #[tokio::test]
+    async fn put_bundle_rejects_invalid_message_keys() {
+        let realm = test_realm();
+        let mut localization = MockLocalizationRepository::new();
+        localization
+            .expect_get_settings()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let service = build_service(&realm, localization);
+        let result = service
+            .put_message_bundle(
+                Identity::User(test_user(&realm)),
+                PutMessageBundleInput {
+                    realm_name: realm.name.clone(),
+                    locale: "en".to_string(),
+                    messages: BTreeMap::from([("login title".to_string(), "Sign in".to_string())]),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidLocalization(_))));
+    }
+
+    #[tokio::test]
+    async fn resolve_messages_follows_fallback_chain() {
+        let realm = test_realm();
+        let realm_settings = settings(&realm, "en", &["en", "fr", "de", "ja"]);
+        let realm_id = realm.id;
+        let mut localization = MockLocalizationRepository::new();
+        localization.expect_get_settings().returning(move |_| {
+            let settings = realm_settings.clone();
+            Box::pin(async move { Ok(Some(settings)) })
+        });
+        localization
+            .expect_get_bundles()
+            .withf(|_, locales| locales == &["fr", "ja", "en"])
+            .returning(move |_, _| {
+                Box::pin(async move {
+                    Ok(vec![
+                        MessageBundle {
+                            realm_id,
+                            locale: "en".to_string(),
+                            messages: BTreeMap::from([
+                                ("login.title".to_string(), "Sign in".to_string()),
+                                ("login.submit".to_string(), "Continue".to_string()),
+                            ]),
+                            updated_at: Utc::now(),
+                        },
+                        MessageBundle {
+                            realm_id,
+                            locale: "fr".to_string(),
+                            messages: BTreeMap::from([(
+                                "login.title".to_string(),
+                                "Connexion".to_string(),
+                            )]),
+                            updated_at: Utc::now(),
+                        },
+                    ])
+                })
+            });
+
+        let service = build_service(&realm, localization);
+        let messages = service
+            .resolve_messages(ResolveMessagesInput {
+                realm_name: realm.name.clone(),
+                hints: LocaleHints {
+                    ui_locales: Some("fr-CA".to_string()),
+                    user_locale: None,
+                    accept_language: Some("ja".to_string()),
+                },
+            })
+            .await;
+
+        // ui_locales wins over Accept-Language.
+        let messages = messages.expect("messages should resolve");
+        assert_eq!(messages.locale, "fr");
+        assert_eq!(messages.messages["login.title"], "Connexion");
+        assert_eq!(messages.messages["login.submit"], "Continue");
+    }
+
+    #[tokio::test]
+    async fn localize_for_user_falls_back_on_errors() {
+        let mut localization = MockLocalizationRepository::new();
+        localization
+            .expect_get_user_locale()
+            .returning(|_| Box::pin(async { Err(CoreError::Database("down".to_string())) }));
+
+        let messages =
+            localize_for_user(&localization, RealmId::new(Uuid::new_v4()), Uuid::new_v4()).await;
+
+        assert_eq!(messages, LocalizedMessages::fallback());
+    }
+}
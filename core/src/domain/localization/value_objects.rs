@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use super::entities::parse_accept_language;
+
+/// What a request tells about the locale it wants, in priority order.
+#[derive(Debug, Clone, Default)]
+pub struct LocaleHints {
+    /// OIDC `ui_locales`: space-separated language tags.
+    pub ui_locales: Option<String>,
+    /// The user's `locale` attribute.
+    pub user_locale: Option<String>,
+    /// Raw `Accept-Language` header.
+    pub accept_language: Option<String>,
+}
+
+impl LocaleHints {
+    /// Requested language tags, most preferred first.
+    pub fn requested(&self) -> Vec<String> {
+        let mut requested: Vec<String> = self
+            .ui_locales
+            .iter()
+            .flat_map(|locales| locales.split_whitespace().map(str::to_string))
+            .collect();
+        requested.extend(self.user_locale.clone());
+        requested.extend(
+            self.accept_language
+                .as_deref()
+                .map(parse_accept_language)
+                .unwrap_or_default(),
+        );
+
+        requested
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetLocalizationSettingsInput {
+    pub realm_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateLocalizationSettingsInput {
+    pub realm_name: String,
+    pub default_locale: String,
+    pub supported_locales: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListMessageBundlesInput {
+    pub realm_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PutMessageBundleInput {
+    pub realm_name: String,
+    pub locale: String,
+    pub messages: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteMessageBundleInput {
+    pub realm_name: String,
+    pub locale: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResolveMessagesInput {
+    pub realm_name: String,
+    pub hints: LocaleHints,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requested_orders_ui_locales_then_user_then_header() {
+        let hints = LocaleHints {
+            ui_locales: Some("ja  fr-CA".to_string()),
+            user_locale: Some("de".to_string()),
+            accept_language: Some("en;q=0.5, fr".to_string()),
+        };
+
+        assert_eq!(hints.requested(), vec!["ja", "fr-CA", "de", "fr", "en"]);
+    }
+}
@@ -1,3 +1,4 @@
 pub use ferriskey_domain::maintenance::ports::{
-    MaintenanceService, MaintenanceWhitelistRepository, RealmMaintenanceWhitelistRepository,
+    MaintenanceService, MaintenanceSessionRepository, MaintenanceWhitelistRepository,
+    MaintenanceWindowRepository, RealmMaintenanceRepository, RealmMaintenanceWhitelistRepository,
 };
@@ -1,36 +1,47 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use serde_json::json;
-use tracing::info;
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::domain::{
     authentication::value_objects::Identity,
-    client::ports::ClientRepository,
+    client::{entities::Client, ports::ClientRepository},
     common::{
         entities::app_errors::CoreError,
         policies::{FerriskeyPolicy, ensure_policy},
     },
     maintenance::{
-        entities::{MaintenanceWhitelistEntry, RealmMaintenanceWhitelistEntry},
-        ports::{MaintenanceWhitelistRepository, RealmMaintenanceWhitelistRepository},
-        value_objects::ToggleMaintenanceRequest,
+        entities::{
+            MaintenanceWhitelistEntry, MaintenanceWindow, MaintenanceWindowStatus,
+            RealmMaintenance, RealmMaintenanceWhitelistEntry,
+        },
+        ports::{
+            MaintenanceSessionRepository, MaintenanceWhitelistRepository,
+            MaintenanceWindowRepository, RealmMaintenanceRepository,
+            RealmMaintenanceWhitelistRepository,
+        },
+        value_objects::{ScheduleMaintenanceWindowRequest, ToggleMaintenanceRequest},
     },
-    realm::ports::RealmRepository,
-    seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
+    realm::ports::{RealmPolicy, RealmRepository},
+    seawatch::{ActorType, EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
     user::ports::{UserRepository, UserRoleRepository},
     webhook::{
         entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
         ports::WebhookRepository,
     },
 };
+use ferriskey_domain::client::entities::MaintenanceSessionStrategy;
 use ferriskey_domain::client::ports::ClientPolicy;
 use ferriskey_domain::client::value_objects::UpdateClientRequest;
 use ferriskey_domain::maintenance::ports::MaintenanceService;
 use ferriskey_domain::realm::RealmId;
 
+const DEFAULT_MAINTENANCE_REASON: &str = "This service is currently under maintenance";
+
 #[derive(Clone, Debug)]
-pub struct MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW>
+pub struct MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW, RM, MWR, MSR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -40,17 +51,25 @@ where
     SE: SecurityEventRepository,
     MW: MaintenanceWhitelistRepository,
     RMW: RealmMaintenanceWhitelistRepository,
+    RM: RealmMaintenanceRepository,
+    MWR: MaintenanceWindowRepository,
+    MSR: MaintenanceSessionRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) client_repository: Arc<C>,
+    pub(crate) user_role_repository: Arc<UR>,
     pub(crate) webhook_repository: Arc<W>,
     pub(crate) security_event_repository: Arc<SE>,
     pub(crate) maintenance_whitelist_repository: Arc<MW>,
     pub(crate) realm_maintenance_whitelist_repository: Arc<RMW>,
+    pub(crate) realm_maintenance_repository: Arc<RM>,
+    pub(crate) maintenance_window_repository: Arc<MWR>,
+    pub(crate) maintenance_session_repository: Arc<MSR>,
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, W, SE, MW, RMW> MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW>
+impl<R, U, C, UR, W, SE, MW, RMW, RM, MWR, MSR>
+    MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW, RM, MWR, MSR>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -60,64 +79,76 @@ where
     SE: SecurityEventRepository,
     MW: MaintenanceWhitelistRepository,
     RMW: RealmMaintenanceWhitelistRepository,
+    RM: RealmMaintenanceRepository,
+    MWR: MaintenanceWindowRepository,
+    MSR: MaintenanceSessionRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         realm_repository: Arc<R>,
         client_repository: Arc<C>,
+        user_role_repository: Arc<UR>,
         webhook_repository: Arc<W>,
         security_event_repository: Arc<SE>,
         maintenance_whitelist_repository: Arc<MW>,
         realm_maintenance_whitelist_repository: Arc<RMW>,
+        realm_maintenance_repository: Arc<RM>,
+        maintenance_window_repository: Arc<MWR>,
+        maintenance_session_repository: Arc<MSR>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
             realm_repository,
             client_repository,
+            user_role_repository,
             webhook_repository,
             security_event_repository,
             maintenance_whitelist_repository,
             realm_maintenance_whitelist_repository,
+            realm_maintenance_repository,
+            maintenance_window_repository,
+            maintenance_session_repository,
             policy,
         }
     }
-}
 
-impl<R, U, C, UR, W, SE, MW, RMW> MaintenanceService
-    for MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW>
-where
-    R: RealmRepository,
-    U: UserRepository,
-    C: ClientRepository,
-    UR: UserRoleRepository,
-    W: WebhookRepository,
-    SE: SecurityEventRepository,
-    MW: MaintenanceWhitelistRepository,
-    RMW: RealmMaintenanceWhitelistRepository,
-{
-    async fn toggle_maintenance(
+    async fn is_realm_whitelisted(
         &self,
-        identity: Identity,
-        realm_name: String,
-        client_id: Uuid,
-        request: ToggleMaintenanceRequest,
-    ) -> Result<(), CoreError> {
-        let realm = self
-            .realm_repository
-            .get_by_name(&realm_name)
-            .await?
-            .ok_or(CoreError::InvalidRealm)?;
+        realm_id: RealmId,
+        user_id: Uuid,
+        user_role_ids: &[Uuid],
+    ) -> Result<bool, CoreError> {
+        let realm_user_ids = self
+            .realm_maintenance_whitelist_repository
+            .get_whitelisted_user_ids(realm_id)
+            .await?;
+        if realm_user_ids.contains(&user_id) {
+            return Ok(true);
+        }
 
-        ensure_policy(
-            self.policy.can_update_client(&identity, &realm).await,
-            "insufficient permissions to toggle maintenance",
-        )?;
+        let realm_role_ids = self
+            .realm_maintenance_whitelist_repository
+            .get_whitelisted_role_ids(realm_id)
+            .await?;
+        Ok(user_role_ids.iter().any(|r| realm_role_ids.contains(r)))
+    }
 
-        let client = self
-            .client_repository
-            .get_by_id(client_id)
-            .await
-            .map_err(|_| CoreError::ClientNotFound)?;
+    /// Switches maintenance on or off for a client, applies the session
+    /// strategy and fires the audit event and webhook. `actor` is `None` when
+    /// a maintenance window triggered the change.
+    async fn set_client_maintenance(
+        &self,
+        client: Client,
+        enabled: bool,
+        reason: Option<String>,
+        session_strategy: Option<MaintenanceSessionStrategy>,
+        actor: Option<Uuid>,
+    ) -> Result<(), CoreError> {
+        let realm_id = client.realm_id;
+        let client_id = client.id;
+        let strategy = session_strategy
+            .clone()
+            .unwrap_or_else(|| client.maintenance_session_strategy.clone());
 
         let update = UpdateClientRequest {
             name: None,
@@ -129,9 +160,9 @@ where
             refresh_token_lifetime: None,
             id_token_lifetime: None,
             temporary_token_lifetime: None,
-            maintenance_enabled: Some(request.enabled),
-            maintenance_reason: Some(request.reason.clone()),
-            maintenance_session_strategy: request.session_strategy.clone(),
+            maintenance_enabled: Some(enabled),
+            maintenance_reason: Some(reason.clone()),
+            maintenance_session_strategy: session_strategy,
         };
 
         self.client_repository
@@ -139,7 +170,15 @@ where
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let (event_type, trigger) = if request.enabled {
+        let terminated = if enabled && strategy == MaintenanceSessionStrategy::Terminate {
+            self.maintenance_session_repository
+                .terminate_client_sessions(&client)
+                .await?
+        } else {
+            0
+        };
+
+        let (event_type, trigger) = if enabled {
             (
                 SecurityEventType::ClientMaintenanceEnabled,
                 WebhookTrigger::ClientMaintenanceEnabled,
@@ -152,38 +191,218 @@ where
         };
 
         info!(
-            "Maintenance mode {} for client {} ({})",
-            if request.enabled {
-                "enabled"
-            } else {
-                "disabled"
-            },
+            "Maintenance mode {} for client {} ({}), {} sessions terminated",
+            if enabled { "enabled" } else { "disabled" },
             client.name,
-            client_id
+            client_id,
+            terminated
         );
 
         self.security_event_repository
             .store_event(
-                SecurityEvent::new(realm.id, event_type, EventStatus::Success, identity.id())
+                maintenance_event(realm_id, event_type, actor)
                     .with_target("client".to_string(), client_id, None)
                     .with_details(json!({
-                        "enabled": request.enabled,
-                        "reason": request.reason,
-                        "session_strategy": request.session_strategy,
+                        "enabled": enabled,
+                        "reason": reason,
+                        "session_strategy": strategy,
+                        "terminated": terminated,
                     })),
             )
             .await?;
 
         self.webhook_repository
             .notify(
-                realm.id,
-                WebhookPayload::new(trigger, realm.id.into(), Some(client)),
+                realm_id,
+                WebhookPayload::new(trigger, realm_id.into(), Some(client)),
             )
             .await?;
 
         Ok(())
     }
 
+    /// Realm-wide counterpart of [`Self::set_client_maintenance`].
+    async fn set_realm_maintenance(
+        &self,
+        realm_id: RealmId,
+        enabled: bool,
+        reason: Option<String>,
+        session_strategy: Option<MaintenanceSessionStrategy>,
+        actor: Option<Uuid>,
+    ) -> Result<RealmMaintenance, CoreError> {
+        let current = self
+            .realm_maintenance_repository
+            .get_by_realm_id(realm_id)
+            .await?
+            .unwrap_or_else(|| RealmMaintenance::disabled(realm_id));
+
+        let maintenance = self
+            .realm_maintenance_repository
+            .upsert(RealmMaintenance {
+                realm_id,
+                enabled,
+                reason,
+                session_strategy: session_strategy.unwrap_or(current.session_strategy),
+                updated_at: Utc::now(),
+            })
+            .await?;
+
+        let terminated =
+            if enabled && maintenance.session_strategy == MaintenanceSessionStrategy::Terminate {
+                self.maintenance_session_repository
+                    .terminate_realm_sessions(realm_id)
+                    .await?
+            } else {
+                0
+            };
+
+        let (event_type, trigger) = if enabled {
+            (
+                SecurityEventType::RealmMaintenanceEnabled,
+                WebhookTrigger::RealmMaintenanceEnabled,
+            )
+        } else {
+            (
+                SecurityEventType::RealmMaintenanceDisabled,
+                WebhookTrigger::RealmMaintenanceDisabled,
+            )
+        };
+
+        info!(
+            "Maintenance mode {} for realm {}, {} sessions terminated",
+            if enabled { "enabled" } else { "disabled" },
+            Uuid::from(realm_id),
+            terminated
+        );
+
+        self.security_event_repository
+            .store_event(
+                maintenance_event(realm_id, event_type, actor).with_details(json!({
+                    "enabled": maintenance.enabled,
+                    "reason": maintenance.reason,
+                    "session_strategy": maintenance.session_strategy,
+                    "terminated": terminated,
+                })),
+            )
+            .await?;
+
+        self.webhook_repository
+            .notify(
+                realm_id,
+                WebhookPayload::new(trigger, realm_id.into(), Some(maintenance.clone())),
+            )
+            .await?;
+
+        Ok(maintenance)
+    }
+
+    async fn apply_window(
+        &self,
+        window: &MaintenanceWindow,
+        enabled: bool,
+        actor: Option<Uuid>,
+    ) -> Result<(), CoreError> {
+        let reason = if enabled { window.reason.clone() } else { None };
+        let session_strategy = Some(window.session_strategy.clone());
+
+        match window.client_id {
+            Some(client_id) => {
+                let client = self.client_repository.get_by_id(client_id).await?;
+                self.set_client_maintenance(client, enabled, reason, session_strategy, actor)
+                    .await
+            }
+            None => self
+                .set_realm_maintenance(window.realm_id, enabled, reason, session_strategy, actor)
+                .await
+                .map(|_| ()),
+        }
+    }
+
+    async fn ensure_can_manage_window(
+        &self,
+        identity: &Identity,
+        realm: &crate::domain::realm::entities::Realm,
+        client_id: Option<Uuid>,
+    ) -> Result<(), CoreError> {
+        match client_id {
+            Some(_) => ensure_policy(
+                self.policy.can_update_client(identity, realm).await,
+                "insufficient permissions to manage maintenance windows",
+            ),
+            None => ensure_policy(
+                self.policy.can_update_realm(identity, realm).await,
+                "insufficient permissions to manage maintenance windows",
+            ),
+        }
+    }
+}
+
+/// Security event for a maintenance change, attributed to the system when
+/// a maintenance window triggered it.
+fn maintenance_event(
+    realm_id: RealmId,
+    event_type: SecurityEventType,
+    actor: Option<Uuid>,
+) -> SecurityEvent {
+    match actor {
+        Some(actor_id) => SecurityEvent::new(realm_id, event_type, EventStatus::Success, actor_id),
+        None => SecurityEvent {
+            actor_id: None,
+            actor_type: Some(ActorType::System),
+            ..SecurityEvent::new(realm_id, event_type, EventStatus::Success, Uuid::nil())
+        },
+    }
+}
+
+impl<R, U, C, UR, W, SE, MW, RMW, RM, MWR, MSR> MaintenanceService
+    for MaintenanceServiceImpl<R, U, C, UR, W, SE, MW, RMW, RM, MWR, MSR>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    W: WebhookRepository,
+    SE: SecurityEventRepository,
+    MW: MaintenanceWhitelistRepository,
+    RMW: RealmMaintenanceWhitelistRepository,
+    RM: RealmMaintenanceRepository,
+    MWR: MaintenanceWindowRepository,
+    MSR: MaintenanceSessionRepository,
+{
+    async fn toggle_maintenance(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        client_id: Uuid,
+        request: ToggleMaintenanceRequest,
+    ) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy.can_update_client(&identity, &realm).await,
+            "insufficient permissions to toggle maintenance",
+        )?;
+
+        let client = self
+            .client_repository
+            .get_by_id(client_id)
+            .await
+            .map_err(|_| CoreError::ClientNotFound)?;
+
+        self.set_client_maintenance(
+            client,
+            request.enabled,
+            request.reason,
+            request.session_strategy,
+            Some(identity.id()),
+        )
+        .await
+    }
+
     async fn is_user_allowed(
         &self,
         client_id: Uuid,
@@ -207,23 +426,243 @@ where
             return Ok(true);
         }
 
-        let realm_user_ids = self
-            .realm_maintenance_whitelist_repository
-            .get_whitelisted_user_ids(realm_id)
+        self.is_realm_whitelisted(realm_id, user_id, user_role_ids)
+            .await
+    }
+
+    async fn ensure_access_allowed(
+        &self,
+        realm_id: RealmId,
+        client: Option<&Client>,
+        user_id: Uuid,
+    ) -> Result<(), CoreError> {
+        let realm_maintenance = self
+            .realm_maintenance_repository
+            .get_by_realm_id(realm_id)
+            .await?
+            .filter(|maintenance| maintenance.enabled);
+        let client = client.filter(|client| client.maintenance_enabled);
+
+        if realm_maintenance.is_none() && client.is_none() {
+            return Ok(());
+        }
+
+        let role_ids: Vec<Uuid> = self
+            .user_role_repository
+            .get_user_roles(user_id)
+            .await?
+            .iter()
+            .map(|role| role.id)
+            .collect();
+
+        // Realm-wide maintenance only lets the realm whitelist through; the
+        // client whitelists apply to their own client's maintenance.
+        if let Some(maintenance) = realm_maintenance
+            && !self
+                .is_realm_whitelisted(realm_id, user_id, &role_ids)
+                .await?
+        {
+            warn!("User {} denied access (realm maintenance mode)", user_id);
+            return Err(CoreError::ClientUnderMaintenance(
+                maintenance
+                    .reason
+                    .unwrap_or_else(|| DEFAULT_MAINTENANCE_REASON.to_string()),
+            ));
+        }
+
+        if let Some(client) = client
+            && !self
+                .is_user_allowed(client.id, realm_id, user_id, &role_ids)
+                .await?
+        {
+            warn!(
+                "User {} denied access to client {} (maintenance mode)",
+                user_id, client.name
+            );
+            return Err(CoreError::ClientUnderMaintenance(
+                client
+                    .maintenance_reason
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_MAINTENANCE_REASON.to_string()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn get_realm_maintenance(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<RealmMaintenance, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        ensure_policy(
+            self.policy.can_view_realm(&identity, &realm).await,
+            "insufficient permissions to view realm maintenance",
+        )?;
+
+        Ok(self
+            .realm_maintenance_repository
+            .get_by_realm_id(realm.id)
+            .await?
+            .unwrap_or_else(|| RealmMaintenance::disabled(realm.id)))
+    }
+
+    async fn toggle_realm_maintenance(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ToggleMaintenanceRequest,
+    ) -> Result<RealmMaintenance, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        ensure_policy(
+            self.policy.can_update_realm(&identity, &realm).await,
+            "insufficient permissions to toggle realm maintenance",
+        )?;
+
+        self.set_realm_maintenance(
+            realm.id,
+            request.enabled,
+            request.reason,
+            request.session_strategy,
+            Some(identity.id()),
+        )
+        .await
+    }
+
+    async fn list_maintenance_windows(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        ensure_policy(
+            self.policy.can_view_realm(&identity, &realm).await,
+            "insufficient permissions to view maintenance windows",
+        )?;
+
+        self.maintenance_window_repository
+            .list_by_realm_id(realm.id)
+            .await
+    }
+
+    async fn schedule_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ScheduleMaintenanceWindowRequest,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+        self.ensure_can_manage_window(&identity, &realm, request.client_id)
             .await?;
-        if realm_user_ids.contains(&user_id) {
-            return Ok(true);
+
+        if request.ends_at <= request.starts_at || request.ends_at <= Utc::now() {
+            return Err(CoreError::Invalid);
         }
 
-        let realm_role_ids = self
-            .realm_maintenance_whitelist_repository
-            .get_whitelisted_role_ids(realm_id)
+        if let Some(client_id) = request.client_id {
+            let client = self
+                .client_repository
+                .get_by_id(client_id)
+                .await
+                .map_err(|_| CoreError::ClientNotFound)?;
+            if client.realm_id != realm.id {
+                return Err(CoreError::ClientNotFound);
+            }
+        }
+
+        self.maintenance_window_repository
+            .create(MaintenanceWindow::new(
+                realm.id,
+                request.client_id,
+                request.reason,
+                request.session_strategy.unwrap_or_default(),
+                request.starts_at,
+                request.ends_at,
+            ))
+            .await
+    }
+
+    async fn cancel_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        window_id: Uuid,
+    ) -> Result<MaintenanceWindow, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let window = self
+            .maintenance_window_repository
+            .get_by_id(window_id)
+            .await?
+            .filter(|window| window.realm_id == realm.id)
+            .ok_or(CoreError::NotFound)?;
+        self.ensure_can_manage_window(&identity, &realm, window.client_id)
             .await?;
-        if user_role_ids.iter().any(|r| realm_role_ids.contains(r)) {
-            return Ok(true);
+
+        let cancelled = self.maintenance_window_repository.cancel(window_id).await?;
+
+        // Cancelling a running window ends its maintenance right away.
+        if window.status == MaintenanceWindowStatus::Active {
+            self.apply_window(&window, false, Some(identity.id()))
+                .await?;
+        }
+
+        Ok(cancelled)
+    }
+
+    async fn apply_due_maintenance_windows(&self, now: DateTime<Utc>) -> Result<usize, CoreError> {
+        let mut applied = 0;
+
+        // Ends go first so back-to-back windows leave maintenance enabled.
+        for (enabled, windows) in [
+            (
+                false,
+                self.maintenance_window_repository
+                    .claim_due_ends(now)
+                    .await?,
+            ),
+            (
+                true,
+                self.maintenance_window_repository
+                    .claim_due_starts(now)
+                    .await?,
+            ),
+        ] {
+            for window in windows {
+                // One failing window must not keep the others from applying.
+                match self.apply_window(&window, enabled, None).await {
+                    Ok(()) => applied += 1,
+                    Err(e) => error!(
+                        "Failed to {} maintenance window {}: {e}",
+                        if enabled { "start" } else { "end" },
+                        window.id
+                    ),
+                }
+            }
         }
 
-        Ok(false)
+        Ok(applied)
     }
 
     async fn add_client_whitelist_user(
@@ -1,25 +1,37 @@
 pub mod abyss;
 pub mod account;
 pub mod aegis;
+pub mod auth_flow;
 pub mod authentication;
 pub mod client;
 pub mod common;
 pub mod compass;
 pub mod credential;
 pub mod crypto;
+pub mod email_outbox;
 pub mod email_template;
 pub mod email_verification;
 pub mod health;
+pub mod housekeeping;
 pub mod jwt;
+pub mod localization;
 pub mod maintenance;
+pub mod one_time_code;
 pub mod organization;
+pub mod otp;
 pub mod password_policy;
 pub mod portal_layouts;
 pub mod portal_theme;
+pub mod privacy;
 pub mod realm;
+pub mod risk;
 pub mod role;
+pub mod scim;
 pub mod seawatch;
+pub mod secrets;
+pub mod security_notification;
 pub mod session;
 pub mod trident;
+pub mod trusted_device;
 pub mod user;
 pub mod webhook;
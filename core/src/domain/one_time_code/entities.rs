@@ -0,0 +1,327 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    common::{entities::app_errors::CoreError, generate_uuid_v7},
+    credential::entities::CredentialType,
+    realm::entities::RealmId,
+    user::entities::RequiredAction,
+};
+
+/// Digits of a delivered code.
+pub const CODE_DIGITS: u32 = 6;
+
+/// How long a delivered code can be used.
+pub const CODE_TTL: TimeDelta = TimeDelta::minutes(5);
+
+/// Wrong guesses after which a code is discarded.
+pub const MAX_ATTEMPTS: u32 = 5;
+
+/// Shortest delay between two codes sent for the same purpose.
+pub const RESEND_INTERVAL: TimeDelta = TimeDelta::seconds(30);
+
+/// Codes a user can be sent on a channel within [`SEND_WINDOW`].
+pub const MAX_SENDS_PER_WINDOW: u64 = 5;
+pub const SEND_WINDOW: TimeDelta = TimeDelta::minutes(15);
+
+/// Where one-time codes are delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OneTimeCodeChannel {
+    Email,
+    Sms,
+}
+
+impl OneTimeCodeChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OneTimeCodeChannel::Email => "email",
+            OneTimeCodeChannel::Sms => "sms",
+        }
+    }
+
+    /// Credential recording that a user receives codes on this channel.
+    pub fn credential_type(&self) -> CredentialType {
+        match self {
+            OneTimeCodeChannel::Email => CredentialType::EmailOtp,
+            OneTimeCodeChannel::Sms => CredentialType::SmsOtp,
+        }
+    }
+
+    /// Required action asking a user to enroll this channel.
+    pub fn required_action(&self) -> RequiredAction {
+        match self {
+            OneTimeCodeChannel::Email => RequiredAction::ConfigureEmailOtp,
+            OneTimeCodeChannel::Sms => RequiredAction::ConfigureSmsOtp,
+        }
+    }
+}
+
+impl Display for OneTimeCodeChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OneTimeCodeChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "email" => Ok(OneTimeCodeChannel::Email),
+            "sms" => Ok(OneTimeCodeChannel::Sms),
+            other => Err(format!("unknown one-time code channel: {other}")),
+        }
+    }
+}
+
+/// What a delivered code is checked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OneTimeCodePurpose {
+    /// Proves the user receives codes at a new destination, e.g. verifies a
+    /// phone number.
+    Enrollment,
+    /// Second factor of a sign-in.
+    SignIn,
+}
+
+impl OneTimeCodePurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OneTimeCodePurpose::Enrollment => "enrollment",
+            OneTimeCodePurpose::SignIn => "sign_in",
+        }
+    }
+}
+
+impl Display for OneTimeCodePurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OneTimeCodePurpose {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "enrollment" => Ok(OneTimeCodePurpose::Enrollment),
+            "sign_in" => Ok(OneTimeCodePurpose::SignIn),
+            other => Err(format!("unknown one-time code purpose: {other}")),
+        }
+    }
+}
+
+/// A code sent by email or SMS. Only its hash is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OneTimeCode {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub user_id: Uuid,
+    pub channel: OneTimeCodeChannel,
+    pub purpose: OneTimeCodePurpose,
+    /// Email address or E.164 phone number the code was sent to.
+    pub destination: String,
+    pub code_hash: String,
+    /// Wrong codes submitted so far.
+    pub attempts: u32,
+    pub expires_at: DateTime<Utc>,
+    pub consumed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OneTimeCode {
+    /// A new code for `destination`, along with its clear value to deliver.
+    pub fn issue(
+        realm_id: RealmId,
+        user_id: Uuid,
+        channel: OneTimeCodeChannel,
+        purpose: OneTimeCodePurpose,
+        destination: String,
+        now: DateTime<Utc>,
+    ) -> (Self, String) {
+        let id = generate_uuid_v7();
+        let code = format!(
+            "{:0width$}",
+            rand::thread_rng().gen_range(0..10u32.pow(CODE_DIGITS)),
+            width = CODE_DIGITS as usize
+        );
+
+        (
+            Self {
+                id,
+                realm_id,
+                user_id,
+                channel,
+                purpose,
+                destination,
+                code_hash: hash_code(id, &code),
+                attempts: 0,
+                expires_at: now + CODE_TTL,
+                consumed_at: None,
+                created_at: now,
+            },
+            code,
+        )
+    }
+
+    /// Whether the code can still be submitted at `now`.
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.consumed_at.is_none() && self.expires_at > now && self.attempts < MAX_ATTEMPTS
+    }
+
+    pub fn matches(&self, code: &str) -> bool {
+        hash_code(self.id, code.trim())
+            .as_bytes()
+            .ct_eq(self.code_hash.as_bytes())
+            .into()
+    }
+}
+
+/// Salted with the code id, so equal codes do not share a hash.
+fn hash_code(id: Uuid, code: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(id.as_bytes());
+    hasher.update(code.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Normalizes a phone number to E.164, dropping the spaces, dots, dashes
+/// and parentheses people type in them.
+pub fn normalize_phone_number(input: &str) -> Result<String, CoreError> {
+    let digits: String = input
+        .trim()
+        .strip_prefix('+')
+        .ok_or(CoreError::InvalidPhoneNumber)?
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '.' | '-' | '(' | ')'))
+        .collect();
+
+    let valid = (8..=15).contains(&digits.len())
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && !digits.starts_with('0');
+
+    if !valid {
+        return Err(CoreError::InvalidPhoneNumber);
+    }
+
+    Ok(format!("+{digits}"))
+}
+
+/// Hides most of a destination, enough for the user to recognize it.
+pub fn mask_destination(channel: OneTimeCodeChannel, destination: &str) -> String {
+    match channel {
+        OneTimeCodeChannel::Email => match destination.split_once('@') {
+            Some((local, domain)) => {
+                let first: String = local.chars().take(1).collect();
+                format!("{first}***@{domain}")
+            }
+            None => "***".to_string(),
+        },
+        OneTimeCodeChannel::Sms => {
+            let visible = destination.len().saturating_sub(4);
+            format!("+***{}", &destination[visible..])
+        }
+    }
+}
+
+/// Where and until when a code was sent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OneTimeCodeDelivery {
+    pub channel: OneTimeCodeChannel,
+    /// Masked destination.
+    pub destination: String,
+    pub expires_at: DateTime<Utc>,
+    /// Another code cannot be requested before this.
+    pub resend_available_at: DateTime<Utc>,
+}
+
+/// A channel the user enrolled to receive sign-in codes on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OneTimeCodeMethod {
+    pub credential_id: Uuid,
+    pub channel: OneTimeCodeChannel,
+    /// Masked destination.
+    pub destination: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sms_code(now: DateTime<Utc>) -> (OneTimeCode, String) {
+        OneTimeCode::issue(
+            RealmId::default(),
+            Uuid::new_v4(),
+            OneTimeCodeChannel::Sms,
+            OneTimeCodePurpose::SignIn,
+            "+33612345678".to_string(),
+            now,
+        )
+    }
+
+    #[test]
+    fn issued_code_matches_only_its_value() {
+        let (code, value) = sms_code(Utc::now());
+
+        assert_eq!(value.len(), CODE_DIGITS as usize);
+        assert!(code.matches(&value));
+        assert!(!code.matches("not-it"));
+        assert_ne!(code.code_hash, value);
+    }
+
+    #[test]
+    fn code_stops_being_usable() {
+        let now = Utc::now();
+        let (code, _) = sms_code(now);
+        assert!(code.is_usable(now));
+
+        assert!(!code.is_usable(now + CODE_TTL));
+        assert!(
+            !OneTimeCode {
+                attempts: MAX_ATTEMPTS,
+                ..code.clone()
+            }
+            .is_usable(now)
+        );
+        assert!(
+            !OneTimeCode {
+                consumed_at: Some(now),
+                ..code
+            }
+            .is_usable(now)
+        );
+    }
+
+    #[test]
+    fn normalizes_phone_numbers() {
+        assert_eq!(
+            normalize_phone_number(" +33 (6) 12-34.56.78 ").unwrap(),
+            "+33612345678"
+        );
+        assert!(normalize_phone_number("0612345678").is_err());
+        assert!(normalize_phone_number("+0612345678").is_err());
+        assert!(normalize_phone_number("+33abc45678").is_err());
+        assert!(normalize_phone_number("+1234").is_err());
+    }
+
+    #[test]
+    fn masks_destinations() {
+        assert_eq!(
+            mask_destination(OneTimeCodeChannel::Email, "jane@example.com"),
+            "j***@example.com"
+        );
+        assert_eq!(
+            mask_destination(OneTimeCodeChannel::Sms, "+33612345678"),
+            "+***5678"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity, common::entities::app_errors::CoreError,
+    trident::ports::ChallengeOtpOutput,
+};
+
+use super::{
+    entities::{
+        OneTimeCode, OneTimeCodeChannel, OneTimeCodeDelivery, OneTimeCodeMethod, OneTimeCodePurpose,
+    },
+    value_objects::{
+        ChallengeOneTimeCodeInput, ConfirmOneTimeCodeEnrollmentInput,
+        SendOneTimeCodeEnrollmentInput, SendOneTimeCodeInput,
+    },
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait OneTimeCodeRepository: Send + Sync {
+    fn create(&self, code: OneTimeCode) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// The code sent last to the user for `purpose`, used or not.
+    fn get_latest(
+        &self,
+        user_id: Uuid,
+        channel: OneTimeCodeChannel,
+        purpose: OneTimeCodePurpose,
+    ) -> impl Future<Output = Result<Option<OneTimeCode>, CoreError>> + Send;
+    /// Codes sent to the user on `channel` since `since`, for any purpose.
+    fn count_sent_since(
+        &self,
+        user_id: Uuid,
+        channel: OneTimeCodeChannel,
+        since: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+    fn record_failed_attempt(&self, id: Uuid)
+    -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Marks the code used, unless a concurrent request already did.
+    /// Returns whether this call did.
+    fn consume(
+        &self,
+        id: Uuid,
+        consumed_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+/// Second factor codes delivered by email or SMS, for users without an
+/// authenticator app.
+pub trait OneTimeCodeService: Send + Sync {
+    /// Sends a code proving the user receives messages on the channel.
+    fn send_one_time_code_enrollment(
+        &self,
+        identity: Identity,
+        input: SendOneTimeCodeEnrollmentInput,
+    ) -> impl Future<Output = Result<OneTimeCodeDelivery, CoreError>> + Send;
+    /// Checks the enrollment code and enrolls the channel it was sent on.
+    fn confirm_one_time_code_enrollment(
+        &self,
+        identity: Identity,
+        input: ConfirmOneTimeCodeEnrollmentInput,
+    ) -> impl Future<Output = Result<OneTimeCodeMethod, CoreError>> + Send;
+    fn list_one_time_code_methods(
+        &self,
+        identity: Identity,
+    ) -> impl Future<Output = Result<Vec<OneTimeCodeMethod>, CoreError>> + Send;
+    /// Sends a sign-in code on an enrolled channel.
+    fn send_one_time_code(
+        &self,
+        identity: Identity,
+        input: SendOneTimeCodeInput,
+    ) -> impl Future<Output = Result<OneTimeCodeDelivery, CoreError>> + Send;
+    /// Completes the sign-in of the auth session with a code sent by
+    /// `send_one_time_code`.
+    fn challenge_one_time_code(
+        &self,
+        identity: Identity,
+        input: ChallengeOneTimeCodeInput,
+    ) -> impl Future<Output = Result<ChallengeOtpOutput, CoreError>> + Send;
+}
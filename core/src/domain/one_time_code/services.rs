@@ -0,0 +1,763 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::{
+    auth_flow::{entities::Authenticator, services::complete_auth_flow_stage},
+    authentication::{ports::AuthSessionRepository, value_objects::Identity},
+    common::{email::EmailPort, entities::app_errors::CoreError, sms::SmsSender},
+    credential::{
+        entities::{Credential, CredentialData},
+        ports::CredentialRepository,
+    },
+    localization::{ports::LocalizationRepository, services::localize_for_user},
+    one_time_code::{
+        entities::{
+            CODE_TTL, MAX_SENDS_PER_WINDOW, OneTimeCode, OneTimeCodeChannel, OneTimeCodeDelivery,
+            OneTimeCodeMethod, OneTimeCodePurpose, RESEND_INTERVAL, SEND_WINDOW, mask_destination,
+            normalize_phone_number,
+        },
+        ports::{OneTimeCodeRepository, OneTimeCodeService},
+        value_objects::{
+            ChallengeOneTimeCodeInput, ConfirmOneTimeCodeEnrollmentInput,
+            SendOneTimeCodeEnrollmentInput, SendOneTimeCodeInput,
+        },
+    },
+    realm::ports::SmtpConfigRepository,
+    security_notification::{entities::SecurityNotification, ports::SecurityNotifier},
+    trident::{ports::ChallengeOtpOutput, services::store_auth_code_and_generate_login_url},
+    user::{entities::User, ports::UserRequiredActionRepository},
+};
+
+fn signed_in_user(identity: Identity) -> Result<User, CoreError> {
+    match identity {
+        Identity::User(user) => Ok(user),
+        _ => Err(CoreError::Forbidden("is not user".to_string())),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OneTimeCodeServiceImpl<CR, OC, URA, AS, ES, SC, SMS, LO, SN>
+where
+    CR: CredentialRepository,
+    OC: OneTimeCodeRepository,
+    URA: UserRequiredActionRepository,
+    AS: AuthSessionRepository,
+    ES: EmailPort,
+    SC: SmtpConfigRepository,
+    SMS: SmsSender,
+    LO: LocalizationRepository,
+    SN: SecurityNotifier,
+{
+    pub(crate) credential_repository: Arc<CR>,
+    pub(crate) one_time_code_repository: Arc<OC>,
+    pub(crate) user_required_action_repository: Arc<URA>,
+    pub(crate) auth_session_repository: Arc<AS>,
+    pub(crate) email_port: Arc<ES>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    pub(crate) sms_sender: Arc<SMS>,
+    pub(crate) localization_repository: Arc<LO>,
+    pub(crate) security_notifier: Arc<SN>,
+}
+
+impl<CR, OC, URA, AS, ES, SC, SMS, LO, SN>
+    OneTimeCodeServiceImpl<CR, OC, URA, AS, ES, SC, SMS, LO, SN>
+where
+    CR: CredentialRepository,
+    OC: OneTimeCodeRepository,
+    URA: UserRequiredActionRepository,
+    AS: AuthSessionRepository,
+    ES: EmailPort,
+    SC: SmtpConfigRepository,
+    SMS: SmsSender,
+    LO: LocalizationRepository,
+    SN: SecurityNotifier,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        credential_repository: Arc<CR>,
+        one_time_code_repository: Arc<OC>,
+        user_required_action_repository: Arc<URA>,
+        auth_session_repository: Arc<AS>,
+        email_port: Arc<ES>,
+        smtp_config_repository: Arc<SC>,
+        sms_sender: Arc<SMS>,
+        localization_repository: Arc<LO>,
+        security_notifier: Arc<SN>,
+    ) -> Self {
+        Self {
+            credential_repository,
+            one_time_code_repository,
+            user_required_action_repository,
+            auth_session_repository,
+            email_port,
+            smtp_config_repository,
+            sms_sender,
+            localization_repository,
+            security_notifier,
+        }
+    }
+
+    async fn enrolled_credentials(
+        &self,
+        user_id: Uuid,
+        channel: Option<OneTimeCodeChannel>,
+    ) -> Result<Vec<(OneTimeCodeChannel, Credential)>, CoreError> {
+        let credentials = self
+            .credential_repository
+            .get_credentials_by_user_id(user_id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?;
+
+        Ok(credentials
+            .into_iter()
+            .filter_map(|credential| match &credential.credential_data {
+                CredentialData::OneTimeCode { channel, .. }
+                    if credential.credential_type == channel.credential_type() =>
+                {
+                    Some((*channel, credential))
+                }
+                _ => None,
+            })
+            .filter(|(enrolled, _)| channel.is_none_or(|channel| channel == *enrolled))
+            .collect())
+    }
+
+    /// Address or number sign-in codes of an enrolled channel go to. Email
+    /// codes follow the user's current address.
+    fn destination(user: &User, credential: &Credential) -> Option<String> {
+        match &credential.credential_data {
+            CredentialData::OneTimeCode {
+                channel: OneTimeCodeChannel::Email,
+                ..
+            } => user.email.clone(),
+            CredentialData::OneTimeCode { destination, .. } => destination.clone(),
+            _ => None,
+        }
+    }
+
+    async fn issue_code(
+        &self,
+        user: &User,
+        channel: OneTimeCodeChannel,
+        purpose: OneTimeCodePurpose,
+        destination: String,
+    ) -> Result<OneTimeCodeDelivery, CoreError> {
+        let now = Utc::now();
+
+        let latest = self
+            .one_time_code_repository
+            .get_latest(user.id, channel, purpose)
+            .await?;
+        if let Some(latest) = latest
+            && latest.created_at + RESEND_INTERVAL > now
+        {
+            return Err(CoreError::TooManyRequests(
+                "wait before requesting another code".to_string(),
+            ));
+        }
+
+        let sent = self
+            .one_time_code_repository
+            .count_sent_since(user.id, channel, now - SEND_WINDOW)
+            .await?;
+        if sent >= MAX_SENDS_PER_WINDOW {
+            warn!(user_id = %user.id, %channel, "one-time code send limit reached");
+            return Err(CoreError::TooManyRequests(
+                "too many codes requested, try again later".to_string(),
+            ));
+        }
+
+        let (code, value) =
+            OneTimeCode::issue(user.realm_id, user.id, channel, purpose, destination, now);
+        let delivery = OneTimeCodeDelivery {
+            channel,
+            destination: mask_destination(channel, &code.destination),
+            expires_at: code.expires_at,
+            resend_available_at: now + RESEND_INTERVAL,
+        };
+
+        self.one_time_code_repository.create(code.clone()).await?;
+        self.deliver(user, &code, &value).await?;
+
+        Ok(delivery)
+    }
+
+    async fn deliver(&self, user: &User, code: &OneTimeCode, value: &str) -> Result<(), CoreError> {
+        let minutes = CODE_TTL.num_minutes();
+
+        match code.channel {
+            OneTimeCodeChannel::Email => {
+                let smtp_config = self
+                    .smtp_config_repository
+                    .get_by_realm_id(user.realm_id)
+                    .await?
+                    .ok_or_else(|| {
+                        CoreError::ServiceUnavailable(
+                            "email is not configured for this realm".to_string(),
+                        )
+                    })?;
+                let messages =
+                    localize_for_user(&*self.localization_repository, user.realm_id, user.id).await;
+
+                self.email_port
+                    .send_email(
+                        &smtp_config,
+                        &code.destination,
+                        &messages.message_or("email.one_time_code.subject", "Your security code"),
+                        &format!(
+                            "Your security code is {value}.\n\nIt expires in {minutes} minutes.\n\nIf you did not request this, please ignore this email.",
+                        ),
+                        None,
+                    )
+                    .await
+            }
+            OneTimeCodeChannel::Sms => {
+                self.sms_sender
+                    .send_sms(
+                        &code.destination,
+                        &format!("{value} is your security code. It expires in {minutes} minutes."),
+                    )
+                    .await
+            }
+        }
+    }
+
+    /// Uses the code last sent to the user for `purpose`. Every wrong code
+    /// counts against it, and a code can only be used once.
+    async fn consume_code(
+        &self,
+        user_id: Uuid,
+        channel: OneTimeCodeChannel,
+        purpose: OneTimeCodePurpose,
+        value: &str,
+    ) -> Result<OneTimeCode, CoreError> {
+        let now = Utc::now();
+
+        let Some(code) = self
+            .one_time_code_repository
+            .get_latest(user_id, channel, purpose)
+            .await?
+            .filter(|code| code.is_usable(now))
+        else {
+            return Err(CoreError::InvalidOneTimeCode);
+        };
+
+        if !code.matches(value) {
+            self.one_time_code_repository
+                .record_failed_attempt(code.id)
+                .await?;
+            return Err(CoreError::InvalidOneTimeCode);
+        }
+
+        if !self.one_time_code_repository.consume(code.id, now).await? {
+            warn!(
+                %user_id,
+                code_id = %code.id,
+                "one-time code used concurrently, rejecting replay"
+            );
+            return Err(CoreError::InvalidOneTimeCode);
+        }
+
+        Ok(code)
+    }
+}
+
+impl<CR, OC, URA, AS, ES, SC, SMS, LO, SN> OneTimeCodeService
+    for OneTimeCodeServiceImpl<CR, OC, URA, AS, ES, SC, SMS, LO, SN>
+where
+    CR: CredentialRepository,
+    OC: OneTimeCodeRepository,
+    URA: UserRequiredActionRepository,
+    AS: AuthSessionRepository,
+    ES: EmailPort,
+    SC: SmtpConfigRepository,
+    SMS: SmsSender,
+    LO: LocalizationRepository,
+    SN: SecurityNotifier,
+{
+    async fn send_one_time_code_enrollment(
+        &self,
+        identity: Identity,
+        input: SendOneTimeCodeEnrollmentInput,
+    ) -> Result<OneTimeCodeDelivery, CoreError> {
+        let user = signed_in_user(identity)?;
+
+        let destination = match input.channel {
+            OneTimeCodeChannel::Email => user
+                .email
+                .clone()
+                .ok_or_else(|| CoreError::Forbidden("user has no email address".to_string()))?,
+            OneTimeCodeChannel::Sms => normalize_phone_number(
+                input
+                    .phone_number
+                    .as_deref()
+                    .ok_or(CoreError::InvalidPhoneNumber)?,
+            )?,
+        };
+
+        self.issue_code(
+            &user,
+            input.channel,
+            OneTimeCodePurpose::Enrollment,
+            destination,
+        )
+        .await
+    }
+
+    async fn confirm_one_time_code_enrollment(
+        &self,
+        identity: Identity,
+        input: ConfirmOneTimeCodeEnrollmentInput,
+    ) -> Result<OneTimeCodeMethod, CoreError> {
+        let user = signed_in_user(identity)?;
+
+        let code = self
+            .consume_code(
+                user.id,
+                input.channel,
+                OneTimeCodePurpose::Enrollment,
+                &input.code,
+            )
+            .await?;
+
+        // A user receives codes on a single address and a single number;
+        // verifying a new number replaces the previous one.
+        for (_, existing) in self
+            .enrolled_credentials(user.id, Some(input.channel))
+            .await?
+        {
+            self.credential_repository
+                .delete_by_id(existing.id)
+                .await
+                .map_err(|_| CoreError::DeleteCredentialError)?;
+        }
+
+        let credential_type = input.channel.credential_type();
+        let credential_data = serde_json::to_value(CredentialData::OneTimeCode {
+            channel: input.channel,
+            destination: match input.channel {
+                OneTimeCodeChannel::Email => None,
+                OneTimeCodeChannel::Sms => Some(code.destination.clone()),
+            },
+        })
+        .map_err(|_| CoreError::InternalServerError)?;
+
+        let credential = self
+            .credential_repository
+            .create_custom_credential(
+                user.id,
+                credential_type.to_string(),
+                String::new(),
+                input.label,
+                credential_data,
+            )
+            .await
+            .map_err(|_| CoreError::CreateCredentialError)?;
+
+        if let Err(e) = self
+            .user_required_action_repository
+            .remove_required_action(user.id, input.channel.required_action())
+            .await
+        {
+            warn!(
+                user_id = %user.id,
+                "Failed to remove required action after {} code enrollment: {e:?}",
+                input.channel
+            );
+        }
+
+        self.security_notifier
+            .notify(
+                user.realm_id,
+                user.id,
+                SecurityNotification::MfaAdded {
+                    method: credential_type.to_string(),
+                },
+            )
+            .await;
+
+        Ok(OneTimeCodeMethod {
+            credential_id: credential.id,
+            channel: input.channel,
+            destination: mask_destination(input.channel, &code.destination),
+        })
+    }
+
+    async fn list_one_time_code_methods(
+        &self,
+        identity: Identity,
+    ) -> Result<Vec<OneTimeCodeMethod>, CoreError> {
+        let user = signed_in_user(identity)?;
+
+        Ok(self
+            .enrolled_credentials(user.id, None)
+            .await?
+            .into_iter()
+            .filter_map(|(channel, credential)| {
+                Some(OneTimeCodeMethod {
+                    credential_id: credential.id,
+                    channel,
+                    destination: mask_destination(channel, &Self::destination(&user, &credential)?),
+                })
+            })
+            .collect())
+    }
+
+    async fn send_one_time_code(
+        &self,
+        identity: Identity,
+        input: SendOneTimeCodeInput,
+    ) -> Result<OneTimeCodeDelivery, CoreError> {
+        let user = signed_in_user(identity)?;
+
+        let destination = self
+            .enrolled_credentials(user.id, Some(input.channel))
+            .await?
+            .first()
+            .and_then(|(_, credential)| Self::destination(&user, credential))
+            .ok_or_else(|| {
+                CoreError::Forbidden(format!("{} codes are not enrolled", input.channel))
+            })?;
+
+        self.issue_code(
+            &user,
+            input.channel,
+            OneTimeCodePurpose::SignIn,
+            destination,
+        )
+        .await
+    }
+
+    async fn challenge_one_time_code(
+        &self,
+        identity: Identity,
+        input: ChallengeOneTimeCodeInput,
+    ) -> Result<ChallengeOtpOutput, CoreError> {
+        let user = signed_in_user(identity)?;
+
+        let session_code =
+            Uuid::parse_str(&input.session_code).map_err(|_| CoreError::SessionCreateError)?;
+        let auth_session = self
+            .auth_session_repository
+            .get_by_session_code(session_code)
+            .await
+            .map_err(|_| CoreError::SessionNotFound)?;
+
+        // Codes sent before the channel was removed no longer sign in.
+        if self
+            .enrolled_credentials(user.id, Some(input.channel))
+            .await?
+            .is_empty()
+        {
+            return Err(CoreError::InvalidOneTimeCode);
+        }
+
+        self.consume_code(
+            user.id,
+            input.channel,
+            OneTimeCodePurpose::SignIn,
+            &input.code,
+        )
+        .await?;
+
+        let authenticator = match input.channel {
+            OneTimeCodeChannel::Email => Authenticator::EmailOtp,
+            OneTimeCodeChannel::Sms => Authenticator::SmsOtp,
+        };
+
+        if let Some(next) = complete_auth_flow_stage(
+            self.auth_session_repository.as_ref(),
+            &auth_session,
+            user.id,
+            &authenticator,
+        )
+        .await?
+        {
+            return Ok(ChallengeOtpOutput {
+                login_url: None,
+                required_actions: Vec::new(),
+                temporary_token: None,
+                methods: next.methods,
+            });
+        }
+
+        let required_actions = self
+            .user_required_action_repository
+            .get_required_actions(user.id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if !required_actions.is_empty() {
+            return Ok(ChallengeOtpOutput {
+                login_url: None,
+                required_actions,
+                temporary_token: None,
+                methods: Vec::new(),
+            });
+        }
+
+        let login_url = store_auth_code_and_generate_login_url(
+            self.auth_session_repository.as_ref(),
+            &auth_session,
+            user.id,
+        )
+        .await?;
+
+        Ok(ChallengeOtpOutput {
+            login_url: Some(login_url),
+            required_actions: Vec::new(),
+            temporary_token: None,
+            methods: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        authentication::ports::MockAuthSessionRepository,
+        common::{
+            email::MockEmailPort,
+            services::tests::{create_test_realm_with_name, create_test_user},
+            sms::MockSmsSender,
+        },
+        credential::{entities::CredentialConfig, ports::MockCredentialRepository},
+        localization::ports::MockLocalizationRepository,
+        one_time_code::ports::MockOneTimeCodeRepository,
+        realm::ports::MockSmtpConfigRepository,
+        security_notification::{
+            ports::MockSecurityNotifier, services::tests::permissive_notifier,
+        },
+        user::ports::MockUserRequiredActionRepository,
+    };
+
+    type TestService = OneTimeCodeServiceImpl<
+        MockCredentialRepository,
+        MockOneTimeCodeRepository,
+        MockUserRequiredActionRepository,
+        MockAuthSessionRepository,
+        MockEmailPort,
+        MockSmtpConfigRepository,
+        MockSmsSender,
+        MockLocalizationRepository,
+        MockSecurityNotifier,
+    >;
+
+    fn build_service(
+        credential_repository: MockCredentialRepository,
+        one_time_code_repository: MockOneTimeCodeRepository,
+        sms_sender: MockSmsSender,
+    ) -> TestService {
+        OneTimeCodeServiceImpl::new(
+            Arc::new(credential_repository),
+            Arc::new(one_time_code_repository),
+            Arc::new(MockUserRequiredActionRepository::new()),
+            Arc::new(MockAuthSessionRepository::new()),
+            Arc::new(MockEmailPort::new()),
+            Arc::new(MockSmtpConfigRepository::new()),
+            Arc::new(sms_sender),
+            Arc::new(MockLocalizationRepository::new()),
+            Arc::new(permissive_notifier()),
+        )
+    }
+
+    fn test_user() -> User {
+        create_test_user(create_test_realm_with_name("test-realm").id)
+    }
+
+    fn enrolled_sms(user_id: Uuid) -> MockCredentialRepository {
+        let now = Utc::now();
+        let credential = Credential::new(CredentialConfig {
+            id: Uuid::new_v4(),
+            salt: None,
+            credential_type: "sms-otp".to_string(),
+            user_id,
+            user_label: None,
+            secret_data: String::new(),
+            credential_data: CredentialData::OneTimeCode {
+                channel: OneTimeCodeChannel::Sms,
+                destination: Some("+33612345678".to_string()),
+            },
+            temporary: false,
+            created_at: now,
+            updated_at: now,
+            webauthn_credential_id: None,
+        });
+
+        let mut repository = MockCredentialRepository::new();
+        repository
+            .expect_get_credentials_by_user_id()
+            .returning(move |_| {
+                let credentials = vec![credential.clone()];
+                Box::pin(async move { Ok(credentials) })
+            });
+        repository
+    }
+
+    fn latest_code(code: OneTimeCode) -> MockOneTimeCodeRepository {
+        let mut repository = MockOneTimeCodeRepository::new();
+        repository.expect_get_latest().returning(move |_, _, _| {
+            let code = code.clone();
+            Box::pin(async move { Ok(Some(code)) })
+        });
+        repository
+    }
+
+    fn issued(user: &User, purpose: OneTimeCodePurpose) -> (OneTimeCode, String) {
+        OneTimeCode::issue(
+            user.realm_id,
+            user.id,
+            OneTimeCodeChannel::Sms,
+            purpose,
+            "+33612345678".to_string(),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn enrollment_sends_code_to_normalized_phone_number() {
+        let user = test_user();
+
+        let mut codes = MockOneTimeCodeRepository::new();
+        codes
+            .expect_get_latest()
+            .returning(|_, _, _| Box::pin(async { Ok(None) }));
+        codes
+            .expect_count_sent_since()
+            .returning(|_, _, _| Box::pin(async { Ok(0) }));
+        codes
+            .expect_create()
+            .withf(|code| {
+                code.destination == "+33612345678" && code.purpose == OneTimeCodePurpose::Enrollment
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let mut sms_sender = MockSmsSender::new();
+        sms_sender
+            .expect_send_sms()
+            .withf(|to, body| to == "+33612345678" && body.contains("security code"))
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let delivery = build_service(MockCredentialRepository::new(), codes, sms_sender)
+            .send_one_time_code_enrollment(
+                Identity::User(user),
+                SendOneTimeCodeEnrollmentInput {
+                    channel: OneTimeCodeChannel::Sms,
+                    phone_number: Some("+33 6 12 34 56 78".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(delivery.destination, "+***5678");
+    }
+
+    #[tokio::test]
+    async fn codes_cannot_be_resent_right_away() {
+        let user = test_user();
+        let (latest, _) = issued(&user, OneTimeCodePurpose::SignIn);
+
+        let mut codes = latest_code(latest);
+        codes.expect_create().never();
+        let mut sms_sender = MockSmsSender::new();
+        sms_sender.expect_send_sms().never();
+
+        let result = build_service(enrolled_sms(user.id), codes, sms_sender)
+            .send_one_time_code(
+                Identity::User(user),
+                SendOneTimeCodeInput {
+                    channel: OneTimeCodeChannel::Sms,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::TooManyRequests(_))));
+    }
+
+    #[tokio::test]
+    async fn sends_are_limited_per_window() {
+        let user = test_user();
+
+        let mut codes = MockOneTimeCodeRepository::new();
+        codes
+            .expect_get_latest()
+            .returning(|_, _, _| Box::pin(async { Ok(None) }));
+        codes
+            .expect_count_sent_since()
+            .returning(|_, _, _| Box::pin(async { Ok(MAX_SENDS_PER_WINDOW) }));
+        codes.expect_create().never();
+
+        let result = build_service(enrolled_sms(user.id), codes, MockSmsSender::new())
+            .send_one_time_code(
+                Identity::User(user),
+                SendOneTimeCodeInput {
+                    channel: OneTimeCodeChannel::Sms,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::TooManyRequests(_))));
+    }
+
+    #[tokio::test]
+    async fn wrong_code_counts_as_failed_attempt() {
+        let user = test_user();
+        let (pending, _) = issued(&user, OneTimeCodePurpose::Enrollment);
+        let pending_id = pending.id;
+
+        let mut codes = latest_code(pending);
+        codes
+            .expect_record_failed_attempt()
+            .withf(move |id| *id == pending_id)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        codes.expect_consume().never();
+
+        let result = build_service(MockCredentialRepository::new(), codes, MockSmsSender::new())
+            .confirm_one_time_code_enrollment(
+                Identity::User(user),
+                ConfirmOneTimeCodeEnrollmentInput {
+                    channel: OneTimeCodeChannel::Sms,
+                    code: "not-it".to_string(),
+                    label: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidOneTimeCode)));
+    }
+
+    #[tokio::test]
+    async fn code_used_concurrently_is_rejected() {
+        let user = test_user();
+        let (pending, value) = issued(&user, OneTimeCodePurpose::Enrollment);
+
+        let mut codes = latest_code(pending);
+        codes
+            .expect_consume()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+
+        let mut credentials = MockCredentialRepository::new();
+        credentials.expect_create_custom_credential().never();
+
+        let result = build_service(credentials, codes, MockSmsSender::new())
+            .confirm_one_time_code_enrollment(
+                Identity::User(user),
+                ConfirmOneTimeCodeEnrollmentInput {
+                    channel: OneTimeCodeChannel::Sms,
+                    code: value,
+                    label: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::InvalidOneTimeCode)));
+    }
+}
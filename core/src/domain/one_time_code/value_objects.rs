@@ -0,0 +1,28 @@
+use super::entities::OneTimeCodeChannel;
+
+#[derive(Debug, Clone)]
+pub struct SendOneTimeCodeEnrollmentInput {
+    pub channel: OneTimeCodeChannel,
+    /// Number to verify, required for SMS. Email codes go to the user's
+    /// address.
+    pub phone_number: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfirmOneTimeCodeEnrollmentInput {
+    pub channel: OneTimeCodeChannel,
+    pub code: String,
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SendOneTimeCodeInput {
+    pub channel: OneTimeCodeChannel,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChallengeOneTimeCodeInput {
+    pub session_code: String,
+    pub channel: OneTimeCodeChannel,
+    pub code: String,
+}
@@ -3,8 +3,8 @@ pub use ferriskey_organization::{
     DeleteOrganizationAttributeInput, DeleteOrganizationInput, GetOrganizationInput,
     ListOrganizationAttributesInput, ListOrganizationMembersInput, ListOrganizationsInput,
     ListUserOrganizationsInput, Organization, OrganizationAttribute,
-    OrganizationAttributeRepository, OrganizationId, OrganizationMember,
+    OrganizationAttributeRepository, OrganizationFilter, OrganizationId, OrganizationMember,
     OrganizationMemberRepository, OrganizationPolicy, OrganizationRepository, OrganizationService,
-    OrganizationValidationError, RemoveOrganizationMemberInput, UpdateOrganizationInput,
-    UpdateOrganizationParams, UpsertOrganizationAttributeInput,
+    OrganizationSortField, OrganizationValidationError, RemoveOrganizationMemberInput,
+    UpdateOrganizationInput, UpdateOrganizationParams, UpsertOrganizationAttributeInput,
 };
@@ -9,7 +9,7 @@ use crate::domain::{
     authentication::value_objects::Identity,
     client::ports::ClientRepository,
     common::{
-        entities::app_errors::CoreError,
+        entities::{app_errors::CoreError, listing::Page},
         policies::{FerriskeyPolicy, ensure_policy},
     },
     organization::ports::{
@@ -183,7 +183,33 @@ where
         &self,
         identity: Identity,
         input: ListOrganizationsInput,
-    ) -> Result<Vec<Organization>, CoreError> {
+    ) -> Result<Page<Organization>, CoreError> {
+        let realm = self.get_realm_by_name(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_create_organization(&identity, realm.id)
+                .await,
+            "insufficient permissions to list organizations",
+        )?;
+
+        let limit = input.filter.page.limit();
+        let sort = input.filter.sort;
+        let organizations = self
+            .organization_repository
+            .search_organizations(realm.id, input.filter)
+            .await?;
+
+        Ok(Page::from_overfetch(organizations, limit, |organization| {
+            sort.cursor(organization)
+        }))
+    }
+
+    async fn count_organizations(
+        &self,
+        identity: Identity,
+        input: ListOrganizationsInput,
+    ) -> Result<u64, CoreError> {
         let realm = self.get_realm_by_name(input.realm_name).await?;
 
         ensure_policy(
@@ -194,7 +220,7 @@ where
         )?;
 
         self.organization_repository
-            .list_organizations_by_realm(realm.id)
+            .count_organizations(realm.id, input.filter)
             .await
     }
 
@@ -0,0 +1,314 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+use utoipa::ToSchema;
+
+use crate::domain::realm::entities::RealmId;
+
+/// How a device derives the moving factor of its codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OtpType {
+    /// RFC 6238: the moving factor is the current time step.
+    Totp,
+    /// RFC 4226: the moving factor is a counter bumped on every use, as on
+    /// hardware tokens.
+    Hotp,
+}
+
+impl OtpType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OtpType::Totp => "totp",
+            OtpType::Hotp => "hotp",
+        }
+    }
+}
+
+impl Display for OtpType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OtpType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "totp" => Ok(OtpType::Totp),
+            "hotp" => Ok(OtpType::Hotp),
+            other => Err(format!("unknown OTP type: {other}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+pub enum OtpAlgorithm {
+    /// Devices enrolled before realms had an OTP policy recorded
+    /// `HmacSha256`, but their codes were always checked with SHA-1.
+    #[serde(rename = "SHA1", alias = "HmacSha256")]
+    Sha1,
+    #[serde(rename = "SHA256")]
+    Sha256,
+    #[serde(rename = "SHA512")]
+    Sha512,
+}
+
+impl OtpAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OtpAlgorithm::Sha1 => "SHA1",
+            OtpAlgorithm::Sha256 => "SHA256",
+            OtpAlgorithm::Sha512 => "SHA512",
+        }
+    }
+
+    fn hmac(&self, secret: &[u8], message: &[u8]) -> Vec<u8> {
+        fn sign<M: Mac + hmac::digest::KeyInit>(secret: &[u8], message: &[u8]) -> Vec<u8> {
+            let mut mac =
+                <M as Mac>::new_from_slice(secret).expect("HMAC accepts keys of any size");
+            mac.update(message);
+            mac.finalize().into_bytes().to_vec()
+        }
+
+        match self {
+            OtpAlgorithm::Sha1 => sign::<Hmac<Sha1>>(secret, message),
+            OtpAlgorithm::Sha256 => sign::<Hmac<Sha256>>(secret, message),
+            OtpAlgorithm::Sha512 => sign::<Hmac<Sha512>>(secret, message),
+        }
+    }
+}
+
+impl Display for OtpAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OtpAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SHA1" => Ok(OtpAlgorithm::Sha1),
+            "SHA256" => Ok(OtpAlgorithm::Sha256),
+            "SHA512" => Ok(OtpAlgorithm::Sha512),
+            other => Err(format!("unknown OTP algorithm: {other}")),
+        }
+    }
+}
+
+/// How new OTP devices of a realm are enrolled and how far off a code may
+/// be. Devices keep the settings they were enrolled with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OtpPolicy {
+    pub realm_id: RealmId,
+    pub otp_type: OtpType,
+    pub algorithm: OtpAlgorithm,
+    pub digits: u32,
+    /// Seconds per time step, for TOTP.
+    pub period: u64,
+    /// Time steps accepted on each side of the current one for TOTP, or
+    /// counter values accepted ahead of the expected one for HOTP.
+    pub look_around: u32,
+    /// Counter of newly enrolled HOTP devices.
+    pub initial_counter: u64,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OtpPolicy {
+    /// The settings OTP used before realms could configure them.
+    pub fn new(realm_id: RealmId) -> Self {
+        Self {
+            realm_id,
+            otp_type: OtpType::Totp,
+            algorithm: OtpAlgorithm::Sha1,
+            digits: 6,
+            period: 30,
+            look_around: 1,
+            initial_counter: 0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Settings of a device enrolled under this policy.
+    pub fn credential_data(&self) -> OtpCredentialData {
+        OtpCredentialData {
+            sub_type: self.otp_type,
+            algorithm: self.algorithm,
+            digits: self.digits,
+            period: self.period,
+            counter: self.initial_counter,
+            last_used_step: None,
+        }
+    }
+}
+
+/// Settings and moving factor of an enrolled OTP device, stored as the
+/// credential data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OtpCredentialData {
+    pub sub_type: OtpType,
+    pub algorithm: OtpAlgorithm,
+    pub digits: u32,
+    pub period: u64,
+    /// Next expected counter, for HOTP.
+    pub counter: u64,
+    /// Time step of the last accepted code, for TOTP. Codes of this step
+    /// or an earlier one are replays.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used_step: Option<u64>,
+}
+
+impl OtpCredentialData {
+    /// RFC 4226 code for a moving factor.
+    pub fn generate(&self, secret: &[u8], moving_factor: u64) -> u32 {
+        let hash = self.algorithm.hmac(secret, &moving_factor.to_be_bytes());
+
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let code = ((hash[offset] as u32 & 0x7f) << 24)
+            | ((hash[offset + 1] as u32) << 16)
+            | ((hash[offset + 2] as u32) << 8)
+            | (hash[offset + 3] as u32);
+
+        code % 10u32.pow(self.digits)
+    }
+
+    /// Checks `code` at `now` (seconds since the epoch). On success returns
+    /// the data to store so the same code is not accepted again.
+    pub fn verify(
+        &self,
+        secret: &[u8],
+        code: &str,
+        now: u64,
+        look_around: u32,
+    ) -> Option<OtpCredentialData> {
+        if code.len() != self.digits as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let expected: u32 = code.parse().ok()?;
+        let look_around = u64::from(look_around);
+
+        match self.sub_type {
+            OtpType::Totp => {
+                let current = now / self.period.max(1);
+                let first = current.saturating_sub(look_around);
+                let first = match self.last_used_step {
+                    Some(last) => first.max(last + 1),
+                    None => first,
+                };
+
+                (first..=current + look_around)
+                    .find(|step| self.generate(secret, *step) == expected)
+                    .map(|step| OtpCredentialData {
+                        last_used_step: Some(step),
+                        ..self.clone()
+                    })
+            }
+            OtpType::Hotp => (self.counter..=self.counter + look_around)
+                .find(|counter| self.generate(secret, *counter) == expected)
+                .map(|counter| OtpCredentialData {
+                    counter: counter + 1,
+                    ..self.clone()
+                }),
+        }
+    }
+
+    /// Key URI scanned by authenticator apps, see
+    /// <https://github.com/google/google-authenticator/wiki/Key-Uri-Format>.
+    pub fn otpauth_uri(&self, issuer: &str, account_name: &str, secret_base32: &str) -> String {
+        let label = urlencoding::encode(account_name);
+        let issuer = urlencoding::encode(issuer);
+        let moving_factor = match self.sub_type {
+            OtpType::Totp => format!("period={}", self.period),
+            OtpType::Hotp => format!("counter={}", self.counter),
+        };
+
+        format!(
+            "otpauth://{}/{label}?secret={secret_base32}&issuer={issuer}&algorithm={}&digits={}&{moving_factor}",
+            self.sub_type, self.algorithm, self.digits
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 6238 appendix B seeds.
+    const SHA1_SEED: &[u8] = b"12345678901234567890";
+    const SHA256_SEED: &[u8] = b"12345678901234567890123456789012";
+
+    fn totp(algorithm: OtpAlgorithm) -> OtpCredentialData {
+        OtpCredentialData {
+            algorithm,
+            digits: 8,
+            ..OtpPolicy::new(RealmId::default()).credential_data()
+        }
+    }
+
+    #[test]
+    fn generates_rfc_6238_codes() {
+        assert_eq!(
+            totp(OtpAlgorithm::Sha1).generate(SHA1_SEED, 59 / 30),
+            94287082
+        );
+        assert_eq!(
+            totp(OtpAlgorithm::Sha256).generate(SHA256_SEED, 59 / 30),
+            46119246
+        );
+    }
+
+    #[test]
+    fn totp_code_cannot_be_replayed() {
+        let data = totp(OtpAlgorithm::Sha1);
+
+        let used = data.verify(SHA1_SEED, "94287082", 59, 1).unwrap();
+        assert_eq!(used.last_used_step, Some(1));
+
+        assert!(used.verify(SHA1_SEED, "94287082", 59, 1).is_none());
+    }
+
+    #[test]
+    fn totp_rejects_codes_outside_the_window() {
+        let data = totp(OtpAlgorithm::Sha1);
+
+        assert!(data.verify(SHA1_SEED, "94287082", 59 + 90, 1).is_none());
+        assert!(data.verify(SHA1_SEED, "94287082", 59 + 30, 1).is_some());
+    }
+
+    #[test]
+    fn hotp_advances_the_counter() {
+        // RFC 4226 appendix D.
+        let data = OtpCredentialData {
+            sub_type: OtpType::Hotp,
+            digits: 6,
+            ..totp(OtpAlgorithm::Sha1)
+        };
+
+        let used = data.verify(SHA1_SEED, "287082", 0, 2).unwrap();
+        assert_eq!(used.counter, 2);
+        assert!(used.verify(SHA1_SEED, "287082", 0, 2).is_none());
+        assert!(used.verify(SHA1_SEED, "359152", 0, 2).is_some());
+    }
+
+    #[test]
+    fn reads_data_of_devices_enrolled_before_otp_policies() {
+        let data: OtpCredentialData = serde_json::from_value(serde_json::json!({
+            "subType": "totp",
+            "digits": 6,
+            "counter": 0,
+            "period": 30,
+            "algorithm": "HmacSha256",
+        }))
+        .unwrap();
+
+        assert_eq!(data, OtpPolicy::new(RealmId::default()).credential_data());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    realm::entities::{Realm, RealmId},
+};
+
+use super::{
+    entities::OtpPolicy,
+    value_objects::{GetOtpPolicyInput, UpdateOtpPolicyInput},
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait OtpPolicyRepository: Send + Sync {
+    fn get_policy(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<OtpPolicy>, CoreError>> + Send;
+    fn upsert_policy(
+        &self,
+        policy: OtpPolicy,
+    ) -> impl Future<Output = Result<OtpPolicy, CoreError>> + Send;
+}
+
+pub trait OtpPolicyService: Send + Sync {
+    fn get_otp_policy(
+        &self,
+        identity: Identity,
+        input: GetOtpPolicyInput,
+    ) -> impl Future<Output = Result<OtpPolicy, CoreError>> + Send;
+    fn update_otp_policy(
+        &self,
+        identity: Identity,
+        input: UpdateOtpPolicyInput,
+    ) -> impl Future<Output = Result<OtpPolicy, CoreError>> + Send;
+}
+
+pub trait OtpPolicyPolicy: Send + Sync {
+    fn can_view_otp_policy(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_otp_policy(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    otp::{
+        entities::OtpPolicy,
+        ports::{OtpPolicyPolicy, OtpPolicyRepository, OtpPolicyService},
+        value_objects::{GetOtpPolicyInput, UpdateOtpPolicyInput},
+    },
+    realm::{
+        entities::{Realm, RealmId},
+        ports::RealmRepository,
+    },
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// The realm's OTP policy, or the default one if it never set any.
+pub(crate) async fn realm_otp_policy<OP: OtpPolicyRepository>(
+    repository: &OP,
+    realm_id: RealmId,
+) -> Result<OtpPolicy, CoreError> {
+    Ok(repository
+        .get_policy(realm_id)
+        .await?
+        .unwrap_or_else(|| OtpPolicy::new(realm_id)))
+}
+
+#[derive(Clone, Debug)]
+pub struct OtpPolicyServiceImpl<R, U, C, UR, OP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OP: OtpPolicyRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) otp_policy_repository: Arc<OP>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, OP> OtpPolicyServiceImpl<R, U, C, UR, OP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OP: OtpPolicyRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        otp_policy_repository: Arc<OP>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            otp_policy_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+}
+
+impl<R, U, C, UR, OP> OtpPolicyService for OtpPolicyServiceImpl<R, U, C, UR, OP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    OP: OtpPolicyRepository,
+{
+    async fn get_otp_policy(
+        &self,
+        identity: Identity,
+        input: GetOtpPolicyInput,
+    ) -> Result<OtpPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_otp_policy(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        realm_otp_policy(self.otp_policy_repository.as_ref(), realm.id).await
+    }
+
+    async fn update_otp_policy(
+        &self,
+        identity: Identity,
+        input: UpdateOtpPolicyInput,
+    ) -> Result<OtpPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_otp_policy(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let current = realm_otp_policy(self.otp_policy_repository.as_ref(), realm.id).await?;
+
+        self.otp_policy_repository
+            .upsert_policy(OtpPolicy {
+                realm_id: realm.id,
+                otp_type: input.otp_type.unwrap_or(current.otp_type),
+                algorithm: input.algorithm.unwrap_or(current.algorithm),
+                digits: input.digits.unwrap_or(current.digits),
+                period: input.period.unwrap_or(current.period),
+                look_around: input.look_around.unwrap_or(current.look_around),
+                initial_counter: input.initial_counter.unwrap_or(current.initial_counter),
+                updated_at: Utc::now(),
+            })
+            .await
+    }
+}
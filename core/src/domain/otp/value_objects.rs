@@ -0,0 +1,18 @@
+use super::entities::{OtpAlgorithm, OtpType};
+
+#[derive(Debug, Clone)]
+pub struct GetOtpPolicyInput {
+    pub realm_name: String,
+}
+
+/// Fields left out keep their current value.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOtpPolicyInput {
+    pub realm_name: String,
+    pub otp_type: Option<OtpType>,
+    pub algorithm: Option<OtpAlgorithm>,
+    pub digits: Option<u32>,
+    pub period: Option<u64>,
+    pub look_around: Option<u32>,
+    pub initial_counter: Option<u64>,
+}
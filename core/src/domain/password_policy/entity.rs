@@ -4,6 +4,8 @@ use sqlx::FromRow;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::error::PasswordPolicyError;
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct PasswordPolicy {
     pub id: Uuid,
@@ -33,6 +35,49 @@ impl PasswordPolicy {
             updated_at: Utc::now(),
         }
     }
+
+    /// Every rule of the policy the password breaks.
+    pub fn validate(&self, password: &str) -> Result<(), Vec<PasswordPolicyError>> {
+        let mut errors = Vec::new();
+
+        // Check minimum length
+        if password.len() < self.min_length as usize {
+            errors.push(PasswordPolicyError::TooShort {
+                min: self.min_length,
+                actual: password.len(),
+            });
+        }
+
+        // Check uppercase requirement
+        if self.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+            errors.push(PasswordPolicyError::MissingUppercase);
+        }
+
+        // Check lowercase requirement
+        if self.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+            errors.push(PasswordPolicyError::MissingLowercase);
+        }
+
+        // Check number requirement
+        if self.require_number && !password.chars().any(|c| c.is_numeric()) {
+            errors.push(PasswordPolicyError::MissingNumber);
+        }
+
+        // Check special character requirement
+        if self.require_special
+            && !password
+                .chars()
+                .any(|c| "!@#$%^&*()_+-=[]{}|;':\"\",./<>?".contains(c))
+        {
+            errors.push(PasswordPolicyError::MissingSpecialCharacter);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -78,45 +78,7 @@ where
         password: &str,
         policy: &PasswordPolicy,
     ) -> Result<(), Vec<PasswordPolicyError>> {
-        let mut errors = Vec::new();
-
-        // Check minimum length
-        if password.len() < policy.min_length as usize {
-            errors.push(PasswordPolicyError::TooShort {
-                min: policy.min_length,
-                actual: password.len(),
-            });
-        }
-
-        // Check uppercase requirement
-        if policy.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
-            errors.push(PasswordPolicyError::MissingUppercase);
-        }
-
-        // Check lowercase requirement
-        if policy.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
-            errors.push(PasswordPolicyError::MissingLowercase);
-        }
-
-        // Check number requirement
-        if policy.require_number && !password.chars().any(|c| c.is_numeric()) {
-            errors.push(PasswordPolicyError::MissingNumber);
-        }
-
-        // Check special character requirement
-        if policy.require_special
-            && !password
-                .chars()
-                .any(|c| "!@#$%^&*()_+-=[]{}|;':\"\",./<>?".contains(c))
-        {
-            errors.push(PasswordPolicyError::MissingSpecialCharacter);
-        }
-
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(errors)
-        }
+        policy.validate(password)
     }
 }
 
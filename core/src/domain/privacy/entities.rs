@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    account::entities::{AccountSession, LinkedIdentity},
+    common::generate_uuid_v7,
+    compass::entities::CompassFlow,
+    credential::entities::CredentialOverview,
+    organization::ports::OrganizationMember,
+    realm::entities::RealmId,
+    role::entities::Role,
+    seawatch::entities::SecurityEvent,
+    user::entities::{User, UserAttribute},
+};
+
+/// Version of the [`UserDataExport`] layout, bumped whenever a section is
+/// renamed or removed.
+pub const USER_DATA_EXPORT_VERSION: u32 = 1;
+
+/// A pending account deletion. The account stays usable until
+/// `scheduled_for`, and the request can be cancelled until then.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AccountDeletionRequest {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub user_id: Uuid,
+    /// The user themselves, or the admin who requested it on their behalf.
+    pub requested_by: Uuid,
+    pub requested_at: DateTime<Utc>,
+    pub scheduled_for: DateTime<Utc>,
+}
+
+impl AccountDeletionRequest {
+    pub fn new(
+        realm_id: RealmId,
+        user_id: Uuid,
+        requested_by: Uuid,
+        cooling_off: chrono::Duration,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: generate_uuid_v7(),
+            realm_id,
+            user_id,
+            requested_by,
+            requested_at: now,
+            scheduled_for: now + cooling_off,
+        }
+    }
+}
+
+/// Everything stored about a user, for GDPR article 15 requests.
+/// Secrets such as password hashes and OTP seeds are never included.
+#[derive(Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct UserDataExport {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub realm_name: String,
+    pub profile: User,
+    pub attributes: Vec<UserAttribute>,
+    pub roles: Vec<Role>,
+    pub organizations: Vec<OrganizationMember>,
+    pub credentials: Vec<CredentialOverview>,
+    pub linked_identities: Vec<LinkedIdentity>,
+    pub sessions: Vec<AccountSession>,
+    pub security_events: Vec<SecurityEvent>,
+    pub compass_flows: Vec<CompassFlow>,
+    pub pending_deletion: Option<AccountDeletionRequest>,
+}
+
+/// Rows rewritten when the audit trail of a deleted user was pseudonymized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PseudonymizationReport {
+    pub security_events: u64,
+    pub admin_events: u64,
+    pub compass_flows: u64,
+}
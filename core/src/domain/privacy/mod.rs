@@ -0,0 +1,5 @@
+pub mod entities;
+pub mod ports;
+pub mod pseudonymize;
+pub mod services;
+pub mod value_objects;
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity, common::entities::app_errors::CoreError,
+    realm::entities::RealmId,
+};
+
+use super::{
+    entities::{AccountDeletionRequest, PseudonymizationReport, UserDataExport},
+    pseudonymize::Pseudonymizer,
+    value_objects::{RequestAccountDeletionInput, UserDataInput},
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait AccountDeletionRepository: Send + Sync {
+    fn get_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Option<AccountDeletionRequest>, CoreError>> + Send;
+    fn create(
+        &self,
+        request: AccountDeletionRequest,
+    ) -> impl Future<Output = Result<AccountDeletionRequest, CoreError>> + Send;
+    /// Returns whether a request was deleted.
+    fn delete_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    /// Requests scheduled at or before `now`, oldest first.
+    fn list_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<AccountDeletionRequest>, CoreError>> + Send;
+    /// Rewrites the SeaWatch, admin and Compass rows about the user in place,
+    /// so the audit trail keeps its shape without identifying them.
+    fn pseudonymize_user_records(
+        &self,
+        realm_id: RealmId,
+        user_id: Uuid,
+        pseudonymizer: Pseudonymizer,
+    ) -> impl Future<Output = Result<PseudonymizationReport, CoreError>> + Send;
+}
+
+/// Data subject requests. Users act on their own account; admins who may
+/// view or delete users act on behalf of any user of the realm.
+pub trait PrivacyService: Send + Sync {
+    fn export_user_data(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> impl Future<Output = Result<UserDataExport, CoreError>> + Send;
+    fn request_account_deletion(
+        &self,
+        identity: Identity,
+        input: RequestAccountDeletionInput,
+    ) -> impl Future<Output = Result<AccountDeletionRequest, CoreError>> + Send;
+    fn get_account_deletion(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> impl Future<Output = Result<Option<AccountDeletionRequest>, CoreError>> + Send;
+    fn cancel_account_deletion(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Deletes the accounts whose cooling-off period ended, and returns how
+    /// many were deleted.
+    fn process_due_account_deletions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
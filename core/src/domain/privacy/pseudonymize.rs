@@ -0,0 +1,186 @@
+use maskass::{EmailMask, FullMask, HashMask, MaskStrategy};
+use serde_json::Value;
+
+use crate::domain::user::entities::User;
+
+/// Keys replaced by the user's pseudonym.
+const NAME_KEYS: [&str; 4] = [
+    "username",
+    "preferred_username",
+    "actor_name",
+    "external_username",
+];
+
+/// Keys holding personal data that is dropped entirely.
+const PERSONAL_KEYS: [&str; 8] = [
+    "firstname",
+    "lastname",
+    "first_name",
+    "last_name",
+    "given_name",
+    "family_name",
+    "ip_address",
+    "user_agent",
+];
+
+/// Rewrites audit rows about a deleted user so they can be kept without
+/// identifying them. The pseudonym is derived from the user id, so rows
+/// about the same person still correlate with each other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pseudonymizer {
+    pseudonym: String,
+    identifiers: Vec<String>,
+}
+
+impl Pseudonymizer {
+    pub fn for_user(user: &User) -> Self {
+        let identifiers = [
+            Some(&user.username),
+            user.email.as_ref(),
+            user.firstname.as_ref(),
+            user.lastname.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|value| !value.is_empty())
+        .cloned()
+        .collect();
+
+        Self {
+            pseudonym: HashMask::mask(&user.id.to_string()),
+            identifiers,
+        }
+    }
+
+    pub fn pseudonym(&self) -> &str {
+        &self.pseudonym
+    }
+
+    /// IP addresses and user agents are dropped rather than hashed: the IPv4
+    /// space is small enough to reverse any hash of it.
+    pub fn mask_network(&self, value: Option<String>) -> Option<String> {
+        value.map(|value| FullMask::mask(&value))
+    }
+
+    /// Masks secrets, personal fields and any value equal to one of the
+    /// user's identifiers, at any depth.
+    pub fn mask_json(&self, value: &mut Value) {
+        maskass::mask_json(value);
+        self.mask_value(None, value);
+    }
+
+    fn mask_value(&self, key: Option<&str>, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    self.mask_value(Some(key), value);
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.mask_value(key, item);
+                }
+            }
+            Value::String(text) => {
+                if let Some(masked) = self.mask_string(key, text) {
+                    *text = masked;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mask_string(&self, key: Option<&str>, text: &str) -> Option<String> {
+        let key = key.map(str::to_ascii_lowercase);
+        let key = key.as_deref();
+
+        if key.is_some_and(|key| NAME_KEYS.contains(&key)) {
+            return Some(self.pseudonym.clone());
+        }
+        if key == Some("email") {
+            return Some(EmailMask::mask(text));
+        }
+        if key.is_some_and(|key| PERSONAL_KEYS.contains(&key)) {
+            return Some(FullMask::mask(text));
+        }
+        if !self.identifiers.iter().any(|identifier| identifier == text) {
+            return None;
+        }
+
+        Some(if text.contains('@') {
+            EmailMask::mask(text)
+        } else {
+            self.pseudonym.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::realm::entities::RealmId;
+
+    fn user() -> User {
+        User {
+            id: Uuid::new_v4(),
+            realm_id: RealmId::default(),
+            client_id: None,
+            username: "jdoe".to_string(),
+            firstname: Some("John".to_string()),
+            lastname: Some("Doe".to_string()),
+            email: Some("john@example.com".to_string()),
+            email_verified: true,
+            enabled: true,
+            roles: None,
+            realm: None,
+            required_actions: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn mask_json_replaces_personal_data_and_keeps_the_rest() {
+        let pseudonymizer = Pseudonymizer::for_user(&user());
+        let mut value = json!({
+            "username": "jdoe",
+            "email": "john@example.com",
+            "firstname": "John",
+            "password": "hunter2",
+            "realm": "acme",
+            "members": ["jdoe", "alice"],
+        });
+
+        pseudonymizer.mask_json(&mut value);
+
+        assert_eq!(value["username"], pseudonymizer.pseudonym());
+        assert_eq!(value["email"], "******@example.com");
+        assert_eq!(value["firstname"], "******");
+        assert_ne!(value["password"], "hunter2");
+        assert_eq!(value["realm"], "acme");
+        assert_eq!(value["members"][0], pseudonymizer.pseudonym());
+        assert_eq!(value["members"][1], "alice");
+    }
+
+    #[test]
+    fn pseudonym_is_stable_per_user() {
+        let user = user();
+        let other = User {
+            id: Uuid::new_v4(),
+            ..user.clone()
+        };
+
+        assert_eq!(
+            Pseudonymizer::for_user(&user).pseudonym(),
+            Pseudonymizer::for_user(&user).pseudonym()
+        );
+        assert_ne!(
+            Pseudonymizer::for_user(&user).pseudonym(),
+            Pseudonymizer::for_user(&other).pseudonym()
+        );
+    }
+}
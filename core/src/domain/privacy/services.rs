@@ -0,0 +1,726 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use crate::domain::{
+    abyss::identity_provider::{
+        IdentityProviderRepository, broker::IdentityProviderLinkRepository,
+    },
+    account::{entities::LinkedIdentity, ports::AccountSessionRepository},
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    compass::{ports::CompassFlowRepository, value_objects::FlowFilter},
+    credential::{entities::CredentialOverview, ports::CredentialRepository},
+    organization::ports::OrganizationMemberRepository,
+    privacy::{
+        entities::{AccountDeletionRequest, USER_DATA_EXPORT_VERSION, UserDataExport},
+        ports::{AccountDeletionRepository, PrivacyService},
+        pseudonymize::Pseudonymizer,
+        value_objects::{RequestAccountDeletionInput, UserDataInput},
+    },
+    realm::{entities::Realm, ports::RealmRepository},
+    seawatch::{
+        entities::{ActorType, EventStatus, SecurityEvent, SecurityEventType},
+        ports::SecurityEventRepository,
+        value_objects::SecurityEventFilter,
+    },
+    user::{
+        entities::User,
+        ports::{UserAttributeRepository, UserPolicy, UserRepository, UserRoleRepository},
+    },
+    webhook::{
+        entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
+        ports::WebhookRepository,
+    },
+};
+
+/// Cooling-off period of self-service deletions, and of admin requests that
+/// do not set one.
+pub const DEFAULT_COOLING_OFF_DAYS: u32 = 30;
+pub const MAX_COOLING_OFF_DAYS: u32 = 365;
+
+/// Upper bound on the security events and Compass flows of one export.
+const MAX_EXPORTED_RECORDS: u32 = 10_000;
+
+/// Deletions carried out per scheduler tick.
+const DELETION_BATCH_SIZE: u64 = 100;
+
+#[derive(Clone, Debug)]
+pub struct PrivacyServiceImpl<R, U, C, UR, UA, OM, CR, IP, IL, AS, SE, CF, AD, W>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    UA: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    CR: CredentialRepository,
+    IP: IdentityProviderRepository,
+    IL: IdentityProviderLinkRepository,
+    AS: AccountSessionRepository,
+    SE: SecurityEventRepository,
+    CF: CompassFlowRepository,
+    AD: AccountDeletionRepository,
+    W: WebhookRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) user_role_repository: Arc<UR>,
+    pub(crate) user_attribute_repository: Arc<UA>,
+    pub(crate) organization_member_repository: Arc<OM>,
+    pub(crate) credential_repository: Arc<CR>,
+    pub(crate) identity_provider_repository: Arc<IP>,
+    pub(crate) identity_provider_link_repository: Arc<IL>,
+    pub(crate) account_session_repository: Arc<AS>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) compass_flow_repository: Arc<CF>,
+    pub(crate) account_deletion_repository: Arc<AD>,
+    pub(crate) webhook_repository: Arc<W>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, UA, OM, CR, IP, IL, AS, SE, CF, AD, W>
+    PrivacyServiceImpl<R, U, C, UR, UA, OM, CR, IP, IL, AS, SE, CF, AD, W>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    UA: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    CR: CredentialRepository,
+    IP: IdentityProviderRepository,
+    IL: IdentityProviderLinkRepository,
+    AS: AccountSessionRepository,
+    SE: SecurityEventRepository,
+    CF: CompassFlowRepository,
+    AD: AccountDeletionRepository,
+    W: WebhookRepository,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        user_repository: Arc<U>,
+        user_role_repository: Arc<UR>,
+        user_attribute_repository: Arc<UA>,
+        organization_member_repository: Arc<OM>,
+        credential_repository: Arc<CR>,
+        identity_provider_repository: Arc<IP>,
+        identity_provider_link_repository: Arc<IL>,
+        account_session_repository: Arc<AS>,
+        security_event_repository: Arc<SE>,
+        compass_flow_repository: Arc<CF>,
+        account_deletion_repository: Arc<AD>,
+        webhook_repository: Arc<W>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            user_repository,
+            user_role_repository,
+            user_attribute_repository,
+            organization_member_repository,
+            credential_repository,
+            identity_provider_repository,
+            identity_provider_link_repository,
+            account_session_repository,
+            security_event_repository,
+            compass_flow_repository,
+            account_deletion_repository,
+            webhook_repository,
+            policy,
+        }
+    }
+
+    /// The realm and the user the request is about. Without a `user_id`, or
+    /// with their own, users act on their account; anyone else needs the
+    /// permission to view (or, with `manage`, delete) users of the realm.
+    async fn resolve_subject(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+        user_id: Option<Uuid>,
+        manage: bool,
+    ) -> Result<(Realm, User), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let caller = identity
+            .as_user()
+            .filter(|user| identity.is_regular_user() && user.realm_id == realm.id)
+            .map(|user| user.id);
+
+        let user_id = match (user_id, caller) {
+            (None, Some(caller)) => caller,
+            (Some(user_id), Some(caller)) if user_id == caller => caller,
+            (None, None) => {
+                return Err(CoreError::Forbidden(
+                    "only users of this realm have an account here".into(),
+                ));
+            }
+            (Some(user_id), _) => {
+                let allowed = if manage {
+                    self.policy.can_delete_user(identity, &realm).await
+                } else {
+                    self.policy.can_view_user(identity, &realm).await
+                };
+                ensure_policy(allowed, "insufficient permissions")?;
+                user_id
+            }
+        };
+
+        let user = self.user_repository.get_by_id(user_id).await?;
+        if user.realm_id != realm.id {
+            return Err(CoreError::UserNotFound);
+        }
+
+        Ok((realm, user))
+    }
+
+    async fn linked_identities(&self, user_id: Uuid) -> Result<Vec<LinkedIdentity>, CoreError> {
+        let links = self
+            .identity_provider_link_repository
+            .get_by_user_id(user_id)
+            .await?;
+
+        let mut identities = Vec::with_capacity(links.len());
+        for link in links {
+            let Some(provider) = self
+                .identity_provider_repository
+                .get_identity_provider_by_id(link.identity_provider_id.as_uuid())
+                .await?
+            else {
+                continue;
+            };
+
+            identities.push(LinkedIdentity {
+                identity_provider_alias: provider.alias,
+                identity_provider_display_name: provider.display_name,
+                external_user_id: link.identity_provider_user_id,
+                external_username: link.identity_provider_username,
+                linked_at: link.created_at,
+            });
+        }
+
+        Ok(identities)
+    }
+
+    /// Audit is best-effort: a failure to record must not fail the request.
+    async fn record(&self, identity: &Identity, user: &User, event_type: SecurityEventType) {
+        let actor_type = if identity.is_service_account() {
+            ActorType::ServiceAccount
+        } else if identity.id() == user.id {
+            ActorType::User
+        } else {
+            ActorType::Admin
+        };
+
+        let event = SecurityEvent::new(
+            user.realm_id,
+            event_type,
+            EventStatus::Success,
+            identity.id(),
+        )
+        .with_actor(identity.id(), actor_type)
+        .with_target("user".to_string(), user.id, None);
+
+        if let Err(e) = self.security_event_repository.store_event(event).await {
+            warn!("Failed to store privacy security event: {}", e);
+        }
+    }
+
+    /// Pseudonymizes the audit trail of the user, then deletes the account.
+    async fn delete_account(&self, request: &AccountDeletionRequest) -> Result<(), CoreError> {
+        let user = match self.user_repository.get_by_id(request.user_id).await {
+            Ok(user) => user,
+            Err(CoreError::UserNotFound) => {
+                self.account_deletion_repository
+                    .delete_by_user_id(request.user_id)
+                    .await?;
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let report = self
+            .account_deletion_repository
+            .pseudonymize_user_records(request.realm_id, user.id, Pseudonymizer::for_user(&user))
+            .await?;
+
+        self.user_repository.delete_user(user.id).await?;
+
+        let event = SecurityEvent::new(
+            request.realm_id,
+            SecurityEventType::UserDeleted,
+            EventStatus::Success,
+            request.requested_by,
+        )
+        .with_actor(request.requested_by, ActorType::System)
+        .with_target("user".to_string(), user.id, None)
+        .with_details(json!({
+            "reason": "account_deletion_request",
+            "requested_at": request.requested_at,
+            "pseudonymized": report,
+        }));
+        if let Err(e) = self.security_event_repository.store_event(event).await {
+            warn!("Failed to store account deletion security event: {}", e);
+        }
+
+        self.webhook_repository
+            .notify(
+                request.realm_id,
+                WebhookPayload::new(
+                    WebhookTrigger::UserDeleted,
+                    request.realm_id.into(),
+                    Some(user),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl<R, U, C, UR, UA, OM, CR, IP, IL, AS, SE, CF, AD, W> PrivacyService
+    for PrivacyServiceImpl<R, U, C, UR, UA, OM, CR, IP, IL, AS, SE, CF, AD, W>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    UA: UserAttributeRepository,
+    OM: OrganizationMemberRepository,
+    CR: CredentialRepository,
+    IP: IdentityProviderRepository,
+    IL: IdentityProviderLinkRepository,
+    AS: AccountSessionRepository,
+    SE: SecurityEventRepository,
+    CF: CompassFlowRepository,
+    AD: AccountDeletionRepository,
+    W: WebhookRepository,
+{
+    async fn export_user_data(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> Result<UserDataExport, CoreError> {
+        let (realm, user) = self
+            .resolve_subject(&identity, &input.realm_name, input.user_id, false)
+            .await?;
+
+        let credentials = self
+            .credential_repository
+            .get_credentials_by_user_id(user.id)
+            .await
+            .map_err(|_| CoreError::GetUserCredentialsError)?
+            .into_iter()
+            .map(CredentialOverview::from)
+            .collect();
+
+        let security_events = self
+            .security_event_repository
+            .get_events(
+                realm.id,
+                SecurityEventFilter {
+                    user_id: Some(user.id),
+                    limit: Some(MAX_EXPORTED_RECORDS),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let compass_flows = self
+            .compass_flow_repository
+            .get_flows(
+                realm.id,
+                FlowFilter {
+                    user_id: Some(user.id),
+                    limit: Some(MAX_EXPORTED_RECORDS),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let export = UserDataExport {
+            format_version: USER_DATA_EXPORT_VERSION,
+            exported_at: Utc::now(),
+            realm_name: realm.name,
+            attributes: self
+                .user_attribute_repository
+                .list_by_user_id(user.id)
+                .await?,
+            roles: self.user_role_repository.get_user_roles(user.id).await?,
+            organizations: self
+                .organization_member_repository
+                .list_organizations_for_user(user.id)
+                .await?,
+            credentials,
+            linked_identities: self.linked_identities(user.id).await?,
+            sessions: self.account_session_repository.list_active(user.id).await?,
+            security_events,
+            compass_flows,
+            pending_deletion: self
+                .account_deletion_repository
+                .get_by_user_id(user.id)
+                .await?,
+            profile: user,
+        };
+
+        self.record(
+            &identity,
+            &export.profile,
+            SecurityEventType::UserDataExported,
+        )
+        .await;
+
+        Ok(export)
+    }
+
+    async fn request_account_deletion(
+        &self,
+        identity: Identity,
+        input: RequestAccountDeletionInput,
+    ) -> Result<AccountDeletionRequest, CoreError> {
+        let (realm, user) = self
+            .resolve_subject(&identity, &input.realm_name, input.user_id, true)
+            .await?;
+
+        // Asking twice keeps the first schedule rather than pushing it back.
+        if let Some(pending) = self
+            .account_deletion_repository
+            .get_by_user_id(user.id)
+            .await?
+        {
+            return Ok(pending);
+        }
+
+        let cooling_off_days = if identity.id() == user.id {
+            DEFAULT_COOLING_OFF_DAYS
+        } else {
+            input
+                .cooling_off_days
+                .unwrap_or(DEFAULT_COOLING_OFF_DAYS)
+                .min(MAX_COOLING_OFF_DAYS)
+        };
+
+        let request = self
+            .account_deletion_repository
+            .create(AccountDeletionRequest::new(
+                realm.id,
+                user.id,
+                identity.id(),
+                chrono::Duration::days(i64::from(cooling_off_days)),
+            ))
+            .await?;
+
+        self.record(
+            &identity,
+            &user,
+            SecurityEventType::AccountDeletionRequested,
+        )
+        .await;
+
+        Ok(request)
+    }
+
+    async fn get_account_deletion(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> Result<Option<AccountDeletionRequest>, CoreError> {
+        let (_, user) = self
+            .resolve_subject(&identity, &input.realm_name, input.user_id, false)
+            .await?;
+
+        self.account_deletion_repository
+            .get_by_user_id(user.id)
+            .await
+    }
+
+    async fn cancel_account_deletion(
+        &self,
+        identity: Identity,
+        input: UserDataInput,
+    ) -> Result<(), CoreError> {
+        let (_, user) = self
+            .resolve_subject(&identity, &input.realm_name, input.user_id, true)
+            .await?;
+
+        if !self
+            .account_deletion_repository
+            .delete_by_user_id(user.id)
+            .await?
+        {
+            return Err(CoreError::AccountDeletionNotFound);
+        }
+
+        self.record(
+            &identity,
+            &user,
+            SecurityEventType::AccountDeletionCancelled,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn process_due_account_deletions(&self, now: DateTime<Utc>) -> Result<u64, CoreError> {
+        let due = self
+            .account_deletion_repository
+            .list_due(now, DELETION_BATCH_SIZE)
+            .await?;
+
+        let mut deleted = 0;
+        for request in &due {
+            // One failing account must not hold back the others.
+            match self.delete_account(request).await {
+                Ok(()) => deleted += 1,
+                Err(e) => error!("Failed to delete account {}: {}", request.user_id, e),
+            }
+        }
+
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use ferriskey_organization::MockOrganizationMemberRepository;
+
+    use crate::domain::{
+        abyss::identity_provider::{
+            broker::ports::MockIdentityProviderLinkRepository,
+            ports::MockIdentityProviderRepository,
+        },
+        account::ports::MockAccountSessionRepository,
+        client::ports::MockClientRepository,
+        common::services::tests::{
+            create_test_realm_with_name, create_test_user_with_params_and_realm,
+        },
+        compass::ports::MockCompassFlowRepository,
+        credential::ports::MockCredentialRepository,
+        privacy::{entities::PseudonymizationReport, ports::MockAccountDeletionRepository},
+        realm::ports::MockRealmRepository,
+        seawatch::ports::MockSecurityEventRepository,
+        user::ports::{MockUserAttributeRepository, MockUserRepository, MockUserRoleRepository},
+        webhook::ports::MockWebhookRepository,
+    };
+
+    type TestPrivacyService = PrivacyServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockUserAttributeRepository,
+        MockOrganizationMemberRepository,
+        MockCredentialRepository,
+        MockIdentityProviderRepository,
+        MockIdentityProviderLinkRepository,
+        MockAccountSessionRepository,
+        MockSecurityEventRepository,
+        MockCompassFlowRepository,
+        MockAccountDeletionRepository,
+        MockWebhookRepository,
+    >;
+
+    struct Mocks {
+        realm: MockRealmRepository,
+        user: MockUserRepository,
+        account_deletion: MockAccountDeletionRepository,
+        security_event: MockSecurityEventRepository,
+        webhook: MockWebhookRepository,
+    }
+
+    /// Mocks resolving `realm` by name and `user` by id.
+    fn mocks_for(realm: &Realm, user: &User) -> Mocks {
+        let mut realm_repo = MockRealmRepository::new();
+        let r = realm.clone();
+        realm_repo
+            .expect_get_by_name()
+            .returning(move |_| Box::pin(std::future::ready(Ok(Some(r.clone())))));
+        let mut user_repo = MockUserRepository::new();
+        let u = user.clone();
+        user_repo
+            .expect_get_by_id()
+            .returning(move |_| Box::pin(std::future::ready(Ok(u.clone()))));
+
+        Mocks {
+            realm: realm_repo,
+            user: user_repo,
+            account_deletion: MockAccountDeletionRepository::new(),
+            security_event: MockSecurityEventRepository::new(),
+            webhook: MockWebhookRepository::new(),
+        }
+    }
+
+    fn build(mocks: Mocks) -> TestPrivacyService {
+        let user = Arc::new(mocks.user);
+        let user_role = Arc::new(MockUserRoleRepository::new());
+
+        PrivacyServiceImpl::new(
+            Arc::new(mocks.realm),
+            user.clone(),
+            user_role.clone(),
+            Arc::new(MockUserAttributeRepository::new()),
+            Arc::new(MockOrganizationMemberRepository::new()),
+            Arc::new(MockCredentialRepository::new()),
+            Arc::new(MockIdentityProviderRepository::new()),
+            Arc::new(MockIdentityProviderLinkRepository::new()),
+            Arc::new(MockAccountSessionRepository::new()),
+            Arc::new(mocks.security_event),
+            Arc::new(MockCompassFlowRepository::new()),
+            Arc::new(mocks.account_deletion),
+            Arc::new(mocks.webhook),
+            Arc::new(FerriskeyPolicy::new(
+                user,
+                Arc::new(MockClientRepository::new()),
+                user_role,
+            )),
+        )
+    }
+
+    fn setup() -> (Realm, User) {
+        let realm = create_test_realm_with_name("privacy");
+        let user = create_test_user_with_params_and_realm(
+            &realm,
+            "jane",
+            "jane@example.com".to_string(),
+            true,
+        );
+        (realm, user)
+    }
+
+    #[tokio::test]
+    async fn self_service_deletion_uses_the_default_cooling_off_period() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user);
+        mocks
+            .account_deletion
+            .expect_get_by_user_id()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mocks
+            .account_deletion
+            .expect_create()
+            .times(1)
+            .returning(|request| Box::pin(async move { Ok(request) }));
+        mocks
+            .security_event
+            .expect_store_event()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let service = build(mocks);
+
+        let request = service
+            .request_account_deletion(
+                Identity::User(user.clone()),
+                RequestAccountDeletionInput {
+                    realm_name: realm.name.clone(),
+                    user_id: None,
+                    cooling_off_days: Some(0),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(request.user_id, user.id);
+        assert_eq!(request.requested_by, user.id);
+        assert_eq!(
+            request.scheduled_for - request.requested_at,
+            Duration::days(i64::from(DEFAULT_COOLING_OFF_DAYS))
+        );
+    }
+
+    #[tokio::test]
+    async fn users_of_another_realm_cannot_request_a_deletion() {
+        let (realm, user) = setup();
+        let other_realm = create_test_realm_with_name("other");
+        let stranger = create_test_user_with_params_and_realm(
+            &other_realm,
+            "john",
+            "john@example.com".to_string(),
+            true,
+        );
+        let service = build(mocks_for(&realm, &user));
+
+        let result = service
+            .request_account_deletion(
+                Identity::User(stranger),
+                RequestAccountDeletionInput {
+                    realm_name: realm.name.clone(),
+                    user_id: None,
+                    cooling_off_days: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn due_deletions_pseudonymize_before_deleting() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user);
+        let request = AccountDeletionRequest::new(realm.id, user.id, user.id, Duration::zero());
+        let mut sequence = mockall::Sequence::new();
+
+        mocks
+            .account_deletion
+            .expect_list_due()
+            .returning(move |_, _| {
+                let due = vec![request.clone()];
+                Box::pin(async move { Ok(due) })
+            });
+        mocks
+            .account_deletion
+            .expect_pseudonymize_user_records()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    Ok(PseudonymizationReport {
+                        security_events: 3,
+                        ..Default::default()
+                    })
+                })
+            });
+        let user_id = user.id;
+        mocks
+            .user
+            .expect_delete_user()
+            .withf(move |id| *id == user_id)
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mocks
+            .security_event
+            .expect_store_event()
+            .withf(|event| event.event_type == SecurityEventType::UserDeleted)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mocks
+            .webhook
+            .expect_notify::<User>()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        let service = build(mocks);
+
+        let deleted = service
+            .process_due_account_deletions(Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(deleted, 1);
+    }
+}
@@ -0,0 +1,18 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct UserDataInput {
+    pub realm_name: String,
+    /// `None` targets the account of the caller.
+    pub user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestAccountDeletionInput {
+    pub realm_name: String,
+    /// `None` targets the account of the caller.
+    pub user_id: Option<Uuid>,
+    /// Only honoured for admins acting on behalf of a user. Users always get
+    /// the default cooling-off period.
+    pub cooling_off_days: Option<u32>,
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 pub use ferriskey_domain::realm::{Realm, RealmId, RealmSetting};
 use serde::{Deserialize, Serialize};
@@ -18,6 +20,7 @@ pub struct SmtpConfig {
     pub from_email: String,
     pub from_name: String,
     pub encryption: SmtpEncryption,
+    pub provider: EmailProvider,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -40,6 +43,41 @@ impl SmtpEncryption {
     }
 }
 
+/// Where a realm's emails are delivered.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EmailProvider {
+    /// Through the SMTP server described by the rest of the config.
+    #[default]
+    Smtp,
+    /// Posted as JSON to a provider API such as SendGrid or Postmark.
+    HttpApi(HttpApiSettings),
+    /// Written as `.eml` files under the server's maildir directory, for
+    /// development and CI.
+    Maildir,
+}
+
+impl EmailProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailProvider::Smtp => "smtp",
+            EmailProvider::HttpApi(_) => "http_api",
+            EmailProvider::Maildir => "maildir",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct HttpApiSettings {
+    pub url: String,
+    /// Request headers, typically carrying the API key; never returned.
+    #[serde(default, skip_serializing)]
+    pub headers: BTreeMap<String, String>,
+    /// JSON body with `{{placeholder}}` tokens such as `{{to}}`,
+    /// `{{subject}}` or `{{html_body}}`.
+    pub body_template: String,
+}
+
 impl std::str::FromStr for SmtpEncryption {
     type Err = std::convert::Infallible;
 
@@ -62,6 +100,9 @@ pub struct RealmLoginSetting {
     pub magic_link_enabled: bool,
     pub magic_link_ttl: u32,
     pub passkey_enabled: bool,
+    /// Days the login page can offer to trust the browser for; `0` when it
+    /// shouldn't offer it.
+    pub trusted_device_days: u32,
     pub theme: crate::domain::portal_theme::entities::PortalThemeConfig,
 }
 
@@ -76,6 +117,7 @@ impl From<RealmSetting> for RealmLoginSetting {
             magic_link_enabled: value.magic_link_enabled,
             magic_link_ttl: value.magic_link_ttl,
             passkey_enabled: value.passkey_enabled,
+            trusted_device_days: value.trusted_device_days,
             theme: crate::domain::portal_theme::entities::PortalThemeConfig::default(),
         }
     }
@@ -6,7 +6,7 @@ use crate::domain::realm::entities::RealmId;
 use crate::domain::{
     authentication::value_objects::Identity,
     common::entities::app_errors::CoreError,
-    realm::entities::{Realm, RealmLoginSetting, RealmSetting, SmtpConfig},
+    realm::entities::{EmailProvider, Realm, RealmLoginSetting, RealmSetting, SmtpConfig},
     user::entities::User,
 };
 
@@ -161,6 +161,7 @@ pub trait RealmRepository: Send + Sync {
         email_verification_template_id: Option<Option<Uuid>>,
         email_verification_enabled: Option<bool>,
         email_verification_ttl_hours: Option<i64>,
+        trusted_device_days: Option<u32>,
     ) -> impl Future<Output = Result<RealmSetting, CoreError>> + Send;
 
     fn get_realm_settings(
@@ -214,6 +215,7 @@ pub struct UpdateRealmSettingInput {
     pub email_verification_template_id: Option<Option<Uuid>>,
     pub email_verification_enabled: Option<bool>,
     pub email_verification_ttl_hours: Option<i64>,
+    pub trusted_device_days: Option<u32>,
 }
 
 pub struct DeleteRealmInput {
@@ -247,6 +249,7 @@ pub struct UpsertSmtpConfigInput {
     pub from_email: String,
     pub from_name: String,
     pub encryption: String,
+    pub provider: EmailProvider,
 }
 
 pub struct GetSmtpConfigInput {
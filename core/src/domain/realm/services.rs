@@ -781,6 +781,7 @@ where
                 input.email_verification_template_id,
                 input.email_verification_enabled,
                 input.email_verification_ttl_hours,
+                input.trusted_device_days,
             )
             .await?;
 
@@ -944,6 +945,7 @@ where
                 .encryption
                 .parse()
                 .expect("invariant: SmtpEncryption FromStr is infallible"),
+            provider: input.provider,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -0,0 +1,428 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::realm::entities::RealmId;
+
+/// Travel faster than this between two sign-ins is taken as the account
+/// being used from two places at once, roughly an airliner's speed.
+pub const MAX_TRAVEL_SPEED_KMH: f64 = 1000.0;
+
+/// Sign-ins closer than this are not checked for travel, GeoIP
+/// databases being too coarse to tell them apart.
+const MIN_TRAVEL_DISTANCE_KM: f64 = 100.0;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Something unusual about a sign-in, weighing on its risk score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskSignal {
+    /// The user never signed in with this browser or device.
+    NewDevice,
+    /// The user never signed in from this country.
+    NewCountry,
+    /// The previous sign-in was too far away to have travelled since.
+    ImpossibleTravel,
+    /// The address is on a configured list, such as TOR exit nodes.
+    ListedIp,
+    /// The password of the user was recently mistyped many times.
+    FailureVelocity,
+}
+
+impl RiskSignal {
+    /// Points the signal adds to the score, out of 100.
+    pub fn weight(&self) -> u8 {
+        match self {
+            RiskSignal::NewDevice => 20,
+            RiskSignal::NewCountry => 30,
+            RiskSignal::ImpossibleTravel => 50,
+            RiskSignal::ListedIp => 60,
+            RiskSignal::FailureVelocity => 30,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskSignal::NewDevice => "new_device",
+            RiskSignal::NewCountry => "new_country",
+            RiskSignal::ImpossibleTravel => "impossible_travel",
+            RiskSignal::ListedIp => "listed_ip",
+            RiskSignal::FailureVelocity => "failure_velocity",
+        }
+    }
+}
+
+impl Display for RiskSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What a sign-in goes through given its score, from the mildest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskAction {
+    Allow,
+    /// The user is told about the sign-in by email.
+    Notify,
+    /// The user has to pass a second factor, even if the flow would not
+    /// ask for one.
+    StepUp,
+    Block,
+}
+
+/// Risk scoring settings of a realm. Realms that never configured one
+/// don't score sign-ins.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RiskPolicy {
+    pub realm_id: RealmId,
+    pub enabled: bool,
+    /// Scores from 0 to 100 at which each action starts. A threshold equal
+    /// to the next one turns its action off, as 101 does for blocking.
+    pub notify_threshold: u8,
+    pub step_up_threshold: u8,
+    pub block_threshold: u8,
+    /// Failed sign-ins of the user within the window raising
+    /// [`RiskSignal::FailureVelocity`].
+    pub max_failures: u32,
+    pub failure_window_minutes: u32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RiskPolicy {
+    pub fn new(realm_id: RealmId) -> Self {
+        Self {
+            realm_id,
+            enabled: false,
+            notify_threshold: 20,
+            step_up_threshold: 50,
+            block_threshold: 90,
+            max_failures: 5,
+            failure_window_minutes: 15,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.block_threshold > 101 {
+            return Err("thresholds must be at most 101".to_string());
+        }
+
+        if self.notify_threshold == 0 {
+            return Err("notify_threshold must be at least 1".to_string());
+        }
+
+        if self.notify_threshold > self.step_up_threshold
+            || self.step_up_threshold > self.block_threshold
+        {
+            return Err("thresholds must satisfy notify <= step_up <= block".to_string());
+        }
+
+        if self.max_failures == 0 || self.failure_window_minutes == 0 {
+            return Err("max_failures and failure_window_minutes must be positive".to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn action_for(&self, score: u8) -> RiskAction {
+        if score >= self.block_threshold {
+            RiskAction::Block
+        } else if score >= self.step_up_threshold {
+            RiskAction::StepUp
+        } else if score >= self.notify_threshold {
+            RiskAction::Notify
+        } else {
+            RiskAction::Allow
+        }
+    }
+
+    /// Scores what is known about a sign-in.
+    pub fn assess(&self, observation: &RiskObservation) -> RiskAssessment {
+        let signals = observation.signals(self.max_failures);
+        let score = signals
+            .iter()
+            .map(|signal| u32::from(signal.weight()))
+            .sum::<u32>()
+            .min(100) as u8;
+
+        RiskAssessment {
+            score,
+            signals,
+            action: self.action_for(score),
+            country: observation
+                .location
+                .as_ref()
+                .map(|location| location.country.clone()),
+        }
+    }
+}
+
+/// Where an IP address is, as told by the GeoIP database.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeoLocation {
+    /// ISO 3166-1 alpha-2 code.
+    pub country: String,
+    /// Only city-level databases have coordinates.
+    pub coordinates: Option<(f64, f64)>,
+}
+
+impl GeoLocation {
+    /// Great-circle distance to `other`, if both have coordinates.
+    pub fn distance_km(&self, other: &GeoLocation) -> Option<f64> {
+        let (lat1, lon1) = self.coordinates?;
+        let (lat2, lon2) = other.coordinates?;
+
+        let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+        let dlat = lat2 - lat1;
+        let dlon = (lon2 - lon1).to_radians();
+
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+
+        Some(2.0 * EARTH_RADIUS_KM * a.sqrt().asin())
+    }
+}
+
+/// A previous sign-in of the user the current one is compared with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PastSignIn {
+    pub user_agent: Option<String>,
+    pub location: Option<GeoLocation>,
+    pub at: DateTime<Utc>,
+}
+
+/// What is known about a sign-in when it is scored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskObservation {
+    pub user_agent: Option<String>,
+    pub location: Option<GeoLocation>,
+    pub at: DateTime<Utc>,
+    /// Known origins of the user. The first sign-in of a user has nothing
+    /// to be compared with, so it raises no device, country or travel
+    /// signal.
+    pub history: Vec<PastSignIn>,
+    pub ip_listed: bool,
+    pub recent_failures: u64,
+}
+
+impl RiskObservation {
+    fn signals(&self, max_failures: u32) -> Vec<RiskSignal> {
+        let mut signals = Vec::new();
+
+        if !self.history.is_empty() {
+            if self.user_agent.is_some()
+                && !self
+                    .history
+                    .iter()
+                    .any(|past| past.user_agent == self.user_agent)
+            {
+                signals.push(RiskSignal::NewDevice);
+            }
+
+            if let Some(location) = &self.location {
+                let mut countries = self
+                    .history
+                    .iter()
+                    .filter_map(|past| past.location.as_ref())
+                    .map(|past| &past.country)
+                    .peekable();
+
+                if countries.peek().is_some() && !countries.any(|c| *c == location.country) {
+                    signals.push(RiskSignal::NewCountry);
+                }
+
+                if self.travelled_too_fast(location) {
+                    signals.push(RiskSignal::ImpossibleTravel);
+                }
+            }
+        }
+
+        if self.ip_listed {
+            signals.push(RiskSignal::ListedIp);
+        }
+
+        if self.recent_failures >= u64::from(max_failures) {
+            signals.push(RiskSignal::FailureVelocity);
+        }
+
+        signals
+    }
+
+    fn travelled_too_fast(&self, location: &GeoLocation) -> bool {
+        let Some(last) = self
+            .history
+            .iter()
+            .filter(|past| past.at <= self.at)
+            .max_by_key(|past| past.at)
+        else {
+            return false;
+        };
+
+        let Some(distance) = last
+            .location
+            .as_ref()
+            .and_then(|past| past.distance_km(location))
+        else {
+            return false;
+        };
+
+        if distance < MIN_TRAVEL_DISTANCE_KM {
+            return false;
+        }
+
+        // A minute at least, so sign-ins at the same instant don't divide
+        // by zero.
+        let hours = (self.at - last.at).num_seconds().max(60) as f64 / 3600.0;
+
+        distance / hours > MAX_TRAVEL_SPEED_KMH
+    }
+}
+
+/// Outcome of scoring a sign-in, kept on the session so tokens can carry
+/// it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RiskAssessment {
+    pub score: u8,
+    pub signals: Vec<RiskSignal>,
+    pub action: RiskAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    fn paris() -> GeoLocation {
+        GeoLocation {
+            country: "FR".to_string(),
+            coordinates: Some((48.8566, 2.3522)),
+        }
+    }
+
+    fn new_york() -> GeoLocation {
+        GeoLocation {
+            country: "US".to_string(),
+            coordinates: Some((40.7128, -74.0060)),
+        }
+    }
+
+    fn observation(location: GeoLocation, history: Vec<PastSignIn>) -> RiskObservation {
+        RiskObservation {
+            user_agent: Some("Firefox".to_string()),
+            location: Some(location),
+            at: Utc::now(),
+            history,
+            ip_listed: false,
+            recent_failures: 0,
+        }
+    }
+
+    fn past(location: GeoLocation, ago: Duration) -> PastSignIn {
+        PastSignIn {
+            user_agent: Some("Firefox".to_string()),
+            location: Some(location),
+            at: Utc::now() - ago,
+        }
+    }
+
+    #[test]
+    fn first_sign_in_is_not_compared_with_anything() {
+        let policy = RiskPolicy::new(RealmId::default());
+
+        let assessment = policy.assess(&observation(new_york(), Vec::new()));
+
+        assert_eq!(assessment.score, 0);
+        assert_eq!(assessment.action, RiskAction::Allow);
+        assert_eq!(assessment.country.as_deref(), Some("US"));
+    }
+
+    #[test]
+    fn usual_sign_in_raises_no_signal() {
+        let policy = RiskPolicy::new(RealmId::default());
+
+        let assessment = policy.assess(&observation(
+            paris(),
+            vec![past(paris(), Duration::days(2))],
+        ));
+
+        assert!(assessment.signals.is_empty());
+    }
+
+    #[test]
+    fn crossing_the_atlantic_in_an_hour_is_impossible_travel() {
+        let policy = RiskPolicy::new(RealmId::default());
+
+        let assessment = policy.assess(&observation(
+            new_york(),
+            vec![past(paris(), Duration::hours(1))],
+        ));
+
+        assert_eq!(
+            assessment.signals,
+            vec![RiskSignal::NewCountry, RiskSignal::ImpossibleTravel]
+        );
+        assert_eq!(assessment.score, 80);
+        assert_eq!(assessment.action, RiskAction::StepUp);
+    }
+
+    #[test]
+    fn crossing_the_atlantic_in_a_day_is_only_a_new_country() {
+        let policy = RiskPolicy::new(RealmId::default());
+
+        let assessment = policy.assess(&observation(
+            new_york(),
+            vec![past(paris(), Duration::days(1))],
+        ));
+
+        assert_eq!(assessment.signals, vec![RiskSignal::NewCountry]);
+        assert_eq!(assessment.action, RiskAction::Notify);
+    }
+
+    #[test]
+    fn new_device_listed_ip_and_failures_add_up() {
+        let policy = RiskPolicy::new(RealmId::default());
+        let mut observation = observation(paris(), vec![past(paris(), Duration::days(2))]);
+        observation.user_agent = Some("curl".to_string());
+        observation.ip_listed = true;
+        observation.recent_failures = 7;
+
+        let assessment = policy.assess(&observation);
+
+        assert_eq!(
+            assessment.signals,
+            vec![
+                RiskSignal::NewDevice,
+                RiskSignal::ListedIp,
+                RiskSignal::FailureVelocity
+            ]
+        );
+        assert_eq!(assessment.score, 100);
+        assert_eq!(assessment.action, RiskAction::Block);
+    }
+
+    #[test]
+    fn thresholds_must_be_ordered() {
+        let mut policy = RiskPolicy::new(RealmId::default());
+        assert!(policy.validate().is_ok());
+
+        policy.step_up_threshold = 95;
+        assert!(policy.validate().is_err());
+
+        policy.step_up_threshold = 90;
+        assert!(policy.validate().is_ok());
+        assert_eq!(policy.action_for(90), RiskAction::Block);
+
+        policy.block_threshold = 101;
+        assert!(policy.validate().is_ok());
+        assert_eq!(policy.action_for(100), RiskAction::StepUp);
+
+        policy.block_threshold = 102;
+        assert!(policy.validate().is_err());
+    }
+}
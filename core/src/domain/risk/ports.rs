@@ -0,0 +1,71 @@
+use std::net::IpAddr;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    realm::entities::{Realm, RealmId},
+};
+
+use super::{
+    entities::{GeoLocation, RiskAssessment, RiskPolicy},
+    value_objects::{AssessSignInInput, GetRiskPolicyInput, UpdateRiskPolicyInput},
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RiskPolicyRepository: Send + Sync {
+    fn get_policy(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<RiskPolicy>, CoreError>> + Send;
+    fn upsert_policy(
+        &self,
+        policy: RiskPolicy,
+    ) -> impl Future<Output = Result<RiskPolicy, CoreError>> + Send;
+}
+
+/// What is known about IP addresses from local databases.
+#[cfg_attr(test, mockall::automock)]
+pub trait IpIntelligence: Send + Sync {
+    fn locate(&self, ip: IpAddr) -> Option<GeoLocation>;
+    /// Whether the address is on one of the configured lists, such as TOR
+    /// exit nodes or known attackers.
+    fn is_listed(&self, ip: IpAddr) -> bool;
+}
+
+/// Scores sign-ins for the authentication service.
+#[cfg_attr(test, mockall::automock)]
+pub trait RiskAssessor: Send + Sync {
+    /// Scores a sign-in whose credentials are valid, records the outcome
+    /// in SeaWatch and notifies the user when the realm asks for it.
+    /// Returns `None` when the realm doesn't score sign-ins.
+    fn assess_sign_in(
+        &self,
+        input: AssessSignInInput,
+    ) -> impl Future<Output = Result<Option<RiskAssessment>, CoreError>> + Send;
+}
+
+pub trait RiskService: Send + Sync {
+    fn get_risk_policy(
+        &self,
+        identity: Identity,
+        input: GetRiskPolicyInput,
+    ) -> impl Future<Output = Result<RiskPolicy, CoreError>> + Send;
+    fn update_risk_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRiskPolicyInput,
+    ) -> impl Future<Output = Result<RiskPolicy, CoreError>> + Send;
+}
+
+pub trait RiskPolicyPolicy: Send + Sync {
+    fn can_view_risk_policy(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_risk_policy(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
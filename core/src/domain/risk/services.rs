@@ -0,0 +1,507 @@
+use std::{net::IpAddr, sync::Arc};
+
+use chrono::{Duration, Utc};
+use serde_json::json;
+use tracing::warn;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    realm::{
+        entities::{Realm, RealmId},
+        ports::RealmRepository,
+    },
+    risk::{
+        entities::{PastSignIn, RiskAction, RiskAssessment, RiskObservation, RiskPolicy},
+        ports::{
+            IpIntelligence, RiskAssessor, RiskPolicyPolicy, RiskPolicyRepository, RiskService,
+        },
+        value_objects::{AssessSignInInput, GetRiskPolicyInput, UpdateRiskPolicyInput},
+    },
+    seawatch::{
+        entities::{EventStatus, SecurityEvent, SecurityEventType},
+        ports::SecurityEventRepository,
+        value_objects::SecurityEventFilter,
+    },
+    security_notification::{
+        entities::SecurityNotification,
+        ports::{SecurityNotificationRepository, SecurityNotifier},
+    },
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// The realm's risk policy, or the default, disabled, one if it never set
+/// any.
+pub(crate) async fn realm_risk_policy<RP: RiskPolicyRepository>(
+    repository: &RP,
+    realm_id: RealmId,
+) -> Result<RiskPolicy, CoreError> {
+    Ok(repository
+        .get_policy(realm_id)
+        .await?
+        .unwrap_or_else(|| RiskPolicy::new(realm_id)))
+}
+
+#[derive(Clone, Debug)]
+pub struct RiskServiceImpl<R, U, C, UR, RP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: RiskPolicyRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) risk_policy_repository: Arc<RP>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, RP> RiskServiceImpl<R, U, C, UR, RP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: RiskPolicyRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        risk_policy_repository: Arc<RP>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            risk_policy_repository,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+}
+
+impl<R, U, C, UR, RP> RiskService for RiskServiceImpl<R, U, C, UR, RP>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RP: RiskPolicyRepository,
+{
+    async fn get_risk_policy(
+        &self,
+        identity: Identity,
+        input: GetRiskPolicyInput,
+    ) -> Result<RiskPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_risk_policy(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        realm_risk_policy(self.risk_policy_repository.as_ref(), realm.id).await
+    }
+
+    async fn update_risk_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRiskPolicyInput,
+    ) -> Result<RiskPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_risk_policy(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let current = realm_risk_policy(self.risk_policy_repository.as_ref(), realm.id).await?;
+
+        let policy = RiskPolicy {
+            realm_id: realm.id,
+            enabled: input.enabled.unwrap_or(current.enabled),
+            notify_threshold: input.notify_threshold.unwrap_or(current.notify_threshold),
+            step_up_threshold: input.step_up_threshold.unwrap_or(current.step_up_threshold),
+            block_threshold: input.block_threshold.unwrap_or(current.block_threshold),
+            max_failures: input.max_failures.unwrap_or(current.max_failures),
+            failure_window_minutes: input
+                .failure_window_minutes
+                .unwrap_or(current.failure_window_minutes),
+            updated_at: Utc::now(),
+        };
+        policy.validate().map_err(CoreError::InvalidRiskPolicy)?;
+
+        self.risk_policy_repository.upsert_policy(policy).await
+    }
+}
+
+/// Scores sign-ins against the user's known origins, the configured IP
+/// databases and their recent failures.
+#[derive(Clone, Debug)]
+pub struct RiskEngine<RP, SN, II, SER, NT>
+where
+    RP: RiskPolicyRepository,
+    SN: SecurityNotificationRepository,
+    II: IpIntelligence,
+    SER: SecurityEventRepository,
+    NT: SecurityNotifier,
+{
+    pub(crate) risk_policy_repository: Arc<RP>,
+    pub(crate) security_notification_repository: Arc<SN>,
+    pub(crate) ip_intelligence: Arc<II>,
+    pub(crate) security_event_repository: Arc<SER>,
+    pub(crate) security_notifier: Arc<NT>,
+}
+
+impl<RP, SN, II, SER, NT> RiskEngine<RP, SN, II, SER, NT>
+where
+    RP: RiskPolicyRepository,
+    SN: SecurityNotificationRepository,
+    II: IpIntelligence,
+    SER: SecurityEventRepository,
+    NT: SecurityNotifier,
+{
+    pub fn new(
+        risk_policy_repository: Arc<RP>,
+        security_notification_repository: Arc<SN>,
+        ip_intelligence: Arc<II>,
+        security_event_repository: Arc<SER>,
+        security_notifier: Arc<NT>,
+    ) -> Self {
+        Self {
+            risk_policy_repository,
+            security_notification_repository,
+            ip_intelligence,
+            security_event_repository,
+            security_notifier,
+        }
+    }
+
+    fn parse_ip(ip_address: Option<&str>) -> Option<IpAddr> {
+        ip_address.and_then(|ip| ip.parse().ok())
+    }
+
+    async fn observe(
+        &self,
+        policy: &RiskPolicy,
+        input: &AssessSignInInput,
+    ) -> Result<RiskObservation, CoreError> {
+        let ip = Self::parse_ip(input.ip_address.as_deref());
+        let now = Utc::now();
+
+        let history = self
+            .security_notification_repository
+            .list_sign_in_origins(input.user_id)
+            .await?
+            .into_iter()
+            .map(|origin| PastSignIn {
+                location: Self::parse_ip(origin.ip_address.as_deref())
+                    .and_then(|ip| self.ip_intelligence.locate(ip)),
+                user_agent: origin.user_agent,
+                at: origin.last_seen_at,
+            })
+            .collect();
+
+        let recent_failures = self
+            .security_event_repository
+            .count_events(
+                input.realm_id.into(),
+                SecurityEventFilter {
+                    user_id: Some(input.user_id),
+                    event_types: Some(vec![SecurityEventType::LoginFailure]),
+                    from_timestamp: Some(
+                        now - Duration::minutes(i64::from(policy.failure_window_minutes)),
+                    ),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        Ok(RiskObservation {
+            user_agent: input.user_agent.clone(),
+            location: ip.and_then(|ip| self.ip_intelligence.locate(ip)),
+            at: now,
+            history,
+            ip_listed: ip.is_some_and(|ip| self.ip_intelligence.is_listed(ip)),
+            recent_failures: recent_failures.max(0) as u64,
+        })
+    }
+}
+
+impl<RP, SN, II, SER, NT> RiskAssessor for RiskEngine<RP, SN, II, SER, NT>
+where
+    RP: RiskPolicyRepository,
+    SN: SecurityNotificationRepository,
+    II: IpIntelligence,
+    SER: SecurityEventRepository,
+    NT: SecurityNotifier,
+{
+    async fn assess_sign_in(
+        &self,
+        input: AssessSignInInput,
+    ) -> Result<Option<RiskAssessment>, CoreError> {
+        let policy =
+            realm_risk_policy(self.risk_policy_repository.as_ref(), input.realm_id).await?;
+
+        if !policy.enabled {
+            return Ok(None);
+        }
+
+        let observation = self.observe(&policy, &input).await?;
+        let assessment = policy.assess(&observation);
+
+        let status = if assessment.action == RiskAction::Block {
+            EventStatus::Failure
+        } else {
+            EventStatus::Success
+        };
+
+        let event = SecurityEvent::new(
+            input.realm_id,
+            SecurityEventType::RiskAssessed,
+            status,
+            input.user_id,
+        )
+        .with_target("user".to_string(), input.user_id, None)
+        .with_context(input.ip_address.clone(), input.user_agent.clone(), None)
+        .with_details(json!({
+            "score": assessment.score,
+            "signals": assessment.signals,
+            "action": assessment.action,
+            "country": assessment.country,
+        }));
+
+        if let Err(e) = self.security_event_repository.store_event(event).await {
+            warn!(user_id = %input.user_id, error = %e, "Failed to record risk assessment");
+        }
+
+        if assessment.action >= RiskAction::Notify {
+            self.security_notifier
+                .notify(
+                    input.realm_id,
+                    input.user_id,
+                    SecurityNotification::SuspiciousSignIn {
+                        ip_address: input.ip_address,
+                        user_agent: input.user_agent,
+                        signals: assessment.signals.iter().map(ToString::to_string).collect(),
+                    },
+                )
+                .await;
+        }
+
+        Ok(Some(assessment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{
+        risk::{
+            entities::{GeoLocation, RiskSignal},
+            ports::{MockIpIntelligence, MockRiskPolicyRepository},
+        },
+        seawatch::ports::MockSecurityEventRepository,
+        security_notification::{
+            entities::SignInOrigin,
+            ports::{MockSecurityNotificationRepository, MockSecurityNotifier},
+        },
+    };
+
+    type TestEngine = RiskEngine<
+        MockRiskPolicyRepository,
+        MockSecurityNotificationRepository,
+        MockIpIntelligence,
+        MockSecurityEventRepository,
+        MockSecurityNotifier,
+    >;
+
+    fn enabled_policy(realm_id: RealmId) -> RiskPolicy {
+        RiskPolicy {
+            enabled: true,
+            ..RiskPolicy::new(realm_id)
+        }
+    }
+
+    fn build_engine(
+        policy: Option<RiskPolicy>,
+        origins: Vec<SignInOrigin>,
+        failures: i64,
+        notifier: MockSecurityNotifier,
+    ) -> TestEngine {
+        let mut risk_policy_repository = MockRiskPolicyRepository::new();
+        risk_policy_repository
+            .expect_get_policy()
+            .returning(move |_| {
+                let policy = policy.clone();
+                Box::pin(async move { Ok(policy) })
+            });
+
+        let mut security_notification_repository = MockSecurityNotificationRepository::new();
+        security_notification_repository
+            .expect_list_sign_in_origins()
+            .returning(move |_| {
+                let origins = origins.clone();
+                Box::pin(async move { Ok(origins) })
+            });
+
+        let mut ip_intelligence = MockIpIntelligence::new();
+        ip_intelligence.expect_locate().returning(|ip| {
+            let country = if ip.to_string().starts_with("198.51.100.") {
+                "JP"
+            } else {
+                "FR"
+            };
+            Some(GeoLocation {
+                country: country.to_string(),
+                coordinates: None,
+            })
+        });
+        ip_intelligence.expect_is_listed().returning(|_| false);
+
+        let mut security_event_repository = MockSecurityEventRepository::new();
+        security_event_repository
+            .expect_count_events()
+            .returning(move |_, _| Box::pin(async move { Ok(failures) }));
+        security_event_repository
+            .expect_store_event()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        RiskEngine::new(
+            Arc::new(risk_policy_repository),
+            Arc::new(security_notification_repository),
+            Arc::new(ip_intelligence),
+            Arc::new(security_event_repository),
+            Arc::new(notifier),
+        )
+    }
+
+    fn input(realm_id: RealmId, user_id: Uuid, ip: &str, agent: &str) -> AssessSignInInput {
+        AssessSignInInput {
+            realm_id,
+            user_id,
+            ip_address: Some(ip.to_string()),
+            user_agent: Some(agent.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn disabled_policy_does_not_score() {
+        let mut notifier = MockSecurityNotifier::new();
+        notifier.expect_notify().never();
+
+        let engine = build_engine(None, Vec::new(), 0, notifier);
+        let realm_id = RealmId::default();
+
+        let assessment = engine
+            .assess_sign_in(input(realm_id, Uuid::new_v4(), "203.0.113.7", "Firefox"))
+            .await
+            .unwrap();
+
+        assert_eq!(assessment, None);
+    }
+
+    #[tokio::test]
+    async fn known_origin_is_allowed_silently() {
+        let realm_id = RealmId::default();
+        let user_id = Uuid::new_v4();
+
+        let mut notifier = MockSecurityNotifier::new();
+        notifier.expect_notify().never();
+
+        let engine = build_engine(
+            Some(enabled_policy(realm_id)),
+            vec![SignInOrigin::new(
+                user_id,
+                Some("203.0.113.7".to_string()),
+                Some("Firefox".to_string()),
+            )],
+            0,
+            notifier,
+        );
+
+        let assessment = engine
+            .assess_sign_in(input(realm_id, user_id, "203.0.113.7", "Firefox"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(assessment.score, 0);
+        assert_eq!(assessment.action, RiskAction::Allow);
+    }
+
+    #[tokio::test]
+    async fn unusual_sign_in_steps_up_and_notifies() {
+        let realm_id = RealmId::default();
+        let user_id = Uuid::new_v4();
+
+        let mut notifier = MockSecurityNotifier::new();
+        notifier
+            .expect_notify()
+            .withf(|_, _, notification| {
+                matches!(
+                    notification,
+                    SecurityNotification::SuspiciousSignIn { signals, .. }
+                        if signals == &["new_device", "new_country"]
+                )
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async {}));
+
+        let engine = build_engine(
+            Some(enabled_policy(realm_id)),
+            vec![SignInOrigin::new(
+                user_id,
+                Some("203.0.113.7".to_string()),
+                Some("Firefox".to_string()),
+            )],
+            0,
+            notifier,
+        );
+
+        let assessment = engine
+            .assess_sign_in(input(realm_id, user_id, "198.51.100.9", "Safari"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            assessment.signals,
+            vec![RiskSignal::NewDevice, RiskSignal::NewCountry]
+        );
+        assert_eq!(assessment.action, RiskAction::StepUp);
+        assert_eq!(assessment.country.as_deref(), Some("JP"));
+    }
+
+    #[tokio::test]
+    async fn failure_velocity_counts_recent_failures() {
+        let realm_id = RealmId::default();
+
+        let mut notifier = MockSecurityNotifier::new();
+        notifier
+            .expect_notify()
+            .times(1)
+            .returning(|_, _, _| Box::pin(async {}));
+
+        let engine = build_engine(Some(enabled_policy(realm_id)), Vec::new(), 5, notifier);
+
+        let assessment = engine
+            .assess_sign_in(input(realm_id, Uuid::new_v4(), "203.0.113.7", "Firefox"))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(assessment.signals, vec![RiskSignal::FailureVelocity]);
+        assert_eq!(assessment.action, RiskAction::Notify);
+    }
+}
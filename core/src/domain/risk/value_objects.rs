@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+use crate::domain::realm::entities::RealmId;
+
+#[derive(Debug, Clone)]
+pub struct GetRiskPolicyInput {
+    pub realm_name: String,
+}
+
+/// Fields left out keep their current value.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateRiskPolicyInput {
+    pub realm_name: String,
+    pub enabled: Option<bool>,
+    pub notify_threshold: Option<u8>,
+    pub step_up_threshold: Option<u8>,
+    pub block_threshold: Option<u8>,
+    pub max_failures: Option<u32>,
+    pub failure_window_minutes: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AssessSignInInput {
+    pub realm_id: RealmId,
+    pub user_id: Uuid,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
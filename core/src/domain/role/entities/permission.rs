@@ -43,6 +43,9 @@ pub enum Permissions {
 
     ManageEmailTemplates = 1 << 25, // 1 << 25
     ViewEmailTemplates = 1 << 26,   // 1 << 26
+
+    ManageScim = 1 << 27, // 1 << 27
+    ViewScim = 1 << 28,   // 1 << 28
 }
 
 impl Permissions {
@@ -75,6 +78,8 @@ impl Permissions {
             Self::ViewClientScopes,
             Self::ManageEmailTemplates,
             Self::ViewEmailTemplates,
+            Self::ManageScim,
+            Self::ViewScim,
         ];
 
         all_permissions
@@ -113,6 +118,8 @@ impl Permissions {
             Self::ViewClientScopes => "view_client_scopes".to_string(),
             Self::ManageEmailTemplates => "manage_email_templates".to_string(),
             Self::ViewEmailTemplates => "view_email_templates".to_string(),
+            Self::ManageScim => "manage_scim".to_string(),
+            Self::ViewScim => "view_scim".to_string(),
         }
     }
 
@@ -170,6 +177,8 @@ impl Permissions {
             "view_client_scopes" => Some(Self::ViewClientScopes),
             "manage_email_templates" => Some(Self::ManageEmailTemplates),
             "view_email_templates" => Some(Self::ViewEmailTemplates),
+            "manage_scim" => Some(Self::ManageScim),
+            "view_scim" => Some(Self::ViewScim),
             _ => None,
         }
     }
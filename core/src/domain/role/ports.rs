@@ -12,7 +12,9 @@ mod mocks {
     use ferriskey_domain::realm::RealmId;
     use ferriskey_domain::role::{
         entities::Role,
-        value_objects::{CreateRoleRequest, UpdateRolePermissionsRequest, UpdateRoleRequest},
+        value_objects::{
+            CreateRoleRequest, RoleFilter, UpdateRolePermissionsRequest, UpdateRoleRequest,
+        },
     };
 
     mock! {
@@ -37,6 +39,16 @@ mod mocks {
                 &self,
                 realm_id: RealmId,
             ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+            fn list_roles(
+                &self,
+                realm_id: RealmId,
+                filter: RoleFilter,
+            ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+            fn count_roles(
+                &self,
+                realm_id: RealmId,
+                filter: RoleFilter,
+            ) -> impl Future<Output = Result<u64, CoreError>> + Send;
             fn find_by_name(
                 &self,
                 name: String,
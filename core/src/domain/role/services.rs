@@ -4,12 +4,12 @@ use crate::domain::{
     authentication::value_objects::Identity,
     client::ports::ClientRepository,
     common::{
-        entities::app_errors::CoreError,
+        entities::{app_errors::CoreError, listing::Page},
         policies::{FerriskeyPolicy, ensure_policy},
     },
     realm::ports::RealmRepository,
     role::{
-        entities::{CreateRoleInput, GetUserRolesInput, Role, UpdateRoleInput},
+        entities::{CreateRoleInput, GetRolesInput, GetUserRolesInput, Role, UpdateRoleInput},
         ports::{RolePolicy, RoleRepository, RoleService},
         value_objects::{CreateRoleRequest, UpdateRolePermissionsRequest, UpdateRoleRequest},
     },
@@ -202,25 +202,50 @@ where
     async fn get_roles(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> Result<Vec<Role>, CoreError> {
+        input: GetRolesInput,
+    ) -> Result<Page<Role>, CoreError> {
         let realm = self
             .realm_repository
-            .get_by_name(&realm_name)
+            .get_by_name(&input.realm_name)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::InternalServerError)?;
+
+        ensure_policy(
+            self.policy.can_view_role(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let limit = input.filter.page.limit();
+        let sort = input.filter.sort;
+        let roles = self
+            .role_repository
+            .list_roles(realm.id, input.filter)
+            .await?;
+
+        Ok(Page::from_overfetch(roles, limit, |role| sort.cursor(role)))
+    }
+
+    async fn count_roles(
+        &self,
+        identity: Identity,
+        input: GetRolesInput,
+    ) -> Result<u64, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
             .await
             .map_err(|_| CoreError::InternalServerError)?
             .ok_or(CoreError::InternalServerError)?;
 
-        let realm_id = realm.id;
         ensure_policy(
             self.policy.can_view_role(&identity, &realm).await,
             "insufficient permissions",
         )?;
 
         self.role_repository
-            .find_by_realm_id(realm_id)
+            .count_roles(realm.id, input.filter)
             .await
-            .map_err(|_| CoreError::NotFound)
     }
 
     async fn get_user_roles(
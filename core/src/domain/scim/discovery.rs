@@ -0,0 +1,154 @@
+use serde_json::{Value, json};
+
+use super::{
+    entities::{
+        FERRISKEY_USER_SCHEMA, GROUP_SCHEMA, MAX_BULK_OPERATIONS, MAX_BULK_PAYLOAD_SIZE,
+        MAX_RESULTS, SCHEMA_SCHEMA, SERVICE_PROVIDER_CONFIG_SCHEMA, USER_SCHEMA,
+    },
+    value_objects::ScimEndpoint,
+};
+
+/// `/ServiceProviderConfig` (RFC 7643 §5).
+pub fn service_provider_config(endpoint: &ScimEndpoint) -> Value {
+    json!({
+        "schemas": [SERVICE_PROVIDER_CONFIG_SCHEMA],
+        "patch": { "supported": true },
+        "bulk": {
+            "supported": true,
+            "maxOperations": MAX_BULK_OPERATIONS,
+            "maxPayloadSize": MAX_BULK_PAYLOAD_SIZE,
+        },
+        "filter": { "supported": true, "maxResults": MAX_RESULTS },
+        "changePassword": { "supported": false },
+        "sort": { "supported": false },
+        "etag": { "supported": false },
+        "authenticationSchemes": [{
+            "type": "oauthbearertoken",
+            "name": "OAuth Bearer Token",
+            "description": "Access token of a service account granted the manage_scim permission",
+            "primary": true,
+        }],
+        "meta": {
+            "resourceType": "ServiceProviderConfig",
+            "location": format!("{}/ServiceProviderConfig", endpoint.url()),
+        },
+    })
+}
+
+/// `/Schemas` (RFC 7643 §7): the attributes this server stores.
+pub fn schemas(endpoint: &ScimEndpoint) -> Vec<Value> {
+    let reference = || {
+        vec![
+            attribute("value", "string", false, true, "readWrite"),
+            attribute("display", "string", false, false, "readOnly"),
+            attribute("$ref", "reference", false, false, "readOnly"),
+        ]
+    };
+
+    let user = schema(
+        endpoint,
+        USER_SCHEMA,
+        "User",
+        vec![
+            with_uniqueness(attribute("userName", "string", false, true, "readWrite")),
+            attribute("externalId", "string", false, false, "readWrite"),
+            complex(
+                attribute("name", "complex", false, false, "readWrite"),
+                vec![
+                    attribute("formatted", "string", false, false, "readOnly"),
+                    attribute("givenName", "string", false, false, "readWrite"),
+                    attribute("familyName", "string", false, false, "readWrite"),
+                ],
+            ),
+            attribute("displayName", "string", false, false, "readOnly"),
+            complex(
+                attribute("emails", "complex", true, false, "readWrite"),
+                vec![
+                    attribute("value", "string", false, true, "readWrite"),
+                    attribute("type", "string", false, false, "readWrite"),
+                    attribute("primary", "boolean", false, false, "readWrite"),
+                ],
+            ),
+            attribute("active", "boolean", false, false, "readWrite"),
+            complex(
+                attribute("roles", "complex", true, false, "readWrite"),
+                reference(),
+            ),
+            complex(
+                attribute("groups", "complex", true, false, "readOnly"),
+                reference(),
+            ),
+        ],
+    );
+
+    let extension = schema(
+        endpoint,
+        FERRISKEY_USER_SCHEMA,
+        "FerrisKey User",
+        vec![attribute(
+            "attributes",
+            "complex",
+            false,
+            false,
+            "readWrite",
+        )],
+    );
+
+    let group = schema(
+        endpoint,
+        GROUP_SCHEMA,
+        "Group",
+        vec![
+            attribute("displayName", "string", false, true, "readWrite"),
+            attribute("externalId", "string", false, false, "readWrite"),
+            complex(
+                attribute("members", "complex", true, false, "readWrite"),
+                reference(),
+            ),
+        ],
+    );
+
+    vec![user, extension, group]
+}
+
+fn schema(endpoint: &ScimEndpoint, id: &str, name: &str, attributes: Vec<Value>) -> Value {
+    json!({
+        "schemas": [SCHEMA_SCHEMA],
+        "id": id,
+        "name": name,
+        "attributes": attributes,
+        "meta": {
+            "resourceType": "Schema",
+            "location": format!("{}/Schemas/{id}", endpoint.url()),
+        },
+    })
+}
+
+fn attribute(
+    name: &str,
+    kind: &str,
+    multi_valued: bool,
+    required: bool,
+    mutability: &str,
+) -> Value {
+    json!({
+        "name": name,
+        "type": kind,
+        "multiValued": multi_valued,
+        "required": required,
+        "caseExact": false,
+        "mutability": mutability,
+        "returned": "default",
+        "uniqueness": "none",
+    })
+}
+
+fn complex(mut attribute: Value, sub_attributes: Vec<Value>) -> Value {
+    attribute["subAttributes"] = Value::Array(sub_attributes);
+    attribute
+}
+
+fn with_uniqueness(mut attribute: Value) -> Value {
+    attribute["uniqueness"] = json!("server");
+    attribute
+}
@@ -0,0 +1,100 @@
+use thiserror::Error;
+
+use crate::domain::common::entities::app_errors::CoreError;
+
+/// Errors surfaced by the SCIM 2.0 endpoints.
+///
+/// Protocol errors carry the `scimType` defined by RFC 7644 §3.12 so the HTTP
+/// layer (and bulk responses) can render them as SCIM error messages.
+#[derive(Debug, Clone, Error)]
+pub enum ScimError {
+    /// The filter syntax was invalid or the filter is not supported.
+    #[error("{0}")]
+    InvalidFilter(String),
+
+    /// The `path` attribute of a PATCH operation was invalid or malformed.
+    #[error("{0}")]
+    InvalidPath(String),
+
+    /// A PATCH `path` did not yield an attribute that could be operated on.
+    #[error("{0}")]
+    NoTarget(String),
+
+    /// A required value was missing or a value was not compatible with the
+    /// attribute.
+    #[error("{0}")]
+    InvalidValue(String),
+
+    /// The request body could not be parsed or violates the schema.
+    #[error("{0}")]
+    InvalidSyntax(String),
+
+    /// The request tried to modify an immutable or read-only attribute.
+    #[error("{0}")]
+    Mutability(String),
+
+    /// One or more unique attribute values are already in use.
+    #[error("{0}")]
+    Uniqueness(String),
+
+    /// A bulk request exceeded the advertised number of operations.
+    #[error("too many operations, at most {0} are allowed")]
+    TooMany(usize),
+
+    /// A bulk request body exceeded the advertised payload size.
+    #[error("payload too large, at most {0} bytes are allowed")]
+    PayloadTooLarge(usize),
+
+    #[error("resource {0} not found")]
+    NotFound(String),
+
+    #[error(transparent)]
+    Core(#[from] CoreError),
+}
+
+impl ScimError {
+    /// HTTP status code of the error, as carried in the SCIM error message.
+    pub fn status(&self) -> u16 {
+        match self {
+            ScimError::InvalidFilter(_)
+            | ScimError::InvalidPath(_)
+            | ScimError::NoTarget(_)
+            | ScimError::InvalidValue(_)
+            | ScimError::InvalidSyntax(_)
+            | ScimError::Mutability(_)
+            | ScimError::TooMany(_) => 400,
+            ScimError::Uniqueness(_) => 409,
+            ScimError::PayloadTooLarge(_) => 413,
+            ScimError::NotFound(_) => 404,
+            ScimError::Core(err) => match err {
+                CoreError::NotFound | CoreError::InvalidRealm | CoreError::InvalidUser => 404,
+                CoreError::Forbidden(_) => 403,
+                CoreError::AlreadyExists
+                | CoreError::UsernameAlreadyExists
+                | CoreError::EmailAlreadyExists => 409,
+                CoreError::Invalid | CoreError::InvalidRequest => 400,
+                _ => 500,
+            },
+        }
+    }
+
+    /// The RFC 7644 `scimType` keyword, for the errors that have one.
+    pub fn scim_type(&self) -> Option<&'static str> {
+        match self {
+            ScimError::InvalidFilter(_) => Some("invalidFilter"),
+            ScimError::InvalidPath(_) => Some("invalidPath"),
+            ScimError::NoTarget(_) => Some("noTarget"),
+            ScimError::InvalidValue(_) => Some("invalidValue"),
+            ScimError::InvalidSyntax(_) => Some("invalidSyntax"),
+            ScimError::Mutability(_) => Some("mutability"),
+            ScimError::Uniqueness(_) => Some("uniqueness"),
+            ScimError::TooMany(_) => Some("tooMany"),
+            ScimError::Core(
+                CoreError::AlreadyExists
+                | CoreError::UsernameAlreadyExists
+                | CoreError::EmailAlreadyExists,
+            ) => Some("uniqueness"),
+            ScimError::PayloadTooLarge(_) | ScimError::NotFound(_) | ScimError::Core(_) => None,
+        }
+    }
+}
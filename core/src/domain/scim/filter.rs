@@ -0,0 +1,555 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use super::{
+    entities::{GROUP_SCHEMA, USER_SCHEMA},
+    error::ScimError,
+};
+
+/// An attribute reference such as `userName`, `name.givenName` or
+/// `urn:ietf:params:scim:schemas:extension:ferriskey:2.0:User:attributes.team`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttrPath {
+    pub schema: Option<String>,
+    pub name: String,
+    pub sub_attribute: Option<String>,
+}
+
+impl AttrPath {
+    pub fn parse(raw: &str) -> Option<Self> {
+        // Schema URNs contain dots (`2.0`), so split them off first.
+        let (schema, rest) = match raw.rsplit_once(':') {
+            Some((schema, rest)) => (Some(schema.to_string()), rest),
+            None => (None, raw),
+        };
+        let (name, sub_attribute) = match rest.split_once('.') {
+            Some((name, sub)) => (name, Some(sub.to_string())),
+            None => (rest, None),
+        };
+
+        let valid = |part: &str| {
+            part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '$')
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '$')
+        };
+        if !valid(name) || !sub_attribute.as_deref().is_none_or(valid) {
+            return None;
+        }
+
+        Some(Self {
+            schema,
+            name: name.to_string(),
+            sub_attribute,
+        })
+    }
+
+    /// The object holding this attribute: the resource itself for core
+    /// attributes, the extension object for extension attributes.
+    pub(crate) fn container<'a>(&self, resource: &'a Value) -> Option<&'a Value> {
+        match &self.schema {
+            Some(schema) if !is_core_schema(schema) => get_ci(resource, schema),
+            _ => Some(resource),
+        }
+    }
+
+    fn is_case_exact(&self) -> bool {
+        self.sub_attribute.is_none()
+            && (self.name.eq_ignore_ascii_case("id")
+                || self.name.eq_ignore_ascii_case("externalId"))
+    }
+
+    /// Values this path designates in `resource`. Multi-valued attributes
+    /// yield one value per element, their `value` sub-attribute when no
+    /// sub-attribute is given.
+    fn values<'a>(&self, resource: &'a Value) -> Vec<&'a Value> {
+        let Some(attribute) = self
+            .container(resource)
+            .and_then(|container| get_ci(container, &self.name))
+        else {
+            return Vec::new();
+        };
+
+        let sub_attribute = self.sub_attribute.as_deref();
+        let values: Vec<&Value> = match (attribute, sub_attribute) {
+            (Value::Array(elements), sub) => elements
+                .iter()
+                .filter_map(|element| match (element, sub) {
+                    (Value::Object(_), Some(sub)) => get_ci(element, sub),
+                    (Value::Object(_), None) => get_ci(element, "value"),
+                    (_, None) => Some(element),
+                    (_, Some(_)) => None,
+                })
+                .collect(),
+            (Value::Object(_), Some(sub)) => get_ci(attribute, sub).into_iter().collect(),
+            (Value::Object(_), None) => Vec::new(),
+            (_, Some(_)) => Vec::new(),
+            (_, None) => vec![attribute],
+        };
+
+        values
+            .into_iter()
+            .filter(|value| !value.is_null())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Co,
+    Sw,
+    Ew,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl CompareOp {
+    fn parse(raw: &str) -> Option<Self> {
+        let op = match raw.to_ascii_lowercase().as_str() {
+            "eq" => Self::Eq,
+            "ne" => Self::Ne,
+            "co" => Self::Co,
+            "sw" => Self::Sw,
+            "ew" => Self::Ew,
+            "gt" => Self::Gt,
+            "ge" => Self::Ge,
+            "lt" => Self::Lt,
+            "le" => Self::Le,
+            _ => return None,
+        };
+
+        Some(op)
+    }
+}
+
+/// A parsed SCIM filter (RFC 7644 §3.4.2.2), evaluated against the JSON
+/// representation of a resource.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    Present(AttrPath),
+    Compare(AttrPath, CompareOp, Value),
+    /// `emails[type eq "work"]`: the inner filter applies to the elements.
+    ValuePath(AttrPath, Box<Filter>),
+    Not(Box<Filter>),
+    And(Box<Filter>, Box<Filter>),
+    Or(Box<Filter>, Box<Filter>),
+}
+
+impl Filter {
+    pub fn parse(input: &str) -> Result<Self, ScimError> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+        };
+
+        let filter = parser.or()?;
+        if parser.position != parser.tokens.len() {
+            return Err(invalid(format!("unexpected input in filter `{input}`")));
+        }
+
+        Ok(filter)
+    }
+
+    pub fn matches(&self, resource: &Value) -> bool {
+        match self {
+            Filter::Present(path) => path.values(resource).into_iter().any(is_present),
+            Filter::Compare(path, op, Value::Null) => {
+                let present = path.values(resource).into_iter().any(is_present);
+                match op {
+                    CompareOp::Ne => present,
+                    _ => !present,
+                }
+            }
+            Filter::Compare(path, CompareOp::Ne, expected) => !path
+                .values(resource)
+                .into_iter()
+                .any(|value| compare(value, CompareOp::Eq, expected, path.is_case_exact())),
+            Filter::Compare(path, op, expected) => path
+                .values(resource)
+                .into_iter()
+                .any(|value| compare(value, *op, expected, path.is_case_exact())),
+            Filter::ValuePath(path, filter) => {
+                let attribute = path
+                    .container(resource)
+                    .and_then(|container| get_ci(container, &path.name));
+                match attribute {
+                    Some(Value::Array(elements)) => {
+                        elements.iter().any(|element| filter.matches(element))
+                    }
+                    Some(element @ Value::Object(_)) => filter.matches(element),
+                    _ => false,
+                }
+            }
+            Filter::Not(filter) => !filter.matches(resource),
+            Filter::And(left, right) => left.matches(resource) && right.matches(resource),
+            Filter::Or(left, right) => left.matches(resource) || right.matches(resource),
+        }
+    }
+
+    /// The value `attribute` must equal for the filter to match, when the
+    /// filter pins it with `eq`, possibly as one side of an `and`.
+    pub fn required_value(&self, attribute: &str) -> Option<&Value> {
+        match self {
+            Filter::Compare(path, CompareOp::Eq, value)
+                if path.name.eq_ignore_ascii_case(attribute)
+                    && path.sub_attribute.is_none()
+                    && path.schema.as_deref().is_none_or(is_core_schema) =>
+            {
+                Some(value)
+            }
+            Filter::And(left, right) => left
+                .required_value(attribute)
+                .or_else(|| right.required_value(attribute)),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn is_core_schema(schema: &str) -> bool {
+    schema.eq_ignore_ascii_case(USER_SCHEMA) || schema.eq_ignore_ascii_case(GROUP_SCHEMA)
+}
+
+/// Attribute names are case-insensitive in SCIM.
+pub(crate) fn get_ci<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    value
+        .as_object()?
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(key))
+        .map(|(_, value)| value)
+}
+
+fn is_present(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::String(value) => !value.is_empty(),
+        Value::Array(values) => !values.is_empty(),
+        _ => true,
+    }
+}
+
+fn compare(actual: &Value, op: CompareOp, expected: &Value, case_exact: bool) -> bool {
+    match (actual, expected) {
+        (Value::String(actual), Value::String(expected)) => {
+            compare_strings(actual, op, expected, case_exact)
+        }
+        (Value::Number(actual), Value::Number(expected)) => {
+            let (Some(actual), Some(expected)) = (actual.as_f64(), expected.as_f64()) else {
+                return false;
+            };
+            actual
+                .partial_cmp(&expected)
+                .is_some_and(|ordering| ordered(ordering, op))
+        }
+        (Value::Bool(actual), Value::Bool(expected)) => op == CompareOp::Eq && actual == expected,
+        _ => false,
+    }
+}
+
+fn compare_strings(actual: &str, op: CompareOp, expected: &str, case_exact: bool) -> bool {
+    let (actual, expected) = if case_exact {
+        (actual.to_string(), expected.to_string())
+    } else {
+        (actual.to_lowercase(), expected.to_lowercase())
+    };
+
+    match op {
+        CompareOp::Eq => actual == expected,
+        CompareOp::Ne => actual != expected,
+        CompareOp::Co => actual.contains(&expected),
+        CompareOp::Sw => actual.starts_with(&expected),
+        CompareOp::Ew => actual.ends_with(&expected),
+        CompareOp::Gt | CompareOp::Ge | CompareOp::Lt | CompareOp::Le => {
+            // Timestamps compare chronologically whatever their offset.
+            let ordering = match (
+                actual.parse::<DateTime<Utc>>(),
+                expected.parse::<DateTime<Utc>>(),
+            ) {
+                (Ok(actual), Ok(expected)) => actual.cmp(&expected),
+                _ => actual.cmp(&expected),
+            };
+            ordered(ordering, op)
+        }
+    }
+}
+
+fn ordered(ordering: Ordering, op: CompareOp) -> bool {
+    match op {
+        CompareOp::Eq => ordering == Ordering::Equal,
+        CompareOp::Ne => ordering != Ordering::Equal,
+        CompareOp::Gt => ordering == Ordering::Greater,
+        CompareOp::Ge => ordering != Ordering::Less,
+        CompareOp::Lt => ordering == Ordering::Less,
+        CompareOp::Le => ordering != Ordering::Greater,
+        CompareOp::Co | CompareOp::Sw | CompareOp::Ew => false,
+    }
+}
+
+fn invalid(detail: String) -> ScimError {
+    ScimError::InvalidFilter(detail)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    OpenParen,
+    CloseParen,
+    OpenBracket,
+    CloseBracket,
+    String(String),
+    Word(String),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ScimError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | '[' | ']' => {
+                chars.next();
+                tokens.push(match c {
+                    '(' => Token::OpenParen,
+                    ')' => Token::CloseParen,
+                    '[' => Token::OpenBracket,
+                    _ => Token::CloseBracket,
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut escaped = false;
+                let end = loop {
+                    match chars.next() {
+                        Some((_, '\\')) if !escaped => escaped = true,
+                        Some((end, '"')) if !escaped => break end,
+                        Some(_) => escaped = false,
+                        None => return Err(invalid("unterminated string in filter".to_string())),
+                    }
+                };
+                let value: String = serde_json::from_str(&input[start..=end])
+                    .map_err(|_| invalid("invalid string in filter".to_string()))?;
+                tokens.push(Token::String(value));
+            }
+            _ => {
+                let mut end = input.len();
+                while let Some(&(index, c)) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '(' | ')' | '[' | ']' | '"') {
+                        end = index;
+                        break;
+                    }
+                    chars.next();
+                }
+                tokens.push(Token::Word(input[start..end].to_string()));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ScimError> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(invalid(format!("expected {expected:?} in filter"))),
+        }
+    }
+
+    fn or(&mut self) -> Result<Filter, ScimError> {
+        let mut filter = self.and()?;
+        while self.peek_keyword("or") {
+            self.position += 1;
+            filter = Filter::Or(Box::new(filter), Box::new(self.and()?));
+        }
+
+        Ok(filter)
+    }
+
+    fn and(&mut self) -> Result<Filter, ScimError> {
+        let mut filter = self.unary()?;
+        while self.peek_keyword("and") {
+            self.position += 1;
+            filter = Filter::And(Box::new(filter), Box::new(self.unary()?));
+        }
+
+        Ok(filter)
+    }
+
+    fn unary(&mut self) -> Result<Filter, ScimError> {
+        if self.peek_keyword("not")
+            && matches!(self.tokens.get(self.position + 1), Some(Token::OpenParen))
+        {
+            self.position += 1;
+            return Ok(Filter::Not(Box::new(self.group()?)));
+        }
+
+        match self.peek() {
+            Some(Token::OpenParen) => self.group(),
+            Some(Token::Word(_)) => self.attribute_expression(),
+            _ => Err(invalid("expected an attribute expression".to_string())),
+        }
+    }
+
+    fn group(&mut self) -> Result<Filter, ScimError> {
+        self.expect(Token::OpenParen)?;
+        let filter = self.or()?;
+        self.expect(Token::CloseParen)?;
+
+        Ok(filter)
+    }
+
+    fn attribute_expression(&mut self) -> Result<Filter, ScimError> {
+        let Some(Token::Word(raw_path)) = self.next() else {
+            return Err(invalid("expected an attribute path".to_string()));
+        };
+        let path = AttrPath::parse(&raw_path)
+            .ok_or_else(|| invalid(format!("invalid attribute path `{raw_path}`")))?;
+
+        if self.peek() == Some(&Token::OpenBracket) {
+            self.position += 1;
+            let filter = self.or()?;
+            self.expect(Token::CloseBracket)?;
+            return Ok(Filter::ValuePath(path, Box::new(filter)));
+        }
+
+        let Some(Token::Word(operator)) = self.next() else {
+            return Err(invalid(format!("expected an operator after `{raw_path}`")));
+        };
+        if operator.eq_ignore_ascii_case("pr") {
+            return Ok(Filter::Present(path));
+        }
+        let op = CompareOp::parse(&operator)
+            .ok_or_else(|| invalid(format!("unknown operator `{operator}`")))?;
+
+        let value = match self.next() {
+            Some(Token::String(value)) => Value::String(value),
+            Some(Token::Word(word)) => match word.to_ascii_lowercase().as_str() {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                "null" => Value::Null,
+                _ => serde_json::from_str::<serde_json::Number>(&word)
+                    .map(Value::Number)
+                    .map_err(|_| invalid(format!("invalid comparison value `{word}`")))?,
+            },
+            _ => return Err(invalid(format!("expected a value after `{operator}`"))),
+        };
+
+        Ok(Filter::Compare(path, op, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn user() -> Value {
+        json!({
+            "id": "8f1b",
+            "userName": "Jane.Doe",
+            "active": true,
+            "name": { "givenName": "Jane", "familyName": "Doe" },
+            "emails": [
+                { "value": "jane@example.com", "type": "work", "primary": true },
+                { "value": "jane@home.example", "type": "home" }
+            ],
+            "urn:ietf:params:scim:schemas:extension:ferriskey:2.0:User": {
+                "attributes": { "team": "platform" }
+            },
+            "meta": { "lastModified": "2026-03-01T10:00:00Z" }
+        })
+    }
+
+    fn matches(filter: &str) -> bool {
+        Filter::parse(filter).unwrap().matches(&user())
+    }
+
+    #[test]
+    fn comparisons_are_case_insensitive_except_identifiers() {
+        assert!(matches(r#"userName eq "jane.doe""#));
+        assert!(matches(r#"USERNAME sw "JANE""#));
+        assert!(!matches(r#"id eq "8F1B""#));
+        assert!(matches(r#"name.familyName co "o""#));
+    }
+
+    #[test]
+    fn multi_valued_attributes_match_any_element() {
+        assert!(matches(r#"emails co "@home.example""#));
+        assert!(matches(
+            r#"emails[type eq "work" and value ew "example.com"]"#
+        ));
+        assert!(!matches(r#"emails[type eq "home" and primary eq true]"#));
+    }
+
+    #[test]
+    fn logical_operators_follow_precedence() {
+        assert!(matches(
+            r#"userName eq "nobody" or active eq true and not (title pr)"#
+        ));
+        assert!(!matches(
+            r#"(userName eq "nobody" or active eq true) and title pr"#
+        ));
+        assert!(matches(
+            r#"meta.lastModified gt "2026-03-01T09:00:00+00:00""#
+        ));
+        assert!(matches(r#"title eq null"#));
+    }
+
+    #[test]
+    fn extension_attributes_are_addressed_by_schema() {
+        assert!(matches(
+            r#"urn:ietf:params:scim:schemas:extension:ferriskey:2.0:User:attributes.team eq "platform""#
+        ));
+    }
+
+    #[test]
+    fn required_value_is_found_in_conjunctions() {
+        let filter = Filter::parse(r#"active eq true and userName eq "jane""#).unwrap();
+
+        assert_eq!(filter.required_value("username"), Some(&json!("jane")));
+        assert_eq!(filter.required_value("externalId"), None);
+    }
+
+    #[test]
+    fn malformed_filters_are_rejected() {
+        for filter in [
+            r#"userName eq"#,
+            r#"userName like "x""#,
+            r#"(active eq true"#,
+            r#"userName eq "x" junk"#,
+        ] {
+            assert!(matches!(
+                Filter::parse(filter),
+                Err(ScimError::InvalidFilter(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod discovery;
+pub mod entities;
+pub mod error;
+pub mod filter;
+pub mod patch;
+pub mod policies;
+pub mod ports;
+pub mod services;
+pub mod value_objects;
@@ -0,0 +1,507 @@
+use serde_json::{Map, Value};
+
+use super::{
+    entities::{FERRISKEY_USER_SCHEMA, ScimPatchOperation},
+    error::ScimError,
+    filter::{AttrPath, CompareOp, Filter, get_ci, is_core_schema},
+};
+
+/// Names used when a PATCH introduces an attribute the resource does not
+/// have yet, so that a lower-cased path still lands on the schema name.
+const ATTRIBUTE_NAMES: &[&str] = &[
+    "active",
+    "attributes",
+    "display",
+    "displayName",
+    "emails",
+    "externalId",
+    "familyName",
+    "formatted",
+    "givenName",
+    "members",
+    "name",
+    "primary",
+    "roles",
+    "type",
+    "userName",
+    "value",
+    FERRISKEY_USER_SCHEMA,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PatchOp {
+    Add,
+    Remove,
+    Replace,
+}
+
+impl PatchOp {
+    fn parse(raw: &str) -> Result<Self, ScimError> {
+        match raw.to_ascii_lowercase().as_str() {
+            "add" => Ok(Self::Add),
+            "remove" => Ok(Self::Remove),
+            "replace" => Ok(Self::Replace),
+            _ => Err(ScimError::InvalidSyntax(format!(
+                "unknown PATCH operation `{raw}`"
+            ))),
+        }
+    }
+}
+
+/// `attr`, `attr.sub`, `attr[filter]` or `attr[filter].sub`.
+struct PatchPath {
+    schema: Option<String>,
+    name: String,
+    filter: Option<Filter>,
+    sub_attribute: Option<String>,
+}
+
+impl PatchPath {
+    fn parse(raw: &str) -> Result<Self, ScimError> {
+        let invalid = || ScimError::InvalidPath(format!("invalid PATCH path `{raw}`"));
+
+        // A bare extension URN designates the whole extension object.
+        if raw.eq_ignore_ascii_case(FERRISKEY_USER_SCHEMA) {
+            return Ok(Self {
+                schema: None,
+                name: raw.to_string(),
+                filter: None,
+                sub_attribute: None,
+            });
+        }
+
+        let (head, filter, sub_attribute) = match raw.split_once('[') {
+            Some((head, rest)) => {
+                let (inner, tail) = rest.rsplit_once(']').ok_or_else(invalid)?;
+                let filter = Filter::parse(inner)
+                    .map_err(|_| ScimError::InvalidPath(format!("invalid filter in `{raw}`")))?;
+                let sub_attribute = match tail {
+                    "" => None,
+                    tail => Some(tail.strip_prefix('.').ok_or_else(invalid)?.to_string()),
+                };
+                (head, Some(filter), sub_attribute)
+            }
+            None => (raw, None, None),
+        };
+
+        let path = AttrPath::parse(head).ok_or_else(invalid)?;
+        if filter.is_some() && path.sub_attribute.is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            schema: path.schema.filter(|schema| !is_core_schema(schema)),
+            name: path.name,
+            filter,
+            sub_attribute: sub_attribute.or(path.sub_attribute),
+        })
+    }
+}
+
+/// Applies the operations of a PATCH request (RFC 7644 §3.5.2) to the JSON
+/// representation of a resource. Operations apply in order; the caller
+/// validates the result by deserializing it.
+pub fn apply_patch(
+    resource: &mut Value,
+    operations: &[ScimPatchOperation],
+) -> Result<(), ScimError> {
+    for operation in operations {
+        apply_operation(
+            resource,
+            PatchOp::parse(&operation.op)?,
+            operation.path.as_deref(),
+            operation.value.clone(),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn apply_operation(
+    resource: &mut Value,
+    op: PatchOp,
+    path: Option<&str>,
+    value: Option<Value>,
+) -> Result<(), ScimError> {
+    let Some(path) = path.filter(|path| !path.trim().is_empty()) else {
+        // Without a path the value holds the attributes to set, each key
+        // being a path of its own.
+        if op == PatchOp::Remove {
+            return Err(ScimError::NoTarget("remove requires a path".to_string()));
+        }
+        let Some(Value::Object(attributes)) = value else {
+            return Err(ScimError::InvalidValue(
+                "a PATCH without path needs an object value".to_string(),
+            ));
+        };
+        for (key, value) in attributes {
+            apply_operation(resource, op, Some(&key), Some(value))?;
+        }
+        return Ok(());
+    };
+
+    let path = PatchPath::parse(path.trim())?;
+    let value = match (op, value) {
+        (PatchOp::Remove, value) => value,
+        (_, Some(value)) => Some(value),
+        (_, None) => {
+            return Err(ScimError::InvalidValue(format!(
+                "{op:?} on `{}` requires a value",
+                path.name
+            )));
+        }
+    };
+
+    let container = match &path.schema {
+        Some(schema) => object_entry(resource, schema)?,
+        None => resource,
+    };
+    let Value::Object(container) = container else {
+        return Err(ScimError::InvalidPath(
+            "target is not an object".to_string(),
+        ));
+    };
+
+    match path.filter {
+        Some(filter) => apply_filtered(
+            container,
+            op,
+            &path.name,
+            &filter,
+            path.sub_attribute,
+            value,
+        ),
+        None => apply_plain(container, op, &path.name, path.sub_attribute, value),
+    }
+}
+
+fn apply_plain(
+    container: &mut Map<String, Value>,
+    op: PatchOp,
+    name: &str,
+    sub_attribute: Option<String>,
+    value: Option<Value>,
+) -> Result<(), ScimError> {
+    let key = key_for(container, name);
+
+    if let Some(sub_attribute) = sub_attribute {
+        let attribute = container
+            .entry(key)
+            .or_insert_with(|| Value::Object(Map::new()));
+        return match attribute {
+            Value::Array(elements) => {
+                for element in elements.iter_mut().filter(|element| element.is_object()) {
+                    set_member(element, op, &sub_attribute, value.clone());
+                }
+                Ok(())
+            }
+            Value::Null => {
+                *attribute = Value::Object(Map::new());
+                set_member(attribute, op, &sub_attribute, value);
+                Ok(())
+            }
+            Value::Object(_) => {
+                set_member(attribute, op, &sub_attribute, value);
+                Ok(())
+            }
+            _ => Err(ScimError::InvalidPath(format!(
+                "`{name}` has no sub-attribute `{sub_attribute}`"
+            ))),
+        };
+    }
+
+    match (op, value) {
+        (PatchOp::Remove, Some(Value::Array(removed))) => {
+            if let Some(Value::Array(elements)) = container.get_mut(&key) {
+                elements.retain(|element| !removed.iter().any(|r| same_element(element, r)));
+            }
+        }
+        (PatchOp::Remove, _) => {
+            container.remove(&key);
+        }
+        (op, Some(value)) => {
+            let current = container.entry(key).or_insert(Value::Null);
+            match (op, current, value) {
+                (PatchOp::Add, Value::Array(elements), value) => {
+                    let added = match value {
+                        Value::Array(added) => added,
+                        value => vec![value],
+                    };
+                    for element in added {
+                        if !elements.iter().any(|e| same_element(e, &element)) {
+                            elements.push(element);
+                        }
+                    }
+                }
+                (_, current @ Value::Object(_), value @ Value::Object(_)) => merge(current, value),
+                (_, current, value) => *current = value,
+            }
+        }
+        (_, None) => {}
+    }
+
+    Ok(())
+}
+
+fn apply_filtered(
+    container: &mut Map<String, Value>,
+    op: PatchOp,
+    name: &str,
+    filter: &Filter,
+    sub_attribute: Option<String>,
+    value: Option<Value>,
+) -> Result<(), ScimError> {
+    let key = key_for(container, name);
+    let attribute = container
+        .entry(key)
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if attribute.is_null() {
+        *attribute = Value::Array(Vec::new());
+    }
+    let Value::Array(elements) = attribute else {
+        return Err(ScimError::InvalidPath(format!(
+            "`{name}` is not multi-valued"
+        )));
+    };
+
+    if op == PatchOp::Remove {
+        match sub_attribute {
+            Some(sub_attribute) => elements
+                .iter_mut()
+                .filter(|element| filter.matches(element))
+                .for_each(|element| set_member(element, op, &sub_attribute, None)),
+            None => elements.retain(|element| !filter.matches(element)),
+        }
+        return Ok(());
+    }
+
+    if !elements.iter().any(|element| filter.matches(element)) {
+        // `emails[type eq "work"].value` on a user without a work email
+        // creates it, as identity providers expect.
+        let Filter::Compare(path, CompareOp::Eq, expected) = filter else {
+            return Err(ScimError::NoTarget(format!(
+                "no `{name}` value matches the filter"
+            )));
+        };
+        let mut element = Map::new();
+        element.insert(canonical_name(&path.name), expected.clone());
+        elements.push(Value::Object(element));
+    }
+
+    for element in elements
+        .iter_mut()
+        .filter(|element| filter.matches(element))
+    {
+        match (&sub_attribute, op, value.clone()) {
+            (Some(sub_attribute), op, value) => set_member(element, op, sub_attribute, value),
+            (None, PatchOp::Replace, Some(value)) => *element = value,
+            (None, _, Some(value)) => merge(element, value),
+            (None, _, None) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Sets or removes a key of an object value.
+fn set_member(object: &mut Value, op: PatchOp, name: &str, value: Option<Value>) {
+    let Value::Object(map) = object else {
+        return;
+    };
+    let key = key_for(map, name);
+
+    match (op, value) {
+        (PatchOp::Remove, _) | (_, None) => {
+            map.remove(&key);
+        }
+        (_, Some(value)) => {
+            let current = map.entry(key).or_insert(Value::Null);
+            if current.is_object() && value.is_object() {
+                merge(current, value);
+            } else {
+                *current = value;
+            }
+        }
+    }
+}
+
+/// Complex attributes are merged: sub-attributes absent from `value` are
+/// left untouched.
+fn merge(target: &mut Value, value: Value) {
+    match (target, value) {
+        (Value::Object(target), Value::Object(value)) => {
+            for (name, value) in value {
+                let key = key_for(target, &name);
+                match target.get_mut(&key) {
+                    Some(current) if current.is_object() && value.is_object() => {
+                        merge(current, value)
+                    }
+                    _ => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
+
+fn object_entry<'a>(resource: &'a mut Value, name: &str) -> Result<&'a mut Value, ScimError> {
+    let Value::Object(map) = resource else {
+        return Err(ScimError::InvalidPath(
+            "resource is not an object".to_string(),
+        ));
+    };
+    let key = key_for(map, name);
+    let entry = map.entry(key).or_insert_with(|| Value::Object(Map::new()));
+    if entry.is_null() {
+        *entry = Value::Object(Map::new());
+    }
+
+    Ok(entry)
+}
+
+/// The existing key matching `name` case-insensitively, or the schema name.
+fn key_for(map: &Map<String, Value>, name: &str) -> String {
+    map.keys()
+        .find(|key| key.eq_ignore_ascii_case(name))
+        .cloned()
+        .unwrap_or_else(|| canonical_name(name))
+}
+
+fn canonical_name(name: &str) -> String {
+    ATTRIBUTE_NAMES
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(name))
+        .map_or_else(|| name.to_string(), |known| known.to_string())
+}
+
+/// Elements of a multi-valued attribute are identified by their `value`.
+fn same_element(element: &Value, other: &Value) -> bool {
+    match (get_ci(element, "value"), get_ci(other, "value")) {
+        (Some(left), Some(right)) => left == right,
+        _ => element == other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn operation(op: &str, path: Option<&str>, value: Value) -> ScimPatchOperation {
+        ScimPatchOperation {
+            op: op.to_string(),
+            path: path.map(str::to_string),
+            value: (!value.is_null()).then_some(value),
+        }
+    }
+
+    #[test]
+    fn pathless_operations_set_each_attribute() {
+        let mut user = json!({ "userName": "jane", "name": { "givenName": "Jane" } });
+
+        apply_patch(
+            &mut user,
+            &[operation(
+                "Replace",
+                None,
+                json!({ "active": "False", "name.familyName": "Doe", "displayname": "Jane D." }),
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(user["active"], json!("False"));
+        assert_eq!(
+            user["name"],
+            json!({ "givenName": "Jane", "familyName": "Doe" })
+        );
+        assert_eq!(user["displayName"], json!("Jane D."));
+    }
+
+    #[test]
+    fn filtered_path_creates_the_missing_element() {
+        let mut user = json!({ "userName": "jane" });
+
+        apply_patch(
+            &mut user,
+            &[operation(
+                "replace",
+                Some(r#"emails[type eq "work"].value"#),
+                json!("jane@example.com"),
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(
+            user["emails"],
+            json!([{ "type": "work", "value": "jane@example.com" }])
+        );
+    }
+
+    #[test]
+    fn members_are_added_once_and_removed_by_filter_or_value() {
+        let mut group = json!({ "displayName": "Ops", "members": [{ "value": "a" }] });
+
+        apply_patch(
+            &mut group,
+            &[
+                operation(
+                    "add",
+                    Some("members"),
+                    json!([{ "value": "a" }, { "value": "b" }, { "value": "c" }]),
+                ),
+                operation("remove", Some(r#"members[value eq "b"]"#), Value::Null),
+                operation("remove", Some("members"), json!([{ "value": "c" }])),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(group["members"], json!([{ "value": "a" }]));
+    }
+
+    #[test]
+    fn extension_attributes_are_patched_in_their_schema() {
+        let mut user = json!({ "userName": "jane" });
+
+        apply_patch(
+            &mut user,
+            &[operation(
+                "add",
+                Some("urn:ietf:params:scim:schemas:extension:ferriskey:2.0:User:attributes.team"),
+                json!("platform"),
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(
+            user[FERRISKEY_USER_SCHEMA],
+            json!({ "attributes": { "team": "platform" } })
+        );
+    }
+
+    #[test]
+    fn invalid_operations_are_rejected() {
+        let mut user = json!({ "userName": "jane" });
+
+        assert!(matches!(
+            apply_patch(&mut user, &[operation("remove", None, Value::Null)]),
+            Err(ScimError::NoTarget(_))
+        ));
+        assert!(matches!(
+            apply_patch(
+                &mut user,
+                &[operation("move", Some("userName"), json!("x"))]
+            ),
+            Err(ScimError::InvalidSyntax(_))
+        ));
+        assert!(matches!(
+            apply_patch(
+                &mut user,
+                &[operation("add", Some("emails[type eq"), json!("x"))]
+            ),
+            Err(ScimError::InvalidPath(_))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, Policy},
+    },
+    realm::entities::Realm,
+    role::entities::permission::Permissions,
+    scim::ports::ScimPolicy,
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+impl<U, C, UR> ScimPolicy for FerriskeyPolicy<U, C, UR>
+where
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+{
+    async fn can_view_scim(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(identity).await?;
+
+        let permissions = self
+            .get_permission_for_target_realm(&user, target_realm)
+            .await?;
+
+        let has_permission = Permissions::has_one_of_permissions(
+            &permissions,
+            &[
+                Permissions::ManageRealm,
+                Permissions::ManageScim,
+                Permissions::ViewScim,
+            ],
+        );
+
+        Ok(has_permission)
+    }
+
+    async fn can_manage_scim(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(identity).await?;
+
+        let permissions = self
+            .get_permission_for_target_realm(&user, target_realm)
+            .await?;
+
+        let has_permission = Permissions::has_one_of_permissions(
+            &permissions,
+            &[Permissions::ManageRealm, Permissions::ManageScim],
+        );
+
+        Ok(has_permission)
+    }
+}
@@ -0,0 +1,101 @@
+use serde_json::Value;
+
+use crate::domain::{
+    authentication::value_objects::Identity, common::entities::app_errors::CoreError,
+    realm::entities::Realm,
+};
+
+use super::{
+    entities::{ScimBulkResponse, ScimGroup, ScimListResponse, ScimUser},
+    error::ScimError,
+    value_objects::{
+        CreateScimGroupInput, CreateScimUserInput, DeleteScimResourceInput, GetScimResourceInput,
+        ListScimResourcesInput, PatchScimResourceInput, ReplaceScimGroupInput,
+        ReplaceScimUserInput, ScimBulkInput,
+    },
+};
+
+/// SCIM 2.0 provisioning of realm users (`/Users`) and organizations
+/// (`/Groups`).
+pub trait ScimService: Send + Sync {
+    fn list_users(
+        &self,
+        identity: Identity,
+        input: ListScimResourcesInput,
+    ) -> impl Future<Output = Result<ScimListResponse, ScimError>> + Send;
+    fn get_user(
+        &self,
+        identity: Identity,
+        input: GetScimResourceInput,
+    ) -> impl Future<Output = Result<Value, ScimError>> + Send;
+    fn create_user(
+        &self,
+        identity: Identity,
+        input: CreateScimUserInput,
+    ) -> impl Future<Output = Result<ScimUser, ScimError>> + Send;
+    fn replace_user(
+        &self,
+        identity: Identity,
+        input: ReplaceScimUserInput,
+    ) -> impl Future<Output = Result<ScimUser, ScimError>> + Send;
+    fn patch_user(
+        &self,
+        identity: Identity,
+        input: PatchScimResourceInput,
+    ) -> impl Future<Output = Result<ScimUser, ScimError>> + Send;
+    fn delete_user(
+        &self,
+        identity: Identity,
+        input: DeleteScimResourceInput,
+    ) -> impl Future<Output = Result<(), ScimError>> + Send;
+
+    fn list_groups(
+        &self,
+        identity: Identity,
+        input: ListScimResourcesInput,
+    ) -> impl Future<Output = Result<ScimListResponse, ScimError>> + Send;
+    fn get_group(
+        &self,
+        identity: Identity,
+        input: GetScimResourceInput,
+    ) -> impl Future<Output = Result<Value, ScimError>> + Send;
+    fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateScimGroupInput,
+    ) -> impl Future<Output = Result<ScimGroup, ScimError>> + Send;
+    fn replace_group(
+        &self,
+        identity: Identity,
+        input: ReplaceScimGroupInput,
+    ) -> impl Future<Output = Result<ScimGroup, ScimError>> + Send;
+    fn patch_group(
+        &self,
+        identity: Identity,
+        input: PatchScimResourceInput,
+    ) -> impl Future<Output = Result<ScimGroup, ScimError>> + Send;
+    fn delete_group(
+        &self,
+        identity: Identity,
+        input: DeleteScimResourceInput,
+    ) -> impl Future<Output = Result<(), ScimError>> + Send;
+
+    fn bulk(
+        &self,
+        identity: Identity,
+        input: ScimBulkInput,
+    ) -> impl Future<Output = Result<ScimBulkResponse, ScimError>> + Send;
+}
+
+pub trait ScimPolicy: Send + Sync {
+    fn can_view_scim(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_scim(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
@@ -0,0 +1,1442 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::Arc,
+};
+
+use ferriskey_organization::{CreateOrganizationParams, OrganizationConfig};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::{
+            app_errors::CoreError,
+            listing::{ListCursor, PageRequest},
+        },
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    organization::ports::{
+        Organization, OrganizationAttributeRepository, OrganizationId,
+        OrganizationMemberRepository, OrganizationRepository, UpdateOrganizationParams,
+    },
+    realm::{entities::Realm, ports::RealmRepository},
+    role::{entities::Role, ports::RoleRepository},
+    seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
+    user::{
+        entities::User,
+        ports::{UserAttributeRepository, UserRepository, UserRoleRepository},
+        value_objects::{CreateUserRequest, UpdateUserRequest, UserFilter, UserSortField},
+    },
+    webhook::{
+        entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
+        ports::WebhookRepository,
+    },
+};
+
+use super::{
+    entities::{
+        BULK_RESPONSE_SCHEMA, FERRISKEY_USER_SCHEMA, GROUP_SCHEMA, MAX_BULK_OPERATIONS,
+        MAX_RESULTS, ScimBulkOperation, ScimBulkOperationResult, ScimBulkResponse, ScimEmail,
+        ScimErrorMessage, ScimGroup, ScimListResponse, ScimMeta, ScimName, ScimPatchRequest,
+        ScimReference, ScimUser, ScimUserExtension, USER_SCHEMA,
+    },
+    error::ScimError,
+    filter::{AttrPath, Filter, is_core_schema},
+    patch::apply_patch,
+    ports::{ScimPolicy, ScimService},
+    value_objects::{
+        CreateScimGroupInput, CreateScimUserInput, DeleteScimResourceInput, EXTERNAL_ID_ATTRIBUTE,
+        GROUP_RESOURCE, GetScimResourceInput, ListScimResourcesInput, PatchScimResourceInput,
+        ReplaceScimGroupInput, ReplaceScimUserInput, ScimBulkInput, ScimEndpoint, ScimQuery,
+        USER_RESOURCE,
+    },
+};
+
+const DEFAULT_COUNT: usize = 100;
+
+#[derive(Clone, Debug)]
+pub struct ScimServiceImpl<R, U, C, UR, RO, UA, OR, OAR, OMR, SE, W>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RO: RoleRepository,
+    UA: UserAttributeRepository,
+    OR: OrganizationRepository,
+    OAR: OrganizationAttributeRepository,
+    OMR: OrganizationMemberRepository,
+    SE: SecurityEventRepository,
+    W: WebhookRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) user_role_repository: Arc<UR>,
+    pub(crate) role_repository: Arc<RO>,
+    pub(crate) user_attribute_repository: Arc<UA>,
+    pub(crate) organization_repository: Arc<OR>,
+    pub(crate) organization_attribute_repository: Arc<OAR>,
+    pub(crate) organization_member_repository: Arc<OMR>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) webhook_repository: Arc<W>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, RO, UA, OR, OAR, OMR, SE, W>
+    ScimServiceImpl<R, U, C, UR, RO, UA, OR, OAR, OMR, SE, W>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RO: RoleRepository,
+    UA: UserAttributeRepository,
+    OR: OrganizationRepository,
+    OAR: OrganizationAttributeRepository,
+    OMR: OrganizationMemberRepository,
+    SE: SecurityEventRepository,
+    W: WebhookRepository,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        realm_repository: Arc<R>,
+        user_repository: Arc<U>,
+        user_role_repository: Arc<UR>,
+        role_repository: Arc<RO>,
+        user_attribute_repository: Arc<UA>,
+        organization_repository: Arc<OR>,
+        organization_attribute_repository: Arc<OAR>,
+        organization_member_repository: Arc<OMR>,
+        security_event_repository: Arc<SE>,
+        webhook_repository: Arc<W>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            user_repository,
+            user_role_repository,
+            role_repository,
+            user_attribute_repository,
+            organization_repository,
+            organization_attribute_repository,
+            organization_member_repository,
+            security_event_repository,
+            webhook_repository,
+            policy,
+        }
+    }
+
+    async fn authorize(
+        &self,
+        identity: &Identity,
+        endpoint: &ScimEndpoint,
+        manage: bool,
+    ) -> Result<Realm, ScimError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&endpoint.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let allowed = if manage {
+            self.policy.can_manage_scim(identity, &realm).await
+        } else {
+            self.policy.can_view_scim(identity, &realm).await
+        };
+        ensure_policy(allowed, "insufficient permissions for SCIM provisioning")?;
+
+        Ok(realm)
+    }
+
+    /// A regular user of the realm; service accounts are not exposed.
+    async fn find_user(&self, realm: &Realm, id: &str) -> Result<User, ScimError> {
+        let not_found = || ScimError::NotFound(format!("User {id}"));
+        let user_id = Uuid::parse_str(id).map_err(|_| not_found())?;
+
+        let user = match self.user_repository.get_by_id(user_id).await {
+            Ok(user) => user,
+            Err(CoreError::NotFound) => return Err(not_found()),
+            Err(e) => return Err(e.into()),
+        };
+        if user.realm_id != realm.id || user.client_id.is_some() {
+            return Err(not_found());
+        }
+
+        Ok(user)
+    }
+
+    async fn find_group(&self, realm: &Realm, id: &str) -> Result<Organization, ScimError> {
+        let not_found = || ScimError::NotFound(format!("Group {id}"));
+        let organization_id = Uuid::parse_str(id).map_err(|_| not_found())?;
+
+        let organization = self
+            .organization_repository
+            .get_organization_by_id(OrganizationId::new(organization_id))
+            .await?
+            .filter(|organization| organization.realm_id == realm.id)
+            .ok_or_else(not_found)?;
+
+        Ok(organization)
+    }
+
+    async fn to_scim_user(
+        &self,
+        endpoint: &ScimEndpoint,
+        user: &User,
+    ) -> Result<ScimUser, ScimError> {
+        let roles = self
+            .user_role_repository
+            .get_user_roles(user.id)
+            .await?
+            .into_iter()
+            .filter(|role| role.client_id.is_none())
+            .map(|role| ScimReference {
+                value: role.name.clone(),
+                display: Some(role.name),
+                reference: None,
+            })
+            .collect();
+
+        let mut attributes: BTreeMap<String, String> = self
+            .user_attribute_repository
+            .list_by_user_id(user.id)
+            .await?
+            .into_iter()
+            .map(|attribute| (attribute.key, attribute.value))
+            .collect();
+        let external_id = attributes.remove(EXTERNAL_ID_ATTRIBUTE);
+
+        let mut groups = Vec::new();
+        for membership in self
+            .organization_member_repository
+            .list_organizations_for_user(user.id)
+            .await?
+        {
+            if let Some(organization) = self
+                .organization_repository
+                .get_organization_by_id(membership.organization_id)
+                .await?
+            {
+                groups.push(group_reference(endpoint, &organization));
+            }
+        }
+
+        let formatted = [user.firstname.as_deref(), user.lastname.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let name = (!formatted.is_empty()).then(|| ScimName {
+            formatted: Some(formatted.clone()),
+            given_name: user.firstname.clone(),
+            family_name: user.lastname.clone(),
+        });
+
+        let mut schemas = vec![USER_SCHEMA.to_string()];
+        let extension = (!attributes.is_empty()).then(|| {
+            schemas.push(FERRISKEY_USER_SCHEMA.to_string());
+            ScimUserExtension { attributes }
+        });
+
+        Ok(ScimUser {
+            schemas,
+            id: Some(user.id.to_string()),
+            external_id,
+            user_name: user.username.clone(),
+            display_name: name.as_ref().and(Some(formatted)),
+            name,
+            emails: user
+                .email
+                .iter()
+                .map(|email| ScimEmail {
+                    value: email.clone(),
+                    kind: Some("work".to_string()),
+                    primary: Some(true),
+                })
+                .collect(),
+            active: Some(user.enabled),
+            roles,
+            groups,
+            extension,
+            meta: Some(ScimMeta {
+                resource_type: USER_RESOURCE.to_string(),
+                created: user.created_at,
+                last_modified: user.updated_at,
+                location: endpoint.location(USER_RESOURCE, user.id),
+            }),
+        })
+    }
+
+    async fn to_scim_group(
+        &self,
+        endpoint: &ScimEndpoint,
+        organization: &Organization,
+        with_members: bool,
+    ) -> Result<ScimGroup, ScimError> {
+        let external_id = self
+            .organization_attribute_repository
+            .list_attributes(organization.id)
+            .await?
+            .into_iter()
+            .find(|attribute| attribute.key == EXTERNAL_ID_ATTRIBUTE)
+            .map(|attribute| attribute.value);
+
+        let mut members = Vec::new();
+        if with_members {
+            for member in self
+                .organization_member_repository
+                .list_members(organization.id)
+                .await?
+            {
+                let Ok(user) = self.user_repository.get_by_id(member.user_id).await else {
+                    continue;
+                };
+                members.push(ScimReference {
+                    value: user.id.to_string(),
+                    display: Some(user.username),
+                    reference: Some(endpoint.location(USER_RESOURCE, user.id)),
+                });
+            }
+        }
+
+        let id: Uuid = organization.id.into();
+        Ok(ScimGroup {
+            schemas: vec![GROUP_SCHEMA.to_string()],
+            id: Some(id.to_string()),
+            external_id,
+            display_name: organization.name.clone(),
+            members,
+            meta: Some(ScimMeta {
+                resource_type: GROUP_RESOURCE.to_string(),
+                created: organization.created_at,
+                last_modified: organization.updated_at,
+                location: endpoint.location(GROUP_RESOURCE, id),
+            }),
+        })
+    }
+
+    async fn resolve_roles(&self, realm: &Realm, user: &ScimUser) -> Result<Vec<Role>, ScimError> {
+        let mut roles = Vec::new();
+        for reference in &user.roles {
+            let role = self
+                .role_repository
+                .find_by_name(reference.value.clone(), realm.id.into())
+                .await?
+                .filter(|role| role.client_id.is_none())
+                .ok_or_else(|| {
+                    ScimError::InvalidValue(format!("unknown realm role `{}`", reference.value))
+                })?;
+            roles.push(role);
+        }
+
+        Ok(roles)
+    }
+
+    /// Brings a user in line with its SCIM representation. Attributes the
+    /// client did not send (`externalId`, the FerrisKey extension) are left
+    /// untouched, so that clients unaware of them do not wipe them.
+    async fn write_user(
+        &self,
+        realm: &Realm,
+        user: User,
+        desired: ScimUser,
+    ) -> Result<User, ScimError> {
+        if desired.user_name != user.username {
+            return Err(ScimError::Mutability(
+                "userName cannot be changed".to_string(),
+            ));
+        }
+        let roles = self.resolve_roles(realm, &desired).await?;
+
+        let email = desired
+            .primary_email()
+            .map(|email| email.trim().to_string());
+        let firstname = desired
+            .name
+            .as_ref()
+            .and_then(|name| name.given_name.clone());
+        let lastname = desired
+            .name
+            .as_ref()
+            .and_then(|name| name.family_name.clone());
+        let enabled = desired.active.unwrap_or(user.enabled);
+
+        let user = if (&email, &firstname, &lastname, enabled)
+            != (&user.email, &user.firstname, &user.lastname, user.enabled)
+        {
+            let email_verified = user.email_verified && email == user.email;
+            self.user_repository
+                .update_user(
+                    user.id,
+                    UpdateUserRequest {
+                        firstname,
+                        lastname,
+                        email,
+                        email_verified,
+                        enabled,
+                        required_actions: None,
+                    },
+                )
+                .await?
+        } else {
+            user
+        };
+
+        self.sync_roles(&user, roles).await?;
+        self.sync_attributes(realm, &user, desired.external_id, desired.extension)
+            .await?;
+
+        Ok(user)
+    }
+
+    async fn sync_roles(&self, user: &User, roles: Vec<Role>) -> Result<(), ScimError> {
+        let current: HashMap<Uuid, Role> = self
+            .user_role_repository
+            .get_user_roles(user.id)
+            .await?
+            .into_iter()
+            .filter(|role| role.client_id.is_none())
+            .map(|role| (role.id, role))
+            .collect();
+        let desired: BTreeSet<Uuid> = roles.iter().map(|role| role.id).collect();
+
+        for role_id in &desired {
+            if !current.contains_key(role_id) {
+                self.user_role_repository
+                    .assign_role(user.id, *role_id)
+                    .await?;
+            }
+        }
+        for role_id in current.keys() {
+            if !desired.contains(role_id) {
+                self.user_role_repository
+                    .revoke_role(user.id, *role_id)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn sync_attributes(
+        &self,
+        realm: &Realm,
+        user: &User,
+        external_id: Option<String>,
+        extension: Option<ScimUserExtension>,
+    ) -> Result<(), ScimError> {
+        if external_id.is_none() && extension.is_none() {
+            return Ok(());
+        }
+
+        let current: HashMap<String, String> = self
+            .user_attribute_repository
+            .list_by_user_id(user.id)
+            .await?
+            .into_iter()
+            .map(|attribute| (attribute.key, attribute.value))
+            .collect();
+
+        let mut desired = HashMap::new();
+        if let Some(external_id) = external_id {
+            desired.insert(EXTERNAL_ID_ATTRIBUTE.to_string(), external_id);
+        }
+        let managed_keys = extension.as_ref().map(|_| {
+            current
+                .keys()
+                .filter(|key| key.as_str() != EXTERNAL_ID_ATTRIBUTE)
+                .cloned()
+                .collect::<Vec<_>>()
+        });
+        if let Some(extension) = extension {
+            for (key, value) in extension.attributes {
+                if key == EXTERNAL_ID_ATTRIBUTE {
+                    return Err(ScimError::InvalidValue(format!(
+                        "attribute `{key}` is reserved"
+                    )));
+                }
+                desired.insert(key, value);
+            }
+        }
+
+        for key in managed_keys.unwrap_or_default() {
+            if !desired.contains_key(&key) {
+                self.user_attribute_repository
+                    .delete_by_key(user.id, key)
+                    .await?;
+            }
+        }
+
+        let changed: HashMap<String, String> = desired
+            .into_iter()
+            .filter(|(key, value)| current.get(key) != Some(value))
+            .collect();
+        if !changed.is_empty() {
+            self.user_attribute_repository
+                .upsert_many(user.id, realm.id, changed)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Brings an organization's name, external id and members in line with
+    /// its SCIM representation.
+    async fn write_group(
+        &self,
+        realm: &Realm,
+        organization: Organization,
+        desired: ScimGroup,
+    ) -> Result<Organization, ScimError> {
+        let members = self.resolve_members(realm, &desired).await?;
+
+        let organization = if desired.display_name.trim() != organization.name {
+            self.organization_repository
+                .update_organization(
+                    organization.id,
+                    UpdateOrganizationParams {
+                        name: Some(required_display_name(&desired)?),
+                        alias: None,
+                        domain: None,
+                        redirect_url: None,
+                        description: None,
+                        enabled: None,
+                    },
+                )
+                .await?
+        } else {
+            organization
+        };
+
+        if let Some(external_id) = desired.external_id {
+            self.organization_attribute_repository
+                .upsert_attribute(
+                    organization.id,
+                    EXTERNAL_ID_ATTRIBUTE.to_string(),
+                    external_id,
+                )
+                .await?;
+        }
+
+        let current: BTreeSet<Uuid> = self
+            .organization_member_repository
+            .list_members(organization.id)
+            .await?
+            .into_iter()
+            .map(|member| member.user_id)
+            .collect();
+        for user_id in members.difference(&current) {
+            self.organization_member_repository
+                .add_member(organization.id, *user_id)
+                .await?;
+        }
+        for user_id in current.difference(&members) {
+            self.organization_member_repository
+                .remove_member(organization.id, *user_id)
+                .await?;
+        }
+
+        Ok(organization)
+    }
+
+    async fn resolve_members(
+        &self,
+        realm: &Realm,
+        group: &ScimGroup,
+    ) -> Result<BTreeSet<Uuid>, ScimError> {
+        let mut members = BTreeSet::new();
+        for member in &group.members {
+            let user = self.find_user(realm, &member.value).await.map_err(|_| {
+                ScimError::InvalidValue(format!(
+                    "member `{}` is not a user of the realm",
+                    member.value
+                ))
+            })?;
+            members.insert(user.id);
+        }
+
+        Ok(members)
+    }
+
+    async fn users_matching(
+        &self,
+        endpoint: &ScimEndpoint,
+        realm: &Realm,
+        filter: &Filter,
+    ) -> Result<Vec<ScimUser>, ScimError> {
+        // Narrow the candidates in the database with what the filter pins,
+        // then evaluate the filter itself on the full representations.
+        let mut candidates = UserFilter {
+            service_account: Some(false),
+            sort: UserSortField::Username,
+            ..Default::default()
+        };
+        if let Some(Value::String(user_name)) = filter.required_value("userName") {
+            candidates.search = Some(user_name.clone());
+        }
+        if let Some(Value::String(external_id)) = filter.required_value("externalId") {
+            candidates.attribute_key = Some(EXTERNAL_ID_ATTRIBUTE.to_string());
+            candidates.attribute_value = Some(external_id.clone());
+        }
+        if let Some(Value::Bool(active)) = filter.required_value("active") {
+            candidates.enabled = Some(*active);
+        }
+
+        let mut matching = Vec::new();
+        let mut cursor = None;
+        loop {
+            candidates.page = PageRequest {
+                limit: Some(PageRequest::MAX_LIMIT),
+                cursor: cursor.take(),
+                ..Default::default()
+            };
+            let mut users = self
+                .user_repository
+                .list_users(realm.id, candidates.clone())
+                .await?;
+            let has_more = users.len() > PageRequest::MAX_LIMIT as usize;
+            users.truncate(PageRequest::MAX_LIMIT as usize);
+
+            for user in &users {
+                let scim_user = self.to_scim_user(endpoint, user).await?;
+                if filter.matches(&to_json(&scim_user)?) {
+                    matching.push(scim_user);
+                }
+            }
+
+            match users.last() {
+                Some(last) if has_more => {
+                    cursor = Some(ListCursor {
+                        key: last.username.clone(),
+                        id: last.id,
+                    })
+                }
+                _ => break,
+            }
+        }
+
+        Ok(matching)
+    }
+
+    async fn run_bulk_operation(
+        &self,
+        identity: &Identity,
+        endpoint: &ScimEndpoint,
+        operation: ScimBulkOperation,
+    ) -> Result<(u16, Option<String>, Option<Value>), ScimError> {
+        let method = operation.method.to_ascii_uppercase();
+        let segments: Vec<&str> = operation
+            .path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        let data = || {
+            operation
+                .data
+                .clone()
+                .ok_or_else(|| ScimError::InvalidSyntax(format!("{method} requires data")))
+        };
+        let endpoint = endpoint.clone();
+
+        match (method.as_str(), segments.as_slice()) {
+            ("POST", ["Users"]) => {
+                let user = self
+                    .create_user(
+                        identity.clone(),
+                        CreateScimUserInput {
+                            endpoint,
+                            user: parse(data()?)?,
+                        },
+                    )
+                    .await?;
+                Ok((201, location(&user.meta), Some(to_json(&user)?)))
+            }
+            ("PUT", ["Users", id]) => {
+                let user = self
+                    .replace_user(
+                        identity.clone(),
+                        ReplaceScimUserInput {
+                            endpoint,
+                            id: id.to_string(),
+                            user: parse(data()?)?,
+                        },
+                    )
+                    .await?;
+                Ok((200, location(&user.meta), Some(to_json(&user)?)))
+            }
+            ("PATCH", ["Users", id]) => {
+                let user = self
+                    .patch_user(
+                        identity.clone(),
+                        PatchScimResourceInput {
+                            endpoint,
+                            id: id.to_string(),
+                            patch: parse(data()?)?,
+                        },
+                    )
+                    .await?;
+                Ok((200, location(&user.meta), Some(to_json(&user)?)))
+            }
+            ("DELETE", ["Users", id]) => {
+                let location = endpoint.url() + "/Users/" + id;
+                self.delete_user(
+                    identity.clone(),
+                    DeleteScimResourceInput {
+                        endpoint,
+                        id: id.to_string(),
+                    },
+                )
+                .await?;
+                Ok((204, Some(location), None))
+            }
+            ("POST", ["Groups"]) => {
+                let group = self
+                    .create_group(
+                        identity.clone(),
+                        CreateScimGroupInput {
+                            endpoint,
+                            group: parse(data()?)?,
+                        },
+                    )
+                    .await?;
+                Ok((201, location(&group.meta), Some(to_json(&group)?)))
+            }
+            ("PUT", ["Groups", id]) => {
+                let group = self
+                    .replace_group(
+                        identity.clone(),
+                        ReplaceScimGroupInput {
+                            endpoint,
+                            id: id.to_string(),
+                            group: parse(data()?)?,
+                        },
+                    )
+                    .await?;
+                Ok((200, location(&group.meta), Some(to_json(&group)?)))
+            }
+            ("PATCH", ["Groups", id]) => {
+                let group = self
+                    .patch_group(
+                        identity.clone(),
+                        PatchScimResourceInput {
+                            endpoint,
+                            id: id.to_string(),
+                            patch: parse(data()?)?,
+                        },
+                    )
+                    .await?;
+                Ok((200, location(&group.meta), Some(to_json(&group)?)))
+            }
+            ("DELETE", ["Groups", id]) => {
+                let location = endpoint.url() + "/Groups/" + id;
+                self.delete_group(
+                    identity.clone(),
+                    DeleteScimResourceInput {
+                        endpoint,
+                        id: id.to_string(),
+                    },
+                )
+                .await?;
+                Ok((204, Some(location), None))
+            }
+            _ => Err(ScimError::InvalidPath(format!(
+                "unsupported bulk operation {method} {}",
+                operation.path
+            ))),
+        }
+    }
+
+    async fn notify_user(
+        &self,
+        identity: &Identity,
+        realm: &Realm,
+        user: &User,
+        trigger: WebhookTrigger,
+    ) -> Result<(), ScimError> {
+        let event_type = match trigger {
+            WebhookTrigger::UserCreated => Some(SecurityEventType::UserCreated),
+            WebhookTrigger::UserDeleted => Some(SecurityEventType::UserDeleted),
+            _ => None,
+        };
+        if let Some(event_type) = event_type {
+            self.security_event_repository
+                .store_event(
+                    SecurityEvent::new(realm.id, event_type, EventStatus::Success, identity.id())
+                        .with_target("user".to_string(), user.id, None),
+                )
+                .await?;
+        }
+
+        self.webhook_repository
+            .notify(
+                realm.id,
+                WebhookPayload::new(trigger, realm.id.into(), Some(user.clone())),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl<R, U, C, UR, RO, UA, OR, OAR, OMR, SE, W> ScimService
+    for ScimServiceImpl<R, U, C, UR, RO, UA, OR, OAR, OMR, SE, W>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    RO: RoleRepository,
+    UA: UserAttributeRepository,
+    OR: OrganizationRepository,
+    OAR: OrganizationAttributeRepository,
+    OMR: OrganizationMemberRepository,
+    SE: SecurityEventRepository,
+    W: WebhookRepository,
+{
+    async fn list_users(
+        &self,
+        identity: Identity,
+        input: ListScimResourcesInput,
+    ) -> Result<ScimListResponse, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, false).await?;
+        let query = input.query;
+        let (start_index, count) = page_bounds(&query);
+
+        let (total, users) = match query.filter.as_deref().map(Filter::parse).transpose()? {
+            Some(filter) => {
+                let users = self
+                    .users_matching(&input.endpoint, &realm, &filter)
+                    .await?;
+                let total = users.len();
+                let page = users
+                    .into_iter()
+                    .skip(start_index - 1)
+                    .take(count)
+                    .collect();
+                (total, page)
+            }
+            None => {
+                let filter = UserFilter {
+                    service_account: Some(false),
+                    ..Default::default()
+                };
+                let total = self
+                    .user_repository
+                    .count_users(realm.id, filter.clone())
+                    .await? as usize;
+
+                let mut page = Vec::new();
+                if count > 0 {
+                    let users = self
+                        .user_repository
+                        .list_users(
+                            realm.id,
+                            UserFilter {
+                                page: PageRequest {
+                                    limit: Some(count as u32),
+                                    offset: Some((start_index - 1) as u32),
+                                    ..Default::default()
+                                },
+                                ..filter
+                            },
+                        )
+                        .await?;
+                    for user in users.iter().take(count) {
+                        page.push(self.to_scim_user(&input.endpoint, user).await?);
+                    }
+                }
+                (total, page)
+            }
+        };
+
+        let resources = users
+            .iter()
+            .map(|user| {
+                Ok(project(
+                    to_json(user)?,
+                    &query.attributes,
+                    &query.excluded_attributes,
+                ))
+            })
+            .collect::<Result<_, ScimError>>()?;
+
+        Ok(ScimListResponse::new(total, start_index, resources))
+    }
+
+    async fn get_user(
+        &self,
+        identity: Identity,
+        input: GetScimResourceInput,
+    ) -> Result<Value, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, false).await?;
+        let user = self.find_user(&realm, &input.id).await?;
+        let user = self.to_scim_user(&input.endpoint, &user).await?;
+
+        Ok(project(
+            to_json(&user)?,
+            &input.attributes,
+            &input.excluded_attributes,
+        ))
+    }
+
+    async fn create_user(
+        &self,
+        identity: Identity,
+        input: CreateScimUserInput,
+    ) -> Result<ScimUser, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let desired = input.user;
+
+        let username = desired.user_name.trim().to_string();
+        if username.is_empty() {
+            return Err(ScimError::InvalidValue("userName is required".to_string()));
+        }
+        if self
+            .user_repository
+            .get_by_username(username.clone(), realm.id)
+            .await
+            .is_ok()
+        {
+            return Err(ScimError::Uniqueness(format!(
+                "userName `{username}` is already taken"
+            )));
+        }
+        self.resolve_roles(&realm, &desired).await?;
+
+        let user = self
+            .user_repository
+            .create_user(CreateUserRequest {
+                realm_id: realm.id,
+                client_id: None,
+                username: username.clone(),
+                firstname: None,
+                lastname: None,
+                email: None,
+                email_verified: false,
+                enabled: desired.active.unwrap_or(true),
+            })
+            .await?;
+        let user = self
+            .write_user(
+                &realm,
+                user,
+                ScimUser {
+                    user_name: username,
+                    ..desired
+                },
+            )
+            .await?;
+
+        self.notify_user(&identity, &realm, &user, WebhookTrigger::UserCreated)
+            .await?;
+
+        self.to_scim_user(&input.endpoint, &user).await
+    }
+
+    async fn replace_user(
+        &self,
+        identity: Identity,
+        input: ReplaceScimUserInput,
+    ) -> Result<ScimUser, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let user = self.find_user(&realm, &input.id).await?;
+
+        let user = self.write_user(&realm, user, input.user).await?;
+        self.notify_user(&identity, &realm, &user, WebhookTrigger::UserUpdated)
+            .await?;
+
+        self.to_scim_user(&input.endpoint, &user).await
+    }
+
+    async fn patch_user(
+        &self,
+        identity: Identity,
+        input: PatchScimResourceInput,
+    ) -> Result<ScimUser, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let user = self.find_user(&realm, &input.id).await?;
+
+        let current = self.to_scim_user(&input.endpoint, &user).await?;
+        let desired: ScimUser = patched(&current, &input.patch)?;
+
+        let user = self.write_user(&realm, user, desired).await?;
+        self.notify_user(&identity, &realm, &user, WebhookTrigger::UserUpdated)
+            .await?;
+
+        self.to_scim_user(&input.endpoint, &user).await
+    }
+
+    async fn delete_user(
+        &self,
+        identity: Identity,
+        input: DeleteScimResourceInput,
+    ) -> Result<(), ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let user = self.find_user(&realm, &input.id).await?;
+
+        self.user_repository.delete_user(user.id).await?;
+        self.notify_user(&identity, &realm, &user, WebhookTrigger::UserDeleted)
+            .await
+    }
+
+    async fn list_groups(
+        &self,
+        identity: Identity,
+        input: ListScimResourcesInput,
+    ) -> Result<ScimListResponse, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, false).await?;
+        let query = input.query;
+        let (start_index, count) = page_bounds(&query);
+        let filter = query.filter.as_deref().map(Filter::parse).transpose()?;
+        let with_members = !excludes(&query, "members");
+
+        let mut groups = Vec::new();
+        for organization in self
+            .organization_repository
+            .list_organizations_by_realm(realm.id)
+            .await?
+        {
+            let group = self
+                .to_scim_group(&input.endpoint, &organization, with_members)
+                .await?;
+            let group = to_json(&group)?;
+            if filter.as_ref().is_none_or(|filter| filter.matches(&group)) {
+                groups.push(group);
+            }
+        }
+
+        let total = groups.len();
+        let resources = groups
+            .into_iter()
+            .skip(start_index - 1)
+            .take(count)
+            .map(|group| project(group, &query.attributes, &query.excluded_attributes))
+            .collect();
+
+        Ok(ScimListResponse::new(total, start_index, resources))
+    }
+
+    async fn get_group(
+        &self,
+        identity: Identity,
+        input: GetScimResourceInput,
+    ) -> Result<Value, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, false).await?;
+        let organization = self.find_group(&realm, &input.id).await?;
+        let with_members = !input
+            .excluded_attributes
+            .iter()
+            .any(|attribute| attribute.eq_ignore_ascii_case("members"));
+
+        let group = self
+            .to_scim_group(&input.endpoint, &organization, with_members)
+            .await?;
+
+        Ok(project(
+            to_json(&group)?,
+            &input.attributes,
+            &input.excluded_attributes,
+        ))
+    }
+
+    async fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateScimGroupInput,
+    ) -> Result<ScimGroup, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let name = required_display_name(&input.group)?;
+        self.resolve_members(&realm, &input.group).await?;
+
+        // Organizations need a unique alias; derive one from the name.
+        let base_alias = alias_for(&name);
+        let mut alias = base_alias.clone();
+        let mut suffix = 1;
+        while self
+            .organization_repository
+            .exists_organization_by_realm_and_alias(realm.id, &alias)
+            .await?
+        {
+            suffix += 1;
+            alias = format!("{base_alias}-{suffix}");
+        }
+
+        let organization = Organization::new(OrganizationConfig {
+            realm_id: realm.id,
+            name,
+            alias,
+            domain: None,
+            redirect_url: None,
+            description: None,
+            enabled: true,
+        })
+        .map_err(|e| ScimError::InvalidValue(e.to_string()))?;
+
+        let organization = self
+            .organization_repository
+            .create_organization(CreateOrganizationParams {
+                realm_id: organization.realm_id,
+                name: organization.name,
+                alias: organization.alias,
+                domain: None,
+                redirect_url: None,
+                description: None,
+                enabled: organization.enabled,
+            })
+            .await?;
+        let organization = self.write_group(&realm, organization, input.group).await?;
+
+        self.to_scim_group(&input.endpoint, &organization, true)
+            .await
+    }
+
+    async fn replace_group(
+        &self,
+        identity: Identity,
+        input: ReplaceScimGroupInput,
+    ) -> Result<ScimGroup, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let organization = self.find_group(&realm, &input.id).await?;
+
+        let organization = self.write_group(&realm, organization, input.group).await?;
+
+        self.to_scim_group(&input.endpoint, &organization, true)
+            .await
+    }
+
+    async fn patch_group(
+        &self,
+        identity: Identity,
+        input: PatchScimResourceInput,
+    ) -> Result<ScimGroup, ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let organization = self.find_group(&realm, &input.id).await?;
+
+        let current = self
+            .to_scim_group(&input.endpoint, &organization, true)
+            .await?;
+        let desired: ScimGroup = patched(&current, &input.patch)?;
+
+        let organization = self.write_group(&realm, organization, desired).await?;
+
+        self.to_scim_group(&input.endpoint, &organization, true)
+            .await
+    }
+
+    async fn delete_group(
+        &self,
+        identity: Identity,
+        input: DeleteScimResourceInput,
+    ) -> Result<(), ScimError> {
+        let realm = self.authorize(&identity, &input.endpoint, true).await?;
+        let organization = self.find_group(&realm, &input.id).await?;
+
+        self.organization_repository
+            .delete_organization(organization.id)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn bulk(
+        &self,
+        identity: Identity,
+        input: ScimBulkInput,
+    ) -> Result<ScimBulkResponse, ScimError> {
+        self.authorize(&identity, &input.endpoint, true).await?;
+        let request = input.request;
+        if request.operations.len() > MAX_BULK_OPERATIONS {
+            return Err(ScimError::TooMany(MAX_BULK_OPERATIONS));
+        }
+
+        let mut bulk_ids: HashMap<String, String> = HashMap::new();
+        let mut results = Vec::new();
+        let mut errors = 0;
+
+        for mut operation in request.operations {
+            if request
+                .fail_on_errors
+                .is_some_and(|fail_on_errors| errors >= fail_on_errors)
+            {
+                break;
+            }
+
+            let method = operation.method.to_ascii_uppercase();
+            let bulk_id = operation.bulk_id.clone();
+            let outcome = match resolve_bulk_ids(&mut operation, &bulk_ids) {
+                Ok(()) => {
+                    self.run_bulk_operation(&identity, &input.endpoint, operation)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            let result = match outcome {
+                Ok((status, location, response)) => {
+                    if let (Some(bulk_id), Some(id)) = (
+                        &bulk_id,
+                        response.as_ref().and_then(|response| response.get("id")),
+                    ) && let Some(id) = id.as_str()
+                    {
+                        bulk_ids.insert(bulk_id.clone(), id.to_string());
+                    }
+                    ScimBulkOperationResult {
+                        method,
+                        bulk_id,
+                        location,
+                        status: status.to_string(),
+                        response: None,
+                    }
+                }
+                Err(e) => {
+                    errors += 1;
+                    ScimBulkOperationResult {
+                        method,
+                        bulk_id,
+                        location: None,
+                        status: e.status().to_string(),
+                        response: Some(to_json(&ScimErrorMessage::from(&e))?),
+                    }
+                }
+            };
+            results.push(result);
+        }
+
+        Ok(ScimBulkResponse {
+            schemas: vec![BULK_RESPONSE_SCHEMA.to_string()],
+            operations: results,
+        })
+    }
+}
+
+fn group_reference(endpoint: &ScimEndpoint, organization: &Organization) -> ScimReference {
+    let id: Uuid = organization.id.into();
+
+    ScimReference {
+        value: id.to_string(),
+        display: Some(organization.name.clone()),
+        reference: Some(endpoint.location(GROUP_RESOURCE, id)),
+    }
+}
+
+fn required_display_name(group: &ScimGroup) -> Result<String, ScimError> {
+    let name = group.display_name.trim();
+    if name.is_empty() {
+        return Err(ScimError::InvalidValue(
+            "displayName is required".to_string(),
+        ));
+    }
+
+    Ok(name.to_string())
+}
+
+/// Organization alias derived from a group name: `Site Reliability` becomes
+/// `site-reliability`.
+fn alias_for(name: &str) -> String {
+    let alias = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if alias.is_empty() {
+        "group".to_string()
+    } else {
+        alias
+    }
+}
+
+/// 1-based start index and page size of a list request.
+fn page_bounds(query: &ScimQuery) -> (usize, usize) {
+    (
+        query.start_index.unwrap_or(1).max(1),
+        query.count.unwrap_or(DEFAULT_COUNT).min(MAX_RESULTS),
+    )
+}
+
+fn excludes(query: &ScimQuery, attribute: &str) -> bool {
+    query
+        .excluded_attributes
+        .iter()
+        .any(|excluded| excluded.eq_ignore_ascii_case(attribute))
+}
+
+/// Applies `attributes` / `excludedAttributes` (RFC 7644 §3.9). `schemas`,
+/// `id` and `meta` are always returned.
+fn project(mut resource: Value, attributes: &[String], excluded: &[String]) -> Value {
+    const ALWAYS_RETURNED: [&str; 3] = ["schemas", "id", "meta"];
+    let Value::Object(map) = &mut resource else {
+        return resource;
+    };
+    let always = |key: &str| {
+        ALWAYS_RETURNED
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(key))
+    };
+    let designates = |path: &AttrPath, key: &str| match &path.schema {
+        Some(schema) if !is_core_schema(schema) => key.eq_ignore_ascii_case(schema),
+        _ => key.eq_ignore_ascii_case(&path.name),
+    };
+
+    let requested: Vec<AttrPath> = attributes
+        .iter()
+        .filter_map(|a| AttrPath::parse(a))
+        .collect();
+    if !requested.is_empty() {
+        map.retain(|key, _| always(key) || requested.iter().any(|path| designates(path, key)));
+    }
+
+    for path in excluded.iter().filter_map(|a| AttrPath::parse(a)) {
+        match &path.schema {
+            Some(schema) if !is_core_schema(schema) => {
+                if let Some(Value::Object(extension)) = map
+                    .iter_mut()
+                    .find(|(key, _)| key.eq_ignore_ascii_case(schema))
+                    .map(|(_, value)| value)
+                {
+                    extension.retain(|key, _| !key.eq_ignore_ascii_case(&path.name));
+                }
+            }
+            _ => map.retain(|key, _| always(key) || !key.eq_ignore_ascii_case(&path.name)),
+        }
+    }
+
+    resource
+}
+
+/// The resource after the PATCH operations, validated against its type.
+fn patched<T>(current: &T, patch: &ScimPatchRequest) -> Result<T, ScimError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut resource = to_json(current)?;
+    apply_patch(&mut resource, &patch.operations)?;
+
+    serde_json::from_value(resource).map_err(|e| ScimError::InvalidValue(e.to_string()))
+}
+
+/// Replaces `bulkId:<id>` references with the ids of resources created
+/// earlier in the same request.
+fn resolve_bulk_ids(
+    operation: &mut ScimBulkOperation,
+    bulk_ids: &HashMap<String, String>,
+) -> Result<(), ScimError> {
+    fn resolve(value: &mut Value, bulk_ids: &HashMap<String, String>) -> Result<(), ScimError> {
+        match value {
+            Value::String(reference) => {
+                if let Some(bulk_id) = reference.strip_prefix("bulkId:") {
+                    *reference = bulk_ids.get(bulk_id).cloned().ok_or_else(|| {
+                        ScimError::InvalidValue(format!("unknown bulkId `{bulk_id}`"))
+                    })?;
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    resolve(value, bulk_ids)?;
+                }
+            }
+            Value::Object(map) => {
+                for value in map.values_mut() {
+                    resolve(value, bulk_ids)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    if let Some(data) = operation.data.as_mut() {
+        resolve(data, bulk_ids)?;
+    }
+    let mut segments = Vec::new();
+    for segment in operation.path.split('/') {
+        let mut segment = Value::String(segment.to_string());
+        resolve(&mut segment, bulk_ids)?;
+        segments.push(segment.as_str().unwrap_or_default().to_string());
+    }
+    operation.path = segments.join("/");
+
+    Ok(())
+}
+
+fn location(meta: &Option<ScimMeta>) -> Option<String> {
+    meta.as_ref().map(|meta| meta.location.clone())
+}
+
+fn parse<T: serde::de::DeserializeOwned>(data: Value) -> Result<T, ScimError> {
+    serde_json::from_value(data).map_err(|e| ScimError::InvalidSyntax(e.to_string()))
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<Value, ScimError> {
+    serde_json::to_value(value).map_err(|_| CoreError::InternalServerError.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn projection_keeps_identifiers_and_honours_exclusions() {
+        let group = json!({
+            "schemas": [GROUP_SCHEMA],
+            "id": "1",
+            "displayName": "Ops",
+            "members": [{ "value": "u" }],
+            "meta": { "resourceType": "Group" }
+        });
+
+        let projected = project(group.clone(), &["displayName".to_string()], &[]);
+        assert_eq!(
+            projected.as_object().unwrap().keys().collect::<Vec<_>>(),
+            ["displayName", "id", "meta", "schemas"]
+        );
+
+        let projected = project(group, &[], &["members".to_string(), "id".to_string()]);
+        assert!(projected.get("members").is_none());
+        assert!(projected.get("id").is_some());
+    }
+
+    #[test]
+    fn bulk_references_are_resolved_in_data_and_path() {
+        let mut operation = ScimBulkOperation {
+            method: "PATCH".to_string(),
+            bulk_id: None,
+            path: "/Groups/bulkId:ops".to_string(),
+            data: Some(json!({ "members": [{ "value": "bulkId:jane" }] })),
+        };
+        let bulk_ids = HashMap::from([
+            ("ops".to_string(), "g-1".to_string()),
+            ("jane".to_string(), "u-1".to_string()),
+        ]);
+
+        resolve_bulk_ids(&mut operation, &bulk_ids).unwrap();
+
+        assert_eq!(operation.path, "/Groups/g-1");
+        assert_eq!(operation.data.unwrap()["members"][0]["value"], "u-1");
+
+        let mut dangling = ScimBulkOperation {
+            method: "DELETE".to_string(),
+            bulk_id: None,
+            path: "/Users/bulkId:missing".to_string(),
+            data: None,
+        };
+        assert!(matches!(
+            resolve_bulk_ids(&mut dangling, &bulk_ids),
+            Err(ScimError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn group_names_become_organization_aliases() {
+        assert_eq!(
+            alias_for("Site Reliability / EMEA"),
+            "site-reliability-emea"
+        );
+        assert_eq!(alias_for("日本"), "group");
+    }
+}
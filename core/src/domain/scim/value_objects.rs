@@ -0,0 +1,88 @@
+use uuid::Uuid;
+
+use super::entities::{ScimBulkRequest, ScimGroup, ScimPatchRequest, ScimUser};
+
+/// User attribute holding the `externalId` a SCIM client assigned to a user.
+/// Groups keep theirs in an organization attribute of the same key.
+pub const EXTERNAL_ID_ATTRIBUTE: &str = "scim.external_id";
+
+pub const USER_RESOURCE: &str = "User";
+pub const GROUP_RESOURCE: &str = "Group";
+
+/// The realm a SCIM request targets and the root-scoped public base URL used
+/// to build resource locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScimEndpoint {
+    pub realm_name: String,
+    pub base_url: String,
+}
+
+impl ScimEndpoint {
+    pub fn url(&self) -> String {
+        format!("{}/realms/{}/scim/v2", self.base_url, self.realm_name)
+    }
+
+    pub fn location(&self, resource_type: &str, id: Uuid) -> String {
+        format!("{}/{resource_type}s/{id}", self.url())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScimQuery {
+    pub filter: Option<String>,
+    pub attributes: Vec<String>,
+    pub excluded_attributes: Vec<String>,
+    /// 1-based index of the first result
+    pub start_index: Option<usize>,
+    pub count: Option<usize>,
+}
+
+pub struct ListScimResourcesInput {
+    pub endpoint: ScimEndpoint,
+    pub query: ScimQuery,
+}
+
+pub struct GetScimResourceInput {
+    pub endpoint: ScimEndpoint,
+    pub id: String,
+    pub attributes: Vec<String>,
+    pub excluded_attributes: Vec<String>,
+}
+
+pub struct CreateScimUserInput {
+    pub endpoint: ScimEndpoint,
+    pub user: ScimUser,
+}
+
+pub struct ReplaceScimUserInput {
+    pub endpoint: ScimEndpoint,
+    pub id: String,
+    pub user: ScimUser,
+}
+
+pub struct CreateScimGroupInput {
+    pub endpoint: ScimEndpoint,
+    pub group: ScimGroup,
+}
+
+pub struct ReplaceScimGroupInput {
+    pub endpoint: ScimEndpoint,
+    pub id: String,
+    pub group: ScimGroup,
+}
+
+pub struct PatchScimResourceInput {
+    pub endpoint: ScimEndpoint,
+    pub id: String,
+    pub patch: ScimPatchRequest,
+}
+
+pub struct DeleteScimResourceInput {
+    pub endpoint: ScimEndpoint,
+    pub id: String,
+}
+
+pub struct ScimBulkInput {
+    pub endpoint: ScimEndpoint,
+    pub request: ScimBulkRequest,
+}
@@ -0,0 +1,483 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, SubsecRound, Utc};
+use jsonwebtoken::{Algorithm, Header, Validation};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::{
+    common::{entities::app_errors::CoreError, generate_uuid_v7},
+    jwt::entities::JwtKeyPair,
+    realm::entities::RealmId,
+};
+
+use super::entities::{
+    BrokenLink, BrokenLinkReason, SecurityEvent, SecurityEventChainHead, SecurityEventCheckpoint,
+    SecurityEventLink,
+};
+
+/// `prev_hash` of the first event of every realm chain.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Fields covered by the chain hash, in a fixed order. Enums are hashed by
+/// their stored string form and the timestamp at the storage precision.
+#[derive(Serialize)]
+struct CanonicalEvent<'a> {
+    id: Uuid,
+    realm_id: Uuid,
+    sequence: i64,
+    timestamp_micros: i64,
+    event_type: String,
+    status: String,
+    actor_id: Option<Uuid>,
+    actor_type: Option<String>,
+    target_type: Option<&'a str>,
+    target_id: Option<Uuid>,
+    resource: Option<&'a str>,
+    trace_id: Option<&'a str>,
+    ip_address: Option<&'a str>,
+    user_agent: Option<&'a str>,
+    details: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CheckpointClaims {
+    realm_id: Uuid,
+    sequence: i64,
+    hash: String,
+    iat: i64,
+}
+
+/// Postgres keeps timestamps to the microsecond, so events are truncated
+/// before hashing to make the hash reproducible from the stored row.
+pub fn normalize_timestamp(timestamp: DateTime<Utc>) -> DateTime<Utc> {
+    timestamp.trunc_subsecs(6)
+}
+
+/// `sha256(prev_hash || "\n" || canonical_json(event))`, hex encoded.
+pub fn compute_event_hash(prev_hash: &str, sequence: i64, event: &SecurityEvent) -> String {
+    let canonical = CanonicalEvent {
+        id: event.id.clone().into(),
+        realm_id: event.realm_id.into(),
+        sequence,
+        timestamp_micros: event.timestamp.timestamp_micros(),
+        event_type: event.event_type.to_string(),
+        status: event.status.to_string(),
+        actor_id: event.actor_id,
+        actor_type: event.actor_type.as_ref().map(|t| t.to_string()),
+        target_type: event.target_type.as_deref(),
+        target_id: event.target_id,
+        resource: event.resource.as_deref(),
+        trace_id: event.trace_id.as_deref(),
+        ip_address: event.ip_address.as_deref(),
+        user_agent: event.user_agent.as_deref(),
+        details: event.details.as_ref().map(canonicalize),
+    };
+
+    // Serializing plain structs, strings and JSON values cannot fail.
+    let payload = serde_json::to_vec(&canonical).unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(prev_hash.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(&payload);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Rebuilds JSON objects with sorted keys. JSONB does not preserve key order,
+/// so `details` read back from the database may differ from what was hashed.
+fn canonicalize(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| (key.clone(), canonicalize(value)))
+                .collect::<BTreeMap<_, _>>()
+                .into_iter()
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(canonicalize).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Signs the current chain head with the realm key.
+pub fn sign_checkpoint(
+    head: &SecurityEventChainHead,
+    key_pair: &JwtKeyPair,
+) -> Result<SecurityEventCheckpoint, CoreError> {
+    let now = Utc::now();
+    let claims = CheckpointClaims {
+        realm_id: head.realm_id.into(),
+        sequence: head.sequence,
+        hash: head.hash.clone(),
+        iat: now.timestamp(),
+    };
+
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(key_pair.id.to_string());
+
+    let signature =
+        jsonwebtoken::encode(&header, &claims, &key_pair.encoding_key).map_err(|e| {
+            tracing::error!("Failed to sign security event checkpoint: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+    Ok(SecurityEventCheckpoint {
+        id: generate_uuid_v7(),
+        realm_id: head.realm_id,
+        sequence: head.sequence,
+        hash: head.hash.clone(),
+        signature,
+        created_at: now,
+    })
+}
+
+/// Checks that the signature verifies and that it covers exactly the stored
+/// realm, sequence and hash.
+pub fn verify_checkpoint_signature(
+    checkpoint: &SecurityEventCheckpoint,
+    key_pair: &JwtKeyPair,
+) -> bool {
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.required_spec_claims.clear();
+    validation.validate_exp = false;
+
+    match jsonwebtoken::decode::<CheckpointClaims>(
+        &checkpoint.signature,
+        &key_pair.decoding_key,
+        &validation,
+    ) {
+        Ok(data) => {
+            let realm_id: Uuid = checkpoint.realm_id.into();
+            data.claims.realm_id == realm_id
+                && data.claims.sequence == checkpoint.sequence
+                && data.claims.hash == checkpoint.hash
+        }
+        Err(_) => false,
+    }
+}
+
+/// Walks a realm chain in ascending sequence order, one link at a time, so
+/// callers can stream links from storage in batches.
+#[derive(Debug, Default)]
+pub struct ChainVerifier {
+    previous: Option<(i64, String)>,
+    first_sequence: Option<i64>,
+    verified_events: u64,
+    /// Signature-checked checkpoints, keyed by sequence.
+    checkpoints: BTreeMap<i64, String>,
+    verified_checkpoints: u64,
+}
+
+impl ChainVerifier {
+    pub fn new(checkpoints: &[SecurityEventCheckpoint]) -> Self {
+        Self {
+            checkpoints: checkpoints
+                .iter()
+                .map(|checkpoint| (checkpoint.sequence, checkpoint.hash.clone()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn verify(&mut self, link: &SecurityEventLink) -> Result<(), BrokenLink> {
+        let event_id: Uuid = link.event.id.clone().into();
+        let broken = |sequence, event_id, reason| BrokenLink {
+            sequence,
+            event_id,
+            reason,
+        };
+
+        match &self.previous {
+            Some((sequence, _)) if link.sequence != sequence + 1 => {
+                return Err(broken(sequence + 1, None, BrokenLinkReason::MissingEvent));
+            }
+            Some((_, hash)) if &link.prev_hash != hash => {
+                return Err(broken(
+                    link.sequence,
+                    Some(event_id),
+                    BrokenLinkReason::PrevHashMismatch,
+                ));
+            }
+            // A chain starting after 1 had its prefix purged by retention:
+            // the first remaining link is taken as the anchor.
+            None if link.sequence == 1 && link.prev_hash != GENESIS_HASH => {
+                return Err(broken(
+                    link.sequence,
+                    Some(event_id),
+                    BrokenLinkReason::PrevHashMismatch,
+                ));
+            }
+            _ => {}
+        }
+
+        if !link.pseudonymized
+            && compute_event_hash(&link.prev_hash, link.sequence, &link.event) != link.hash
+        {
+            return Err(broken(
+                link.sequence,
+                Some(event_id),
+                BrokenLinkReason::HashMismatch,
+            ));
+        }
+
+        if let Some(expected) = self.checkpoints.get(&link.sequence) {
+            if expected != &link.hash {
+                return Err(broken(
+                    link.sequence,
+                    Some(event_id),
+                    BrokenLinkReason::CheckpointMismatch,
+                ));
+            }
+            self.verified_checkpoints += 1;
+        }
+
+        self.first_sequence.get_or_insert(link.sequence);
+        self.previous = Some((link.sequence, link.hash.clone()));
+        self.verified_events += 1;
+
+        Ok(())
+    }
+
+    /// Checks the end of the chain once every link has been fed: the head
+    /// and every checkpoint must be reachable from the last verified link.
+    pub fn finish(&self, head: Option<&SecurityEventChainHead>) -> Result<(), BrokenLink> {
+        let last_sequence = self.last_sequence().unwrap_or(0);
+
+        if self.checkpoints.range(last_sequence + 1..).next().is_some() {
+            return Err(BrokenLink {
+                sequence: last_sequence + 1,
+                event_id: None,
+                reason: BrokenLinkReason::MissingEvent,
+            });
+        }
+
+        let Some(head) = head else {
+            return Ok(());
+        };
+
+        if head.sequence > last_sequence {
+            return Err(BrokenLink {
+                sequence: last_sequence + 1,
+                event_id: None,
+                reason: BrokenLinkReason::MissingEvent,
+            });
+        }
+
+        let head_matches = match &self.previous {
+            Some((sequence, hash)) => head.sequence == *sequence && &head.hash == hash,
+            None => false,
+        };
+        if !head_matches {
+            return Err(BrokenLink {
+                sequence: head.sequence,
+                event_id: None,
+                reason: BrokenLinkReason::HeadMismatch,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn last_sequence(&self) -> Option<i64> {
+        self.previous.as_ref().map(|(sequence, _)| *sequence)
+    }
+
+    pub fn first_sequence(&self) -> Option<i64> {
+        self.first_sequence
+    }
+
+    pub fn verified_events(&self) -> u64 {
+        self.verified_events
+    }
+
+    pub fn verified_checkpoints(&self) -> u64 {
+        self.verified_checkpoints
+    }
+}
+
+/// Appends `event` after `head` (or at the start of a new chain) and returns
+/// the resulting link.
+pub fn next_link(
+    realm_id: RealmId,
+    head: Option<&SecurityEventChainHead>,
+    mut event: SecurityEvent,
+) -> SecurityEventLink {
+    event.realm_id = realm_id;
+    event.timestamp = normalize_timestamp(event.timestamp);
+
+    let (sequence, prev_hash) = match head {
+        Some(head) => (head.sequence + 1, head.hash.clone()),
+        None => (1, GENESIS_HASH.to_string()),
+    };
+    let hash = compute_event_hash(&prev_hash, sequence, &event);
+
+    SecurityEventLink {
+        event,
+        sequence,
+        prev_hash,
+        hash,
+        pseudonymized: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::seawatch::entities::{EventStatus, SecurityEventType};
+
+    fn event(realm_id: RealmId) -> SecurityEvent {
+        SecurityEvent::new(
+            realm_id,
+            SecurityEventType::LoginSuccess,
+            EventStatus::Success,
+            Uuid::now_v7(),
+        )
+        .with_details(json!({ "b": 1, "a": { "d": true, "c": [1, 2] } }))
+    }
+
+    fn chain(realm_id: RealmId, len: usize) -> Vec<SecurityEventLink> {
+        let mut links: Vec<SecurityEventLink> = Vec::new();
+        for _ in 0..len {
+            let head = links.last().map(|link| SecurityEventChainHead {
+                realm_id,
+                sequence: link.sequence,
+                hash: link.hash.clone(),
+            });
+            links.push(next_link(realm_id, head.as_ref(), event(realm_id)));
+        }
+        links
+    }
+
+    fn head_of(links: &[SecurityEventLink]) -> SecurityEventChainHead {
+        let last = links.last().unwrap();
+        SecurityEventChainHead {
+            realm_id: last.event.realm_id,
+            sequence: last.sequence,
+            hash: last.hash.clone(),
+        }
+    }
+
+    fn verify_all(
+        links: &[SecurityEventLink],
+        head: &SecurityEventChainHead,
+    ) -> Result<ChainVerifier, BrokenLink> {
+        let mut verifier = ChainVerifier::new(&[]);
+        for link in links {
+            verifier.verify(link)?;
+        }
+        verifier.finish(Some(head))?;
+        Ok(verifier)
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let links = chain(RealmId::default(), 5);
+
+        let verifier = verify_all(&links, &head_of(&links)).unwrap();
+
+        assert_eq!(verifier.verified_events(), 5);
+        assert_eq!(links[0].prev_hash, GENESIS_HASH);
+        assert_eq!(links[1].prev_hash, links[0].hash);
+    }
+
+    #[test]
+    fn hash_ignores_details_key_order() {
+        let realm_id = RealmId::default();
+        let mut reordered = event(realm_id);
+        let original = reordered.clone();
+        reordered.details = Some(json!({ "a": { "c": [1, 2], "d": true }, "b": 1 }));
+
+        assert_eq!(
+            compute_event_hash(GENESIS_HASH, 1, &original),
+            compute_event_hash(GENESIS_HASH, 1, &reordered)
+        );
+    }
+
+    #[test]
+    fn edited_event_is_reported() {
+        let mut links = chain(RealmId::default(), 4);
+        let head = head_of(&links);
+        links[2].event.ip_address = Some("198.51.100.1".to_string());
+
+        let broken = verify_all(&links, &head).unwrap_err();
+
+        assert_eq!(broken.sequence, 3);
+        assert_eq!(broken.reason, BrokenLinkReason::HashMismatch);
+    }
+
+    #[test]
+    fn pseudonymized_event_keeps_the_chain_valid() {
+        let mut links = chain(RealmId::default(), 4);
+        let head = head_of(&links);
+        links[2].event.ip_address = Some("******".to_string());
+        links[2].pseudonymized = true;
+
+        let verifier = verify_all(&links, &head).unwrap();
+
+        assert_eq!(verifier.verified_events(), 4);
+    }
+
+    #[test]
+    fn deleted_event_is_reported() {
+        let mut links = chain(RealmId::default(), 4);
+        let head = head_of(&links);
+        links.remove(1);
+
+        let broken = verify_all(&links, &head).unwrap_err();
+
+        assert_eq!(broken.sequence, 2);
+        assert_eq!(broken.reason, BrokenLinkReason::MissingEvent);
+    }
+
+    #[test]
+    fn truncated_tail_is_reported_against_head() {
+        let mut links = chain(RealmId::default(), 4);
+        let head = head_of(&links);
+        links.truncate(2);
+
+        let broken = verify_all(&links, &head).unwrap_err();
+
+        assert_eq!(broken.sequence, 3);
+        assert_eq!(broken.reason, BrokenLinkReason::MissingEvent);
+    }
+
+    #[test]
+    fn purged_prefix_is_accepted() {
+        let links = chain(RealmId::default(), 4);
+
+        let verifier = verify_all(&links[2..], &head_of(&links)).unwrap();
+
+        assert_eq!(verifier.first_sequence(), Some(3));
+    }
+
+    #[test]
+    fn rewritten_chain_is_caught_by_checkpoint() {
+        let realm_id = RealmId::default();
+        let original = chain(realm_id, 3);
+        let checkpoint = SecurityEventCheckpoint {
+            id: Uuid::now_v7(),
+            realm_id,
+            sequence: 2,
+            hash: original[1].hash.clone(),
+            signature: String::new(),
+            created_at: Utc::now(),
+        };
+        // A fully recomputed chain is internally consistent, but cannot match
+        // the hash that was signed before the rewrite.
+        let forged = chain(realm_id, 3);
+
+        let mut verifier = ChainVerifier::new(&[checkpoint]);
+        let broken = forged
+            .iter()
+            .try_for_each(|link| verifier.verify(link))
+            .unwrap_err();
+
+        assert_eq!(broken.sequence, 2);
+        assert_eq!(broken.reason, BrokenLinkReason::CheckpointMismatch);
+    }
+}
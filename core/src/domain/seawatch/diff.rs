@@ -0,0 +1,115 @@
+use std::collections::BTreeSet;
+
+use serde_json::{Map, Value};
+
+use super::entities::AdminEventChange;
+
+/// Field-level differences between two snapshots. Objects are compared key
+/// by key; any other value, arrays included, is compared as a whole. A
+/// missing snapshot (creation or deletion) counts as an empty object, so every
+/// top-level field shows up as its own change.
+pub fn diff_snapshots(before: Option<&Value>, after: Option<&Value>) -> Vec<AdminEventChange> {
+    let empty = Value::Object(Map::new());
+    let (before, after) = match (before, after) {
+        (None, Some(after @ Value::Object(_))) => (Some(&empty), Some(after)),
+        (Some(before @ Value::Object(_)), None) => (Some(before), Some(&empty)),
+        other => other,
+    };
+
+    let mut changes = Vec::new();
+    diff_at(String::new(), before, after, &mut changes);
+    changes
+}
+
+fn diff_at(
+    path: String,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<AdminEventChange>,
+) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+
+            for key in keys {
+                diff_at(
+                    format!("{path}/{}", escape_pointer(key)),
+                    before.get(key),
+                    after.get(key),
+                    changes,
+                );
+            }
+        }
+        (before, after) if before != after => changes.push(AdminEventChange {
+            path: if path.is_empty() {
+                "/".to_string()
+            } else {
+                path
+            },
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+/// RFC 6901 reference token escaping.
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn reports_only_changed_fields() {
+        let before = json!({ "name": "app", "redirect_uri": "https://a", "nested": { "x": 1 } });
+        let after = json!({ "name": "app", "redirect_uri": "https://b", "nested": { "x": 1 } });
+
+        let changes = diff_snapshots(Some(&before), Some(&after));
+
+        assert_eq!(
+            changes,
+            vec![AdminEventChange {
+                path: "/redirect_uri".to_string(),
+                before: Some(json!("https://a")),
+                after: Some(json!("https://b")),
+            }]
+        );
+    }
+
+    #[test]
+    fn added_and_removed_keys_have_one_side_empty() {
+        let before = json!({ "a/b": 1 });
+        let after = json!({ "c": [1, 2] });
+
+        let changes = diff_snapshots(Some(&before), Some(&after));
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].path, "/a~1b");
+        assert_eq!(changes[0].after, None);
+        assert_eq!(changes[1].path, "/c");
+        assert_eq!(changes[1].before, None);
+    }
+
+    #[test]
+    fn creation_lists_every_field() {
+        let after = json!({ "name": "app", "enabled": true });
+
+        let changes = diff_snapshots(None, Some(&after));
+
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|change| change.before.is_none()));
+    }
+
+    #[test]
+    fn non_object_snapshots_are_compared_at_the_root() {
+        let changes = diff_snapshots(Some(&json!([1])), Some(&json!([2])));
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "/");
+    }
+}
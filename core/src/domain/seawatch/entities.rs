@@ -16,6 +16,15 @@ pub enum SecurityEventType {
     #[serde(rename = "login_failure")]
     LoginFailure,
 
+    #[serde(rename = "risk_assessed")]
+    RiskAssessed,
+
+    #[serde(rename = "device_trusted")]
+    DeviceTrusted,
+
+    #[serde(rename = "trusted_device_revoked")]
+    TrustedDeviceRevoked,
+
     #[serde(rename = "password_reset")]
     PasswordReset,
 
@@ -31,9 +40,21 @@ pub enum SecurityEventType {
     #[serde(rename = "user_email_verified")]
     UserEmailVerified,
 
+    #[serde(rename = "user_email_changed")]
+    UserEmailChanged,
+
     #[serde(rename = "user_deleted")]
     UserDeleted,
 
+    #[serde(rename = "user_data_exported")]
+    UserDataExported,
+
+    #[serde(rename = "account_deletion_requested")]
+    AccountDeletionRequested,
+
+    #[serde(rename = "account_deletion_cancelled")]
+    AccountDeletionCancelled,
+
     #[serde(rename = "role_assigned")]
     RoleAssigned,
 
@@ -69,6 +90,12 @@ pub enum SecurityEventType {
 
     #[serde(rename = "client_maintenance_disabled")]
     ClientMaintenanceDisabled,
+
+    #[serde(rename = "realm_maintenance_enabled")]
+    RealmMaintenanceEnabled,
+
+    #[serde(rename = "realm_maintenance_disabled")]
+    RealmMaintenanceDisabled,
 }
 
 impl Display for SecurityEventType {
@@ -76,12 +103,23 @@ impl Display for SecurityEventType {
         match self {
             SecurityEventType::LoginSuccess => write!(f, "login_success"),
             SecurityEventType::LoginFailure => write!(f, "login_failure"),
+            SecurityEventType::RiskAssessed => write!(f, "risk_assessed"),
+            SecurityEventType::DeviceTrusted => write!(f, "device_trusted"),
+            SecurityEventType::TrustedDeviceRevoked => write!(f, "trusted_device_revoked"),
             SecurityEventType::PasswordReset => write!(f, "password_reset"),
             SecurityEventType::PasswordResetRequested => write!(f, "password_reset_requested"),
             SecurityEventType::PasswordResetCompleted => write!(f, "password_reset_completed"),
             SecurityEventType::UserCreated => write!(f, "user_created"),
             SecurityEventType::UserEmailVerified => write!(f, "user_email_verified"),
+            SecurityEventType::UserEmailChanged => write!(f, "user_email_changed"),
             SecurityEventType::UserDeleted => write!(f, "user_deleted"),
+            SecurityEventType::UserDataExported => write!(f, "user_data_exported"),
+            SecurityEventType::AccountDeletionRequested => {
+                write!(f, "account_deletion_requested")
+            }
+            SecurityEventType::AccountDeletionCancelled => {
+                write!(f, "account_deletion_cancelled")
+            }
             SecurityEventType::RoleAssigned => write!(f, "role_assigned"),
             SecurityEventType::RoleUnassigned => write!(f, "role_unassigned"),
             SecurityEventType::RoleCreated => write!(f, "role_created"),
@@ -98,6 +136,49 @@ impl Display for SecurityEventType {
             SecurityEventType::ClientMaintenanceDisabled => {
                 write!(f, "client_maintenance_disabled")
             }
+            SecurityEventType::RealmMaintenanceEnabled => write!(f, "realm_maintenance_enabled"),
+            SecurityEventType::RealmMaintenanceDisabled => {
+                write!(f, "realm_maintenance_disabled")
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for SecurityEventType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "login_success" => Ok(SecurityEventType::LoginSuccess),
+            "login_failure" => Ok(SecurityEventType::LoginFailure),
+            "risk_assessed" => Ok(SecurityEventType::RiskAssessed),
+            "device_trusted" => Ok(SecurityEventType::DeviceTrusted),
+            "trusted_device_revoked" => Ok(SecurityEventType::TrustedDeviceRevoked),
+            "password_reset" => Ok(SecurityEventType::PasswordReset),
+            "password_reset_requested" => Ok(SecurityEventType::PasswordResetRequested),
+            "password_reset_completed" => Ok(SecurityEventType::PasswordResetCompleted),
+            "user_created" => Ok(SecurityEventType::UserCreated),
+            "user_email_verified" => Ok(SecurityEventType::UserEmailVerified),
+            "user_email_changed" => Ok(SecurityEventType::UserEmailChanged),
+            "user_deleted" => Ok(SecurityEventType::UserDeleted),
+            "user_data_exported" => Ok(SecurityEventType::UserDataExported),
+            "account_deletion_requested" => Ok(SecurityEventType::AccountDeletionRequested),
+            "account_deletion_cancelled" => Ok(SecurityEventType::AccountDeletionCancelled),
+            "role_assigned" => Ok(SecurityEventType::RoleAssigned),
+            "role_unassigned" => Ok(SecurityEventType::RoleUnassigned),
+            "role_created" => Ok(SecurityEventType::RoleCreated),
+            "role_removed" => Ok(SecurityEventType::RoleRemoved),
+            "client_created" => Ok(SecurityEventType::ClientCreated),
+            "client_deleted" => Ok(SecurityEventType::ClientDeleted),
+            "client_secret_rotated" => Ok(SecurityEventType::ClientSecretRotated),
+            "realm_config_changed" => Ok(SecurityEventType::RealmConfigChanged),
+            "email_not_sent" => Ok(SecurityEventType::EmailNotSent),
+            "email_sent" => Ok(SecurityEventType::EmailSent),
+            "client_maintenance_enabled" => Ok(SecurityEventType::ClientMaintenanceEnabled),
+            "client_maintenance_disabled" => Ok(SecurityEventType::ClientMaintenanceDisabled),
+            "realm_maintenance_enabled" => Ok(SecurityEventType::RealmMaintenanceEnabled),
+            "realm_maintenance_disabled" => Ok(SecurityEventType::RealmMaintenanceDisabled),
+            _ => Err(format!("Unknown security event type: {value}")),
         }
     }
 }
@@ -251,3 +332,182 @@ impl SecurityEvent {
         self
     }
 }
+
+/// One page of a cursor-paginated security event listing. `next_cursor` is
+/// `None` once the last page has been reached.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct SecurityEventPage {
+    pub data: Vec<SecurityEvent>,
+    pub next_cursor: Option<String>,
+}
+
+/// Per-realm retention window applied by the background purge job.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct SecurityEventRetentionPolicy {
+    pub realm_id: RealmId,
+    /// Events older than this many days are deleted. `None` keeps events forever.
+    pub retention_days: Option<u32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SecurityEventRetentionPolicy {
+    pub fn new(realm_id: RealmId, retention_days: Option<u32>) -> Self {
+        Self {
+            realm_id,
+            retention_days,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Oldest timestamp that must be kept, relative to `now`.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.retention_days
+            .map(|days| now - chrono::Duration::days(i64::from(days)))
+    }
+}
+
+/// A stored event together with its position in the realm hash chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityEventLink {
+    pub event: SecurityEvent,
+    pub sequence: i64,
+    pub prev_hash: String,
+    pub hash: String,
+    /// The event was pseudonymized after its owner was deleted. Its content
+    /// no longer matches `hash`, but the link itself is still checked.
+    pub pseudonymized: bool,
+}
+
+/// Tip of a realm hash chain: the last appended sequence and its hash.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityEventChainHead {
+    pub realm_id: RealmId,
+    pub sequence: i64,
+    pub hash: String,
+}
+
+/// Signed statement that the realm chain had `hash` at `sequence`. The
+/// signature is a compact JWS produced with the realm key.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct SecurityEventCheckpoint {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub sequence: i64,
+    pub hash: String,
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrokenLinkReason {
+    /// The event content no longer matches its stored hash.
+    HashMismatch,
+    /// The event does not point to the hash of its predecessor.
+    PrevHashMismatch,
+    /// One or more events are missing from the chain.
+    MissingEvent,
+    /// A signed checkpoint disagrees with the chain at its sequence.
+    CheckpointMismatch,
+    /// A checkpoint signature does not verify against the realm key.
+    InvalidCheckpointSignature,
+    /// The recorded chain head does not match the last stored event.
+    HeadMismatch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct BrokenLink {
+    pub sequence: i64,
+    /// `None` when the event at `sequence` is missing.
+    pub event_id: Option<Uuid>,
+    pub reason: BrokenLinkReason,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ChainVerificationReport {
+    pub realm_id: RealmId,
+    pub valid: bool,
+    pub verified_events: u64,
+    pub verified_checkpoints: u64,
+    /// Oldest sequence still stored. Anything before it was purged by the
+    /// retention job and is only covered by checkpoints.
+    pub first_sequence: Option<i64>,
+    pub last_sequence: Option<i64>,
+    pub broken_link: Option<BrokenLink>,
+    pub verified_at: DateTime<Utc>,
+}
+
+/// Kind of change an admin API call made to a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum AdminOperation {
+    #[serde(rename = "create")]
+    Create,
+
+    #[serde(rename = "update")]
+    Update,
+
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+impl Display for AdminOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdminOperation::Create => write!(f, "create"),
+            AdminOperation::Update => write!(f, "update"),
+            AdminOperation::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+impl TryFrom<String> for AdminOperation {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "create" => Ok(AdminOperation::Create),
+            "update" => Ok(AdminOperation::Update),
+            "delete" => Ok(AdminOperation::Delete),
+            _ => Err(format!("Unknown admin operation: {value}")),
+        }
+    }
+}
+
+/// One field that differs between the `before` and `after` snapshots of an
+/// admin event. `path` is a JSON pointer (RFC 6901) into the snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AdminEventChange {
+    pub path: String,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+}
+
+/// Record of a successful mutating admin API call. Snapshots are stored with
+/// secrets already masked.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AdminEvent {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub actor_id: Uuid,
+    pub actor_type: ActorType,
+    /// Username, or `client:<client_id>` for service accounts.
+    pub actor_name: String,
+    pub operation: AdminOperation,
+    pub resource_type: String,
+    pub resource_id: Option<String>,
+    /// Request path relative to the realm, e.g. `/clients/{id}/redirects/{id}`.
+    pub resource_path: String,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub changes: Vec<AdminEventChange>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// One page of a cursor-paginated admin event listing.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AdminEventPage {
+    pub data: Vec<AdminEvent>,
+    pub next_cursor: Option<String>,
+}
@@ -0,0 +1,172 @@
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+
+use super::{entities::SecurityEvent, value_objects::ExportFormat};
+
+const CSV_COLUMNS: [&str; 14] = [
+    "id",
+    "realm_id",
+    "timestamp",
+    "event_type",
+    "status",
+    "actor_id",
+    "actor_type",
+    "target_type",
+    "target_id",
+    "resource",
+    "ip_address",
+    "user_agent",
+    "trace_id",
+    "details",
+];
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Ndjson => "application/x-ndjson",
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    /// Leading line emitted once before any event, if the format has one.
+    pub fn header(&self) -> Option<String> {
+        match self {
+            ExportFormat::Ndjson => None,
+            ExportFormat::Csv => Some(format!("{}\n", CSV_COLUMNS.join(","))),
+        }
+    }
+
+    /// Encodes a single event as one newline-terminated record.
+    pub fn encode(&self, event: &SecurityEvent) -> Result<String, CoreError> {
+        match self {
+            ExportFormat::Ndjson => {
+                let mut line = serde_json::to_string(event).map_err(|e| {
+                    tracing::error!("Failed to serialize security event: {}", e);
+                    CoreError::InternalServerError
+                })?;
+                line.push('\n');
+                Ok(line)
+            }
+            ExportFormat::Csv => {
+                let id: Uuid = event.id.clone().into();
+                let realm_id: Uuid = event.realm_id.into();
+                let details = event
+                    .details
+                    .as_ref()
+                    .map(|d| d.to_string())
+                    .unwrap_or_default();
+
+                let fields = [
+                    id.to_string(),
+                    realm_id.to_string(),
+                    event.timestamp.to_rfc3339(),
+                    event.event_type.to_string(),
+                    event.status.to_string(),
+                    opt_to_string(event.actor_id),
+                    opt_to_string(event.actor_type.as_ref()),
+                    opt_to_string(event.target_type.as_ref()),
+                    opt_to_string(event.target_id),
+                    opt_to_string(event.resource.as_ref()),
+                    opt_to_string(event.ip_address.as_ref()),
+                    opt_to_string(event.user_agent.as_ref()),
+                    opt_to_string(event.trace_id.as_ref()),
+                    details,
+                ];
+
+                let mut line = fields
+                    .iter()
+                    .map(|field| escape_csv(field))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                line.push('\n');
+                Ok(line)
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for ExportFormat {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "ndjson" => Ok(ExportFormat::Ndjson),
+            "csv" => Ok(ExportFormat::Csv),
+            _ => Err(format!("Unsupported export format: {value}")),
+        }
+    }
+}
+
+fn opt_to_string<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// RFC 4180 quoting: fields containing a delimiter, quote or line break are
+/// wrapped in double quotes, with inner quotes doubled.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::{
+        realm::entities::RealmId,
+        seawatch::entities::{EventStatus, SecurityEventType},
+    };
+
+    fn event() -> SecurityEvent {
+        SecurityEvent::new(
+            RealmId::default(),
+            SecurityEventType::LoginFailure,
+            EventStatus::Failure,
+            Uuid::now_v7(),
+        )
+        .with_context(
+            Some("10.0.0.1".to_string()),
+            Some("Mozilla/5.0 (X11, Linux)".to_string()),
+            None,
+        )
+        .with_details(json!({ "reason": "bad \"password\"" }))
+    }
+
+    #[test]
+    fn ndjson_emits_one_line_per_event() {
+        let line = ExportFormat::Ndjson.encode(&event()).unwrap();
+
+        assert!(line.ends_with('\n'));
+        assert_eq!(line.matches('\n').count(), 1);
+        let parsed: serde_json::Value = serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(parsed["event_type"], "login_failure");
+    }
+
+    #[test]
+    fn csv_row_matches_header_and_escapes_fields() {
+        let header = ExportFormat::Csv.header().unwrap();
+        let row = ExportFormat::Csv.encode(&event()).unwrap();
+
+        assert_eq!(header.trim_end().split(',').count(), CSV_COLUMNS.len());
+        assert!(row.contains("\"Mozilla/5.0 (X11, Linux)\""));
+        assert!(row.contains("\"{\"\"reason\"\":\"\"bad \\\"\"password\\\"\"\"\"}\""));
+    }
+
+    #[test]
+    fn escape_csv_leaves_plain_fields_untouched() {
+        assert_eq!(escape_csv("login_success"), "login_success");
+        assert_eq!(escape_csv(""), "");
+    }
+}
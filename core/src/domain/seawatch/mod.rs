@@ -1,9 +1,20 @@
+pub mod chain;
+pub mod diff;
 pub mod entities;
+pub mod export;
 pub mod policies;
 pub mod ports;
 pub mod services;
 pub mod value_objects;
 
-pub use entities::{ActorType, EventStatus, SecurityEvent, SecurityEventType};
-pub use ports::{SecurityEventPolicy, SecurityEventRepository};
-pub use value_objects::{EventExportRequest, ExportFormat, SecurityEventFilter};
+pub use entities::{
+    ActorType, AdminEvent, AdminEventChange, AdminEventPage, AdminOperation, BrokenLink,
+    BrokenLinkReason, ChainVerificationReport, EventStatus, SecurityEvent, SecurityEventChainHead,
+    SecurityEventCheckpoint, SecurityEventLink, SecurityEventPage, SecurityEventRetentionPolicy,
+    SecurityEventType,
+};
+pub use ports::{
+    AdminEventRepository, SecurityEventChainRepository, SecurityEventPolicy,
+    SecurityEventRepository, SecurityEventRetentionRepository,
+};
+pub use value_objects::{AdminEventFilter, ExportFormat, SecurityEventCursor, SecurityEventFilter};
@@ -42,4 +42,34 @@ where
 
         Ok(has_permissions)
     }
+
+    async fn can_manage_retention(
+        &self,
+        identity: &Identity,
+        realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(identity).await?;
+
+        let permissions = self.get_permission_for_target_realm(&user, realm).await?;
+
+        let has_permissions =
+            Permissions::has_one_of_permissions(&permissions, &[Permissions::ManageRealm]);
+
+        Ok(has_permissions)
+    }
+
+    async fn can_verify_chain(
+        &self,
+        identity: &Identity,
+        realm: &Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(identity).await?;
+
+        let permissions = self.get_permission_for_target_realm(&user, realm).await?;
+
+        let has_permissions =
+            Permissions::has_one_of_permissions(&permissions, &[Permissions::ManageRealm]);
+
+        Ok(has_permissions)
+    }
 }
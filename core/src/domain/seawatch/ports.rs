@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
 use std::future::Future;
 use uuid::Uuid;
 
@@ -5,15 +7,58 @@ use crate::domain::authentication::value_objects::Identity;
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::domain::realm::entities::{Realm, RealmId};
 
-use super::entities::SecurityEvent;
-use super::value_objects::{FetchEventsInput, SecurityEventFilter};
+use super::entities::{
+    AdminEvent, AdminEventPage, ChainVerificationReport, SecurityEvent, SecurityEventChainHead,
+    SecurityEventCheckpoint, SecurityEventLink, SecurityEventPage, SecurityEventRetentionPolicy,
+};
+use super::value_objects::{
+    AdminEventFilter, ExportEventsInput, FetchAdminEventsInput, FetchEventsInput,
+    GetRetentionPolicyInput, RecordAdminEventInput, SecurityEventFilter,
+    UpdateRetentionPolicyInput, VerifyChainInput,
+};
 
 pub trait SecurityEventService: Send + Sync {
     fn fetch_events(
         &self,
         identity: Identity,
         input: FetchEventsInput,
-    ) -> impl Future<Output = Result<Vec<SecurityEvent>, CoreError>> + Send;
+    ) -> impl Future<Output = Result<SecurityEventPage, CoreError>> + Send;
+    /// Streams every matching event, already encoded in the requested format.
+    /// Events are read from the repository in batches so the export never
+    /// holds the whole range in memory.
+    fn export_events(
+        &self,
+        identity: Identity,
+        input: ExportEventsInput,
+    ) -> impl Future<Output = Result<BoxStream<'static, Result<String, CoreError>>, CoreError>> + Send;
+    fn get_retention_policy(
+        &self,
+        identity: Identity,
+        input: GetRetentionPolicyInput,
+    ) -> impl Future<Output = Result<SecurityEventRetentionPolicy, CoreError>> + Send;
+    fn update_retention_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRetentionPolicyInput,
+    ) -> impl Future<Output = Result<SecurityEventRetentionPolicy, CoreError>> + Send;
+    /// Walks the realm hash chain and reports the first broken link, if any.
+    fn verify_chain(
+        &self,
+        identity: Identity,
+        input: VerifyChainInput,
+    ) -> impl Future<Output = Result<ChainVerificationReport, CoreError>> + Send;
+    /// Stores an admin event on behalf of `identity`. The call it describes
+    /// has already been authorized, so no further policy check is made.
+    fn record_admin_event(
+        &self,
+        identity: Identity,
+        input: RecordAdminEventInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn fetch_admin_events(
+        &self,
+        identity: Identity,
+        input: FetchAdminEventsInput,
+    ) -> impl Future<Output = Result<AdminEventPage, CoreError>> + Send;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -33,6 +78,73 @@ pub trait SecurityEventRepository: Send + Sync {
         realm_id: Uuid,
         filter: SecurityEventFilter,
     ) -> impl Future<Output = Result<i64, CoreError>> + Send;
+    /// Deletes every event of the realm older than `before` and returns how
+    /// many rows were removed. The chain tip is always kept so the chain head
+    /// stays verifiable.
+    fn delete_events_before(
+        &self,
+        realm_id: RealmId,
+        before: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait SecurityEventRetentionRepository: Send + Sync {
+    fn get_by_realm_id(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<SecurityEventRetentionPolicy>, CoreError>> + Send;
+    fn upsert(
+        &self,
+        policy: SecurityEventRetentionPolicy,
+    ) -> impl Future<Output = Result<SecurityEventRetentionPolicy, CoreError>> + Send;
+    /// Every policy with a retention window, used by the purge job.
+    fn list_active(
+        &self,
+    ) -> impl Future<Output = Result<Vec<SecurityEventRetentionPolicy>, CoreError>> + Send;
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait AdminEventRepository: Send + Sync {
+    fn store_event(&self, event: AdminEvent) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn get_events(
+        &self,
+        realm_id: RealmId,
+        filter: AdminEventFilter,
+    ) -> impl Future<Output = Result<Vec<AdminEvent>, CoreError>> + Send;
+}
+
+/// Read side of the per-realm hash chain. Links are appended by
+/// [`SecurityEventRepository::store_event`].
+#[cfg_attr(test, mockall::automock)]
+pub trait SecurityEventChainRepository: Send + Sync {
+    fn get_head(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<SecurityEventChainHead>, CoreError>> + Send;
+    fn list_heads(
+        &self,
+    ) -> impl Future<Output = Result<Vec<SecurityEventChainHead>, CoreError>> + Send;
+    /// Up to `limit` links with a sequence greater than `after_sequence`, in
+    /// ascending order.
+    fn get_links(
+        &self,
+        realm_id: RealmId,
+        after_sequence: i64,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<SecurityEventLink>, CoreError>> + Send;
+    fn list_checkpoints(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<SecurityEventCheckpoint>, CoreError>> + Send;
+    fn get_latest_checkpoint(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<SecurityEventCheckpoint>, CoreError>> + Send;
+    fn store_checkpoint(
+        &self,
+        checkpoint: SecurityEventCheckpoint,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
 pub trait SecurityEventPolicy: Send + Sync {
@@ -46,4 +158,14 @@ pub trait SecurityEventPolicy: Send + Sync {
         identity: &Identity,
         realm: &Realm,
     ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_retention(
+        &self,
+        identity: &Identity,
+        realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_verify_chain(
+        &self,
+        identity: &Identity,
+        realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
 }
@@ -1,91 +1,452 @@
 use std::sync::Arc;
 
+use chrono::Utc;
+use ferriskey_security::jwt::ports::KeyStoreRepository;
+use futures::{
+    StreamExt, TryStreamExt,
+    stream::{self, BoxStream},
+};
+
 use crate::domain::{
     authentication::value_objects::Identity,
     client::ports::ClientRepository,
     common::{
         entities::app_errors::CoreError,
+        generate_uuid_v7,
         policies::{FerriskeyPolicy, ensure_policy},
     },
+    realm::entities::Realm,
     realm::ports::RealmRepository,
     seawatch::{
-        SecurityEvent, SecurityEventFilter, SecurityEventPolicy, SecurityEventRepository,
-        ports::SecurityEventService, value_objects::FetchEventsInput,
+        ActorType, AdminEvent, AdminEventFilter, AdminEventPage, AdminEventRepository, BrokenLink,
+        BrokenLinkReason, ChainVerificationReport, SecurityEventChainRepository,
+        SecurityEventCursor, SecurityEventFilter, SecurityEventPage, SecurityEventPolicy,
+        SecurityEventRepository, SecurityEventRetentionPolicy, SecurityEventRetentionRepository,
+        chain::{ChainVerifier, verify_checkpoint_signature},
+        diff::diff_snapshots,
+        ports::SecurityEventService,
+        value_objects::{
+            ExportEventsInput, FetchAdminEventsInput, FetchEventsInput, GetRetentionPolicyInput,
+            RecordAdminEventInput, UpdateRetentionPolicyInput, VerifyChainInput,
+        },
     },
     user::ports::{UserRepository, UserRoleRepository},
 };
 
+/// Number of events read from the repository per round-trip while exporting.
+const EXPORT_BATCH_SIZE: u32 = 500;
+
+/// Upper bound for a single page of [`SecurityEventService::fetch_events`].
+const MAX_PAGE_SIZE: u32 = 500;
+
+/// Number of chain links read per round-trip while verifying.
+const VERIFY_BATCH_SIZE: u32 = 1000;
+
 #[derive(Clone, Debug)]
-pub struct SecurityEventServiceImpl<R, U, C, UR, SE>
+pub struct SecurityEventServiceImpl<R, U, C, UR, SE, SR, CH, K, AE>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     SE: SecurityEventRepository,
+    SR: SecurityEventRetentionRepository,
+    CH: SecurityEventChainRepository,
+    K: KeyStoreRepository,
+    AE: AdminEventRepository,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) retention_repository: Arc<SR>,
+    pub(crate) chain_repository: Arc<CH>,
+    pub(crate) keystore_repository: Arc<K>,
+    pub(crate) admin_event_repository: Arc<AE>,
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, SE> SecurityEventServiceImpl<R, U, C, UR, SE>
+impl<R, U, C, UR, SE, SR, CH, K, AE> SecurityEventServiceImpl<R, U, C, UR, SE, SR, CH, K, AE>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
     SE: SecurityEventRepository,
+    SR: SecurityEventRetentionRepository,
+    CH: SecurityEventChainRepository,
+    K: KeyStoreRepository,
+    AE: AdminEventRepository,
 {
     pub fn new(
         realm_repository: Arc<R>,
         security_event_repository: Arc<SE>,
+        retention_repository: Arc<SR>,
+        chain_repository: Arc<CH>,
+        keystore_repository: Arc<K>,
+        admin_event_repository: Arc<AE>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
             realm_repository,
             security_event_repository,
+            retention_repository,
+            chain_repository,
+            keystore_repository,
+            admin_event_repository,
             policy,
         }
     }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    /// Verifies a realm chain without any permission check. Meant for
+    /// operator tooling such as the `verify-audit-chain` CLI command.
+    pub async fn verify_realm_chain(
+        &self,
+        realm_name: &str,
+    ) -> Result<ChainVerificationReport, CoreError> {
+        let realm = self.get_realm(realm_name).await?;
+
+        self.run_chain_verification(&realm).await
+    }
+
+    async fn run_chain_verification(
+        &self,
+        realm: &Realm,
+    ) -> Result<ChainVerificationReport, CoreError> {
+        let key_pair = self
+            .keystore_repository
+            .get_or_generate_key(realm.id)
+            .await
+            .map_err(|_| CoreError::RealmKeyNotFound)?;
+
+        let checkpoints = self.chain_repository.list_checkpoints(realm.id).await?;
+        let head = self.chain_repository.get_head(realm.id).await?;
+
+        let mut broken_link = checkpoints
+            .iter()
+            .find(|checkpoint| !verify_checkpoint_signature(checkpoint, &key_pair))
+            .map(|checkpoint| BrokenLink {
+                sequence: checkpoint.sequence,
+                event_id: None,
+                reason: BrokenLinkReason::InvalidCheckpointSignature,
+            });
+
+        let mut verifier = ChainVerifier::new(&checkpoints);
+
+        if broken_link.is_none() {
+            let mut after_sequence = 0;
+
+            'walk: loop {
+                let links = self
+                    .chain_repository
+                    .get_links(realm.id, after_sequence, VERIFY_BATCH_SIZE)
+                    .await?;
+
+                for link in &links {
+                    if let Err(broken) = verifier.verify(link) {
+                        broken_link = Some(broken);
+                        break 'walk;
+                    }
+                }
+
+                match links.last() {
+                    Some(last) if links.len() as u32 == VERIFY_BATCH_SIZE => {
+                        after_sequence = last.sequence;
+                    }
+                    _ => break,
+                }
+            }
+
+            if broken_link.is_none() {
+                broken_link = verifier.finish(head.as_ref()).err();
+            }
+        }
+
+        if let Some(broken) = &broken_link {
+            tracing::warn!(
+                "SeaWatch chain verification failed for realm {} at sequence {}: {:?}",
+                realm.name,
+                broken.sequence,
+                broken.reason
+            );
+        }
+
+        Ok(ChainVerificationReport {
+            realm_id: realm.id,
+            valid: broken_link.is_none(),
+            verified_events: verifier.verified_events(),
+            verified_checkpoints: verifier.verified_checkpoints(),
+            first_sequence: verifier.first_sequence(),
+            last_sequence: verifier.last_sequence(),
+            broken_link,
+            verified_at: Utc::now(),
+        })
+    }
 }
 
-impl<R, U, C, UR, SE> SecurityEventService for SecurityEventServiceImpl<R, U, C, UR, SE>
+impl<R, U, C, UR, SE, SR, CH, K, AE> SecurityEventService
+    for SecurityEventServiceImpl<R, U, C, UR, SE, SR, CH, K, AE>
 where
     R: RealmRepository,
     U: UserRepository,
     C: ClientRepository,
     UR: UserRoleRepository,
-    SE: SecurityEventRepository,
+    SE: SecurityEventRepository + 'static,
+    SR: SecurityEventRetentionRepository,
+    CH: SecurityEventChainRepository,
+    K: KeyStoreRepository,
+    AE: AdminEventRepository,
 {
     async fn fetch_events(
         &self,
         identity: Identity,
         input: FetchEventsInput,
-    ) -> Result<Vec<SecurityEvent>, CoreError> {
-        let realm = self
-            .realm_repository
-            .get_by_name(&input.realm_name)
-            .await
-            .map_err(|_| CoreError::InvalidRealm)?
-            .ok_or(CoreError::InvalidRealm)?;
+    ) -> Result<SecurityEventPage, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
 
-        let realm_id = realm.id;
         ensure_policy(
             self.policy.can_view_events(&identity, &realm).await,
             "insufficient permissions",
         )?;
 
-        let security_events = self
+        let limit = input
+            .filter
+            .limit
+            .unwrap_or(MAX_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let filter = SecurityEventFilter {
+            limit: Some(limit),
+            ..input.filter
+        };
+
+        let data = self
             .security_event_repository
-            .get_events(
-                realm_id,
-                SecurityEventFilter {
-                    ..Default::default()
-                },
-            )
+            .get_events(realm.id, filter)
             .await?;
 
-        Ok(security_events)
+        let next_cursor = if data.len() as u32 == limit {
+            data.last().map(|event| {
+                SecurityEventCursor {
+                    timestamp: event.timestamp,
+                    id: event.id.clone().into(),
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
+
+        Ok(SecurityEventPage { data, next_cursor })
+    }
+
+    async fn export_events(
+        &self,
+        identity: Identity,
+        input: ExportEventsInput,
+    ) -> Result<BoxStream<'static, Result<String, CoreError>>, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_export_events(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let realm_id = realm.id;
+        let format = input.format;
+        let repository = self.security_event_repository.clone();
+        let initial = SecurityEventFilter {
+            event_types: input.event_types,
+            from_timestamp: input.from_timestamp,
+            to_timestamp: input.to_timestamp,
+            limit: Some(EXPORT_BATCH_SIZE),
+            offset: None,
+            ..Default::default()
+        };
+
+        let batches = stream::try_unfold(Some(initial), move |state| {
+            let repository = repository.clone();
+            async move {
+                let Some(filter) = state else {
+                    return Ok(None);
+                };
+
+                let events = repository.get_events(realm_id, filter.clone()).await?;
+
+                let next = match events.last() {
+                    Some(last) if events.len() as u32 == EXPORT_BATCH_SIZE => {
+                        Some(SecurityEventFilter {
+                            cursor: Some(SecurityEventCursor {
+                                timestamp: last.timestamp,
+                                id: last.id.clone().into(),
+                            }),
+                            ..filter
+                        })
+                    }
+                    _ => None,
+                };
+
+                let chunk = events
+                    .iter()
+                    .map(|event| format.encode(event))
+                    .collect::<Result<String, CoreError>>()?;
+
+                Ok(Some((chunk, next)))
+            }
+        })
+        .try_filter(|chunk| futures::future::ready(!chunk.is_empty()));
+
+        Ok(stream::iter(format.header().map(Ok)).chain(batches).boxed())
+    }
+
+    async fn get_retention_policy(
+        &self,
+        identity: Identity,
+        input: GetRetentionPolicyInput,
+    ) -> Result<SecurityEventRetentionPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_events(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let policy = self
+            .retention_repository
+            .get_by_realm_id(realm.id)
+            .await?
+            .unwrap_or_else(|| SecurityEventRetentionPolicy::new(realm.id, None));
+
+        Ok(policy)
+    }
+
+    async fn update_retention_policy(
+        &self,
+        identity: Identity,
+        input: UpdateRetentionPolicyInput,
+    ) -> Result<SecurityEventRetentionPolicy, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_manage_retention(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        if input.retention_days == Some(0) {
+            return Err(CoreError::Invalid);
+        }
+
+        self.retention_repository
+            .upsert(SecurityEventRetentionPolicy::new(
+                realm.id,
+                input.retention_days,
+            ))
+            .await
+    }
+
+    async fn verify_chain(
+        &self,
+        identity: Identity,
+        input: VerifyChainInput,
+    ) -> Result<ChainVerificationReport, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_verify_chain(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.run_chain_verification(&realm).await
+    }
+
+    async fn record_admin_event(
+        &self,
+        identity: Identity,
+        input: RecordAdminEventInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        let mask = |snapshot: Option<serde_json::Value>| {
+            snapshot.map(|mut value| {
+                maskass::mask_json(&mut value);
+                value
+            })
+        };
+        let before = mask(input.before);
+        let after = mask(input.after);
+        let changes = diff_snapshots(before.as_ref(), after.as_ref());
+
+        let actor_type = if identity.is_regular_user() {
+            ActorType::Admin
+        } else {
+            ActorType::ServiceAccount
+        };
+
+        self.admin_event_repository
+            .store_event(AdminEvent {
+                id: generate_uuid_v7(),
+                realm_id: realm.id,
+                actor_id: identity.id(),
+                actor_type,
+                actor_name: identity.display_name(),
+                operation: input.operation,
+                resource_type: input.resource_type,
+                resource_id: input.resource_id,
+                resource_path: input.resource_path,
+                before,
+                after,
+                changes,
+                ip_address: input.ip_address,
+                user_agent: input.user_agent,
+                timestamp: Utc::now(),
+            })
+            .await
+    }
+
+    async fn fetch_admin_events(
+        &self,
+        identity: Identity,
+        input: FetchAdminEventsInput,
+    ) -> Result<AdminEventPage, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_events(&identity, &realm).await,
+            "insufficient permissions",
+        )?;
+
+        let limit = input
+            .filter
+            .limit
+            .unwrap_or(MAX_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let filter = AdminEventFilter {
+            limit: Some(limit),
+            ..input.filter
+        };
+
+        let data = self
+            .admin_event_repository
+            .get_events(realm.id, filter)
+            .await?;
+
+        let next_cursor = if data.len() as u32 == limit {
+            data.last().map(|event| {
+                SecurityEventCursor {
+                    timestamp: event.timestamp,
+                    id: event.id,
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
+
+        Ok(AdminEventPage { data, next_cursor })
     }
 }
@@ -1,9 +1,14 @@
-use super::entities::SecurityEventType;
+use super::entities::{AdminOperation, SecurityEventType};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use chrono::{DateTime, Utc};
+use std::time::Duration;
 use uuid::Uuid;
 
+use crate::domain::common::entities::app_errors::CoreError;
+
 #[derive(Debug, Clone)]
 pub struct SecurityEventFilter {
+    /// Events the user caused or that target them.
     pub user_id: Option<Uuid>,
     pub client_id: Option<Uuid>,
     pub actor_id: Option<Uuid>,
@@ -13,20 +18,44 @@ pub struct SecurityEventFilter {
     pub ip_address: Option<String>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    /// Keyset position: only events strictly older than this one are returned.
+    /// Takes precedence over `offset` when set.
+    pub cursor: Option<SecurityEventCursor>,
 }
 
-#[derive(Debug, Clone)]
-pub struct EventExportRequest {
-    pub realm_id: Uuid,
-    pub filter: SecurityEventFilter,
-    pub format: ExportFormat,
+/// Position of a security event in the `(timestamp DESC, id DESC)` ordering
+/// used by every listing, serialized as an opaque token for API clients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityEventCursor {
+    pub timestamp: DateTime<Utc>,
+    pub id: Uuid,
 }
 
-#[derive(Debug, Clone)]
+impl SecurityEventCursor {
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}:{}", self.timestamp.timestamp_micros(), self.id))
+    }
+
+    pub fn decode(value: &str) -> Result<Self, CoreError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|_| CoreError::InvalidCursor)?;
+        let raw = String::from_utf8(bytes).map_err(|_| CoreError::InvalidCursor)?;
+        let (micros, id) = raw.split_once(':').ok_or(CoreError::InvalidCursor)?;
+
+        let micros: i64 = micros.parse().map_err(|_| CoreError::InvalidCursor)?;
+        let timestamp =
+            DateTime::<Utc>::from_timestamp_micros(micros).ok_or(CoreError::InvalidCursor)?;
+        let id = Uuid::parse_str(id).map_err(|_| CoreError::InvalidCursor)?;
+
+        Ok(Self { timestamp, id })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExportFormat {
-    Json,
+    Ndjson,
     Csv,
-    Xlsx,
 }
 
 impl Default for SecurityEventFilter {
@@ -41,10 +70,140 @@ impl Default for SecurityEventFilter {
             ip_address: None,
             limit: Some(100),
             offset: Some(0),
+            cursor: None,
         }
     }
 }
 
 pub struct FetchEventsInput {
     pub realm_name: String,
+    pub filter: SecurityEventFilter,
+}
+
+pub struct ExportEventsInput {
+    pub realm_name: String,
+    pub format: ExportFormat,
+    pub event_types: Option<Vec<SecurityEventType>>,
+    pub from_timestamp: Option<DateTime<Utc>>,
+    pub to_timestamp: Option<DateTime<Utc>>,
+}
+
+pub struct GetRetentionPolicyInput {
+    pub realm_name: String,
+}
+
+pub struct UpdateRetentionPolicyInput {
+    pub realm_name: String,
+    /// `None` disables the purge job for this realm.
+    pub retention_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AdminEventFilter {
+    pub actor_id: Option<Uuid>,
+    pub resource_type: Option<String>,
+    pub resource_id: Option<String>,
+    pub operation: Option<AdminOperation>,
+    pub from_timestamp: Option<DateTime<Utc>>,
+    pub to_timestamp: Option<DateTime<Utc>>,
+    pub limit: Option<u32>,
+    /// Same `(timestamp DESC, id DESC)` keyset as security events.
+    pub cursor: Option<SecurityEventCursor>,
+}
+
+pub struct FetchAdminEventsInput {
+    pub realm_name: String,
+    pub filter: AdminEventFilter,
+}
+
+/// A mutating admin call as observed at the HTTP boundary. Snapshots are the
+/// raw JSON representations; masking and diffing happen in the service.
+pub struct RecordAdminEventInput {
+    pub realm_name: String,
+    pub operation: AdminOperation,
+    pub resource_type: String,
+    pub resource_id: Option<String>,
+    pub resource_path: String,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+pub struct VerifyChainInput {
+    pub realm_name: String,
+}
+
+/// Process-wide SeaWatch settings, supplied at startup.
+#[derive(Debug, Clone)]
+pub struct SeawatchConfig {
+    /// How often the retention purge job runs.
+    pub retention_interval: Duration,
+    /// How often each realm hash chain head is signed into a checkpoint.
+    pub checkpoint_interval: Duration,
+    /// When set, every stored event is also forwarded to this collector.
+    pub syslog: Option<SyslogConfig>,
+}
+
+impl Default for SeawatchConfig {
+    fn default() -> Self {
+        Self {
+            retention_interval: Duration::from_secs(3600),
+            checkpoint_interval: Duration::from_secs(900),
+            syslog: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyslogConfig {
+    /// `host:port` of the collector.
+    pub address: String,
+    pub transport: SyslogTransport,
+    pub format: SyslogFormat,
+    /// HOSTNAME field of the syslog header; `-` when empty.
+    pub hostname: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyslogTransport {
+    Udp,
+    Tcp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyslogFormat {
+    /// RFC 5424 message with the JSON-encoded event as MSG.
+    Rfc5424,
+    /// RFC 5424 envelope carrying an ArcSight CEF payload.
+    Cef,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = SecurityEventCursor {
+            timestamp: DateTime::<Utc>::from_timestamp_micros(1_760_000_000_123_456).unwrap(),
+            id: Uuid::now_v7(),
+        };
+
+        let decoded = SecurityEventCursor::decode(&cursor.encode()).unwrap();
+
+        assert_eq!(decoded, cursor);
+    }
+
+    #[test]
+    fn cursor_rejects_garbage() {
+        assert!(matches!(
+            SecurityEventCursor::decode("not-a-cursor"),
+            Err(CoreError::InvalidCursor)
+        ));
+        assert!(matches!(
+            SecurityEventCursor::decode(&URL_SAFE_NO_PAD.encode("12:not-a-uuid")),
+            Err(CoreError::InvalidCursor)
+        ));
+    }
 }
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+/// Secrets re-encrypted with the current master key, per table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SecretRotationReport {
+    pub credentials: u64,
+    pub jwt_keys: u64,
+    pub smtp_configs: u64,
+    pub identity_providers: u64,
+    pub federation_providers: u64,
+}
+
+impl SecretRotationReport {
+    pub fn total(&self) -> u64 {
+        self.credentials
+            + self.jwt_keys
+            + self.smtp_configs
+            + self.identity_providers
+            + self.federation_providers
+    }
+}
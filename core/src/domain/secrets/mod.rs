@@ -0,0 +1,9 @@
+pub mod entities;
+pub mod ports;
+
+pub use ferriskey_security::cipher::{
+    entities::{MasterKey, SEALED_PREFIX, SealedSecret},
+    envelope::EnvelopeCipher,
+    local::LocalKeyProvider,
+    ports::{KeyProvider, SecretCipher},
+};
@@ -0,0 +1,13 @@
+use crate::domain::common::entities::app_errors::CoreError;
+
+use super::entities::SecretRotationReport;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait SecretRotationRepository: Send + Sync {
+    /// Seals every secret still stored in clear or under a previous master
+    /// key with the current one. Safe to run again: up to date secrets are
+    /// left untouched.
+    fn rotate_secrets(
+        &self,
+    ) -> impl Future<Output = Result<SecretRotationReport, CoreError>> + Send;
+}
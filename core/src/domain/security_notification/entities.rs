@@ -0,0 +1,296 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{email_template::entities::EmailType, realm::entities::RealmId};
+
+/// A change on a user's account they are told about by email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecurityNotification {
+    PasswordChanged,
+    NewSignIn {
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    },
+    MfaAdded {
+        method: String,
+    },
+    MfaRemoved {
+        method: String,
+    },
+    RecoveryCodesRegenerated,
+    AccountLocked,
+    /// Sent to both addresses, so that the previous owner learns about it.
+    EmailChanged {
+        old_email: String,
+        new_email: String,
+    },
+    /// A sign-in the realm's risk policy scored high enough to tell about.
+    SuspiciousSignIn {
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+        signals: Vec<String>,
+    },
+}
+
+impl SecurityNotification {
+    pub fn email_type(&self) -> EmailType {
+        match self {
+            SecurityNotification::PasswordChanged => EmailType::PasswordChanged,
+            SecurityNotification::NewSignIn { .. } => EmailType::NewSignIn,
+            SecurityNotification::MfaAdded { .. } => EmailType::MfaAdded,
+            SecurityNotification::MfaRemoved { .. } => EmailType::MfaRemoved,
+            SecurityNotification::RecoveryCodesRegenerated => EmailType::RecoveryCodesRegenerated,
+            SecurityNotification::AccountLocked => EmailType::AccountLocked,
+            SecurityNotification::EmailChanged { .. } => EmailType::EmailChanged,
+            SecurityNotification::SuspiciousSignIn { .. } => EmailType::SuspiciousSignIn,
+        }
+    }
+
+    /// Template variables of the notification, on top of the user ones.
+    /// Matches [`EmailType::available_variables`].
+    pub fn variables(&self, at: DateTime<Utc>) -> Vec<(&'static str, String)> {
+        let at = at.format("%Y-%m-%d %H:%M UTC").to_string();
+
+        match self {
+            SecurityNotification::NewSignIn {
+                ip_address,
+                user_agent,
+            } => vec![
+                ("signed_in_at", at),
+                (
+                    "ip_address",
+                    ip_address.clone().unwrap_or_else(|| "unknown".to_string()),
+                ),
+                (
+                    "user_agent",
+                    user_agent
+                        .clone()
+                        .unwrap_or_else(|| "unknown device".to_string()),
+                ),
+            ],
+            SecurityNotification::SuspiciousSignIn {
+                ip_address,
+                user_agent,
+                signals,
+            } => vec![
+                ("signed_in_at", at),
+                (
+                    "ip_address",
+                    ip_address.clone().unwrap_or_else(|| "unknown".to_string()),
+                ),
+                (
+                    "user_agent",
+                    user_agent
+                        .clone()
+                        .unwrap_or_else(|| "unknown device".to_string()),
+                ),
+                ("risk_signals", signals.join(", ")),
+            ],
+            SecurityNotification::MfaAdded { method }
+            | SecurityNotification::MfaRemoved { method } => {
+                vec![("mfa_method", method.clone()), ("changed_at", at)]
+            }
+            SecurityNotification::EmailChanged {
+                old_email,
+                new_email,
+            } => vec![
+                ("old_email", old_email.clone()),
+                ("new_email", new_email.clone()),
+                ("changed_at", at),
+            ],
+            SecurityNotification::PasswordChanged
+            | SecurityNotification::RecoveryCodesRegenerated
+            | SecurityNotification::AccountLocked => vec![("changed_at", at)],
+        }
+    }
+
+    /// Addresses the notification goes to, given the user's current one.
+    pub fn recipients(&self, user_email: Option<&str>) -> Vec<String> {
+        match self {
+            SecurityNotification::EmailChanged {
+                old_email,
+                new_email,
+            } => vec![old_email.clone(), new_email.clone()],
+            _ => user_email.map(str::to_string).into_iter().collect(),
+        }
+    }
+}
+
+/// Which security notifications a realm sends. All are on by default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SecurityNotificationSettings {
+    pub realm_id: RealmId,
+    pub password_changed: bool,
+    pub new_sign_in: bool,
+    pub mfa_added: bool,
+    pub mfa_removed: bool,
+    pub recovery_codes_regenerated: bool,
+    pub account_locked: bool,
+    pub email_changed: bool,
+    pub suspicious_sign_in: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SecurityNotificationSettings {
+    pub fn new(realm_id: RealmId) -> Self {
+        Self {
+            realm_id,
+            password_changed: true,
+            new_sign_in: true,
+            mfa_added: true,
+            mfa_removed: true,
+            recovery_codes_regenerated: true,
+            account_locked: true,
+            email_changed: true,
+            suspicious_sign_in: true,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn is_enabled(&self, email_type: &EmailType) -> bool {
+        match email_type {
+            EmailType::PasswordChanged => self.password_changed,
+            EmailType::NewSignIn => self.new_sign_in,
+            EmailType::MfaAdded => self.mfa_added,
+            EmailType::MfaRemoved => self.mfa_removed,
+            EmailType::RecoveryCodesRegenerated => self.recovery_codes_regenerated,
+            EmailType::AccountLocked => self.account_locked,
+            EmailType::EmailChanged => self.email_changed,
+            EmailType::SuspiciousSignIn => self.suspicious_sign_in,
+            EmailType::ResetPassword | EmailType::MagicLink | EmailType::EmailVerification => false,
+        }
+    }
+}
+
+/// An IP address and device pair a user signed in from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignInOrigin {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub fingerprint: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl SignInOrigin {
+    pub fn new(user_id: Uuid, ip_address: Option<String>, user_agent: Option<String>) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            fingerprint: sign_in_fingerprint(ip_address.as_deref(), user_agent.as_deref()),
+            ip_address,
+            user_agent,
+            first_seen_at: now,
+            last_seen_at: now,
+        }
+    }
+}
+
+fn sign_in_fingerprint(ip_address: Option<&str>, user_agent: Option<&str>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(ip_address.unwrap_or_default().as_bytes());
+    hasher.update(b"\n");
+    hasher.update(user_agent.unwrap_or_default().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_only_cover_security_notifications() {
+        let settings = SecurityNotificationSettings::new(RealmId::default());
+
+        for email_type in EmailType::ALL {
+            assert_eq!(
+                settings.is_enabled(&email_type),
+                email_type.is_security_notification()
+            );
+        }
+    }
+
+    #[test]
+    fn variables_match_the_email_type() {
+        let notifications = [
+            SecurityNotification::PasswordChanged,
+            SecurityNotification::NewSignIn {
+                ip_address: None,
+                user_agent: None,
+            },
+            SecurityNotification::MfaAdded {
+                method: "otp".to_string(),
+            },
+            SecurityNotification::MfaRemoved {
+                method: "webauthn".to_string(),
+            },
+            SecurityNotification::RecoveryCodesRegenerated,
+            SecurityNotification::AccountLocked,
+            SecurityNotification::EmailChanged {
+                old_email: "old@example.com".to_string(),
+                new_email: "new@example.com".to_string(),
+            },
+            SecurityNotification::SuspiciousSignIn {
+                ip_address: None,
+                user_agent: None,
+                signals: vec!["new_device".to_string()],
+            },
+        ];
+
+        for notification in notifications {
+            let available = notification.email_type().available_variables();
+            for (name, _) in notification.variables(Utc::now()) {
+                assert!(
+                    available.iter().any(|v| v.name == name),
+                    "{name} is not advertised for {}",
+                    notification.email_type()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn email_change_goes_to_both_addresses() {
+        let notification = SecurityNotification::EmailChanged {
+            old_email: "old@example.com".to_string(),
+            new_email: "new@example.com".to_string(),
+        };
+
+        assert_eq!(
+            notification.recipients(Some("new@example.com")),
+            vec!["old@example.com", "new@example.com"]
+        );
+        assert_eq!(
+            SecurityNotification::AccountLocked.recipients(None),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn fingerprint_depends_on_ip_and_device() {
+        let user_id = Uuid::new_v4();
+        let origin = |ip: &str, agent: &str| {
+            SignInOrigin::new(user_id, Some(ip.to_string()), Some(agent.to_string())).fingerprint
+        };
+
+        assert_eq!(
+            origin("203.0.113.7", "Firefox"),
+            origin("203.0.113.7", "Firefox")
+        );
+        assert_ne!(
+            origin("203.0.113.7", "Firefox"),
+            origin("203.0.113.8", "Firefox")
+        );
+        assert_ne!(
+            origin("203.0.113.7", "Firefox"),
+            origin("203.0.113.7", "Safari")
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    realm::entities::{Realm, RealmId},
+};
+
+use super::{
+    entities::{SecurityNotification, SecurityNotificationSettings, SignInOrigin},
+    value_objects::{
+        GetSecurityNotificationSettingsInput, RecordSignInInput,
+        UpdateSecurityNotificationSettingsInput,
+    },
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait SecurityNotificationRepository: Send + Sync {
+    fn get_settings(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<SecurityNotificationSettings>, CoreError>> + Send;
+    fn upsert_settings(
+        &self,
+        settings: SecurityNotificationSettings,
+    ) -> impl Future<Output = Result<SecurityNotificationSettings, CoreError>> + Send;
+    fn has_sign_in_origins(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    /// Known origins of the user, most recently seen first.
+    fn list_sign_in_origins(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<SignInOrigin>, CoreError>> + Send;
+    /// Stores the origin or bumps its `last_seen_at`. Returns whether it was
+    /// new.
+    fn touch_sign_in_origin(
+        &self,
+        origin: SignInOrigin,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+/// Sends security notifications on behalf of the services changing an
+/// account.
+#[cfg_attr(test, mockall::automock)]
+pub trait SecurityNotifier: Send + Sync {
+    /// Emails the user if the realm has the notification enabled. Never
+    /// fails the caller: delivery problems are logged.
+    fn notify(
+        &self,
+        realm_id: RealmId,
+        user_id: Uuid,
+        notification: SecurityNotification,
+    ) -> impl Future<Output = ()> + Send;
+}
+
+pub trait SecurityNotificationService: Send + Sync {
+    fn get_security_notification_settings(
+        &self,
+        identity: Identity,
+        input: GetSecurityNotificationSettingsInput,
+    ) -> impl Future<Output = Result<SecurityNotificationSettings, CoreError>> + Send;
+    fn update_security_notification_settings(
+        &self,
+        identity: Identity,
+        input: UpdateSecurityNotificationSettingsInput,
+    ) -> impl Future<Output = Result<SecurityNotificationSettings, CoreError>> + Send;
+    /// Remembers where a successful sign-in came from and notifies the user
+    /// when it is not a known origin. The first sign-in is never notified.
+    fn record_sign_in(
+        &self,
+        input: RecordSignInInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait SecurityNotificationPolicy: Send + Sync {
+    fn can_view_security_notifications(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+    fn can_manage_security_notifications(
+        &self,
+        identity: &Identity,
+        target_realm: &Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
@@ -0,0 +1,678 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::Utc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        email::EmailPort,
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    email_template::{
+        entities::{EmailType, interpolate_messages, interpolate_variables, localized_mjml},
+        ports::{EmailTemplateRepository, TemplateRenderer},
+    },
+    localization::{
+        entities::LocalizedMessages, ports::LocalizationRepository, services::localize_for_user,
+    },
+    realm::{
+        entities::{Realm, RealmId},
+        ports::{RealmRepository, SmtpConfigRepository},
+    },
+    security_notification::{
+        entities::{SecurityNotification, SecurityNotificationSettings, SignInOrigin},
+        ports::{
+            SecurityNotificationPolicy, SecurityNotificationRepository,
+            SecurityNotificationService, SecurityNotifier,
+        },
+        value_objects::{
+            GetSecurityNotificationSettingsInput, RecordSignInInput,
+            UpdateSecurityNotificationSettingsInput,
+        },
+    },
+    user::ports::{UserRepository, UserRoleRepository},
+};
+
+/// Fills `{{name}}` placeholders of a plain-text body, without escaping.
+fn fill_text(text: &str, variables: &HashMap<String, String>) -> String {
+    variables
+        .iter()
+        .fold(text.to_string(), |text, (key, value)| {
+            text.replace(&format!("{{{{{key}}}}}"), value)
+        })
+}
+
+#[derive(Clone, Debug)]
+pub struct SecurityNotifierImpl<U, SC, ES, ETR, TR, LO, SN>
+where
+    U: UserRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    ETR: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    LO: LocalizationRepository,
+    SN: SecurityNotificationRepository,
+{
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) smtp_config_repository: Arc<SC>,
+    pub(crate) email_port: Arc<ES>,
+    pub(crate) email_template_repository: Arc<ETR>,
+    pub(crate) template_renderer: Arc<TR>,
+    pub(crate) localization_repository: Arc<LO>,
+    pub(crate) security_notification_repository: Arc<SN>,
+}
+
+impl<U, SC, ES, ETR, TR, LO, SN> SecurityNotifierImpl<U, SC, ES, ETR, TR, LO, SN>
+where
+    U: UserRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    ETR: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    LO: LocalizationRepository,
+    SN: SecurityNotificationRepository,
+{
+    pub fn new(
+        user_repository: Arc<U>,
+        smtp_config_repository: Arc<SC>,
+        email_port: Arc<ES>,
+        email_template_repository: Arc<ETR>,
+        template_renderer: Arc<TR>,
+        localization_repository: Arc<LO>,
+        security_notification_repository: Arc<SN>,
+    ) -> Self {
+        Self {
+            user_repository,
+            smtp_config_repository,
+            email_port,
+            email_template_repository,
+            template_renderer,
+            localization_repository,
+            security_notification_repository,
+        }
+    }
+
+    /// Renders the realm's latest template of `email_type`, or the built-in
+    /// one when the realm has none.
+    async fn render_html(
+        &self,
+        realm_id: RealmId,
+        email_type: &EmailType,
+        messages: &LocalizedMessages,
+        variables: &HashMap<String, String>,
+    ) -> Result<String, CoreError> {
+        let template = self
+            .email_template_repository
+            .fetch_by_realm(realm_id.into())
+            .await?
+            .into_iter()
+            .filter(|t| &t.email_type == email_type)
+            .max_by_key(|t| t.updated_at);
+
+        let mjml = match template {
+            Some(template) => {
+                let localizations = self
+                    .email_template_repository
+                    .list_localizations(template.id)
+                    .await?;
+                localized_mjml(&template, &localizations, &messages.fallback_chain).to_string()
+            }
+            None => email_type
+                .default_mjml()
+                .ok_or(CoreError::EmailTemplateNotFound)?,
+        };
+
+        let html = interpolate_messages(&self.template_renderer.render_to_html(&mjml)?, messages);
+
+        Ok(interpolate_variables(&html, variables))
+    }
+
+    async fn send(
+        &self,
+        realm_id: RealmId,
+        user_id: Uuid,
+        notification: &SecurityNotification,
+    ) -> Result<(), CoreError> {
+        let email_type = notification.email_type();
+
+        let settings = self
+            .security_notification_repository
+            .get_settings(realm_id)
+            .await?
+            .unwrap_or_else(|| SecurityNotificationSettings::new(realm_id));
+        if !settings.is_enabled(&email_type) {
+            return Ok(());
+        }
+
+        let Some(smtp_config) = self
+            .smtp_config_repository
+            .get_by_realm_id(realm_id)
+            .await?
+        else {
+            debug!(realm_id = ?realm_id, "SMTP not configured, skipping {email_type} notification");
+            return Ok(());
+        };
+
+        let user = self.user_repository.get_by_id(user_id).await?;
+        let recipients = notification.recipients(user.email.as_deref());
+        if recipients.is_empty() {
+            return Ok(());
+        }
+
+        let mut variables = HashMap::from([
+            (
+                "user.first_name".to_string(),
+                user.firstname.clone().unwrap_or_default(),
+            ),
+            (
+                "user.last_name".to_string(),
+                user.lastname.clone().unwrap_or_default(),
+            ),
+            (
+                "user.email".to_string(),
+                user.email.clone().unwrap_or_default(),
+            ),
+        ]);
+        for (key, value) in notification.variables(Utc::now()) {
+            variables.insert(key.to_string(), value);
+        }
+
+        let messages = localize_for_user(&*self.localization_repository, realm_id, user_id).await;
+        let (subject, text) = email_type
+            .default_content()
+            .ok_or(CoreError::EmailTemplateNotFound)?;
+        let subject = messages.message_or(&format!("email.{email_type}.subject"), subject);
+        let body = fill_text(
+            &messages.message_or(&format!("email.{email_type}.text"), text),
+            &variables,
+        );
+
+        let html_body = self
+            .render_html(realm_id, &email_type, &messages, &variables)
+            .await
+            .inspect_err(|e| warn!("Failed to render {email_type} notification: {e}"))
+            .ok();
+
+        for recipient in recipients {
+            self.email_port
+                .send_email(&smtp_config, &recipient, &subject, &body, html_body.clone())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<U, SC, ES, ETR, TR, LO, SN> SecurityNotifier
+    for SecurityNotifierImpl<U, SC, ES, ETR, TR, LO, SN>
+where
+    U: UserRepository,
+    SC: SmtpConfigRepository,
+    ES: EmailPort,
+    ETR: EmailTemplateRepository,
+    TR: TemplateRenderer,
+    LO: LocalizationRepository,
+    SN: SecurityNotificationRepository,
+{
+    async fn notify(&self, realm_id: RealmId, user_id: Uuid, notification: SecurityNotification) {
+        if let Err(e) = self.send(realm_id, user_id, &notification).await {
+            warn!(
+                user_id = %user_id,
+                "Failed to send {} notification: {}",
+                notification.email_type(),
+                e
+            );
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SecurityNotificationServiceImpl<R, U, C, UR, SN, N>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SN: SecurityNotificationRepository,
+    N: SecurityNotifier,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) security_notification_repository: Arc<SN>,
+    pub(crate) notifier: Arc<N>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, SN, N> SecurityNotificationServiceImpl<R, U, C, UR, SN, N>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SN: SecurityNotificationRepository,
+    N: SecurityNotifier,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        security_notification_repository: Arc<SN>,
+        notifier: Arc<N>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            security_notification_repository,
+            notifier,
+            policy,
+        }
+    }
+
+    async fn get_realm(&self, realm_name: &str) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn get_settings(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<SecurityNotificationSettings, CoreError> {
+        Ok(self
+            .security_notification_repository
+            .get_settings(realm_id)
+            .await?
+            .unwrap_or_else(|| SecurityNotificationSettings::new(realm_id)))
+    }
+}
+
+impl<R, U, C, UR, SN, N> SecurityNotificationService
+    for SecurityNotificationServiceImpl<R, U, C, UR, SN, N>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    SN: SecurityNotificationRepository,
+    N: SecurityNotifier,
+{
+    async fn get_security_notification_settings(
+        &self,
+        identity: Identity,
+        input: GetSecurityNotificationSettingsInput,
+    ) -> Result<SecurityNotificationSettings, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_view_security_notifications(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        self.get_settings(realm.id).await
+    }
+
+    async fn update_security_notification_settings(
+        &self,
+        identity: Identity,
+        input: UpdateSecurityNotificationSettingsInput,
+    ) -> Result<SecurityNotificationSettings, CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_security_notifications(&identity, &realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let current = self.get_settings(realm.id).await?;
+
+        self.security_notification_repository
+            .upsert_settings(SecurityNotificationSettings {
+                realm_id: realm.id,
+                password_changed: input.password_changed.unwrap_or(current.password_changed),
+                new_sign_in: input.new_sign_in.unwrap_or(current.new_sign_in),
+                mfa_added: input.mfa_added.unwrap_or(current.mfa_added),
+                mfa_removed: input.mfa_removed.unwrap_or(current.mfa_removed),
+                recovery_codes_regenerated: input
+                    .recovery_codes_regenerated
+                    .unwrap_or(current.recovery_codes_regenerated),
+                account_locked: input.account_locked.unwrap_or(current.account_locked),
+                email_changed: input.email_changed.unwrap_or(current.email_changed),
+                suspicious_sign_in: input
+                    .suspicious_sign_in
+                    .unwrap_or(current.suspicious_sign_in),
+                updated_at: Utc::now(),
+            })
+            .await
+    }
+
+    async fn record_sign_in(&self, input: RecordSignInInput) -> Result<(), CoreError> {
+        let realm = self.get_realm(&input.realm_name).await?;
+
+        let known_user = self
+            .security_notification_repository
+            .has_sign_in_origins(input.user_id)
+            .await?;
+        let origin = SignInOrigin::new(
+            input.user_id,
+            input.ip_address.clone(),
+            input.user_agent.clone(),
+        );
+        let new_origin = self
+            .security_notification_repository
+            .touch_sign_in_origin(origin)
+            .await?;
+
+        if known_user && new_origin {
+            self.notifier
+                .notify(
+                    realm.id,
+                    input.user_id,
+                    SecurityNotification::NewSignIn {
+                        ip_address: input.ip_address,
+                        user_agent: input.user_agent,
+                    },
+                )
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::domain::{
+        client::ports::MockClientRepository,
+        common::email::MockEmailPort,
+        email_template::ports::MockEmailTemplateRepository,
+        localization::ports::MockLocalizationRepository,
+        realm::{
+            entities::{EmailProvider, SmtpConfig, SmtpEncryption},
+            ports::{MockRealmRepository, MockSmtpConfigRepository},
+        },
+        security_notification::ports::{MockSecurityNotificationRepository, MockSecurityNotifier},
+        user::{
+            entities::{User, UserConfig},
+            ports::{MockUserRepository, MockUserRoleRepository},
+        },
+    };
+
+    /// A notifier that accepts and drops every notification, for services
+    /// whose tests don't assert on them.
+    pub fn permissive_notifier() -> MockSecurityNotifier {
+        let mut notifier = MockSecurityNotifier::new();
+        notifier
+            .expect_notify()
+            .returning(|_, _, _| Box::pin(async {}));
+        notifier
+    }
+
+    struct EchoRenderer;
+
+    impl TemplateRenderer for EchoRenderer {
+        fn render_to_intermediate(
+            &self,
+            _structure: &serde_json::Value,
+        ) -> Result<String, CoreError> {
+            Ok(String::new())
+        }
+
+        fn render_to_html(&self, intermediate: &str) -> Result<String, CoreError> {
+            Ok(intermediate.to_string())
+        }
+    }
+
+    type TestNotifier = SecurityNotifierImpl<
+        MockUserRepository,
+        MockSmtpConfigRepository,
+        MockEmailPort,
+        MockEmailTemplateRepository,
+        EchoRenderer,
+        MockLocalizationRepository,
+        MockSecurityNotificationRepository,
+    >;
+
+    fn test_user(realm_id: RealmId) -> User {
+        User::new(UserConfig {
+            realm_id,
+            client_id: None,
+            username: "jane".to_string(),
+            firstname: Some("Jane".to_string()),
+            lastname: None,
+            email: Some("jane@example.com".to_string()),
+            email_verified: true,
+            enabled: true,
+        })
+    }
+
+    fn smtp_config(realm_id: RealmId) -> SmtpConfig {
+        SmtpConfig {
+            id: Uuid::new_v4(),
+            realm_id: realm_id.into(),
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            username: String::new(),
+            password: String::new(),
+            from_email: "noreply@example.com".to_string(),
+            from_name: "Example".to_string(),
+            encryption: SmtpEncryption::Tls,
+            provider: EmailProvider::Smtp,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn unlocalized_repo() -> MockLocalizationRepository {
+        let mut repo = MockLocalizationRepository::new();
+        repo.expect_get_user_locale()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        repo.expect_get_settings()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        repo.expect_get_bundles()
+            .returning(|_, _| Box::pin(async { Ok(vec![]) }));
+        repo
+    }
+
+    fn build_notifier(
+        user: User,
+        settings: SecurityNotificationSettings,
+        email_port: MockEmailPort,
+    ) -> TestNotifier {
+        let realm_id = user.realm_id;
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_get_by_id().returning(move |_| {
+            let user = user.clone();
+            Box::pin(async move { Ok(user) })
+        });
+
+        let mut smtp_repo = MockSmtpConfigRepository::new();
+        smtp_repo.expect_get_by_realm_id().returning(move |_| {
+            let config = smtp_config(realm_id);
+            Box::pin(async move { Ok(Some(config)) })
+        });
+
+        let mut template_repo = MockEmailTemplateRepository::new();
+        template_repo
+            .expect_fetch_by_realm()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let mut notification_repo = MockSecurityNotificationRepository::new();
+        notification_repo.expect_get_settings().returning(move |_| {
+            let settings = settings.clone();
+            Box::pin(async move { Ok(Some(settings)) })
+        });
+
+        SecurityNotifierImpl::new(
+            Arc::new(user_repo),
+            Arc::new(smtp_repo),
+            Arc::new(email_port),
+            Arc::new(template_repo),
+            Arc::new(EchoRenderer),
+            Arc::new(unlocalized_repo()),
+            Arc::new(notification_repo),
+        )
+    }
+
+    #[tokio::test]
+    async fn email_change_is_sent_to_both_addresses_with_the_default_template() {
+        let user = test_user(RealmId::default());
+        let realm_id = user.realm_id;
+        let sent = Arc::new(Mutex::new(Vec::new()));
+
+        let mut email_port = MockEmailPort::new();
+        let sent_clone = sent.clone();
+        email_port
+            .expect_send_email()
+            .times(2)
+            .returning(move |_, to, subject, body, html| {
+                sent_clone.lock().unwrap().push((
+                    to.to_string(),
+                    subject.to_string(),
+                    body.to_string(),
+                    html.unwrap_or_default(),
+                ));
+                Box::pin(async { Ok(()) })
+            });
+
+        let notifier = build_notifier(
+            user.clone(),
+            SecurityNotificationSettings::new(realm_id),
+            email_port,
+        );
+        notifier
+            .notify(
+                realm_id,
+                user.id,
+                SecurityNotification::EmailChanged {
+                    old_email: "old@example.com".to_string(),
+                    new_email: "jane@example.com".to_string(),
+                },
+            )
+            .await;
+
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[0].0, "old@example.com");
+        assert_eq!(sent[1].0, "jane@example.com");
+        assert_eq!(sent[0].1, "Your email address was changed");
+        assert!(
+            sent[0]
+                .2
+                .contains("from old@example.com to jane@example.com")
+        );
+        assert!(sent[0].3.contains("Hello Jane,"));
+    }
+
+    #[tokio::test]
+    async fn disabled_notifications_are_not_sent() {
+        let user = test_user(RealmId::default());
+        let realm_id = user.realm_id;
+
+        let mut email_port = MockEmailPort::new();
+        email_port.expect_send_email().never();
+
+        let settings = SecurityNotificationSettings {
+            password_changed: false,
+            ..SecurityNotificationSettings::new(realm_id)
+        };
+        let notifier = build_notifier(user.clone(), settings, email_port);
+
+        notifier
+            .notify(realm_id, user.id, SecurityNotification::PasswordChanged)
+            .await;
+    }
+
+    fn build_service(
+        realm: Realm,
+        known_user: bool,
+        new_origin: bool,
+        notifier: MockSecurityNotifier,
+    ) -> SecurityNotificationServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockSecurityNotificationRepository,
+        MockSecurityNotifier,
+    > {
+        let mut realm_repo = MockRealmRepository::new();
+        realm_repo.expect_get_by_name().returning(move |_| {
+            let realm = realm.clone();
+            Box::pin(async move { Ok(Some(realm)) })
+        });
+
+        let mut repo = MockSecurityNotificationRepository::new();
+        repo.expect_has_sign_in_origins()
+            .returning(move |_| Box::pin(async move { Ok(known_user) }));
+        repo.expect_touch_sign_in_origin()
+            .returning(move |_| Box::pin(async move { Ok(new_origin) }));
+
+        let policy = Arc::new(FerriskeyPolicy::new(
+            Arc::new(MockUserRepository::new()),
+            Arc::new(MockClientRepository::new()),
+            Arc::new(MockUserRoleRepository::new()),
+        ));
+
+        SecurityNotificationServiceImpl::new(
+            Arc::new(realm_repo),
+            Arc::new(repo),
+            Arc::new(notifier),
+            policy,
+        )
+    }
+
+    fn sign_in(user_id: Uuid) -> RecordSignInInput {
+        RecordSignInInput {
+            realm_name: "test-realm".to_string(),
+            user_id,
+            ip_address: Some("203.0.113.7".to_string()),
+            user_agent: Some("Firefox".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn sign_in_from_a_new_origin_is_notified() {
+        let realm = Realm::new("test-realm".to_string());
+        let user_id = Uuid::new_v4();
+
+        let mut notifier = MockSecurityNotifier::new();
+        notifier
+            .expect_notify()
+            .withf(move |_, id, notification| {
+                *id == user_id && matches!(notification, SecurityNotification::NewSignIn { .. })
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async {}));
+
+        let service = build_service(realm, true, true, notifier);
+        service.record_sign_in(sign_in(user_id)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn first_and_known_sign_ins_are_not_notified() {
+        for (known_user, new_origin) in [(false, true), (true, false)] {
+            let mut notifier = MockSecurityNotifier::new();
+            notifier.expect_notify().never();
+
+            let service = build_service(
+                Realm::new("test-realm".to_string()),
+                known_user,
+                new_origin,
+                notifier,
+            );
+            service
+                .record_sign_in(sign_in(Uuid::new_v4()))
+                .await
+                .unwrap();
+        }
+    }
+}
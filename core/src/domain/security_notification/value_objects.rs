@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct GetSecurityNotificationSettingsInput {
+    pub realm_name: String,
+}
+
+/// Toggles left out keep their current value.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateSecurityNotificationSettingsInput {
+    pub realm_name: String,
+    pub password_changed: Option<bool>,
+    pub new_sign_in: Option<bool>,
+    pub mfa_added: Option<bool>,
+    pub mfa_removed: Option<bool>,
+    pub recovery_codes_regenerated: Option<bool>,
+    pub account_locked: Option<bool>,
+    pub email_changed: Option<bool>,
+    pub suspicious_sign_in: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordSignInInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
@@ -3,6 +3,7 @@ use ferriskey_trident::entities::{MagicLink, PasswordResetToken};
 use uuid::Uuid;
 
 use crate::domain::{
+    auth_flow::entities::Authenticator,
     authentication::value_objects::Identity,
     common::entities::app_errors::CoreError,
     crypto::HashResult,
@@ -78,10 +79,19 @@ pub struct PasskeyAuthenticateInput {
     pub session_code: String,
     pub rp_info: WebAuthnRpInfo,
     pub credential: PublicKeyCredential,
+    pub base_url: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub trusted_device_token: Option<String>,
 }
 
 pub struct PasskeyAuthenticateOutput {
-    pub login_url: String,
+    pub login_url: Option<String>,
+    /// Authenticators the user can pass next when the flow has more steps.
+    pub methods: Vec<Authenticator>,
+    /// Token to pass the next authenticators with, when the passkey started
+    /// the sign-in.
+    pub temporary_token: Option<String>,
 }
 
 pub struct ChallengeOtpInput {
@@ -93,6 +103,8 @@ pub struct ChallengeOtpOutput {
     pub login_url: Option<String>,
     pub required_actions: Vec<RequiredAction>,
     pub temporary_token: Option<String>,
+    /// Authenticators the user can pass next when the flow has more steps.
+    pub methods: Vec<Authenticator>,
 }
 
 pub struct SetupOtpInput {
@@ -6,19 +6,18 @@ use std::{
 use chrono::{Duration, Utc};
 use ferriskey_domain::generate_uuid_v7;
 use futures::future::try_join_all;
-use hmac::{Hmac, Mac};
 use rand::RngCore;
-use sha1::Sha1;
 use tracing::{debug, error, warn};
 use uuid::Uuid;
 use webauthn_rs::prelude::*;
 
 use crate::{
     domain::{
+        auth_flow::{entities::Authenticator, services::complete_auth_flow_stage},
         authentication::{
             entities::{AuthSession, WebAuthnChallenge},
-            ports::AuthSessionRepository,
-            value_objects::Identity,
+            ports::{AuthSessionRepository, SignInGate},
+            value_objects::{CheckSignInInput, Identity},
         },
         common::{
             email::EmailPort, entities::app_errors::CoreError, generate_random_string,
@@ -30,9 +29,17 @@ use crate::{
         },
         crypto::HasherRepository,
         email_template::{
-            entities::interpolate_variables,
+            entities::{interpolate_messages, interpolate_variables, localized_mjml},
             ports::{EmailTemplateRepository, TemplateRenderer},
         },
+        localization::{
+            entities::LocalizedMessages, ports::LocalizationRepository, services::localize_for_user,
+        },
+        otp::{
+            entities::{OtpCredentialData, OtpPolicy},
+            ports::OtpPolicyRepository,
+            services::realm_otp_policy,
+        },
         realm::{
             entities::RealmId,
             ports::{RealmRepository, SmtpConfigRepository},
@@ -41,6 +48,7 @@ use crate::{
             entities::{EventStatus, SecurityEvent, SecurityEventType},
             ports::SecurityEventRepository,
         },
+        security_notification::{entities::SecurityNotification, ports::SecurityNotifier},
         trident::{
             entities::{MfaRecoveryCode, PasswordResetToken, TotpSecret},
             ports::{
@@ -72,8 +80,6 @@ use crate::{
     },
 };
 
-type HmacSha1 = Hmac<Sha1>;
-
 fn generate_secret() -> Result<TotpSecret, CoreError> {
     let mut bytes = [0u8; 20];
     rand::thread_rng()
@@ -85,69 +91,20 @@ fn generate_secret() -> Result<TotpSecret, CoreError> {
     Ok(TotpSecret::from_base32(&base32))
 }
 
-fn generate_otpauth_uri(issuer: &str, user_email: &str, secret: &TotpSecret) -> String {
-    let encoded_secret = secret.base32_encoded();
-
-    let issuer_encoded = urlencoding::encode(issuer);
-    let label_encoded = urlencoding::encode(user_email);
-
-    format!(
-        "otpauth://totp/{label_encoded}?secret={encoded_secret}&issuer={issuer_encoded}&algorithm=SHA1&digits=6&period=30"
-    )
-}
-
-fn generate_totp_code(secret: &[u8], counter: u64, digits: u32) -> Result<u32, CoreError> {
-    let mut mac = HmacSha1::new_from_slice(secret).map_err(|_| CoreError::InternalServerError)?;
-
-    let mut counter_bytes = [0u8; 8];
-
-    counter_bytes.copy_from_slice(&counter.to_be_bytes());
-
-    mac.update(&counter_bytes);
-
-    let hmac_result = mac.finalize().into_bytes();
-
-    let offset = (hmac_result[19] & 0x0f) as usize;
-    let code = ((hmac_result[offset] as u32 & 0x7f) << 24)
-        | ((hmac_result[offset + 1] as u32) << 16)
-        | ((hmac_result[offset + 2] as u32) << 8)
-        | (hmac_result[offset + 3] as u32);
-
-    Ok(code % 10u32.pow(digits))
-}
-
-fn verify(secret: &TotpSecret, code: &str) -> Result<bool, CoreError> {
-    let Ok(expected_code) = code.parse::<u32>() else {
-        error!("failed to parse code: {}", code);
-        return Ok(false);
-    };
-
-    let Ok(secret_bytes) = secret.to_bytes() else {
-        error!("failed to convert secret to bytes");
-        return Ok(false);
-    };
-
-    let time_step = 30;
-    let digits = 6;
-
-    let now = SystemTime::now()
+fn unix_now() -> u64 {
+    SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .expect("system time before UNIX_EPOCH")
-        .as_secs();
-
-    let counter = now / time_step;
-
-    let counters_to_check = [counter.saturating_sub(1), counter, counter + 1];
-
-    for &check_counter in counters_to_check.iter() {
-        let generated = generate_totp_code(&secret_bytes, check_counter, digits)?;
+        .as_secs()
+}
 
-        if generated == expected_code {
-            return Ok(true);
-        }
+/// Settings of an OTP device. Devices whose data predates OTP policies were
+/// enrolled with the default ones.
+fn otp_credential_data(credential: &Credential, realm_id: RealmId) -> OtpCredentialData {
+    match &credential.credential_data {
+        CredentialData::Otp(data) => data.clone(),
+        _ => OtpPolicy::new(realm_id).credential_data(),
     }
-
-    Ok(false)
 }
 
 fn format_code(code: &MfaRecoveryCode, format: RecoveryCodeFormat) -> String {
@@ -182,7 +139,7 @@ fn build_webauthn_client(rp_info: WebAuthnRpInfo) -> Result<Webauthn, CoreError>
 
 /// Generates a random authorization code, stores it in the user auth session
 /// and returns it in a formated URL ready to be sent to the user
-async fn store_auth_code_and_generate_login_url<AS: AuthSessionRepository>(
+pub(crate) async fn store_auth_code_and_generate_login_url<AS: AuthSessionRepository>(
     auth_session_repository: &AS,
     auth_session: &AuthSession,
     user_id: Uuid,
@@ -206,8 +163,27 @@ async fn store_auth_code_and_generate_login_url<AS: AuthSessionRepository>(
 }
 
 #[derive(Clone, Debug)]
-pub struct TridentServiceImpl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR>
-where
+pub struct TridentServiceImpl<
+    CR,
+    RC,
+    AS,
+    H,
+    URA,
+    ML,
+    UR,
+    RR,
+    ES,
+    SC,
+    PRT,
+    SE,
+    WH,
+    ETR,
+    TR,
+    LO,
+    SN,
+    OP,
+    SG,
+> where
     CR: CredentialRepository,
     RC: RecoveryCodeRepository,
     AS: AuthSessionRepository,
@@ -223,6 +199,10 @@ where
     WH: WebhookRepository,
     ETR: EmailTemplateRepository,
     TR: TemplateRenderer,
+    LO: LocalizationRepository,
+    SN: SecurityNotifier,
+    OP: OtpPolicyRepository,
+    SG: SignInGate,
 {
     pub(crate) credential_repository: Arc<CR>,
     pub(crate) recovery_code_repository: Arc<RC>,
@@ -239,10 +219,14 @@ where
     pub(crate) webhook_repository: Arc<WH>,
     pub(crate) email_template_repository: Arc<ETR>,
     pub(crate) template_renderer: Arc<TR>,
+    pub(crate) localization_repository: Arc<LO>,
+    pub(crate) security_notifier: Arc<SN>,
+    pub(crate) otp_policy_repository: Arc<OP>,
+    pub(crate) sign_in_gate: Arc<SG>,
 }
 
-impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR>
-    TridentServiceImpl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR>
+impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR, LO, SN, OP, SG>
+    TridentServiceImpl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR, LO, SN, OP, SG>
 where
     CR: CredentialRepository,
     RC: RecoveryCodeRepository,
@@ -259,6 +243,10 @@ where
     WH: WebhookRepository,
     ETR: EmailTemplateRepository,
     TR: TemplateRenderer,
+    LO: LocalizationRepository,
+    SN: SecurityNotifier,
+    OP: OtpPolicyRepository,
+    SG: SignInGate,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -277,6 +265,10 @@ where
         webhook_repository: Arc<WH>,
         email_template_repository: Arc<ETR>,
         template_renderer: Arc<TR>,
+        localization_repository: Arc<LO>,
+        security_notifier: Arc<SN>,
+        otp_policy_repository: Arc<OP>,
+        sign_in_gate: Arc<SG>,
     ) -> Self {
         Self {
             credential_repository,
@@ -294,6 +286,10 @@ where
             webhook_repository,
             email_template_repository,
             template_renderer,
+            localization_repository,
+            security_notifier,
+            otp_policy_repository,
+            sign_in_gate,
         }
     }
 
@@ -301,6 +297,7 @@ where
         &self,
         template_id: Uuid,
         user: &crate::domain::user::entities::User,
+        messages: &LocalizedMessages,
         extra_vars: &[(&str, &str)],
     ) -> Result<String, CoreError> {
         let template = self
@@ -308,8 +305,13 @@ where
             .get_by_id(template_id)
             .await?
             .ok_or(CoreError::EmailTemplateNotFound)?;
+        let localizations = self
+            .email_template_repository
+            .list_localizations(template_id)
+            .await?;
 
-        let html = self.template_renderer.render_to_html(&template.mjml)?;
+        let mjml = localized_mjml(&template, &localizations, &messages.fallback_chain);
+        let html = interpolate_messages(&self.template_renderer.render_to_html(mjml)?, messages);
 
         let mut variables = std::collections::HashMap::new();
         variables.insert(
@@ -332,8 +334,28 @@ where
     }
 }
 
-impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR> TridentService
-    for TridentServiceImpl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR>
+impl<CR, RC, AS, H, URA, ML, UR, RR, ES, SC, PRT, SE, WH, ETR, TR, LO, SN, OP, SG> TridentService
+    for TridentServiceImpl<
+        CR,
+        RC,
+        AS,
+        H,
+        URA,
+        ML,
+        UR,
+        RR,
+        ES,
+        SC,
+        PRT,
+        SE,
+        WH,
+        ETR,
+        TR,
+        LO,
+        SN,
+        OP,
+        SG,
+    >
 where
     CR: CredentialRepository,
     RC: RecoveryCodeRepository,
@@ -350,6 +372,10 @@ where
     WH: WebhookRepository,
     ETR: EmailTemplateRepository,
     TR: TemplateRenderer,
+    LO: LocalizationRepository,
+    SN: SecurityNotifier,
+    OP: OtpPolicyRepository,
+    SG: SignInGate,
 {
     async fn generate_recovery_code(
         &self,
@@ -411,6 +437,14 @@ where
             .map(|c| format_code(&c, format.clone()))
             .collect::<Vec<String>>();
 
+        self.security_notifier
+            .notify(
+                user.realm_id,
+                user.id,
+                SecurityNotification::RecoveryCodesRegenerated,
+            )
+            .await;
+
         Ok(GenerateRecoveryCodeOutput { codes })
     }
 
@@ -624,6 +658,16 @@ where
             .remove_required_action(user.id, RequiredAction::ConfigurePasskey)
             .await;
 
+        self.security_notifier
+            .notify(
+                user.realm_id,
+                user.id,
+                SecurityNotification::MfaAdded {
+                    method: "webauthn".to_string(),
+                },
+            )
+            .await;
+
         Ok(WebAuthnValidatePublicKeyOutput {})
     }
 
@@ -908,6 +952,52 @@ where
             return Err(CoreError::WebAuthnChallengeFailed);
         }
 
+        // A passkey used as a later step of the flow must belong to the user
+        // who passed the previous ones.
+        if !auth_session.pending_auth_stages.is_empty() && auth_session.user_id != Some(user.id) {
+            return Err(CoreError::WebAuthnChallengeFailed);
+        }
+
+        if let Some(next) = complete_auth_flow_stage(
+            self.auth_session_repository.as_ref(),
+            &auth_session,
+            user.id,
+            &Authenticator::Passkey,
+        )
+        .await?
+        {
+            return Ok(PasskeyAuthenticateOutput {
+                login_url: None,
+                methods: next.methods,
+                temporary_token: None,
+            });
+        }
+
+        // Signing in with the passkey alone goes through the same checks as
+        // a password sign-in.
+        if auth_session.pending_auth_stages.is_empty() {
+            let check = self
+                .sign_in_gate
+                .check_sign_in(CheckSignInInput {
+                    auth_session: auth_session.clone(),
+                    user_id: user.id,
+                    authenticator: Authenticator::Passkey,
+                    base_url: input.base_url,
+                    ip_address: input.ip_address,
+                    user_agent: input.user_agent,
+                    trusted_device_token: input.trusted_device_token,
+                })
+                .await?;
+
+            if let Some(next) = check.next {
+                return Ok(PasskeyAuthenticateOutput {
+                    login_url: None,
+                    methods: next.methods,
+                    temporary_token: check.temporary_token,
+                });
+            }
+        }
+
         let login_url = store_auth_code_and_generate_login_url::<AS>(
             &self.auth_session_repository,
             &auth_session,
@@ -915,7 +1005,11 @@ where
         )
         .await?;
 
-        Ok(PasskeyAuthenticateOutput { login_url })
+        Ok(PasskeyAuthenticateOutput {
+            login_url: Some(login_url),
+            methods: Vec::new(),
+            temporary_token: None,
+        })
     }
 
     async fn challenge_otp(
@@ -943,18 +1037,34 @@ where
             .await
             .map_err(|_| CoreError::GetUserCredentialsError)?;
 
-        let otp_credential = user_credentials
+        let policy = realm_otp_policy(self.otp_policy_repository.as_ref(), user.realm_id).await?;
+        let now = unix_now();
+
+        let devices: Vec<&Credential> = user_credentials
             .iter()
-            .find(|cred| cred.credential_type == CredentialType::Otp)
-            .ok_or_else(|| {
-                CoreError::TotpVerificationFailed("user has not OTP configured".to_string())
-            })?;
+            .filter(|cred| cred.credential_type == CredentialType::Otp)
+            .collect();
 
-        let secret = TotpSecret::from_base32(&otp_credential.secret_data);
+        if devices.is_empty() {
+            return Err(CoreError::TotpVerificationFailed(
+                "user has not OTP configured".to_string(),
+            ));
+        }
 
-        let is_valid = verify(&secret, &input.code)?;
+        // Every device of the user is tried, so they don't have to say which
+        // one the code comes from.
+        let accepted = devices.into_iter().find_map(|credential| {
+            let secret = TotpSecret::from_base32(&credential.secret_data)
+                .to_bytes()
+                .inspect_err(|_| error!(credential_id = %credential.id, "invalid OTP secret"))
+                .ok()?;
+
+            otp_credential_data(credential, user.realm_id)
+                .verify(&secret, &input.code, now, policy.look_around)
+                .map(|next| (credential, next))
+        });
 
-        if !is_valid {
+        let Some((credential, next)) = accepted else {
             error!(
                 "invalid OTP code for user: {}",
                 user.email.as_deref().unwrap_or("")
@@ -962,6 +1072,41 @@ where
             return Err(CoreError::TotpVerificationFailed(
                 "failed to verify OTP".to_string(),
             ));
+        };
+
+        // Losing the race against another sign-in with the same code means
+        // it is being replayed.
+        let stored = self
+            .credential_repository
+            .update_otp_credential_data(credential.id, credential.updated_at, next)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if !stored {
+            warn!(
+                user_id = %user.id,
+                credential_id = %credential.id,
+                "OTP code accepted concurrently, rejecting replay"
+            );
+            return Err(CoreError::TotpVerificationFailed(
+                "failed to verify OTP".to_string(),
+            ));
+        }
+
+        if let Some(next) = complete_auth_flow_stage(
+            self.auth_session_repository.as_ref(),
+            &auth_session,
+            user.id,
+            &Authenticator::Otp,
+        )
+        .await?
+        {
+            return Ok(ChallengeOtpOutput {
+                login_url: None,
+                required_actions: Vec::new(),
+                temporary_token: None,
+                methods: next.methods,
+            });
         }
 
         let required_actions = self
@@ -975,6 +1120,7 @@ where
                 login_url: None,
                 required_actions,
                 temporary_token: None,
+                methods: Vec::new(),
             });
         }
 
@@ -998,6 +1144,7 @@ where
             login_url: Some(login_url),
             required_actions: Vec::new(),
             temporary_token: None,
+            methods: Vec::new(),
         })
     }
 
@@ -1011,9 +1158,14 @@ where
             _ => return Err(CoreError::Forbidden("is not user".to_string())),
         };
 
+        let policy = realm_otp_policy(self.otp_policy_repository.as_ref(), user.realm_id).await?;
+
         let secret = generate_secret()?;
-        let otpauth_uri =
-            generate_otpauth_uri(&input.issuer, user.email.as_deref().unwrap_or(""), &secret);
+        let otpauth_uri = policy.credential_data().otpauth_uri(
+            &input.issuer,
+            user.email.as_deref().unwrap_or(""),
+            secret.base32_encoded(),
+        );
 
         Ok(SetupOtpOutput {
             otpauth_uri,
@@ -1059,6 +1211,14 @@ where
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.security_notifier
+            .notify(
+                user.realm_id,
+                user.id,
+                SecurityNotification::PasswordChanged,
+            )
+            .await;
+
         Ok(())
     }
 
@@ -1080,21 +1240,21 @@ where
         };
 
         let secret = TotpSecret::from_base32(&input.secret);
-
-        let is_valid = verify(&secret, &input.code)?;
-
-        if !is_valid {
+        let policy = realm_otp_policy(self.otp_policy_repository.as_ref(), user.realm_id).await?;
+
+        // Enrolling consumes the code, so it cannot be replayed to sign in.
+        let Some(enrolled) = policy.credential_data().verify(
+            &secret.to_bytes()?,
+            &input.code,
+            unix_now(),
+            policy.look_around,
+        ) else {
             error!("invalid OTP code");
             return Err(CoreError::InternalServerError);
-        }
+        };
 
-        let credential_data = serde_json::json!({
-          "subType": "totp",
-          "digits": 6,
-          "counter": 0,
-          "period": 30,
-          "algorithm": "HmacSha256",
-        });
+        let credential_data = serde_json::to_value(CredentialData::Otp(enrolled))
+            .map_err(|_| CoreError::InternalServerError)?;
 
         let existing_credentials = self
             .credential_repository
@@ -1102,9 +1262,10 @@ where
             .await
             .map_err(|_| CoreError::GetUserCredentialsError)?;
 
+        // Enrolling a device under the name of an existing one replaces it.
         for cred in existing_credentials
             .iter()
-            .filter(|c| c.credential_type == CredentialType::Otp)
+            .filter(|c| c.credential_type == CredentialType::Otp && c.user_label == input.label)
         {
             self.credential_repository
                 .delete_by_id(cred.id)
@@ -1141,6 +1302,16 @@ where
             );
         }
 
+        self.security_notifier
+            .notify(
+                user.realm_id,
+                user.id,
+                SecurityNotification::MfaAdded {
+                    method: "otp".to_string(),
+                },
+            )
+            .await;
+
         Ok(VerifyOtpOutput {
             message: "OTP verified successfully".to_string(),
             user_id: user.id,
@@ -1227,10 +1398,13 @@ where
                     "Click the link below to sign in:\n{magic_link_url}\n\nThis link expires in {ttl_minutes} minutes.\n\nIf you did not request this, please ignore this email.",
                 );
 
+                let messages =
+                    localize_for_user(&*self.localization_repository, realm.id, user.id).await;
                 let html_body = self
                     .render_email_template(
                         tid,
                         &user,
+                        &messages,
                         &[
                             ("magic_link", magic_link_url.as_str()),
                             ("expiration", &format!("{ttl_minutes} minutes")),
@@ -1244,7 +1418,7 @@ where
                     .send_email(
                         &smtp_config,
                         user.email.as_deref().unwrap_or(""),
-                        "Your magic link",
+                        &messages.message_or("email.magic_link.subject", "Your magic link"),
                         &body,
                         html_body,
                     )
@@ -1542,10 +1716,13 @@ where
                     "A password reset was requested for your account.\n\nClick the link below to reset your password:\n{reset_link}\n\nThis link expires in {ttl_minutes} minutes.\n\nIf you did not request this, please ignore this email.",
                 );
 
+                let messages =
+                    localize_for_user(&*self.localization_repository, realm.id, user.id).await;
                 let html_body = self
                     .render_email_template(
                         tid,
                         &user,
+                        &messages,
                         &[
                             ("reset_link", reset_link.as_str()),
                             ("expiration", &format!("{ttl_minutes} minutes")),
@@ -1559,7 +1736,7 @@ where
                     .send_email(
                         &smtp_config,
                         user.email.as_deref().unwrap_or(""),
-                        "Reset your password",
+                        &messages.message_or("email.reset_password.subject", "Reset your password"),
                         &body,
                         html_body,
                     )
@@ -1780,6 +1957,14 @@ where
             .await
             .inspect_err(|e| warn!("Failed to emit password reset webhook: {}", e));
 
+        self.security_notifier
+            .notify(
+                realm_id_typed,
+                user_id,
+                SecurityNotification::PasswordChanged,
+            )
+            .await;
+
         let login_url = if let Some(session_code) = auth_session_code {
             match self
                 .auth_session_repository
@@ -1851,12 +2036,20 @@ where
 mod tests {
     use super::*;
     use crate::domain::{
-        authentication::ports::MockAuthSessionRepository,
+        authentication::{
+            entities::AuthSessionParams,
+            ports::{MockAuthSessionRepository, MockSignInGate},
+        },
         common::{email::MockEmailPort, services::tests::create_test_realm_with_name},
-        credential::ports::MockCredentialRepository,
+        credential::{entities::CredentialConfig, ports::MockCredentialRepository},
         email_template::ports::MockEmailTemplateRepository,
+        localization::ports::MockLocalizationRepository,
+        otp::ports::MockOtpPolicyRepository,
         realm::ports::{MockRealmRepository, MockSmtpConfigRepository},
         seawatch::ports::MockSecurityEventRepository,
+        security_notification::{
+            ports::MockSecurityNotifier, services::tests::permissive_notifier,
+        },
         trident::ports::{
             MockMagicLinkRepository, MockPasswordResetTokenRepository, MockRecoveryCodeRepository,
         },
@@ -1898,6 +2091,10 @@ mod tests {
         webhook_repo: Arc<MockWebhookRepository>,
         email_template_repo: Arc<MockEmailTemplateRepository>,
         template_renderer: Arc<NoopTemplateRenderer>,
+        localization_repo: Arc<MockLocalizationRepository>,
+        security_notifier: Arc<MockSecurityNotifier>,
+        otp_policy_repo: Arc<MockOtpPolicyRepository>,
+        sign_in_gate: Arc<MockSignInGate>,
     }
 
     impl TridentTestBuilder {
@@ -1918,6 +2115,10 @@ mod tests {
                 webhook_repo: Arc::new(MockWebhookRepository::new()),
                 email_template_repo: Arc::new(MockEmailTemplateRepository::new()),
                 template_renderer: Arc::new(NoopTemplateRenderer),
+                localization_repo: Arc::new(MockLocalizationRepository::new()),
+                security_notifier: Arc::new(permissive_notifier()),
+                otp_policy_repo: Arc::new(MockOtpPolicyRepository::new()),
+                sign_in_gate: Arc::new(MockSignInGate::new()),
             }
         }
 
@@ -1939,6 +2140,10 @@ mod tests {
             MockWebhookRepository,
             MockEmailTemplateRepository,
             NoopTemplateRenderer,
+            MockLocalizationRepository,
+            MockSecurityNotifier,
+            MockOtpPolicyRepository,
+            MockSignInGate,
         > {
             TridentServiceImpl::new(
                 self.credential_repo,
@@ -1956,6 +2161,10 @@ mod tests {
                 self.webhook_repo,
                 self.email_template_repo,
                 self.template_renderer,
+                self.localization_repo,
+                self.security_notifier,
+                self.otp_policy_repo,
+                self.sign_in_gate,
             )
         }
     }
@@ -2425,4 +2634,103 @@ mod tests {
 
         assert!(matches!(result, Err(CoreError::NotFound)));
     }
+
+    // ── challenge_otp ───────────────────────────────────────────────────
+
+    fn create_otp_credential(user_id: Uuid, seed: &[u8; 20], label: &str) -> Credential {
+        let now = Utc::now();
+
+        Credential::new(CredentialConfig {
+            id: Uuid::new_v4(),
+            salt: None,
+            credential_type: "otp".to_string(),
+            user_id,
+            user_label: Some(label.to_string()),
+            secret_data: TotpSecret::from_bytes(*seed).base32_encoded().to_string(),
+            credential_data: CredentialData::Otp(
+                OtpPolicy::new(RealmId::default()).credential_data(),
+            ),
+            temporary: false,
+            created_at: now,
+            updated_at: now,
+            webauthn_credential_id: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn challenge_otp_rejects_code_accepted_concurrently() {
+        let mut builder = TridentTestBuilder::new();
+        let realm = create_test_realm_with_name("test-realm");
+        let user = crate::domain::common::services::tests::create_test_user(realm.id);
+
+        let phone = create_otp_credential(user.id, b"phone-seed-phone-see", "phone");
+        let token = create_otp_credential(user.id, b"token-seed-token-see", "token");
+        let code = format!(
+            "{:06}",
+            OtpPolicy::new(realm.id)
+                .credential_data()
+                .generate(b"token-seed-token-see", unix_now() / 30)
+        );
+
+        let session = AuthSession::new(AuthSessionParams {
+            realm_id: realm.id,
+            client_id: Uuid::new_v4(),
+            redirect_uri: "https://app.example.com/callback".to_string(),
+            response_type: "code".to_string(),
+            scope: "openid".to_string(),
+            state: Some("state".to_string()),
+            nonce: None,
+            user_id: Some(user.id),
+            code: None,
+            authenticated: false,
+            webauthn_challenge: None,
+            webauthn_challenge_issued_at: None,
+            compass_flow_id: None,
+        });
+
+        Arc::get_mut(&mut builder.auth_session_repo)
+            .unwrap()
+            .expect_get_by_session_code()
+            .returning(move |_| {
+                let s = session.clone();
+                Box::pin(async move { Ok(s) })
+            });
+
+        let credentials = vec![phone, token.clone()];
+        Arc::get_mut(&mut builder.credential_repo)
+            .unwrap()
+            .expect_get_credentials_by_user_id()
+            .returning(move |_| {
+                let c = credentials.clone();
+                Box::pin(async move { Ok(c) })
+            });
+
+        Arc::get_mut(&mut builder.otp_policy_repo)
+            .unwrap()
+            .expect_get_policy()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        // The code comes from the second device; another sign-in stored it
+        // first.
+        let token_id = token.id;
+        Arc::get_mut(&mut builder.credential_repo)
+            .unwrap()
+            .expect_update_otp_credential_data()
+            .withf(move |id, _, data| *id == token_id && data.last_used_step.is_some())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(false) }));
+
+        let service = builder.build();
+        let result = service
+            .challenge_otp(
+                Identity::User(user),
+                ChallengeOtpInput {
+                    session_code: Uuid::new_v4().to_string(),
+                    code,
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::TotpVerificationFailed(_))));
+    }
 }
@@ -0,0 +1,202 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    common::{generate_random_token, generate_uuid_v7},
+    realm::entities::RealmId,
+};
+
+/// Longest trust window a realm can configure.
+pub const MAX_TRUSTED_DEVICE_DAYS: u32 = 365;
+
+/// A browser the user asked not to be asked a second factor on again. The
+/// browser holds the device id and a secret; only the secret's hash is
+/// stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct TrustedDevice {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub user_id: Uuid,
+    /// Browser the device was trusted from. Other browsers presenting the
+    /// cookie are not trusted.
+    pub user_agent: Option<String>,
+    /// Address the device was trusted from.
+    pub ip_address: Option<String>,
+    #[serde(skip)]
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl TrustedDevice {
+    /// A new trusted device, along with the token to hand to the browser.
+    pub fn issue(
+        realm_id: RealmId,
+        user_id: Uuid,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+        days: u32,
+        now: DateTime<Utc>,
+    ) -> (Self, TrustedDeviceToken) {
+        let token = TrustedDeviceToken {
+            device_id: generate_uuid_v7(),
+            secret: generate_random_token(),
+        };
+
+        (
+            Self {
+                id: token.device_id,
+                realm_id,
+                user_id,
+                user_agent,
+                ip_address,
+                token_hash: hash_secret(token.device_id, &token.secret),
+                created_at: now,
+                last_used_at: None,
+                expires_at: now + TimeDelta::days(days.into()),
+            },
+            token,
+        )
+    }
+
+    /// Whether the device still skips the second factor of `user_id` when
+    /// it presents `token` from `user_agent`. The realm's current window
+    /// applies too, so shortening it takes effect on devices already
+    /// trusted.
+    pub fn accepts(
+        &self,
+        token: &TrustedDeviceToken,
+        user_id: Uuid,
+        user_agent: Option<&str>,
+        days: u32,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let window_end = self.created_at + TimeDelta::days(days.into());
+
+        self.id == token.device_id
+            && self.user_id == user_id
+            && self.user_agent.as_deref() == user_agent
+            && days > 0
+            && now < self.expires_at.min(window_end)
+            && bool::from(
+                hash_secret(self.id, &token.secret)
+                    .as_bytes()
+                    .ct_eq(self.token_hash.as_bytes()),
+            )
+    }
+}
+
+/// What the browser keeps in its trusted device cookie.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedDeviceToken {
+    pub device_id: Uuid,
+    pub secret: String,
+}
+
+impl TrustedDeviceToken {
+    pub fn parse(value: &str) -> Option<Self> {
+        let (device_id, secret) = value.split_once('.')?;
+        if secret.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            device_id: device_id.parse().ok()?,
+            secret: secret.to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for TrustedDeviceToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.device_id, self.secret)
+    }
+}
+
+/// A device just trusted, with the token its cookie carries.
+#[derive(Debug, Clone)]
+pub struct IssuedTrustedDevice {
+    pub device: TrustedDevice,
+    pub token: TrustedDeviceToken,
+}
+
+/// Salted with the device id, like one-time codes.
+fn hash_secret(device_id: Uuid, secret: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(device_id.as_bytes());
+    hasher.update(secret.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIREFOX: &str = "Mozilla/5.0 Firefox/130.0";
+
+    fn issue(now: DateTime<Utc>) -> (TrustedDevice, TrustedDeviceToken, Uuid) {
+        let user_id = Uuid::new_v4();
+        let (device, token) = TrustedDevice::issue(
+            RealmId::new(Uuid::new_v4()),
+            user_id,
+            Some(FIREFOX.to_string()),
+            None,
+            30,
+            now,
+        );
+
+        (device, token, user_id)
+    }
+
+    #[test]
+    fn token_round_trips_through_the_cookie_value() {
+        let (_, token, _) = issue(Utc::now());
+
+        assert_eq!(TrustedDeviceToken::parse(&token.to_string()), Some(token));
+        assert_eq!(TrustedDeviceToken::parse("not-a-token"), None);
+        assert_eq!(
+            TrustedDeviceToken::parse(&format!("{}.", Uuid::new_v4())),
+            None
+        );
+    }
+
+    #[test]
+    fn device_is_bound_to_its_user_browser_and_secret() {
+        let now = Utc::now();
+        let (device, token, user_id) = issue(now);
+
+        assert!(device.accepts(&token, user_id, Some(FIREFOX), 30, now));
+        assert!(!device.accepts(&token, Uuid::new_v4(), Some(FIREFOX), 30, now));
+        assert!(!device.accepts(&token, user_id, Some("curl/8.0"), 30, now));
+        assert!(!device.accepts(&token, user_id, None, 30, now));
+
+        let forged = TrustedDeviceToken {
+            secret: "guess".to_string(),
+            ..token
+        };
+        assert!(!device.accepts(&forged, user_id, Some(FIREFOX), 30, now));
+    }
+
+    #[test]
+    fn device_expires_with_the_realm_window() {
+        let now = Utc::now();
+        let (device, token, user_id) = issue(now);
+        let later = now + TimeDelta::days(10);
+
+        assert!(device.accepts(&token, user_id, Some(FIREFOX), 30, later));
+        assert!(!device.accepts(&token, user_id, Some(FIREFOX), 7, later));
+        assert!(!device.accepts(&token, user_id, Some(FIREFOX), 0, now));
+        assert!(!device.accepts(
+            &token,
+            user_id,
+            Some(FIREFOX),
+            365,
+            now + TimeDelta::days(31)
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity, common::entities::app_errors::CoreError,
+};
+
+use super::{
+    entities::{IssuedTrustedDevice, TrustedDevice},
+    value_objects::{ListTrustedDevicesInput, RevokeTrustedDeviceInput, TrustDeviceInput},
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait TrustedDeviceRepository: Send + Sync {
+    fn create(&self, device: TrustedDevice) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn get_by_id(
+        &self,
+        id: Uuid,
+    ) -> impl Future<Output = Result<Option<TrustedDevice>, CoreError>> + Send;
+    /// Devices of the user not expired yet, most recently trusted first.
+    fn list_by_user(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<TrustedDevice>, CoreError>> + Send;
+    fn touch(
+        &self,
+        id: Uuid,
+        used_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Returns whether the user had this device.
+    fn delete(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+/// Browsers users trusted during a sign-in, which skip the second factor
+/// until the realm's window runs out.
+pub trait TrustedDeviceService: Send + Sync {
+    /// Trusts the browser the user just passed a second factor from.
+    /// Returns `None` when the realm doesn't let users trust devices.
+    fn trust_device(
+        &self,
+        input: TrustDeviceInput,
+    ) -> impl Future<Output = Result<Option<IssuedTrustedDevice>, CoreError>> + Send;
+    fn list_trusted_devices(
+        &self,
+        identity: Identity,
+        input: ListTrustedDevicesInput,
+    ) -> impl Future<Output = Result<Vec<TrustedDevice>, CoreError>> + Send;
+    fn revoke_trusted_device(
+        &self,
+        identity: Identity,
+        input: RevokeTrustedDeviceInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
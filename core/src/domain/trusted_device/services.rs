@@ -0,0 +1,539 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::ports::ClientRepository,
+    common::{
+        entities::app_errors::CoreError,
+        policies::{FerriskeyPolicy, ensure_policy},
+    },
+    realm::{entities::RealmId, ports::RealmRepository},
+    seawatch::{
+        entities::{ActorType, EventStatus, SecurityEvent, SecurityEventType},
+        ports::SecurityEventRepository,
+    },
+    trusted_device::{
+        entities::{
+            IssuedTrustedDevice, MAX_TRUSTED_DEVICE_DAYS, TrustedDevice, TrustedDeviceToken,
+        },
+        ports::{TrustedDeviceRepository, TrustedDeviceService},
+        value_objects::{ListTrustedDevicesInput, RevokeTrustedDeviceInput, TrustDeviceInput},
+    },
+    user::{
+        entities::User,
+        ports::{UserPolicy, UserRepository, UserRoleRepository},
+    },
+};
+
+/// Whether the browser presenting `token` is a device `user_id` trusted,
+/// so their sign-in skips the second factor. Tokens that don't parse, or
+/// belong to another user or browser, are not trusted.
+pub(crate) async fn trusted_device_accepted<R, TD>(
+    realm_repository: &R,
+    trusted_device_repository: &TD,
+    realm_id: RealmId,
+    user_id: Uuid,
+    token: Option<&str>,
+    user_agent: Option<&str>,
+) -> Result<bool, CoreError>
+where
+    R: RealmRepository,
+    TD: TrustedDeviceRepository,
+{
+    let Some(token) = token.and_then(TrustedDeviceToken::parse) else {
+        return Ok(false);
+    };
+
+    let Some(device) = trusted_device_repository.get_by_id(token.device_id).await? else {
+        return Ok(false);
+    };
+
+    let days = realm_repository
+        .get_realm_settings(realm_id)
+        .await?
+        .map(|settings| settings.trusted_device_days.min(MAX_TRUSTED_DEVICE_DAYS))
+        .unwrap_or_default();
+
+    let now = Utc::now();
+    if !device.accepts(&token, user_id, user_agent, days, now) {
+        return Ok(false);
+    }
+
+    trusted_device_repository.touch(device.id, now).await?;
+
+    Ok(true)
+}
+
+#[derive(Clone, Debug)]
+pub struct TrustedDeviceServiceImpl<R, U, C, UR, TD, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    TD: TrustedDeviceRepository,
+    SE: SecurityEventRepository,
+{
+    pub(crate) realm_repository: Arc<R>,
+    pub(crate) user_repository: Arc<U>,
+    pub(crate) trusted_device_repository: Arc<TD>,
+    pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
+}
+
+impl<R, U, C, UR, TD, SE> TrustedDeviceServiceImpl<R, U, C, UR, TD, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    TD: TrustedDeviceRepository,
+    SE: SecurityEventRepository,
+{
+    pub fn new(
+        realm_repository: Arc<R>,
+        user_repository: Arc<U>,
+        trusted_device_repository: Arc<TD>,
+        security_event_repository: Arc<SE>,
+        policy: Arc<FerriskeyPolicy<U, C, UR>>,
+    ) -> Self {
+        Self {
+            realm_repository,
+            user_repository,
+            trusted_device_repository,
+            security_event_repository,
+            policy,
+        }
+    }
+
+    /// The user the request is about. Without a `user_id`, or with their
+    /// own, users act on their devices; anyone else needs the permission to
+    /// view (or, with `manage`, update) users of the realm.
+    async fn resolve_subject(
+        &self,
+        identity: &Identity,
+        realm_name: &str,
+        user_id: Option<Uuid>,
+        manage: bool,
+    ) -> Result<User, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let caller = identity
+            .as_user()
+            .filter(|user| identity.is_regular_user() && user.realm_id == realm.id)
+            .map(|user| user.id);
+
+        let user_id = match (user_id, caller) {
+            (None, Some(caller)) => caller,
+            (Some(user_id), Some(caller)) if user_id == caller => caller,
+            (None, None) => {
+                return Err(CoreError::Forbidden(
+                    "only users of this realm have an account here".into(),
+                ));
+            }
+            (Some(user_id), _) => {
+                let allowed = if manage {
+                    self.policy.can_update_user(identity, &realm).await
+                } else {
+                    self.policy.can_view_user(identity, &realm).await
+                };
+                ensure_policy(allowed, "insufficient permissions")?;
+                user_id
+            }
+        };
+
+        let user = self.user_repository.get_by_id(user_id).await?;
+        if user.realm_id != realm.id {
+            return Err(CoreError::UserNotFound);
+        }
+
+        Ok(user)
+    }
+
+    /// Audit is best-effort: a failure to record must not fail the request.
+    async fn record(
+        &self,
+        actor: Option<&Identity>,
+        user: &User,
+        device_id: Uuid,
+        event_type: SecurityEventType,
+    ) {
+        let (actor_id, actor_type) = match actor {
+            Some(identity) if identity.is_service_account() => {
+                (identity.id(), ActorType::ServiceAccount)
+            }
+            Some(identity) if identity.id() != user.id => (identity.id(), ActorType::Admin),
+            _ => (user.id, ActorType::User),
+        };
+
+        let event = SecurityEvent::new(user.realm_id, event_type, EventStatus::Success, actor_id)
+            .with_actor(actor_id, actor_type)
+            .with_target("trusted_device".to_string(), device_id, None);
+
+        if let Err(e) = self.security_event_repository.store_event(event).await {
+            warn!("Failed to store trusted device security event: {}", e);
+        }
+    }
+}
+
+impl<R, U, C, UR, TD, SE> TrustedDeviceService for TrustedDeviceServiceImpl<R, U, C, UR, TD, SE>
+where
+    R: RealmRepository,
+    U: UserRepository,
+    C: ClientRepository,
+    UR: UserRoleRepository,
+    TD: TrustedDeviceRepository,
+    SE: SecurityEventRepository,
+{
+    async fn trust_device(
+        &self,
+        input: TrustDeviceInput,
+    ) -> Result<Option<IssuedTrustedDevice>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let days = self
+            .realm_repository
+            .get_realm_settings(realm.id)
+            .await?
+            .map(|settings| settings.trusted_device_days.min(MAX_TRUSTED_DEVICE_DAYS))
+            .unwrap_or_default();
+
+        if days == 0 {
+            return Ok(None);
+        }
+
+        let user = self.user_repository.get_by_id(input.user_id).await?;
+        if user.realm_id != realm.id {
+            return Err(CoreError::UserNotFound);
+        }
+
+        let (device, token) = TrustedDevice::issue(
+            realm.id,
+            user.id,
+            input.user_agent,
+            input.ip_address,
+            days,
+            Utc::now(),
+        );
+        self.trusted_device_repository
+            .create(device.clone())
+            .await?;
+
+        self.record(None, &user, device.id, SecurityEventType::DeviceTrusted)
+            .await;
+
+        Ok(Some(IssuedTrustedDevice { device, token }))
+    }
+
+    async fn list_trusted_devices(
+        &self,
+        identity: Identity,
+        input: ListTrustedDevicesInput,
+    ) -> Result<Vec<TrustedDevice>, CoreError> {
+        let user = self
+            .resolve_subject(&identity, &input.realm_name, input.user_id, false)
+            .await?;
+
+        self.trusted_device_repository
+            .list_by_user(user.id, Utc::now())
+            .await
+    }
+
+    async fn revoke_trusted_device(
+        &self,
+        identity: Identity,
+        input: RevokeTrustedDeviceInput,
+    ) -> Result<(), CoreError> {
+        let user = self
+            .resolve_subject(&identity, &input.realm_name, input.user_id, true)
+            .await?;
+
+        if !self
+            .trusted_device_repository
+            .delete(user.id, input.device_id)
+            .await?
+        {
+            return Err(CoreError::NotFound);
+        }
+
+        self.record(
+            Some(&identity),
+            &user,
+            input.device_id,
+            SecurityEventType::TrustedDeviceRevoked,
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::domain::{
+        client::ports::MockClientRepository,
+        common::services::tests::{
+            create_test_realm_with_name, create_test_user_with_params_and_realm,
+        },
+        realm::{
+            entities::{Realm, RealmSetting},
+            ports::MockRealmRepository,
+        },
+        seawatch::ports::MockSecurityEventRepository,
+        trusted_device::ports::MockTrustedDeviceRepository,
+        user::ports::{MockUserRepository, MockUserRoleRepository},
+    };
+
+    type TestTrustedDeviceService = TrustedDeviceServiceImpl<
+        MockRealmRepository,
+        MockUserRepository,
+        MockClientRepository,
+        MockUserRoleRepository,
+        MockTrustedDeviceRepository,
+        MockSecurityEventRepository,
+    >;
+
+    struct Mocks {
+        realm: MockRealmRepository,
+        user: MockUserRepository,
+        trusted_device: MockTrustedDeviceRepository,
+        security_event: MockSecurityEventRepository,
+    }
+
+    /// Mocks resolving `realm` by name, with devices trusted for `days`,
+    /// and `user` by id.
+    fn mocks_for(realm: &Realm, user: &User, days: u32) -> Mocks {
+        let mut realm_repo = MockRealmRepository::new();
+        let r = realm.clone();
+        realm_repo
+            .expect_get_by_name()
+            .returning(move |_| Box::pin(std::future::ready(Ok(Some(r.clone())))));
+        let mut settings = RealmSetting::new(realm.id, None);
+        settings.trusted_device_days = days;
+        realm_repo
+            .expect_get_realm_settings()
+            .returning(move |_| Box::pin(std::future::ready(Ok(Some(settings.clone())))));
+        let mut user_repo = MockUserRepository::new();
+        let u = user.clone();
+        user_repo
+            .expect_get_by_id()
+            .returning(move |_| Box::pin(std::future::ready(Ok(u.clone()))));
+        let mut security_event = MockSecurityEventRepository::new();
+        security_event
+            .expect_store_event()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        Mocks {
+            realm: realm_repo,
+            user: user_repo,
+            trusted_device: MockTrustedDeviceRepository::new(),
+            security_event,
+        }
+    }
+
+    fn build(mocks: Mocks) -> TestTrustedDeviceService {
+        let user = Arc::new(mocks.user);
+
+        TrustedDeviceServiceImpl::new(
+            Arc::new(mocks.realm),
+            user.clone(),
+            Arc::new(mocks.trusted_device),
+            Arc::new(mocks.security_event),
+            Arc::new(FerriskeyPolicy::new(
+                user,
+                Arc::new(MockClientRepository::new()),
+                Arc::new(MockUserRoleRepository::new()),
+            )),
+        )
+    }
+
+    fn setup() -> (Realm, User) {
+        let realm = create_test_realm_with_name("devices");
+        let user = create_test_user_with_params_and_realm(
+            &realm,
+            "jane",
+            "jane@example.com".to_string(),
+            true,
+        );
+        (realm, user)
+    }
+
+    fn trust_input(realm: &Realm, user: &User) -> TrustDeviceInput {
+        TrustDeviceInput {
+            realm_name: realm.name.clone(),
+            user_id: user.id,
+            user_agent: Some("Mozilla/5.0 Firefox/130.0".to_string()),
+            ip_address: Some("203.0.113.7".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn devices_are_trusted_for_the_realm_window() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user, 30);
+        mocks
+            .trusted_device
+            .expect_create()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let service = build(mocks);
+
+        let issued = service
+            .trust_device(trust_input(&realm, &user))
+            .await
+            .unwrap()
+            .expect("the realm trusts devices");
+
+        assert_eq!(issued.token.device_id, issued.device.id);
+        assert_eq!(
+            issued.device.expires_at - issued.device.created_at,
+            chrono::TimeDelta::days(30)
+        );
+        assert!(issued.device.accepts(
+            &issued.token,
+            user.id,
+            Some("Mozilla/5.0 Firefox/130.0"),
+            30,
+            Utc::now()
+        ));
+    }
+
+    #[tokio::test]
+    async fn realms_without_a_window_do_not_trust_devices() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user, 0);
+        mocks.trusted_device.expect_create().never();
+        let service = build(mocks);
+
+        let issued = service
+            .trust_device(trust_input(&realm, &user))
+            .await
+            .unwrap();
+
+        assert!(issued.is_none());
+    }
+
+    #[tokio::test]
+    async fn users_revoke_their_own_devices() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user, 30);
+        let user_id = user.id;
+        mocks
+            .trusted_device
+            .expect_delete()
+            .withf(move |owner, _| *owner == user_id)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+        let service = build(mocks);
+
+        service
+            .revoke_trusted_device(
+                Identity::User(user.clone()),
+                RevokeTrustedDeviceInput {
+                    realm_name: realm.name.clone(),
+                    user_id: None,
+                    device_id: Uuid::new_v4(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn revoking_an_unknown_device_is_not_found() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user, 30);
+        mocks
+            .trusted_device
+            .expect_delete()
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+        let service = build(mocks);
+
+        let result = service
+            .revoke_trusted_device(
+                Identity::User(user),
+                RevokeTrustedDeviceInput {
+                    realm_name: realm.name.clone(),
+                    user_id: None,
+                    device_id: Uuid::new_v4(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn trusted_devices_are_accepted_from_the_same_browser() {
+        let (realm, user) = setup();
+        let mut mocks = mocks_for(&realm, &user, 30);
+        let (device, token) = TrustedDevice::issue(
+            realm.id,
+            user.id,
+            Some("Mozilla/5.0 Firefox/130.0".to_string()),
+            None,
+            30,
+            Utc::now(),
+        );
+        mocks
+            .trusted_device
+            .expect_get_by_id()
+            .returning(move |_| Box::pin(std::future::ready(Ok(Some(device.clone())))));
+        mocks
+            .trusted_device
+            .expect_touch()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        let token = token.to_string();
+
+        let accepted = trusted_device_accepted(
+            &mocks.realm,
+            &mocks.trusted_device,
+            realm.id,
+            user.id,
+            Some(&token),
+            Some("Mozilla/5.0 Firefox/130.0"),
+        )
+        .await
+        .unwrap();
+        assert!(accepted);
+
+        let other_browser = trusted_device_accepted(
+            &mocks.realm,
+            &mocks.trusted_device,
+            realm.id,
+            user.id,
+            Some(&token),
+            Some("curl/8.0"),
+        )
+        .await
+        .unwrap();
+        assert!(!other_browser);
+
+        let garbage = trusted_device_accepted(
+            &mocks.realm,
+            &mocks.trusted_device,
+            realm.id,
+            user.id,
+            Some("not-a-token"),
+            Some("Mozilla/5.0 Firefox/130.0"),
+        )
+        .await
+        .unwrap();
+        assert!(!garbage);
+    }
+}
@@ -0,0 +1,24 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct TrustDeviceInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListTrustedDevicesInput {
+    pub realm_name: String,
+    /// `None` targets the account of the caller.
+    pub user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RevokeTrustedDeviceInput {
+    pub realm_name: String,
+    /// `None` targets the account of the caller.
+    pub user_id: Option<Uuid>,
+    pub device_id: Uuid,
+}
@@ -7,17 +7,17 @@ use crate::domain::role::entities::permission::Permissions;
 use crate::domain::user::entities::GetUserPermissionsInput;
 use crate::domain::{
     authentication::value_objects::Identity,
-    common::entities::app_errors::CoreError,
+    common::entities::{app_errors::CoreError, listing::Page},
     realm::entities::Realm,
     role::entities::Role,
     user::{
         entities::{
             AssignRoleInput, BulkDeleteUsersInput, CreateUserInput, DeleteUserAttributeInput,
-            GetUserAttributesInput, GetUserInput, RequiredAction, RequiredActionError,
-            ResetPasswordInput, SetUserAttributesInput, UnassignRoleInput, UpdateUserInput, User,
-            UserAttribute,
+            GetUserAttributesInput, GetUserInput, GetUsersInput, RequiredAction,
+            RequiredActionError, ResetPasswordInput, SetUserAttributesInput, UnassignRoleInput,
+            UpdateUserInput, User, UserAttribute,
         },
-        value_objects::{CreateUserRequest, UpdateUserRequest},
+        value_objects::{CreateUserRequest, UpdateUserRequest, UserFilter},
     },
 };
 
@@ -42,8 +42,13 @@ pub trait UserService: Send + Sync {
     fn get_users(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> impl Future<Output = Result<Vec<User>, CoreError>> + Send;
+        input: GetUsersInput,
+    ) -> impl Future<Output = Result<Page<User>, CoreError>> + Send;
+    fn count_users(
+        &self,
+        identity: Identity,
+        input: GetUsersInput,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
     fn assign_role(
         &self,
         identity: Identity,
@@ -119,6 +124,20 @@ pub trait UserRepository: Send + Sync {
         realm_id: RealmId,
     ) -> impl Future<Output = Result<Vec<User>, CoreError>> + Send;
 
+    /// Up to `filter.page.limit() + 1` users, the extra one telling whether
+    /// there is a next page.
+    fn list_users(
+        &self,
+        realm_id: RealmId,
+        filter: UserFilter,
+    ) -> impl Future<Output = Result<Vec<User>, CoreError>> + Send;
+
+    fn count_users(
+        &self,
+        realm_id: RealmId,
+        filter: UserFilter,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
     fn get_by_email(
         &self,
         email: &str,
@@ -7,7 +7,7 @@ use crate::domain::{
     authentication::value_objects::Identity,
     client::ports::ClientRepository,
     common::{
-        entities::app_errors::CoreError,
+        entities::{app_errors::CoreError, listing::Page},
         policies::{FerriskeyPolicy, Policy, ensure_policy},
     },
     credential::ports::CredentialRepository,
@@ -15,11 +15,13 @@ use crate::domain::{
     realm::ports::RealmRepository,
     role::{entities::permission::Permissions, ports::RoleRepository},
     seawatch::{EventStatus, SecurityEvent, SecurityEventRepository, SecurityEventType},
+    security_notification::{entities::SecurityNotification, ports::SecurityNotifier},
     user::{
         entities::{
             AssignRoleInput, CreateUserInput, DeleteUserAttributeInput, GetUserAttributesInput,
-            GetUserInput, GetUserPermissionsInput, RequiredAction, ResetPasswordInput,
-            SetUserAttributesInput, UnassignRoleInput, UpdateUserInput, User, UserAttribute,
+            GetUserInput, GetUserPermissionsInput, GetUsersInput, RequiredAction,
+            ResetPasswordInput, SetUserAttributesInput, UnassignRoleInput, UpdateUserInput, User,
+            UserAttribute,
         },
         ports::{
             UserAttributeRepository, UserPolicy, UserRepository, UserRequiredActionRepository,
@@ -48,7 +50,7 @@ fn normalize_optional_email(email: Option<String>) -> Option<String> {
 }
 
 #[derive(Clone, Debug)]
-pub struct UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR>
+pub struct UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, N>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -61,6 +63,7 @@ where
     W: WebhookRepository,
     SE: SecurityEventRepository,
     UAR: UserAttributeRepository,
+    N: SecurityNotifier,
 {
     pub(crate) realm_repository: Arc<R>,
     pub(crate) user_repository: Arc<U>,
@@ -72,12 +75,13 @@ where
     pub(crate) user_attribute_repository: Arc<UAR>,
     pub(crate) webhook_repository: Arc<W>,
     pub(crate) security_event_repository: Arc<SE>,
+    pub(crate) security_notifier: Arc<N>,
 
     pub(crate) policy: Arc<FerriskeyPolicy<U, C, UR>>,
 }
 
-impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR>
-    UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR>
+impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, N>
+    UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, N>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -90,6 +94,7 @@ where
     W: WebhookRepository,
     SE: SecurityEventRepository,
     UAR: UserAttributeRepository,
+    N: SecurityNotifier,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -103,6 +108,7 @@ where
         user_attribute_repository: Arc<UAR>,
         webhook_repository: Arc<W>,
         security_event_repository: Arc<SE>,
+        security_notifier: Arc<N>,
         policy: Arc<FerriskeyPolicy<U, C, UR>>,
     ) -> Self {
         Self {
@@ -116,13 +122,14 @@ where
             user_attribute_repository,
             webhook_repository,
             security_event_repository,
+            security_notifier,
             policy,
         }
     }
 }
 
-impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR> UserService
-    for UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR>
+impl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, N> UserService
+    for UserServiceImpl<R, U, C, UR, CR, H, RO, URA, W, SE, UAR, N>
 where
     R: RealmRepository,
     U: UserRepository,
@@ -135,6 +142,7 @@ where
     W: WebhookRepository,
     SE: SecurityEventRepository,
     UAR: UserAttributeRepository,
+    N: SecurityNotifier,
 {
     async fn delete_user(
         &self,
@@ -243,6 +251,14 @@ where
             )
             .await?;
 
+        self.security_notifier
+            .notify(
+                realm.id,
+                input.user_id,
+                SecurityNotification::PasswordChanged,
+            )
+            .await;
+
         // @TODO: webhook call action
 
         Ok(())
@@ -265,6 +281,8 @@ where
             "You are not allowed to view users in this realm.",
         )?;
 
+        let previous = self.user_repository.get_by_id(input.user_id).await?;
+
         let user = self
             .user_repository
             .update_user(
@@ -307,21 +325,66 @@ where
             )
             .await?;
 
+        if let (Some(old_email), Some(new_email)) = (&previous.email, &user.email)
+            && !old_email.eq_ignore_ascii_case(new_email)
+        {
+            self.security_notifier
+                .notify(
+                    realm_id,
+                    user.id,
+                    SecurityNotification::EmailChanged {
+                        old_email: old_email.clone(),
+                        new_email: new_email.clone(),
+                    },
+                )
+                .await;
+        }
+
+        if previous.enabled && !user.enabled {
+            self.security_notifier
+                .notify(realm_id, user.id, SecurityNotification::AccountLocked)
+                .await;
+        }
+
         Ok(user)
     }
 
     async fn get_users(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> Result<Vec<User>, CoreError> {
+        input: GetUsersInput,
+    ) -> Result<Page<User>, CoreError> {
         let realm = self
             .realm_repository
-            .get_by_name(&realm_name)
+            .get_by_name(&input.realm_name)
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
-        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_view_user(&identity, &realm).await,
+            "You are not allowed to view users in this realm.",
+        )?;
+
+        let limit = input.filter.page.limit();
+        let sort = input.filter.sort;
+        let users = self
+            .user_repository
+            .list_users(realm.id, input.filter)
+            .await?;
+
+        Ok(Page::from_overfetch(users, limit, |user| sort.cursor(user)))
+    }
+
+    async fn count_users(
+        &self,
+        identity: Identity,
+        input: GetUsersInput,
+    ) -> Result<u64, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(&input.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
 
         ensure_policy(
             self.policy.can_view_user(&identity, &realm).await,
@@ -329,9 +392,8 @@ where
         )?;
 
         self.user_repository
-            .find_by_realm_id(realm_id)
+            .count_users(realm.id, input.filter)
             .await
-            .map_err(|_| CoreError::InternalServerError)
     }
 
     async fn assign_role(
@@ -699,6 +761,7 @@ mod tests {
     use super::*;
     use crate::domain::{
         client::ports::MockClientRepository,
+        common::entities::listing::{ListCursor, PageRequest},
         common::services::tests::{
             create_test_realm_with_name, create_test_user_identity_with_realm,
             create_test_user_with_params_and_realm,
@@ -708,10 +771,14 @@ mod tests {
         realm::{entities::Realm, ports::MockRealmRepository},
         role::ports::MockRoleRepository,
         seawatch::ports::MockSecurityEventRepository,
+        security_notification::{
+            ports::MockSecurityNotifier, services::tests::permissive_notifier,
+        },
         user::ports::{
             MockUserAttributeRepository, MockUserRepository, MockUserRequiredActionRepository,
             MockUserRoleRepository,
         },
+        user::value_objects::UserFilter,
         webhook::{entities::webhook_payload::WebhookPayload, ports::MockWebhookRepository},
     };
 
@@ -727,6 +794,7 @@ mod tests {
         webhook_repo: Arc<MockWebhookRepository>,
         client_repo: Arc<MockClientRepository>,
         security_event_repo: Arc<MockSecurityEventRepository>,
+        security_notifier: Arc<MockSecurityNotifier>,
     }
 
     impl UserServiceTestBuilder {
@@ -743,6 +811,7 @@ mod tests {
                 webhook_repo: Arc::new(MockWebhookRepository::new()),
                 client_repo: Arc::new(MockClientRepository::new()),
                 security_event_repo: Arc::new(MockSecurityEventRepository::new()),
+                security_notifier: Arc::new(permissive_notifier()),
             }
         }
 
@@ -793,6 +862,31 @@ mod tests {
             self
         }
 
+        fn with_existing_user(mut self, user: User) -> Self {
+            Arc::get_mut(&mut self.user_repo)
+                .unwrap()
+                .expect_get_by_id()
+                .with(mockall::predicate::eq(user.id))
+                .times(1)
+                .return_once(move |_| Box::pin(async move { Ok(user) }));
+            self
+        }
+
+        fn with_notification(mut self, notification: SecurityNotification) -> Self {
+            let mut notifier = MockSecurityNotifier::new();
+            notifier
+                .expect_notify()
+                .with(
+                    mockall::predicate::always(),
+                    mockall::predicate::always(),
+                    mockall::predicate::eq(notification),
+                )
+                .times(1)
+                .returning(|_, _, _| Box::pin(async {}));
+            self.security_notifier = Arc::new(notifier);
+            self
+        }
+
         fn with_update_user_success(mut self, user_id: uuid::Uuid, updated_user: User) -> Self {
             Arc::get_mut(&mut self.user_repo)
                 .unwrap()
@@ -821,6 +915,15 @@ mod tests {
             self
         }
 
+        fn with_list_users(mut self, users: Vec<User>) -> Self {
+            Arc::get_mut(&mut self.user_repo)
+                .unwrap()
+                .expect_list_users()
+                .times(1)
+                .return_once(move |_, _| Box::pin(async move { Ok(users) }));
+            self
+        }
+
         fn with_webhook_notify(mut self) -> Self {
             Arc::get_mut(&mut self.webhook_repo)
                 .unwrap()
@@ -844,6 +947,7 @@ mod tests {
             MockWebhookRepository,
             MockSecurityEventRepository,
             MockUserAttributeRepository,
+            MockSecurityNotifier,
         > {
             use crate::domain::common::policies::FerriskeyPolicy;
 
@@ -864,6 +968,7 @@ mod tests {
                 self.user_attribute_repo,
                 self.webhook_repo,
                 self.security_event_repo,
+                self.security_notifier,
                 Arc::new(policy),
             )
         }
@@ -981,6 +1086,7 @@ mod tests {
         let service = UserServiceTestBuilder::new()
             .with_realm("test-realm".to_string(), realm.clone())
             .with_user_permissions(user_id, vec![admin_role])
+            .with_existing_user(user_to_update.clone())
             .with_update_user_email_exists(user_to_update.id)
             .build();
 
@@ -1020,12 +1126,14 @@ mod tests {
         );
 
         let update_user_id = user_to_update.id;
+        let previous_user = user_to_update.clone();
         user_to_update.firstname = Some("Updated".to_string());
 
         // Keeping own email doesn't violate the constraint
         let service = UserServiceTestBuilder::new()
             .with_realm("test-realm".to_string(), realm.clone())
             .with_user_permissions(user_id, vec![admin_role])
+            .with_existing_user(previous_user)
             .with_update_user_success(update_user_id, user_to_update.clone())
             .with_webhook_notify()
             .build();
@@ -1068,11 +1176,17 @@ mod tests {
         );
 
         let update_user_id = user_to_update.id;
+        let previous_user = user_to_update.clone();
         user_to_update.email = Some("newemail@example.com".to_string());
 
         let service = UserServiceTestBuilder::new()
             .with_realm("test-realm".to_string(), realm.clone())
             .with_user_permissions(user_id, vec![admin_role])
+            .with_existing_user(previous_user)
+            .with_notification(SecurityNotification::EmailChanged {
+                old_email: "old@example.com".to_string(),
+                new_email: "newemail@example.com".to_string(),
+            })
             .with_update_user_success(update_user_id, user_to_update.clone())
             .with_webhook_notify()
             .build();
@@ -1094,4 +1208,103 @@ mod tests {
         let updated_user = result.unwrap();
         assert_eq!(updated_user.email, Some("newemail@example.com".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_update_user_disabling_account_notifies_lock() {
+        let realm = create_test_realm_with_name("test-realm");
+        let identity = create_test_user_identity_with_realm(&realm);
+        let admin_role = create_admin_role(&realm);
+
+        let user_id = match &identity {
+            Identity::User(u) => u.id,
+            _ => panic!("Expected user identity"),
+        };
+
+        let mut user_to_update = create_test_user_with_params_and_realm(
+            &realm,
+            "user_to_update",
+            "locked@example.com".to_string(),
+            true,
+        );
+
+        let update_user_id = user_to_update.id;
+        let previous_user = user_to_update.clone();
+        user_to_update.enabled = false;
+
+        let service = UserServiceTestBuilder::new()
+            .with_realm("test-realm".to_string(), realm.clone())
+            .with_user_permissions(user_id, vec![admin_role])
+            .with_existing_user(previous_user)
+            .with_update_user_success(update_user_id, user_to_update)
+            .with_webhook_notify()
+            .with_notification(SecurityNotification::AccountLocked)
+            .build();
+
+        let input = UpdateUserInput {
+            realm_name: "test-realm".to_string(),
+            user_id: update_user_id,
+            firstname: None,
+            lastname: None,
+            email: Some("locked@example.com".to_string()),
+            email_verified: Some(true),
+            enabled: false,
+            required_actions: None,
+        };
+
+        let result = service.update_user(identity, input).await;
+
+        assert!(result.is_ok());
+        assert!(!result.unwrap().enabled);
+    }
+
+    #[tokio::test]
+    async fn test_get_users_returns_cursor_when_more_users_exist() {
+        let realm = create_test_realm_with_name("test-realm");
+        let identity = create_test_user_identity_with_realm(&realm);
+        let mut admin_role = create_admin_role(&realm);
+        admin_role
+            .permissions
+            .push(crate::domain::role::entities::permission::Permissions::ViewUsers.name());
+
+        let user_id = match &identity {
+            Identity::User(u) => u.id,
+            _ => panic!("Expected user identity"),
+        };
+
+        let users: Vec<User> = ["alice", "bob", "carol"]
+            .into_iter()
+            .map(|name| {
+                create_test_user_with_params_and_realm(
+                    &realm,
+                    name,
+                    format!("{name}@example.com"),
+                    true,
+                )
+            })
+            .collect();
+
+        let service = UserServiceTestBuilder::new()
+            .with_realm("test-realm".to_string(), realm.clone())
+            .with_user_permissions(user_id, vec![admin_role])
+            .with_list_users(users.clone())
+            .build();
+
+        let input = GetUsersInput {
+            realm_name: "test-realm".to_string(),
+            filter: UserFilter {
+                page: PageRequest {
+                    limit: Some(2),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        };
+
+        let page = service.get_users(identity, input).await.unwrap();
+
+        assert_eq!(page.data, users[..2]);
+        let cursor = ListCursor::decode(&page.next_cursor.unwrap()).unwrap();
+        assert_eq!(cursor.key, "bob");
+        assert_eq!(cursor.id, users[1].id);
+    }
 }
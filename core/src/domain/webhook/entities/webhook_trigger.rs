@@ -10,6 +10,8 @@ pub enum WebhookTrigger {
     UserCreated,
     #[serde(rename = "user.email_verified")]
     UserEmailVerified,
+    #[serde(rename = "user.email_changed")]
+    UserEmailChanged,
     #[serde(rename = "user.updated")]
     UserUpdated,
     #[serde(rename = "user.deleted")]
@@ -72,6 +74,10 @@ pub enum WebhookTrigger {
     ClientMaintenanceEnabled,
     #[serde(rename = "client.maintenance.disabled")]
     ClientMaintenanceDisabled,
+    #[serde(rename = "realm.maintenance.enabled")]
+    RealmMaintenanceEnabled,
+    #[serde(rename = "realm.maintenance.disabled")]
+    RealmMaintenanceDisabled,
 }
 
 impl Display for WebhookTrigger {
@@ -79,6 +85,7 @@ impl Display for WebhookTrigger {
         match self {
             WebhookTrigger::UserCreated => write!(f, "user.created"),
             WebhookTrigger::UserEmailVerified => write!(f, "user.email_verified"),
+            WebhookTrigger::UserEmailChanged => write!(f, "user.email_changed"),
             WebhookTrigger::UserUpdated => write!(f, "user.updated"),
             WebhookTrigger::UserDeleted => write!(f, "user.deleted"),
             WebhookTrigger::UserBulkDeleted => write!(f, "user.bulk_deleted"),
@@ -114,6 +121,8 @@ impl Display for WebhookTrigger {
             WebhookTrigger::ClientMaintenanceDisabled => {
                 write!(f, "client.maintenance.disabled")
             }
+            WebhookTrigger::RealmMaintenanceEnabled => write!(f, "realm.maintenance.enabled"),
+            WebhookTrigger::RealmMaintenanceDisabled => write!(f, "realm.maintenance.disabled"),
         }
     }
 }
@@ -125,6 +134,7 @@ impl TryFrom<String> for WebhookTrigger {
         match value.as_str() {
             "user.created" => Ok(WebhookTrigger::UserCreated),
             "user.email_verified" => Ok(WebhookTrigger::UserEmailVerified),
+            "user.email_changed" => Ok(WebhookTrigger::UserEmailChanged),
             "user.updated" => Ok(WebhookTrigger::UserUpdated),
             "user.deleted" => Ok(WebhookTrigger::UserDeleted),
             "user.bulk_deleted" => Ok(WebhookTrigger::UserBulkDeleted),
@@ -156,6 +166,8 @@ impl TryFrom<String> for WebhookTrigger {
             "webhook.deleted" => Ok(WebhookTrigger::WebhookDeleted),
             "client.maintenance.enabled" => Ok(WebhookTrigger::ClientMaintenanceEnabled),
             "client.maintenance.disabled" => Ok(WebhookTrigger::ClientMaintenanceDisabled),
+            "realm.maintenance.enabled" => Ok(WebhookTrigger::RealmMaintenanceEnabled),
+            "realm.maintenance.disabled" => Ok(WebhookTrigger::RealmMaintenanceDisabled),
             _ => Err("Invalid webhook trigger".to_string()),
         }
     }
@@ -29,6 +29,8 @@ pub struct Model {
     pub webauthn_challenge: Option<Json>,
     pub webauthn_challenge_issued_at: Option<DateTime>,
     pub compass_flow_id: Option<Uuid>,
+    pub pending_auth_stages: Json,
+    pub risk_assessment: Option<Json>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -49,6 +51,8 @@ pub enum Column {
     WebauthnChallenge,
     WebauthnChallengeIssuedAt,
     CompassFlowId,
+    PendingAuthStages,
+    RiskAssessment,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -95,6 +99,8 @@ impl ColumnTrait for Column {
             Self::WebauthnChallenge => ColumnType::JsonBinary.def().null(),
             Self::WebauthnChallengeIssuedAt => ColumnType::DateTime.def().null(),
             Self::CompassFlowId => ColumnType::Uuid.def().null(),
+            Self::PendingAuthStages => ColumnType::JsonBinary.def(),
+            Self::RiskAssessment => ColumnType::JsonBinary.def().null(),
         }
     }
 }
@@ -75,6 +75,7 @@ impl PrimaryKeyTrait for PrimaryKey {
 
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
+    AuthFlows,
     AuthSessions,
     BrokerAuthSessions,
     ClientMaintenanceWhitelist,
@@ -121,6 +122,7 @@ impl ColumnTrait for Column {
 impl RelationTrait for Relation {
     fn def(&self) -> RelationDef {
         match self {
+            Self::AuthFlows => Entity::has_many(super::auth_flows::Entity).into(),
             Self::AuthSessions => Entity::has_many(super::auth_sessions::Entity).into(),
             Self::BrokerAuthSessions => {
                 Entity::has_many(super::broker_auth_sessions::Entity).into()
@@ -148,6 +150,12 @@ impl RelationTrait for Relation {
     }
 }
 
+impl Related<super::auth_flows::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthFlows.def()
+    }
+}
+
 impl Related<super::auth_sessions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::AuthSessions.def()
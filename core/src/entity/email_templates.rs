@@ -49,6 +49,8 @@ impl PrimaryKeyTrait for PrimaryKey {
 
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
+    EmailTemplateLocalizations,
+    EmailTemplateVersions,
     Realms,
 }
 
@@ -71,6 +73,12 @@ impl ColumnTrait for Column {
 impl RelationTrait for Relation {
     fn def(&self) -> RelationDef {
         match self {
+            Self::EmailTemplateLocalizations => {
+                Entity::has_many(super::email_template_localizations::Entity).into()
+            }
+            Self::EmailTemplateVersions => {
+                Entity::has_many(super::email_template_versions::Entity).into()
+            }
             Self::Realms => Entity::belongs_to(super::realms::Entity)
                 .from(Column::RealmId)
                 .to(super::realms::Column::Id)
@@ -79,6 +87,18 @@ impl RelationTrait for Relation {
     }
 }
 
+impl Related<super::email_template_localizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EmailTemplateLocalizations.def()
+    }
+}
+
+impl Related<super::email_template_versions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EmailTemplateVersions.def()
+    }
+}
+
 impl Related<super::realms::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Realms.def()
@@ -20,6 +20,8 @@ pub struct Model {
     pub expires_at: DateTimeWithTimeZone,
     pub created_at: DateTimeWithTimeZone,
     pub used_at: Option<DateTimeWithTimeZone>,
+    pub purpose: String,
+    pub email: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -31,6 +33,8 @@ pub enum Column {
     ExpiresAt,
     CreatedAt,
     UsedAt,
+    Purpose,
+    Email,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -62,6 +66,8 @@ impl ColumnTrait for Column {
             Self::ExpiresAt => ColumnType::TimestampWithTimeZone.def(),
             Self::CreatedAt => ColumnType::TimestampWithTimeZone.def(),
             Self::UsedAt => ColumnType::TimestampWithTimeZone.def().null(),
+            Self::Purpose => ColumnType::String(StringLen::N(32u32)).def(),
+            Self::Email => ColumnType::String(StringLen::N(255u32)).def().null(),
         }
     }
 }
@@ -3,6 +3,9 @@
 pub mod prelude;
 
 pub mod access_tokens;
+pub mod account_deletion_requests;
+pub mod admin_events;
+pub mod auth_flows;
 pub mod auth_sessions;
 pub mod broker_auth_sessions;
 pub mod client_maintenance_whitelist;
@@ -16,34 +19,51 @@ pub mod compass_flows;
 pub mod credentials;
 pub mod data_migrations;
 pub mod device_auth_sessions;
+pub mod email_outbox;
+pub mod email_template_localizations;
+pub mod email_template_versions;
 pub mod email_templates;
 pub mod email_verification_tokens;
+pub mod housekeeping_retention_policies;
 pub mod identity_provider_links;
 pub mod identity_providers;
 pub mod jwt_keys;
 pub mod magic_links;
+pub mod maintenance_windows;
+pub mod message_bundles;
+pub mod one_time_codes;
 pub mod organization_attributes;
 pub mod organization_members;
 pub mod organizations;
+pub mod otp_policies;
 pub mod password_policy;
 pub mod password_reset_tokens;
 pub mod portal_layouts;
 pub mod portal_themes;
 pub mod post_logout_redirect_uris;
+pub mod realm_localizations;
+pub mod realm_maintenance;
 pub mod realm_maintenance_whitelist;
 pub mod realm_settings;
 pub mod realms;
 pub mod redirect_uris;
 pub mod refresh_tokens;
+pub mod risk_policies;
 pub mod roles;
+pub mod security_event_chain_heads;
+pub mod security_event_checkpoints;
+pub mod security_event_retention_policies;
 pub mod security_events;
+pub mod security_notification_settings;
 pub mod smtp_configs;
+pub mod trusted_devices;
 pub mod user_attributes;
 pub mod user_federation_mappings;
 pub mod user_federation_providers;
 pub mod user_required_actions;
 pub mod user_role;
 pub mod user_sessions;
+pub mod user_sign_in_origins;
 pub mod users;
 pub mod webhook_subscribers;
 pub mod webhooks;
@@ -1,6 +1,9 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
 pub use super::access_tokens::Entity as AccessTokens;
+pub use super::account_deletion_requests::Entity as AccountDeletionRequests;
+pub use super::admin_events::Entity as AdminEvents;
+pub use super::auth_flows::Entity as AuthFlows;
 pub use super::auth_sessions::Entity as AuthSessions;
 pub use super::broker_auth_sessions::Entity as BrokerAuthSessions;
 pub use super::client_maintenance_whitelist::Entity as ClientMaintenanceWhitelist;
@@ -14,34 +17,51 @@ pub use super::compass_flows::Entity as CompassFlows;
 pub use super::credentials::Entity as Credentials;
 pub use super::data_migrations::Entity as DataMigrations;
 pub use super::device_auth_sessions::Entity as DeviceAuthSessions;
+pub use super::email_outbox::Entity as EmailOutbox;
+pub use super::email_template_localizations::Entity as EmailTemplateLocalizations;
+pub use super::email_template_versions::Entity as EmailTemplateVersions;
 pub use super::email_templates::Entity as EmailTemplates;
 pub use super::email_verification_tokens::Entity as EmailVerificationTokens;
+pub use super::housekeeping_retention_policies::Entity as HousekeepingRetentionPolicies;
 pub use super::identity_provider_links::Entity as IdentityProviderLinks;
 pub use super::identity_providers::Entity as IdentityProviders;
 pub use super::jwt_keys::Entity as JwtKeys;
 pub use super::magic_links::Entity as MagicLinks;
+pub use super::maintenance_windows::Entity as MaintenanceWindows;
+pub use super::message_bundles::Entity as MessageBundles;
+pub use super::one_time_codes::Entity as OneTimeCodes;
 pub use super::organization_attributes::Entity as OrganizationAttributes;
 pub use super::organization_members::Entity as OrganizationMembers;
 pub use super::organizations::Entity as Organizations;
+pub use super::otp_policies::Entity as OtpPolicies;
 pub use super::password_policy::Entity as PasswordPolicy;
 pub use super::password_reset_tokens::Entity as PasswordResetTokens;
 pub use super::portal_layouts::Entity as PortalLayouts;
 pub use super::portal_themes::Entity as PortalThemes;
 pub use super::post_logout_redirect_uris::Entity as PostLogoutRedirectUris;
+pub use super::realm_localizations::Entity as RealmLocalizations;
+pub use super::realm_maintenance::Entity as RealmMaintenance;
 pub use super::realm_maintenance_whitelist::Entity as RealmMaintenanceWhitelist;
 pub use super::realm_settings::Entity as RealmSettings;
 pub use super::realms::Entity as Realms;
 pub use super::redirect_uris::Entity as RedirectUris;
 pub use super::refresh_tokens::Entity as RefreshTokens;
+pub use super::risk_policies::Entity as RiskPolicies;
 pub use super::roles::Entity as Roles;
+pub use super::security_event_chain_heads::Entity as SecurityEventChainHeads;
+pub use super::security_event_checkpoints::Entity as SecurityEventCheckpoints;
+pub use super::security_event_retention_policies::Entity as SecurityEventRetentionPolicies;
 pub use super::security_events::Entity as SecurityEvents;
+pub use super::security_notification_settings::Entity as SecurityNotificationSettings;
 pub use super::smtp_configs::Entity as SmtpConfigs;
+pub use super::trusted_devices::Entity as TrustedDevices;
 pub use super::user_attributes::Entity as UserAttributes;
 pub use super::user_federation_mappings::Entity as UserFederationMappings;
 pub use super::user_federation_providers::Entity as UserFederationProviders;
 pub use super::user_required_actions::Entity as UserRequiredActions;
 pub use super::user_role::Entity as UserRole;
 pub use super::user_sessions::Entity as UserSessions;
+pub use super::user_sign_in_origins::Entity as UserSignInOrigins;
 pub use super::users::Entity as Users;
 pub use super::webhook_subscribers::Entity as WebhookSubscribers;
 pub use super::webhooks::Entity as Webhooks;
@@ -34,6 +34,7 @@ pub struct Model {
     pub email_verification_enabled: bool,
     pub email_verification_ttl_hours: i32,
     pub portal_theme_id: Option<Uuid>,
+    pub trusted_device_days: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -59,6 +60,7 @@ pub enum Column {
     EmailVerificationEnabled,
     EmailVerificationTtlHours,
     PortalThemeId,
+    TrustedDeviceDays,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -107,6 +109,7 @@ impl ColumnTrait for Column {
             Self::EmailVerificationEnabled => ColumnType::Boolean.def(),
             Self::EmailVerificationTtlHours => ColumnType::Integer.def(),
             Self::PortalThemeId => ColumnType::Uuid.def().null(),
+            Self::TrustedDeviceDays => ColumnType::Integer.def(),
         }
     }
 }
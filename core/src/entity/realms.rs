@@ -41,28 +41,44 @@ impl PrimaryKeyTrait for PrimaryKey {
 
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
+    AuthFlows,
     AccessTokens,
+    AdminEvents,
     AuthSessions,
     BrokerAuthSessions,
     ClientScopes,
     Clients,
     CompassFlows,
     DeviceAuthSessions,
+    EmailOutbox,
     EmailTemplates,
     EmailVerificationTokens,
+    HousekeepingRetentionPolicies,
     IdentityProviders,
     JwtKeys,
     MagicLinks,
+    MaintenanceWindows,
+    MessageBundles,
+    OneTimeCodes,
     Organizations,
+    OtpPolicies,
     PasswordPolicy,
     PasswordResetTokens,
     PortalLayouts,
     PortalThemes,
+    RealmLocalizations,
+    RealmMaintenance,
     RealmMaintenanceWhitelist,
     RealmSettings,
+    RiskPolicies,
     Roles,
+    SecurityEventChainHeads,
+    SecurityEventCheckpoints,
+    SecurityEventRetentionPolicies,
     SecurityEvents,
+    SecurityNotificationSettings,
     SmtpConfigs,
+    TrustedDevices,
     UserAttributes,
     UserFederationProviders,
     UserSessions,
@@ -86,6 +102,8 @@ impl RelationTrait for Relation {
     fn def(&self) -> RelationDef {
         match self {
             Self::AccessTokens => Entity::has_many(super::access_tokens::Entity).into(),
+            Self::AdminEvents => Entity::has_many(super::admin_events::Entity).into(),
+            Self::AuthFlows => Entity::has_many(super::auth_flows::Entity).into(),
             Self::AuthSessions => Entity::has_many(super::auth_sessions::Entity).into(),
             Self::BrokerAuthSessions => {
                 Entity::has_many(super::broker_auth_sessions::Entity).into()
@@ -96,27 +114,51 @@ impl RelationTrait for Relation {
             Self::DeviceAuthSessions => {
                 Entity::has_many(super::device_auth_sessions::Entity).into()
             }
+            Self::EmailOutbox => Entity::has_many(super::email_outbox::Entity).into(),
             Self::EmailTemplates => Entity::has_many(super::email_templates::Entity).into(),
             Self::EmailVerificationTokens => {
                 Entity::has_many(super::email_verification_tokens::Entity).into()
             }
+            Self::HousekeepingRetentionPolicies => {
+                Entity::has_many(super::housekeeping_retention_policies::Entity).into()
+            }
             Self::IdentityProviders => Entity::has_many(super::identity_providers::Entity).into(),
             Self::JwtKeys => Entity::has_many(super::jwt_keys::Entity).into(),
             Self::MagicLinks => Entity::has_many(super::magic_links::Entity).into(),
+            Self::MaintenanceWindows => Entity::has_many(super::maintenance_windows::Entity).into(),
+            Self::MessageBundles => Entity::has_many(super::message_bundles::Entity).into(),
+            Self::OneTimeCodes => Entity::has_many(super::one_time_codes::Entity).into(),
             Self::Organizations => Entity::has_many(super::organizations::Entity).into(),
+            Self::OtpPolicies => Entity::has_one(super::otp_policies::Entity).into(),
             Self::PasswordPolicy => Entity::has_one(super::password_policy::Entity).into(),
             Self::PasswordResetTokens => {
                 Entity::has_many(super::password_reset_tokens::Entity).into()
             }
             Self::PortalLayouts => Entity::has_one(super::portal_layouts::Entity).into(),
             Self::PortalThemes => Entity::has_many(super::portal_themes::Entity).into(),
+            Self::RealmLocalizations => Entity::has_one(super::realm_localizations::Entity).into(),
+            Self::RealmMaintenance => Entity::has_one(super::realm_maintenance::Entity).into(),
             Self::RealmMaintenanceWhitelist => {
                 Entity::has_many(super::realm_maintenance_whitelist::Entity).into()
             }
             Self::RealmSettings => Entity::has_many(super::realm_settings::Entity).into(),
+            Self::RiskPolicies => Entity::has_one(super::risk_policies::Entity).into(),
             Self::Roles => Entity::has_many(super::roles::Entity).into(),
+            Self::SecurityEventChainHeads => {
+                Entity::has_one(super::security_event_chain_heads::Entity).into()
+            }
+            Self::SecurityEventCheckpoints => {
+                Entity::has_many(super::security_event_checkpoints::Entity).into()
+            }
+            Self::SecurityEventRetentionPolicies => {
+                Entity::has_one(super::security_event_retention_policies::Entity).into()
+            }
             Self::SecurityEvents => Entity::has_many(super::security_events::Entity).into(),
+            Self::SecurityNotificationSettings => {
+                Entity::has_one(super::security_notification_settings::Entity).into()
+            }
             Self::SmtpConfigs => Entity::has_one(super::smtp_configs::Entity).into(),
+            Self::TrustedDevices => Entity::has_many(super::trusted_devices::Entity).into(),
             Self::UserAttributes => Entity::has_many(super::user_attributes::Entity).into(),
             Self::UserFederationProviders => {
                 Entity::has_many(super::user_federation_providers::Entity).into()
@@ -134,6 +176,18 @@ impl Related<super::access_tokens::Entity> for Entity {
     }
 }
 
+impl Related<super::admin_events::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AdminEvents.def()
+    }
+}
+
+impl Related<super::auth_flows::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AuthFlows.def()
+    }
+}
+
 impl Related<super::auth_sessions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::AuthSessions.def()
@@ -170,6 +224,24 @@ impl Related<super::device_auth_sessions::Entity> for Entity {
     }
 }
 
+impl Related<super::message_bundles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MessageBundles.def()
+    }
+}
+
+impl Related<super::realm_localizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RealmLocalizations.def()
+    }
+}
+
+impl Related<super::email_outbox::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EmailOutbox.def()
+    }
+}
+
 impl Related<super::email_templates::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::EmailTemplates.def()
@@ -182,6 +254,12 @@ impl Related<super::email_verification_tokens::Entity> for Entity {
     }
 }
 
+impl Related<super::housekeeping_retention_policies::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::HousekeepingRetentionPolicies.def()
+    }
+}
+
 impl Related<super::identity_providers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::IdentityProviders.def()
@@ -200,12 +278,24 @@ impl Related<super::magic_links::Entity> for Entity {
     }
 }
 
+impl Related<super::one_time_codes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OneTimeCodes.def()
+    }
+}
+
 impl Related<super::organizations::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Organizations.def()
     }
 }
 
+impl Related<super::otp_policies::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OtpPolicies.def()
+    }
+}
+
 impl Related<super::password_policy::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::PasswordPolicy.def()
@@ -230,6 +320,18 @@ impl Related<super::portal_themes::Entity> for Entity {
     }
 }
 
+impl Related<super::maintenance_windows::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MaintenanceWindows.def()
+    }
+}
+
+impl Related<super::realm_maintenance::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RealmMaintenance.def()
+    }
+}
+
 impl Related<super::realm_maintenance_whitelist::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::RealmMaintenanceWhitelist.def()
@@ -242,24 +344,60 @@ impl Related<super::realm_settings::Entity> for Entity {
     }
 }
 
+impl Related<super::risk_policies::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RiskPolicies.def()
+    }
+}
+
 impl Related<super::roles::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Roles.def()
     }
 }
 
+impl Related<super::security_event_chain_heads::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SecurityEventChainHeads.def()
+    }
+}
+
+impl Related<super::security_event_checkpoints::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SecurityEventCheckpoints.def()
+    }
+}
+
+impl Related<super::security_event_retention_policies::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SecurityEventRetentionPolicies.def()
+    }
+}
+
 impl Related<super::security_events::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::SecurityEvents.def()
     }
 }
 
+impl Related<super::security_notification_settings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SecurityNotificationSettings.def()
+    }
+}
+
 impl Related<super::smtp_configs::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::SmtpConfigs.def()
     }
 }
 
+impl Related<super::trusted_devices::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TrustedDevices.def()
+    }
+}
+
 impl Related<super::user_attributes::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserAttributes.def()
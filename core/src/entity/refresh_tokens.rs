@@ -16,6 +16,7 @@ pub struct Model {
     pub id: Uuid,
     pub jti: Uuid,
     pub user_id: Uuid,
+    pub client_id: Option<Uuid>,
     pub revoked: bool,
     pub expires_at: Option<DateTime>,
     pub created_at: DateTime,
@@ -26,6 +27,7 @@ pub enum Column {
     Id,
     Jti,
     UserId,
+    ClientId,
     Revoked,
     ExpiresAt,
     CreatedAt,
@@ -55,6 +57,7 @@ impl ColumnTrait for Column {
             Self::Id => ColumnType::Uuid.def(),
             Self::Jti => ColumnType::Uuid.def().unique(),
             Self::UserId => ColumnType::Uuid.def(),
+            Self::ClientId => ColumnType::Uuid.def().null(),
             Self::Revoked => ColumnType::Boolean.def(),
             Self::ExpiresAt => ColumnType::DateTime.def().null(),
             Self::CreatedAt => ColumnType::DateTime.def(),
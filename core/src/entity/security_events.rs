@@ -28,6 +28,10 @@ pub struct Model {
     pub user_agent: Option<String>,
     pub details: Option<Json>,
     pub created_at: DateTime,
+    pub sequence: Option<i64>,
+    pub prev_hash: Option<String>,
+    pub hash: Option<String>,
+    pub pseudonymized_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -47,6 +51,10 @@ pub enum Column {
     UserAgent,
     Details,
     CreatedAt,
+    Sequence,
+    PrevHash,
+    Hash,
+    PseudonymizedAt,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -85,6 +93,10 @@ impl ColumnTrait for Column {
             Self::UserAgent => ColumnType::Text.def().null(),
             Self::Details => ColumnType::JsonBinary.def().null(),
             Self::CreatedAt => ColumnType::DateTime.def(),
+            Self::Sequence => ColumnType::BigInteger.def().null(),
+            Self::PrevHash => ColumnType::String(StringLen::N(64u32)).def().null(),
+            Self::Hash => ColumnType::String(StringLen::N(64u32)).def().null(),
+            Self::PseudonymizedAt => ColumnType::DateTime.def().null(),
         }
     }
 }
@@ -22,6 +22,10 @@ pub struct Model {
     pub from_email: String,
     pub from_name: String,
     pub encryption: String,
+    pub provider: String,
+    pub http_url: Option<String>,
+    pub http_headers: Option<Json>,
+    pub http_body_template: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -37,6 +41,10 @@ pub enum Column {
     FromEmail,
     FromName,
     Encryption,
+    Provider,
+    HttpUrl,
+    HttpHeaders,
+    HttpBodyTemplate,
     CreatedAt,
     UpdatedAt,
 }
@@ -71,6 +79,10 @@ impl ColumnTrait for Column {
             Self::FromEmail => ColumnType::String(StringLen::N(255u32)).def(),
             Self::FromName => ColumnType::String(StringLen::N(255u32)).def(),
             Self::Encryption => ColumnType::String(StringLen::N(10u32)).def(),
+            Self::Provider => ColumnType::String(StringLen::N(20u32)).def(),
+            Self::HttpUrl => ColumnType::Text.def().null(),
+            Self::HttpHeaders => ColumnType::JsonBinary.def().null(),
+            Self::HttpBodyTemplate => ColumnType::Text.def().null(),
             Self::CreatedAt => ColumnType::TimestampWithTimeZone.def(),
             Self::UpdatedAt => ColumnType::TimestampWithTimeZone.def(),
         }
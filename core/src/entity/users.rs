@@ -65,16 +65,19 @@ pub enum Relation {
     EmailVerificationTokens,
     IdentityProviderLinks,
     MagicLinks,
+    OneTimeCodes,
     OrganizationMembers,
     PasswordResetTokens,
     RealmMaintenanceWhitelist,
     Realms,
     RefreshTokens,
+    TrustedDevices,
     UserAttributes,
     UserFederationMappings,
     UserRequiredActions,
     UserRole,
     UserSessions,
+    UserSignInOrigins,
 }
 
 impl ColumnTrait for Column {
@@ -120,6 +123,7 @@ impl RelationTrait for Relation {
                 Entity::has_many(super::identity_provider_links::Entity).into()
             }
             Self::MagicLinks => Entity::has_many(super::magic_links::Entity).into(),
+            Self::OneTimeCodes => Entity::has_many(super::one_time_codes::Entity).into(),
             Self::OrganizationMembers => {
                 Entity::has_many(super::organization_members::Entity).into()
             }
@@ -134,6 +138,7 @@ impl RelationTrait for Relation {
                 .to(super::realms::Column::Id)
                 .into(),
             Self::RefreshTokens => Entity::has_many(super::refresh_tokens::Entity).into(),
+            Self::TrustedDevices => Entity::has_many(super::trusted_devices::Entity).into(),
             Self::UserAttributes => Entity::has_many(super::user_attributes::Entity).into(),
             Self::UserFederationMappings => {
                 Entity::has_many(super::user_federation_mappings::Entity).into()
@@ -143,6 +148,7 @@ impl RelationTrait for Relation {
             }
             Self::UserRole => Entity::has_many(super::user_role::Entity).into(),
             Self::UserSessions => Entity::has_many(super::user_sessions::Entity).into(),
+            Self::UserSignInOrigins => Entity::has_many(super::user_sign_in_origins::Entity).into(),
         }
     }
 }
@@ -207,6 +213,12 @@ impl Related<super::magic_links::Entity> for Entity {
     }
 }
 
+impl Related<super::one_time_codes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OneTimeCodes.def()
+    }
+}
+
 impl Related<super::organization_members::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::OrganizationMembers.def()
@@ -237,6 +249,12 @@ impl Related<super::refresh_tokens::Entity> for Entity {
     }
 }
 
+impl Related<super::trusted_devices::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TrustedDevices.def()
+    }
+}
+
 impl Related<super::user_attributes::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserAttributes.def()
@@ -267,4 +285,10 @@ impl Related<super::user_sessions::Entity> for Entity {
     }
 }
 
+impl Related<super::user_sign_in_origins::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserSignInOrigins.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -1,8 +1,6 @@
 use std::collections::HashMap;
 use std::time::Duration;
 
-use base64::Engine;
-use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use ldap3::{Ldap, LdapConnAsync, LdapConnSettings, Scope, SearchEntry, SearchResult};
 use serde::Deserialize;
 use tokio::time::timeout;
@@ -40,7 +38,8 @@ struct LdapConnection {
 #[allow(dead_code)]
 struct LdapBind {
     bind_dn: String,
-    bind_password_encrypted: String, // TODO: Decrypt this
+    /// Decrypted by the federation repository when the provider is loaded.
+    bind_password_encrypted: String,
 }
 
 #[derive(Deserialize, Debug)]
@@ -70,26 +69,6 @@ impl LdapClientImpl {
             .map_err(|e| CoreError::Configuration(format!("Invalid LDAP config: {}", e)))
     }
 
-    /// Decrypt the encrypted bind password
-    /// TODO: Integrate with a proper encryption service (e.g., KMS, Vault)
-    #[allow(dead_code)]
-    fn decrypt_password(encrypted: &str) -> Result<String, CoreError> {
-        // Placeholder: In production, call your encryption service
-        // For now, assume the password is base64 encoded or plaintext
-        tracing::warn!("Using placeholder password decryption - implement proper KMS integration");
-
-        // Try base64 decode as a simple obfuscation
-        match BASE64_STANDARD.decode(encrypted) {
-            Ok(decoded) => String::from_utf8(decoded)
-                .map_err(|e| CoreError::Configuration(format!("Invalid password encoding: {}", e))),
-            Err(_) => {
-                // If not base64, assume plaintext (for development only!)
-                tracing::warn!("Password not base64 encoded, using as plaintext");
-                Ok(encrypted.to_string())
-            }
-        }
-    }
-
     #[allow(dead_code)]
     fn build_url(config: &LdapConfig) -> String {
         let mut url = config.connection.server_url.clone();
@@ -155,10 +134,7 @@ impl LdapClientImpl {
         let mut ldap = self.connect(provider).await?;
         let config = Self::parse_config(provider)?;
 
-        // Decrypt the password before using it
-        let password = Self::decrypt_password(&config.bind.bind_password_encrypted)?;
-
-        ldap.simple_bind(&config.bind.bind_dn, &password)
+        ldap.simple_bind(&config.bind.bind_dn, &config.bind.bind_password_encrypted)
             .await
             .map_err(|e| CoreError::External(format!("LDAP Bind failed: {}", e)))?;
 
@@ -368,8 +344,10 @@ impl LdapClientImpl {
         };
 
         // 2. Test Bind
-        let password = Self::decrypt_password(&config.bind.bind_password_encrypted)?;
-        if let Err(e) = ldap.simple_bind(&config.bind.bind_dn, &password).await {
+        if let Err(e) = ldap
+            .simple_bind(&config.bind.bind_dn, &config.bind.bind_password_encrypted)
+            .await
+        {
             return Ok(TestConnectionResult {
                 success: false,
                 message: format!("Failed to bind: {}", e),
@@ -1,6 +1,8 @@
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64_STANDARD};
 use chrono::Utc;
 use sea_orm::*;
 use serde::Deserialize;
+use serde_json::Value;
 use tracing::error;
 use uuid::Uuid;
 
@@ -12,12 +14,27 @@ use crate::domain::abyss::federation::value_objects::{
     CreateProviderRequest, UpdateProviderRequest,
 };
 use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::secrets::{EnvelopeCipher, SealedSecret};
 use crate::entity::{user_federation_mappings, user_federation_providers};
+use crate::infrastructure::secrets::{
+    FEDERATION_BIND_PASSWORD, cipher_error, open_json_secret, seal_json_secret,
+};
 
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
 pub struct FederationRepositoryImpl {
     db: DatabaseConnection,
+    cipher: EnvelopeCipher,
+}
+
+/// Bind passwords stored before encryption at rest were base64 encoded when
+/// they decoded to UTF-8, and used as is otherwise.
+pub(crate) fn decode_legacy_bind_password(value: &str) -> String {
+    BASE64_STANDARD
+        .decode(value)
+        .ok()
+        .and_then(|decoded| String::from_utf8(decoded).ok())
+        .unwrap_or_else(|| value.to_string())
 }
 
 #[derive(Deserialize)]
@@ -29,8 +46,32 @@ struct SyncSettings {
 
 impl FederationRepositoryImpl {
     #[allow(dead_code)]
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, cipher: EnvelopeCipher) -> Self {
+        Self { db, cipher }
+    }
+
+    async fn seal(&self, mut config: serde_json::Value) -> Result<serde_json::Value, CoreError> {
+        seal_json_secret(&self.cipher, &mut config, FEDERATION_BIND_PASSWORD)
+            .await
+            .map_err(|e| cipher_error("encrypt federation bind password", e))?;
+
+        Ok(config)
+    }
+
+    async fn reveal(
+        &self,
+        mut model: user_federation_providers::Model,
+    ) -> Result<FederationProvider, CoreError> {
+        match model.config.pointer_mut(FEDERATION_BIND_PASSWORD) {
+            Some(Value::String(password)) if !SealedSecret::is_sealed(password) => {
+                *password = decode_legacy_bind_password(password);
+            }
+            _ => open_json_secret(&self.cipher, &mut model.config, FEDERATION_BIND_PASSWORD)
+                .await
+                .map_err(|e| cipher_error("decrypt federation bind password", e))?,
+        }
+
+        model.try_into()
     }
 }
 
@@ -135,13 +176,16 @@ impl FederationRepository for FederationRepositoryImpl {
         &self,
         request: CreateProviderRequest,
     ) -> Result<FederationProvider, CoreError> {
-        let active_model: user_federation_providers::ActiveModel = request.try_into()?;
+        let mut active_model: user_federation_providers::ActiveModel = request.try_into()?;
+        if let Some(config) = active_model.config.take() {
+            active_model.config = Set(self.seal(config).await?);
+        }
 
         let model = active_model.insert(&self.db).await.map_err(|e| {
             CoreError::Database(format!("Failed to create federation provider: {}", e))
         })?;
 
-        model.try_into()
+        self.reveal(model).await
     }
 
     async fn get_by_id(&self, id: Uuid) -> Result<Option<FederationProvider>, CoreError> {
@@ -153,7 +197,7 @@ impl FederationRepository for FederationRepositoryImpl {
             })?;
 
         match model {
-            Some(m) => Ok(Some(m.try_into()?)),
+            Some(m) => Ok(Some(self.reveal(m).await?)),
             None => Ok(None),
         }
     }
@@ -185,7 +229,7 @@ impl FederationRepository for FederationRepositoryImpl {
             active_model.priority = Set(priority);
         }
         if let Some(config) = request.config {
-            active_model.config = Set(config);
+            active_model.config = Set(self.seal(config).await?);
         }
 
         if let Some(sync_settings_json) = request.sync_settings {
@@ -203,7 +247,7 @@ impl FederationRepository for FederationRepositoryImpl {
             CoreError::Database(format!("Failed to update federation provider: {}", e))
         })?;
 
-        updated_model.try_into()
+        self.reveal(updated_model).await
     }
 
     async fn delete(&self, id: Uuid) -> Result<(), CoreError> {
@@ -226,7 +270,12 @@ impl FederationRepository for FederationRepositoryImpl {
                 CoreError::Database(format!("Failed to list federation providers: {}", e))
             })?;
 
-        models.into_iter().map(|m| m.try_into()).collect()
+        let mut providers = Vec::with_capacity(models.len());
+        for model in models {
+            providers.push(self.reveal(model).await?);
+        }
+
+        Ok(providers)
     }
 
     async fn create_mapping(
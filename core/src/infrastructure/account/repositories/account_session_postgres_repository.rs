@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use chrono::{TimeZone, Utc};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::domain::account::{entities::AccountSession, ports::AccountSessionRepository};
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::{clients, refresh_tokens};
+
+/// Sessions are the user's refresh tokens: each sign-in to an application
+/// issues one, and revoking it stops the application from getting new
+/// access tokens.
+#[derive(Debug, Clone)]
+pub struct PostgresAccountSessionRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresAccountSessionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn active(user_id: Uuid) -> Condition {
+        Condition::all()
+            .add(refresh_tokens::Column::UserId.eq(user_id))
+            .add(refresh_tokens::Column::Revoked.eq(false))
+            .add(
+                Condition::any()
+                    .add(refresh_tokens::Column::ExpiresAt.is_null())
+                    .add(refresh_tokens::Column::ExpiresAt.gt(Utc::now().naive_utc())),
+            )
+    }
+}
+
+impl AccountSessionRepository for PostgresAccountSessionRepository {
+    async fn list_active(&self, user_id: Uuid) -> Result<Vec<AccountSession>, CoreError> {
+        let tokens = refresh_tokens::Entity::find()
+            .filter(Self::active(user_id))
+            .order_by_desc(refresh_tokens::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        let client_ids: Vec<Uuid> = tokens.iter().filter_map(|t| t.client_id).collect();
+        let clients: HashMap<Uuid, clients::Model> = if client_ids.is_empty() {
+            HashMap::new()
+        } else {
+            clients::Entity::find()
+                .filter(clients::Column::Id.is_in(client_ids))
+                .all(&self.db)
+                .await
+                .map_err(|e| CoreError::Database(e.to_string()))?
+                .into_iter()
+                .map(|client| (client.id, client))
+                .collect()
+        };
+
+        Ok(tokens
+            .into_iter()
+            .map(|token| {
+                let client = token.client_id.and_then(|id| clients.get(&id));
+                AccountSession {
+                    id: token.id,
+                    client_id: client.map(|c| c.client_id.clone()),
+                    client_name: client.map(|c| c.name.clone()),
+                    created_at: Utc.from_utc_datetime(&token.created_at),
+                    expires_at: token.expires_at.map(|dt| Utc.from_utc_datetime(&dt)),
+                }
+            })
+            .collect())
+    }
+
+    async fn revoke(&self, user_id: Uuid, session_id: Uuid) -> Result<bool, CoreError> {
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(refresh_tokens::Column::Revoked, Expr::value(true))
+            .filter(Self::active(user_id))
+            .filter(refresh_tokens::Column::Id.eq(session_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
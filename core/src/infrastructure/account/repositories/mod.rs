@@ -0,0 +1,3 @@
+pub mod account_session_postgres_repository;
+
+pub use account_session_postgres_repository::PostgresAccountSessionRepository;
@@ -0,0 +1,15 @@
+use crate::domain::auth_flow::entities::AuthFlow;
+use crate::entity::auth_flows;
+
+impl TryFrom<auth_flows::Model> for AuthFlow {
+    type Error = serde_json::Error;
+
+    fn try_from(model: auth_flows::Model) -> Result<Self, Self::Error> {
+        Ok(AuthFlow {
+            realm_id: model.realm_id.into(),
+            client_id: model.client_id,
+            steps: serde_json::from_value(model.steps)?,
+            updated_at: model.updated_at.to_utc(),
+        })
+    }
+}
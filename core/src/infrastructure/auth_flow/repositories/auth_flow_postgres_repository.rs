@@ -0,0 +1,128 @@
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        auth_flow::{entities::AuthFlow, ports::AuthFlowRepository},
+        common::{entities::app_errors::CoreError, generate_uuid_v7},
+        realm::entities::RealmId,
+    },
+    entity::auth_flows,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresAuthFlowRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresAuthFlowRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {}: {}", context, e);
+    CoreError::InternalServerError
+}
+
+/// Matches the realm's own flow when `client_id` is `None`.
+fn flow_of(realm_id: RealmId, client_id: Option<Uuid>) -> Condition {
+    let client = match client_id {
+        Some(client_id) => auth_flows::Column::ClientId.eq(client_id),
+        None => auth_flows::Column::ClientId.is_null(),
+    };
+
+    Condition::all()
+        .add(auth_flows::Column::RealmId.eq(Uuid::from(realm_id)))
+        .add(client)
+}
+
+impl AuthFlowRepository for PostgresAuthFlowRepository {
+    async fn get_flow(
+        &self,
+        realm_id: RealmId,
+        client_id: Option<Uuid>,
+    ) -> Result<Option<AuthFlow>, CoreError> {
+        let model = auth_flows::Entity::find()
+            .filter(flow_of(realm_id, client_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get auth flow", e))?;
+
+        model
+            .map(AuthFlow::try_from)
+            .transpose()
+            .map_err(|e| database_error("read auth flow", e))
+    }
+
+    async fn list_flows(&self, realm_id: RealmId) -> Result<Vec<AuthFlow>, CoreError> {
+        let models = auth_flows::Entity::find()
+            .filter(auth_flows::Column::RealmId.eq(Uuid::from(realm_id)))
+            .order_by_asc(auth_flows::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("list auth flows", e))?;
+
+        models
+            .into_iter()
+            .map(AuthFlow::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|e| database_error("read auth flow", e))
+    }
+
+    async fn upsert_flow(&self, flow: AuthFlow) -> Result<AuthFlow, CoreError> {
+        let steps = serde_json::to_value(&flow.steps)
+            .map_err(|e| database_error("serialize auth flow", e))?;
+
+        // The unique indexes are partial, which ON CONFLICT can't target
+        // without repeating their predicate, so the row is looked up first.
+        let existing = auth_flows::Entity::find()
+            .filter(flow_of(flow.realm_id, flow.client_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get auth flow", e))?;
+
+        let model = match existing {
+            Some(existing) => auth_flows::Entity::update(auth_flows::ActiveModel {
+                id: Set(existing.id),
+                steps: Set(steps),
+                updated_at: Set(flow.updated_at.into()),
+                ..Default::default()
+            })
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("update auth flow", e))?,
+            None => auth_flows::Entity::insert(auth_flows::ActiveModel {
+                id: Set(generate_uuid_v7()),
+                realm_id: Set(flow.realm_id.into()),
+                client_id: Set(flow.client_id),
+                steps: Set(steps),
+                created_at: Set(flow.updated_at.into()),
+                updated_at: Set(flow.updated_at.into()),
+            })
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| database_error("create auth flow", e))?,
+        };
+
+        AuthFlow::try_from(model).map_err(|e| database_error("read auth flow", e))
+    }
+
+    async fn delete_flow(
+        &self,
+        realm_id: RealmId,
+        client_id: Option<Uuid>,
+    ) -> Result<bool, CoreError> {
+        let result = auth_flows::Entity::delete_many()
+            .filter(flow_of(realm_id, client_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("delete auth flow", e))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod auth_flow_postgres_repository;
+
+pub use auth_flow_postgres_repository::PostgresAuthFlowRepository;
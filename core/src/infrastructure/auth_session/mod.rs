@@ -1,7 +1,9 @@
+use crate::domain::auth_flow::entities::AuthFlowStage;
 use crate::domain::authentication::entities::{
     AuthSession, AuthenticationError, WebAuthnChallenge,
 };
 use crate::domain::authentication::ports::AuthSessionRepository;
+use crate::domain::risk::entities::RiskAssessment;
 use crate::infrastructure::repositories::auth_session_repository::PostgresAuthSessionRepository;
 use uuid::Uuid;
 
@@ -111,4 +113,30 @@ impl AuthSessionRepository for AuthSessionRepoAny {
             }
         }
     }
+
+    async fn update_pending_auth_stages(
+        &self,
+        session_code: Uuid,
+        pending_auth_stages: Vec<AuthFlowStage>,
+    ) -> Result<(), AuthenticationError> {
+        match self {
+            AuthSessionRepoAny::Postgres(repo) => {
+                repo.update_pending_auth_stages(session_code, pending_auth_stages)
+                    .await
+            }
+        }
+    }
+
+    async fn update_risk_assessment(
+        &self,
+        session_code: Uuid,
+        risk_assessment: RiskAssessment,
+    ) -> Result<(), AuthenticationError> {
+        match self {
+            AuthSessionRepoAny::Postgres(repo) => {
+                repo.update_risk_assessment(session_code, risk_assessment)
+                    .await
+            }
+        }
+    }
 }
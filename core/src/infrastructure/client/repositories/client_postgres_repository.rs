@@ -4,7 +4,8 @@ use crate::{
 };
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    IntoSimpleExpr, PaginatorTrait, QueryFilter, sea_query::SimpleExpr,
 };
 use tracing::instrument;
 use uuid::Uuid;
@@ -14,10 +15,31 @@ use crate::domain::{
     client::{
         entities::{Client, redirect_uri::RedirectUri},
         ports::ClientRepository,
-        value_objects::{CreateClientRequest, UpdateClientRequest},
+        value_objects::{ClientFilter, ClientSortField, CreateClientRequest, UpdateClientRequest},
     },
     common::{generate_timestamp, generate_uuid_v7},
 };
+use crate::infrastructure::common::{paginate, search_condition, timestamp_key};
+
+fn client_filter_condition(realm_id: RealmId, filter: &ClientFilter) -> Condition {
+    use crate::entity::clients::Column;
+
+    let mut condition = Condition::all()
+        .add(Column::RealmId.eq::<Uuid>(realm_id.into()))
+        .add(search_condition(
+            filter.search.as_deref(),
+            &[Column::ClientId, Column::Name],
+        ));
+
+    if let Some(enabled) = filter.enabled {
+        condition = condition.add(Column::Enabled.eq(enabled));
+    }
+    if let Some(public_client) = filter.public_client {
+        condition = condition.add(Column::PublicClient.eq(public_client));
+    }
+
+    condition
+}
 
 #[derive(Debug, Clone)]
 pub struct PostgresClientRepository {
@@ -124,6 +146,54 @@ impl ClientRepository for PostgresClientRepository {
         Ok(clients)
     }
 
+    async fn list_clients(
+        &self,
+        realm_id: RealmId,
+        filter: ClientFilter,
+    ) -> Result<Vec<Client>, CoreError> {
+        use crate::entity::clients::Column;
+
+        let (sort, key): (SimpleExpr, _) = match filter.sort {
+            ClientSortField::ClientId => (
+                Column::ClientId.into_simple_expr(),
+                filter.page.cursor.as_ref().map(|c| c.key.clone().into()),
+            ),
+            ClientSortField::Name => (
+                Column::Name.into_simple_expr(),
+                filter.page.cursor.as_ref().map(|c| c.key.clone().into()),
+            ),
+            ClientSortField::CreatedAt => (
+                Column::CreatedAt.into_simple_expr(),
+                filter
+                    .page
+                    .cursor
+                    .as_ref()
+                    .map(|c| timestamp_key(c).map(|t| t.naive_utc().into()))
+                    .transpose()?,
+            ),
+        };
+
+        let select = ClientEntity::find().filter(client_filter_condition(realm_id, &filter));
+        let clients = paginate(select, sort, Column::Id, key, &filter.page)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(clients.into_iter().map(|c| c.into()).collect())
+    }
+
+    async fn count_clients(
+        &self,
+        realm_id: RealmId,
+        filter: ClientFilter,
+    ) -> Result<u64, CoreError> {
+        ClientEntity::find()
+            .filter(client_filter_condition(realm_id, &filter))
+            .count(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
+
     async fn update_client(
         &self,
         client_id: Uuid,
@@ -1 +1,146 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ColumnTrait, Condition, IntoSimpleExpr, Order, QueryFilter, QueryOrder, QuerySelect, Value,
+    sea_query::{Expr, Func, SimpleExpr, extension::postgres::PgExpr},
+};
 
+use crate::domain::common::entities::{
+    app_errors::CoreError,
+    listing::{ListCursor, PageRequest, SortDirection},
+};
+
+/// Sort expression for a nullable text column: `NULL` sorts (and compares in
+/// cursors) as the empty string.
+pub fn coalesced(column: impl ColumnTrait) -> SimpleExpr {
+    Func::coalesce([column.into_simple_expr(), Expr::val("").into()]).into()
+}
+
+/// Every whitespace-separated term of `search` must match at least one of
+/// `columns`, case-insensitively.
+pub fn search_condition<C: ColumnTrait>(search: Option<&str>, columns: &[C]) -> Condition {
+    let terms = search.unwrap_or_default().split_whitespace();
+
+    terms.fold(Condition::all(), |all, term| {
+        let pattern = format!("%{}%", escape_like(term));
+        let any = columns.iter().fold(Condition::any(), |any, column| {
+            any.add(column.into_expr().ilike(pattern.clone()))
+        });
+        all.add(any)
+    })
+}
+
+fn escape_like(term: &str) -> String {
+    term.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Cursor key of a `created_at` ordering, as written by the domain sort fields.
+pub fn timestamp_key(cursor: &ListCursor) -> Result<DateTime<Utc>, CoreError> {
+    let micros: i64 = cursor.key.parse().map_err(|_| CoreError::InvalidCursor)?;
+
+    DateTime::from_timestamp_micros(micros).ok_or(CoreError::InvalidCursor)
+}
+
+/// Orders by `(sort, id)` and applies the page: keyset when `key` (the cursor
+/// key converted to the sort column type) is given, offset otherwise. Fetches
+/// one row more than the limit so callers can tell whether a next page exists.
+pub fn paginate<Q, C>(
+    select: Q,
+    sort: SimpleExpr,
+    id: C,
+    key: Option<Value>,
+    page: &PageRequest,
+) -> Q
+where
+    Q: QueryFilter + QueryOrder + QuerySelect,
+    C: ColumnTrait,
+{
+    let mut select = select;
+
+    match (&page.cursor, key) {
+        (Some(cursor), Some(key)) => {
+            let sort_expr = Expr::expr(sort.clone());
+            let (past_key, past_id) = match page.direction {
+                SortDirection::Asc => (sort_expr.clone().gt(key.clone()), id.gt(cursor.id)),
+                SortDirection::Desc => (sort_expr.clone().lt(key.clone()), id.lt(cursor.id)),
+            };
+
+            select = select.filter(
+                Condition::any()
+                    .add(past_key)
+                    .add(Condition::all().add(sort_expr.eq(key)).add(past_id)),
+            );
+        }
+        _ => {
+            if let Some(offset) = page.offset {
+                select = select.offset(offset as u64);
+            }
+        }
+    }
+
+    let order = match page.direction {
+        SortDirection::Asc => Order::Asc,
+        SortDirection::Desc => Order::Desc,
+    };
+
+    select
+        .order_by(sort, order.clone())
+        .order_by(id, order)
+        .limit(page.limit() as u64 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{DbBackend, EntityTrait, QueryTrait};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::entity::users;
+
+    #[test]
+    fn search_terms_must_each_match_a_column() {
+        let sql = users::Entity::find()
+            .filter(search_condition(
+                Some("jane 100%"),
+                &[users::Column::Username, users::Column::Email],
+            ))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(sql.contains(
+            r#"(("users"."username" ILIKE '%jane%') OR ("users"."email" ILIKE '%jane%'))"#
+        ));
+        assert!(sql.contains(r#"AND (("users"."username" ILIKE E'%100\\%%')"#));
+    }
+
+    #[test]
+    fn keyset_page_resumes_after_the_cursor() {
+        let id = Uuid::nil();
+        let page = PageRequest {
+            limit: Some(10),
+            offset: Some(40),
+            cursor: Some(ListCursor {
+                key: "jane".to_string(),
+                id,
+            }),
+            direction: SortDirection::Desc,
+        };
+
+        let sql = paginate(
+            users::Entity::find(),
+            users::Column::Username.into_simple_expr(),
+            users::Column::Id,
+            Some("jane".into()),
+            &page,
+        )
+        .build(DbBackend::Postgres)
+        .to_string();
+
+        assert!(sql.contains(
+            r#""users"."username" < 'jane' OR ("users"."username" = 'jane' AND "users"."id" < "#
+        ));
+        assert!(sql.contains("LIMIT 11"));
+        assert!(!sql.contains("OFFSET"));
+    }
+}
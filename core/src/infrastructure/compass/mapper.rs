@@ -57,6 +57,8 @@ impl From<compass_flow_steps::Model> for CompassFlowStep {
         let step_name = match model.step_name.as_str() {
             "credential_validation" => FlowStepName::CredentialValidation,
             "mfa_challenge" => FlowStepName::MfaChallenge,
+            "condition_check" => FlowStepName::ConditionCheck,
+            "risk_assessment" => FlowStepName::RiskAssessment,
             "token_exchange" => FlowStepName::TokenExchange,
             "idp_redirect" => FlowStepName::IdpRedirect,
             "idp_callback" => FlowStepName::IdpCallback,
@@ -1,47 +1,66 @@
+use std::path::PathBuf;
+
 use ferriskey_mail::{
-    adapters::smtp::SmtpEmailSender,
-    entities::{EmailAddress, EmailFrom, EmailMessage, EmailSubject, SmtpEncryption},
+    adapters::{http::HttpEmailSender, maildir::MaildirEmailSender, smtp::SmtpEmailSender},
+    entities::{
+        EmailAddress, EmailFrom, EmailMessage, EmailSubject, HttpApiConfig, SmtpEncryption,
+    },
+    error::EmailError,
     ports::EmailSender,
 };
 
 use crate::domain::{
     common::{email::EmailPort, entities::app_errors::CoreError},
-    realm::entities::SmtpConfig,
+    realm::entities::{EmailProvider, SmtpConfig},
 };
 
+/// Sends through whichever provider the realm's email config selects.
 #[derive(Debug, Clone, Default)]
-pub struct SmtpEmailPort;
+pub struct ProviderEmailPort {
+    http_client: reqwest::Client,
+    maildir_path: Option<PathBuf>,
+}
 
-impl SmtpEmailPort {
-    pub fn new() -> Self {
-        Self
+impl ProviderEmailPort {
+    pub fn new(maildir_path: Option<PathBuf>) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            maildir_path,
+        }
     }
-}
 
-impl EmailPort for SmtpEmailPort {
-    async fn send_email(
+    async fn send_smtp(
         &self,
         config: &SmtpConfig,
-        to_email: &str,
-        subject: &str,
-        body: &str,
-        html_body: Option<String>,
-    ) -> Result<(), CoreError> {
+        message: EmailMessage,
+    ) -> Result<(), EmailError> {
         let encryption = match &config.encryption {
             crate::domain::realm::entities::SmtpEncryption::Tls => SmtpEncryption::Tls,
             crate::domain::realm::entities::SmtpEncryption::StartTls => SmtpEncryption::StartTls,
             crate::domain::realm::entities::SmtpEncryption::None => SmtpEncryption::None,
         };
 
-        let sender = SmtpEmailSender::with_config(
+        SmtpEmailSender::with_config(
             &config.host,
             config.port,
             &config.username,
             &config.password,
             &encryption,
-        )
-        .map_err(|e| CoreError::External(format!("Failed to create SMTP sender: {e}")))?;
+        )?
+        .send(message)
+        .await
+    }
+}
 
+impl EmailPort for ProviderEmailPort {
+    async fn send_email(
+        &self,
+        config: &SmtpConfig,
+        to_email: &str,
+        subject: &str,
+        body: &str,
+        html_body: Option<String>,
+    ) -> Result<(), CoreError> {
         let from_email = EmailAddress::new(config.from_email.clone())
             .map_err(|e| CoreError::External(format!("Invalid from email: {e}")))?;
         let from = EmailFrom::new(config.from_name.clone(), from_email)
@@ -54,11 +73,100 @@ impl EmailPort for SmtpEmailPort {
         let message = EmailMessage::new(from, vec![to], email_subject, body.to_string(), html_body)
             .map_err(|e| CoreError::External(format!("Failed to build email: {e}")))?;
 
-        sender
-            .send(message)
+        let result = match &config.provider {
+            EmailProvider::Smtp => self.send_smtp(config, message).await,
+            EmailProvider::HttpApi(settings) => {
+                HttpEmailSender::with_client(
+                    self.http_client.clone(),
+                    HttpApiConfig {
+                        url: settings.url.clone(),
+                        headers: settings
+                            .headers
+                            .iter()
+                            .map(|(name, value)| (name.clone(), value.clone()))
+                            .collect(),
+                        body_template: settings.body_template.clone(),
+                    },
+                )
+                .send(message)
+                .await
+            }
+            EmailProvider::Maildir => {
+                let root = self.maildir_path.as_ref().ok_or_else(|| {
+                    CoreError::Configuration(
+                        "maildir email provider selected but no maildir path is configured"
+                            .to_string(),
+                    )
+                })?;
+
+                MaildirEmailSender::new(root.join(config.realm_id.to_string()))
+                    .send(message)
+                    .await
+            }
+        };
+
+        result.map_err(|e| match e {
+            EmailError::Rejected(reason) => CoreError::EmailRejected(reason),
+            e => CoreError::External(format!("Failed to send email: {e}")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::realm::entities::SmtpEncryption;
+
+    fn maildir_config() -> SmtpConfig {
+        SmtpConfig {
+            id: Uuid::new_v4(),
+            realm_id: Uuid::new_v4(),
+            host: String::new(),
+            port: 587,
+            username: String::new(),
+            password: String::new(),
+            from_email: "noreply@example.com".to_string(),
+            from_name: "FerrisKey".to_string(),
+            encryption: SmtpEncryption::StartTls,
+            provider: EmailProvider::Maildir,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn maildir_provider_writes_under_realm_directory() {
+        let root = std::env::temp_dir().join(format!("ferriskey-maildir-{}", Uuid::new_v4()));
+        let config = maildir_config();
+
+        ProviderEmailPort::new(Some(root.clone()))
+            .send_email(&config, "alice@example.com", "Hello", "Body", None)
             .await
-            .map_err(|e| CoreError::External(format!("Failed to send email: {e}")))?;
+            .expect("send should succeed");
+
+        let delivered = std::fs::read_dir(root.join(config.realm_id.to_string()).join("new"))
+            .expect("realm maildir should exist")
+            .count();
+        assert_eq!(delivered, 1);
+
+        std::fs::remove_dir_all(&root).expect("cleanup");
+    }
+
+    #[tokio::test]
+    async fn maildir_provider_requires_configured_path() {
+        let result = ProviderEmailPort::new(None)
+            .send_email(
+                &maildir_config(),
+                "alice@example.com",
+                "Hello",
+                "Body",
+                None,
+            )
+            .await;
 
-        Ok(())
+        assert!(matches!(result, Err(CoreError::Configuration(_))));
     }
 }
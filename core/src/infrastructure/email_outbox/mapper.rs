@@ -0,0 +1,24 @@
+use crate::domain::email_outbox::entities::{OutboxEmail, OutboxEmailStatus};
+use crate::entity::email_outbox;
+
+impl TryFrom<email_outbox::Model> for OutboxEmail {
+    type Error = String;
+
+    fn try_from(model: email_outbox::Model) -> Result<Self, Self::Error> {
+        Ok(OutboxEmail {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            recipient: model.recipient,
+            subject: model.subject,
+            body: model.body,
+            html_body: model.html_body,
+            status: model.status.parse::<OutboxEmailStatus>()?,
+            attempts: model.attempts.max(0) as u32,
+            next_attempt_at: model.next_attempt_at.to_utc(),
+            last_error: model.last_error,
+            sent_at: model.sent_at.map(|sent_at| sent_at.to_utc()),
+            created_at: model.created_at.to_utc(),
+            updated_at: model.updated_at.to_utc(),
+        })
+    }
+}
@@ -0,0 +1,3 @@
+mod mapper;
+pub mod repositories;
+pub mod scheduler;
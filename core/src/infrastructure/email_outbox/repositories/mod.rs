@@ -0,0 +1,3 @@
+pub mod outbox_email_postgres_repository;
+
+pub use outbox_email_postgres_repository::PostgresOutboxEmailRepository;
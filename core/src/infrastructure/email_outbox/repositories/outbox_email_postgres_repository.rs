@@ -0,0 +1,225 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, DbBackend, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Statement, prelude::DateTimeWithTimeZone, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::email_outbox::{
+    entities::{OutboxEmail, OutboxEmailStatus},
+    ports::OutboxEmailRepository,
+};
+use crate::domain::realm::entities::RealmId;
+use crate::entity::email_outbox::{ActiveModel, Column, Entity, Model};
+
+#[derive(Debug, Clone)]
+pub struct PostgresOutboxEmailRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresOutboxEmailRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Applies `update` to the email, bumping its `updated_at`.
+    async fn update(
+        &self,
+        email_id: Uuid,
+        update: sea_orm::UpdateMany<Entity>,
+    ) -> Result<(), CoreError> {
+        update
+            .col_expr(
+                Column::UpdatedAt,
+                Expr::value(DateTimeWithTimeZone::from(Utc::now())),
+            )
+            .filter(Column::Id.eq(email_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+fn into_email(model: Model) -> Result<OutboxEmail, CoreError> {
+    OutboxEmail::try_from(model).map_err(|e| {
+        tracing::error!("Failed to read outbox email: {}", e);
+        CoreError::InternalServerError
+    })
+}
+
+fn into_emails(models: Vec<Model>) -> Result<Vec<OutboxEmail>, CoreError> {
+    models.into_iter().map(into_email).collect()
+}
+
+impl OutboxEmailRepository for PostgresOutboxEmailRepository {
+    async fn enqueue(&self, email: OutboxEmail) -> Result<OutboxEmail, CoreError> {
+        let model = ActiveModel {
+            id: Set(email.id),
+            realm_id: Set(email.realm_id.into()),
+            recipient: Set(email.recipient),
+            subject: Set(email.subject),
+            body: Set(email.body),
+            html_body: Set(email.html_body),
+            status: Set(email.status.to_string()),
+            attempts: Set(email.attempts as i32),
+            next_attempt_at: Set(email.next_attempt_at.into()),
+            last_error: Set(email.last_error),
+            sent_at: Set(email.sent_at.map(Into::into)),
+            created_at: Set(email.created_at.into()),
+            updated_at: Set(email.updated_at.into()),
+        };
+
+        let model = Entity::insert(model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        into_email(model)
+    }
+
+    async fn get_by_id(
+        &self,
+        realm_id: RealmId,
+        email_id: Uuid,
+    ) -> Result<Option<OutboxEmail>, CoreError> {
+        Entity::find_by_id(email_id)
+            .filter(Column::RealmId.eq(Uuid::from(realm_id)))
+            .one(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?
+            .map(into_email)
+            .transpose()
+    }
+
+    async fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+        status: Option<OutboxEmailStatus>,
+        limit: u64,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        let mut query = Entity::find().filter(Column::RealmId.eq(Uuid::from(realm_id)));
+        if let Some(status) = status {
+            query = query.filter(Column::Status.eq(status.to_string()));
+        }
+
+        let models = query
+            .order_by_desc(Column::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        into_emails(models)
+    }
+
+    async fn claim_due(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<OutboxEmail>, CoreError> {
+        // SKIP LOCKED lets replicas claim disjoint batches concurrently.
+        let models = Entity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE email_outbox \
+                 SET attempts = attempts + 1, next_attempt_at = $2, updated_at = $1 \
+                 WHERE id IN ( \
+                     SELECT id FROM email_outbox \
+                     WHERE status = 'queued' AND next_attempt_at <= $1 \
+                     ORDER BY next_attempt_at \
+                     LIMIT $3 \
+                     FOR UPDATE SKIP LOCKED \
+                 ) \
+                 RETURNING *",
+                [
+                    DateTimeWithTimeZone::from(now).into(),
+                    DateTimeWithTimeZone::from(lease_until).into(),
+                    (limit as i64).into(),
+                ],
+            ))
+            .all(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        into_emails(models)
+    }
+
+    async fn mark_sent(&self, email_id: Uuid, sent_at: DateTime<Utc>) -> Result<(), CoreError> {
+        self.update(
+            email_id,
+            Entity::update_many()
+                .col_expr(
+                    Column::Status,
+                    Expr::value(OutboxEmailStatus::Sent.to_string()),
+                )
+                .col_expr(
+                    Column::SentAt,
+                    Expr::value(DateTimeWithTimeZone::from(sent_at)),
+                )
+                .col_expr(Column::LastError, Expr::value(Option::<String>::None)),
+        )
+        .await
+    }
+
+    async fn schedule_retry(
+        &self,
+        email_id: Uuid,
+        error: String,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        self.update(
+            email_id,
+            Entity::update_many()
+                .col_expr(
+                    Column::NextAttemptAt,
+                    Expr::value(DateTimeWithTimeZone::from(next_attempt_at)),
+                )
+                .col_expr(Column::LastError, Expr::value(error)),
+        )
+        .await
+    }
+
+    async fn mark_undeliverable(
+        &self,
+        email_id: Uuid,
+        status: OutboxEmailStatus,
+        error: String,
+    ) -> Result<(), CoreError> {
+        self.update(
+            email_id,
+            Entity::update_many()
+                .col_expr(Column::Status, Expr::value(status.to_string()))
+                .col_expr(Column::LastError, Expr::value(error)),
+        )
+        .await
+    }
+
+    async fn requeue(
+        &self,
+        realm_id: RealmId,
+        email_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Option<OutboxEmail>, CoreError> {
+        let now = DateTimeWithTimeZone::from(now);
+        let models = Entity::update_many()
+            .col_expr(
+                Column::Status,
+                Expr::value(OutboxEmailStatus::Queued.to_string()),
+            )
+            .col_expr(Column::Attempts, Expr::value(0))
+            .col_expr(Column::NextAttemptAt, Expr::value(now))
+            .col_expr(Column::LastError, Expr::value(Option::<String>::None))
+            .col_expr(Column::UpdatedAt, Expr::value(now))
+            .filter(Column::Id.eq(email_id))
+            .filter(Column::RealmId.eq(Uuid::from(realm_id)))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        models.into_iter().next().map(into_email).transpose()
+    }
+}
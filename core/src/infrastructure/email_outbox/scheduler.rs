@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::domain::email_outbox::ports::EmailOutboxService;
+
+/// How often the outbox is polled, which bounds how late a queued email is
+/// first attempted.
+pub const EMAIL_OUTBOX_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Periodically delivers the queued emails whose next attempt is due.
+pub async fn email_outbox_task<S>(service: S, interval: Duration)
+where
+    S: EmailOutboxService,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        // Drain the backlog batch by batch; failed emails are pushed to a
+        // later attempt, so this stops once nothing is due.
+        loop {
+            match service.deliver_due_emails(Utc::now()).await {
+                Ok(0) => break,
+                Ok(attempted) => tracing::debug!("Email outbox: attempted {attempted} emails"),
+                Err(e) => {
+                    tracing::error!("Email outbox: failed to deliver emails: {e}");
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use chrono::{TimeZone, Utc};
 
 use crate::{
-    domain::email_template::entities::{EmailTemplate, EmailType},
-    entity::email_templates::Model as EmailTemplateModel,
+    domain::email_template::entities::{
+        EmailTemplate, EmailTemplateLocalization, EmailTemplateVersion, EmailType,
+    },
+    entity::{
+        email_template_localizations::Model as EmailTemplateLocalizationModel,
+        email_template_versions::Model as EmailTemplateVersionModel,
+        email_templates::Model as EmailTemplateModel,
+    },
 };
 
 impl From<EmailTemplateModel> for EmailTemplate {
@@ -46,3 +52,32 @@ impl From<&EmailTemplateModel> for EmailTemplate {
         }
     }
 }
+
+impl From<EmailTemplateLocalizationModel> for EmailTemplateLocalization {
+    fn from(value: EmailTemplateLocalizationModel) -> Self {
+        Self {
+            id: value.id,
+            template_id: value.template_id,
+            locale: value.locale,
+            structure: value.structure,
+            mjml: value.mjml,
+            created_at: Utc.from_utc_datetime(&value.created_at),
+            updated_at: Utc.from_utc_datetime(&value.updated_at),
+        }
+    }
+}
+
+impl From<EmailTemplateVersionModel> for EmailTemplateVersion {
+    fn from(value: EmailTemplateVersionModel) -> Self {
+        Self {
+            id: value.id,
+            template_id: value.template_id,
+            version: value.version,
+            name: value.name,
+            structure: value.structure,
+            mjml: value.mjml,
+            created_by: value.created_by,
+            created_at: Utc.from_utc_datetime(&value.created_at),
+        }
+    }
+}
@@ -315,4 +315,15 @@ mod tests {
         let html = renderer.render_to_html(&mjml).unwrap();
         assert!(html.contains("Hello {{user.first_name}}"));
     }
+
+    #[test]
+    fn test_default_templates_render() {
+        use crate::domain::email_template::entities::EmailType;
+
+        let renderer = MjmlTemplateRenderer::new();
+        for mjml in EmailType::ALL.iter().filter_map(EmailType::default_mjml) {
+            let html = renderer.render_to_html(&mjml).unwrap();
+            assert!(html.contains("{{user.first_name}}"));
+        }
+    }
 }
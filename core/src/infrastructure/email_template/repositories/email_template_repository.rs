@@ -1,18 +1,77 @@
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set, TransactionTrait, sea_query::OnConflict,
+};
 use tracing::error;
 use uuid::Uuid;
 
 use crate::{
     domain::{
         common::{entities::app_errors::CoreError, generate_timestamp},
-        email_template::{entities::EmailTemplate, ports::EmailTemplateRepository},
+        email_template::{
+            entities::{EmailTemplate, EmailTemplateLocalization, EmailTemplateVersion},
+            ports::EmailTemplateRepository,
+        },
     },
-    entity::email_templates::{
-        ActiveModel as EmailTemplateActiveModel, Column as EmailTemplateColumn,
-        Entity as EmailTemplateEntity,
+    entity::{
+        email_template_localizations::{
+            ActiveModel as EmailTemplateLocalizationActiveModel,
+            Column as EmailTemplateLocalizationColumn, Entity as EmailTemplateLocalizationEntity,
+        },
+        email_template_versions::{
+            ActiveModel as EmailTemplateVersionActiveModel, Column as EmailTemplateVersionColumn,
+            Entity as EmailTemplateVersionEntity,
+        },
+        email_templates::{
+            ActiveModel as EmailTemplateActiveModel, Column as EmailTemplateColumn,
+            Entity as EmailTemplateEntity, Model as EmailTemplateModel,
+        },
     },
 };
 
+fn transaction_error(e: sea_orm::DbErr) -> CoreError {
+    error!("Email template transaction failed: {}", e);
+    CoreError::InternalServerError
+}
+
+/// Snapshots `template` as its next version.
+async fn insert_version<C: ConnectionTrait>(
+    db: &C,
+    template: &EmailTemplateModel,
+    created_by: Option<Uuid>,
+) -> Result<(), CoreError> {
+    let latest = EmailTemplateVersionEntity::find()
+        .filter(EmailTemplateVersionColumn::TemplateId.eq(template.id))
+        .order_by_desc(EmailTemplateVersionColumn::Version)
+        .one(db)
+        .await
+        .map_err(|e| {
+            error!("Failed to find latest email template version: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+    let (_, timestamp) = generate_timestamp();
+    let model = EmailTemplateVersionActiveModel {
+        id: Set(Uuid::new_v7(timestamp)),
+        template_id: Set(template.id),
+        version: Set(latest.map_or(1, |v| v.version + 1)),
+        name: Set(template.name.clone()),
+        structure: Set(template.structure.clone()),
+        mjml: Set(template.mjml.clone()),
+        created_by: Set(created_by),
+        created_at: Set(template.updated_at),
+    };
+
+    EmailTemplateVersionEntity::insert(model)
+        .exec(db)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            error!("Failed to record email template version: {}", e);
+            CoreError::InternalServerError
+        })
+}
+
 #[derive(Debug, Clone)]
 pub struct PostgresEmailTemplateRepository {
     pub db: DatabaseConnection,
@@ -55,6 +114,7 @@ impl EmailTemplateRepository for PostgresEmailTemplateRepository {
         email_type: String,
         structure: serde_json::Value,
         mjml: String,
+        created_by: Option<Uuid>,
     ) -> Result<EmailTemplate, CoreError> {
         let (_, timestamp) = generate_timestamp();
         let id = Uuid::new_v7(timestamp);
@@ -70,14 +130,20 @@ impl EmailTemplateRepository for PostgresEmailTemplateRepository {
             updated_at: Set(chrono::Utc::now().naive_utc()),
         };
 
-        EmailTemplateEntity::insert(model)
-            .exec_with_returning(&self.db)
+        let txn = self.db.begin().await.map_err(transaction_error)?;
+
+        let template = EmailTemplateEntity::insert(model)
+            .exec_with_returning(&txn)
             .await
-            .map(EmailTemplate::from)
             .map_err(|e| {
                 error!("Failed to create email template: {}", e);
                 CoreError::InternalServerError
-            })
+            })?;
+        insert_version(&txn, &template, created_by).await?;
+
+        txn.commit().await.map_err(transaction_error)?;
+
+        Ok(EmailTemplate::from(template))
     }
 
     async fn update(
@@ -86,9 +152,12 @@ impl EmailTemplateRepository for PostgresEmailTemplateRepository {
         name: String,
         structure: serde_json::Value,
         mjml: String,
+        created_by: Option<Uuid>,
     ) -> Result<EmailTemplate, CoreError> {
+        let txn = self.db.begin().await.map_err(transaction_error)?;
+
         let existing = EmailTemplateEntity::find_by_id(template_id)
-            .one(&self.db)
+            .one(&txn)
             .await
             .map_err(|e| {
                 error!("Failed to find email template for update: {}", e);
@@ -102,14 +171,15 @@ impl EmailTemplateRepository for PostgresEmailTemplateRepository {
         active.mjml = Set(mjml);
         active.updated_at = Set(chrono::Utc::now().naive_utc());
 
-        active
-            .update(&self.db)
-            .await
-            .map(EmailTemplate::from)
-            .map_err(|e| {
-                error!("Failed to update email template: {}", e);
-                CoreError::InternalServerError
-            })
+        let template = active.update(&txn).await.map_err(|e| {
+            error!("Failed to update email template: {}", e);
+            CoreError::InternalServerError
+        })?;
+        insert_version(&txn, &template, created_by).await?;
+
+        txn.commit().await.map_err(transaction_error)?;
+
+        Ok(EmailTemplate::from(template))
     }
 
     async fn delete(&self, template_id: Uuid) -> Result<(), CoreError> {
@@ -122,4 +192,117 @@ impl EmailTemplateRepository for PostgresEmailTemplateRepository {
                 CoreError::InternalServerError
             })
     }
+
+    async fn list_localizations(
+        &self,
+        template_id: Uuid,
+    ) -> Result<Vec<EmailTemplateLocalization>, CoreError> {
+        EmailTemplateLocalizationEntity::find()
+            .filter(EmailTemplateLocalizationColumn::TemplateId.eq(template_id))
+            .order_by_asc(EmailTemplateLocalizationColumn::Locale)
+            .all(&self.db)
+            .await
+            .map(|models| {
+                models
+                    .into_iter()
+                    .map(EmailTemplateLocalization::from)
+                    .collect()
+            })
+            .map_err(|e| {
+                error!("Failed to fetch email template localizations: {}", e);
+                CoreError::InternalServerError
+            })
+    }
+
+    async fn upsert_localization(
+        &self,
+        template_id: Uuid,
+        locale: String,
+        structure: serde_json::Value,
+        mjml: String,
+    ) -> Result<EmailTemplateLocalization, CoreError> {
+        let (_, timestamp) = generate_timestamp();
+        let now = chrono::Utc::now().naive_utc();
+
+        let model = EmailTemplateLocalizationActiveModel {
+            id: Set(Uuid::new_v7(timestamp)),
+            template_id: Set(template_id),
+            locale: Set(locale),
+            structure: Set(structure),
+            mjml: Set(mjml),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        EmailTemplateLocalizationEntity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    EmailTemplateLocalizationColumn::TemplateId,
+                    EmailTemplateLocalizationColumn::Locale,
+                ])
+                .update_columns([
+                    EmailTemplateLocalizationColumn::Structure,
+                    EmailTemplateLocalizationColumn::Mjml,
+                    EmailTemplateLocalizationColumn::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map(EmailTemplateLocalization::from)
+            .map_err(|e| {
+                error!("Failed to upsert email template localization: {}", e);
+                CoreError::InternalServerError
+            })
+    }
+
+    async fn delete_localization(
+        &self,
+        template_id: Uuid,
+        locale: String,
+    ) -> Result<bool, CoreError> {
+        EmailTemplateLocalizationEntity::delete_many()
+            .filter(EmailTemplateLocalizationColumn::TemplateId.eq(template_id))
+            .filter(EmailTemplateLocalizationColumn::Locale.eq(locale))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected > 0)
+            .map_err(|e| {
+                error!("Failed to delete email template localization: {}", e);
+                CoreError::InternalServerError
+            })
+    }
+
+    async fn list_versions(
+        &self,
+        template_id: Uuid,
+    ) -> Result<Vec<EmailTemplateVersion>, CoreError> {
+        EmailTemplateVersionEntity::find()
+            .filter(EmailTemplateVersionColumn::TemplateId.eq(template_id))
+            .order_by_desc(EmailTemplateVersionColumn::Version)
+            .all(&self.db)
+            .await
+            .map(|models| models.into_iter().map(EmailTemplateVersion::from).collect())
+            .map_err(|e| {
+                error!("Failed to fetch email template versions: {}", e);
+                CoreError::InternalServerError
+            })
+    }
+
+    async fn get_version(
+        &self,
+        template_id: Uuid,
+        version: i32,
+    ) -> Result<Option<EmailTemplateVersion>, CoreError> {
+        EmailTemplateVersionEntity::find()
+            .filter(EmailTemplateVersionColumn::TemplateId.eq(template_id))
+            .filter(EmailTemplateVersionColumn::Version.eq(version))
+            .one(&self.db)
+            .await
+            .map(|model| model.map(EmailTemplateVersion::from))
+            .map_err(|e| {
+                error!("Failed to get email template version: {}", e);
+                CoreError::InternalServerError
+            })
+    }
 }
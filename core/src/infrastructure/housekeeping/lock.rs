@@ -0,0 +1,71 @@
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DatabaseTransaction, Statement,
+    TransactionTrait,
+};
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    housekeeping::{
+        entities::HousekeepingJob,
+        ports::{HousekeepingLock, HousekeepingLockGuard},
+    },
+};
+
+/// Postgres advisory locks scoped to a transaction: the lock lives on the
+/// transaction's connection and is released when it commits, or when the
+/// connection drops if the replica dies mid-job.
+#[derive(Debug, Clone)]
+pub struct PostgresHousekeepingLock {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresHousekeepingLock {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+pub struct PostgresHousekeepingLockGuard {
+    transaction: DatabaseTransaction,
+}
+
+impl HousekeepingLock for PostgresHousekeepingLock {
+    type Guard = PostgresHousekeepingLockGuard;
+
+    async fn try_acquire(&self, job: HousekeepingJob) -> Result<Option<Self::Guard>, CoreError> {
+        let transaction = self.db.begin().await.map_err(|e| {
+            tracing::error!("Failed to open housekeeping lock transaction: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let locked = transaction
+            .query_one(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                "SELECT pg_try_advisory_xact_lock($1) AS locked",
+                [job.lock_key().into()],
+            ))
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to take housekeeping lock for {}: {}", job, e);
+                CoreError::InternalServerError
+            })?
+            .and_then(|row| row.try_get::<bool>("", "locked").ok())
+            .unwrap_or(false);
+
+        if !locked {
+            // Dropping the transaction rolls it back.
+            return Ok(None);
+        }
+
+        Ok(Some(PostgresHousekeepingLockGuard { transaction }))
+    }
+}
+
+impl HousekeepingLockGuard for PostgresHousekeepingLockGuard {
+    async fn release(self) -> Result<(), CoreError> {
+        self.transaction.commit().await.map_err(|e| {
+            tracing::error!("Failed to release housekeeping lock: {}", e);
+            CoreError::InternalServerError
+        })
+    }
+}
@@ -0,0 +1,15 @@
+use crate::domain::housekeeping::entities::{HousekeepingJob, HousekeepingRetentionPolicy};
+use crate::entity::housekeeping_retention_policies;
+
+impl TryFrom<housekeeping_retention_policies::Model> for HousekeepingRetentionPolicy {
+    type Error = String;
+
+    fn try_from(model: housekeeping_retention_policies::Model) -> Result<Self, Self::Error> {
+        Ok(HousekeepingRetentionPolicy {
+            realm_id: model.realm_id.into(),
+            job: model.job.parse::<HousekeepingJob>()?,
+            retention_hours: model.retention_hours.map(|hours| hours as u32),
+            updated_at: model.updated_at.to_utc(),
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod lock;
+mod mapper;
+pub mod repositories;
+pub mod scheduler;
@@ -0,0 +1,105 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::housekeeping::{
+    entities::{HousekeepingJob, HousekeepingRetentionPolicy},
+    ports::HousekeepingRetentionRepository,
+};
+use crate::domain::realm::entities::RealmId;
+use crate::entity::housekeeping_retention_policies::{ActiveModel, Column, Entity, Model};
+
+#[derive(Debug, Clone)]
+pub struct PostgresHousekeepingRetentionRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresHousekeepingRetentionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+/// Rows naming a job this version does not know are skipped rather than
+/// failing the whole listing.
+fn into_policies(models: Vec<Model>) -> Vec<HousekeepingRetentionPolicy> {
+    models
+        .into_iter()
+        .filter_map(|model| match HousekeepingRetentionPolicy::try_from(model) {
+            Ok(policy) => Some(policy),
+            Err(e) => {
+                tracing::warn!("Ignoring housekeeping retention policy: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+impl HousekeepingRetentionRepository for PostgresHousekeepingRetentionRepository {
+    async fn list_by_job(
+        &self,
+        job: HousekeepingJob,
+    ) -> Result<Vec<HousekeepingRetentionPolicy>, CoreError> {
+        let models = Entity::find()
+            .filter(Column::Job.eq(job.name()))
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list housekeeping retention policies: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(into_policies(models))
+    }
+
+    async fn list_by_realm(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Vec<HousekeepingRetentionPolicy>, CoreError> {
+        let models = Entity::find()
+            .filter(Column::RealmId.eq(Uuid::from(realm_id)))
+            .order_by_asc(Column::Job)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list housekeeping retention policies: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(into_policies(models))
+    }
+
+    async fn upsert(
+        &self,
+        policy: HousekeepingRetentionPolicy,
+    ) -> Result<HousekeepingRetentionPolicy, CoreError> {
+        let model = ActiveModel {
+            realm_id: Set(policy.realm_id.into()),
+            job: Set(policy.job.name().to_string()),
+            retention_hours: Set(policy.retention_hours.map(|hours| hours as i32)),
+            updated_at: Set(Utc::now().into()),
+        };
+
+        let model = Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([Column::RealmId, Column::Job])
+                    .update_columns([Column::RetentionHours, Column::UpdatedAt])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to upsert housekeeping retention policy: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        HousekeepingRetentionPolicy::try_from(model).map_err(|e| {
+            tracing::error!("Failed to read housekeeping retention policy: {}", e);
+            CoreError::InternalServerError
+        })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod housekeeping_postgres_repository;
+pub mod housekeeping_retention_postgres_repository;
+
+pub use housekeeping_postgres_repository::PostgresHousekeepingRepository;
+pub use housekeeping_retention_postgres_repository::PostgresHousekeepingRetentionRepository;
@@ -0,0 +1,349 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    housekeeping::{
+        entities::{HousekeepingJob, HousekeepingRun, HousekeepingRunOutcome},
+        ports::{
+            HousekeepingLock, HousekeepingLockGuard, HousekeepingRepository,
+            HousekeepingRetentionRepository,
+        },
+        registry::HousekeepingRegistry,
+        value_objects::{HousekeepingConfig, HousekeepingJobConfig, PurgeScope},
+    },
+};
+
+/// Starts one task per enabled housekeeping job.
+pub fn spawn_housekeeping<L, H, HR>(
+    config: &HousekeepingConfig,
+    lock: Arc<L>,
+    repository: Arc<H>,
+    retention_repository: Arc<HR>,
+    registry: HousekeepingRegistry,
+) where
+    L: HousekeepingLock + 'static,
+    H: HousekeepingRepository + 'static,
+    HR: HousekeepingRetentionRepository + 'static,
+{
+    if !config.enabled {
+        tracing::info!("Housekeeping: disabled");
+        return;
+    }
+
+    for job in HousekeepingJob::ALL {
+        let job_config = config.job(job);
+        if !job_config.enabled {
+            continue;
+        }
+
+        tokio::spawn(housekeeping_task(
+            job,
+            job_config,
+            config.batch_size,
+            lock.clone(),
+            repository.clone(),
+            retention_repository.clone(),
+            registry.clone(),
+        ));
+    }
+}
+
+async fn housekeeping_task<L, H, HR>(
+    job: HousekeepingJob,
+    config: HousekeepingJobConfig,
+    batch_size: u64,
+    lock: Arc<L>,
+    repository: Arc<H>,
+    retention_repository: Arc<HR>,
+    registry: HousekeepingRegistry,
+) where
+    L: HousekeepingLock,
+    H: HousekeepingRepository,
+    HR: HousekeepingRetentionRepository,
+{
+    let mut ticker = tokio::time::interval(config.interval.max(Duration::from_secs(1)));
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        registry.mark_running(job);
+        let run = run_job(
+            job,
+            &config,
+            batch_size,
+            lock.as_ref(),
+            repository.as_ref(),
+            retention_repository.as_ref(),
+        )
+        .await;
+
+        match (&run.outcome, &run.error) {
+            (HousekeepingRunOutcome::Failed, Some(error)) => {
+                tracing::error!("Housekeeping: {job} failed: {error}")
+            }
+            (HousekeepingRunOutcome::Succeeded, _) if run.purged > 0 => {
+                tracing::info!("Housekeeping: {job} purged {} rows", run.purged)
+            }
+            _ => {}
+        }
+
+        record_metrics(job, &run);
+        registry.record(job, run);
+    }
+}
+
+/// Runs a job once under its cluster lock.
+pub async fn run_job<L, H, HR>(
+    job: HousekeepingJob,
+    config: &HousekeepingJobConfig,
+    batch_size: u64,
+    lock: &L,
+    repository: &H,
+    retention_repository: &HR,
+) -> HousekeepingRun
+where
+    L: HousekeepingLock,
+    H: HousekeepingRepository,
+    HR: HousekeepingRetentionRepository,
+{
+    let started_at = Utc::now();
+    let finish = |outcome, purged, error| HousekeepingRun {
+        started_at,
+        finished_at: Utc::now(),
+        outcome,
+        purged,
+        error,
+    };
+
+    let guard = match lock.try_acquire(job).await {
+        Ok(Some(guard)) => guard,
+        Ok(None) => return finish(HousekeepingRunOutcome::Skipped, 0, None),
+        Err(e) => return finish(HousekeepingRunOutcome::Failed, 0, Some(e.to_string())),
+    };
+
+    let result = purge(
+        job,
+        config,
+        batch_size,
+        repository,
+        retention_repository,
+        started_at,
+    )
+    .await;
+
+    if let Err(e) = guard.release().await {
+        tracing::warn!("Housekeeping: failed to release the {job} lock: {e}");
+    }
+
+    match result {
+        Ok(purged) => finish(HousekeepingRunOutcome::Succeeded, purged, None),
+        Err(e) => finish(HousekeepingRunOutcome::Failed, 0, Some(e.to_string())),
+    }
+}
+
+async fn purge<H, HR>(
+    job: HousekeepingJob,
+    config: &HousekeepingJobConfig,
+    batch_size: u64,
+    repository: &H,
+    retention_repository: &HR,
+    now: DateTime<Utc>,
+) -> Result<u64, CoreError>
+where
+    H: HousekeepingRepository,
+    HR: HousekeepingRetentionRepository,
+{
+    let overrides = retention_repository.list_by_job(job).await?;
+
+    // Realms with an override are left to their own window below.
+    let mut purged = repository
+        .purge(
+            job,
+            PurgeScope::AllExcept(overrides.iter().map(|policy| policy.realm_id).collect()),
+            cutoff(now, config.retention),
+            batch_size,
+        )
+        .await?;
+
+    for policy in overrides {
+        let Some(retention) = policy.retention() else {
+            continue;
+        };
+
+        // One failing realm must not stop the others from being purged.
+        match repository
+            .purge(
+                job,
+                PurgeScope::Realm(policy.realm_id),
+                cutoff(now, retention),
+                batch_size,
+            )
+            .await
+        {
+            Ok(count) => purged += count,
+            Err(e) => tracing::error!(
+                "Housekeeping: failed to purge {job} in realm {:?}: {e}",
+                policy.realm_id
+            ),
+        }
+    }
+
+    Ok(purged)
+}
+
+fn cutoff(now: DateTime<Utc>, retention: Duration) -> DateTime<Utc> {
+    TimeDelta::from_std(retention)
+        .ok()
+        .and_then(|retention| now.checked_sub_signed(retention))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
+fn record_metrics(job: HousekeepingJob, run: &HousekeepingRun) {
+    let outcome = match run.outcome {
+        HousekeepingRunOutcome::Succeeded => "succeeded",
+        HousekeepingRunOutcome::Failed => "failed",
+        HousekeepingRunOutcome::Skipped => "skipped",
+    };
+
+    metrics::counter!("housekeeping_runs_total", "job" => job.name(), "outcome" => outcome)
+        .increment(1);
+    metrics::counter!("housekeeping_purged_rows_total", "job" => job.name()).increment(run.purged);
+    metrics::histogram!("housekeeping_run_duration_seconds", "job" => job.name()).record(
+        (run.finished_at - run.started_at)
+            .to_std()
+            .unwrap_or_default()
+            .as_secs_f64(),
+    );
+    if run.outcome == HousekeepingRunOutcome::Succeeded {
+        metrics::gauge!("housekeeping_last_success_timestamp_seconds", "job" => job.name())
+            .set(run.finished_at.timestamp() as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{
+        housekeeping::{
+            entities::HousekeepingRetentionPolicy,
+            ports::{MockHousekeepingRepository, MockHousekeepingRetentionRepository},
+        },
+        realm::entities::RealmId,
+    };
+
+    struct FakeLock {
+        held_elsewhere: bool,
+        released: Arc<AtomicBool>,
+    }
+
+    struct FakeGuard(Arc<AtomicBool>);
+
+    impl HousekeepingLock for FakeLock {
+        type Guard = FakeGuard;
+
+        async fn try_acquire(&self, _job: HousekeepingJob) -> Result<Option<FakeGuard>, CoreError> {
+            Ok((!self.held_elsewhere).then(|| FakeGuard(self.released.clone())))
+        }
+    }
+
+    impl HousekeepingLockGuard for FakeGuard {
+        async fn release(self) -> Result<(), CoreError> {
+            self.0.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn config() -> HousekeepingJobConfig {
+        HousekeepingJobConfig {
+            enabled: true,
+            interval: Duration::from_secs(60),
+            retention: Duration::from_secs(24 * 3600),
+        }
+    }
+
+    #[tokio::test]
+    async fn realm_overrides_are_purged_with_their_own_window() {
+        let custom = RealmId::from(Uuid::new_v4());
+        let forever = RealmId::from(Uuid::new_v4());
+
+        let mut retention_repository = MockHousekeepingRetentionRepository::new();
+        retention_repository
+            .expect_list_by_job()
+            .with(eq(HousekeepingJob::AuthSessions))
+            .returning(move |job| {
+                let policies = vec![
+                    HousekeepingRetentionPolicy::new(custom, job, Some(1)),
+                    HousekeepingRetentionPolicy::new(forever, job, None),
+                ];
+                Box::pin(async move { Ok(policies) })
+            });
+
+        let mut repository = MockHousekeepingRepository::new();
+        repository
+            .expect_purge()
+            .withf(move |_, scope, _, batch_size| {
+                *scope == PurgeScope::AllExcept(vec![custom, forever]) && *batch_size == 100
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(7) }));
+        repository
+            .expect_purge()
+            .withf(move |_, scope, cutoff, _| {
+                let age = Utc::now() - *cutoff;
+                *scope == PurgeScope::Realm(custom)
+                    && age > TimeDelta::minutes(59)
+                    && age < TimeDelta::minutes(61)
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(3) }));
+
+        let released = Arc::new(AtomicBool::new(false));
+        let lock = FakeLock {
+            held_elsewhere: false,
+            released: released.clone(),
+        };
+
+        let run = run_job(
+            HousekeepingJob::AuthSessions,
+            &config(),
+            100,
+            &lock,
+            &repository,
+            &retention_repository,
+        )
+        .await;
+
+        assert_eq!(run.outcome, HousekeepingRunOutcome::Succeeded);
+        assert_eq!(run.purged, 10);
+        assert!(released.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn job_is_skipped_while_another_replica_holds_the_lock() {
+        let lock = FakeLock {
+            held_elsewhere: true,
+            released: Arc::new(AtomicBool::new(false)),
+        };
+
+        let run = run_job(
+            HousekeepingJob::CompassFlows,
+            &config(),
+            100,
+            &lock,
+            &MockHousekeepingRepository::new(),
+            &MockHousekeepingRetentionRepository::new(),
+        )
+        .await;
+
+        assert_eq!(run.outcome, HousekeepingRunOutcome::Skipped);
+        assert_eq!(run.purged, 0);
+    }
+}
@@ -12,7 +12,13 @@ use crate::domain::abyss::identity_provider::{
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::domain::common::generate_uuid_v7;
 use crate::domain::realm::entities::RealmId;
-use crate::entity::identity_providers::{ActiveModel, Column, Entity as IdentityProviderEntity};
+use crate::domain::secrets::EnvelopeCipher;
+use crate::entity::identity_providers::{
+    ActiveModel, Column, Entity as IdentityProviderEntity, Model,
+};
+use crate::infrastructure::secrets::{
+    IDENTITY_PROVIDER_SECRET, cipher_error, open_json_secret, seal_json_secret,
+};
 
 /// PostgreSQL implementation of the IdentityProviderRepository trait
 ///
@@ -20,6 +26,7 @@ use crate::entity::identity_providers::{ActiveModel, Column, Entity as IdentityP
 #[derive(Debug, Clone)]
 pub struct PostgresIdentityProviderRepository {
     db: DatabaseConnection,
+    cipher: EnvelopeCipher,
 }
 
 impl PostgresIdentityProviderRepository {
@@ -27,8 +34,25 @@ impl PostgresIdentityProviderRepository {
     ///
     /// # Arguments
     /// * `db` - The database connection
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    /// * `cipher` - Seals the client secret stored in each provider config
+    pub fn new(db: DatabaseConnection, cipher: EnvelopeCipher) -> Self {
+        Self { db, cipher }
+    }
+
+    async fn seal(&self, mut config: serde_json::Value) -> Result<serde_json::Value, CoreError> {
+        seal_json_secret(&self.cipher, &mut config, IDENTITY_PROVIDER_SECRET)
+            .await
+            .map_err(|e| cipher_error("encrypt identity provider secret", e))?;
+
+        Ok(config)
+    }
+
+    async fn reveal(&self, mut model: Model) -> Result<IdentityProvider, CoreError> {
+        open_json_secret(&self.cipher, &mut model.config, IDENTITY_PROVIDER_SECRET)
+            .await
+            .map_err(|e| cipher_error("decrypt identity provider secret", e))?;
+
+        Ok(model.into())
     }
 }
 
@@ -53,7 +77,7 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
             add_read_token_role_on_create: Set(request.add_read_token_role_on_create),
             trust_email: Set(request.trust_email),
             link_only: Set(request.link_only),
-            config: Set(request.config),
+            config: Set(self.seal(request.config).await?),
             created_at: Set(now),
             updated_at: Set(now),
         };
@@ -63,7 +87,7 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
             CoreError::InternalServerError
         })?;
 
-        Ok(identity_provider.into())
+        self.reveal(identity_provider).await
     }
 
     #[instrument(skip(self), fields(identity_provider_id = %id))]
@@ -78,10 +102,12 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
             .map_err(|e| {
                 tracing::error!("Failed to get identity provider by id: {}", e);
                 CoreError::InternalServerError
-            })?
-            .map(IdentityProvider::from);
+            })?;
 
-        Ok(identity_provider)
+        match identity_provider {
+            Some(model) => Ok(Some(self.reveal(model).await?)),
+            None => Ok(None),
+        }
     }
 
     #[instrument(skip(self), fields(realm_id = ?realm_id, alias = %alias))]
@@ -98,10 +124,12 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
             .map_err(|e| {
                 tracing::error!("Failed to get identity provider by realm and alias: {}", e);
                 CoreError::InternalServerError
-            })?
-            .map(IdentityProvider::from);
+            })?;
 
-        Ok(identity_provider)
+        match identity_provider {
+            Some(model) => Ok(Some(self.reveal(model).await?)),
+            None => Ok(None),
+        }
     }
 
     #[instrument(skip(self), fields(realm_id = ?realm_id))]
@@ -126,12 +154,12 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
                 CoreError::InternalServerError
             })?;
 
-        let identity_providers: Vec<IdentityProvider> = identity_providers
-            .into_iter()
-            .map(IdentityProvider::from)
-            .collect();
+        let mut revealed = Vec::with_capacity(identity_providers.len());
+        for model in identity_providers {
+            revealed.push(self.reveal(model).await?);
+        }
 
-        Ok(identity_providers)
+        Ok(revealed)
     }
 
     #[instrument(skip(self, request), fields(identity_provider_id = %id))]
@@ -179,7 +207,7 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
             identity_provider.link_only = Set(link_only);
         }
         if let Some(config) = request.config {
-            identity_provider.config = Set(config);
+            identity_provider.config = Set(self.seal(config).await?);
         }
 
         identity_provider.updated_at = Set(chrono::Utc::now().fixed_offset());
@@ -189,7 +217,7 @@ impl IdentityProviderRepository for PostgresIdentityProviderRepository {
             CoreError::InternalServerError
         })?;
 
-        Ok(updated.into())
+        self.reveal(updated).await
     }
 
     #[instrument(skip(self), fields(identity_provider_id = %id))]
@@ -0,0 +1,30 @@
+use crate::domain::localization::entities::{MessageBundle, RealmLocalization};
+use crate::entity::{message_bundles, realm_localizations};
+
+impl TryFrom<realm_localizations::Model> for RealmLocalization {
+    type Error = String;
+
+    fn try_from(model: realm_localizations::Model) -> Result<Self, Self::Error> {
+        Ok(RealmLocalization {
+            realm_id: model.realm_id.into(),
+            default_locale: model.default_locale,
+            supported_locales: serde_json::from_value(model.supported_locales)
+                .map_err(|e| format!("invalid supported locales: {e}"))?,
+            updated_at: model.updated_at.to_utc(),
+        })
+    }
+}
+
+impl TryFrom<message_bundles::Model> for MessageBundle {
+    type Error = String;
+
+    fn try_from(model: message_bundles::Model) -> Result<Self, Self::Error> {
+        Ok(MessageBundle {
+            realm_id: model.realm_id.into(),
+            locale: model.locale,
+            messages: serde_json::from_value(model.messages)
+                .map_err(|e| format!("invalid messages: {e}"))?,
+            updated_at: model.updated_at.to_utc(),
+        })
+    }
+}
@@ -0,0 +1,163 @@
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::{entities::app_errors::CoreError, generate_uuid_v7},
+        localization::{
+            entities::{MessageBundle, RealmLocalization, USER_LOCALE_ATTRIBUTE},
+            ports::LocalizationRepository,
+        },
+        realm::entities::RealmId,
+    },
+    entity::{message_bundles, realm_localizations, user_attributes},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresLocalizationRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresLocalizationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {}: {}", context, e);
+    CoreError::InternalServerError
+}
+
+fn into_bundles(models: Vec<message_bundles::Model>) -> Result<Vec<MessageBundle>, CoreError> {
+    models
+        .into_iter()
+        .map(|model| {
+            MessageBundle::try_from(model).map_err(|e| database_error("read message bundle", e))
+        })
+        .collect()
+}
+
+impl LocalizationRepository for PostgresLocalizationRepository {
+    async fn get_settings(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<RealmLocalization>, CoreError> {
+        realm_localizations::Entity::find_by_id(Uuid::from(realm_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get realm localization", e))?
+            .map(|model| {
+                RealmLocalization::try_from(model)
+                    .map_err(|e| database_error("read realm localization", e))
+            })
+            .transpose()
+    }
+
+    async fn upsert_settings(
+        &self,
+        settings: RealmLocalization,
+    ) -> Result<RealmLocalization, CoreError> {
+        let model = realm_localizations::ActiveModel {
+            realm_id: Set(settings.realm_id.into()),
+            default_locale: Set(settings.default_locale),
+            supported_locales: Set(serde_json::json!(settings.supported_locales)),
+            updated_at: Set(settings.updated_at.into()),
+        };
+
+        let model = realm_localizations::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(realm_localizations::Column::RealmId)
+                    .update_columns([
+                        realm_localizations::Column::DefaultLocale,
+                        realm_localizations::Column::SupportedLocales,
+                        realm_localizations::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| database_error("upsert realm localization", e))?;
+
+        RealmLocalization::try_from(model).map_err(|e| database_error("read realm localization", e))
+    }
+
+    async fn list_bundles(&self, realm_id: RealmId) -> Result<Vec<MessageBundle>, CoreError> {
+        let models = message_bundles::Entity::find()
+            .filter(message_bundles::Column::RealmId.eq(Uuid::from(realm_id)))
+            .order_by_asc(message_bundles::Column::Locale)
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("list message bundles", e))?;
+
+        into_bundles(models)
+    }
+
+    async fn get_bundles(
+        &self,
+        realm_id: RealmId,
+        locales: Vec<String>,
+    ) -> Result<Vec<MessageBundle>, CoreError> {
+        let models = message_bundles::Entity::find()
+            .filter(message_bundles::Column::RealmId.eq(Uuid::from(realm_id)))
+            .filter(message_bundles::Column::Locale.is_in(locales))
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("get message bundles", e))?;
+
+        into_bundles(models)
+    }
+
+    async fn upsert_bundle(&self, bundle: MessageBundle) -> Result<MessageBundle, CoreError> {
+        let model = message_bundles::ActiveModel {
+            id: Set(generate_uuid_v7()),
+            realm_id: Set(bundle.realm_id.into()),
+            locale: Set(bundle.locale),
+            messages: Set(serde_json::json!(bundle.messages)),
+            updated_at: Set(bundle.updated_at.into()),
+        };
+
+        let model = message_bundles::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    message_bundles::Column::RealmId,
+                    message_bundles::Column::Locale,
+                ])
+                .update_columns([
+                    message_bundles::Column::Messages,
+                    message_bundles::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| database_error("upsert message bundle", e))?;
+
+        MessageBundle::try_from(model).map_err(|e| database_error("read message bundle", e))
+    }
+
+    async fn delete_bundle(&self, realm_id: RealmId, locale: String) -> Result<bool, CoreError> {
+        let result = message_bundles::Entity::delete_many()
+            .filter(message_bundles::Column::RealmId.eq(Uuid::from(realm_id)))
+            .filter(message_bundles::Column::Locale.eq(locale))
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("delete message bundle", e))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn get_user_locale(&self, user_id: Uuid) -> Result<Option<String>, CoreError> {
+        let attribute = user_attributes::Entity::find()
+            .filter(user_attributes::Column::UserId.eq(user_id))
+            .filter(user_attributes::Column::Key.eq(USER_LOCALE_ATTRIBUTE))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get user locale", e))?;
+
+        Ok(attribute.map(|attribute| attribute.value))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod localization_postgres_repository;
+
+pub use localization_postgres_repository::PostgresLocalizationRepository;
@@ -0,0 +1,97 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement, TransactionTrait};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use ferriskey_domain::client::entities::Client;
+use ferriskey_domain::maintenance::ports::MaintenanceSessionRepository;
+use ferriskey_domain::realm::RealmId;
+
+#[derive(Clone, Debug)]
+pub struct PostgresMaintenanceSessionRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresMaintenanceSessionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Runs the statements in one transaction and sums the affected rows.
+    async fn execute_all(&self, statements: Vec<Statement>) -> Result<u64, CoreError> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        let mut affected = 0;
+        for statement in statements {
+            affected += txn
+                .execute(statement)
+                .await
+                .map_err(|e| CoreError::Database(e.to_string()))?
+                .rows_affected();
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(affected)
+    }
+}
+
+impl MaintenanceSessionRepository for PostgresMaintenanceSessionRepository {
+    async fn terminate_client_sessions(&self, client: &Client) -> Result<u64, CoreError> {
+        let realm_id: Uuid = client.realm_id.into();
+
+        self.execute_all(vec![
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE refresh_tokens SET revoked = TRUE WHERE client_id = $1 AND revoked = FALSE",
+                [client.id.into()],
+            ),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE access_tokens SET revoked = TRUE \
+                 WHERE realm_id = $1 AND claims->>'azp' = $2 AND revoked = FALSE",
+                [realm_id.into(), client.client_id.clone().into()],
+            ),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "DELETE FROM auth_sessions WHERE client_id = $1",
+                [client.id.into()],
+            ),
+        ])
+        .await
+    }
+
+    async fn terminate_realm_sessions(&self, realm_id: RealmId) -> Result<u64, CoreError> {
+        let realm_id: Uuid = realm_id.into();
+
+        self.execute_all(vec![
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE refresh_tokens SET revoked = TRUE \
+                 WHERE revoked = FALSE AND user_id IN (SELECT id FROM users WHERE realm_id = $1)",
+                [realm_id.into()],
+            ),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "UPDATE access_tokens SET revoked = TRUE WHERE realm_id = $1 AND revoked = FALSE",
+                [realm_id.into()],
+            ),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "DELETE FROM auth_sessions WHERE realm_id = $1",
+                [realm_id.into()],
+            ),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "DELETE FROM user_sessions WHERE realm_id = $1",
+                [realm_id.into()],
+            ),
+        ])
+        .await
+    }
+}
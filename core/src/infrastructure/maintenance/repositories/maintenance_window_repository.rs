@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::maintenance_windows::{ActiveModel, Column, Entity, Model};
+use ferriskey_domain::maintenance::{
+    entities::{MaintenanceWindow, MaintenanceWindowStatus},
+    ports::MaintenanceWindowRepository,
+};
+use ferriskey_domain::realm::RealmId;
+
+#[derive(Clone, Debug)]
+pub struct PostgresMaintenanceWindowRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresMaintenanceWindowRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Moves the windows in `from` matching `due` to `to` in one statement,
+    /// returning the rows this call transitioned.
+    async fn transition(
+        &self,
+        from: MaintenanceWindowStatus,
+        to: MaintenanceWindowStatus,
+        due: sea_orm::sea_query::SimpleExpr,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        let models = Entity::update_many()
+            .col_expr(Column::Status, Expr::value(to.to_string()))
+            .col_expr(
+                Column::UpdatedAt,
+                Expr::value(sea_orm::prelude::DateTimeWithTimeZone::from(Utc::now())),
+            )
+            .filter(Column::Status.eq(from.to_string()))
+            .filter(due)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
+
+impl MaintenanceWindowRepository for PostgresMaintenanceWindowRepository {
+    async fn create(&self, window: MaintenanceWindow) -> Result<MaintenanceWindow, CoreError> {
+        let model = ActiveModel {
+            id: Set(window.id),
+            realm_id: Set(window.realm_id.into()),
+            client_id: Set(window.client_id),
+            reason: Set(window.reason.clone()),
+            session_strategy: Set(window.session_strategy.to_string()),
+            starts_at: Set(window.starts_at.into()),
+            ends_at: Set(window.ends_at.into()),
+            status: Set(window.status.to_string()),
+            created_at: Set(window.created_at.into()),
+            updated_at: Set(window.updated_at.into()),
+        };
+        model
+            .insert(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+        Ok(window)
+    }
+
+    async fn get_by_id(&self, window_id: Uuid) -> Result<Option<MaintenanceWindow>, CoreError> {
+        let model = Entity::find_by_id(window_id)
+            .one(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn list_by_realm_id(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        let models = Entity::find()
+            .filter(Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .order_by_desc(Column::StartsAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn cancel(&self, window_id: Uuid) -> Result<MaintenanceWindow, CoreError> {
+        let models = Entity::update_many()
+            .col_expr(
+                Column::Status,
+                Expr::value(MaintenanceWindowStatus::Cancelled.to_string()),
+            )
+            .col_expr(
+                Column::UpdatedAt,
+                Expr::value(sea_orm::prelude::DateTimeWithTimeZone::from(Utc::now())),
+            )
+            .filter(Column::Id.eq(window_id))
+            .filter(Column::Status.is_in([
+                MaintenanceWindowStatus::Scheduled.to_string(),
+                MaintenanceWindowStatus::Active.to_string(),
+            ]))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        models
+            .into_iter()
+            .next()
+            .map(Into::into)
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn claim_due_starts(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        self.transition(
+            MaintenanceWindowStatus::Scheduled,
+            MaintenanceWindowStatus::Active,
+            Column::StartsAt.lte(now),
+        )
+        .await
+    }
+
+    async fn claim_due_ends(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<MaintenanceWindow>, CoreError> {
+        self.transition(
+            MaintenanceWindowStatus::Active,
+            MaintenanceWindowStatus::Completed,
+            Column::EndsAt.lte(now),
+        )
+        .await
+    }
+}
+
+impl From<Model> for MaintenanceWindow {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            client_id: model.client_id,
+            reason: model.reason,
+            session_strategy: model.session_strategy.parse().unwrap_or_default(),
+            starts_at: model.starts_at.into(),
+            ends_at: model.ends_at.into(),
+            status: model
+                .status
+                .parse()
+                .unwrap_or(MaintenanceWindowStatus::Cancelled),
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
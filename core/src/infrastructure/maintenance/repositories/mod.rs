@@ -1,2 +1,5 @@
+pub mod maintenance_session_repository;
 pub mod maintenance_whitelist_repository;
+pub mod maintenance_window_repository;
+pub mod realm_maintenance_repository;
 pub mod realm_maintenance_whitelist_repository;
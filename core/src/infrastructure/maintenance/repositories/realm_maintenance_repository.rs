@@ -0,0 +1,77 @@
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::entity::realm_maintenance::{ActiveModel, Column, Entity, Model};
+use ferriskey_domain::maintenance::{
+    entities::RealmMaintenance, ports::RealmMaintenanceRepository,
+};
+use ferriskey_domain::realm::RealmId;
+
+#[derive(Clone, Debug)]
+pub struct PostgresRealmMaintenanceRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRealmMaintenanceRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl RealmMaintenanceRepository for PostgresRealmMaintenanceRepository {
+    async fn get_by_realm_id(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<RealmMaintenance>, CoreError> {
+        let model = Entity::find()
+            .filter(Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .one(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn upsert(&self, maintenance: RealmMaintenance) -> Result<RealmMaintenance, CoreError> {
+        let model = ActiveModel {
+            realm_id: Set(maintenance.realm_id.into()),
+            enabled: Set(maintenance.enabled),
+            reason: Set(maintenance.reason.clone()),
+            session_strategy: Set(maintenance.session_strategy.to_string()),
+            updated_at: Set(maintenance.updated_at.into()),
+        };
+
+        Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(Column::RealmId)
+                    .update_columns([
+                        Column::Enabled,
+                        Column::Reason,
+                        Column::SessionStrategy,
+                        Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|e| CoreError::Database(e.to_string()))?;
+
+        Ok(maintenance)
+    }
+}
+
+impl From<Model> for RealmMaintenance {
+    fn from(model: Model) -> Self {
+        Self {
+            realm_id: model.realm_id.into(),
+            enabled: model.enabled,
+            reason: model.reason,
+            session_strategy: model.session_strategy.parse().unwrap_or_default(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
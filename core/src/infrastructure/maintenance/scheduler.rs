@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::domain::maintenance::ports::MaintenanceService;
+
+/// How often due maintenance windows are looked for, which bounds how late
+/// a window may start or end.
+pub const MAINTENANCE_WINDOW_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Periodically switches maintenance on and off for the windows whose start
+/// or end has passed.
+pub async fn maintenance_window_task<S>(service: S, interval: Duration)
+where
+    S: MaintenanceService,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        match service.apply_due_maintenance_windows(Utc::now()).await {
+            Ok(0) => {}
+            Ok(applied) => tracing::info!("Maintenance windows: applied {applied} windows"),
+            Err(e) => tracing::error!("Maintenance windows: failed to apply windows: {e}"),
+        }
+    }
+}
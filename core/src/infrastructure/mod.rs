@@ -1,20 +1,33 @@
 pub mod abyss;
+pub mod account;
 pub mod aegis;
+pub mod auth_flow;
 pub mod client;
 pub mod common;
 pub mod compass;
 pub mod db;
 pub mod email;
+pub mod email_outbox;
 pub mod email_template;
 pub mod health;
+pub mod housekeeping;
 pub mod identity_provider;
+pub mod localization;
 pub mod maintenance;
 pub mod migrate;
+pub mod one_time_code;
 pub mod organization;
+pub mod otp;
+pub mod privacy;
 pub mod realm;
 pub mod recovery_code;
 pub mod repositories;
+pub mod risk;
 pub mod role;
 pub mod seawatch;
+pub mod secrets;
+pub mod security_notification;
+pub mod sms;
+pub mod trusted_device;
 pub mod user;
 pub mod webhook;
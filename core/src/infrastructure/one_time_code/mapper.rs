@@ -0,0 +1,22 @@
+use crate::domain::one_time_code::entities::{OneTimeCode, OneTimeCodeChannel, OneTimeCodePurpose};
+use crate::entity::one_time_codes;
+
+impl TryFrom<one_time_codes::Model> for OneTimeCode {
+    type Error = String;
+
+    fn try_from(model: one_time_codes::Model) -> Result<Self, Self::Error> {
+        Ok(OneTimeCode {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            user_id: model.user_id,
+            channel: model.channel.parse::<OneTimeCodeChannel>()?,
+            purpose: model.purpose.parse::<OneTimeCodePurpose>()?,
+            destination: model.destination,
+            code_hash: model.code_hash,
+            attempts: model.attempts.max(0) as u32,
+            expires_at: model.expires_at.to_utc(),
+            consumed_at: model.consumed_at.map(|consumed_at| consumed_at.to_utc()),
+            created_at: model.created_at.to_utc(),
+        })
+    }
+}
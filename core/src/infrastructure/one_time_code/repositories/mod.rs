@@ -0,0 +1,3 @@
+pub mod one_time_code_postgres_repository;
+
+pub use one_time_code_postgres_repository::PostgresOneTimeCodeRepository;
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, prelude::DateTimeWithTimeZone, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::one_time_code::{
+    entities::{OneTimeCode, OneTimeCodeChannel, OneTimeCodePurpose},
+    ports::OneTimeCodeRepository,
+};
+use crate::entity::one_time_codes::{ActiveModel, Column, Entity};
+
+#[derive(Debug, Clone)]
+pub struct PostgresOneTimeCodeRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresOneTimeCodeRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {}: {}", context, e);
+    CoreError::InternalServerError
+}
+
+impl OneTimeCodeRepository for PostgresOneTimeCodeRepository {
+    async fn create(&self, code: OneTimeCode) -> Result<(), CoreError> {
+        let model = ActiveModel {
+            id: Set(code.id),
+            realm_id: Set(code.realm_id.into()),
+            user_id: Set(code.user_id),
+            channel: Set(code.channel.to_string()),
+            purpose: Set(code.purpose.to_string()),
+            destination: Set(code.destination),
+            code_hash: Set(code.code_hash),
+            attempts: Set(code.attempts as i32),
+            expires_at: Set(code.expires_at.into()),
+            consumed_at: Set(code.consumed_at.map(Into::into)),
+            created_at: Set(code.created_at.into()),
+        };
+
+        Entity::insert(model)
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| database_error("create one-time code", e))?;
+
+        Ok(())
+    }
+
+    async fn get_latest(
+        &self,
+        user_id: Uuid,
+        channel: OneTimeCodeChannel,
+        purpose: OneTimeCodePurpose,
+    ) -> Result<Option<OneTimeCode>, CoreError> {
+        let model = Entity::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Channel.eq(channel.to_string()))
+            .filter(Column::Purpose.eq(purpose.to_string()))
+            .order_by_desc(Column::CreatedAt)
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get latest one-time code", e))?;
+
+        model
+            .map(OneTimeCode::try_from)
+            .transpose()
+            .map_err(|e| database_error("read one-time code", e))
+    }
+
+    async fn count_sent_since(
+        &self,
+        user_id: Uuid,
+        channel: OneTimeCodeChannel,
+        since: DateTime<Utc>,
+    ) -> Result<u64, CoreError> {
+        Entity::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::Channel.eq(channel.to_string()))
+            .filter(Column::CreatedAt.gte(DateTimeWithTimeZone::from(since)))
+            .count(&self.db)
+            .await
+            .map_err(|e| database_error("count one-time codes", e))
+    }
+
+    async fn record_failed_attempt(&self, id: Uuid) -> Result<(), CoreError> {
+        Entity::update_many()
+            .col_expr(Column::Attempts, Expr::col(Column::Attempts).add(1))
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("record one-time code attempt", e))?;
+
+        Ok(())
+    }
+
+    async fn consume(&self, id: Uuid, consumed_at: DateTime<Utc>) -> Result<bool, CoreError> {
+        let result = Entity::update_many()
+            .col_expr(
+                Column::ConsumedAt,
+                Expr::value(DateTimeWithTimeZone::from(consumed_at)),
+            )
+            .filter(Column::Id.eq(id))
+            .filter(Column::ConsumedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("consume one-time code", e))?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
@@ -1,13 +1,16 @@
 use chrono::Utc;
 use sea_orm::ActiveValue::Set;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, IntoSimpleExpr, PaginatorTrait,
+    QueryFilter, sea_query::SimpleExpr,
+};
 use tracing::error;
 use uuid::Uuid;
 
 use ferriskey_domain::realm::RealmId;
 use ferriskey_organization::{
-    CreateOrganizationParams, Organization, OrganizationId, OrganizationRepository,
-    UpdateOrganizationParams,
+    CreateOrganizationParams, Organization, OrganizationFilter, OrganizationId,
+    OrganizationRepository, OrganizationSortField, UpdateOrganizationParams,
 };
 
 use crate::domain::common::entities::app_errors::CoreError;
@@ -16,6 +19,7 @@ use crate::entity::organizations::{
     ActiveModel as OrganizationActiveModel, Column as OrganizationColumn,
     Entity as OrganizationEntity, Model as OrganizationModel,
 };
+use crate::infrastructure::common::{paginate, search_condition, timestamp_key};
 
 #[derive(Debug, Clone)]
 pub struct PostgresOrganizationRepository {
@@ -43,6 +47,25 @@ fn model_to_domain(model: OrganizationModel) -> Organization {
     }
 }
 
+fn filter_condition(realm_id: RealmId, filter: &OrganizationFilter) -> Condition {
+    let mut condition = Condition::all()
+        .add(OrganizationColumn::RealmId.eq::<Uuid>(realm_id.into()))
+        .add(search_condition(
+            filter.search.as_deref(),
+            &[
+                OrganizationColumn::Name,
+                OrganizationColumn::Alias,
+                OrganizationColumn::Domain,
+            ],
+        ));
+
+    if let Some(enabled) = filter.enabled {
+        condition = condition.add(OrganizationColumn::Enabled.eq(enabled));
+    }
+
+    condition
+}
+
 impl OrganizationRepository for PostgresOrganizationRepository {
     async fn create_organization(
         &self,
@@ -123,6 +146,58 @@ impl OrganizationRepository for PostgresOrganizationRepository {
         Ok(models.into_iter().map(model_to_domain).collect())
     }
 
+    async fn search_organizations(
+        &self,
+        realm_id: RealmId,
+        filter: OrganizationFilter,
+    ) -> Result<Vec<Organization>, CoreError> {
+        let (sort, key): (SimpleExpr, _) = match filter.sort {
+            OrganizationSortField::Name => (
+                OrganizationColumn::Name.into_simple_expr(),
+                filter.page.cursor.as_ref().map(|c| c.key.clone().into()),
+            ),
+            OrganizationSortField::Alias => (
+                OrganizationColumn::Alias.into_simple_expr(),
+                filter.page.cursor.as_ref().map(|c| c.key.clone().into()),
+            ),
+            OrganizationSortField::CreatedAt => (
+                OrganizationColumn::CreatedAt.into_simple_expr(),
+                filter
+                    .page
+                    .cursor
+                    .as_ref()
+                    .map(|c| timestamp_key(c).map(|t| t.fixed_offset().into()))
+                    .transpose()?,
+            ),
+        };
+
+        let select = OrganizationEntity::find().filter(filter_condition(realm_id, &filter));
+        let models = paginate(select, sort, OrganizationColumn::Id, key, &filter.page)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to search organizations: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models.into_iter().map(model_to_domain).collect())
+    }
+
+    async fn count_organizations(
+        &self,
+        realm_id: RealmId,
+        filter: OrganizationFilter,
+    ) -> Result<u64, CoreError> {
+        OrganizationEntity::find()
+            .filter(filter_condition(realm_id, &filter))
+            .count(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to count organizations: {}", e);
+                CoreError::InternalServerError
+            })
+    }
+
     async fn update_organization(
         &self,
         id: OrganizationId,
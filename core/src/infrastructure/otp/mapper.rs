@@ -0,0 +1,17 @@
+use crate::domain::otp::entities::{OtpAlgorithm, OtpPolicy, OtpType};
+use crate::entity::otp_policies;
+
+impl From<otp_policies::Model> for OtpPolicy {
+    fn from(model: otp_policies::Model) -> Self {
+        OtpPolicy {
+            realm_id: model.realm_id.into(),
+            otp_type: model.otp_type.parse().unwrap_or(OtpType::Totp),
+            algorithm: model.algorithm.parse().unwrap_or(OtpAlgorithm::Sha1),
+            digits: model.digits as u32,
+            period: model.period as u64,
+            look_around: model.look_around as u32,
+            initial_counter: model.initial_counter as u64,
+            updated_at: model.updated_at.to_utc(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod otp_policy_postgres_repository;
+
+pub use otp_policy_postgres_repository::PostgresOtpPolicyRepository;
@@ -0,0 +1,71 @@
+use sea_orm::{ActiveValue::Set, DatabaseConnection, EntityTrait, sea_query::OnConflict};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        otp::{entities::OtpPolicy, ports::OtpPolicyRepository},
+        realm::entities::RealmId,
+    },
+    entity::otp_policies,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresOtpPolicyRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresOtpPolicyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {}: {}", context, e);
+    CoreError::InternalServerError
+}
+
+impl OtpPolicyRepository for PostgresOtpPolicyRepository {
+    async fn get_policy(&self, realm_id: RealmId) -> Result<Option<OtpPolicy>, CoreError> {
+        let model = otp_policies::Entity::find_by_id(Uuid::from(realm_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get OTP policy", e))?;
+
+        Ok(model.map(OtpPolicy::from))
+    }
+
+    async fn upsert_policy(&self, policy: OtpPolicy) -> Result<OtpPolicy, CoreError> {
+        let model = otp_policies::ActiveModel {
+            realm_id: Set(policy.realm_id.into()),
+            otp_type: Set(policy.otp_type.to_string()),
+            algorithm: Set(policy.algorithm.to_string()),
+            digits: Set(policy.digits as i32),
+            period: Set(policy.period as i32),
+            look_around: Set(policy.look_around as i32),
+            initial_counter: Set(policy.initial_counter as i64),
+            updated_at: Set(policy.updated_at.into()),
+        };
+
+        let model = otp_policies::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(otp_policies::Column::RealmId)
+                    .update_columns([
+                        otp_policies::Column::OtpType,
+                        otp_policies::Column::Algorithm,
+                        otp_policies::Column::Digits,
+                        otp_policies::Column::Period,
+                        otp_policies::Column::LookAround,
+                        otp_policies::Column::InitialCounter,
+                        otp_policies::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| database_error("upsert OTP policy", e))?;
+
+        Ok(model.into())
+    }
+}
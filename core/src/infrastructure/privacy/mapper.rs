@@ -0,0 +1,30 @@
+use sea_orm::ActiveValue::Set;
+
+use crate::domain::privacy::entities::AccountDeletionRequest;
+use crate::entity::account_deletion_requests;
+
+impl From<account_deletion_requests::Model> for AccountDeletionRequest {
+    fn from(model: account_deletion_requests::Model) -> Self {
+        AccountDeletionRequest {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            user_id: model.user_id,
+            requested_by: model.requested_by,
+            requested_at: model.requested_at.to_utc(),
+            scheduled_for: model.scheduled_for.to_utc(),
+        }
+    }
+}
+
+impl From<AccountDeletionRequest> for account_deletion_requests::ActiveModel {
+    fn from(request: AccountDeletionRequest) -> Self {
+        account_deletion_requests::ActiveModel {
+            id: Set(request.id),
+            realm_id: Set(request.realm_id.into()),
+            user_id: Set(request.user_id),
+            requested_by: Set(request.requested_by),
+            requested_at: Set(request.requested_at.into()),
+            scheduled_for: Set(request.scheduled_for.into()),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, IntoActiveModel,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        privacy::{
+            entities::{AccountDeletionRequest, PseudonymizationReport},
+            ports::AccountDeletionRepository,
+            pseudonymize::Pseudonymizer,
+        },
+        realm::entities::RealmId,
+    },
+    entity::{account_deletion_requests, admin_events, compass_flows, security_events},
+};
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {context}: {e}");
+    CoreError::InternalServerError
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresAccountDeletionRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresAccountDeletionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl AccountDeletionRepository for PostgresAccountDeletionRepository {
+    async fn get_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<AccountDeletionRequest>, CoreError> {
+        account_deletion_requests::Entity::find()
+            .filter(account_deletion_requests::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await
+            .map(|model| model.map(AccountDeletionRequest::from))
+            .map_err(|e| database_error("get account deletion request", e))
+    }
+
+    async fn create(
+        &self,
+        request: AccountDeletionRequest,
+    ) -> Result<AccountDeletionRequest, CoreError> {
+        account_deletion_requests::ActiveModel::from(request)
+            .insert(&self.db)
+            .await
+            .map(AccountDeletionRequest::from)
+            .map_err(|e| database_error("create account deletion request", e))
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<bool, CoreError> {
+        account_deletion_requests::Entity::delete_many()
+            .filter(account_deletion_requests::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map(|result| result.rows_affected > 0)
+            .map_err(|e| database_error("delete account deletion request", e))
+    }
+
+    async fn list_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<AccountDeletionRequest>, CoreError> {
+        account_deletion_requests::Entity::find()
+            .filter(account_deletion_requests::Column::ScheduledFor.lte(now))
+            .order_by_asc(account_deletion_requests::Column::ScheduledFor)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map(|models| {
+                models
+                    .into_iter()
+                    .map(AccountDeletionRequest::from)
+                    .collect()
+            })
+            .map_err(|e| database_error("list due account deletions", e))
+    }
+
+    async fn pseudonymize_user_records(
+        &self,
+        realm_id: RealmId,
+        user_id: Uuid,
+        pseudonymizer: Pseudonymizer,
+    ) -> Result<PseudonymizationReport, CoreError> {
+        let realm_id: Uuid = realm_id.into();
+        let now = Utc::now().naive_utc();
+        let mut report = PseudonymizationReport::default();
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| database_error("start pseudonymization", e))?;
+
+        let security_events = security_events::Entity::find()
+            .filter(security_events::Column::RealmId.eq(realm_id))
+            .filter(security_events::Column::PseudonymizedAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(security_events::Column::ActorId.eq(user_id))
+                    .add(
+                        Condition::all()
+                            .add(security_events::Column::TargetType.eq("user"))
+                            .add(security_events::Column::TargetId.eq(user_id)),
+                    ),
+            )
+            .all(&txn)
+            .await
+            .map_err(|e| database_error("load security events to pseudonymize", e))?;
+
+        for model in security_events {
+            let ip_address = pseudonymizer.mask_network(model.ip_address.clone());
+            let user_agent = pseudonymizer.mask_network(model.user_agent.clone());
+            let details = model.details.clone().map(|mut details| {
+                pseudonymizer.mask_json(&mut details);
+                details
+            });
+
+            let mut active = model.into_active_model();
+            active.ip_address = Set(ip_address);
+            active.user_agent = Set(user_agent);
+            active.details = Set(details);
+            active.pseudonymized_at = Set(Some(now));
+            active
+                .update(&txn)
+                .await
+                .map_err(|e| database_error("pseudonymize security event", e))?;
+            report.security_events += 1;
+        }
+
+        // Admin events keep the acting admin's network data; only rows
+        // written by the user themselves lose it.
+        let admin_events = admin_events::Entity::find()
+            .filter(admin_events::Column::RealmId.eq(realm_id))
+            .filter(
+                Condition::any()
+                    .add(admin_events::Column::ActorId.eq(user_id))
+                    .add(admin_events::Column::ResourceId.eq(user_id.to_string())),
+            )
+            .all(&txn)
+            .await
+            .map_err(|e| database_error("load admin events to pseudonymize", e))?;
+
+        for model in admin_events {
+            let is_actor = model.actor_id == user_id;
+            let is_resource = model.resource_id.as_deref() == Some(user_id.to_string().as_str());
+            let mut before = model.before.clone();
+            let mut after = model.after.clone();
+            let mut changes = model.changes.clone();
+            let ip_address = model.ip_address.clone();
+            let user_agent = model.user_agent.clone();
+
+            let mut active = model.into_active_model();
+            if is_actor {
+                active.actor_name = Set(pseudonymizer.pseudonym().to_string());
+                active.ip_address = Set(pseudonymizer.mask_network(ip_address));
+                active.user_agent = Set(pseudonymizer.mask_network(user_agent));
+            }
+            if is_resource {
+                for snapshot in [before.as_mut(), after.as_mut()].into_iter().flatten() {
+                    pseudonymizer.mask_json(snapshot);
+                }
+                pseudonymizer.mask_json(&mut changes);
+                active.before = Set(before);
+                active.after = Set(after);
+                active.changes = Set(changes);
+            }
+            active
+                .update(&txn)
+                .await
+                .map_err(|e| database_error("pseudonymize admin event", e))?;
+            report.admin_events += 1;
+        }
+
+        let compass_flows = compass_flows::Entity::find()
+            .filter(compass_flows::Column::RealmId.eq(realm_id))
+            .filter(compass_flows::Column::UserId.eq(user_id))
+            .all(&txn)
+            .await
+            .map_err(|e| database_error("load compass flows to pseudonymize", e))?;
+
+        for model in compass_flows {
+            let ip_address = pseudonymizer.mask_network(model.ip_address.clone());
+            let user_agent = pseudonymizer.mask_network(model.user_agent.clone());
+
+            let mut active = model.into_active_model();
+            active.ip_address = Set(ip_address);
+            active.user_agent = Set(user_agent);
+            active
+                .update(&txn)
+                .await
+                .map_err(|e| database_error("pseudonymize compass flow", e))?;
+            report.compass_flows += 1;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| database_error("commit pseudonymization", e))?;
+
+        Ok(report)
+    }
+}
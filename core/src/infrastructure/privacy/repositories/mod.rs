@@ -0,0 +1,3 @@
+pub mod account_deletion_postgres_repository;
+
+pub use account_deletion_postgres_repository::PostgresAccountDeletionRepository;
@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::domain::privacy::ports::PrivacyService;
+
+/// How often accounts whose cooling-off period ended are looked for.
+pub const ACCOUNT_DELETION_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Periodically deletes the accounts whose deletion is due.
+pub async fn account_deletion_task<S>(service: S, interval: Duration)
+where
+    S: PrivacyService,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        match service.process_due_account_deletions(Utc::now()).await {
+            Ok(0) => {}
+            Ok(deleted) => tracing::info!("Account deletion: deleted {deleted} accounts"),
+            Err(e) => tracing::error!("Account deletion: failed to process due deletions: {e}"),
+        }
+    }
+}
@@ -1,11 +1,31 @@
 use chrono::{DateTime, Utc};
 
-use crate::{domain::realm::entities::SmtpConfig, entity::smtp_configs::Model};
+use crate::{
+    domain::realm::entities::{EmailProvider, HttpApiSettings, SmtpConfig},
+    entity::smtp_configs::Model,
+};
+
+fn provider(value: &Model) -> EmailProvider {
+    match value.provider.as_str() {
+        "http_api" => EmailProvider::HttpApi(HttpApiSettings {
+            url: value.http_url.clone().unwrap_or_default(),
+            headers: value
+                .http_headers
+                .clone()
+                .and_then(|headers| serde_json::from_value(headers).ok())
+                .unwrap_or_default(),
+            body_template: value.http_body_template.clone().unwrap_or_default(),
+        }),
+        "maildir" => EmailProvider::Maildir,
+        _ => EmailProvider::Smtp,
+    }
+}
 
 impl From<Model> for SmtpConfig {
     fn from(value: Model) -> Self {
         let created_at: DateTime<Utc> = value.created_at.into();
         let updated_at: DateTime<Utc> = value.updated_at.into();
+        let provider = provider(&value);
 
         SmtpConfig {
             id: value.id,
@@ -20,6 +40,7 @@ impl From<Model> for SmtpConfig {
                 .encryption
                 .parse()
                 .expect("invariant: SmtpEncryption FromStr is infallible"),
+            provider,
             created_at,
             updated_at,
         }
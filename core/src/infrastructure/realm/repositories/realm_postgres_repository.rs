@@ -187,6 +187,7 @@ impl RealmRepository for PostgresRealmRepository {
         email_verification_template_id: Option<Option<Uuid>>,
         email_verification_enabled: Option<bool>,
         email_verification_ttl_hours: Option<i64>,
+        trusted_device_days: Option<u32>,
     ) -> Result<RealmSetting, CoreError> {
         let realm_setting = crate::entity::realm_settings::Entity::find()
             .filter(crate::entity::realm_settings::Column::RealmId.eq::<Uuid>(realm_id.into()))
@@ -271,6 +272,11 @@ impl RealmRepository for PostgresRealmRepository {
                 Set(i32::try_from(hours).map_err(|_| CoreError::Invalid)?);
         }
 
+        if let Some(days) = trusted_device_days {
+            realm_setting.trusted_device_days =
+                Set(i32::try_from(days).map_err(|_| CoreError::Invalid)?);
+        }
+
         let realm_setting = realm_setting
             .update(&self.db)
             .await
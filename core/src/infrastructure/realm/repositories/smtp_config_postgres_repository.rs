@@ -7,38 +7,76 @@ use crate::{
     domain::{
         common::entities::app_errors::CoreError,
         realm::{
-            entities::{RealmId, SmtpConfig},
+            entities::{EmailProvider, RealmId, SmtpConfig},
             ports::SmtpConfigRepository,
         },
+        secrets::{EnvelopeCipher, SecretCipher},
     },
-    entity::smtp_configs::{ActiveModel, Entity as SmtpConfigEntity},
+    entity::smtp_configs::{ActiveModel, Entity as SmtpConfigEntity, Model},
+    infrastructure::secrets::cipher_error,
 };
 
 #[derive(Debug, Clone)]
 pub struct PostgresSmtpConfigRepository {
     pub db: DatabaseConnection,
+    pub cipher: EnvelopeCipher,
 }
 
 impl PostgresSmtpConfigRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, cipher: EnvelopeCipher) -> Self {
+        Self { db, cipher }
+    }
+
+    async fn reveal(&self, mut model: Model) -> Result<SmtpConfig, CoreError> {
+        model.password = self
+            .cipher
+            .decrypt(&model.password)
+            .await
+            .map_err(|e| cipher_error("decrypt SMTP password", e))?;
+
+        Ok(SmtpConfig::from(model))
+    }
+}
+
+/// Columns holding the HTTP API settings, empty for other providers.
+fn http_api_columns(
+    provider: &EmailProvider,
+) -> (Option<String>, Option<serde_json::Value>, Option<String>) {
+    match provider {
+        EmailProvider::HttpApi(settings) => (
+            Some(settings.url.clone()),
+            Some(serde_json::json!(settings.headers)),
+            Some(settings.body_template.clone()),
+        ),
+        EmailProvider::Smtp | EmailProvider::Maildir => (None, None, None),
     }
 }
 
 impl SmtpConfigRepository for PostgresSmtpConfigRepository {
     async fn get_by_realm_id(&self, realm_id: RealmId) -> Result<Option<SmtpConfig>, CoreError> {
-        let result = SmtpConfigEntity::find()
+        let model = SmtpConfigEntity::find()
             .filter(crate::entity::smtp_configs::Column::RealmId.eq::<Uuid>(realm_id.into()))
             .one(&self.db)
             .await
-            .map_err(|e| CoreError::Database(e.to_string()))?
-            .map(SmtpConfig::from);
+            .map_err(|e| CoreError::Database(e.to_string()))?;
 
-        Ok(result)
+        match model {
+            Some(model) => Ok(Some(self.reveal(model).await?)),
+            None => Ok(None),
+        }
     }
 
     async fn upsert(&self, config: &SmtpConfig) -> Result<SmtpConfig, CoreError> {
         let now = chrono::Utc::now().fixed_offset();
+        let (http_url, http_headers, http_body_template) = http_api_columns(&config.provider);
+        let password = if config.password.is_empty() {
+            String::new()
+        } else {
+            self.cipher
+                .encrypt(&config.password)
+                .await
+                .map_err(|e| cipher_error("encrypt SMTP password", e))?
+        };
 
         let existing = SmtpConfigEntity::find()
             .filter(crate::entity::smtp_configs::Column::RealmId.eq(config.realm_id))
@@ -51,10 +89,14 @@ impl SmtpConfigRepository for PostgresSmtpConfigRepository {
             active.host = Set(config.host.clone());
             active.port = Set(config.port as i32);
             active.username = Set(config.username.clone());
-            active.password = Set(config.password.clone());
+            active.password = Set(password);
             active.from_email = Set(config.from_email.clone());
             active.from_name = Set(config.from_name.clone());
             active.encryption = Set(config.encryption.as_str().to_string());
+            active.provider = Set(config.provider.as_str().to_string());
+            active.http_url = Set(http_url);
+            active.http_headers = Set(http_headers);
+            active.http_body_template = Set(http_body_template);
             active.updated_at = Set(now);
 
             active
@@ -69,10 +111,14 @@ impl SmtpConfigRepository for PostgresSmtpConfigRepository {
                 host: Set(config.host.clone()),
                 port: Set(config.port as i32),
                 username: Set(config.username.clone()),
-                password: Set(config.password.clone()),
+                password: Set(password),
                 from_email: Set(config.from_email.clone()),
                 from_name: Set(config.from_name.clone()),
                 encryption: Set(config.encryption.as_str().to_string()),
+                provider: Set(config.provider.as_str().to_string()),
+                http_url: Set(http_url),
+                http_headers: Set(http_headers),
+                http_body_template: Set(http_body_template),
                 created_at: Set(now),
                 updated_at: Set(now),
             };
@@ -83,7 +129,7 @@ impl SmtpConfigRepository for PostgresSmtpConfigRepository {
                 .map_err(|e| CoreError::Database(e.to_string()))?
         };
 
-        Ok(SmtpConfig::from(model))
+        self.reveal(model).await
     }
 
     async fn delete_by_realm_id(&self, realm_id: RealmId) -> Result<(), CoreError> {
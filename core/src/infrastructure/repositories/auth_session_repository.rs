@@ -6,10 +6,12 @@ use sea_orm::{
 use tracing::error;
 use uuid::Uuid;
 
+use crate::domain::auth_flow::entities::AuthFlowStage;
 use crate::domain::authentication::{
     entities::{AuthSession, AuthenticationError, WebAuthnChallenge},
     ports::AuthSessionRepository,
 };
+use crate::domain::risk::entities::RiskAssessment;
 
 impl From<crate::entity::auth_sessions::Model> for AuthSession {
     fn from(model: crate::entity::auth_sessions::Model) -> Self {
@@ -25,6 +27,11 @@ impl From<crate::entity::auth_sessions::Model> for AuthSession {
             None
         };
 
+        // Sessions stored with stages from an older flow format start over
+        // with nothing pending rather than failing to load.
+        let pending_auth_stages =
+            serde_json::from_value(model.pending_auth_stages).unwrap_or_default();
+
         AuthSession {
             id: model.id,
             realm_id: model.realm_id.into(),
@@ -42,6 +49,10 @@ impl From<crate::entity::auth_sessions::Model> for AuthSession {
             webauthn_challenge,
             webauthn_challenge_issued_at,
             compass_flow_id: model.compass_flow_id,
+            pending_auth_stages,
+            risk_assessment: model
+                .risk_assessment
+                .and_then(|value| serde_json::from_value(value).ok()),
         }
     }
 }
@@ -76,6 +87,11 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
             webauthn_challenge: Set(None),
             webauthn_challenge_issued_at: Set(None),
             compass_flow_id: Set(session.compass_flow_id),
+            pending_auth_stages: Set(serde_json::json!(session.pending_auth_stages)),
+            risk_assessment: Set(session
+                .risk_assessment
+                .as_ref()
+                .map(|assessment| serde_json::json!(assessment))),
         };
 
         let t = model
@@ -355,6 +371,48 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
 
         Ok(())
     }
+
+    async fn update_pending_auth_stages(
+        &self,
+        session_code: Uuid,
+        pending_auth_stages: Vec<AuthFlowStage>,
+    ) -> Result<(), AuthenticationError> {
+        crate::entity::auth_sessions::Entity::update_many()
+            .col_expr(
+                crate::entity::auth_sessions::Column::PendingAuthStages,
+                Expr::value(serde_json::json!(pending_auth_stages)),
+            )
+            .filter(crate::entity::auth_sessions::Column::Id.eq(session_code))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Error updating session pending_auth_stages: {:?}", e);
+                AuthenticationError::Invalid
+            })?;
+
+        Ok(())
+    }
+
+    async fn update_risk_assessment(
+        &self,
+        session_code: Uuid,
+        risk_assessment: RiskAssessment,
+    ) -> Result<(), AuthenticationError> {
+        crate::entity::auth_sessions::Entity::update_many()
+            .col_expr(
+                crate::entity::auth_sessions::Column::RiskAssessment,
+                Expr::value(serde_json::json!(risk_assessment)),
+            )
+            .filter(crate::entity::auth_sessions::Column::Id.eq(session_code))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Error updating session risk_assessment: {:?}", e);
+                AuthenticationError::Invalid
+            })?;
+
+        Ok(())
+    }
 }
 
 /// Integration tests for `PostgresAuthSessionRepository`.
@@ -2,10 +2,10 @@ use crate::{
     domain::credential::entities::CredentialType,
     entity::credentials::{ActiveModel, Entity as CredentialEntity},
 };
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
-    IntoActiveModel, ModelTrait, QueryFilter,
+    IntoActiveModel, ModelTrait, QueryFilter, sea_query::Expr,
 };
 use serde_json::Value;
 use tracing::error;
@@ -18,8 +18,15 @@ use crate::domain::{
         ports::CredentialRepository,
     },
     crypto::HashResult,
+    otp::entities::OtpCredentialData,
+    secrets::{EnvelopeCipher, SecretCipher},
 };
 
+/// Only OTP seeds can be read back; every other secret is a hash or a marker.
+fn holds_reversible_secret(credential_type: &str) -> bool {
+    credential_type == CredentialType::Otp.as_str()
+}
+
 impl From<crate::entity::credentials::Model> for Credential {
     fn from(model: crate::entity::credentials::Model) -> Self {
         let created_at = Utc.from_utc_datetime(&model.created_at);
@@ -53,11 +60,29 @@ impl From<crate::entity::credentials::Model> for Credential {
 #[derive(Debug, Clone)]
 pub struct PostgresCredentialRepository {
     pub db: DatabaseConnection,
+    pub cipher: EnvelopeCipher,
 }
 
 impl PostgresCredentialRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, cipher: EnvelopeCipher) -> Self {
+        Self { db, cipher }
+    }
+
+    async fn reveal(
+        &self,
+        model: crate::entity::credentials::Model,
+    ) -> Result<Credential, CredentialError> {
+        let mut credential = Credential::from(model);
+        credential.secret_data =
+            self.cipher
+                .decrypt(&credential.secret_data)
+                .await
+                .map_err(|e| {
+                    error!("Failed to decrypt credential {}: {}", credential.id, e);
+                    CredentialError::GetUserCredentialsError
+                })?;
+
+        Ok(credential)
     }
 }
 
@@ -142,14 +167,16 @@ impl CredentialRepository for PostgresCredentialRepository {
         &self,
         user_id: uuid::Uuid,
     ) -> Result<Vec<Credential>, CredentialError> {
-        let credentials = CredentialEntity::find()
+        let models = CredentialEntity::find()
             .filter(crate::entity::credentials::Column::UserId.eq(user_id))
             .all(&self.db)
             .await
-            .map_err(|_| CredentialError::GetUserCredentialsError)?
-            .into_iter()
-            .map(Credential::from)
-            .collect();
+            .map_err(|_| CredentialError::GetUserCredentialsError)?;
+
+        let mut credentials = Vec::with_capacity(models.len());
+        for model in models {
+            credentials.push(self.reveal(model).await?);
+        }
 
         Ok(credentials)
     }
@@ -180,6 +207,15 @@ impl CredentialRepository for PostgresCredentialRepository {
     ) -> Result<Credential, CredentialError> {
         let (now, _) = generate_timestamp();
 
+        let secret_data = if holds_reversible_secret(&credential_type) {
+            self.cipher.encrypt(&secret_data).await.map_err(|e| {
+                error!("Failed to encrypt credential secret: {}", e);
+                CredentialError::CreateCredentialError
+            })?
+        } else {
+            secret_data
+        };
+
         let payload = ActiveModel {
             id: Set(generate_uuid_v7()),
             salt: Set(None),
@@ -199,7 +235,7 @@ impl CredentialRepository for PostgresCredentialRepository {
             .await
             .map_err(|_| CredentialError::CreateCredentialError)?;
 
-        Ok(model.into())
+        self.reveal(model).await
     }
 
     async fn create_recovery_code_credentials(
@@ -316,6 +352,36 @@ impl CredentialRepository for PostgresCredentialRepository {
         Ok(credential)
     }
 
+    async fn update_otp_credential_data(
+        &self,
+        credential_id: uuid::Uuid,
+        read_at: DateTime<Utc>,
+        data: OtpCredentialData,
+    ) -> Result<bool, CredentialError> {
+        let data = serde_json::to_value(CredentialData::Otp(data))
+            .map_err(|_| CredentialError::UpdateCredentialError)?;
+
+        let result = CredentialEntity::update_many()
+            .col_expr(
+                crate::entity::credentials::Column::CredentialData,
+                Expr::value(data),
+            )
+            .col_expr(
+                crate::entity::credentials::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(crate::entity::credentials::Column::Id.eq(credential_id))
+            .filter(crate::entity::credentials::Column::UpdatedAt.eq(read_at.naive_utc()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to update OTP credential {}: {}", credential_id, e);
+                CredentialError::UpdateCredentialError
+            })?;
+
+        Ok(result.rows_affected == 1)
+    }
+
     async fn update_webauthn_credential(
         &self,
         auth_result: &AuthenticationResult,
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    sea_query::Expr,
 };
 use tracing::error;
 use uuid::Uuid;
@@ -9,7 +10,7 @@ use crate::{
     domain::{
         common::entities::app_errors::CoreError,
         email_verification::{
-            entities::EmailVerificationToken,
+            entities::{EmailTokenPurpose, EmailVerificationToken},
             ports::{CreateEmailVerificationTokenInput, EmailVerificationTokenRepository},
         },
     },
@@ -43,6 +44,9 @@ impl From<EvtModel> for EmailVerificationToken {
             created_at,
             expires_at,
             used_at,
+            purpose: EmailTokenPurpose::try_from(model.purpose.as_str())
+                .unwrap_or(EmailTokenPurpose::VerifyEmail),
+            email: model.email,
         }
     }
 }
@@ -61,6 +65,8 @@ impl EmailVerificationTokenRepository for PostgresEmailVerificationTokenReposito
             expires_at: Set(input.expires_at.fixed_offset()),
             created_at: Set(Utc::now().fixed_offset()),
             used_at: Set(None),
+            purpose: Set(input.purpose.to_string()),
+            email: Set(input.email),
         };
 
         let model = active_model.insert(&self.db).await.map_err(|e| {
@@ -140,4 +146,24 @@ impl EmailVerificationTokenRepository for PostgresEmailVerificationTokenReposito
 
         Ok(result.rows_affected)
     }
+
+    async fn revoke_pending(
+        &self,
+        user_id: Uuid,
+        purpose: EmailTokenPurpose,
+    ) -> Result<u64, CoreError> {
+        let result = EvtEntity::update_many()
+            .col_expr(EvtColumn::UsedAt, Expr::value(Utc::now().fixed_offset()))
+            .filter(EvtColumn::UserId.eq(user_id))
+            .filter(EvtColumn::Purpose.eq(purpose.to_string()))
+            .filter(EvtColumn::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to revoke email verification tokens: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected)
+    }
 }
@@ -8,6 +8,7 @@ use crate::domain::realm::entities::RealmId;
 use crate::domain::{
     common::generate_uuid_v7,
     jwt::{JwtError, entities::JwtKeyPair},
+    secrets::{EnvelopeCipher, SecretCipher},
 };
 
 impl TryFrom<crate::entity::jwt_keys::Model> for JwtKeyPair {
@@ -28,11 +29,12 @@ impl TryFrom<crate::entity::jwt_keys::Model> for JwtKeyPair {
 #[derive(Debug, Clone)]
 pub struct PostgresKeyStoreRepository {
     pub db: DatabaseConnection,
+    pub cipher: EnvelopeCipher,
 }
 
 impl PostgresKeyStoreRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+    pub fn new(db: DatabaseConnection, cipher: EnvelopeCipher) -> Self {
+        Self { db, cipher }
     }
 }
 
@@ -44,7 +46,8 @@ impl KeyStoreRepository for PostgresKeyStoreRepository {
             .await
             .map_err(|_| JwtError::RealmKeyNotFound)?;
 
-        if let Some(key) = key {
+        if let Some(mut key) = key {
+            key.private_key = self.cipher.decrypt(&key.private_key).await?;
             return key.try_into();
         }
 
@@ -57,7 +60,7 @@ impl KeyStoreRepository for PostgresKeyStoreRepository {
             id: Set(id),
             realm_id: Set(realm_id.into()),
             public_key: Set(public_key),
-            private_key: Set(private_key),
+            private_key: Set(self.cipher.encrypt(&private_key).await?),
             created_at: Set(chrono::Utc::now().naive_utc()),
         };
 
@@ -67,6 +70,6 @@ impl KeyStoreRepository for PostgresKeyStoreRepository {
             .await
             .map_err(|e| JwtError::GenerationError(e.to_string()))?;
 
-        result.try_into()
+        JwtKeyPair::from_pem(&private_key, &result.public_key, result.realm_id, result.id)
     }
 }
@@ -19,6 +19,7 @@ impl From<crate::entity::refresh_tokens::Model> for RefreshToken {
             id: model.id,
             jti: model.jti,
             user_id: model.user_id,
+            client_id: model.client_id,
             revoked: model.revoked,
             created_at,
             expires_at,
@@ -42,12 +43,14 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         &self,
         jti: Uuid,
         user_id: Uuid,
+        client_id: Option<Uuid>,
         expires_at: Option<DateTime<Utc>>,
     ) -> Result<RefreshToken, JwtError> {
         let model = crate::entity::refresh_tokens::ActiveModel {
             id: Set(generate_uuid_v7()),
             jti: Set(jti),
             user_id: Set(user_id),
+            client_id: Set(client_id),
             revoked: Set(false),
             created_at: Set(Utc::now().naive_utc()),
             expires_at: Set(expires_at.map(|dt| dt.naive_utc())),
@@ -0,0 +1,241 @@
+use std::{fs, net::IpAddr, path::Path};
+
+use tracing::info;
+
+use crate::domain::{
+    auth_flow::entities::IpRange,
+    common::{RiskConfig, entities::app_errors::CoreError},
+    risk::{entities::GeoLocation, ports::IpIntelligence},
+};
+
+/// Country DB-IP gives addresses it can't place.
+const UNKNOWN_COUNTRY: &str = "ZZ";
+
+/// Addresses of both families on one scale, IPv4 ones mapped into IPv6.
+fn ip_key(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ip) => u128::from(ip.to_ipv6_mapped()),
+        IpAddr::V6(ip) => u128::from(ip),
+    }
+}
+
+#[derive(Clone)]
+struct GeoRange {
+    start: u128,
+    end: u128,
+    location: GeoLocation,
+}
+
+/// Splits a CSV line, honouring double quotes.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+/// Reads a DB-IP lite CSV, either the country one
+/// (`start,end,country`) or the city one
+/// (`start,end,continent,country,region,city,latitude,longitude`).
+fn parse_geoip(content: &str) -> Result<Vec<GeoRange>, String> {
+    let mut ranges = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = || format!("line {}: unexpected format", index + 1);
+        let fields = split_csv_line(line);
+
+        let start: IpAddr = fields[0].parse().map_err(|_| invalid())?;
+        let end: IpAddr = fields
+            .get(1)
+            .and_then(|end| end.parse().ok())
+            .ok_or_else(invalid)?;
+
+        let (country, coordinates) = match fields.len() {
+            3 => (fields[2].clone(), None),
+            8 => (
+                fields[3].clone(),
+                fields[6].parse().ok().zip(fields[7].parse().ok()),
+            ),
+            _ => return Err(invalid()),
+        };
+
+        if country.is_empty() || country == UNKNOWN_COUNTRY {
+            continue;
+        }
+
+        ranges.push(GeoRange {
+            start: ip_key(start),
+            end: ip_key(end),
+            location: GeoLocation {
+                country,
+                coordinates,
+            },
+        });
+    }
+
+    ranges.sort_by_key(|range| range.start);
+
+    Ok(ranges)
+}
+
+/// Reads a list of addresses or CIDR ranges, one per line, `#` starting
+/// comments.
+fn parse_blocklist(content: &str) -> Result<Vec<IpRange>, String> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.split('#').next().unwrap_or_default().trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            IpRange::parse(line)
+                .ok_or_else(|| format!("line {}: invalid address {line}", index + 1))
+        })
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<String, CoreError> {
+    fs::read_to_string(path)
+        .map_err(|e| CoreError::Configuration(format!("cannot read {}: {e}", path.display())))
+}
+
+/// Looks addresses up in the GeoIP database and blocklists configured for
+/// the risk engine, loaded in memory at startup.
+#[derive(Clone, Default)]
+pub struct FileIpIntelligence {
+    geoip: Vec<GeoRange>,
+    blocklist: Vec<IpRange>,
+}
+
+impl std::fmt::Debug for FileIpIntelligence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileIpIntelligence")
+            .field("geoip_ranges", &self.geoip.len())
+            .field("blocklist_entries", &self.blocklist.len())
+            .finish()
+    }
+}
+
+impl FileIpIntelligence {
+    pub fn load(config: &RiskConfig) -> Result<Self, CoreError> {
+        let mut intelligence = Self::default();
+
+        if let Some(path) = &config.geoip_database {
+            intelligence.geoip = parse_geoip(&read_file(path)?).map_err(|e| {
+                CoreError::Configuration(format!("invalid GeoIP database {}: {e}", path.display()))
+            })?;
+            info!(
+                ranges = intelligence.geoip.len(),
+                "Loaded GeoIP database {}",
+                path.display()
+            );
+        }
+
+        for path in &config.ip_blocklists {
+            let ranges = parse_blocklist(&read_file(path)?).map_err(|e| {
+                CoreError::Configuration(format!("invalid IP blocklist {}: {e}", path.display()))
+            })?;
+            info!(
+                entries = ranges.len(),
+                "Loaded IP blocklist {}",
+                path.display()
+            );
+            intelligence.blocklist.extend(ranges);
+        }
+
+        Ok(intelligence)
+    }
+}
+
+impl IpIntelligence for FileIpIntelligence {
+    fn locate(&self, ip: IpAddr) -> Option<GeoLocation> {
+        let key = ip_key(ip);
+        let index = self.geoip.partition_point(|range| range.start <= key);
+        let range = self.geoip.get(index.checked_sub(1)?)?;
+
+        (key <= range.end).then(|| range.location.clone())
+    }
+
+    fn is_listed(&self, ip: IpAddr) -> bool {
+        self.blocklist.iter().any(|range| range.contains(ip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn intelligence(geoip: &str, blocklist: &str) -> FileIpIntelligence {
+        FileIpIntelligence {
+            geoip: parse_geoip(geoip).unwrap(),
+            blocklist: parse_blocklist(blocklist).unwrap(),
+        }
+    }
+
+    #[test]
+    fn locates_addresses_in_country_and_city_databases() {
+        let countries = intelligence(
+            "1.0.0.0,1.0.0.255,AU\n2.0.0.0,2.0.255.255,FR\n2001:db8::,2001:db8::ffff,DE\n",
+            "",
+        );
+
+        let located = |ip: &str| {
+            countries
+                .locate(ip.parse().unwrap())
+                .map(|location| location.country)
+        };
+        assert_eq!(located("2.0.1.2").as_deref(), Some("FR"));
+        assert_eq!(located("2001:db8::42").as_deref(), Some("DE"));
+        assert_eq!(located("1.0.1.0"), None);
+        assert_eq!(located("0.0.0.1"), None);
+
+        let cities = intelligence(
+            r#"1.0.0.0,1.0.0.255,OC,AU,Queensland,"South Brisbane, QLD",-27.4767,153.017"#,
+            "",
+        );
+        assert_eq!(
+            cities.locate("1.0.0.7".parse().unwrap()),
+            Some(GeoLocation {
+                country: "AU".to_string(),
+                coordinates: Some((-27.4767, 153.017)),
+            })
+        );
+    }
+
+    #[test]
+    fn blocklists_take_addresses_and_ranges() {
+        let lists = intelligence(
+            "",
+            "# TOR exit nodes\n203.0.113.7\n198.51.100.0/24 # scanners\n",
+        );
+
+        assert!(lists.is_listed("203.0.113.7".parse().unwrap()));
+        assert!(lists.is_listed("198.51.100.42".parse().unwrap()));
+        assert!(!lists.is_listed("203.0.113.8".parse().unwrap()));
+    }
+
+    #[test]
+    fn malformed_files_are_rejected() {
+        assert!(parse_geoip("not,a,database").is_err());
+        assert!(parse_blocklist("203.0.113.7\nnot-an-ip\n").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use crate::domain::risk::entities::RiskPolicy;
+use crate::entity::risk_policies;
+
+impl From<risk_policies::Model> for RiskPolicy {
+    fn from(model: risk_policies::Model) -> Self {
+        RiskPolicy {
+            realm_id: model.realm_id.into(),
+            enabled: model.enabled,
+            notify_threshold: model.notify_threshold as u8,
+            step_up_threshold: model.step_up_threshold as u8,
+            block_threshold: model.block_threshold as u8,
+            max_failures: model.max_failures as u32,
+            failure_window_minutes: model.failure_window_minutes as u32,
+            updated_at: model.updated_at.to_utc(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod ip_intelligence;
+mod mapper;
+pub mod repositories;
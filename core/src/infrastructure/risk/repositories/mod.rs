@@ -0,0 +1,3 @@
+pub mod risk_policy_postgres_repository;
+
+pub use risk_policy_postgres_repository::PostgresRiskPolicyRepository;
@@ -0,0 +1,71 @@
+use sea_orm::{ActiveValue::Set, DatabaseConnection, EntityTrait, sea_query::OnConflict};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        realm::entities::RealmId,
+        risk::{entities::RiskPolicy, ports::RiskPolicyRepository},
+    },
+    entity::risk_policies,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRiskPolicyRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresRiskPolicyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {}: {}", context, e);
+    CoreError::InternalServerError
+}
+
+impl RiskPolicyRepository for PostgresRiskPolicyRepository {
+    async fn get_policy(&self, realm_id: RealmId) -> Result<Option<RiskPolicy>, CoreError> {
+        let model = risk_policies::Entity::find_by_id(Uuid::from(realm_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get risk policy", e))?;
+
+        Ok(model.map(RiskPolicy::from))
+    }
+
+    async fn upsert_policy(&self, policy: RiskPolicy) -> Result<RiskPolicy, CoreError> {
+        let model = risk_policies::ActiveModel {
+            realm_id: Set(policy.realm_id.into()),
+            enabled: Set(policy.enabled),
+            notify_threshold: Set(i16::from(policy.notify_threshold)),
+            step_up_threshold: Set(i16::from(policy.step_up_threshold)),
+            block_threshold: Set(i16::from(policy.block_threshold)),
+            max_failures: Set(policy.max_failures as i32),
+            failure_window_minutes: Set(policy.failure_window_minutes as i32),
+            updated_at: Set(policy.updated_at.into()),
+        };
+
+        let model = risk_policies::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(risk_policies::Column::RealmId)
+                    .update_columns([
+                        risk_policies::Column::Enabled,
+                        risk_policies::Column::NotifyThreshold,
+                        risk_policies::Column::StepUpThreshold,
+                        risk_policies::Column::BlockThreshold,
+                        risk_policies::Column::MaxFailures,
+                        risk_policies::Column::FailureWindowMinutes,
+                        risk_policies::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| database_error("upsert risk policy", e))?;
+
+        Ok(model.into())
+    }
+}
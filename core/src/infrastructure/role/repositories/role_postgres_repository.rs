@@ -1,6 +1,7 @@
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    IntoSimpleExpr, PaginatorTrait, QueryFilter, sea_query::SimpleExpr,
 };
 use uuid::Uuid;
 
@@ -10,9 +11,30 @@ use crate::domain::{
     role::{
         entities::{Role, permission::Permissions},
         ports::RoleRepository,
-        value_objects::{CreateRoleRequest, UpdateRolePermissionsRequest, UpdateRoleRequest},
+        value_objects::{
+            CreateRoleRequest, RoleFilter, RoleSortField, UpdateRolePermissionsRequest,
+            UpdateRoleRequest,
+        },
     },
 };
+use crate::infrastructure::common::{paginate, search_condition, timestamp_key};
+
+fn role_filter_condition(realm_id: RealmId, filter: &RoleFilter) -> Condition {
+    use crate::entity::roles::Column;
+
+    let mut condition = Condition::all()
+        .add(Column::RealmId.eq::<Uuid>(realm_id.into()))
+        .add(search_condition(
+            filter.search.as_deref(),
+            &[Column::Name, Column::Description],
+        ));
+
+    if let Some(client_id) = filter.client_id {
+        condition = condition.add(Column::ClientId.eq(client_id));
+    }
+
+    condition
+}
 
 #[derive(Debug, Clone)]
 pub struct PostgresRoleRepository {
@@ -100,6 +122,55 @@ impl RoleRepository for PostgresRoleRepository {
         Ok(())
     }
 
+    async fn list_roles(
+        &self,
+        realm_id: RealmId,
+        filter: RoleFilter,
+    ) -> Result<Vec<Role>, CoreError> {
+        use crate::entity::roles::Column;
+
+        let (sort, key): (SimpleExpr, _) = match filter.sort {
+            RoleSortField::Name => (
+                Column::Name.into_simple_expr(),
+                filter.page.cursor.as_ref().map(|c| c.key.clone().into()),
+            ),
+            RoleSortField::CreatedAt => (
+                Column::CreatedAt.into_simple_expr(),
+                filter
+                    .page
+                    .cursor
+                    .as_ref()
+                    .map(|c| timestamp_key(c).map(|t| t.naive_utc().into()))
+                    .transpose()?,
+            ),
+        };
+
+        let select = crate::entity::roles::Entity::find()
+            .filter(role_filter_condition(realm_id, &filter))
+            .find_also_related(crate::entity::clients::Entity);
+        let rows = paginate(select, sort, Column::Id, key, &filter.page)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(role, client)| {
+                let mut role: Role = role.into();
+                role.client = client.map(Into::into);
+                role
+            })
+            .collect())
+    }
+
+    async fn count_roles(&self, realm_id: RealmId, filter: RoleFilter) -> Result<u64, CoreError> {
+        crate::entity::roles::Entity::find()
+            .filter(role_filter_condition(realm_id, &filter))
+            .count(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
+
     async fn find_by_realm_id(&self, realm_id: RealmId) -> Result<Vec<Role>, CoreError> {
         let roles = crate::entity::roles::Entity::find()
             .filter(crate::entity::roles::Column::RealmId.eq::<Uuid>(realm_id.into()))
@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use ferriskey_security::jwt::ports::KeyStoreRepository;
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    seawatch::{chain::sign_checkpoint, ports::SecurityEventChainRepository},
+};
+
+/// Periodically signs the head of every realm hash chain that moved since its
+/// last checkpoint. Checkpoints let verification detect a chain that was
+/// rewritten end to end, which hashes alone cannot.
+pub async fn security_event_checkpoint_task<CH, K>(chain_repo: CH, keystore: K, interval: Duration)
+where
+    CH: SecurityEventChainRepository,
+    K: KeyStoreRepository,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        match checkpoint_chains(&chain_repo, &keystore).await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("SeaWatch chain: signed {count} checkpoints"),
+            Err(e) => tracing::error!("SeaWatch chain: checkpointing failed: {e}"),
+        }
+    }
+}
+
+pub async fn checkpoint_chains<CH, K>(chain_repo: &CH, keystore: &K) -> Result<u64, CoreError>
+where
+    CH: SecurityEventChainRepository,
+    K: KeyStoreRepository,
+{
+    let mut signed = 0;
+
+    for head in chain_repo.list_heads().await? {
+        let latest = chain_repo.get_latest_checkpoint(head.realm_id).await?;
+        if latest.is_some_and(|checkpoint| checkpoint.sequence >= head.sequence) {
+            continue;
+        }
+
+        let key_pair = match keystore.get_or_generate_key(head.realm_id).await {
+            Ok(key_pair) => key_pair,
+            Err(e) => {
+                // One realm without a usable key must not block the others.
+                tracing::error!(
+                    "SeaWatch chain: no signing key for realm {:?}: {e}",
+                    head.realm_id
+                );
+                continue;
+            }
+        };
+
+        chain_repo
+            .store_checkpoint(sign_checkpoint(&head, &key_pair)?)
+            .await?;
+        signed += 1;
+    }
+
+    Ok(signed)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::{
+        jwt::{JwtError, entities::JwtKeyPair},
+        realm::entities::RealmId,
+        seawatch::{
+            entities::{SecurityEventChainHead, SecurityEventCheckpoint},
+            ports::MockSecurityEventChainRepository,
+        },
+    };
+
+    struct NoKeyStore;
+
+    impl KeyStoreRepository for NoKeyStore {
+        async fn get_or_generate_key(&self, _realm_id: RealmId) -> Result<JwtKeyPair, JwtError> {
+            Err(JwtError::RealmKeyNotFound)
+        }
+    }
+
+    #[tokio::test]
+    async fn skips_heads_that_are_already_checkpointed() {
+        let realm_id = RealmId::default();
+
+        let mut chain_repo = MockSecurityEventChainRepository::new();
+        chain_repo.expect_list_heads().returning(move || {
+            Box::pin(async move {
+                Ok(vec![SecurityEventChainHead {
+                    realm_id,
+                    sequence: 42,
+                    hash: "abc".to_string(),
+                }])
+            })
+        });
+        chain_repo
+            .expect_get_latest_checkpoint()
+            .returning(move |_| {
+                Box::pin(async move {
+                    Ok(Some(SecurityEventCheckpoint {
+                        id: Uuid::now_v7(),
+                        realm_id,
+                        sequence: 42,
+                        hash: "abc".to_string(),
+                        signature: "sig".to_string(),
+                        created_at: Utc::now(),
+                    }))
+                })
+            });
+        chain_repo.expect_store_checkpoint().never();
+
+        let signed = checkpoint_chains(&chain_repo, &NoKeyStore).await.unwrap();
+
+        assert_eq!(signed, 0);
+    }
+
+    #[tokio::test]
+    async fn realm_without_key_does_not_fail_the_run() {
+        let mut chain_repo = MockSecurityEventChainRepository::new();
+        chain_repo.expect_list_heads().returning(|| {
+            Box::pin(async {
+                Ok(vec![SecurityEventChainHead {
+                    realm_id: RealmId::default(),
+                    sequence: 1,
+                    hash: "abc".to_string(),
+                }])
+            })
+        });
+        chain_repo
+            .expect_get_latest_checkpoint()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        chain_repo.expect_store_checkpoint().never();
+
+        let signed = checkpoint_chains(&chain_repo, &NoKeyStore).await.unwrap();
+
+        assert_eq!(signed, 0);
+    }
+}
@@ -1,39 +1,34 @@
 use chrono::{TimeZone, Utc};
 use sea_orm::ActiveValue::Set;
 
-use crate::domain::seawatch::entities::{ActorType, EventStatus, SecurityEvent, SecurityEventType};
-use crate::entity::security_events;
+use crate::domain::seawatch::entities::{
+    ActorType, AdminEvent, AdminOperation, EventStatus, SecurityEvent, SecurityEventChainHead,
+    SecurityEventCheckpoint, SecurityEventLink, SecurityEventRetentionPolicy, SecurityEventType,
+};
+use crate::entity::{
+    admin_events, security_event_chain_heads, security_event_checkpoints,
+    security_event_retention_policies, security_events,
+};
+
+fn parse_actor_type(value: &str) -> Option<ActorType> {
+    match value {
+        "user" => Some(ActorType::User),
+        "service_account" => Some(ActorType::ServiceAccount),
+        "admin" => Some(ActorType::Admin),
+        "system" => Some(ActorType::System),
+        _ => None,
+    }
+}
 
 impl From<security_events::Model> for SecurityEvent {
     fn from(model: security_events::Model) -> Self {
-        let actor_type = model.actor_type.and_then(|s| match s.as_str() {
-            "user" => Some(ActorType::User),
-            "service_account" => Some(ActorType::ServiceAccount),
-            "admin" => Some(ActorType::Admin),
-            "system" => Some(ActorType::System),
-            _ => None,
-        });
+        let actor_type = model.actor_type.as_deref().and_then(parse_actor_type);
 
-        let event_type = match model.event_type.as_str() {
-            "login_success" => SecurityEventType::LoginSuccess,
-            "login_failure" => SecurityEventType::LoginFailure,
-            "password_reset" => SecurityEventType::PasswordReset,
-            "password_reset_requested" => SecurityEventType::PasswordResetRequested,
-            "password_reset_completed" => SecurityEventType::PasswordResetCompleted,
-            "user_created" => SecurityEventType::UserCreated,
-            "user_email_verified" => SecurityEventType::UserEmailVerified,
-            "user_deleted" => SecurityEventType::UserDeleted,
-            "role_assigned" => SecurityEventType::RoleAssigned,
-            "role_unassigned" => SecurityEventType::RoleUnassigned,
-            "role_created" => SecurityEventType::RoleCreated,
-            "role_removed" => SecurityEventType::RoleRemoved,
-            "client_created" => SecurityEventType::ClientCreated,
-            "client_deleted" => SecurityEventType::ClientDeleted,
-            "client_secret_rotated" => SecurityEventType::ClientSecretRotated,
-            "realm_config_changed" => SecurityEventType::RealmConfigChanged,
-            "email_not_sent" => SecurityEventType::EmailNotSent,
-            _ => SecurityEventType::LoginSuccess,
-        };
+        let event_type =
+            SecurityEventType::try_from(model.event_type.clone()).unwrap_or_else(|e| {
+                tracing::warn!("{e}");
+                SecurityEventType::LoginSuccess
+            });
 
         let status = match model.status.as_str() {
             "success" => EventStatus::Success,
@@ -60,8 +55,10 @@ impl From<security_events::Model> for SecurityEvent {
     }
 }
 
-impl From<SecurityEvent> for security_events::ActiveModel {
-    fn from(event: SecurityEvent) -> Self {
+impl From<SecurityEventLink> for security_events::ActiveModel {
+    fn from(link: SecurityEventLink) -> Self {
+        let event = link.event;
+
         security_events::ActiveModel {
             id: Set(event.id.into()),
             realm_id: Set(event.realm_id.into()),
@@ -78,6 +75,136 @@ impl From<SecurityEvent> for security_events::ActiveModel {
             user_agent: Set(event.user_agent),
             details: Set(event.details),
             created_at: Set(Utc::now().naive_utc()),
+            sequence: Set(Some(link.sequence)),
+            prev_hash: Set(Some(link.prev_hash)),
+            hash: Set(Some(link.hash)),
+            pseudonymized_at: Set(None),
+        }
+    }
+}
+
+/// Rows stored before chaining was introduced have no chain columns and are
+/// not part of any link.
+impl TryFrom<security_events::Model> for SecurityEventLink {
+    type Error = ();
+
+    fn try_from(mut model: security_events::Model) -> Result<Self, Self::Error> {
+        let (Some(sequence), Some(prev_hash), Some(hash)) = (
+            model.sequence.take(),
+            model.prev_hash.take(),
+            model.hash.take(),
+        ) else {
+            return Err(());
+        };
+
+        let pseudonymized = model.pseudonymized_at.is_some();
+
+        Ok(SecurityEventLink {
+            event: model.into(),
+            sequence,
+            prev_hash,
+            hash,
+            pseudonymized,
+        })
+    }
+}
+
+impl From<security_event_chain_heads::Model> for SecurityEventChainHead {
+    fn from(model: security_event_chain_heads::Model) -> Self {
+        SecurityEventChainHead {
+            realm_id: model.realm_id.into(),
+            sequence: model.sequence,
+            hash: model.hash,
+        }
+    }
+}
+
+impl From<security_event_checkpoints::Model> for SecurityEventCheckpoint {
+    fn from(model: security_event_checkpoints::Model) -> Self {
+        SecurityEventCheckpoint {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            sequence: model.sequence,
+            hash: model.hash,
+            signature: model.signature,
+            created_at: model.created_at.to_utc(),
+        }
+    }
+}
+
+impl From<SecurityEventCheckpoint> for security_event_checkpoints::ActiveModel {
+    fn from(checkpoint: SecurityEventCheckpoint) -> Self {
+        security_event_checkpoints::ActiveModel {
+            id: Set(checkpoint.id),
+            realm_id: Set(checkpoint.realm_id.into()),
+            sequence: Set(checkpoint.sequence),
+            hash: Set(checkpoint.hash),
+            signature: Set(checkpoint.signature),
+            created_at: Set(checkpoint.created_at.into()),
+        }
+    }
+}
+
+impl From<security_event_retention_policies::Model> for SecurityEventRetentionPolicy {
+    fn from(model: security_event_retention_policies::Model) -> Self {
+        SecurityEventRetentionPolicy {
+            realm_id: model.realm_id.into(),
+            retention_days: model.retention_days.map(|days| days as u32),
+            updated_at: model.updated_at.to_utc(),
+        }
+    }
+}
+
+impl From<admin_events::Model> for AdminEvent {
+    fn from(model: admin_events::Model) -> Self {
+        let operation = AdminOperation::try_from(model.operation).unwrap_or_else(|e| {
+            tracing::warn!("{e}");
+            AdminOperation::Update
+        });
+
+        let changes = serde_json::from_value(model.changes).unwrap_or_else(|e| {
+            tracing::warn!("Invalid admin event changes: {e}");
+            Vec::new()
+        });
+
+        AdminEvent {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            actor_id: model.actor_id,
+            actor_type: parse_actor_type(&model.actor_type).unwrap_or(ActorType::Admin),
+            actor_name: model.actor_name,
+            operation,
+            resource_type: model.resource_type,
+            resource_id: model.resource_id,
+            resource_path: model.resource_path,
+            before: model.before,
+            after: model.after,
+            changes,
+            ip_address: model.ip_address,
+            user_agent: model.user_agent,
+            timestamp: Utc.from_utc_datetime(&model.timestamp),
+        }
+    }
+}
+
+impl From<AdminEvent> for admin_events::ActiveModel {
+    fn from(event: AdminEvent) -> Self {
+        admin_events::ActiveModel {
+            id: Set(event.id),
+            realm_id: Set(event.realm_id.into()),
+            actor_id: Set(event.actor_id),
+            actor_type: Set(event.actor_type.to_string()),
+            actor_name: Set(event.actor_name),
+            operation: Set(event.operation.to_string()),
+            resource_type: Set(event.resource_type),
+            resource_id: Set(event.resource_id),
+            resource_path: Set(event.resource_path),
+            before: Set(event.before),
+            after: Set(event.after),
+            changes: Set(serde_json::to_value(event.changes).unwrap_or_default()),
+            ip_address: Set(event.ip_address),
+            user_agent: Set(event.user_agent),
+            timestamp: Set(event.timestamp.naive_utc()),
         }
     }
 }
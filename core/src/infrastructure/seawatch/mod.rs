@@ -1,2 +1,5 @@
+pub mod checkpoint;
 mod mapper;
 pub mod repositories;
+pub mod retention;
+pub mod syslog;
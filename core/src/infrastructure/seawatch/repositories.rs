@@ -1 +1,4 @@
+pub mod admin_event_postgres_repository;
+pub mod security_event_chain_postgres_repository;
 pub mod security_event_postgres_repository;
+pub mod security_event_retention_postgres_repository;
@@ -0,0 +1,99 @@
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::domain::seawatch::{
+    entities::AdminEvent, ports::AdminEventRepository, value_objects::AdminEventFilter,
+};
+use crate::entity::admin_events;
+
+#[derive(Debug, Clone)]
+pub struct PostgresAdminEventRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresAdminEventRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl AdminEventRepository for PostgresAdminEventRepository {
+    async fn store_event(&self, event: AdminEvent) -> Result<(), CoreError> {
+        let active_model: admin_events::ActiveModel = event.into();
+
+        admin_events::Entity::insert(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to store admin event: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    async fn get_events(
+        &self,
+        realm_id: RealmId,
+        filter: AdminEventFilter,
+    ) -> Result<Vec<AdminEvent>, CoreError> {
+        let mut query = admin_events::Entity::find()
+            .filter(admin_events::Column::RealmId.eq::<Uuid>(realm_id.into()));
+
+        if let Some(actor_id) = filter.actor_id {
+            query = query.filter(admin_events::Column::ActorId.eq(actor_id));
+        }
+
+        if let Some(resource_type) = filter.resource_type {
+            query = query.filter(admin_events::Column::ResourceType.eq(resource_type));
+        }
+
+        if let Some(resource_id) = filter.resource_id {
+            query = query.filter(admin_events::Column::ResourceId.eq(resource_id));
+        }
+
+        if let Some(operation) = filter.operation {
+            query = query.filter(admin_events::Column::Operation.eq(operation.to_string()));
+        }
+
+        if let Some(from) = filter.from_timestamp {
+            query = query.filter(admin_events::Column::Timestamp.gte(from.naive_utc()));
+        }
+
+        if let Some(to) = filter.to_timestamp {
+            query = query.filter(admin_events::Column::Timestamp.lte(to.naive_utc()));
+        }
+
+        if let Some(cursor) = &filter.cursor {
+            let timestamp = cursor.timestamp.naive_utc();
+            query = query.filter(
+                Condition::any()
+                    .add(admin_events::Column::Timestamp.lt(timestamp))
+                    .add(
+                        Condition::all()
+                            .add(admin_events::Column::Timestamp.eq(timestamp))
+                            .add(admin_events::Column::Id.lt(cursor.id)),
+                    ),
+            );
+        }
+
+        query = query
+            .order_by_desc(admin_events::Column::Timestamp)
+            .order_by_desc(admin_events::Column::Id);
+
+        if let Some(limit) = filter.limit {
+            query = query.limit(limit as u64);
+        }
+
+        let models = query.all(&self.db).await.map_err(|e| {
+            tracing::error!("Failed to get admin events: {}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(models.into_iter().map(AdminEvent::from).collect())
+    }
+}
@@ -0,0 +1,143 @@
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::domain::seawatch::{
+    entities::{SecurityEventChainHead, SecurityEventCheckpoint, SecurityEventLink},
+    ports::SecurityEventChainRepository,
+};
+use crate::entity::{security_event_chain_heads, security_event_checkpoints, security_events};
+
+#[derive(Debug, Clone)]
+pub struct PostgresSecurityEventChainRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresSecurityEventChainRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl SecurityEventChainRepository for PostgresSecurityEventChainRepository {
+    async fn get_head(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<SecurityEventChainHead>, CoreError> {
+        let model = security_event_chain_heads::Entity::find_by_id::<Uuid>(realm_id.into())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get security event chain head: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(SecurityEventChainHead::from))
+    }
+
+    async fn list_heads(&self) -> Result<Vec<SecurityEventChainHead>, CoreError> {
+        let models = security_event_chain_heads::Entity::find()
+            .filter(security_event_chain_heads::Column::Sequence.gt(0))
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list security event chain heads: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models
+            .into_iter()
+            .map(SecurityEventChainHead::from)
+            .collect())
+    }
+
+    async fn get_links(
+        &self,
+        realm_id: RealmId,
+        after_sequence: i64,
+        limit: u32,
+    ) -> Result<Vec<SecurityEventLink>, CoreError> {
+        let models = security_events::Entity::find()
+            .filter(security_events::Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .filter(security_events::Column::Sequence.gt(after_sequence))
+            .order_by_asc(security_events::Column::Sequence)
+            .limit(limit as u64)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get security event chain links: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        // `sequence > n` excludes unchained rows, and chained rows always
+        // carry both hashes, so nothing is dropped here in practice.
+        Ok(models
+            .into_iter()
+            .filter_map(|model| SecurityEventLink::try_from(model).ok())
+            .collect())
+    }
+
+    async fn list_checkpoints(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Vec<SecurityEventCheckpoint>, CoreError> {
+        let models = security_event_checkpoints::Entity::find()
+            .filter(security_event_checkpoints::Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .order_by_asc(security_event_checkpoints::Column::Sequence)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list security event checkpoints: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models
+            .into_iter()
+            .map(SecurityEventCheckpoint::from)
+            .collect())
+    }
+
+    async fn get_latest_checkpoint(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<SecurityEventCheckpoint>, CoreError> {
+        let model = security_event_checkpoints::Entity::find()
+            .filter(security_event_checkpoints::Column::RealmId.eq::<Uuid>(realm_id.into()))
+            .order_by_desc(security_event_checkpoints::Column::Sequence)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get latest security event checkpoint: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(SecurityEventCheckpoint::from))
+    }
+
+    async fn store_checkpoint(&self, checkpoint: SecurityEventCheckpoint) -> Result<(), CoreError> {
+        let active_model: security_event_checkpoints::ActiveModel = checkpoint.into();
+
+        // Re-signing an unchanged head is a no-op.
+        security_event_checkpoints::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    security_event_checkpoints::Column::RealmId,
+                    security_event_checkpoints::Column::Sequence,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to store security event checkpoint: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
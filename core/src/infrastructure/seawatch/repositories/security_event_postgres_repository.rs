@@ -1,38 +1,117 @@
+use chrono::{DateTime, Utc};
 use sea_orm::{
-    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait, sea_query::OnConflict,
 };
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::domain::realm::entities::RealmId;
 use crate::domain::seawatch::{
-    entities::SecurityEvent, ports::SecurityEventRepository, value_objects::SecurityEventFilter,
+    chain::{GENESIS_HASH, next_link},
+    entities::{SecurityEvent, SecurityEventChainHead},
+    ports::SecurityEventRepository,
+    value_objects::SecurityEventFilter,
 };
-use crate::entity::security_events;
+use crate::entity::{security_event_chain_heads, security_events};
+
+/// Events the user caused or that target them.
+fn user_condition(user_id: Uuid) -> Condition {
+    Condition::any()
+        .add(security_events::Column::ActorId.eq(user_id))
+        .add(
+            Condition::all()
+                .add(security_events::Column::TargetType.eq("user"))
+                .add(security_events::Column::TargetId.eq(user_id)),
+        )
+}
 
 #[derive(Debug, Clone)]
 pub struct PostgresSecurityEventRepository {
     pub db: DatabaseConnection,
+    forwarder: Option<mpsc::Sender<SecurityEvent>>,
 }
 
 impl PostgresSecurityEventRepository {
     pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+        Self {
+            db,
+            forwarder: None,
+        }
+    }
+
+    /// Hands every stored event to the syslog forwarder task as well.
+    pub fn with_forwarder(mut self, forwarder: mpsc::Sender<SecurityEvent>) -> Self {
+        self.forwarder = Some(forwarder);
+        self
     }
 }
 
 impl SecurityEventRepository for PostgresSecurityEventRepository {
     async fn store_event(&self, event: SecurityEvent) -> Result<(), CoreError> {
-        let active_model: security_events::ActiveModel = event.into();
+        let realm_id: Uuid = event.realm_id.into();
+        let db_error = |e: sea_orm::DbErr| {
+            tracing::error!("Failed to store security event: {}", e);
+            CoreError::InternalServerError
+        };
+
+        let txn = self.db.begin().await.map_err(db_error)?;
+
+        // Make sure the realm has a head row, then lock it: appends to the
+        // same chain are serialized until the transaction commits.
+        security_event_chain_heads::Entity::insert(security_event_chain_heads::ActiveModel {
+            realm_id: Set(realm_id),
+            sequence: Set(0),
+            hash: Set(GENESIS_HASH.to_string()),
+            updated_at: Set(Utc::now().into()),
+        })
+        .on_conflict(
+            OnConflict::column(security_event_chain_heads::Column::RealmId)
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await
+        .map_err(db_error)?;
+
+        let head: SecurityEventChainHead = security_event_chain_heads::Entity::find_by_id(realm_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(db_error)?
+            .ok_or(CoreError::InternalServerError)?
+            .into();
+
+        let link = next_link(head.realm_id, Some(&head), event);
+
+        // Forwarding is best effort: a slow or unreachable collector must never
+        // fail the request that produced the event.
+        let forwarded = self.forwarder.as_ref().map(|tx| (tx, link.event.clone()));
+
+        let head_update = security_event_chain_heads::ActiveModel {
+            realm_id: Set(realm_id),
+            sequence: Set(link.sequence),
+            hash: Set(link.hash.clone()),
+            updated_at: Set(Utc::now().into()),
+        };
+
+        let active_model: security_events::ActiveModel = link.into();
 
         security_events::Entity::insert(active_model)
-            .exec(&self.db)
+            .exec(&txn)
             .await
-            .map_err(|e| {
-                tracing::error!("Failed to store security event: {}", e);
-                CoreError::InternalServerError
-            })?;
+            .map_err(db_error)?;
+
+        head_update.update(&txn).await.map_err(db_error)?;
+
+        txn.commit().await.map_err(db_error)?;
+
+        if let Some((tx, event)) = forwarded
+            && let Err(e) = tx.try_send(event)
+        {
+            tracing::warn!("Dropping security event for syslog forwarding: {}", e);
+        }
 
         Ok(())
     }
@@ -49,6 +128,10 @@ impl SecurityEventRepository for PostgresSecurityEventRepository {
             query = query.filter(security_events::Column::ActorId.eq(actor_id));
         }
 
+        if let Some(user_id) = filter.user_id {
+            query = query.filter(user_condition(user_id));
+        }
+
         if let Some(client_id) = filter.client_id {
             query = query.filter(security_events::Column::TargetId.eq(client_id));
         }
@@ -71,13 +154,30 @@ impl SecurityEventRepository for PostgresSecurityEventRepository {
             query = query.filter(security_events::Column::IpAddress.eq(ip));
         }
 
-        query = query.order_by_desc(security_events::Column::Timestamp);
+        if let Some(cursor) = &filter.cursor {
+            let timestamp = cursor.timestamp.naive_utc();
+            query = query.filter(
+                Condition::any()
+                    .add(security_events::Column::Timestamp.lt(timestamp))
+                    .add(
+                        Condition::all()
+                            .add(security_events::Column::Timestamp.eq(timestamp))
+                            .add(security_events::Column::Id.lt(cursor.id)),
+                    ),
+            );
+        }
+
+        query = query
+            .order_by_desc(security_events::Column::Timestamp)
+            .order_by_desc(security_events::Column::Id);
 
         if let Some(limit) = filter.limit {
             query = query.limit(limit as u64);
         }
 
-        if let Some(offset) = filter.offset {
+        if filter.cursor.is_none()
+            && let Some(offset) = filter.offset
+        {
             query = query.offset(offset as u64);
         }
 
@@ -121,6 +221,10 @@ impl SecurityEventRepository for PostgresSecurityEventRepository {
                 query = query.filter(security_events::Column::ActorId.eq(actor_id));
             }
 
+            if let Some(user_id) = filter.user_id {
+                query = query.filter(user_condition(user_id));
+            }
+
             if let Some(client_id) = filter.client_id {
                 query = query.filter(security_events::Column::TargetId.eq(client_id));
             }
@@ -151,4 +255,37 @@ impl SecurityEventRepository for PostgresSecurityEventRepository {
             Ok(count as i64)
         }
     }
+
+    async fn delete_events_before(
+        &self,
+        realm_id: RealmId,
+        before: DateTime<Utc>,
+    ) -> Result<u64, CoreError> {
+        let realm_id: Uuid = realm_id.into();
+        let db_error = |e: sea_orm::DbErr| {
+            tracing::error!("Failed to purge security events: {}", e);
+            CoreError::InternalServerError
+        };
+
+        let mut query = security_events::Entity::delete_many()
+            .filter(security_events::Column::RealmId.eq(realm_id))
+            .filter(security_events::Column::Timestamp.lt(before.naive_utc()));
+
+        let head = security_event_chain_heads::Entity::find_by_id(realm_id)
+            .one(&self.db)
+            .await
+            .map_err(db_error)?;
+
+        if let Some(head) = head {
+            query = query.filter(
+                Condition::any()
+                    .add(security_events::Column::Sequence.is_null())
+                    .add(security_events::Column::Sequence.lt(head.sequence)),
+            );
+        }
+
+        let result = query.exec(&self.db).await.map_err(db_error)?;
+
+        Ok(result.rows_affected)
+    }
 }
@@ -0,0 +1,83 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::realm::entities::RealmId;
+use crate::domain::seawatch::{
+    entities::SecurityEventRetentionPolicy, ports::SecurityEventRetentionRepository,
+};
+use crate::entity::security_event_retention_policies::{ActiveModel, Column, Entity};
+
+#[derive(Debug, Clone)]
+pub struct PostgresSecurityEventRetentionRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresSecurityEventRetentionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl SecurityEventRetentionRepository for PostgresSecurityEventRetentionRepository {
+    async fn get_by_realm_id(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<SecurityEventRetentionPolicy>, CoreError> {
+        let model = Entity::find_by_id::<Uuid>(realm_id.into())
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get security event retention policy: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.map(SecurityEventRetentionPolicy::from))
+    }
+
+    async fn upsert(
+        &self,
+        policy: SecurityEventRetentionPolicy,
+    ) -> Result<SecurityEventRetentionPolicy, CoreError> {
+        let model = ActiveModel {
+            realm_id: Set(policy.realm_id.into()),
+            retention_days: Set(policy.retention_days.map(|days| days as i32)),
+            updated_at: Set(Utc::now().into()),
+        };
+
+        let model = Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(Column::RealmId)
+                    .update_columns([Column::RetentionDays, Column::UpdatedAt])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to upsert security event retention policy: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(model.into())
+    }
+
+    async fn list_active(&self) -> Result<Vec<SecurityEventRetentionPolicy>, CoreError> {
+        let models = Entity::find()
+            .filter(Column::RetentionDays.is_not_null())
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list security event retention policies: {}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(models
+            .into_iter()
+            .map(SecurityEventRetentionPolicy::from)
+            .collect())
+    }
+}
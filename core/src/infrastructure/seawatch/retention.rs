@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    seawatch::ports::{SecurityEventRepository, SecurityEventRetentionRepository},
+};
+
+/// Periodically deletes security events that fell out of their realm's
+/// retention window. Realms without a policy keep their events forever.
+pub async fn security_event_retention_task<P, E>(
+    retention_repo: P,
+    event_repo: E,
+    interval: Duration,
+) where
+    P: SecurityEventRetentionRepository,
+    E: SecurityEventRepository,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+    loop {
+        ticker.tick().await;
+
+        match purge_expired_events(&retention_repo, &event_repo, Utc::now()).await {
+            Ok(0) => {}
+            Ok(purged) => tracing::info!("SeaWatch retention: purged {purged} security events"),
+            Err(e) => tracing::error!("SeaWatch retention: purge failed: {e}"),
+        }
+    }
+}
+
+pub async fn purge_expired_events<P, E>(
+    retention_repo: &P,
+    event_repo: &E,
+    now: DateTime<Utc>,
+) -> Result<u64, CoreError>
+where
+    P: SecurityEventRetentionRepository,
+    E: SecurityEventRepository,
+{
+    let mut purged = 0;
+
+    for policy in retention_repo.list_active().await? {
+        let Some(cutoff) = policy.cutoff(now) else {
+            continue;
+        };
+
+        // One failing realm must not stop the others from being purged.
+        match event_repo
+            .delete_events_before(policy.realm_id, cutoff)
+            .await
+        {
+            Ok(count) => purged += count,
+            Err(e) => tracing::error!(
+                "SeaWatch retention: failed to purge realm {:?}: {e}",
+                policy.realm_id
+            ),
+        }
+    }
+
+    Ok(purged)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::domain::{
+        realm::entities::RealmId,
+        seawatch::{
+            entities::SecurityEventRetentionPolicy,
+            ports::{MockSecurityEventRepository, MockSecurityEventRetentionRepository},
+        },
+    };
+
+    #[tokio::test]
+    async fn purges_each_realm_with_its_own_cutoff() {
+        let now = Utc.with_ymd_and_hms(2026, 7, 1, 12, 0, 0).unwrap();
+        let short = RealmId::default();
+        let long = RealmId::default();
+
+        let mut retention_repo = MockSecurityEventRetentionRepository::new();
+        retention_repo.expect_list_active().returning(move || {
+            Box::pin(async move {
+                Ok(vec![
+                    SecurityEventRetentionPolicy::new(short, Some(7)),
+                    SecurityEventRetentionPolicy::new(long, Some(365)),
+                ])
+            })
+        });
+
+        let mut event_repo = MockSecurityEventRepository::new();
+        event_repo
+            .expect_delete_events_before()
+            .withf(move |realm_id, before| {
+                *realm_id == short
+                    && *before == Utc.with_ymd_and_hms(2026, 6, 24, 12, 0, 0).unwrap()
+            })
+            .returning(|_, _| Box::pin(async { Ok(3) }));
+        event_repo
+            .expect_delete_events_before()
+            .withf(move |realm_id, before| {
+                *realm_id == long && *before == Utc.with_ymd_and_hms(2025, 7, 1, 12, 0, 0).unwrap()
+            })
+            .returning(|_, _| Box::pin(async { Ok(2) }));
+
+        let purged = purge_expired_events(&retention_repo, &event_repo, now)
+            .await
+            .unwrap();
+
+        assert_eq!(purged, 5);
+    }
+
+    #[tokio::test]
+    async fn keeps_going_when_a_realm_fails() {
+        let failing = RealmId::default();
+        let healthy = RealmId::default();
+
+        let mut retention_repo = MockSecurityEventRetentionRepository::new();
+        retention_repo.expect_list_active().returning(move || {
+            Box::pin(async move {
+                Ok(vec![
+                    SecurityEventRetentionPolicy::new(failing, Some(30)),
+                    SecurityEventRetentionPolicy::new(healthy, Some(30)),
+                ])
+            })
+        });
+
+        let mut event_repo = MockSecurityEventRepository::new();
+        event_repo
+            .expect_delete_events_before()
+            .withf(move |realm_id, _| *realm_id == failing)
+            .returning(|_, _| Box::pin(async { Err(CoreError::InternalServerError) }));
+        event_repo
+            .expect_delete_events_before()
+            .withf(move |realm_id, _| *realm_id == healthy)
+            .returning(|_, _| Box::pin(async { Ok(4) }));
+
+        let purged = purge_expired_events(&retention_repo, &event_repo, Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(purged, 4);
+    }
+}
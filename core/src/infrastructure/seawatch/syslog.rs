@@ -0,0 +1,247 @@
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpStream, UdpSocket},
+    sync::mpsc,
+};
+use uuid::Uuid;
+
+use crate::domain::seawatch::{
+    entities::{EventStatus, SecurityEvent},
+    value_objects::{SyslogConfig, SyslogFormat, SyslogTransport},
+};
+
+/// `authpriv` facility (RFC 5424 §6.2.1): security/authorization messages.
+const FACILITY_AUTHPRIV: u8 = 10;
+const SEVERITY_WARNING: u8 = 4;
+const SEVERITY_INFO: u8 = 6;
+
+const APP_NAME: &str = "ferriskey";
+/// Structured data ID. 32473 is the private enterprise number reserved for
+/// documentation (RFC 5612), as FerrisKey has no registered one.
+const SD_ID: &str = "ferriskey@32473";
+
+/// Drains the forwarding channel and ships every event to the configured
+/// collector. Delivery is best effort: events are dropped (and logged) while
+/// the collector is unreachable.
+pub async fn syslog_forwarder_task(
+    mut receiver: mpsc::Receiver<SecurityEvent>,
+    config: SyslogConfig,
+) {
+    let mut tcp: Option<TcpStream> = None;
+    let mut udp: Option<UdpSocket> = None;
+
+    while let Some(event) = receiver.recv().await {
+        let message = format_message(&event, &config);
+
+        let result = match config.transport {
+            SyslogTransport::Udp => send_udp(&mut udp, &config.address, &message).await,
+            SyslogTransport::Tcp => send_tcp(&mut tcp, &config.address, &message).await,
+        };
+
+        if let Err(e) = result {
+            tracing::warn!(
+                "SeaWatch syslog: failed to forward event to {}: {e}",
+                config.address
+            );
+        }
+    }
+}
+
+async fn send_udp(
+    socket: &mut Option<UdpSocket>,
+    address: &str,
+    message: &str,
+) -> std::io::Result<()> {
+    if socket.is_none() {
+        let bound = UdpSocket::bind("0.0.0.0:0").await?;
+        bound.connect(address).await?;
+        *socket = Some(bound);
+    }
+
+    if let Some(socket) = socket {
+        socket.send(message.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+async fn send_tcp(
+    stream: &mut Option<TcpStream>,
+    address: &str,
+    message: &str,
+) -> std::io::Result<()> {
+    if stream.is_none() {
+        *stream = Some(TcpStream::connect(address).await?);
+    }
+
+    // RFC 6587 octet-counting framing.
+    let frame = format!("{} {}", message.len(), message);
+
+    if let Some(connection) = stream
+        && let Err(e) = connection.write_all(frame.as_bytes()).await
+    {
+        // Reconnect on the next event rather than retrying here.
+        *stream = None;
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+pub fn format_message(event: &SecurityEvent, config: &SyslogConfig) -> String {
+    let severity = match event.status {
+        EventStatus::Success => SEVERITY_INFO,
+        EventStatus::Failure => SEVERITY_WARNING,
+    };
+    let pri = FACILITY_AUTHPRIV * 8 + severity;
+    let event_id: Uuid = event.id.clone().into();
+    let realm_id: Uuid = event.realm_id.into();
+
+    let mut structured_data = format!(
+        "[{SD_ID} eventId=\"{event_id}\" realmId=\"{realm_id}\" status=\"{}\"",
+        event.status
+    );
+    if let Some(actor_id) = event.actor_id {
+        structured_data.push_str(&format!(" actorId=\"{actor_id}\""));
+    }
+    if let Some(ip_address) = &event.ip_address {
+        structured_data.push_str(&format!(" ip=\"{}\"", escape_sd_value(ip_address)));
+    }
+    structured_data.push(']');
+
+    let msg = match config.format {
+        SyslogFormat::Rfc5424 => serde_json::to_string(event).unwrap_or_default(),
+        SyslogFormat::Cef => format_cef(event, severity),
+    };
+
+    format!(
+        "<{pri}>1 {} {} {APP_NAME} - {} {structured_data} {msg}",
+        event
+            .timestamp
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        nil_if_empty(&config.hostname),
+        event.event_type,
+    )
+}
+
+/// ArcSight Common Event Format payload. CEF severity runs 0-10, so syslog
+/// severities are mapped onto "low" (3) and "medium" (6).
+fn format_cef(event: &SecurityEvent, syslog_severity: u8) -> String {
+    let cef_severity = if syslog_severity == SEVERITY_WARNING {
+        6
+    } else {
+        3
+    };
+    let event_id: Uuid = event.id.clone().into();
+    let realm_id: Uuid = event.realm_id.into();
+
+    let mut extension = vec![
+        format!("rt={}", event.timestamp.timestamp_millis()),
+        format!("outcome={}", event.status),
+        format!("externalId={event_id}"),
+        "cs1Label=realmId".to_string(),
+        format!("cs1={realm_id}"),
+    ];
+    if let Some(actor_id) = event.actor_id {
+        extension.push(format!("suid={actor_id}"));
+    }
+    if let Some(ip_address) = &event.ip_address {
+        extension.push(format!("src={}", escape_cef_extension(ip_address)));
+    }
+    if let Some(user_agent) = &event.user_agent {
+        extension.push(format!(
+            "requestClientApplication={}",
+            escape_cef_extension(user_agent)
+        ));
+    }
+    if let Some(target_id) = event.target_id {
+        extension.push(format!("duid={target_id}"));
+    }
+
+    format!(
+        "CEF:0|FerrisKey|FerrisKey|{}|{}|{}|{cef_severity}|{}",
+        env!("CARGO_PKG_VERSION"),
+        escape_cef_header(&event.event_type.to_string()),
+        escape_cef_header(&event.event_type.to_string().replace('_', " ")),
+        extension.join(" ")
+    )
+}
+
+fn nil_if_empty(value: &str) -> &str {
+    if value.is_empty() { "-" } else { value }
+}
+
+fn escape_sd_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(']', "\\]")
+}
+
+fn escape_cef_header(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+fn escape_cef_extension(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('=', "\\=")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        realm::entities::RealmId,
+        seawatch::entities::{SecurityEvent, SecurityEventType},
+    };
+
+    fn config(format: SyslogFormat) -> SyslogConfig {
+        SyslogConfig {
+            address: "127.0.0.1:514".to_string(),
+            transport: SyslogTransport::Udp,
+            format,
+            hostname: "auth-1".to_string(),
+        }
+    }
+
+    fn failed_login() -> SecurityEvent {
+        SecurityEvent::new(
+            RealmId::default(),
+            SecurityEventType::LoginFailure,
+            EventStatus::Failure,
+            Uuid::now_v7(),
+        )
+        .with_context(
+            Some("203.0.113.7".to_string()),
+            Some("curl/8.0 a=b".to_string()),
+            None,
+        )
+    }
+
+    #[test]
+    fn rfc5424_header_uses_authpriv_and_event_type_as_msgid() {
+        let message = format_message(&failed_login(), &config(SyslogFormat::Rfc5424));
+
+        assert!(message.starts_with("<84>1 "));
+        assert!(message.contains(" auth-1 ferriskey - login_failure [ferriskey@32473 "));
+        assert!(message.contains("ip=\"203.0.113.7\""));
+    }
+
+    #[test]
+    fn cef_payload_escapes_extension_values() {
+        let message = format_message(&failed_login(), &config(SyslogFormat::Cef));
+
+        assert!(message.contains("CEF:0|FerrisKey|FerrisKey|"));
+        assert!(message.contains("|login_failure|login failure|6|"));
+        assert!(message.contains("requestClientApplication=curl/8.0 a\\=b"));
+        assert!(message.contains("outcome=failure"));
+    }
+
+    #[test]
+    fn structured_data_values_are_escaped() {
+        assert_eq!(escape_sd_value(r#"a"b]c\"#), r#"a\"b\]c\\"#);
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use ferriskey_security::SecurityError;
+use serde_json::Value;
+
+use crate::domain::{
+    common::{SecretsConfig, entities::app_errors::CoreError},
+    secrets::{EnvelopeCipher, LocalKeyProvider, MasterKey, SealedSecret, SecretCipher},
+};
+
+pub mod rotation;
+
+/// Location of the client secret in an identity provider config.
+pub const IDENTITY_PROVIDER_SECRET: &str = "/client_secret";
+/// Location of the bind password in an LDAP federation provider config.
+pub const FEDERATION_BIND_PASSWORD: &str = "/bind/bind_password_encrypted";
+
+/// Builds the cipher sealing secrets at rest from the local master key.
+pub fn build_secret_cipher(config: &SecretsConfig) -> Result<EnvelopeCipher, CoreError> {
+    let invalid = |e: SecurityError| CoreError::Configuration(e.to_string());
+
+    let previous = config
+        .previous_master_keys
+        .iter()
+        .map(|spec| MasterKey::parse(spec))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(invalid)?;
+
+    let provider = match (&config.master_key, &config.master_key_file) {
+        (Some(key), _) => LocalKeyProvider::new(
+            MasterKey::from_base64(config.master_key_id.clone(), key).map_err(invalid)?,
+            previous,
+        ),
+        (None, Some(path)) => {
+            LocalKeyProvider::from_file(config.master_key_id.clone(), path, previous)
+                .map_err(invalid)?
+        }
+        (None, None) => {
+            tracing::warn!(
+                "No secrets master key configured: OTP seeds, signing keys and provider secrets are stored in clear"
+            );
+            return Ok(EnvelopeCipher::disabled());
+        }
+    };
+
+    Ok(EnvelopeCipher::new(Arc::new(provider)))
+}
+
+pub(crate) fn cipher_error(context: &str, e: SecurityError) -> CoreError {
+    tracing::error!("Failed to {context}: {e}");
+    CoreError::InternalServerError
+}
+
+/// Seals the string at `pointer` in a JSON config, if present and not
+/// already sealed.
+pub(crate) async fn seal_json_secret<S: SecretCipher>(
+    cipher: &S,
+    config: &mut Value,
+    pointer: &str,
+) -> Result<(), SecurityError> {
+    if let Some(Value::String(secret)) = config.pointer_mut(pointer)
+        && !secret.is_empty()
+        && !SealedSecret::is_sealed(secret)
+    {
+        *secret = cipher.encrypt(secret).await?;
+    }
+
+    Ok(())
+}
+
+/// Reverses [`seal_json_secret`].
+pub(crate) async fn open_json_secret<S: SecretCipher>(
+    cipher: &S,
+    config: &mut Value,
+    pointer: &str,
+) -> Result<(), SecurityError> {
+    if let Some(Value::String(secret)) = config.pointer_mut(pointer) {
+        *secret = cipher.decrypt(secret).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, QueryFilter,
+    Set,
+};
+use serde_json::Value;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        secrets::{
+            EnvelopeCipher, SealedSecret, SecretCipher, entities::SecretRotationReport,
+            ports::SecretRotationRepository,
+        },
+    },
+    entity::{credentials, identity_providers, jwt_keys, smtp_configs, user_federation_providers},
+    infrastructure::{
+        abyss::federation::repository::decode_legacy_bind_password,
+        secrets::{FEDERATION_BIND_PASSWORD, IDENTITY_PROVIDER_SECRET, cipher_error},
+    },
+};
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {context}: {e}");
+    CoreError::InternalServerError
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresSecretRotationRepository {
+    pub db: DatabaseConnection,
+    pub cipher: EnvelopeCipher,
+}
+
+impl PostgresSecretRotationRepository {
+    pub fn new(db: DatabaseConnection, cipher: EnvelopeCipher) -> Self {
+        Self { db, cipher }
+    }
+
+    /// Returns `value` sealed with the current master key, or `None` when it
+    /// already is.
+    async fn reseal(&self, value: &str) -> Result<Option<String>, CoreError> {
+        if !self.cipher.needs_rotation(value) {
+            return Ok(None);
+        }
+
+        let plaintext = self
+            .cipher
+            .decrypt(value)
+            .await
+            .map_err(|e| cipher_error("decrypt secret for rotation", e))?;
+        self.cipher
+            .encrypt(&plaintext)
+            .await
+            .map(Some)
+            .map_err(|e| cipher_error("encrypt secret for rotation", e))
+    }
+
+    /// Reseals the string at `pointer` in place, returning whether it changed.
+    async fn reseal_json(
+        &self,
+        config: &mut Value,
+        pointer: &str,
+        legacy: fn(&str) -> String,
+    ) -> Result<bool, CoreError> {
+        let Some(Value::String(secret)) = config.pointer_mut(pointer) else {
+            return Ok(false);
+        };
+
+        let current = if SealedSecret::is_sealed(secret) {
+            secret.clone()
+        } else {
+            legacy(secret)
+        };
+
+        match self.reseal(&current).await? {
+            Some(sealed) => {
+                *secret = sealed;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+impl SecretRotationRepository for PostgresSecretRotationRepository {
+    async fn rotate_secrets(&self) -> Result<SecretRotationReport, CoreError> {
+        let mut report = SecretRotationReport::default();
+
+        if !self.cipher.is_enabled() {
+            return Ok(report);
+        }
+
+        let otp_credentials = credentials::Entity::find()
+            .filter(credentials::Column::CredentialType.eq("otp"))
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("load credentials for rotation", e))?;
+
+        for model in otp_credentials {
+            if let Some(sealed) = self.reseal(&model.secret_data).await? {
+                let mut active = model.into_active_model();
+                active.secret_data = Set(sealed);
+                active
+                    .update(&self.db)
+                    .await
+                    .map_err(|e| database_error("rotate credential secret", e))?;
+                report.credentials += 1;
+            }
+        }
+
+        let keys = jwt_keys::Entity::find()
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("load signing keys for rotation", e))?;
+
+        for model in keys {
+            if let Some(sealed) = self.reseal(&model.private_key).await? {
+                let mut active = model.into_active_model();
+                active.private_key = Set(sealed);
+                active
+                    .update(&self.db)
+                    .await
+                    .map_err(|e| database_error("rotate signing key", e))?;
+                report.jwt_keys += 1;
+            }
+        }
+
+        let smtp = smtp_configs::Entity::find()
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("load SMTP configs for rotation", e))?;
+
+        for model in smtp {
+            if let Some(sealed) = self.reseal(&model.password).await? {
+                let mut active = model.into_active_model();
+                active.password = Set(sealed);
+                active
+                    .update(&self.db)
+                    .await
+                    .map_err(|e| database_error("rotate SMTP password", e))?;
+                report.smtp_configs += 1;
+            }
+        }
+
+        let providers = identity_providers::Entity::find()
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("load identity providers for rotation", e))?;
+
+        for model in providers {
+            let mut config = model.config.clone();
+            if self
+                .reseal_json(&mut config, IDENTITY_PROVIDER_SECRET, str::to_string)
+                .await?
+            {
+                let mut active = model.into_active_model();
+                active.config = Set(config);
+                active
+                    .update(&self.db)
+                    .await
+                    .map_err(|e| database_error("rotate identity provider secret", e))?;
+                report.identity_providers += 1;
+            }
+        }
+
+        let federations = user_federation_providers::Entity::find()
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("load federation providers for rotation", e))?;
+
+        for model in federations {
+            let mut config = model.config.clone();
+            if self
+                .reseal_json(
+                    &mut config,
+                    FEDERATION_BIND_PASSWORD,
+                    decode_legacy_bind_password,
+                )
+                .await?
+            {
+                let mut active = model.into_active_model();
+                active.config = Set(config);
+                active
+                    .update(&self.db)
+                    .await
+                    .map_err(|e| database_error("rotate federation bind password", e))?;
+                report.federation_providers += 1;
+            }
+        }
+
+        Ok(report)
+    }
+}
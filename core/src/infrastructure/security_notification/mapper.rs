@@ -0,0 +1,33 @@
+use crate::domain::security_notification::entities::{SecurityNotificationSettings, SignInOrigin};
+use crate::entity::{security_notification_settings, user_sign_in_origins};
+
+impl From<security_notification_settings::Model> for SecurityNotificationSettings {
+    fn from(model: security_notification_settings::Model) -> Self {
+        SecurityNotificationSettings {
+            realm_id: model.realm_id.into(),
+            password_changed: model.password_changed,
+            new_sign_in: model.new_sign_in,
+            mfa_added: model.mfa_added,
+            mfa_removed: model.mfa_removed,
+            recovery_codes_regenerated: model.recovery_codes_regenerated,
+            account_locked: model.account_locked,
+            email_changed: model.email_changed,
+            suspicious_sign_in: model.suspicious_sign_in,
+            updated_at: model.updated_at.to_utc(),
+        }
+    }
+}
+
+impl From<user_sign_in_origins::Model> for SignInOrigin {
+    fn from(model: user_sign_in_origins::Model) -> Self {
+        SignInOrigin {
+            id: model.id,
+            user_id: model.user_id,
+            fingerprint: model.fingerprint,
+            ip_address: model.ip_address,
+            user_agent: model.user_agent,
+            first_seen_at: model.first_seen_at.to_utc(),
+            last_seen_at: model.last_seen_at.to_utc(),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod mapper;
+pub mod repositories;
@@ -0,0 +1,3 @@
+pub mod security_notification_postgres_repository;
+
+pub use security_notification_postgres_repository::PostgresSecurityNotificationRepository;
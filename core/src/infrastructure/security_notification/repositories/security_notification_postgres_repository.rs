@@ -0,0 +1,154 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder, sea_query::OnConflict,
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        realm::entities::RealmId,
+        security_notification::{
+            entities::{SecurityNotificationSettings, SignInOrigin},
+            ports::SecurityNotificationRepository,
+        },
+    },
+    entity::{security_notification_settings, user_sign_in_origins},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresSecurityNotificationRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresSecurityNotificationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {}: {}", context, e);
+    CoreError::InternalServerError
+}
+
+impl SecurityNotificationRepository for PostgresSecurityNotificationRepository {
+    async fn get_settings(
+        &self,
+        realm_id: RealmId,
+    ) -> Result<Option<SecurityNotificationSettings>, CoreError> {
+        let model = security_notification_settings::Entity::find_by_id(Uuid::from(realm_id))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get security notification settings", e))?;
+
+        Ok(model.map(SecurityNotificationSettings::from))
+    }
+
+    async fn upsert_settings(
+        &self,
+        settings: SecurityNotificationSettings,
+    ) -> Result<SecurityNotificationSettings, CoreError> {
+        let model = security_notification_settings::ActiveModel {
+            realm_id: Set(settings.realm_id.into()),
+            password_changed: Set(settings.password_changed),
+            new_sign_in: Set(settings.new_sign_in),
+            mfa_added: Set(settings.mfa_added),
+            mfa_removed: Set(settings.mfa_removed),
+            recovery_codes_regenerated: Set(settings.recovery_codes_regenerated),
+            account_locked: Set(settings.account_locked),
+            email_changed: Set(settings.email_changed),
+            suspicious_sign_in: Set(settings.suspicious_sign_in),
+            updated_at: Set(settings.updated_at.into()),
+        };
+
+        let model = security_notification_settings::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(security_notification_settings::Column::RealmId)
+                    .update_columns([
+                        security_notification_settings::Column::PasswordChanged,
+                        security_notification_settings::Column::NewSignIn,
+                        security_notification_settings::Column::MfaAdded,
+                        security_notification_settings::Column::MfaRemoved,
+                        security_notification_settings::Column::RecoveryCodesRegenerated,
+                        security_notification_settings::Column::AccountLocked,
+                        security_notification_settings::Column::EmailChanged,
+                        security_notification_settings::Column::SuspiciousSignIn,
+                        security_notification_settings::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| database_error("upsert security notification settings", e))?;
+
+        Ok(model.into())
+    }
+
+    async fn has_sign_in_origins(&self, user_id: Uuid) -> Result<bool, CoreError> {
+        let count = user_sign_in_origins::Entity::find()
+            .filter(user_sign_in_origins::Column::UserId.eq(user_id))
+            .count(&self.db)
+            .await
+            .map_err(|e| database_error("count sign-in origins", e))?;
+
+        Ok(count > 0)
+    }
+
+    async fn list_sign_in_origins(&self, user_id: Uuid) -> Result<Vec<SignInOrigin>, CoreError> {
+        let models = user_sign_in_origins::Entity::find()
+            .filter(user_sign_in_origins::Column::UserId.eq(user_id))
+            .order_by_desc(user_sign_in_origins::Column::LastSeenAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("list sign-in origins", e))?;
+
+        Ok(models.into_iter().map(SignInOrigin::from).collect())
+    }
+
+    async fn touch_sign_in_origin(&self, origin: SignInOrigin) -> Result<bool, CoreError> {
+        let existing = user_sign_in_origins::Entity::find()
+            .filter(user_sign_in_origins::Column::UserId.eq(origin.user_id))
+            .filter(user_sign_in_origins::Column::Fingerprint.eq(origin.fingerprint.clone()))
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get sign-in origin", e))?;
+
+        if let Some(existing) = existing {
+            let mut model = existing.into_active_model();
+            model.last_seen_at = Set(origin.last_seen_at.into());
+            model
+                .update(&self.db)
+                .await
+                .map_err(|e| database_error("update sign-in origin", e))?;
+
+            return Ok(false);
+        }
+
+        let model = user_sign_in_origins::ActiveModel {
+            id: Set(origin.id),
+            user_id: Set(origin.user_id),
+            fingerprint: Set(origin.fingerprint),
+            ip_address: Set(origin.ip_address),
+            user_agent: Set(origin.user_agent),
+            first_seen_at: Set(origin.first_seen_at.into()),
+            last_seen_at: Set(origin.last_seen_at.into()),
+        };
+
+        // A concurrent sign-in may have inserted the same origin meanwhile.
+        user_sign_in_origins::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    user_sign_in_origins::Column::UserId,
+                    user_sign_in_origins::Column::Fingerprint,
+                ])
+                .update_column(user_sign_in_origins::Column::LastSeenAt)
+                .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("insert sign-in origin", e))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,106 @@
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+use serde_json::json;
+use tracing::{debug, warn};
+
+use crate::domain::common::{SmsConfig, entities::app_errors::CoreError, sms::SmsSender};
+
+/// Longest gateway error body kept in the error.
+const MAX_ERROR_DETAIL: usize = 512;
+
+/// Renders the gateway body, replacing `{{to}}`, `{{from}}` and `{{body}}`
+/// with JSON strings (`{{from}}` is `null` without a sender). The template
+/// must not quote placeholders.
+pub fn render_sms_body(template: &str, from: Option<&str>, to: &str, body: &str) -> String {
+    template
+        .replace("{{to}}", &json!(to).to_string())
+        .replace("{{from}}", &json!(from).to_string())
+        .replace("{{body}}", &json!(body).to_string())
+}
+
+/// Posts messages to a generic HTTP SMS gateway. Without a gateway URL,
+/// messages are logged instead so that SMS codes work in local setups.
+#[derive(Debug, Clone)]
+pub struct HttpSmsSender {
+    http_client: reqwest::Client,
+    config: SmsConfig,
+}
+
+impl HttpSmsSender {
+    pub fn new(config: SmsConfig) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            config,
+        }
+    }
+}
+
+impl SmsSender for HttpSmsSender {
+    async fn send_sms(&self, to: &str, body: &str) -> Result<(), CoreError> {
+        let Some(url) = &self.config.gateway_url else {
+            warn!("No SMS gateway configured, logging the message instead");
+            debug!(to, body, "SMS message");
+            return Ok(());
+        };
+
+        let mut request = self
+            .http_client
+            .post(url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(render_sms_body(
+                &self.config.body_template,
+                self.config.sender.as_deref(),
+                to,
+                body,
+            ));
+        if let Some(authorization) = &self.config.gateway_authorization {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| CoreError::External(format!("Failed to reach SMS gateway: {e}")))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let detail: String = response
+            .text()
+            .await
+            .unwrap_or_default()
+            .chars()
+            .take(MAX_ERROR_DETAIL)
+            .collect();
+
+        Err(CoreError::External(format!(
+            "SMS gateway answered {status}: {detail}"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_escaped_gateway_body() {
+        let rendered = render_sms_body(
+            r#"{"from": {{from}}, "to": {{to}}, "text": {{body}}}"#,
+            None,
+            "+33612345678",
+            "123456 is your \"security\" code",
+        );
+
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "from": null,
+                "to": "+33612345678",
+                "text": "123456 is your \"security\" code",
+            })
+        );
+    }
+}
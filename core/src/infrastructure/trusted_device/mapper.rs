@@ -0,0 +1,18 @@
+use crate::domain::trusted_device::entities::TrustedDevice;
+use crate::entity::trusted_devices;
+
+impl From<trusted_devices::Model> for TrustedDevice {
+    fn from(model: trusted_devices::Model) -> Self {
+        TrustedDevice {
+            id: model.id,
+            realm_id: model.realm_id.into(),
+            user_id: model.user_id,
+            user_agent: model.user_agent,
+            ip_address: model.ip_address,
+            token_hash: model.token_hash,
+            created_at: model.created_at.to_utc(),
+            last_used_at: model.last_used_at.map(|used_at| used_at.to_utc()),
+            expires_at: model.expires_at.to_utc(),
+        }
+    }
+}
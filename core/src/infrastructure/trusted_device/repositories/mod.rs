@@ -0,0 +1,3 @@
+pub mod trusted_device_postgres_repository;
+
+pub use trusted_device_postgres_repository::PostgresTrustedDeviceRepository;
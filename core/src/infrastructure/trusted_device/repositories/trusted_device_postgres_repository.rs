@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    prelude::DateTimeWithTimeZone, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::domain::common::entities::app_errors::CoreError;
+use crate::domain::trusted_device::{entities::TrustedDevice, ports::TrustedDeviceRepository};
+use crate::entity::trusted_devices::{ActiveModel, Column, Entity};
+
+#[derive(Debug, Clone)]
+pub struct PostgresTrustedDeviceRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresTrustedDeviceRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn database_error(context: &str, e: impl std::fmt::Display) -> CoreError {
+    tracing::error!("Failed to {}: {}", context, e);
+    CoreError::InternalServerError
+}
+
+impl TrustedDeviceRepository for PostgresTrustedDeviceRepository {
+    async fn create(&self, device: TrustedDevice) -> Result<(), CoreError> {
+        let model = ActiveModel {
+            id: Set(device.id),
+            realm_id: Set(device.realm_id.into()),
+            user_id: Set(device.user_id),
+            user_agent: Set(device.user_agent),
+            ip_address: Set(device.ip_address),
+            token_hash: Set(device.token_hash),
+            created_at: Set(device.created_at.into()),
+            last_used_at: Set(device.last_used_at.map(Into::into)),
+            expires_at: Set(device.expires_at.into()),
+        };
+
+        Entity::insert(model)
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| database_error("create trusted device", e))?;
+
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<TrustedDevice>, CoreError> {
+        let model = Entity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|e| database_error("get trusted device", e))?;
+
+        Ok(model.map(TrustedDevice::from))
+    }
+
+    async fn list_by_user(
+        &self,
+        user_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<TrustedDevice>, CoreError> {
+        let models = Entity::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::ExpiresAt.gt(DateTimeWithTimeZone::from(now)))
+            .order_by_desc(Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| database_error("list trusted devices", e))?;
+
+        Ok(models.into_iter().map(TrustedDevice::from).collect())
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<(), CoreError> {
+        Entity::update_many()
+            .col_expr(
+                Column::LastUsedAt,
+                Expr::value(DateTimeWithTimeZone::from(used_at)),
+            )
+            .filter(Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("touch trusted device", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, CoreError> {
+        let result = Entity::delete_many()
+            .filter(Column::Id.eq(id))
+            .filter(Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| database_error("delete trusted device", e))?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
@@ -1,6 +1,9 @@
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, DbErr,
-    EntityTrait, ModelTrait, QueryFilter, SqlErr,
+    ActiveModelTrait,
+    ActiveValue::Set,
+    ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, IntoSimpleExpr, ModelTrait,
+    PaginatorTrait, QueryFilter, SqlErr,
+    sea_query::{Query, SimpleExpr},
 };
 use tracing::{error, instrument};
 use uuid::Uuid;
@@ -11,9 +14,10 @@ use crate::domain::{
     user::{
         entities::{RequiredAction, User, UserConfig},
         ports::UserRepository,
-        value_objects::{CreateUserRequest, UpdateUserRequest},
+        value_objects::{CreateUserRequest, UpdateUserRequest, UserFilter, UserSortField},
     },
 };
+use crate::infrastructure::common::{coalesced, paginate, search_condition, timestamp_key};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum UserUniqueViolation {
@@ -48,6 +52,82 @@ fn classify_user_unique_violation(err: &DbErr) -> Option<UserUniqueViolation> {
     }
 }
 
+fn filter_condition(realm_id: RealmId, filter: &UserFilter) -> Condition {
+    use crate::entity::{organization_members, user_attributes, user_role, users};
+
+    let mut condition = Condition::all()
+        .add(users::Column::RealmId.eq::<Uuid>(realm_id.into()))
+        .add(search_condition(
+            filter.search.as_deref(),
+            &[
+                users::Column::Username,
+                users::Column::Email,
+                users::Column::Firstname,
+                users::Column::Lastname,
+            ],
+        ));
+
+    if let Some(enabled) = filter.enabled {
+        condition = condition.add(users::Column::Enabled.eq(enabled));
+    }
+    if let Some(email_verified) = filter.email_verified {
+        condition = condition.add(users::Column::EmailVerified.eq(email_verified));
+    }
+    if let Some(role_id) = filter.role_id {
+        condition = condition.add(
+            users::Column::Id.in_subquery(
+                Query::select()
+                    .column(user_role::Column::UserId)
+                    .from(user_role::Entity)
+                    .and_where(user_role::Column::RoleId.eq(role_id))
+                    .to_owned(),
+            ),
+        );
+    }
+    if let Some(organization_id) = filter.organization_id {
+        condition = condition.add(
+            users::Column::Id.in_subquery(
+                Query::select()
+                    .column(organization_members::Column::UserId)
+                    .from(organization_members::Entity)
+                    .and_where(organization_members::Column::OrganizationId.eq(organization_id))
+                    .to_owned(),
+            ),
+        );
+    }
+    if let Some(key) = &filter.attribute_key {
+        let mut attributes = Query::select()
+            .column(user_attributes::Column::UserId)
+            .from(user_attributes::Entity)
+            .and_where(user_attributes::Column::Key.eq(key.clone()))
+            .to_owned();
+        if let Some(value) = &filter.attribute_value {
+            attributes.and_where(user_attributes::Column::Value.eq(value.clone()));
+        }
+
+        condition = condition.add(users::Column::Id.in_subquery(attributes));
+    }
+    condition = match filter.service_account {
+        Some(true) => condition.add(users::Column::ClientId.is_not_null()),
+        Some(false) => condition.add(users::Column::ClientId.is_null()),
+        None => condition,
+    };
+
+    condition
+}
+
+fn sort_expr(sort: UserSortField) -> SimpleExpr {
+    use crate::entity::users::Column;
+
+    match sort {
+        UserSortField::Username => Column::Username.into_simple_expr(),
+        UserSortField::Email => coalesced(Column::Email),
+        UserSortField::Firstname => coalesced(Column::Firstname),
+        UserSortField::Lastname => coalesced(Column::Lastname),
+        UserSortField::CreatedAt => Column::CreatedAt.into_simple_expr(),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PostgresUserRepository {
     pub db: DatabaseConnection,
@@ -214,6 +294,49 @@ impl UserRepository for PostgresUserRepository {
         Ok(users)
     }
 
+    async fn list_users(
+        &self,
+        realm_id: RealmId,
+        filter: UserFilter,
+    ) -> Result<Vec<User>, CoreError> {
+        let key = match &filter.page.cursor {
+            Some(cursor) => Some(match filter.sort {
+                UserSortField::CreatedAt => timestamp_key(cursor)?.naive_utc().into(),
+                _ => cursor.key.clone().into(),
+            }),
+            None => None,
+        };
+
+        let select =
+            crate::entity::users::Entity::find().filter(filter_condition(realm_id, &filter));
+        let users = paginate(
+            select,
+            sort_expr(filter.sort),
+            crate::entity::users::Column::Id,
+            key,
+            &filter.page,
+        )
+        .all(&self.db)
+        .await
+        .map_err(|e| {
+            error!("error listing users: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(users.into_iter().map(|user| user.into()).collect())
+    }
+
+    async fn count_users(&self, realm_id: RealmId, filter: UserFilter) -> Result<u64, CoreError> {
+        crate::entity::users::Entity::find()
+            .filter(filter_condition(realm_id, &filter))
+            .count(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error counting users: {:?}", e);
+                CoreError::InternalServerError
+            })
+    }
+
     async fn get_by_email(
         &self,
         email: &str,
@@ -170,16 +170,30 @@ pub struct BrokerCallbackInput {
 
     /// Base URL of the API server (e.g., "https://auth.example.com")
     pub base_url: String,
+
+    /// URL of the login pages, where the user passes the next authenticators
+    /// when the sign-in asks for more than the identity provider
+    pub frontend_url: String,
+
+    /// IP address the user signs in from
+    pub ip_address: Option<String>,
+
+    /// User agent of the browser the user signs in with
+    pub user_agent: Option<String>,
+
+    /// Token of the browser's trusted device cookie, if it has one
+    pub trusted_device_token: Option<String>,
 }
 
 /// Output from broker callback handling
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct BrokerCallbackOutput {
-    /// URL to redirect the user to (client's redirect_uri with code)
+    /// URL to redirect the user to (client's redirect_uri with code, or the
+    /// page of the next authenticator to pass)
     pub redirect_url: String,
 
-    /// Authorization code for the client
-    pub authorization_code: String,
+    /// Authorization code for the client, once the sign-in is complete
+    pub authorization_code: Option<String>,
 
     /// FerrisKey user ID
     pub user_id: Uuid,
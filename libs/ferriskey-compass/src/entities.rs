@@ -119,6 +119,14 @@ pub enum FlowStepName {
     #[serde(rename = "mfa_challenge")]
     MfaChallenge,
 
+    /// A condition of the realm's authentication flow was evaluated.
+    #[serde(rename = "condition_check")]
+    ConditionCheck,
+
+    /// The sign-in was scored against the realm's risk policy.
+    #[serde(rename = "risk_assessment")]
+    RiskAssessment,
+
     #[serde(rename = "token_exchange")]
     TokenExchange,
 
@@ -138,6 +146,8 @@ impl Display for FlowStepName {
             FlowStepName::Authorize => write!(f, "authorize"),
             FlowStepName::CredentialValidation => write!(f, "credential_validation"),
             FlowStepName::MfaChallenge => write!(f, "mfa_challenge"),
+            FlowStepName::ConditionCheck => write!(f, "condition_check"),
+            FlowStepName::RiskAssessment => write!(f, "risk_assessment"),
             FlowStepName::TokenExchange => write!(f, "token_exchange"),
             FlowStepName::IdpRedirect => write!(f, "idp_redirect"),
             FlowStepName::IdpCallback => write!(f, "idp_callback"),
@@ -51,7 +51,7 @@ pub trait CompassPolicy: Send + Sync {
     ) -> impl Future<Output = Result<bool, CoreError>> + Send;
 }
 
-#[cfg_attr(test, mockall::automock)]
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
 pub trait CompassFlowRepository: Send + Sync {
     fn create_flow(&self, flow: CompassFlow) -> impl Future<Output = Result<(), CoreError>> + Send;
 
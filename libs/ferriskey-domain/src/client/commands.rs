@@ -1,7 +1,7 @@
 use uuid::Uuid;
 
 use crate::client::entities::ClientType;
-use crate::client::value_objects::UpdateClientRequest;
+use crate::client::value_objects::{ClientFilter, UpdateClientRequest};
 
 pub struct CreateClientInput {
     pub realm_name: String,
@@ -75,6 +75,7 @@ pub struct GetPostLogoutRedirectUrisInput {
 
 pub struct GetClientsInput {
     pub realm_name: String,
+    pub filter: ClientFilter,
 }
 
 pub struct UpdateClientInput {
@@ -10,9 +10,12 @@ use crate::client::{
         UpdatePostLogoutRedirectUriInput, UpdateRedirectUriInput,
     },
     entities::{Client, redirect_uri::RedirectUri},
-    value_objects::{CreateClientRequest, CreateRedirectUriRequest, UpdateClientRequest},
+    value_objects::{
+        ClientFilter, CreateClientRequest, CreateRedirectUriRequest, UpdateClientRequest,
+    },
 };
 use crate::common::app_errors::CoreError;
+use crate::common::listing::Page;
 use crate::realm::{Realm, RealmId};
 use crate::role::entities::Role;
 
@@ -66,7 +69,12 @@ pub trait ClientService: Send + Sync {
         &self,
         identity: Identity,
         input: GetClientsInput,
-    ) -> impl Future<Output = Result<Vec<Client>, CoreError>> + Send;
+    ) -> impl Future<Output = Result<Page<Client>, CoreError>> + Send;
+    fn count_clients(
+        &self,
+        identity: Identity,
+        input: GetClientsInput,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
 
     fn get_redirect_uris(
         &self,
@@ -135,6 +143,18 @@ pub trait ClientRepository: Send + Sync {
         &self,
         realm_id: RealmId,
     ) -> impl Future<Output = Result<Vec<Client>, CoreError>> + Send;
+    /// Up to `filter.page.limit() + 1` clients, the extra one telling whether
+    /// there is a next page.
+    fn list_clients(
+        &self,
+        realm_id: RealmId,
+        filter: ClientFilter,
+    ) -> impl Future<Output = Result<Vec<Client>, CoreError>> + Send;
+    fn count_clients(
+        &self,
+        realm_id: RealmId,
+        filter: ClientFilter,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
 
     fn update_client(
         &self,
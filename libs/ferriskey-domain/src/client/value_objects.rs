@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-use crate::client::entities::{ClientType, MaintenanceSessionStrategy};
+use crate::client::entities::{Client, ClientType, MaintenanceSessionStrategy};
+use crate::common::listing::{ListCursor, PageRequest};
 use crate::realm::RealmId;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,3 +62,35 @@ pub struct CreateRedirectUriRequest {
     pub value: String,
     pub enabled: bool,
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientSortField {
+    #[default]
+    ClientId,
+    Name,
+    CreatedAt,
+}
+
+impl ClientSortField {
+    pub fn cursor(&self, client: &Client) -> ListCursor {
+        let key = match self {
+            ClientSortField::ClientId => client.client_id.clone(),
+            ClientSortField::Name => client.name.clone(),
+            ClientSortField::CreatedAt => client.created_at.timestamp_micros().to_string(),
+        };
+
+        ListCursor { key, id: client.id }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientFilter {
+    /// Whitespace-separated terms, each matched case-insensitively against the
+    /// client id and name.
+    pub search: Option<String>,
+    pub enabled: Option<bool>,
+    pub public_client: Option<bool>,
+    pub sort: ClientSortField,
+    pub page: PageRequest,
+}
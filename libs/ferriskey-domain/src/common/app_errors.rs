@@ -207,6 +207,9 @@ pub enum CoreError {
     #[error("External error: {0}")]
     External(String),
 
+    #[error("Email rejected by the mail server: {0}")]
+    EmailRejected(String),
+
     #[error("Database error: {0}")]
     Database(String),
 
@@ -286,6 +289,9 @@ pub enum CoreError {
     #[error("Email verification template is not configured for this realm")]
     EmailVerificationTemplateNotConfigured,
 
+    #[error("Invalid localization: {0}")]
+    InvalidLocalization(String),
+
     #[error("Portal theme page tree is missing required blocks: {0}")]
     PortalThemePageInvalid(String),
 
@@ -300,6 +306,30 @@ pub enum CoreError {
 
     #[error("Portal layout is referenced by one or more themes and cannot be deleted")]
     PortalLayoutInUse,
+
+    #[error("Invalid pagination cursor")]
+    InvalidCursor,
+
+    #[error("Password does not satisfy the realm policy: {0}")]
+    PasswordPolicyViolation(String),
+
+    #[error("No account deletion is pending for this user")]
+    AccountDeletionNotFound,
+
+    #[error("Invalid or expired one-time code")]
+    InvalidOneTimeCode,
+
+    #[error("Invalid phone number")]
+    InvalidPhoneNumber,
+
+    #[error("Too many requests: {0}")]
+    TooManyRequests(String),
+
+    #[error("Invalid authentication flow: {0}")]
+    InvalidAuthFlow(String),
+
+    #[error("Invalid risk policy: {0}")]
+    InvalidRiskPolicy(String),
 }
 
 impl From<AuthenticationError> for CoreError {
@@ -0,0 +1,152 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::common::app_errors::CoreError;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Position of an item in a `(sort key, id)` ordering, serialized as an
+/// opaque token for API clients. The key is only meaningful for the sort
+/// field the listing was requested with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListCursor {
+    pub key: String,
+    pub id: Uuid,
+}
+
+impl ListCursor {
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}:{}", self.id, self.key))
+    }
+
+    pub fn decode(value: &str) -> Result<Self, CoreError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|_| CoreError::InvalidCursor)?;
+        let raw = String::from_utf8(bytes).map_err(|_| CoreError::InvalidCursor)?;
+        let (id, key) = raw.split_once(':').ok_or(CoreError::InvalidCursor)?;
+        let id = Uuid::parse_str(id).map_err(|_| CoreError::InvalidCursor)?;
+
+        Ok(Self {
+            key: key.to_string(),
+            id,
+        })
+    }
+}
+
+/// Offset or keyset pagination. When a cursor is given the offset is ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageRequest {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub cursor: Option<ListCursor>,
+    pub direction: SortDirection,
+}
+
+impl PageRequest {
+    pub const DEFAULT_LIMIT: u32 = 100;
+    pub const MAX_LIMIT: u32 = 1000;
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    /// Pass back as the cursor to fetch the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page from a query that fetched one item more than `limit`,
+    /// the extra item only telling whether there is a next page.
+    pub fn from_overfetch(
+        mut items: Vec<T>,
+        limit: u32,
+        cursor_of: impl Fn(&T) -> ListCursor,
+    ) -> Self {
+        let has_more = items.len() > limit as usize;
+        items.truncate(limit as usize);
+
+        let next_cursor = if has_more {
+            items.last().map(|item| cursor_of(item).encode())
+        } else {
+            None
+        };
+
+        Self {
+            data: items,
+            next_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips_keys_containing_separators() {
+        let cursor = ListCursor {
+            key: "jane:doe@example.com".to_string(),
+            id: Uuid::new_v4(),
+        };
+
+        assert_eq!(ListCursor::decode(&cursor.encode()).unwrap(), cursor);
+    }
+
+    #[test]
+    fn malformed_cursor_is_rejected() {
+        assert!(matches!(
+            ListCursor::decode("not a cursor"),
+            Err(CoreError::InvalidCursor)
+        ));
+    }
+
+    #[test]
+    fn overfetched_page_points_at_its_last_item() {
+        let ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+
+        let page = Page::from_overfetch(ids.clone(), 2, |id| ListCursor {
+            key: String::new(),
+            id: *id,
+        });
+
+        assert_eq!(page.data, ids[..2]);
+        let cursor = ListCursor::decode(&page.next_cursor.unwrap()).unwrap();
+        assert_eq!(cursor.id, ids[1]);
+    }
+
+    #[test]
+    fn last_page_has_no_cursor() {
+        let page = Page::from_overfetch(vec![1, 2], 2, |_| ListCursor {
+            key: String::new(),
+            id: Uuid::nil(),
+        });
+
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn limit_is_clamped() {
+        let request = PageRequest {
+            limit: Some(50_000),
+            ..Default::default()
+        };
+
+        assert_eq!(request.limit(), PageRequest::MAX_LIMIT);
+        assert_eq!(PageRequest::default().limit(), PageRequest::DEFAULT_LIMIT);
+    }
+}
@@ -1 +1,2 @@
 pub mod app_errors;
+pub mod listing;
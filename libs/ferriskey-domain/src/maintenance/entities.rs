@@ -1,9 +1,11 @@
+use std::{fmt, str::FromStr};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::{generate_timestamp, realm::RealmId};
+use crate::{client::entities::MaintenanceSessionStrategy, generate_timestamp, realm::RealmId};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct MaintenanceWhitelistEntry {
@@ -70,3 +72,103 @@ impl RealmMaintenanceWhitelistEntry {
         }
     }
 }
+
+/// Realm-wide maintenance state. While enabled, only users on the realm
+/// whitelist can obtain tokens from any client of the realm.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmMaintenance {
+    pub realm_id: RealmId,
+    pub enabled: bool,
+    pub reason: Option<String>,
+    pub session_strategy: MaintenanceSessionStrategy,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RealmMaintenance {
+    /// State of a realm that never had maintenance configured.
+    pub fn disabled(realm_id: RealmId) -> Self {
+        Self {
+            realm_id,
+            enabled: false,
+            reason: None,
+            session_strategy: MaintenanceSessionStrategy::default(),
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceWindowStatus {
+    Scheduled,
+    Active,
+    Completed,
+    Cancelled,
+}
+
+impl fmt::Display for MaintenanceWindowStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaintenanceWindowStatus::Scheduled => write!(f, "scheduled"),
+            MaintenanceWindowStatus::Active => write!(f, "active"),
+            MaintenanceWindowStatus::Completed => write!(f, "completed"),
+            MaintenanceWindowStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl FromStr for MaintenanceWindowStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scheduled" => Ok(MaintenanceWindowStatus::Scheduled),
+            "active" => Ok(MaintenanceWindowStatus::Active),
+            "completed" => Ok(MaintenanceWindowStatus::Completed),
+            "cancelled" => Ok(MaintenanceWindowStatus::Cancelled),
+            _ => Err(format!("unknown maintenance window status: {s}")),
+        }
+    }
+}
+
+/// A planned maintenance period. Targets a single client when `client_id` is
+/// set, the whole realm otherwise. Maintenance is switched on at `starts_at`
+/// and off at `ends_at` by the maintenance window scheduler.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MaintenanceWindow {
+    pub id: Uuid,
+    pub realm_id: RealmId,
+    pub client_id: Option<Uuid>,
+    pub reason: Option<String>,
+    pub session_strategy: MaintenanceSessionStrategy,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub status: MaintenanceWindowStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MaintenanceWindow {
+    pub fn new(
+        realm_id: RealmId,
+        client_id: Option<Uuid>,
+        reason: Option<String>,
+        session_strategy: MaintenanceSessionStrategy,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    ) -> Self {
+        let (now, timestamp) = generate_timestamp();
+        Self {
+            id: Uuid::new_v7(timestamp),
+            realm_id,
+            client_id,
+            reason,
+            session_strategy,
+            starts_at,
+            ends_at,
+            status: MaintenanceWindowStatus::Scheduled,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
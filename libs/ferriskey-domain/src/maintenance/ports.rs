@@ -1,9 +1,15 @@
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::auth::Identity;
+use crate::client::entities::Client;
 use crate::common::app_errors::CoreError;
-use crate::maintenance::entities::{MaintenanceWhitelistEntry, RealmMaintenanceWhitelistEntry};
-use crate::maintenance::value_objects::ToggleMaintenanceRequest;
+use crate::maintenance::entities::{
+    MaintenanceWhitelistEntry, MaintenanceWindow, RealmMaintenance, RealmMaintenanceWhitelistEntry,
+};
+use crate::maintenance::value_objects::{
+    ScheduleMaintenanceWindowRequest, ToggleMaintenanceRequest,
+};
 use crate::realm::RealmId;
 
 pub trait MaintenanceWhitelistRepository: Send + Sync {
@@ -68,6 +74,74 @@ pub trait RealmMaintenanceWhitelistRepository: Send + Sync {
     ) -> impl Future<Output = Result<Vec<Uuid>, CoreError>> + Send;
 }
 
+pub trait RealmMaintenanceRepository: Send + Sync {
+    fn get_by_realm_id(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Option<RealmMaintenance>, CoreError>> + Send;
+
+    fn upsert(
+        &self,
+        maintenance: RealmMaintenance,
+    ) -> impl Future<Output = Result<RealmMaintenance, CoreError>> + Send;
+}
+
+pub trait MaintenanceWindowRepository: Send + Sync {
+    fn create(
+        &self,
+        window: MaintenanceWindow,
+    ) -> impl Future<Output = Result<MaintenanceWindow, CoreError>> + Send;
+
+    fn get_by_id(
+        &self,
+        window_id: Uuid,
+    ) -> impl Future<Output = Result<Option<MaintenanceWindow>, CoreError>> + Send;
+
+    fn list_by_realm_id(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+
+    /// Marks a scheduled or active window as cancelled.
+    fn cancel(
+        &self,
+        window_id: Uuid,
+    ) -> impl Future<Output = Result<MaintenanceWindow, CoreError>> + Send;
+
+    /// Atomically moves the scheduled windows whose start has passed to
+    /// `active` and returns them, so each window is started by one replica.
+    fn claim_due_starts(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+
+    /// Atomically moves the active windows whose end has passed to
+    /// `completed` and returns them.
+    fn claim_due_ends(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+}
+
+/// Revokes the outstanding tokens and sessions affected by the `terminate`
+/// session strategy.
+pub trait MaintenanceSessionRepository: Send + Sync {
+    /// Revokes the refresh and access tokens issued to the client and drops
+    /// its pending authorization sessions. Returns the number of rows
+    /// affected.
+    fn terminate_client_sessions(
+        &self,
+        client: &Client,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Revokes every token issued in the realm and drops its authorization
+    /// and user sessions. Returns the number of rows affected.
+    fn terminate_realm_sessions(
+        &self,
+        realm_id: RealmId,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
+
 pub trait MaintenanceService: Send + Sync {
     fn toggle_maintenance(
         &self,
@@ -85,6 +159,57 @@ pub trait MaintenanceService: Send + Sync {
         user_role_ids: &[Uuid],
     ) -> impl Future<Output = Result<bool, CoreError>> + Send;
 
+    /// The maintenance gate every token issuance path goes through. Fails
+    /// with [`CoreError::ClientUnderMaintenance`] when the realm, or the
+    /// client the tokens are issued to, is under maintenance and the user is
+    /// not whitelisted.
+    fn ensure_access_allowed(
+        &self,
+        realm_id: RealmId,
+        client: Option<&Client>,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_realm_maintenance(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<RealmMaintenance, CoreError>> + Send;
+
+    fn toggle_realm_maintenance(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ToggleMaintenanceRequest,
+    ) -> impl Future<Output = Result<RealmMaintenance, CoreError>> + Send;
+
+    fn list_maintenance_windows(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<MaintenanceWindow>, CoreError>> + Send;
+
+    fn schedule_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        request: ScheduleMaintenanceWindowRequest,
+    ) -> impl Future<Output = Result<MaintenanceWindow, CoreError>> + Send;
+
+    fn cancel_maintenance_window(
+        &self,
+        identity: Identity,
+        realm_name: String,
+        window_id: Uuid,
+    ) -> impl Future<Output = Result<MaintenanceWindow, CoreError>> + Send;
+
+    /// Starts and ends the maintenance windows that are due. Returns the
+    /// number of windows that changed state.
+    fn apply_due_maintenance_windows(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<usize, CoreError>> + Send;
+
     fn add_client_whitelist_user(
         &self,
         identity: Identity,
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -19,3 +20,12 @@ pub struct AddMaintenanceWhitelistUserRequest {
 pub struct AddMaintenanceWhitelistRoleRequest {
     pub role_id: Uuid,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleMaintenanceWindowRequest {
+    pub client_id: Option<Uuid>,
+    pub reason: Option<String>,
+    pub session_strategy: Option<MaintenanceSessionStrategy>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
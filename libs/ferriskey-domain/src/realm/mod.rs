@@ -68,6 +68,9 @@ pub struct RealmSetting {
     pub email_verification_template_id: Option<Uuid>,
     pub email_verification_enabled: bool,
     pub email_verification_ttl_hours: i64,
+    /// Days a device the user trusted skips the second factor. `0` doesn't
+    /// let users trust devices.
+    pub trusted_device_days: u32,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -95,6 +98,7 @@ impl RealmSetting {
             email_verification_template_id: None,
             email_verification_enabled: false,
             email_verification_ttl_hours: 24,
+            trusted_device_days: 0,
             updated_at: now,
         }
     }
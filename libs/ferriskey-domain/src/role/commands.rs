@@ -1,5 +1,7 @@
 use uuid::Uuid;
 
+use crate::role::value_objects::RoleFilter;
+
 pub struct CreateRoleInput {
     pub realm_name: String,
     pub name: String,
@@ -18,3 +20,8 @@ pub struct GetUserRolesInput {
     pub realm_name: String,
     pub user_id: Uuid,
 }
+
+pub struct GetRolesInput {
+    pub realm_name: String,
+    pub filter: RoleFilter,
+}
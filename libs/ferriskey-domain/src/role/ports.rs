@@ -2,11 +2,14 @@ use uuid::Uuid;
 
 use crate::auth::Identity;
 use crate::common::app_errors::CoreError;
+use crate::common::listing::Page;
 use crate::realm::{Realm, RealmId};
 use crate::role::{
-    commands::{CreateRoleInput, GetUserRolesInput, UpdateRoleInput},
+    commands::{CreateRoleInput, GetRolesInput, GetUserRolesInput, UpdateRoleInput},
     entities::Role,
-    value_objects::{CreateRoleRequest, UpdateRolePermissionsRequest, UpdateRoleRequest},
+    value_objects::{
+        CreateRoleRequest, RoleFilter, UpdateRolePermissionsRequest, UpdateRoleRequest,
+    },
 };
 
 pub trait RoleService: Send + Sync {
@@ -30,8 +33,13 @@ pub trait RoleService: Send + Sync {
     fn get_roles(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+        input: GetRolesInput,
+    ) -> impl Future<Output = Result<Page<Role>, CoreError>> + Send;
+    fn count_roles(
+        &self,
+        identity: Identity,
+        input: GetRolesInput,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
     fn update_role_permissions(
         &self,
         identity: Identity,
@@ -90,6 +98,18 @@ pub trait RoleRepository: Send + Sync {
         &self,
         realm_id: RealmId,
     ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    /// Up to `filter.page.limit() + 1` roles, the extra one telling whether
+    /// there is a next page.
+    fn list_roles(
+        &self,
+        realm_id: RealmId,
+        filter: RoleFilter,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    fn count_roles(
+        &self,
+        realm_id: RealmId,
+        filter: RoleFilter,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
     fn find_by_name(
         &self,
         name: String,
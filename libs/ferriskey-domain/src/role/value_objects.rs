@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::common::listing::{ListCursor, PageRequest};
 use crate::realm::RealmId;
+use crate::role::entities::Role;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CreateRoleRequest {
@@ -22,3 +25,33 @@ pub struct UpdateRoleRequest {
 pub struct UpdateRolePermissionsRequest {
     pub permissions: Vec<String>,
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleSortField {
+    #[default]
+    Name,
+    CreatedAt,
+}
+
+impl RoleSortField {
+    pub fn cursor(&self, role: &Role) -> ListCursor {
+        let key = match self {
+            RoleSortField::Name => role.name.clone(),
+            RoleSortField::CreatedAt => role.created_at.timestamp_micros().to_string(),
+        };
+
+        ListCursor { key, id: role.id }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoleFilter {
+    /// Whitespace-separated terms, each matched case-insensitively against the
+    /// role name and description.
+    pub search: Option<String>,
+    /// Only the roles of this client.
+    pub client_id: Option<Uuid>,
+    pub sort: RoleSortField,
+    pub page: PageRequest,
+}
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use uuid::Uuid;
 
+use crate::user::value_objects::UserFilter;
+
 pub struct UpdateUserInput {
     pub realm_name: String,
     pub user_id: Uuid,
@@ -19,6 +21,11 @@ pub struct UnassignRoleInput {
     pub role_id: Uuid,
 }
 
+pub struct GetUsersInput {
+    pub realm_name: String,
+    pub filter: UserFilter,
+}
+
 pub struct GetUserPermissionsInput {
     pub realm_name: String,
     pub user_id: Uuid,
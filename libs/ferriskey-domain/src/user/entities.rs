@@ -66,6 +66,12 @@ pub enum RequiredAction {
 
     #[serde(rename = "configure_passkey")]
     ConfigurePasskey,
+
+    #[serde(rename = "configure_email_otp")]
+    ConfigureEmailOtp,
+
+    #[serde(rename = "configure_sms_otp")]
+    ConfigureSmsOtp,
 }
 
 impl RequiredAction {
@@ -75,6 +81,8 @@ impl RequiredAction {
             "verify_email",
             "update_password",
             "configure_passkey",
+            "configure_email_otp",
+            "configure_sms_otp",
         ]
     }
 }
@@ -86,6 +94,8 @@ impl Display for RequiredAction {
             RequiredAction::VerifyEmail => write!(f, "verify_email"),
             RequiredAction::UpdatePassword => write!(f, "update_password"),
             RequiredAction::ConfigurePasskey => write!(f, "configure_passkey"),
+            RequiredAction::ConfigureEmailOtp => write!(f, "configure_email_otp"),
+            RequiredAction::ConfigureSmsOtp => write!(f, "configure_sms_otp"),
         }
     }
 }
@@ -99,6 +109,8 @@ impl TryFrom<String> for RequiredAction {
             "verify_email" => Ok(RequiredAction::VerifyEmail),
             "update_password" => Ok(RequiredAction::UpdatePassword),
             "configure_passkey" => Ok(RequiredAction::ConfigurePasskey),
+            "configure_email_otp" => Ok(RequiredAction::ConfigureEmailOtp),
+            "configure_sms_otp" => Ok(RequiredAction::ConfigureSmsOtp),
             _ => Err(RequiredActionError::Invalid),
         }
     }
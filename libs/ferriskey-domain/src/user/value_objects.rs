@@ -1,6 +1,10 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::common::listing::{ListCursor, PageRequest};
 use crate::realm::RealmId;
+use crate::user::entities::User;
 
 #[derive(Debug, Clone)]
 pub struct CreateUserRequest {
@@ -23,3 +27,46 @@ pub struct UpdateUserRequest {
     pub enabled: bool,
     pub required_actions: Option<Vec<String>>,
 }
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSortField {
+    #[default]
+    Username,
+    Email,
+    Firstname,
+    Lastname,
+    CreatedAt,
+}
+
+impl UserSortField {
+    pub fn cursor(&self, user: &User) -> ListCursor {
+        let key = match self {
+            UserSortField::Username => user.username.clone(),
+            UserSortField::Email => user.email.clone().unwrap_or_default(),
+            UserSortField::Firstname => user.firstname.clone().unwrap_or_default(),
+            UserSortField::Lastname => user.lastname.clone().unwrap_or_default(),
+            UserSortField::CreatedAt => user.created_at.timestamp_micros().to_string(),
+        };
+
+        ListCursor { key, id: user.id }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserFilter {
+    /// Whitespace-separated terms, each matched case-insensitively against the
+    /// username, email, first and last name.
+    pub search: Option<String>,
+    pub enabled: Option<bool>,
+    pub email_verified: Option<bool>,
+    pub role_id: Option<Uuid>,
+    pub organization_id: Option<Uuid>,
+    /// Users having this attribute, with `attribute_value` if given.
+    pub attribute_key: Option<String>,
+    pub attribute_value: Option<String>,
+    /// Only service account users when `true`, only regular users when `false`.
+    pub service_account: Option<bool>,
+    pub sort: UserSortField,
+    pub page: PageRequest,
+}
@@ -0,0 +1,317 @@
+use reqwest::{Client, StatusCode, header::CONTENT_TYPE};
+use serde_json::{Value, json};
+
+use crate::{
+    entities::{EmailMessage, HttpApiConfig},
+    error::EmailError,
+    ports::EmailSender,
+};
+
+/// SendGrid v3 `mail/send` body; send with an `Authorization: Bearer <key>`
+/// header.
+pub const SENDGRID_BODY_TEMPLATE: &str = r#"{"personalizations":[{"to":{{to_objects}}}],"from":{"email":{{from_email}},"name":{{from_name}}},"subject":{{subject}},"content":[{"type":"text/plain","value":{{text_body}}},{"type":"text/html","value":{{html_body}}}]}"#;
+
+/// Postmark `email` body; send with an `X-Postmark-Server-Token` header.
+pub const POSTMARK_BODY_TEMPLATE: &str = r#"{"From":{{from}},"To":{{to_csv}},"Subject":{{subject}},"TextBody":{{text_body}},"HtmlBody":{{html_body}}}"#;
+
+/// Longest part of an error response kept in the error message.
+const MAX_ERROR_DETAIL: usize = 200;
+
+pub struct HttpEmailSender {
+    client: Client,
+    config: HttpApiConfig,
+}
+
+impl HttpEmailSender {
+    pub fn new(config: HttpApiConfig) -> Self {
+        Self::with_client(Client::new(), config)
+    }
+
+    /// Shares `client`, and so its connection pool, across senders.
+    pub fn with_client(client: Client, config: HttpApiConfig) -> Self {
+        Self { client, config }
+    }
+}
+
+/// Renders `template` for `message`, replacing each placeholder with a JSON
+/// value:
+///
+/// - `{{from}}`: `"Name <email>"`
+/// - `{{from_email}}`, `{{from_name}}`, `{{subject}}`, `{{text_body}}`: strings
+/// - `{{html_body}}`: the HTML body, or the text body when there is none
+/// - `{{to}}`: array of addresses
+/// - `{{to_csv}}`: comma-separated addresses
+/// - `{{to_objects}}`: array of `{"email": address}` objects
+///
+/// Values are escaped, so the template itself must not quote placeholders.
+pub fn render_body(template: &str, message: &EmailMessage) -> Result<String, EmailError> {
+    let recipients: Vec<&str> = message.to.iter().map(|to| to.as_str()).collect();
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .map(|end| start + end)
+            .ok_or_else(|| EmailError::MessageBuild("unclosed placeholder".to_string()))?;
+
+        let value = match rest[start + 2..end].trim() {
+            "from" => json!(message.from.as_mailbox()),
+            "from_email" => json!(message.from.email.as_str()),
+            "from_name" => json!(message.from.name),
+            "subject" => json!(message.subject.as_str()),
+            "text_body" => json!(message.body),
+            "html_body" => json!(message.html_body.as_deref().unwrap_or(&message.body)),
+            "to" => json!(recipients),
+            "to_csv" => json!(recipients.join(", ")),
+            "to_objects" => Value::Array(
+                recipients
+                    .iter()
+                    .map(|email| json!({ "email": email }))
+                    .collect(),
+            ),
+            unknown => {
+                return Err(EmailError::MessageBuild(format!(
+                    "unknown placeholder `{unknown}`"
+                )));
+            }
+        };
+
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&value.to_string());
+        rest = &rest[end + 2..];
+    }
+    rendered.push_str(rest);
+
+    serde_json::from_str::<Value>(&rendered).map_err(|e| {
+        EmailError::MessageBuild(format!("body template does not render to JSON: {e}"))
+    })?;
+
+    Ok(rendered)
+}
+
+/// Client errors mean the provider refused the message; timeouts and rate
+/// limits are worth retrying.
+fn is_rejection(status: StatusCode) -> bool {
+    status.is_client_error()
+        && status != StatusCode::REQUEST_TIMEOUT
+        && status != StatusCode::TOO_MANY_REQUESTS
+}
+
+impl EmailSender for HttpEmailSender {
+    async fn send(&self, message: EmailMessage) -> Result<(), EmailError> {
+        if message.to.is_empty() {
+            return Err(EmailError::MissingRecipients);
+        }
+
+        let body = render_body(&self.config.body_template, &message)?;
+
+        let mut request = self
+            .client
+            .post(&self.config.url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(body);
+        for (name, value) in &self.config.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| EmailError::Transport(format!("failed to reach email API: {e}")))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let detail: String = response
+            .text()
+            .await
+            .unwrap_or_default()
+            .chars()
+            .take(MAX_ERROR_DETAIL)
+            .collect();
+
+        if is_rejection(status) {
+            Err(EmailError::Rejected(format!("{status}: {detail}")))
+        } else {
+            Err(EmailError::Transport(format!(
+                "email API responded {status}: {detail}"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+
+    use super::*;
+    use crate::entities::{EmailAddress, EmailFrom, EmailSubject};
+
+    fn message(html_body: Option<&str>) -> EmailMessage {
+        EmailMessage::new(
+            EmailFrom::new(
+                "Ferris \"Key\"".to_string(),
+                EmailAddress::new("noreply@example.com".to_string()).expect("valid sender"),
+            )
+            .expect("valid from"),
+            vec![
+                EmailAddress::new("alice@example.com".to_string()).expect("valid recipient"),
+                EmailAddress::new("bob@example.com".to_string()).expect("valid recipient"),
+            ],
+            EmailSubject::new("Hello".to_string()).expect("valid subject"),
+            "Line one\nLine two".to_string(),
+            html_body.map(str::to_string),
+        )
+        .expect("valid message")
+    }
+
+    /// Answers one request with `status` and returns what it received.
+    async fn stub_api(status: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind stub");
+        let url = format!("http://{}/send", listener.local_addr().expect("stub addr"));
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept request");
+            let mut received = Vec::new();
+            let mut buffer = [0u8; 4096];
+
+            loop {
+                let read = socket.read(&mut buffer).await.expect("read request");
+                received.extend_from_slice(&buffer[..read]);
+
+                let text = String::from_utf8_lossy(&received);
+                if let Some(headers_end) = text.find("\r\n\r\n") {
+                    let length = text[..headers_end]
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())?
+                        })
+                        .unwrap_or(0);
+                    if received.len() >= headers_end + 4 + length || read == 0 {
+                        break;
+                    }
+                }
+            }
+
+            socket
+                .write_all(
+                    format!("HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                        .as_bytes(),
+                )
+                .await
+                .expect("write response");
+
+            String::from_utf8_lossy(&received).into_owned()
+        });
+
+        (url, handle)
+    }
+
+    fn sender(url: String, body_template: &str) -> HttpEmailSender {
+        HttpEmailSender::new(HttpApiConfig {
+            url,
+            headers: vec![("Authorization".to_string(), "Bearer secret".to_string())],
+            body_template: body_template.to_string(),
+        })
+    }
+
+    #[test]
+    fn render_body_escapes_values() {
+        let body = render_body(
+            r#"{"from":{{from}},"text":{{text_body}},"to":{{to}}}"#,
+            &message(None),
+        )
+        .expect("template should render");
+
+        let value: Value = serde_json::from_str(&body).expect("body should be JSON");
+        assert_eq!(value["from"], "Ferris \"Key\" <noreply@example.com>");
+        assert_eq!(value["text"], "Line one\nLine two");
+        assert_eq!(value["to"], json!(["alice@example.com", "bob@example.com"]));
+    }
+
+    #[test]
+    fn render_body_falls_back_to_text_for_html() {
+        let body = render_body(r#"{"html":{{ html_body }}}"#, &message(None))
+            .expect("template should render");
+
+        assert_eq!(body, r#"{"html":"Line one\nLine two"}"#);
+    }
+
+    #[test]
+    fn render_body_rejects_unknown_placeholders() {
+        let result = render_body(r#"{"cc":{{cc}}}"#, &message(None));
+
+        assert!(matches!(result, Err(EmailError::MessageBuild(_))));
+    }
+
+    #[test]
+    fn render_body_rejects_templates_that_are_not_json() {
+        let result = render_body(r#"{"subject":"{{subject}}"}"#, &message(None));
+
+        assert!(matches!(result, Err(EmailError::MessageBuild(_))));
+    }
+
+    #[test]
+    fn provider_templates_render_to_json() {
+        for template in [SENDGRID_BODY_TEMPLATE, POSTMARK_BODY_TEMPLATE] {
+            render_body(template, &message(Some("<p>Hi</p>"))).expect("template should render");
+        }
+    }
+
+    #[tokio::test]
+    async fn send_posts_rendered_body_with_headers() {
+        let (url, stub) = stub_api("202 Accepted").await;
+
+        sender(url, SENDGRID_BODY_TEMPLATE)
+            .send(message(Some("<p>Hi</p>")))
+            .await
+            .expect("send should succeed");
+
+        let request = stub.await.expect("stub should finish");
+        assert!(request.starts_with("POST /send HTTP/1.1"));
+        assert!(
+            request
+                .to_lowercase()
+                .contains("authorization: bearer secret")
+        );
+        assert!(
+            request.contains(r#""to":[{"email":"alice@example.com"},{"email":"bob@example.com"}]"#)
+        );
+        assert!(request.contains(r#""value":"<p>Hi</p>""#));
+    }
+
+    #[tokio::test]
+    async fn send_maps_client_errors_to_rejections() {
+        let (url, stub) = stub_api("422 Unprocessable Entity").await;
+
+        let result = sender(url, POSTMARK_BODY_TEMPLATE)
+            .send(message(None))
+            .await;
+
+        stub.await.expect("stub should finish");
+        assert!(matches!(result, Err(EmailError::Rejected(_))));
+    }
+
+    #[tokio::test]
+    async fn send_maps_server_errors_and_rate_limits_to_transport_errors() {
+        for status in ["503 Service Unavailable", "429 Too Many Requests"] {
+            let (url, stub) = stub_api(status).await;
+
+            let result = sender(url, POSTMARK_BODY_TEMPLATE)
+                .send(message(None))
+                .await;
+
+            stub.await.expect("stub should finish");
+            assert!(matches!(result, Err(EmailError::Transport(_))));
+        }
+    }
+}
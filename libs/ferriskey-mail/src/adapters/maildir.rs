@@ -0,0 +1,116 @@
+use std::{
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::build_message;
+use crate::{entities::EmailMessage, error::EmailError, ports::EmailSender};
+
+/// Tells apart messages written within the same microsecond.
+static DELIVERIES: AtomicU64 = AtomicU64::new(0);
+
+/// Writes every email as an `.eml` file into a maildir instead of sending
+/// it, so development setups and CI can inspect emails without a mail
+/// server.
+pub struct MaildirEmailSender {
+    root: PathBuf,
+}
+
+impl MaildirEmailSender {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+/// Maildir-style unique name: time, process and a per-process counter.
+fn unique_name() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    format!(
+        "{}.M{}P{}Q{}.ferriskey.eml",
+        now.as_secs(),
+        now.subsec_micros(),
+        process::id(),
+        DELIVERIES.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+fn io_error(e: std::io::Error) -> EmailError {
+    EmailError::Transport(format!("failed to write to maildir: {e}"))
+}
+
+impl EmailSender for MaildirEmailSender {
+    async fn send(&self, message: EmailMessage) -> Result<(), EmailError> {
+        let email = build_message(message)?;
+
+        for directory in ["tmp", "new", "cur"] {
+            tokio::fs::create_dir_all(self.root.join(directory))
+                .await
+                .map_err(io_error)?;
+        }
+
+        // Written aside then renamed, so readers never see a partial file.
+        let name = unique_name();
+        let staged = self.root.join("tmp").join(&name);
+        tokio::fs::write(&staged, email.formatted())
+            .await
+            .map_err(io_error)?;
+        tokio::fs::rename(&staged, self.root.join("new").join(&name))
+            .await
+            .map_err(io_error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{EmailAddress, EmailFrom, EmailSubject};
+
+    #[tokio::test]
+    async fn send_writes_eml_file_into_new() {
+        let root = std::env::temp_dir().join(unique_name().replace(".eml", ".maildir"));
+        let message = EmailMessage::new(
+            EmailFrom::new(
+                "Ferris".to_string(),
+                EmailAddress::new("noreply@example.com".to_string()).expect("valid sender"),
+            )
+            .expect("valid from"),
+            vec![EmailAddress::new("alice@example.com".to_string()).expect("valid recipient")],
+            EmailSubject::new("Hello".to_string()).expect("valid subject"),
+            "Body".to_string(),
+            None,
+        )
+        .expect("valid message");
+
+        MaildirEmailSender::new(&root)
+            .send(message)
+            .await
+            .expect("send should succeed");
+
+        let mut entries = std::fs::read_dir(root.join("new"))
+            .expect("new should exist")
+            .map(|entry| entry.expect("entry should be readable").path())
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 1);
+
+        let path = entries.pop().expect("one email");
+        assert_eq!(path.extension().and_then(|ext| ext.to_str()), Some("eml"));
+        let content = std::fs::read_to_string(&path).expect("email should be readable");
+        assert!(content.contains("Subject: Hello"));
+        assert!(content.contains("To: alice@example.com"));
+        assert_eq!(
+            std::fs::read_dir(root.join("tmp"))
+                .expect("tmp should exist")
+                .count(),
+            0
+        );
+
+        std::fs::remove_dir_all(&root).expect("cleanup");
+    }
+}
@@ -1 +1,55 @@
+use lettre::{
+    Message,
+    message::{Mailbox, MultiPart, SinglePart},
+};
+
+use crate::{entities::EmailMessage, error::EmailError};
+
+pub mod http;
+pub mod maildir;
 pub mod smtp;
+
+/// Builds the MIME message the SMTP and maildir adapters hand over.
+pub(crate) fn build_message(message: EmailMessage) -> Result<Message, EmailError> {
+    let EmailMessage {
+        from,
+        to,
+        subject,
+        body,
+        html_body,
+    } = message;
+
+    if to.is_empty() {
+        return Err(EmailError::MissingRecipients);
+    }
+
+    let from_mailbox: Mailbox = from
+        .as_mailbox()
+        .parse()
+        .map_err(|e| EmailError::MessageBuild(format!("invalid from mailbox: {e}")))?;
+
+    let mut builder = Message::builder()
+        .from(from_mailbox)
+        .subject(subject.as_str());
+
+    for recipient in to {
+        let recipient_mailbox: Mailbox = recipient
+            .as_str()
+            .parse()
+            .map_err(|e| EmailError::MessageBuild(format!("invalid recipient mailbox: {e}")))?;
+        builder = builder.to(recipient_mailbox);
+    }
+
+    match html_body {
+        Some(html) => builder
+            .multipart(
+                MultiPart::alternative()
+                    .singlepart(SinglePart::plain(body))
+                    .singlepart(SinglePart::html(html)),
+            )
+            .map_err(|e| EmailError::MessageBuild(format!("failed to build email message: {e}"))),
+        None => builder
+            .body(body)
+            .map_err(|e| EmailError::MessageBuild(format!("failed to build email message: {e}"))),
+    }
+}
@@ -1,9 +1,9 @@
 use lettre::{
-    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
-    message::{Mailbox, MultiPart, SinglePart},
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
     transport::smtp::authentication::Credentials,
 };
 
+use super::build_message;
 use crate::{
     entities::{EmailMessage, SmtpEncryption},
     error::EmailError,
@@ -58,54 +58,15 @@ impl SmtpEmailSender {
 
 impl EmailSender for SmtpEmailSender {
     async fn send(&self, message: EmailMessage) -> Result<(), EmailError> {
-        let EmailMessage {
-            from,
-            to,
-            subject,
-            body,
-            html_body,
-        } = message;
-
-        if to.is_empty() {
-            return Err(EmailError::MissingRecipients);
-        }
-
-        let from_mailbox: Mailbox = from
-            .as_mailbox()
-            .parse()
-            .map_err(|e| EmailError::MessageBuild(format!("invalid from mailbox: {e}")))?;
-
-        let mut builder = Message::builder()
-            .from(from_mailbox)
-            .subject(subject.as_str());
-
-        for recipient in to {
-            let recipient_mailbox: Mailbox = recipient
-                .as_str()
-                .parse()
-                .map_err(|e| EmailError::MessageBuild(format!("invalid recipient mailbox: {e}")))?;
-            builder = builder.to(recipient_mailbox);
-        }
-
-        let email = match html_body {
-            Some(html) => builder
-                .multipart(
-                    MultiPart::alternative()
-                        .singlepart(SinglePart::plain(body))
-                        .singlepart(SinglePart::html(html)),
-                )
-                .map_err(|e| {
-                    EmailError::MessageBuild(format!("failed to build email message: {e}"))
-                })?,
-            None => builder.body(body).map_err(|e| {
-                EmailError::MessageBuild(format!("failed to build email message: {e}"))
-            })?,
-        };
-
-        self.mailer
-            .send(email)
-            .await
-            .map_err(|e| EmailError::Transport(format!("failed to send email: {e}")))?;
+        let email = build_message(message)?;
+
+        self.mailer.send(email).await.map_err(|e| {
+            if e.is_permanent() {
+                EmailError::Rejected(e.to_string())
+            } else {
+                EmailError::Transport(format!("failed to send email: {e}"))
+            }
+        })?;
 
         Ok(())
     }
@@ -101,6 +101,18 @@ pub enum SmtpEncryption {
     None,
 }
 
+/// Provider HTTP API emails are posted to as JSON, e.g. SendGrid or
+/// Postmark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpApiConfig {
+    pub url: String,
+    /// Sent with every request, typically to carry the API key.
+    pub headers: Vec<(String, String)>,
+    /// JSON request body with `{{placeholder}}` tokens, rendered by
+    /// [`crate::adapters::http::render_body`].
+    pub body_template: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -19,6 +19,10 @@ pub enum EmailError {
 
     #[error("Failed to send email: {0}")]
     Transport(String),
+
+    /// The relay permanently refused the message, so retrying is pointless.
+    #[error("Email rejected: {0}")]
+    Rejected(String),
 }
 
 #[cfg(test)]
@@ -39,4 +43,11 @@ mod tests {
 
         assert_eq!(error.to_string(), "Failed to send email: smtp timeout");
     }
+
+    #[test]
+    fn rejected_error_includes_context() {
+        let error = EmailError::Rejected("550 mailbox unavailable".to_string());
+
+        assert_eq!(error.to_string(), "Email rejected: 550 mailbox unavailable");
+    }
 }
@@ -1,7 +1,11 @@
 use std::fmt::Display;
 
 use chrono::{DateTime, Utc};
-use ferriskey_domain::{generate_timestamp, realm::RealmId};
+use ferriskey_domain::{
+    common::listing::{ListCursor, PageRequest},
+    generate_timestamp,
+    realm::RealmId,
+};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use utoipa::ToSchema;
@@ -198,6 +202,42 @@ pub fn validate_membership_realms(
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationSortField {
+    #[default]
+    Name,
+    Alias,
+    CreatedAt,
+}
+
+impl OrganizationSortField {
+    pub fn cursor(&self, organization: &Organization) -> ListCursor {
+        let key = match self {
+            OrganizationSortField::Name => organization.name.clone(),
+            OrganizationSortField::Alias => organization.alias.clone(),
+            OrganizationSortField::CreatedAt => {
+                organization.created_at.timestamp_micros().to_string()
+            }
+        };
+
+        ListCursor {
+            key,
+            id: organization.id.as_uuid(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrganizationFilter {
+    /// Whitespace-separated terms, each matched case-insensitively against the
+    /// name, alias and domain.
+    pub search: Option<String>,
+    pub enabled: Option<bool>,
+    pub sort: OrganizationSortField,
+    pub page: PageRequest,
+}
+
 // --- Input structs ---
 
 pub struct CreateOrganizationInput {
@@ -217,6 +257,7 @@ pub struct GetOrganizationInput {
 
 pub struct ListOrganizationsInput {
     pub realm_name: String,
+    pub filter: OrganizationFilter,
 }
 
 pub struct UpdateOrganizationInput {
@@ -2,14 +2,15 @@ use uuid::Uuid;
 
 use ferriskey_domain::auth::Identity;
 use ferriskey_domain::common::app_errors::CoreError;
+use ferriskey_domain::common::listing::Page;
 use ferriskey_domain::realm::RealmId;
 
 use crate::entities::{
     AddOrganizationMemberInput, CreateOrganizationInput, CreateOrganizationParams,
     DeleteOrganizationAttributeInput, DeleteOrganizationInput, GetOrganizationInput,
     ListOrganizationAttributesInput, ListOrganizationMembersInput, ListOrganizationsInput,
-    ListUserOrganizationsInput, Organization, OrganizationAttribute, OrganizationId,
-    OrganizationMember, RemoveOrganizationMemberInput, UpdateOrganizationInput,
+    ListUserOrganizationsInput, Organization, OrganizationAttribute, OrganizationFilter,
+    OrganizationId, OrganizationMember, RemoveOrganizationMemberInput, UpdateOrganizationInput,
     UpdateOrganizationParams, UpsertOrganizationAttributeInput,
 };
 
@@ -37,6 +38,20 @@ pub trait OrganizationRepository: Send + Sync {
         realm_id: RealmId,
     ) -> impl Future<Output = Result<Vec<Organization>, CoreError>> + Send;
 
+    /// Up to `filter.page.limit() + 1` organizations, the extra one telling
+    /// whether there is a next page.
+    fn search_organizations(
+        &self,
+        realm_id: RealmId,
+        filter: OrganizationFilter,
+    ) -> impl Future<Output = Result<Vec<Organization>, CoreError>> + Send;
+
+    fn count_organizations(
+        &self,
+        realm_id: RealmId,
+        filter: OrganizationFilter,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
     fn update_organization(
         &self,
         id: OrganizationId,
@@ -127,7 +142,13 @@ pub trait OrganizationService: Send + Sync {
         &self,
         identity: Identity,
         input: ListOrganizationsInput,
-    ) -> impl Future<Output = Result<Vec<Organization>, CoreError>> + Send;
+    ) -> impl Future<Output = Result<Page<Organization>, CoreError>> + Send;
+
+    fn count_organizations(
+        &self,
+        identity: Identity,
+        input: ListOrganizationsInput,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
 
     fn update_organization(
         &self,
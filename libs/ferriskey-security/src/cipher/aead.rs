@@ -0,0 +1,62 @@
+use ring::{
+    aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    rand::{SecureRandom, SystemRandom},
+};
+
+use crate::SecurityError;
+
+pub(crate) const KEY_LEN: usize = 32;
+
+/// Random bytes for keys and nonces.
+pub(crate) fn random_bytes<const N: usize>() -> Result<[u8; N], SecurityError> {
+    let mut bytes = [0u8; N];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| SecurityError::EncryptionError("no randomness available".to_string()))?;
+
+    Ok(bytes)
+}
+
+fn aes_key(key: &[u8]) -> Result<LessSafeKey, SecurityError> {
+    UnboundKey::new(&AES_256_GCM, key)
+        .map(LessSafeKey::new)
+        .map_err(|_| SecurityError::InvalidKey(format!("AES-256 keys are {KEY_LEN} bytes")))
+}
+
+/// AES-256-GCM with a random nonce, returned as `nonce || ciphertext || tag`.
+pub(crate) fn seal(key: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, SecurityError> {
+    let nonce = random_bytes::<NONCE_LEN>()?;
+    let mut in_out = plaintext.to_vec();
+
+    aes_key(key)?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(aad),
+            &mut in_out,
+        )
+        .map_err(|_| SecurityError::EncryptionError("sealing failed".to_string()))?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&in_out);
+    Ok(sealed)
+}
+
+/// Reverses [`seal`]; fails when the key, the data or `aad` do not match.
+pub(crate) fn open(key: &[u8], sealed: &[u8], aad: &[u8]) -> Result<Vec<u8>, SecurityError> {
+    if sealed.len() < NONCE_LEN {
+        return Err(SecurityError::DecryptionError(
+            "truncated ciphertext".to_string(),
+        ));
+    }
+
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| SecurityError::DecryptionError("invalid nonce".to_string()))?;
+    let mut in_out = ciphertext.to_vec();
+
+    let plaintext = aes_key(key)?
+        .open_in_place(nonce, Aad::from(aad), &mut in_out)
+        .map_err(|_| SecurityError::DecryptionError("authentication failed".to_string()))?;
+
+    Ok(plaintext.to_vec())
+}
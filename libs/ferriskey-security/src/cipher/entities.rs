@@ -0,0 +1,110 @@
+use std::fmt;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+
+use crate::{SecurityError, cipher::aead::KEY_LEN};
+
+/// Prefix of every value sealed by an [`EnvelopeCipher`](super::envelope::EnvelopeCipher).
+pub const SEALED_PREFIX: &str = "enc:v1:";
+
+/// A key encryption key held by a [`LocalKeyProvider`](super::local::LocalKeyProvider).
+#[derive(Clone)]
+pub struct MasterKey {
+    pub id: String,
+    key: [u8; KEY_LEN],
+}
+
+impl MasterKey {
+    pub fn new(id: impl Into<String>, key: [u8; KEY_LEN]) -> Result<Self, SecurityError> {
+        let id = id.into();
+        if id.is_empty() || id.contains(':') {
+            return Err(SecurityError::InvalidKey(format!(
+                "master key id {id:?} must be non-empty and contain no ':'"
+            )));
+        }
+
+        Ok(Self { id, key })
+    }
+
+    /// Decodes a base64 encoded 32 byte key, as produced by `openssl rand -base64 32`.
+    pub fn from_base64(id: impl Into<String>, encoded: &str) -> Result<Self, SecurityError> {
+        let bytes = BASE64_STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| SecurityError::InvalidKey(format!("master key is not base64: {e}")))?;
+        let key = bytes
+            .try_into()
+            .map_err(|_| SecurityError::InvalidKey(format!("master keys are {KEY_LEN} bytes")))?;
+
+        Self::new(id, key)
+    }
+
+    /// Parses an `id:base64` pair.
+    pub fn parse(spec: &str) -> Result<Self, SecurityError> {
+        let (id, encoded) = spec
+            .split_once(':')
+            .ok_or_else(|| SecurityError::InvalidKey("expected <id>:<base64 key>".to_string()))?;
+
+        Self::from_base64(id.trim(), encoded)
+    }
+
+    pub(crate) fn bytes(&self) -> &[u8] {
+        &self.key
+    }
+}
+
+impl fmt::Debug for MasterKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MasterKey")
+            .field("id", &self.id)
+            .field("key", &"********")
+            .finish()
+    }
+}
+
+/// A secret encrypted with its own data key, stored next to that data key
+/// wrapped by the master key `key_id`.
+///
+/// Encoded as `enc:v1:<key_id>:<wrapped data key>:<ciphertext>`, base64.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SealedSecret {
+    pub key_id: String,
+    pub wrapped_key: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+impl SealedSecret {
+    /// Returns `None` for values that were not sealed, such as secrets
+    /// written before encryption was enabled.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.strip_prefix(SEALED_PREFIX)?.split(':');
+        let key_id = parts.next()?.to_string();
+        let wrapped_key = BASE64_STANDARD.decode(parts.next()?).ok()?;
+        let ciphertext = BASE64_STANDARD.decode(parts.next()?).ok()?;
+
+        if key_id.is_empty() || parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            key_id,
+            wrapped_key,
+            ciphertext,
+        })
+    }
+
+    pub fn is_sealed(value: &str) -> bool {
+        Self::parse(value).is_some()
+    }
+}
+
+impl fmt::Display for SealedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{SEALED_PREFIX}{}:{}:{}",
+            self.key_id,
+            BASE64_STANDARD.encode(&self.wrapped_key),
+            BASE64_STANDARD.encode(&self.ciphertext)
+        )
+    }
+}
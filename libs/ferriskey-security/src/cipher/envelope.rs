@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::{
+    SecurityError,
+    cipher::{
+        aead::{self, KEY_LEN},
+        entities::SealedSecret,
+        ports::{KeyProvider, SecretCipher},
+    },
+};
+
+/// AES-256-GCM envelope encryption: every secret gets its own random data
+/// key, and only that data key goes through the [`KeyProvider`]. Rotating the
+/// master key therefore rewraps data keys without touching the secrets.
+#[derive(Debug, Clone)]
+pub struct EnvelopeCipher {
+    provider: Option<Arc<dyn KeyProvider>>,
+}
+
+impl EnvelopeCipher {
+    pub fn new(provider: Arc<dyn KeyProvider>) -> Self {
+        Self {
+            provider: Some(provider),
+        }
+    }
+
+    /// A cipher without master key: secrets are stored in clear, and sealed
+    /// values cannot be read.
+    pub fn disabled() -> Self {
+        Self { provider: None }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    fn provider(&self) -> Result<&dyn KeyProvider, SecurityError> {
+        self.provider.as_deref().ok_or_else(|| {
+            SecurityError::DecryptionError("no master key is configured".to_string())
+        })
+    }
+}
+
+impl SecretCipher for EnvelopeCipher {
+    async fn encrypt(&self, plaintext: &str) -> Result<String, SecurityError> {
+        let Some(provider) = self.provider.as_deref() else {
+            return Ok(plaintext.to_string());
+        };
+
+        let key_id = provider.current_key_id().to_string();
+        let data_key = aead::random_bytes::<KEY_LEN>()?;
+        let ciphertext = aead::seal(&data_key, plaintext.as_bytes(), key_id.as_bytes())?;
+        let wrapped_key = provider.wrap_key(&data_key).await?;
+
+        Ok(SealedSecret {
+            key_id,
+            wrapped_key,
+            ciphertext,
+        }
+        .to_string())
+    }
+
+    async fn decrypt(&self, value: &str) -> Result<String, SecurityError> {
+        let Some(sealed) = SealedSecret::parse(value) else {
+            return Ok(value.to_string());
+        };
+
+        let data_key = self
+            .provider()?
+            .unwrap_key(&sealed.key_id, &sealed.wrapped_key)
+            .await?;
+        let plaintext = aead::open(&data_key, &sealed.ciphertext, sealed.key_id.as_bytes())?;
+
+        String::from_utf8(plaintext)
+            .map_err(|_| SecurityError::DecryptionError("secret is not UTF-8".to_string()))
+    }
+
+    fn needs_rotation(&self, value: &str) -> bool {
+        let Some(provider) = self.provider.as_deref() else {
+            return false;
+        };
+
+        match SealedSecret::parse(value) {
+            Some(sealed) => sealed.key_id != provider.current_key_id(),
+            None => !value.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::{entities::MasterKey, local::LocalKeyProvider};
+
+    fn key(id: &str) -> MasterKey {
+        MasterKey::new(id, aead::random_bytes().unwrap()).unwrap()
+    }
+
+    fn cipher(current: &MasterKey, previous: &[MasterKey]) -> EnvelopeCipher {
+        EnvelopeCipher::new(Arc::new(LocalKeyProvider::new(
+            current.clone(),
+            previous.to_vec(),
+        )))
+    }
+
+    #[tokio::test]
+    async fn encrypted_secrets_round_trip_and_hide_the_plaintext() {
+        let cipher = cipher(&key("k1"), &[]);
+
+        let sealed = cipher.encrypt("JBSWY3DPEHPK3PXP").await.unwrap();
+
+        assert!(SealedSecret::is_sealed(&sealed));
+        assert!(!sealed.contains("JBSWY3DPEHPK3PXP"));
+        assert_eq!(cipher.decrypt(&sealed).await.unwrap(), "JBSWY3DPEHPK3PXP");
+    }
+
+    #[tokio::test]
+    async fn unsealed_values_are_returned_unchanged() {
+        let cipher = cipher(&key("k1"), &[]);
+
+        assert_eq!(cipher.decrypt("legacy").await.unwrap(), "legacy");
+        assert!(cipher.needs_rotation("legacy"));
+        assert!(!cipher.needs_rotation(""));
+    }
+
+    #[tokio::test]
+    async fn secrets_sealed_under_a_previous_key_still_decrypt_and_need_rotation() {
+        let old = key("k1");
+        let sealed = cipher(&old, &[]).encrypt("hunter2").await.unwrap();
+
+        let rotated = cipher(&key("k2"), &[old]);
+
+        assert!(rotated.needs_rotation(&sealed));
+        assert_eq!(rotated.decrypt(&sealed).await.unwrap(), "hunter2");
+        let resealed = rotated.encrypt("hunter2").await.unwrap();
+        assert!(!rotated.needs_rotation(&resealed));
+    }
+
+    #[tokio::test]
+    async fn decryption_fails_with_an_unknown_key_or_tampered_data() {
+        let owner = cipher(&key("k1"), &[]);
+        let sealed = owner.encrypt("hunter2").await.unwrap();
+
+        assert!(cipher(&key("k1"), &[]).decrypt(&sealed).await.is_err());
+
+        let mut tampered = SealedSecret::parse(&sealed).unwrap();
+        let last = tampered.ciphertext.len() - 1;
+        tampered.ciphertext[last] ^= 1;
+        assert!(owner.decrypt(&tampered.to_string()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn disabled_cipher_stores_in_clear_and_refuses_sealed_values() {
+        let sealed = cipher(&key("k1"), &[]).encrypt("hunter2").await.unwrap();
+        let disabled = EnvelopeCipher::disabled();
+
+        assert_eq!(disabled.encrypt("hunter2").await.unwrap(), "hunter2");
+        assert!(!disabled.needs_rotation("hunter2"));
+        assert!(disabled.decrypt(&sealed).await.is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use crate::{
+    SecurityError,
+    cipher::{
+        aead,
+        entities::MasterKey,
+        ports::{KeyFuture, KeyProvider},
+    },
+};
+
+/// Keeps the master keys in process memory, loaded from configuration.
+///
+/// Previous keys are only used to unwrap data keys sealed before a rotation;
+/// new data keys are always wrapped with the current key.
+#[derive(Debug, Clone)]
+pub struct LocalKeyProvider {
+    current: MasterKey,
+    previous: Vec<MasterKey>,
+}
+
+impl LocalKeyProvider {
+    pub fn new(current: MasterKey, previous: Vec<MasterKey>) -> Self {
+        Self { current, previous }
+    }
+
+    /// Reads the current master key, base64 encoded, from `path`.
+    pub fn from_file(
+        id: impl Into<String>,
+        path: &Path,
+        previous: Vec<MasterKey>,
+    ) -> Result<Self, SecurityError> {
+        let encoded = std::fs::read_to_string(path).map_err(|e| {
+            SecurityError::InvalidKey(format!("cannot read master key {}: {e}", path.display()))
+        })?;
+
+        Ok(Self::new(MasterKey::from_base64(id, &encoded)?, previous))
+    }
+
+    fn key(&self, key_id: &str) -> Result<&MasterKey, SecurityError> {
+        std::iter::once(&self.current)
+            .chain(&self.previous)
+            .find(|key| key.id == key_id)
+            .ok_or_else(|| SecurityError::InvalidKey(format!("unknown master key {key_id}")))
+    }
+}
+
+impl KeyProvider for LocalKeyProvider {
+    fn current_key_id(&self) -> &str {
+        &self.current.id
+    }
+
+    fn wrap_key<'a>(&'a self, data_key: &'a [u8]) -> KeyFuture<'a, Vec<u8>> {
+        Box::pin(
+            async move { aead::seal(self.current.bytes(), data_key, self.current.id.as_bytes()) },
+        )
+    }
+
+    fn unwrap_key<'a>(&'a self, key_id: &'a str, wrapped_key: &'a [u8]) -> KeyFuture<'a, Vec<u8>> {
+        Box::pin(async move {
+            let key = self.key(key_id)?;
+            aead::open(key.bytes(), wrapped_key, key.id.as_bytes())
+        })
+    }
+}
@@ -0,0 +1,6 @@
+pub mod entities;
+pub mod envelope;
+pub mod local;
+pub mod ports;
+
+mod aead;
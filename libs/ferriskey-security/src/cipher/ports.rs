@@ -0,0 +1,40 @@
+use std::{fmt::Debug, pin::Pin};
+
+use crate::SecurityError;
+
+pub type KeyFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, SecurityError>> + Send + 'a>>;
+
+/// Holds the master keys that wrap data keys.
+///
+/// Object safe so the provider can be chosen at startup: the local provider
+/// reads keys from a file or the environment, and an external KMS (Vault
+/// transit, AWS KMS, ...) implements the same three calls against its API.
+pub trait KeyProvider: Send + Sync + Debug {
+    /// Id of the master key new data keys are wrapped with.
+    fn current_key_id(&self) -> &str;
+
+    /// Wraps `data_key` with the current master key.
+    fn wrap_key<'a>(&'a self, data_key: &'a [u8]) -> KeyFuture<'a, Vec<u8>>;
+
+    /// Unwraps a data key wrapped with the master key `key_id`, which may be
+    /// a previous key kept around for rotation.
+    fn unwrap_key<'a>(&'a self, key_id: &'a str, wrapped_key: &'a [u8]) -> KeyFuture<'a, Vec<u8>>;
+}
+
+/// Encrypts secrets before they are stored, so a database dump does not
+/// disclose them.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+pub trait SecretCipher: Send + Sync {
+    fn encrypt(
+        &self,
+        plaintext: &str,
+    ) -> impl Future<Output = Result<String, SecurityError>> + Send;
+
+    /// Values that were never sealed are returned unchanged, so secrets
+    /// written before encryption was enabled keep working until rotated.
+    fn decrypt(&self, value: &str) -> impl Future<Output = Result<String, SecurityError>> + Send;
+
+    /// Whether `value` is stored in clear or sealed under a master key other
+    /// than the current one.
+    fn needs_rotation(&self, value: &str) -> bool;
+}
@@ -212,6 +212,9 @@ pub struct RefreshToken {
     pub id: Uuid,
     pub jti: Uuid,
     pub user_id: Uuid,
+    /// The client the token was issued to, unknown for tokens issued before
+    /// it was recorded.
+    pub client_id: Option<Uuid>,
     pub revoked: bool,
     pub expires_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
@@ -222,6 +225,7 @@ impl RefreshToken {
         id: Uuid,
         jti: Uuid,
         user_id: Uuid,
+        client_id: Option<Uuid>,
         revoked: bool,
         expires_at: Option<DateTime<Utc>>,
         created_at: DateTime<Utc>,
@@ -230,6 +234,7 @@ impl RefreshToken {
             id,
             jti,
             user_id,
+            client_id,
             revoked,
             expires_at,
             created_at,
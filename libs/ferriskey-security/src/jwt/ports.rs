@@ -36,6 +36,7 @@ pub trait RefreshTokenRepository: Send + Sync {
         &self,
         jti: Uuid,
         user_id: Uuid,
+        client_id: Option<Uuid>,
         expires_at: Option<DateTime<Utc>>,
     ) -> impl Future<Output = Result<RefreshToken, SecurityError>> + Send;
     fn get_by_jti(
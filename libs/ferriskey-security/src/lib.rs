@@ -1,5 +1,6 @@
 use thiserror::Error;
 
+pub mod cipher;
 pub mod crypto;
 pub mod jwt;
 
@@ -31,4 +32,10 @@ pub enum SecurityError {
 
     #[error("Expired token")]
     ExpiredToken,
+
+    #[error("Encryption error: {0}")]
+    EncryptionError(String),
+
+    #[error("Decryption error: {0}")]
+    DecryptionError(String),
 }
@@ -0,0 +1,90 @@
+use serde_json::Value;
+
+use crate::masked::Masked;
+
+/// Key fragments that mark a JSON field as sensitive. Matching is done on the
+/// lowercased key, so `clientSecret`, `client_secret` and `CLIENT_SECRET` are
+/// all caught by `secret`.
+const SENSITIVE_KEY_FRAGMENTS: [&str; 8] = [
+    "secret",
+    "password",
+    "passphrase",
+    "private_key",
+    "privatekey",
+    "api_key",
+    "apikey",
+    "credential_data",
+];
+
+/// Whether values under `key` must never be stored or logged in clear. Keys
+/// ending in `token` are sensitive too, unlike e.g. `token_lifetime`.
+pub fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    key.ends_with("token")
+        || SENSITIVE_KEY_FRAGMENTS
+            .iter()
+            .any(|fragment| key.contains(fragment))
+}
+
+/// Redacts, in place, every value stored under a sensitive key, however deep
+/// it is nested. Redacted values serialize exactly like a [`Masked`] field.
+pub fn mask_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if is_sensitive_key(key) && !field.is_null() {
+                    *field = Value::String(Masked::new(String::new()).to_string());
+                } else {
+                    mask_json(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(mask_json),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn masks_nested_sensitive_fields() {
+        let mut value = json!({
+            "name": "smtp",
+            "password": "hunter2",
+            "config": { "clientSecret": "abc", "port": 587 },
+            "providers": [{ "api_key": 42 }],
+        });
+
+        mask_json(&mut value);
+
+        assert_eq!(
+            value,
+            json!({
+                "name": "smtp",
+                "password": "***",
+                "config": { "clientSecret": "***", "port": 587 },
+                "providers": [{ "api_key": "***" }],
+            })
+        );
+    }
+
+    #[test]
+    fn token_settings_are_not_secrets() {
+        assert!(is_sensitive_key("refresh_token"));
+        assert!(is_sensitive_key("accessToken"));
+        assert!(!is_sensitive_key("access_token_lifetime"));
+    }
+
+    #[test]
+    fn leaves_null_secrets_visible() {
+        let mut value = json!({ "secret": null });
+
+        mask_json(&mut value);
+
+        assert_eq!(value, json!({ "secret": null }));
+    }
+}
@@ -1,5 +1,7 @@
+mod json;
 mod masked;
 mod strategies;
 
+pub use json::{is_sensitive_key, mask_json};
 pub use masked::{Masked, MaskedString, MaskedWith, Redaction};
-pub use strategies::{EmailMask, FullMask, HashMask, PartialMask};
+pub use strategies::{EmailMask, FullMask, HashMask, MaskStrategy, PartialMask};